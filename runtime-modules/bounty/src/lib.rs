@@ -102,8 +102,8 @@ use serde::{Deserialize, Serialize};
 
 use common::council::CouncilBudgetManager;
 use common::membership::{
-    MemberId, MemberOriginValidator, MembershipInfoProvider, MembershipTypes,
-    StakingAccountValidator,
+    MemberId, MemberOriginValidator, MemberReputationHandler, MembershipInfoProvider,
+    MembershipTypes, ReputationActivity, StakingAccountValidator,
 };
 use common::to_kb;
 use frame_support::dispatch::{DispatchError, DispatchResult};
@@ -137,11 +137,12 @@ pub trait Config:
     /// Bounty Id type
     type BountyId: From<u32> + Parameter + Default + Copy + MaxEncodedLen;
 
-    /// Validates staking account ownership for a member, member ID and origin combination and
-    /// providers controller id for a member.
+    /// Validates staking account ownership for a member, member ID and origin combination,
+    /// providers controller id for a member and updates member reputation.
     type Membership: StakingAccountValidator<Self>
         + MembershipInfoProvider<Self>
-        + MemberOriginValidator<Self::Origin, MemberId<Self>, Self::AccountId>;
+        + MemberOriginValidator<Self::Origin, MemberId<Self>, Self::AccountId>
+        + MemberReputationHandler<Self>;
 
     /// Weight information for extrinsics in this pallet.
     type WeightInfo: WeightInfo;
@@ -1493,6 +1494,12 @@ decl_module! {
                         // Delete the work entry record from the storage.
                         Self::remove_work_entry(&bounty_id, entry_id);
 
+                        // Increase the winner reputation.
+                        T::Membership::record_activity(
+                            &entry.member_id,
+                            ReputationActivity::SuccessfulBountyEntry
+                        );
+
                         // Fire an event.
                         Self::deposit_event(RawEvent::WorkEntrantFundsWithdrawn(bounty_id, *entry_id, entry.member_id));
 
@@ -2377,8 +2384,11 @@ impl<T: Config> Module<T> {
                 },
             );
 
-        WeightInfoBounty::<T>::submit_oracle_judgment_by_council(j, k, w, r).max(
-            WeightInfoBounty::<T>::submit_oracle_judgment_by_member(j, k, w, r),
-        )
+        WeightInfoBounty::<T>::submit_oracle_judgment_by_council(j, k, w, r)
+            .max(WeightInfoBounty::<T>::submit_oracle_judgment_by_member(
+                j, k, w, r,
+            ))
+            // Winners reputation is increased
+            .saturating_add(T::Membership::record_activity_weight().saturating_mul(w.into()))
     }
 }
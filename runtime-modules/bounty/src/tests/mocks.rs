@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use frame_support::dispatch::{DispatchError, DispatchResult};
-use frame_support::traits::{ConstU32, Currency, EnsureOneOf, LockIdentifier};
+use frame_support::traits::{ConstU32, ConstU64, Currency, EnsureOneOf, LockIdentifier};
 use frame_support::{ensure, parameter_types, PalletId};
 use frame_system::{ensure_signed, EnsureRoot, EnsureSigned};
 use sp_core::H256;
@@ -190,6 +190,13 @@ impl membership::Config for Test {
     type WorkingGroup = Wg;
     type DefaultInitialInvitationBalance = DefaultInitialInvitationBalance;
     type DefaultMemberInvitesCount = DefaultMemberInvitesCount;
+    type MemberReputationRules = ();
+    type MaxRecoveryGuardians = ();
+    type RecoveryDelay = ();
+    type RecoveryDeposit = ();
+    type HandleExpiryPeriod = ();
+    type HandleExpiryGracePeriod = ();
+    type MaxUplineReferralLevels = ();
     type InvitedMemberStakingHandler = staking_handler::StakingManager<Self, InvitedMemberLockId>;
     type StakingCandidateStakingHandler =
         staking_handler::StakingManager<Self, StakingCandidateLockId>;
@@ -302,13 +309,20 @@ pub type ReferendumInstance = referendum::Instance1;
 impl council::Config for Test {
     type Event = Event;
     type Referendum = referendum::Pallet<Test, ReferendumInstance>;
+    type RecallReferendum = referendum::Pallet<Test, ReferendumInstance>;
     type MinNumberOfExtraCandidates = MinNumberOfExtraCandidates;
     type CouncilSize = CouncilSize;
+    type StaggeredSeatsPerCycle = ConstU32<0>;
+    type MaxConsecutiveTerms = ConstU32<0>;
+    type MaxRunnersUp = ConstU32<0>;
     type AnnouncingPeriodDuration = AnnouncingPeriodDuration;
     type IdlePeriodDuration = IdlePeriodDuration;
     type MinCandidateStake = MinCandidateStake;
     type CandidacyLock = StakingManager<Self, CandidacyLockId>;
     type CouncilorLock = StakingManager<Self, CouncilorLockId>;
+    type RecallPetitionLock = StakingManager<Self, common::locks::RecallPetitionLockId>;
+    type MinRecallPetitionStake = ConstU64<100>;
+    type RecallPetitionThreshold = ConstU64<10000>;
     type ElectedMemberRewardPeriod = ElectedMemberRewardPeriod;
     type BudgetRefillPeriod = BudgetRefillPeriod;
     type StakingAccountValidator = ();
@@ -338,6 +352,7 @@ impl referendum::Config<ReferendumInstance> for Test {
     type MinimumStake = MinimumVotingStake;
     type WeightInfo = ();
     type MaxWinnerTargetCount = MaxWinnerTargetCount;
+    type MaxDelegatorsPerDelegate = ConstU32<10>;
 
     fn calculate_vote_power(
         _: &<Self as frame_system::Config>::AccountId,
@@ -5,6 +5,8 @@ parameter_types! {
     pub const VotingLockId: LockIdentifier = *b"voting  ";
     pub const CandidacyLockId: LockIdentifier = *b"candidac";
     pub const CouncilorLockId: LockIdentifier = *b"councilo";
+    pub const RecallVotingLockId: LockIdentifier = *b"recallvo";
+    pub const RecallPetitionLockId: LockIdentifier = *b"recallpe";
     pub const ProposalsLockId: LockIdentifier = *b"proposal";
     pub const StorageWorkingGroupLockId: LockIdentifier = *b"wg-storg";
     pub const ContentWorkingGroupLockId: LockIdentifier = *b"wg-contt";
@@ -18,6 +20,7 @@ parameter_types! {
     pub const OperationsWorkingGroupBetaLockId: LockIdentifier = *b"wg-operb";
     pub const OperationsWorkingGroupGammaLockId: LockIdentifier = *b"wg-operg";
     pub const DistributionWorkingGroupLockId: LockIdentifier = *b"wg-distr";
+    pub const ForumPollLockId: LockIdentifier = *b"forumpol";
 }
 
 // Staking lock ID used by nomination and validation in the staking pallet.
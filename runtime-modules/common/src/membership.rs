@@ -1,6 +1,10 @@
-use codec::{Codec, MaxEncodedLen};
+use codec::{Codec, Decode, Encode, MaxEncodedLen};
 use frame_support::dispatch::DispatchError;
+use frame_support::weights::Weight;
 use frame_support::Parameter;
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_arithmetic::traits::BaseArithmetic;
 use sp_runtime::traits::{MaybeSerialize, MaybeSerializeDeserialize, Member};
 use sp_std::convert::Into;
@@ -11,6 +15,9 @@ pub type MemberId<T> = <T as MembershipTypes>::MemberId;
 /// Actor id type alias
 pub type ActorId<T> = <T as MembershipTypes>::ActorId;
 
+/// Member reputation score type alias
+pub type Reputation = u32;
+
 /// Generic trait for membership dependent pallets.
 pub trait MembershipTypes: frame_system::Config {
     /// Describes the common type for the members.
@@ -64,3 +71,50 @@ pub trait MembershipInfoProvider<T: MembershipTypes> {
     /// Returns current controller account for a member.
     fn controller_account_id(member_id: MemberId<T>) -> Result<T::AccountId, DispatchError>;
 }
+
+/// On-chain activities of a member affecting the member reputation.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum ReputationActivity {
+    /// Work entry of the member was judged as a bounty winner.
+    SuccessfulBountyEntry,
+
+    /// Proposal created by the member was executed.
+    ProposalExecuted,
+
+    /// Member served one more reward period as a worker.
+    WorkerTenure,
+
+    /// Moderation action was taken against the member.
+    ModerationAction,
+}
+
+/// Gives access to the member reputation and updates it on member activities.
+pub trait MemberReputationHandler<T: MembershipTypes> {
+    /// Returns current reputation of the member.
+    fn reputation(member_id: &MemberId<T>) -> Reputation;
+
+    /// Updates the member reputation according to the provided activity.
+    fn record_activity(member_id: &MemberId<T>, activity: ReputationActivity);
+
+    /// Returns the weight of the `record_activity` call, to be included by the callers.
+    fn record_activity_weight() -> Weight;
+
+    /// Verifies that the member reputation is not less than the provided minimum.
+    fn has_min_reputation(member_id: &MemberId<T>, min_reputation: Reputation) -> bool {
+        Self::reputation(member_id) >= min_reputation
+    }
+}
+
+// No-op implementation: reputation is not tracked.
+impl<T: MembershipTypes> MemberReputationHandler<T> for () {
+    fn reputation(_member_id: &MemberId<T>) -> Reputation {
+        0
+    }
+
+    fn record_activity(_member_id: &MemberId<T>, _activity: ReputationActivity) {}
+
+    fn record_activity_weight() -> Weight {
+        0
+    }
+}
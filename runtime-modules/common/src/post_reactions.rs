@@ -0,0 +1,120 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::dispatch::DispatchResult;
+use frame_support::traits::{Currency, ExistenceRequirement};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_arithmetic::traits::Saturating;
+
+/// Fixed set of reactions that members can attach to a post.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum PostReaction {
+    /// Approval of the post.
+    Like,
+
+    /// Disapproval of the post.
+    Dislike,
+
+    /// Post brings useful insight.
+    Insightful,
+
+    /// Post is funny.
+    Funny,
+
+    /// Post is worth celebrating.
+    Celebrate,
+}
+
+/// Number of reactions of each kind attached to a post.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct PostReactionCounters {
+    /// Number of `Like` reactions.
+    pub like: u32,
+
+    /// Number of `Dislike` reactions.
+    pub dislike: u32,
+
+    /// Number of `Insightful` reactions.
+    pub insightful: u32,
+
+    /// Number of `Funny` reactions.
+    pub funny: u32,
+
+    /// Number of `Celebrate` reactions.
+    pub celebrate: u32,
+}
+
+impl PostReactionCounters {
+    fn counter_mut(&mut self, reaction: PostReaction) -> &mut u32 {
+        match reaction {
+            PostReaction::Like => &mut self.like,
+            PostReaction::Dislike => &mut self.dislike,
+            PostReaction::Insightful => &mut self.insightful,
+            PostReaction::Funny => &mut self.funny,
+            PostReaction::Celebrate => &mut self.celebrate,
+        }
+    }
+
+    /// Number of reactions of the provided kind.
+    pub fn count(&self, reaction: PostReaction) -> u32 {
+        match reaction {
+            PostReaction::Like => self.like,
+            PostReaction::Dislike => self.dislike,
+            PostReaction::Insightful => self.insightful,
+            PostReaction::Funny => self.funny,
+            PostReaction::Celebrate => self.celebrate,
+        }
+    }
+
+    /// Total number of reactions attached to the post.
+    pub fn total(&self) -> u32 {
+        self.like
+            .saturating_add(self.dislike)
+            .saturating_add(self.insightful)
+            .saturating_add(self.funny)
+            .saturating_add(self.celebrate)
+    }
+
+    /// Replaces the `old` reaction of a member with the `new` one.
+    /// Returns `false` (leaving counters unchanged) if the change would make the total number
+    /// of reactions exceed `max_reactions`.
+    pub fn try_replace(
+        &mut self,
+        old: Option<PostReaction>,
+        new: Option<PostReaction>,
+        max_reactions: u32,
+    ) -> bool {
+        if old.is_none() && new.is_some() && self.total() >= max_reactions {
+            return false;
+        }
+
+        if let Some(old) = old {
+            let counter = self.counter_mut(old);
+            *counter = counter.saturating_sub(1);
+        }
+
+        if let Some(new) = new {
+            let counter = self.counter_mut(new);
+            *counter = counter.saturating_add(1);
+        }
+
+        true
+    }
+}
+
+// Transfer a tip from the tipper to the post author's controller account.
+// The tipper account is kept alive.
+pub fn tip_post_author<T: frame_system::Config + balances::Config>(
+    tipper: &T::AccountId,
+    author_account: &T::AccountId,
+    amount: T::Balance,
+) -> DispatchResult {
+    <balances::Pallet<T> as Currency<T::AccountId>>::transfer(
+        tipper,
+        author_account,
+        amount,
+        ExistenceRequirement::KeepAlive,
+    )
+}
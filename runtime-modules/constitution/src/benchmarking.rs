@@ -1,11 +1,14 @@
 #![cfg(feature = "runtime-benchmarks")]
 
-use crate::{Call, Config, ConstitutionInfo, Event, Module as Pallet};
+use crate::{
+    Call, Config, ConstitutionInfo, ConstitutionSections, Event, Module as Pallet, SectionAmendment,
+};
 use frame_benchmarking::benchmarks;
 use frame_system::Pallet as System;
 use frame_system::{EventRecord, RawOrigin};
 use sp_runtime::traits::Hash;
 use sp_std::vec;
+use sp_std::vec::Vec;
 
 fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
     let events = System::<T>::events();
@@ -16,22 +19,35 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
 }
 
 const MAX_BYTES: u32 = 50000;
+const MAX_SECTIONS: u32 = 100;
 
 benchmarks! {
     amend_constitution{
         let i in 1 .. MAX_BYTES;
-        let text = vec![0u8].repeat(i as usize);
+        let s in 1 .. MAX_SECTIONS;
 
-    }: _ (RawOrigin::Root, text.clone())
+        // Worst case scenario: all sections exist and are replaced
+        let initial_amendments = (0..s)
+            .map(|section_id| SectionAmendment::Add(section_id, vec![1u8]))
+            .collect::<Vec<_>>();
+        Pallet::<T>::amend_constitution(RawOrigin::Root.into(), initial_amendments, None).unwrap();
+
+        let text_len = (i / s).max(1) as usize;
+        let amendments = (0..s)
+            .map(|section_id| SectionAmendment::Replace(section_id, vec![0u8].repeat(text_len)))
+            .collect::<Vec<_>>();
+    }: _ (RawOrigin::Root, amendments.clone(), None)
     verify {
-            let hash = T::Hashing::hash(&text);
+            let hash = T::Hashing::hash_of(&amendments);
 
             let constitution_info = ConstitutionInfo{
-                text_hash: hash,
+                amendment_hash: hash,
+                version: 2,
             };
 
             assert_eq!(Pallet::<T>::constitution(), constitution_info);
-            assert_last_event::<T>(Event::<T>::ConstutionAmended(hash, text).into());
+            assert_eq!(ConstitutionSections::<T>::get(0).version, 2);
+            assert_last_event::<T>(Event::<T>::ConstutionAmended(2, hash, amendments, None).into());
     }
 }
 
@@ -1,7 +1,9 @@
 //! # Constitution pallet.
-//! `Constitution` pallet for the Joystream platform. Version 1.
-//! It contains current constitution text hash and amendment number in the storage and extrinsic for
-//! setting the new constitution.
+//! `Constitution` pallet for the Joystream platform. Version 2.
+//! The constitution is modelled as numbered sections, each with a text hash and a version.
+//! It contains the current constitution version, the current state of every section and the
+//! history of all constitution and section versions in the storage, and an extrinsic for
+//! amending the constitution sections.
 //!
 
 // Ensure we're `no_std` when compiling for Wasm.
@@ -27,17 +29,19 @@ extern crate common;
 pub(crate) mod tests;
 
 mod benchmarking;
+mod migrations;
 pub mod weights;
 pub use weights::WeightInfo;
 
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::{decl_event, decl_module, decl_storage};
+use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure, Parameter};
 use frame_system::ensure_root;
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
-use sp_runtime::traits::Hash;
+use sp_runtime::traits::{Hash, Saturating};
 use sp_runtime::SaturatedConversion;
+use sp_std::collections::btree_set::BTreeSet;
 use sp_std::vec::Vec;
 
 type WeightInfoConstitution<T> = <T as Config>::WeightInfo;
@@ -45,61 +49,265 @@ type WeightInfoConstitution<T> = <T as Config>::WeightInfo;
 pub trait Config: frame_system::Config {
     type Event: From<Event<Self>> + Into<<Self as frame_system::Config>::Event>;
 
+    /// Id of the proposal enacting a constitution amendment.
+    type ProposalId: Parameter + Copy + MaxEncodedLen;
+
     /// Weight information for extrinsics in this pallet.
     type WeightInfo: WeightInfo;
 }
 
-/// Contains constitution text hash and its amendment number.
+/// Constitution section number.
+pub type SectionId = u32;
+
+/// Constitution (or section) version number.
+pub type Version = u32;
+
+/// Contains the hash of the last constitution amendment and the current constitution version.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, Default, TypeInfo, MaxEncodedLen)]
 pub struct ConstitutionInfo<Hash> {
-    /// Constitution text hash.
-    pub text_hash: Hash,
+    /// Hash of the section diff which produced the current version.
+    pub amendment_hash: Hash,
+
+    /// Current constitution version.
+    pub version: Version,
+}
+
+/// Retained constitution version.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, Default, TypeInfo, MaxEncodedLen)]
+pub struct ConstitutionVersion<Hash, BlockNumber, ProposalId> {
+    /// Hash of the section diff which produced the version.
+    pub amendment_hash: Hash,
+
+    /// Block at which the version was enacted.
+    pub amended_at: BlockNumber,
+
+    /// Proposal which enacted the version (if any).
+    pub proposal_id: Option<ProposalId>,
+}
+
+/// Current state of a constitution section.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, Default, TypeInfo, MaxEncodedLen)]
+pub struct ConstitutionSection<Hash> {
+    /// Section text hash, `None` if the section doesn't exist (or was removed).
+    pub text_hash: Option<Hash>,
+
+    /// Section version, incremented on every amendment of the section.
+    pub version: Version,
+}
+
+/// Retained constitution section version.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, Default, TypeInfo, MaxEncodedLen)]
+pub struct SectionVersion<Hash> {
+    /// Section text hash, `None` if the section was removed in this version.
+    pub text_hash: Option<Hash>,
+
+    /// Constitution version which introduced the section version.
+    pub constitution_version: Version,
+}
+
+/// Amendment of a single constitution section.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub enum SectionAmendment {
+    /// Adds a new section (or restores a removed one) with the provided text.
+    Add(SectionId, Vec<u8>),
+
+    /// Replaces the text of an existing section.
+    Replace(SectionId, Vec<u8>),
+
+    /// Removes an existing section.
+    Remove(SectionId),
 }
 
+impl SectionAmendment {
+    /// Number of the amended section.
+    pub fn section_id(&self) -> SectionId {
+        match self {
+            SectionAmendment::Add(section_id, _)
+            | SectionAmendment::Replace(section_id, _)
+            | SectionAmendment::Remove(section_id) => *section_id,
+        }
+    }
+
+    /// Length of the new section text.
+    pub fn text_len(&self) -> usize {
+        match self {
+            SectionAmendment::Add(_, text) | SectionAmendment::Replace(_, text) => text.len(),
+            SectionAmendment::Remove(_) => 0,
+        }
+    }
+}
+
+/// Alias for the retained constitution version.
+pub type ConstitutionVersionOf<T> = ConstitutionVersion<
+    <T as frame_system::Config>::Hash,
+    <T as frame_system::Config>::BlockNumber,
+    <T as Config>::ProposalId,
+>;
+
 decl_storage! { generate_storage_info
     trait Store for Module<T: Config> as Constitution {
+        /// Current constitution version.
         Constitution get(fn constitution) : ConstitutionInfo<<T as frame_system::Config>::Hash>;
+
+        /// History of the constitution versions.
+        pub ConstitutionVersions get(fn constitution_versions):
+            map hasher(blake2_128_concat) Version => Option<ConstitutionVersionOf<T>>;
+
+        /// Current state of the constitution sections.
+        pub ConstitutionSections get(fn constitution_sections):
+            map hasher(blake2_128_concat) SectionId => ConstitutionSection<T::Hash>;
+
+        /// History of the constitution section versions.
+        pub SectionVersions get(fn section_versions):
+            double_map hasher(blake2_128_concat) SectionId, hasher(blake2_128_concat) Version =>
+                Option<SectionVersion<T::Hash>>;
     }
 }
 
 decl_event! {
-    pub enum Event<T> where Hash = <T as frame_system::Config>::Hash {
+    pub enum Event<T>
+    where
+        Hash = <T as frame_system::Config>::Hash,
+        ProposalId = <T as Config>::ProposalId,
+    {
         /// Emits on constitution amendment.
         /// Parameters:
-        /// - constitution text hash
-        /// - constitution text
-        ConstutionAmended(Hash, Vec<u8>),
+        /// - new constitution version
+        /// - section diff hash
+        /// - section diff
+        /// - proposal which enacted the amendment
+        ConstutionAmended(Version, Hash, Vec<SectionAmendment>, Option<ProposalId>),
+    }
+}
+
+decl_error! {
+    /// Constitution module predefined errors
+    pub enum Error for Module<T: Config> {
+        /// Constitution amendment contains no section amendments.
+        NoSectionAmendments,
+
+        /// Constitution amendment targets the same section more than once.
+        DuplicateSectionAmendment,
+
+        /// Section to add already exists.
+        SectionAlreadyExists,
+
+        /// Section to replace or remove doesn't exist.
+        SectionDoesNotExist,
     }
 }
 
 decl_module! {
     pub struct Module<T: Config> for enum Call where origin: T::Origin {
+        /// Predefined errors
+        type Error = Error<T>;
+
         fn deposit_event() = default;
 
-        /// Sets the current constitution hash. Requires root origin.
+        /// Amends the constitution sections. Every amendment creates a new constitution version.
+        /// Requires root origin.
         /// # <weight>
-        /// - Complexity: `O(C)` where C is the length of the constitution text.
-        /// - Db reads: 0
-        /// - Db writes: 1 (constant value)
+        /// - Complexity: `O(C + S)` where C is the total length of the section texts and S is the
+        /// number of amended sections.
+        /// - Db reads: S
+        /// - Db writes: 2 + 2 * S
         /// # </weight>
-        #[weight = WeightInfoConstitution::<T>::amend_constitution(constitution_text.len().saturated_into())]
-        pub fn amend_constitution(origin, constitution_text: Vec<u8>) {
+        #[weight = WeightInfoConstitution::<T>::amend_constitution(
+            amendments.iter().map(|amendment| amendment.text_len()).sum::<usize>().saturated_into(),
+            amendments.len().saturated_into(),
+        )]
+        pub fn amend_constitution(
+            origin,
+            amendments: Vec<SectionAmendment>,
+            proposal_id: Option<T::ProposalId>,
+        ) {
             ensure_root(origin)?;
 
+            let sections = Self::ensure_valid_amendments(&amendments)?;
+
             //
             // == MUTATION SAFE ==
             //
 
-            let hash = T::Hashing::hash(&constitution_text);
+            let amendment_hash = T::Hashing::hash_of(&amendments);
+            let version = Self::constitution().version.saturating_add(1);
 
-            let constitution = ConstitutionInfo {
-                text_hash: hash,
-            };
+            for (section_id, section) in sections {
+                SectionVersions::<T>::insert(section_id, section.version, SectionVersion {
+                    text_hash: section.text_hash,
+                    constitution_version: version,
+                });
+                ConstitutionSections::<T>::insert(section_id, section);
+            }
+
+            ConstitutionVersions::<T>::insert(version, ConstitutionVersion {
+                amendment_hash,
+                amended_at: frame_system::Pallet::<T>::block_number(),
+                proposal_id,
+            });
 
-            Constitution::<T>::put(constitution);
+            Constitution::<T>::put(ConstitutionInfo {
+                amendment_hash,
+                version,
+            });
 
-            Self::deposit_event(Event::<T>::ConstutionAmended(hash, constitution_text));
+            Self::deposit_event(
+                Event::<T>::ConstutionAmended(version, amendment_hash, amendments, proposal_id)
+            );
         }
     }
 }
+
+impl<T: Config> Module<T> {
+    // Ensures section amendments are applicable to the current sections and returns the new
+    // state of the amended sections.
+    fn ensure_valid_amendments(
+        amendments: &[SectionAmendment],
+    ) -> Result<Vec<(SectionId, ConstitutionSection<T::Hash>)>, Error<T>> {
+        ensure!(!amendments.is_empty(), Error::<T>::NoSectionAmendments);
+
+        let mut amended_section_ids = BTreeSet::new();
+        let mut sections = Vec::new();
+        for amendment in amendments {
+            let section_id = amendment.section_id();
+            ensure!(
+                amended_section_ids.insert(section_id),
+                Error::<T>::DuplicateSectionAmendment
+            );
+
+            let section = Self::constitution_sections(section_id);
+            let text_hash = match amendment {
+                SectionAmendment::Add(_, text) => {
+                    ensure!(
+                        section.text_hash.is_none(),
+                        Error::<T>::SectionAlreadyExists
+                    );
+                    Some(T::Hashing::hash(text))
+                }
+                SectionAmendment::Replace(_, text) => {
+                    ensure!(section.text_hash.is_some(), Error::<T>::SectionDoesNotExist);
+                    Some(T::Hashing::hash(text))
+                }
+                SectionAmendment::Remove(_) => {
+                    ensure!(section.text_hash.is_some(), Error::<T>::SectionDoesNotExist);
+                    None
+                }
+            };
+
+            sections.push((
+                section_id,
+                ConstitutionSection {
+                    text_hash,
+                    version: section.version.saturating_add(1),
+                },
+            ));
+        }
+
+        Ok(sections)
+    }
+}
@@ -0,0 +1,72 @@
+// Storage migrations of the constitution pallet.
+
+use codec::{Decode, Encode};
+use frame_support::{traits::Get, weights::Weight};
+
+use crate::{
+    Config, Constitution, ConstitutionInfo, ConstitutionSection, ConstitutionSections,
+    ConstitutionVersion, ConstitutionVersions, Module, SectionId, SectionVersion, SectionVersions,
+};
+
+/// Section holding the whole constitution text amended before the sections were introduced.
+pub(crate) const LEGACY_CONSTITUTION_SECTION_ID: SectionId = 1;
+
+/// Constitution info stored before the constitution sections were introduced
+#[derive(Encode, Decode)]
+pub(crate) struct LegacyConstitutionInfo<Hash> {
+    pub text_hash: Hash,
+}
+
+impl<T: Config> Module<T> {
+    /// Translates the stored constitution to the constitution version 1, with the current
+    /// constitution text hash carried over as the `LEGACY_CONSTITUTION_SECTION_ID` section.
+    pub fn migrate_constitution() -> Weight {
+        // Already migrated
+        if ConstitutionVersions::<T>::contains_key(1) {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut legacy_text_hash = None;
+
+        let _ = Constitution::<T>::translate::<LegacyConstitutionInfo<T::Hash>, _>(|info| {
+            info.map(|info| {
+                legacy_text_hash = Some(info.text_hash);
+                ConstitutionInfo {
+                    amendment_hash: info.text_hash,
+                    version: 1,
+                }
+            })
+        });
+
+        let text_hash = match legacy_text_hash {
+            Some(text_hash) => text_hash,
+            None => return T::DbWeight::get().reads(2),
+        };
+
+        ConstitutionVersions::<T>::insert(
+            1,
+            ConstitutionVersion {
+                amendment_hash: text_hash,
+                amended_at: frame_system::Pallet::<T>::block_number(),
+                proposal_id: None,
+            },
+        );
+        ConstitutionSections::<T>::insert(
+            LEGACY_CONSTITUTION_SECTION_ID,
+            ConstitutionSection {
+                text_hash: Some(text_hash),
+                version: 1,
+            },
+        );
+        SectionVersions::<T>::insert(
+            LEGACY_CONSTITUTION_SECTION_ID,
+            1,
+            SectionVersion {
+                text_hash: Some(text_hash),
+                constitution_version: 1,
+            },
+        );
+
+        T::DbWeight::get().reads_writes(2, 4)
+    }
+}
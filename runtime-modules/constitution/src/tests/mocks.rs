@@ -60,6 +60,7 @@ impl frame_system::Config for Test {
 
 impl Config for Test {
     type Event = Event;
+    type ProposalId = u32;
     type WeightInfo = ();
 }
 
@@ -2,7 +2,10 @@
 
 pub(crate) mod mocks;
 
-use crate::{ConstitutionInfo, Event};
+use crate::{
+    ConstitutionInfo, ConstitutionSection, ConstitutionVersion, Error, Event, SectionAmendment,
+    SectionVersion,
+};
 use frame_support::dispatch::DispatchResult;
 use frame_support::traits::{OnFinalize, OnInitialize};
 use frame_system::{EventRecord, Phase, RawOrigin};
@@ -43,37 +46,68 @@ impl EventFixture {
 
 pub struct AmendConstitutionFixture {
     origin: RawOrigin<u64>,
-    text: Vec<u8>,
+    amendments: Vec<SectionAmendment>,
+    proposal_id: Option<u32>,
 }
 
 impl AmendConstitutionFixture {
     pub fn default() -> Self {
         Self {
             origin: RawOrigin::Root,
-            text: Vec::new(),
+            amendments: vec![SectionAmendment::Add(1, b"Section text".to_vec())],
+            proposal_id: Some(1),
         }
     }
     pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
         Self { origin, ..self }
     }
 
-    pub fn with_text(self, text: Vec<u8>) -> Self {
-        Self { text, ..self }
+    pub fn with_amendments(self, amendments: Vec<SectionAmendment>) -> Self {
+        Self { amendments, ..self }
     }
 
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
         let old_constitution = Constitution::constitution();
 
-        let actual_result =
-            Constitution::amend_constitution(self.origin.clone().into(), self.text.clone());
+        let actual_result = Constitution::amend_constitution(
+            self.origin.clone().into(),
+            self.amendments.clone(),
+            self.proposal_id,
+        );
 
         assert_eq!(actual_result, expected_result);
 
         let new_constitution = Constitution::constitution();
         if actual_result.is_ok() {
-            let hash = <Test as frame_system::Config>::Hashing::hash(&self.text);
+            let hash = <Test as frame_system::Config>::Hashing::hash_of(&self.amendments);
+            let version = old_constitution.version + 1;
 
-            assert_eq!(new_constitution, ConstitutionInfo { text_hash: hash });
+            assert_eq!(
+                new_constitution,
+                ConstitutionInfo {
+                    amendment_hash: hash,
+                    version
+                }
+            );
+            assert_eq!(
+                Constitution::constitution_versions(version),
+                Some(ConstitutionVersion {
+                    amendment_hash: hash,
+                    amended_at: System::block_number(),
+                    proposal_id: self.proposal_id,
+                })
+            );
+
+            for amendment in &self.amendments {
+                let section = Constitution::constitution_sections(amendment.section_id());
+                assert_eq!(
+                    Constitution::section_versions(amendment.section_id(), section.version),
+                    Some(SectionVersion {
+                        text_hash: section.text_hash,
+                        constitution_version: version,
+                    })
+                );
+            }
         } else {
             assert_eq!(old_constitution, new_constitution);
         }
@@ -86,15 +120,102 @@ fn amend_contitution_succeeds() {
         let starting_block = 1;
         run_to_block(starting_block);
 
-        let text = b"Constitution text".to_vec();
+        let amendments = vec![
+            SectionAmendment::Add(1, b"First section".to_vec()),
+            SectionAmendment::Add(2, b"Second section".to_vec()),
+        ];
 
-        let hash = <Test as frame_system::Config>::Hashing::hash(&text);
+        let hash = <Test as frame_system::Config>::Hashing::hash_of(&amendments);
 
         let amend_constitution_fixture =
-            AmendConstitutionFixture::default().with_text(text.clone());
+            AmendConstitutionFixture::default().with_amendments(amendments.clone());
         amend_constitution_fixture.call_and_assert(Ok(()));
 
-        EventFixture::assert_last_crate_event(Event::<Test>::ConstutionAmended(hash, text));
+        EventFixture::assert_last_crate_event(Event::<Test>::ConstutionAmended(
+            1,
+            hash,
+            amendments,
+            Some(1),
+        ));
+    });
+}
+
+#[test]
+fn amend_contitution_retains_section_history() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let text = b"Section text".to_vec();
+        let new_text = b"New section text".to_vec();
+
+        AmendConstitutionFixture::default()
+            .with_amendments(vec![SectionAmendment::Add(1, text.clone())])
+            .call_and_assert(Ok(()));
+        AmendConstitutionFixture::default()
+            .with_amendments(vec![SectionAmendment::Replace(1, new_text.clone())])
+            .call_and_assert(Ok(()));
+        AmendConstitutionFixture::default()
+            .with_amendments(vec![SectionAmendment::Remove(1)])
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            Constitution::constitution_sections(1),
+            ConstitutionSection {
+                text_hash: None,
+                version: 3
+            }
+        );
+        assert_eq!(
+            Constitution::section_versions(1, 1),
+            Some(SectionVersion {
+                text_hash: Some(<Test as frame_system::Config>::Hashing::hash(&text)),
+                constitution_version: 1,
+            })
+        );
+        assert_eq!(
+            Constitution::section_versions(1, 2),
+            Some(SectionVersion {
+                text_hash: Some(<Test as frame_system::Config>::Hashing::hash(&new_text)),
+                constitution_version: 2,
+            })
+        );
+
+        // Removed section can be restored
+        AmendConstitutionFixture::default()
+            .with_amendments(vec![SectionAmendment::Add(1, text)])
+            .call_and_assert(Ok(()));
+        assert_eq!(Constitution::constitution().version, 4);
+        assert_eq!(Constitution::constitution_sections(1).version, 4);
+    });
+}
+
+#[test]
+fn amend_contitution_fails_with_invalid_amendments() {
+    build_test_externalities().execute_with(|| {
+        AmendConstitutionFixture::default()
+            .with_amendments(Vec::new())
+            .call_and_assert(Err(Error::<Test>::NoSectionAmendments.into()));
+
+        AmendConstitutionFixture::default()
+            .with_amendments(vec![SectionAmendment::Replace(1, b"text".to_vec())])
+            .call_and_assert(Err(Error::<Test>::SectionDoesNotExist.into()));
+
+        AmendConstitutionFixture::default()
+            .with_amendments(vec![SectionAmendment::Remove(1)])
+            .call_and_assert(Err(Error::<Test>::SectionDoesNotExist.into()));
+
+        AmendConstitutionFixture::default()
+            .with_amendments(vec![
+                SectionAmendment::Add(1, b"text".to_vec()),
+                SectionAmendment::Remove(1),
+            ])
+            .call_and_assert(Err(Error::<Test>::DuplicateSectionAmendment.into()));
+
+        AmendConstitutionFixture::default().call_and_assert(Ok(()));
+
+        AmendConstitutionFixture::default()
+            .call_and_assert(Err(Error::<Test>::SectionAlreadyExists.into()));
     });
 }
 
@@ -106,3 +227,51 @@ fn amend_contitution_fais_with_invalid_origin() {
         amend_constitution_fixture.call_and_assert(Err(DispatchError::BadOrigin));
     });
 }
+
+#[test]
+fn migrate_constitution_carries_over_legacy_text_hash() {
+    build_test_externalities().execute_with(|| {
+        use crate::migrations::{LegacyConstitutionInfo, LEGACY_CONSTITUTION_SECTION_ID};
+        use codec::Encode;
+        use frame_support::storage::{unhashed, StorageValue};
+
+        run_to_block(1);
+        let text_hash = <Test as frame_system::Config>::Hashing::hash(b"Constitution text");
+        unhashed::put_raw(
+            &crate::Constitution::<Test>::hashed_key(),
+            &LegacyConstitutionInfo { text_hash }.encode(),
+        );
+
+        Constitution::migrate_constitution();
+
+        assert_eq!(
+            Constitution::constitution(),
+            ConstitutionInfo {
+                amendment_hash: text_hash,
+                version: 1,
+            }
+        );
+        assert_eq!(
+            Constitution::constitution_versions(1),
+            Some(ConstitutionVersion {
+                amendment_hash: text_hash,
+                amended_at: 1,
+                proposal_id: None,
+            })
+        );
+        assert_eq!(
+            Constitution::constitution_sections(LEGACY_CONSTITUTION_SECTION_ID),
+            ConstitutionSection {
+                text_hash: Some(text_hash),
+                version: 1,
+            }
+        );
+        assert_eq!(
+            Constitution::section_versions(LEGACY_CONSTITUTION_SECTION_ID, 1),
+            Some(SectionVersion {
+                text_hash: Some(text_hash),
+                constitution_version: 1,
+            })
+        );
+    });
+}
@@ -45,24 +45,32 @@ use sp_std::marker::PhantomData;
 
 /// Weight functions needed for pallet_constitution.
 pub trait WeightInfo {
-	fn amend_constitution(_i: u32, ) -> Weight;
+	fn amend_constitution(_i: u32, _s: u32, ) -> Weight;
 }
 
 /// Weights for pallet_constitution using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	// Storage: Constitution Constitution (r:0 w:1)
-	fn amend_constitution(i: u32, ) -> Weight {
-		(31_909_000 as Weight)
+	// Storage: Constitution ConstitutionSections (r:1 w:1)
+	// Storage: Constitution Constitution (r:1 w:1)
+	// Storage: Constitution SectionVersions (r:0 w:1)
+	// Storage: Constitution ConstitutionVersions (r:0 w:1)
+	fn amend_constitution(i: u32, s: u32, ) -> Weight {
+		(34_128_000 as Weight)
 			// Standard Error: 0
 			.saturating_add((2_000 as Weight).saturating_mul(i as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			// Standard Error: 14_000
+			.saturating_add((9_684_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(s as Weight)))
 	}
 }
 
 // Default implementation for tests
 impl WeightInfo for () {
-	fn amend_constitution(i: u32, ) -> Weight {
+	fn amend_constitution(i: u32, s: u32, ) -> Weight {
 		0
 	}
 }
@@ -26,6 +26,7 @@ use project_token::{
 };
 use sp_arithmetic::traits::One;
 use sp_runtime::traits::Hash;
+use sp_runtime::Perbill;
 use sp_runtime::SaturatedConversion;
 use sp_std::{
     cmp::min,
@@ -1652,7 +1653,12 @@ benchmarks! {
                 start_block,
                 tokens_source: curator_member_id,
                 unit_price: DEFAULT_CRT_SALE_PRICE.into(),
-                vesting_schedule_params: Some(default_vesting_schedule_params::<T>())
+                vesting_schedule_params: Some(default_vesting_schedule_params::<T>()),
+                tiers: vec![TokenSaleTier {
+                    unit_price: DEFAULT_CRT_SALE_PRICE.into(),
+                    quantity_left: DEFAULT_CRT_SALE_UPPER_BOUND.into(),
+                    vesting_schedule_params: None,
+                }].try_into().unwrap(),
             }));
             // Verify that owner has max amount of locks possible
             let owner_acc_data = project_token::Module::<T>::account_info_by_token_and_member(token_id, curator_member_id);
@@ -1806,7 +1812,8 @@ benchmarks! {
             assert_eq!(token.revenue_split, RevenueSplitStateOf::<T>::Active(RevenueSplitInfo {
                 allocation,
                 timeline: TimelineOf::<T> { start, duration },
-                dividends_claimed: JoyBalanceOf::<T>::zero()
+                dividends_claimed: JoyBalanceOf::<T>::zero(),
+                eligibility_window: None,
             }));
             // Make sure council budget was increased
             let council_budget_post = T::CouncilBudgetManager::get_budget();
@@ -1860,7 +1867,8 @@ benchmarks! {
             assert_eq!(token.revenue_split, RevenueSplitStateOf::<T>::Active(RevenueSplitInfo {
                 allocation,
                 timeline: TimelineOf::<T> { start, duration },
-                dividends_claimed: JoyBalanceOf::<T>::zero()
+                dividends_claimed: JoyBalanceOf::<T>::zero(),
+                eligibility_window: None,
             }));
             // Make sure channel owner's balances was increased
             let owner_acc_balance_post = balances::Pallet::<T>::usable_balance(owner_acc);
@@ -2000,6 +2008,368 @@ benchmarks! {
             );
         }
 
+    issue_creator_token_poll {
+        let b in 1 .. MAX_KILOBYTES_METADATA;
+        let (channel_id, group_id, lead_acc_id, curator_id, curator_acc_id) =
+            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+        let curator_member_id = curator_member_id::<T>(curator_id);
+        let origin = RawOrigin::Signed(curator_acc_id.clone());
+        let actor = ContentActor::Curator(group_id, curator_id);
+        let token_id =
+            issue_creator_token_with_worst_case_scenario_owner::<T>(
+                curator_acc_id,
+                actor,
+                channel_id,
+                curator_member_id
+            )?;
+        let params = TokenPollParamsOf::<T> {
+            metadata: vec![1u8].repeat((b * 1000) as usize),
+            alternatives: <T as project_token::Config>::MaxPollAlternatives::get(),
+            start: None,
+            duration: T::BlockNumber::one(),
+        };
+        // No pausable feature prevents this
+        set_all_channel_paused_features::<T>(channel_id);
+    }: _(origin, actor, channel_id, params.clone())
+        verify {
+            let poll = project_token::Pallet::<T>::token_poll_by_token_id(token_id).unwrap();
+            assert_eq!(poll.poll_id, 0u32);
+            assert!(!poll.finalized);
+            // Check event emitted
+            assert_last_event::<T>(
+                <T as project_token::Config>::Event::from(
+                    project_token::Event::<T>::TokenPollIssued(
+                        token_id,
+                        0u32,
+                        params
+                    ),
+                ).into(),
+            );
+        }
+
+    issue_creator_token_airdrop {
+        let (channel_id, group_id, lead_acc_id, curator_id, curator_acc_id) =
+            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+        let curator_member_id = curator_member_id::<T>(curator_id);
+        let origin = RawOrigin::Signed(curator_acc_id.clone());
+        let actor = ContentActor::Curator(group_id, curator_id);
+        let token_id =
+            issue_creator_token_with_worst_case_scenario_owner::<T>(
+                curator_acc_id,
+                actor,
+                channel_id,
+                curator_member_id
+            )?;
+        let params = AirdropParamsOf::<T> {
+            commitment: <T as frame_system::Config>::Hashing::hash_of(b"commitment"),
+            total_amount: DEFAULT_CRT_AIRDROP_AMOUNT.into(),
+            expiry: frame_system::Pallet::<T>::block_number()
+                + DEFAULT_CRT_AIRDROP_DURATION.into(),
+        };
+        // No pausable feature prevents this
+        set_all_channel_paused_features::<T>(channel_id);
+    }: _(origin, actor, channel_id, params.clone())
+        verify {
+            let airdrop = project_token::Pallet::<T>::airdrop_by_id(token_id, 0u64).unwrap();
+            assert_eq!(airdrop.amount_left, DEFAULT_CRT_AIRDROP_AMOUNT.into());
+            // Check event emitted
+            assert_last_event::<T>(
+                <T as project_token::Config>::Event::from(
+                    project_token::Event::<T>::AirdropIssued(
+                        token_id,
+                        0u64,
+                        curator_member_id,
+                        params
+                    ),
+                ).into(),
+            );
+        }
+
+    recover_creator_token_airdrop {
+        let (channel_id, group_id, lead_acc_id, curator_id, curator_acc_id) =
+            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+        let curator_member_id = curator_member_id::<T>(curator_id);
+        let origin = RawOrigin::Signed(curator_acc_id.clone());
+        let actor = ContentActor::Curator(group_id, curator_id);
+        let token_id =
+            issue_creator_token_with_worst_case_scenario_owner::<T>(
+                curator_acc_id.clone(),
+                actor,
+                channel_id,
+                curator_member_id
+            )?;
+        Pallet::<T>::issue_creator_token_airdrop(
+            RawOrigin::Signed(curator_acc_id).into(),
+            actor,
+            channel_id,
+            AirdropParamsOf::<T> {
+                commitment: <T as frame_system::Config>::Hashing::hash_of(b"commitment"),
+                total_amount: DEFAULT_CRT_AIRDROP_AMOUNT.into(),
+                expiry: frame_system::Pallet::<T>::block_number()
+                    + DEFAULT_CRT_AIRDROP_DURATION.into(),
+            },
+        )?;
+        fastforward_by_blocks::<T>(DEFAULT_CRT_AIRDROP_DURATION.into());
+        // No pausable feature prevents this
+        set_all_channel_paused_features::<T>(channel_id);
+    }: _(origin, actor, channel_id, 0u64)
+        verify {
+            assert!(project_token::Pallet::<T>::airdrop_by_id(token_id, 0u64).is_none());
+            // Check event emitted
+            assert_last_event::<T>(
+                <T as project_token::Config>::Event::from(
+                    project_token::Event::<T>::UnclaimedAirdropRecovered(
+                        token_id,
+                        0u64,
+                        DEFAULT_CRT_AIRDROP_AMOUNT.into()
+                    ),
+                ).into(),
+            );
+        }
+
+    set_channel_auto_revenue_split {
+        let (channel_id, group_id, lead_acc_id, curator_id, curator_acc_id) =
+            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+        let curator_member_id = curator_member_id::<T>(curator_id);
+        let origin = RawOrigin::Signed(curator_acc_id.clone());
+        let actor = ContentActor::Curator(group_id, curator_id);
+        issue_creator_token_with_worst_case_scenario_owner::<T>(
+            curator_acc_id,
+            actor,
+            channel_id,
+            curator_member_id
+        )?;
+        let split_duration = project_token::Pallet::<T>::min_revenue_split_duration();
+        let params = AutoRevenueSplitParameters::<T> {
+            pool_rate: Perbill::from_percent(50),
+            threshold: Some(1_000_000u32.into()),
+            period: Some(split_duration),
+            split_duration,
+        };
+    }: _(origin, actor, channel_id, Some(params.clone()))
+        verify {
+            let pool = Pallet::<T>::auto_revenue_split_pool(channel_id).unwrap();
+            assert_eq!(pool.params, params);
+            assert_eq!(
+                pool.next_split_at,
+                Some(frame_system::Pallet::<T>::block_number() + split_duration)
+            );
+            // Check event emitted
+            assert_last_event::<T>(
+                <T as Config>::Event::from(
+                    Event::<T>::ChannelAutoRevenueSplitUpdated(
+                        actor,
+                        channel_id,
+                        Some(params)
+                    )
+                ).into()
+            );
+        }
+
+    set_creator_token_frozen_status {
+        let (channel_id, group_id, lead_acc_id, curator_id, curator_acc_id) =
+            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+        let curator_member_id = curator_member_id::<T>(curator_id);
+        let origin = RawOrigin::Signed(curator_acc_id.clone());
+        let actor = ContentActor::Curator(group_id, curator_id);
+        let token_id =
+            issue_creator_token_with_worst_case_scenario_owner::<T>(
+                curator_acc_id,
+                actor,
+                channel_id,
+                curator_member_id
+            )?;
+    }: _(origin, actor, channel_id, Some(curator_member_id), true)
+        verify {
+            assert!(
+                project_token::Pallet::<T>::account_info_by_token_and_member(
+                    token_id,
+                    curator_member_id
+                ).frozen
+            );
+            // Check event emitted
+            assert_last_event::<T>(
+                <T as project_token::Config>::Event::from(
+                    project_token::Event::<T>::AccountFrozenStatusUpdated(
+                        token_id,
+                        curator_member_id,
+                        true
+                    ),
+                ).into(),
+            );
+        }
+
+    set_creator_token_frozen_status_as_council {
+        let (channel_id, group_id, lead_acc_id, curator_id, curator_acc_id) =
+            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+        let curator_member_id = curator_member_id::<T>(curator_id);
+        let actor = ContentActor::Curator(group_id, curator_id);
+        let token_id =
+            issue_creator_token_with_worst_case_scenario_owner::<T>(
+                curator_acc_id,
+                actor,
+                channel_id,
+                curator_member_id
+            )?;
+        let params = CreatorTokenFrozenStatusParameters::<T> {
+            channel_id,
+            member_id: Some(curator_member_id),
+            frozen: true,
+        };
+    }: _(RawOrigin::Root, params)
+        verify {
+            assert!(
+                project_token::Pallet::<T>::account_info_by_token_and_member(
+                    token_id,
+                    curator_member_id
+                ).frozen
+            );
+            // Check event emitted
+            assert_last_event::<T>(
+                <T as project_token::Config>::Event::from(
+                    project_token::Event::<T>::AccountFrozenStatusUpdated(
+                        token_id,
+                        curator_member_id,
+                        true
+                    ),
+                ).into(),
+            );
+        }
+
+    start_creator_token_buyback {
+        let (channel_id, group_id, lead_acc_id, curator_id, curator_acc_id) =
+            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+        let curator_member_id = curator_member_id::<T>(curator_id);
+        let origin = RawOrigin::Signed(curator_acc_id.clone());
+        let actor = ContentActor::Curator(group_id, curator_id);
+        let token_id =
+            issue_creator_token_with_worst_case_scenario_owner::<T>(
+                curator_acc_id,
+                actor,
+                channel_id,
+                curator_member_id
+            )?;
+        let channel_acc = ContentTreasury::<T>::account_for_channel(channel_id);
+        let _ = balances::Pallet::<T>::deposit_creating(&channel_acc, 1_000_000u32.into());
+        let params = BuybackParamsOf::<T> {
+            budget: 500_000u32.into(),
+            max_unit_price: 1_000u32.into(),
+            start: None,
+            duration: 100u32.into(),
+        };
+    }: _(origin, actor, channel_id, params.clone())
+        verify {
+            let program = project_token::Pallet::<T>::buyback_program_by_token_id(token_id)
+                .unwrap();
+            assert_eq!(program.budget, params.budget);
+            // Check event emitted
+            assert_last_event::<T>(
+                <T as project_token::Config>::Event::from(
+                    project_token::Event::<T>::BuybackProgramStarted(token_id, program),
+                ).into(),
+            );
+        }
+
+    set_creator_token_time_weighted_splits {
+        let (channel_id, group_id, lead_acc_id, curator_id, curator_acc_id) =
+            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+        let curator_member_id = curator_member_id::<T>(curator_id);
+        let origin = RawOrigin::Signed(curator_acc_id.clone());
+        let actor = ContentActor::Curator(group_id, curator_id);
+        let token_id =
+            issue_creator_token_with_worst_case_scenario_owner::<T>(
+                curator_acc_id,
+                actor,
+                channel_id,
+                curator_member_id
+            )?;
+        let lookback: T::BlockNumber = 100u32.into();
+    }: _(origin, actor, channel_id, Some(lookback))
+        verify {
+            let settings = project_token::Pallet::<T>::token_info_by_id(token_id)
+                .time_weighted_splits;
+            assert_eq!(settings.as_ref().map(|s| s.lookback), Some(lookback));
+            // Check event emitted
+            assert_last_event::<T>(
+                <T as project_token::Config>::Event::from(
+                    project_token::Event::<T>::TimeWeightedSplitsUpdated(token_id, settings),
+                ).into(),
+            );
+        }
+
+    finalize_creator_token_buyback {
+        let (channel_id, group_id, lead_acc_id, curator_id, curator_acc_id) =
+            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+        let curator_member_id = curator_member_id::<T>(curator_id);
+        let origin = RawOrigin::Signed(curator_acc_id.clone());
+        let actor = ContentActor::Curator(group_id, curator_id);
+        let token_id =
+            issue_creator_token_with_worst_case_scenario_owner::<T>(
+                curator_acc_id,
+                actor,
+                channel_id,
+                curator_member_id
+            )?;
+        let channel_acc = ContentTreasury::<T>::account_for_channel(channel_id);
+        let _ = balances::Pallet::<T>::deposit_creating(&channel_acc, 1_000_000u32.into());
+        let budget: JoyBalanceOf<T> = 500_000u32.into();
+        let duration: T::BlockNumber = 100u32.into();
+        Pallet::<T>::start_creator_token_buyback(
+            origin.clone().into(),
+            actor,
+            channel_id,
+            BuybackParamsOf::<T> {
+                budget,
+                max_unit_price: 1_000u32.into(),
+                start: None,
+                duration,
+            }
+        )?;
+        fastforward_by_blocks::<T>(duration);
+    }: _(origin, actor, channel_id)
+        verify {
+            assert!(project_token::Pallet::<T>::buyback_program_by_token_id(token_id).is_none());
+            // Check event emitted
+            assert_last_event::<T>(
+                <T as project_token::Config>::Event::from(
+                    project_token::Event::<T>::BuybackProgramFinalized(
+                        token_id,
+                        channel_acc,
+                        budget
+                    ),
+                ).into(),
+            );
+        }
+
+    set_creator_token_agent_caps {
+        let (channel_id, group_id, lead_acc_id, curator_id, curator_acc_id) =
+            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+        let curator_member_id = curator_member_id::<T>(curator_id);
+        let origin = RawOrigin::Signed(lead_acc_id);
+        let caps = CreatorTokenAgentCaps::<T> {
+            token_sale_quantity: Some(1_000u32.into()),
+            revenue_split_allocation: Some(1_000u32.into()),
+            issuer_transfer_amount: Some(1_000u32.into()),
+            patronage_credit_amount: Some(1_000u32.into()),
+            min_patronage_rate: Some(YearlyRate(DEFAULT_CRT_PATRONAGE_RATE.0 / 2)),
+        };
+    }: _(origin, channel_id, curator_member_id, Some(caps.clone()))
+        verify {
+            assert_eq!(
+                Pallet::<T>::creator_token_agent_caps(channel_id, curator_member_id),
+                Some(caps.clone())
+            );
+            assert_last_event::<T>(
+                <T as Config>::Event::from(
+                    Event::<T>::CreatorTokenAgentCapsUpdated(
+                        channel_id,
+                        curator_member_id,
+                        Some(caps)
+                    )
+                ).into()
+            );
+        }
+
     claim_creator_token_patronage_credit {
         let (channel_id, owner_member_id, owner_acc, lead_account_id) =
             setup_worst_case_scenario_member_channel_all_max::<T>(false)?;
@@ -3405,6 +3775,116 @@ benchmarks! {
                 new_video_bloat_bond
             );
         }
+
+    // WORST CASE SCENARIO
+    // STATE COMPLEXITY
+    // - curator owned channel
+    // - curator channel agent
+    // INPUT COMPLEXITY
+    // - benefits metadata of max size
+    create_subscription_tier {
+        let b in 1 .. MAX_KILOBYTES_METADATA;
+        let (channel_id, group_id, _, curator_id, curator_account_id) =
+            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+        let origin = RawOrigin::Signed(curator_account_id);
+        let actor = ContentActor::Curator(group_id, curator_id);
+        let params = SubscriptionTierParameters::<T> {
+            price_per_period: subscription_tier_price::<T>(),
+            period_length: SUBSCRIPTION_PERIOD_LENGTH.into(),
+            benefits_metadata: vec![1u8].repeat((b * 1000) as usize),
+        };
+    }: _(origin, actor, channel_id, params.clone())
+        verify {
+            assert!(Pallet::<T>::subscription_tier_by_id(channel_id, 0).is_some());
+            assert_last_event::<T>(
+                <T as Config>::Event::from(
+                    Event::<T>::SubscriptionTierCreated(actor, channel_id, 0, params)
+                ).into()
+            );
+        }
+
+    // WORST CASE SCENARIO
+    // STATE COMPLEXITY
+    // - curator owned channel
+    // - curator channel agent
+    // INPUT COMPLEXITY
+    // -
+    deactivate_subscription_tier {
+        let (channel_id, tier_id, actor, curator_account_id) =
+            setup_channel_subscription_tier::<T>()?;
+        let origin = RawOrigin::Signed(curator_account_id);
+    }: _(origin, actor, channel_id, tier_id)
+        verify {
+            assert!(!Pallet::<T>::subscription_tier_by_id(channel_id, tier_id).unwrap().active);
+        }
+
+    // WORST CASE SCENARIO
+    // STATE COMPLEXITY
+    // - expired subscription with the renewal deposit to refund
+    // INPUT COMPLEXITY
+    // - non-zero renewal deposit
+    subscribe_to_channel {
+        let renewal_deposit = subscription_tier_price::<T>() - One::one();
+        let (channel_id, tier_id, member_id, account_id) =
+            setup_channel_subscription::<T>(renewal_deposit)?;
+        fastforward_by_blocks::<T>(SUBSCRIPTION_PERIOD_LENGTH.into());
+        let origin = RawOrigin::Signed(account_id);
+    }: _(origin, member_id, channel_id, tier_id, 1, renewal_deposit)
+        verify {
+            let subscription = Pallet::<T>::subscription(channel_id, member_id).unwrap();
+            assert_eq!(subscription.subscribed_at, System::<T>::block_number());
+            assert_eq!(subscription.deposit, renewal_deposit);
+        }
+
+    // WORST CASE SCENARIO
+    // STATE COMPLEXITY
+    // - active subscription
+    // INPUT COMPLEXITY
+    // -
+    top_up_channel_subscription {
+        let (channel_id, _, member_id, account_id) =
+            setup_channel_subscription::<T>(Zero::zero())?;
+        let origin = RawOrigin::Signed(account_id);
+        let amount = subscription_tier_price::<T>();
+    }: _(origin, member_id, channel_id, amount)
+        verify {
+            assert_eq!(
+                Pallet::<T>::subscription(channel_id, member_id).unwrap().deposit,
+                amount
+            );
+        }
+
+    // WORST CASE SCENARIO
+    // STATE COMPLEXITY
+    // - subscription period due for renewal
+    // INPUT COMPLEXITY
+    // -
+    renew_channel_subscription {
+        let (channel_id, _, member_id, account_id) =
+            setup_channel_subscription::<T>(subscription_tier_price::<T>())?;
+        fastforward_by_blocks::<T>(SUBSCRIPTION_PERIOD_LENGTH.into());
+        let origin = RawOrigin::Signed(account_id);
+    }: _(origin, channel_id, member_id)
+        verify {
+            assert!(Pallet::<T>::subscription(channel_id, member_id).unwrap().deposit.is_zero());
+        }
+
+    // WORST CASE SCENARIO
+    // STATE COMPLEXITY
+    // - subscription period due for renewal
+    // - remaining renewal deposit to refund
+    // INPUT COMPLEXITY
+    // -
+    cancel_channel_subscription_renewal {
+        let renewal_deposit = subscription_tier_price::<T>() + subscription_tier_price::<T>();
+        let (channel_id, _, member_id, account_id) =
+            setup_channel_subscription::<T>(renewal_deposit)?;
+        fastforward_by_blocks::<T>(SUBSCRIPTION_PERIOD_LENGTH.into());
+        let origin = RawOrigin::Signed(account_id);
+    }: _(origin, member_id, channel_id)
+        verify {
+            assert!(Pallet::<T>::subscription(channel_id, member_id).unwrap().deposit.is_zero());
+        }
 }
 
 #[cfg(test)]
@@ -3937,10 +4417,121 @@ pub mod tests {
         });
     }
 
+    #[test]
+    fn issue_creator_token_poll() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_issue_creator_token_poll());
+        });
+    }
+
+    #[test]
+    fn issue_creator_token_airdrop() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_issue_creator_token_airdrop());
+        });
+    }
+
+    #[test]
+    fn recover_creator_token_airdrop() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_recover_creator_token_airdrop());
+        });
+    }
+
+    #[test]
+    fn set_channel_auto_revenue_split() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_set_channel_auto_revenue_split());
+        });
+    }
+
+    #[test]
+    fn set_creator_token_frozen_status() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_set_creator_token_frozen_status());
+        });
+    }
+
+    #[test]
+    fn set_creator_token_frozen_status_as_council() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_set_creator_token_frozen_status_as_council());
+        });
+    }
+
+    #[test]
+    fn start_creator_token_buyback() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_start_creator_token_buyback());
+        });
+    }
+
+    #[test]
+    fn set_creator_token_time_weighted_splits() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_set_creator_token_time_weighted_splits());
+        });
+    }
+
+    #[test]
+    fn finalize_creator_token_buyback() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_finalize_creator_token_buyback());
+        });
+    }
+
+    #[test]
+    fn set_creator_token_agent_caps() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_set_creator_token_agent_caps());
+        });
+    }
+
     #[test]
     fn claim_creator_token_patronage_credit() {
         with_default_mock_builder(|| {
             assert_ok!(Content::test_benchmark_claim_creator_token_patronage_credit());
         });
     }
+    #[test]
+    fn create_subscription_tier() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_create_subscription_tier());
+        });
+    }
+
+    #[test]
+    fn deactivate_subscription_tier() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_deactivate_subscription_tier());
+        });
+    }
+
+    #[test]
+    fn subscribe_to_channel() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_subscribe_to_channel());
+        });
+    }
+
+    #[test]
+    fn top_up_channel_subscription() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_top_up_channel_subscription());
+        });
+    }
+
+    #[test]
+    fn renew_channel_subscription() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_renew_channel_subscription());
+        });
+    }
+
+    #[test]
+    fn cancel_channel_subscription_renewal() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_cancel_channel_subscription_renewal());
+        });
+    }
 }
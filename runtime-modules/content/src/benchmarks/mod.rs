@@ -76,6 +76,7 @@ const STORAGE_WG_LEADER_ACCOUNT_ID: u64 = 100001; // must match the mocks
 const CONTENT_WG_LEADER_ACCOUNT_ID: u64 = 100005; // must match the mocks LEAD_ACCOUNT_ID
 const DISTRIBUTION_WG_LEADER_ACCOUNT_ID: u64 = 100004; // must match the mocks
 const MAX_KILOBYTES_METADATA: u32 = 100;
+const SUBSCRIPTION_PERIOD_LENGTH: u32 = 10;
 
 // Creator tokens
 const MAX_CRT_INITIAL_ALLOCATION_MEMBERS: u32 = 1024;
@@ -86,8 +87,10 @@ const DEFAULT_CRT_SALE_PRICE: u32 = 500_000_000;
 const DEFAULT_CRT_SALE_UPPER_BOUND: u32 = DEFAULT_CRT_OWNER_ISSUANCE;
 const DEFAULT_CRT_REVENUE_SPLIT_RATE: Permill = Permill::from_percent(50);
 const DEFAULT_CRT_PATRONAGE_RATE: YearlyRate = YearlyRate(Permill::from_percent(10));
+const DEFAULT_CRT_AIRDROP_AMOUNT: u32 = 1_000_000;
+const DEFAULT_CRT_AIRDROP_DURATION: u32 = 100;
 
-const CHANNEL_AGENT_PERMISSIONS: [ChannelActionPermission; 21] = [
+const CHANNEL_AGENT_PERMISSIONS: [ChannelActionPermission; 25] = [
     ChannelActionPermission::UpdateChannelMetadata,
     ChannelActionPermission::ManageNonVideoChannelAssets,
     ChannelActionPermission::ManageChannelCollaborators,
@@ -109,6 +112,10 @@ const CHANNEL_AGENT_PERMISSIONS: [ChannelActionPermission; 21] = [
     ChannelActionPermission::ReduceCreatorTokenPatronageRate,
     ChannelActionPermission::ManageRevenueSplits,
     ChannelActionPermission::DeissueCreatorToken,
+    ChannelActionPermission::ManageSubscriptionTiers,
+    ChannelActionPermission::ManageCreatorTokenPolls,
+    ChannelActionPermission::ManageCreatorTokenCompliance,
+    ChannelActionPermission::ManageCreatorTokenBuyback,
 ];
 
 const CONTENT_MODERATION_ACTIONS: [ContentModerationAction; 15] = [
@@ -929,6 +936,24 @@ where
     Ok(group_id)
 }
 
+type SubscriptionTierData<T> = (
+    <T as storage::Config>::ChannelId, // channel id
+    SubscriptionTierId,                // subscription tier id
+    ContentActor<
+        <T as ContentActorAuthenticator>::CuratorGroupId,
+        <T as ContentActorAuthenticator>::CuratorId,
+        <T as MembershipTypes>::MemberId,
+    >, // curator actor
+    <T as frame_system::Config>::AccountId, // curator account id
+);
+
+type SubscriptionData<T> = (
+    <T as storage::Config>::ChannelId,      // channel id
+    SubscriptionTierId,                     // subscription tier id
+    <T as MembershipTypes>::MemberId,       // subscriber member id
+    <T as frame_system::Config>::AccountId, // subscriber account id
+);
+
 type CuratorChannelData<T> = (
     <T as storage::Config>::ChannelId,                // channel id
     <T as ContentActorAuthenticator>::CuratorGroupId, // curator group id
@@ -1359,6 +1384,7 @@ fn worst_case_scenario_token_sale_params<T: Config>(
         upper_bound_quantity: DEFAULT_CRT_SALE_UPPER_BOUND.into(),
         vesting_schedule_params: Some(default_vesting_schedule_params::<T>()),
         metadata: Some(vec![0xf].repeat((metatada_kb * 1000) as usize)),
+        tiers: vec![],
     }
 }
 
@@ -1676,3 +1702,53 @@ where
 {
     set_all_channel_paused_features_except::<T>(channel_id, vec![]);
 }
+
+fn subscription_tier_price<T: Config>() -> BalanceOf<T> {
+    <T as balances::Config>::ExistentialDeposit::get() + 100u32.into()
+}
+
+fn setup_channel_subscription_tier<T>() -> Result<SubscriptionTierData<T>, DispatchError>
+where
+    T: RuntimeConfig,
+    T::AccountId: CreateAccountId,
+{
+    let (channel_id, group_id, _, curator_id, curator_account_id) =
+        setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+    let tier_id = Pallet::<T>::next_subscription_tier_id(channel_id);
+    let actor = ContentActor::Curator(group_id, curator_id);
+
+    Pallet::<T>::create_subscription_tier(
+        RawOrigin::Signed(curator_account_id.clone()).into(),
+        actor,
+        channel_id,
+        SubscriptionTierParameters::<T> {
+            price_per_period: subscription_tier_price::<T>(),
+            period_length: SUBSCRIPTION_PERIOD_LENGTH.into(),
+            benefits_metadata: Vec::new(),
+        },
+    )?;
+
+    Ok((channel_id, tier_id, actor, curator_account_id))
+}
+
+fn setup_channel_subscription<T>(
+    renewal_deposit: BalanceOf<T>,
+) -> Result<SubscriptionData<T>, DispatchError>
+where
+    T: RuntimeConfig,
+    T::AccountId: CreateAccountId,
+{
+    let (channel_id, tier_id, _, _) = setup_channel_subscription_tier::<T>()?;
+    let (account_id, member_id) = member_funded_account::<T>();
+
+    Pallet::<T>::subscribe_to_channel(
+        RawOrigin::Signed(account_id.clone()).into(),
+        member_id,
+        channel_id,
+        tier_id,
+        1,
+        renewal_deposit,
+    )?;
+
+    Ok((channel_id, tier_id, member_id, account_id))
+}
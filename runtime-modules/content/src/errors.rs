@@ -409,5 +409,72 @@ decl_error! {
 
         /// Channel Transfers are blocked during token sales
         ChannelTransfersBlockedDuringTokenSales,
+
+        // Channel subscriptions
+        // ---------------------
+
+        /// Subscription tier price per period cannot be zero
+        InvalidSubscriptionTierPrice,
+
+        /// Subscription period length cannot be zero
+        InvalidSubscriptionPeriodLength,
+
+        /// Subscription tier doesn't exist
+        SubscriptionTierDoesNotExist,
+
+        /// Subscription tier doesn't accept new subscriptions
+        SubscriptionTierNotActive,
+
+        /// At least one subscription period must be paid
+        InvalidNumberOfSubscriptionPeriods,
+
+        /// Member is already subscribed to the channel
+        AlreadySubscribedToChannel,
+
+        /// Member is not subscribed to the channel
+        SubscriptionDoesNotExist,
+
+        /// Subscription has expired
+        SubscriptionExpired,
+
+        /// No subscription periods are due for renewal
+        SubscriptionRenewalNotDue,
+
+        /// Insufficient balance to pay for the subscription
+        InsufficientBalanceForSubscription,
+
+        /// Automatic revenue split pool rate cannot be zero
+        AutoRevenueSplitPoolRateIsZero,
+
+        /// Either automatic revenue split threshold or period must be provided
+        AutoRevenueSplitTriggerNotProvided,
+
+        /// Automatic revenue split threshold cannot be zero
+        AutoRevenueSplitThresholdIsZero,
+
+        /// Automatic revenue split period cannot be zero
+        AutoRevenueSplitPeriodIsZero,
+
+        /// Creator token agent caps can only be set for channel agents
+        CreatorTokenAgentCapsTargetIsChannelOwner,
+
+        /// Channel agent creator token sale cap exceeded
+        ChannelAgentTokenSaleCapExceeded,
+
+        /// Channel agent revenue split allocation cap exceeded
+        ChannelAgentRevenueSplitCapExceeded,
+
+        /// Channel agent creator token issuer transfer cap exceeded
+        ChannelAgentIssuerTransferCapExceeded,
+
+        /// Channel agent patronage credit claim cap exceeded
+        ChannelAgentPatronageCreditCapExceeded,
+
+        /// Target patronage rate is below the minimum allowed for the channel agent
+        ChannelAgentPatronageRateBelowMin,
+
+        /// Creator token account (or the whole token) was frozen by the council and cannot be
+        /// unfrozen by the channel
+        CreatorTokenFrozenByCouncil,
     }
 }
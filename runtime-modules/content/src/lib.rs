@@ -29,14 +29,16 @@ mod benchmarks;
 mod errors;
 mod nft;
 mod permissions;
+pub mod runtime_api;
 mod types;
 pub mod weights;
 
 use core::marker::PhantomData;
 use project_token::traits::PalletToken;
 use project_token::types::{
-    JoyBalanceOf, TokenIssuanceParametersOf, TokenSaleParamsOf, TransfersWithVestingOf,
-    UploadContextOf, YearlyRate,
+    AirdropId, AirdropParamsOf, AllowanceOf, BuybackParamsOf, JoyBalanceOf, TokenBalanceOf,
+    TokenIssuanceParametersOf, TokenPollParamsOf, TokenSaleParamsOf, TransfersOf,
+    TransfersWithVestingOf, UploadContextOf, YearlyRate,
 };
 use sp_std::vec;
 pub use weights::WeightInfo;
@@ -61,7 +63,7 @@ pub use common::{
         pay_fee,
     },
     council::CouncilBudgetManager,
-    membership::MembershipInfoProvider,
+    membership::{MemberReputationHandler, MembershipInfoProvider, ReputationActivity},
     merkle_tree::Side,
     to_kb,
     working_group::{WorkingGroup, WorkingGroupBudgetHandler},
@@ -72,7 +74,7 @@ use frame_support::{
     dispatch::{DispatchError, DispatchResult},
     ensure,
     traits::{Currency, ExistenceRequirement, Get},
-    PalletId, Parameter,
+    BoundedVec, PalletId, Parameter,
 };
 
 use sp_std::convert::TryInto;
@@ -83,7 +85,7 @@ use frame_system::{ensure_root, ensure_signed};
 use serde::{Deserialize, Serialize};
 use sp_arithmetic::{
     traits::{BaseArithmetic, One, Saturating, Zero},
-    Perbill,
+    Perbill, Permill,
 };
 use sp_runtime::traits::{AccountIdConversion, Hash, MaybeSerializeDeserialize, Member};
 use sp_std::{borrow::ToOwned, collections::btree_set::BTreeSet, vec::Vec};
@@ -127,6 +129,9 @@ pub trait Config:
     /// Type in order to retrieve controller account from channel member owner
     type MemberAuthenticator: MembershipInfoProvider<Self>;
 
+    /// Updates channel owner reputation on moderation actions
+    type MemberReputationHandler: MemberReputationHandler<Self>;
+
     /// Max number of keys per curator_group.permissions_by_level map instance
     type MaxKeysPerCuratorGroupPermissionsByLevelMap: Get<MaxNumber>;
 
@@ -185,6 +190,9 @@ pub trait Config:
         TokenSaleParamsOf<Self>,
         UploadContextOf<Self>,
         TransfersWithVestingOf<Self>,
+        AirdropParamsOf<Self>,
+        AllowanceOf<Self>,
+        TransfersOf<Self>,
     >;
 
     /// Minimum cashout allowed limit
@@ -192,6 +200,9 @@ pub trait Config:
 
     /// Max cashout allowed limit
     type MaximumCashoutAllowedLimit: Get<BalanceOf<Self>>;
+
+    /// Max number of automatic revenue splits scheduled to be issued at the same block
+    type MaxAutoRevenueSplitsPerBlock: Get<u32>;
 }
 
 decl_storage! { generate_storage_info
@@ -295,6 +306,38 @@ decl_storage! { generate_storage_info
         /// Can be updated in flight by the Council
         pub NftLimitsEnabled get(fn nft_limits_enabled) config(): bool;
 
+        /// Subscription tier id to assign to the next tier created in the channel.
+        pub NextSubscriptionTierId get(fn next_subscription_tier_id):
+        map hasher(blake2_128_concat) T::ChannelId => SubscriptionTierId;
+
+        /// Channel subscription tiers.
+        pub SubscriptionTierById get(fn subscription_tier_by_id):
+        double_map hasher(blake2_128_concat) T::ChannelId,
+        hasher(blake2_128_concat) SubscriptionTierId => Option<SubscriptionTier<T>>;
+
+        /// Member subscriptions to the channels.
+        pub SubscriptionByChannelAndMember get(fn subscription):
+        double_map hasher(blake2_128_concat) T::ChannelId,
+        hasher(blake2_128_concat) T::MemberId => Option<Subscription<T>>;
+
+        /// Channel pools of the earnings pending distribution in automatic revenue splits.
+        pub AutoRevenueSplitPoolByChannelId get(fn auto_revenue_split_pool):
+        map hasher(blake2_128_concat) T::ChannelId => Option<AutoRevenueSplitPool<T>>;
+
+        /// Channels with automatic revenue splits due at a given block.
+        pub AutoRevenueSplitsDueAt get(fn auto_revenue_splits_due_at):
+        map hasher(blake2_128_concat) T::BlockNumber =>
+            BoundedVec<T::ChannelId, T::MaxAutoRevenueSplitsPerBlock>;
+
+        /// Caps on the creator token issuer actions performed by the channel agents.
+        pub CreatorTokenAgentCapsByChannelAndMember get(fn creator_token_agent_caps):
+        double_map hasher(blake2_128_concat) T::ChannelId,
+        hasher(blake2_128_concat) T::MemberId => Option<CreatorTokenAgentCaps<T>>;
+
+        /// Creator token accounts (or whole tokens if the member is `None`) frozen by the council.
+        pub CreatorTokenFrozenByCouncil get(fn creator_token_frozen_by_council):
+        double_map hasher(blake2_128_concat) T::ChannelId,
+        hasher(blake2_128_concat) Option<T::MemberId> => bool;
     }
     add_extra_genesis {
         build(|_| {
@@ -343,6 +386,42 @@ decl_module! {
         const DefaultChannelWeeklyNftLimit: LimitPerPeriod<T::BlockNumber> =
             T::DefaultGlobalDailyNftLimit::get();
 
+        /// Exports const - max number of automatic revenue splits scheduled at the same block.
+        const MaxAutoRevenueSplitsPerBlock: u32 = T::MaxAutoRevenueSplitsPerBlock::get();
+
+        /// Issue the automatic revenue splits due at the current block (periodic splits and
+        /// splits which reached the threshold in the previous block).
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (S)` where:
+        /// - `S` is the number of automatic revenue splits due at the current block
+        /// - DB:
+        ///    - O(S)
+        /// # </weight>
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            let due_channels = AutoRevenueSplitsDueAt::<T>::take(now);
+            let processed_pools = due_channels.len() as Weight;
+            let issued_splits = due_channels
+                .into_iter()
+                .filter(|channel_id| Self::process_due_auto_revenue_split(*channel_id, now))
+                .count();
+
+            // due splits (r:1 w:1), each processed pool (r:1 w:1, previous split finalization),
+            // each issued split
+            T::DbWeight::get()
+                .reads_writes(processed_pools.saturating_add(1), processed_pools.saturating_add(1))
+                .saturating_add(
+                    WeightInfoContent::<T>::finalize_revenue_split()
+                        .saturating_mul(processed_pools)
+                )
+                .saturating_add(
+                    WeightInfoContent::<T>::issue_revenue_split()
+                        .saturating_mul(issued_splits as Weight)
+                )
+        }
+
         // ======
         // Next set of extrinsics can only be invoked by lead.
         // ======
@@ -960,6 +1039,8 @@ decl_module! {
                 channel.data_objects = updated_assets;
             });
 
+            Self::record_moderation_action(&channel.owner);
+
             // emit the event
             Self::deposit_event(RawEvent::ChannelAssetsDeletedByModerator(actor, channel_id, assets_to_remove, rationale));
         }
@@ -993,6 +1074,8 @@ decl_module! {
             // ensure channel bag exists and num_objects_to_delete is valid
             Self::ensure_channel_bag_can_be_dropped(channel_id, num_objects_to_delete)?;
 
+            let channel_owner = channel.owner.clone();
+
             // try to remove the channel, slash the bloat bond
             Self::try_to_perform_channel_deletion(&sender, channel_id, channel, true)?;
 
@@ -1000,6 +1083,8 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
+            Self::record_moderation_action(&channel_owner);
+
             // deposit event
             Self::deposit_event(RawEvent::ChannelDeletedByModerator(actor, channel_id, rationale));
 
@@ -1037,6 +1122,10 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
+            if is_hidden {
+                Self::record_moderation_action(&channel.owner);
+            }
+
             // deposit event
             Self::deposit_event(RawEvent::ChannelVisibilitySetByModerator(actor, channel_id, is_hidden, rationale));
 
@@ -1424,6 +1513,8 @@ decl_module! {
                 video.data_objects = updated_assets;
             });
 
+            Self::record_moderation_action(&channel.owner);
+
             // emit the event
             Self::deposit_event(RawEvent::VideoAssetsDeletedByModerator(actor, video_id, assets_to_remove, is_nft, rationale));
         }
@@ -1474,6 +1565,8 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
+            Self::record_moderation_action(&channel.owner);
+
             Self::deposit_event(RawEvent::VideoDeletedByModerator(actor, video_id, rationale));
         }
 
@@ -1512,6 +1605,10 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
+            if is_hidden {
+                Self::record_moderation_action(&channel.owner);
+            }
+
             // deposit event
             Self::deposit_event(RawEvent::VideoVisibilitySetByModerator(actor, video_id, is_hidden, rationale));
 
@@ -1611,7 +1708,7 @@ decl_module! {
             // == MUTATION_SAFE ==
             //
 
-            Self::execute_channel_reward_claim(item.channel_id, &reward_account, amount);
+            let _ = Self::execute_channel_reward_claim(item.channel_id, &reward_account, amount);
 
             Self::deposit_event(
                 RawEvent::ChannelRewardUpdated(item.cumulative_reward_earned, item.channel_id)
@@ -1653,7 +1750,8 @@ decl_module! {
             );
 
             ensure!(
-                Self::channel_account_withdrawable_balance(&reward_account, &channel) >= amount,
+                Self::channel_account_withdrawable_balance(channel_id, &reward_account, &channel)
+                    >= amount,
                 Error::<T>::WithdrawalAmountExceedsChannelAccountWithdrawableBalance
             );
 
@@ -1775,14 +1873,21 @@ decl_module! {
             //
             // == MUTATION_SAFE ==
             //
-            Self::execute_channel_reward_claim(item.channel_id, &reward_account, amount);
+            let routed_amount =
+                Self::execute_channel_reward_claim(item.channel_id, &reward_account, amount);
+            // Amount routed to the automatic revenue split pool stays in the channel account
+            let withdrawn_amount = amount.saturating_sub(routed_amount);
             // This call should (and is assumed to) never fail:
-            Self::execute_channel_balance_withdrawal(&reward_account, &destination, amount)?;
+            Self::execute_channel_balance_withdrawal(
+                &reward_account,
+                &destination,
+                withdrawn_amount,
+            )?;
 
             Self::deposit_event(RawEvent::ChannelRewardClaimedAndWithdrawn(
                 actor,
                 item.channel_id,
-                amount,
+                withdrawn_amount,
                 destination,
             ));
 
@@ -2820,6 +2925,7 @@ decl_module! {
             let royalty_payment = Self::build_royalty_payment(&video, nft.creator_royalty);
             let nft = Self::complete_nft_offer(
                 nft,
+                video.in_channel,
                 royalty_payment,
                 nft_owner_account,
                 receiver_account_id
@@ -2927,6 +3033,7 @@ decl_module! {
             let royalty_payment = Self::build_royalty_payment(&video, nft.creator_royalty);
             let nft = Self::buy_now(
                 nft,
+                video.in_channel,
                 royalty_payment,
                 old_nft_owner_account_id,
                 participant_account_id,
@@ -3168,6 +3275,9 @@ decl_module! {
                 channel.collaborators = new_collaborators;
             });
 
+            // Agent caps set by the previous owner no longer apply
+            CreatorTokenAgentCapsByChannelAndMember::<T>::remove_prefix(channel_id, None);
+
             Self::deposit_event(
                 RawEvent::ChannelTransferAccepted(channel_id, commitment_params)
             );
@@ -3318,11 +3428,12 @@ decl_module! {
             channel.ensure_has_no_active_transfer::<T>()?;
 
             // Permissions check
-            let (sender, _) = ensure_actor_authorized_to_init_and_manage_creator_token_sale::<T>(
-                origin,
-                &actor,
-                &channel
-            )?;
+            let (sender, permissions) =
+                ensure_actor_authorized_to_init_and_manage_creator_token_sale::<T>(
+                    origin,
+                    &actor,
+                    &channel
+                )?;
 
             // Retrieve member_id based on actor
             let member_id = get_member_id_of_actor::<T>(&actor)?;
@@ -3339,6 +3450,14 @@ decl_module! {
             // Auto-finalize the sale only if channel owner is a member
             let auto_finalize = matches!(channel.owner, ChannelOwner::Member { .. });
 
+            // Ensure the tokens on sale are within the channel agent caps
+            let quantity = params.total_quantity();
+            let agent_caps =
+                Self::channel_agent_creator_token_caps(channel_id, &actor, &permissions)?;
+            if let Some((_, ref caps)) = agent_caps {
+                caps.ensure_token_sale_quantity_within_cap::<T>(quantity)?;
+            }
+
             // Call to ProjectToken - should be the first call before MUTATION SAFE!
             T::ProjectToken::init_token_sale(
                 token_id,
@@ -3347,6 +3466,19 @@ decl_module! {
                 auto_finalize,
                 params
             )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if let Some((agent_member_id, mut caps)) = agent_caps {
+                caps.consume_token_sale_quantity(quantity);
+                CreatorTokenAgentCapsByChannelAndMember::<T>::insert(
+                    channel_id,
+                    agent_member_id,
+                    caps
+                );
+            }
         }
 
         /// Update upcoming creator token sale
@@ -3427,11 +3559,12 @@ decl_module! {
             channel.ensure_has_no_active_transfer::<T>()?;
 
             // Permissions check
-            let sender = ensure_actor_authorized_to_perform_creator_token_issuer_transfer::<T>(
-                origin,
-                &actor,
-                &channel
-            )?;
+            let (sender, permissions) =
+                ensure_actor_authorized_to_perform_creator_token_issuer_transfer::<T>(
+                    origin,
+                    &actor,
+                    &channel
+                )?;
 
             // Ensure token was issued
             let token_id = channel.ensure_creator_token_issued::<T>()?;
@@ -3439,6 +3572,19 @@ decl_module! {
             // Retrieve member_id based on actor
             let member_id = get_member_id_of_actor::<T>(&actor)?;
 
+            // Ensure the transferred amount is within the channel agent caps
+            let amount = outputs
+                .0
+                .values()
+                .fold(TokenBalanceOf::<T>::zero(), |acc, payment| {
+                    acc.saturating_add(payment.amount)
+                });
+            let agent_caps =
+                Self::channel_agent_creator_token_caps(channel_id, &actor, &permissions)?;
+            if let Some((_, ref caps)) = agent_caps {
+                caps.ensure_issuer_transfer_amount_within_cap::<T>(amount)?;
+            }
+
             // Call to ProjectToken - should be the first call before MUTATION SAFE!
             T::ProjectToken::issuer_transfer(
                 token_id,
@@ -3447,6 +3593,19 @@ decl_module! {
                 outputs,
                 metadata
             )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if let Some((agent_member_id, mut caps)) = agent_caps {
+                caps.consume_issuer_transfer_amount(amount);
+                CreatorTokenAgentCapsByChannelAndMember::<T>::insert(
+                    channel_id,
+                    agent_member_id,
+                    caps
+                );
+            }
         }
 
 
@@ -3504,7 +3663,7 @@ decl_module! {
             channel.ensure_has_no_active_transfer::<T>()?;
 
             // Permissions check
-            ensure_actor_authorized_to_reduce_creator_token_patronage_rate::<T>(
+            let (_, permissions) = ensure_actor_authorized_to_reduce_creator_token_patronage_rate::<T>(
                 origin,
                 &actor,
                 &channel
@@ -3513,6 +3672,13 @@ decl_module! {
             // Ensure token was issued
             let token_id = channel.ensure_creator_token_issued::<T>()?;
 
+            // Ensure the target rate is within the channel agent caps
+            if let Some((_, caps)) =
+                Self::channel_agent_creator_token_caps(channel_id, &actor, &permissions)?
+            {
+                caps.ensure_patronage_rate_within_cap::<T>(target_rate)?;
+            }
+
             // Call to ProjectToken - should be the first call before MUTATION SAFE!
             T::ProjectToken::reduce_patronage_rate_to(token_id, target_rate)?;
         }
@@ -3537,7 +3703,7 @@ decl_module! {
             channel.ensure_has_no_active_transfer::<T>()?;
 
             // Permissions check
-            ensure_actor_authorized_to_claim_creator_token_patronage::<T>(
+            let (_, permissions) = ensure_actor_authorized_to_claim_creator_token_patronage::<T>(
                 origin,
                 &actor,
                 &channel
@@ -3555,11 +3721,33 @@ decl_module! {
             // Retrieve member_id based on actor
             let member_id = get_member_id_of_actor::<T>(&actor)?;
 
+            // Ensure the claimed credit is within the channel agent caps
+            let amount = project_token::Module::<T>::token_info_by_id(token_id)
+                .unclaimed_patronage_at_block(<frame_system::Pallet<T>>::block_number());
+            let agent_caps =
+                Self::channel_agent_creator_token_caps(channel_id, &actor, &permissions)?;
+            if let Some((_, ref caps)) = agent_caps {
+                caps.ensure_patronage_credit_amount_within_cap::<T>(amount)?;
+            }
+
             // Call to ProjectToken - should be the first call before MUTATION SAFE!
             T::ProjectToken::claim_patronage_credit(
                 token_id,
                 member_id
             )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if let Some((agent_member_id, mut caps)) = agent_caps {
+                caps.consume_patronage_credit_amount(amount);
+                CreatorTokenAgentCapsByChannelAndMember::<T>::insert(
+                    channel_id,
+                    agent_member_id,
+                    caps
+                );
+            }
         }
 
         /// Issue revenue split for a channel
@@ -3584,7 +3772,7 @@ decl_module! {
             channel.ensure_has_no_active_transfer::<T>()?;
 
             // Permissions check
-            ensure_actor_authorized_to_manage_revenue_splits::<T>(
+            let (_, permissions) = ensure_actor_authorized_to_manage_revenue_splits::<T>(
                 origin,
                 &actor,
                 &channel
@@ -3596,11 +3784,21 @@ decl_module! {
             // Get channel's reward account and its balance
             let reward_account = ContentTreasury::<T>::account_for_channel(channel_id);
             let withdrawable_balance =
-                Self::channel_account_withdrawable_balance(&reward_account, &channel);
+                Self::channel_account_withdrawable_balance(channel_id, &reward_account, &channel);
 
             // Get leftover funds destination
             let leftover_destination = Self::channel_funds_destination(&channel)?;
 
+            // Ensure the split allocation is within the channel agent caps
+            let allocation = project_token::Module::<T>::token_info_by_id(token_id)
+                .revenue_split_rate
+                .mul_floor(withdrawable_balance);
+            let agent_caps =
+                Self::channel_agent_creator_token_caps(channel_id, &actor, &permissions)?;
+            if let Some((_, ref caps)) = agent_caps {
+                caps.ensure_revenue_split_allocation_within_cap::<T>(allocation)?;
+            }
+
             // Call to ProjectToken - should be the first call before MUTATION SAFE!
             let leftover_amount = T::ProjectToken::issue_revenue_split(
                 token_id,
@@ -3611,7 +3809,17 @@ decl_module! {
             )?;
 
 
-            Self::execute_channel_balance_withdrawal(&reward_account, &leftover_destination, leftover_amount)?
+
+            Self::execute_channel_balance_withdrawal(&reward_account, &leftover_destination, leftover_amount)?;
+
+            if let Some((agent_member_id, mut caps)) = agent_caps {
+                caps.consume_revenue_split_allocation(allocation);
+                CreatorTokenAgentCapsByChannelAndMember::<T>::insert(
+                    channel_id,
+                    agent_member_id,
+                    caps
+                );
+            }
         }
 
         /// Finalize an ended revenue split
@@ -3653,6 +3861,171 @@ decl_module! {
             )?;
         }
 
+        /// Enable time-weighted revenue splits for the channel creator token, with dividends
+        /// based on the holders' time-weighted average balances over `lookback` blocks
+        /// preceding the split issuance (or disable them if `lookback` is `None`)
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::set_creator_token_time_weighted_splits()]
+        pub fn set_creator_token_time_weighted_splits(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            channel_id: T::ChannelId,
+            lookback: Option<T::BlockNumber>,
+        ) {
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            channel.ensure_has_no_active_transfer::<T>()?;
+
+            // Permissions check
+            ensure_actor_authorized_to_manage_revenue_splits::<T>(
+                origin,
+                &actor,
+                &channel
+            )?;
+
+            // Ensure token was issued
+            let token_id = channel.ensure_creator_token_issued::<T>()?;
+
+            // Call to ProjectToken - should be the first call before MUTATION SAFE!
+            T::ProjectToken::set_time_weighted_revenue_splits(token_id, lookback)?;
+        }
+
+        /// Issue a creator token holder poll for a channel
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (B)` where:
+        /// - `B` is the size of the poll metadata in kilobytes
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::issue_creator_token_poll(to_kb(params.metadata.len() as u32))]
+        pub fn issue_creator_token_poll(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            channel_id: T::ChannelId,
+            params: TokenPollParamsOf<T>,
+        ) {
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            channel.ensure_has_no_active_transfer::<T>()?;
+
+            // Permissions check
+            ensure_actor_authorized_to_manage_creator_token_polls::<T>(
+                origin,
+                &actor,
+                &channel
+            )?;
+
+            // Ensure token was issued
+            let token_id = channel.ensure_creator_token_issued::<T>()?;
+
+            // Call to ProjectToken - should be the first call before MUTATION SAFE!
+            T::ProjectToken::issue_poll(token_id, params)?;
+        }
+
+        /// Issue a creator token airdrop funded from the actor's member token account
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::issue_creator_token_airdrop()]
+        pub fn issue_creator_token_airdrop(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            channel_id: T::ChannelId,
+            params: AirdropParamsOf<T>,
+        ) {
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            channel.ensure_has_no_active_transfer::<T>()?;
+
+            // Permissions check
+            let (_, permissions) =
+                ensure_actor_authorized_to_perform_creator_token_issuer_transfer::<T>(
+                    origin,
+                    &actor,
+                    &channel
+                )?;
+
+            // Ensure token was issued
+            let token_id = channel.ensure_creator_token_issued::<T>()?;
+
+            // Retrieve member_id based on actor
+            let member_id = get_member_id_of_actor::<T>(&actor)?;
+
+            // Ensure the airdropped amount is within the channel agent caps
+            let amount = params.total_amount;
+            let agent_caps =
+                Self::channel_agent_creator_token_caps(channel_id, &actor, &permissions)?;
+            if let Some((_, ref caps)) = agent_caps {
+                caps.ensure_issuer_transfer_amount_within_cap::<T>(amount)?;
+            }
+
+            // Call to ProjectToken - should be the first call before MUTATION SAFE!
+            T::ProjectToken::issue_airdrop(token_id, member_id, params)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if let Some((agent_member_id, mut caps)) = agent_caps {
+                caps.consume_issuer_transfer_amount(amount);
+                CreatorTokenAgentCapsByChannelAndMember::<T>::insert(
+                    channel_id,
+                    agent_member_id,
+                    caps
+                );
+            }
+        }
+
+        /// Recover the unclaimed tokens of an expired creator token airdrop
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::recover_creator_token_airdrop()]
+        pub fn recover_creator_token_airdrop(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            channel_id: T::ChannelId,
+            airdrop_id: AirdropId,
+        ) {
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            channel.ensure_has_no_active_transfer::<T>()?;
+
+            // Permissions check
+            let (sender, _) =
+                ensure_actor_authorized_to_perform_creator_token_issuer_transfer::<T>(
+                    origin,
+                    &actor,
+                    &channel
+                )?;
+
+            // Ensure token was issued
+            let token_id = channel.ensure_creator_token_issued::<T>()?;
+
+            // Call to ProjectToken - should be the first call before MUTATION SAFE!
+            T::ProjectToken::recover_unclaimed_airdrop(token_id, airdrop_id, sender)?;
+        }
+
         /// Finalize an ended creator token sale
         ///
         /// <weight>
@@ -3748,58 +4121,801 @@ decl_module! {
             ChannelById::<T>::mutate(&channel_id, |channel| {
                 channel.creator_token_id = None;
             });
+
+            AutoRevenueSplitPoolByChannelId::<T>::remove(channel_id);
+            CreatorTokenFrozenByCouncil::<T>::remove_prefix(channel_id, None);
         }
-    }
-}
 
-impl<T: Config> Module<T> {
-    /// Ensure `CuratorGroup` under given id exists
-    fn ensure_curator_group_under_given_id_exists(
-        curator_group_id: &T::CuratorGroupId,
-    ) -> Result<(), Error<T>> {
-        ensure!(
-            <CuratorGroupById<T>>::contains_key(curator_group_id),
-            Error::<T>::CuratorGroupDoesNotExist
-        );
-        Ok(())
-    }
+        /// Create channel subscription tier
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (B)` where:
+        /// - `B` is the byte lenght of the benefits metadata
+        /// - DB:
+        ///    - O(1)
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::create_subscription_tier(
+            params.benefits_metadata.len() as u32
+        )]
+        pub fn create_subscription_tier(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            channel_id: T::ChannelId,
+            params: SubscriptionTierParameters<T>,
+        ) {
+            let channel = Self::ensure_channel_exists(&channel_id)?;
 
-    /// Ensure `CuratorGroup` under given id exists, return corresponding one
-    fn ensure_curator_group_exists(
-        curator_group_id: &T::CuratorGroupId,
-    ) -> Result<CuratorGroup<T>, Error<T>> {
-        Self::ensure_curator_group_under_given_id_exists(curator_group_id)?;
-        Ok(Self::curator_group_by_id(curator_group_id))
-    }
+            ensure_actor_authorized_to_manage_subscription_tiers::<T>(origin, &actor, &channel)?;
 
-    // Ensure given video has no associated nft
-    fn ensure_video_can_be_removed(video: &Video<T>) -> DispatchResult {
-        // Ensure nft for this video have not been issued
-        video.ensure_nft_is_not_issued::<T>()?;
-        Ok(())
-    }
+            ensure!(
+                !params.price_per_period.is_zero(),
+                Error::<T>::InvalidSubscriptionTierPrice
+            );
 
-    fn ensure_video_exists(video_id: &T::VideoId) -> Result<Video<T>, Error<T>> {
-        ensure!(
-            VideoById::<T>::contains_key(video_id),
-            Error::<T>::VideoDoesNotExist
-        );
-        Ok(VideoById::<T>::get(video_id))
-    }
+            ensure!(
+                !params.period_length.is_zero(),
+                Error::<T>::InvalidSubscriptionPeriodLength
+            );
 
-    fn ensure_channel_exists(channel_id: &T::ChannelId) -> Result<Channel<T>, Error<T>> {
-        ensure!(
-            ChannelById::<T>::contains_key(channel_id),
-            Error::<T>::ChannelDoesNotExist
-        );
-        Ok(ChannelById::<T>::get(channel_id))
-    }
+            //
+            // == MUTATION SAFE ==
+            //
 
-    fn get_channel_from_video(video: &Video<T>) -> Channel<T> {
-        ChannelById::<T>::get(video.in_channel)
-    }
+            let tier_id = Self::next_subscription_tier_id(channel_id);
 
-    /// Convert InitTransactionalStatus to TransactionalStatus after checking requirements on the Auction variant
+            SubscriptionTierById::<T>::insert(channel_id, tier_id, SubscriptionTier::<T> {
+                price_per_period: params.price_per_period,
+                period_length: params.period_length,
+                active: true,
+            });
+
+            NextSubscriptionTierId::<T>::insert(channel_id, tier_id.saturating_add(1));
+
+            Self::deposit_event(
+                RawEvent::SubscriptionTierCreated(actor, channel_id, tier_id, params)
+            );
+        }
+
+        /// Deactivate channel subscription tier. Existing subscriptions are not affected.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::deactivate_subscription_tier()]
+        pub fn deactivate_subscription_tier(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            channel_id: T::ChannelId,
+            tier_id: SubscriptionTierId,
+        ) {
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            ensure_actor_authorized_to_manage_subscription_tiers::<T>(origin, &actor, &channel)?;
+
+            let tier = Self::ensure_active_subscription_tier(&channel_id, tier_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            SubscriptionTierById::<T>::insert(channel_id, tier_id, SubscriptionTier::<T> {
+                active: false,
+                ..tier
+            });
+
+            Self::deposit_event(RawEvent::SubscriptionTierDeactivated(actor, channel_id, tier_id));
+        }
+
+        /// Subscribe member to the channel subscription tier. The provided number of periods
+        /// is paid to the channel account immediately, the renewal deposit is used to renew
+        /// the subscription after the paid periods.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::subscribe_to_channel()]
+        pub fn subscribe_to_channel(
+            origin,
+            member_id: T::MemberId,
+            channel_id: T::ChannelId,
+            tier_id: SubscriptionTierId,
+            periods: u32,
+            renewal_deposit: BalanceOf<T>,
+        ) {
+            let sender = ensure_signed(origin)?;
+            ensure_member_auth_success::<T>(&sender, &member_id)?;
+
+            Self::ensure_channel_exists(&channel_id)?;
+
+            let tier = Self::ensure_active_subscription_tier(&channel_id, tier_id)?;
+
+            ensure!(periods > 0, Error::<T>::InvalidNumberOfSubscriptionPeriods);
+
+            let now = <frame_system::Pallet<T>>::block_number();
+
+            // Expired subscription is replaced by the new one.
+            let expired_subscription_settlement = match Self::subscription(channel_id, member_id) {
+                Some(subscription) => {
+                    ensure!(
+                        Self::subscription_expires_at(&channel_id, &subscription) <= now,
+                        Error::<T>::AlreadySubscribedToChannel
+                    );
+                    Some(Self::ensure_can_settle_subscription(&channel_id, subscription, now)?)
+                }
+                None => None,
+            };
+
+            let payment = tier.price_per_period.saturating_mul(periods.into());
+
+            ensure!(
+                has_sufficient_balance_for_payment::<T>(
+                    &sender,
+                    payment.saturating_add(renewal_deposit)
+                ),
+                Error::<T>::InsufficientBalanceForSubscription
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if let Some((_, settled_payment, refund)) = expired_subscription_settlement {
+                Self::pay_subscription_settlement(&channel_id, &sender, settled_payment, refund);
+            }
+
+            // Sender balance was checked above
+            let _ = <Balances<T> as Currency<T::AccountId>>::transfer(
+                &sender,
+                &ContentTreasury::<T>::account_for_channel(channel_id),
+                payment,
+                ExistenceRequirement::KeepAlive,
+            );
+
+            if !renewal_deposit.is_zero() {
+                let _ = ContentTreasury::<T>::deposit(&sender, renewal_deposit);
+            }
+
+            let paid_until = now.saturating_add(tier.period_length.saturating_mul(periods.into()));
+
+            SubscriptionByChannelAndMember::<T>::insert(channel_id, member_id, Subscription::<T> {
+                tier_id,
+                subscribed_at: now,
+                paid_until,
+                deposit: renewal_deposit,
+            });
+
+            Self::deposit_event(RawEvent::ChannelSubscribed(
+                member_id,
+                channel_id,
+                tier_id,
+                paid_until,
+                renewal_deposit,
+            ));
+        }
+
+        /// Add balance to the channel subscription renewal deposit.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::top_up_channel_subscription()]
+        pub fn top_up_channel_subscription(
+            origin,
+            member_id: T::MemberId,
+            channel_id: T::ChannelId,
+            amount: BalanceOf<T>,
+        ) {
+            let sender = ensure_signed(origin)?;
+            ensure_member_auth_success::<T>(&sender, &member_id)?;
+
+            let subscription = Self::ensure_subscription_exists(&channel_id, &member_id)?;
+
+            ensure!(
+                Self::subscription_expires_at(&channel_id, &subscription) >
+                    <frame_system::Pallet<T>>::block_number(),
+                Error::<T>::SubscriptionExpired
+            );
+
+            ensure!(
+                has_sufficient_balance_for_payment::<T>(&sender, amount),
+                Error::<T>::InsufficientBalanceForSubscription
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            ContentTreasury::<T>::deposit(&sender, amount)?;
+
+            SubscriptionByChannelAndMember::<T>::insert(channel_id, member_id, Subscription::<T> {
+                deposit: subscription.deposit.saturating_add(amount),
+                ..subscription
+            });
+
+            Self::deposit_event(
+                RawEvent::ChannelSubscriptionToppedUp(member_id, channel_id, amount)
+            );
+        }
+
+        /// Renew the channel subscription periods that are due from the renewal deposit.
+        /// The payment is transferred to the channel account. Can be called by any account.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::renew_channel_subscription()]
+        pub fn renew_channel_subscription(
+            origin,
+            channel_id: T::ChannelId,
+            member_id: T::MemberId,
+        ) {
+            ensure_signed(origin)?;
+
+            let subscription = Self::ensure_subscription_exists(&channel_id, &member_id)?;
+
+            let tier = Self::ensure_subscription_tier_exists(&channel_id, subscription.tier_id)?;
+
+            let (renewed, payment) =
+                subscription.renewed(&tier, <frame_system::Pallet<T>>::block_number());
+
+            ensure!(!payment.is_zero(), Error::<T>::SubscriptionRenewalNotDue);
+
+            ensure!(
+                has_sufficient_balance_for_payment::<T>(
+                    &ContentTreasury::<T>::module_account_id(),
+                    payment
+                ),
+                Error::<T>::InsufficientTreasuryBalance
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // Treasury balance was checked above
+            let _ = ContentTreasury::<T>::withdraw(
+                &ContentTreasury::<T>::account_for_channel(channel_id),
+                payment
+            );
+
+            let paid_until = renewed.paid_until;
+
+            SubscriptionByChannelAndMember::<T>::insert(channel_id, member_id, renewed);
+
+            Self::deposit_event(
+                RawEvent::ChannelSubscriptionRenewed(member_id, channel_id, paid_until, payment)
+            );
+        }
+
+        /// Cancel the channel subscription renewal: periods that are due are renewed and
+        /// the rest of the renewal deposit is refunded. The subscription stays valid until
+        /// the end of the last paid period.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::cancel_channel_subscription_renewal()]
+        pub fn cancel_channel_subscription_renewal(
+            origin,
+            member_id: T::MemberId,
+            channel_id: T::ChannelId,
+        ) {
+            let sender = ensure_signed(origin)?;
+            ensure_member_auth_success::<T>(&sender, &member_id)?;
+
+            let subscription = Self::ensure_subscription_exists(&channel_id, &member_id)?;
+
+            let (settled, payment, refund) = Self::ensure_can_settle_subscription(
+                &channel_id,
+                subscription,
+                <frame_system::Pallet<T>>::block_number(),
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::pay_subscription_settlement(&channel_id, &sender, payment, refund);
+
+            SubscriptionByChannelAndMember::<T>::insert(channel_id, member_id, settled);
+
+            Self::deposit_event(
+                RawEvent::ChannelSubscriptionRenewalCancelled(member_id, channel_id, refund)
+            );
+        }
+
+        /// Set (or remove, if `params` is `None`) the channel automatic revenue splits.
+        /// A `pool_rate` share of the channel reward claims and NFT royalties/sales is put aside
+        /// in the channel account and distributed in a revenue split issued automatically once
+        /// the pending amount reaches the `threshold` (at the beginning of the next block)
+        /// or the `period` elapses.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::set_channel_auto_revenue_split()]
+        pub fn set_channel_auto_revenue_split(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            channel_id: T::ChannelId,
+            params: Option<AutoRevenueSplitParameters<T>>,
+        ) {
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            // Permissions check
+            ensure_actor_authorized_to_manage_revenue_splits::<T>(
+                origin,
+                &actor,
+                &channel
+            )?;
+
+            // Ensure token was issued
+            channel.ensure_creator_token_issued::<T>()?;
+
+            if let Some(ref params) = params {
+                Self::validate_auto_revenue_split_params(params)?;
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            match params.clone() {
+                Some(params) => {
+                    // Earnings already pending are kept in the pool
+                    let pending_amount = Self::auto_revenue_split_pool(channel_id)
+                        .map_or_else(Zero::zero, |pool| pool.pending_amount);
+                    let mut pool = AutoRevenueSplitPool::<T> {
+                        params,
+                        pending_amount,
+                        next_split_at: None,
+                    };
+
+                    Self::reschedule_auto_revenue_split(
+                        channel_id,
+                        &mut pool,
+                        <frame_system::Pallet<T>>::block_number(),
+                    );
+
+                    AutoRevenueSplitPoolByChannelId::<T>::insert(channel_id, pool);
+                }
+                None => AutoRevenueSplitPoolByChannelId::<T>::remove(channel_id),
+            }
+
+            Self::deposit_event(
+                RawEvent::ChannelAutoRevenueSplitUpdated(actor, channel_id, params)
+            );
+        }
+
+        /// Freeze (unfreeze) a creator token account or pause (unpause) the whole creator token.
+        /// If `member_id` is `None` the token paused status is updated.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::set_creator_token_frozen_status()]
+        pub fn set_creator_token_frozen_status(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            channel_id: T::ChannelId,
+            member_id: Option<T::MemberId>,
+            frozen: bool,
+        ) {
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            // Permissions check
+            ensure_actor_authorized_to_manage_creator_token_compliance::<T>(
+                origin,
+                &actor,
+                &channel
+            )?;
+
+            // Only the council can lift a freeze it has enforced
+            ensure!(
+                frozen || !Self::creator_token_frozen_by_council(channel_id, member_id),
+                Error::<T>::CreatorTokenFrozenByCouncil
+            );
+
+            Self::set_creator_token_frozen_status_inner(&channel, member_id, frozen)?;
+        }
+
+        /// Freeze (unfreeze) a creator token account or pause (unpause) the whole creator token
+        /// as the council, overriding the channel issuer.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::set_creator_token_frozen_status_as_council()]
+        pub fn set_creator_token_frozen_status_as_council(
+            origin,
+            params: CreatorTokenFrozenStatusParameters<T>,
+        ) {
+            ensure_root(origin)?;
+
+            let channel = Self::ensure_channel_exists(&params.channel_id)?;
+
+            Self::set_creator_token_frozen_status_inner(&channel, params.member_id, params.frozen)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if params.frozen {
+                CreatorTokenFrozenByCouncil::<T>::insert(params.channel_id, params.member_id, true);
+            } else {
+                CreatorTokenFrozenByCouncil::<T>::remove(params.channel_id, params.member_id);
+            }
+        }
+
+        /// Start a creator token buyback program funded from the channel reward account
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::start_creator_token_buyback()]
+        pub fn start_creator_token_buyback(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            channel_id: T::ChannelId,
+            params: BuybackParamsOf<T>,
+        ) {
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            channel.ensure_has_no_active_transfer::<T>()?;
+
+            // Permissions check
+            ensure_actor_authorized_to_manage_creator_token_buyback::<T>(
+                origin,
+                &actor,
+                &channel
+            )?;
+
+            // Ensure token was issued
+            let token_id = channel.ensure_creator_token_issued::<T>()?;
+
+            // Ensure the budget can be covered by the channel's withdrawable balance
+            let reward_account = ContentTreasury::<T>::account_for_channel(channel_id);
+            ensure!(
+                params.budget <= Self::channel_account_withdrawable_balance(
+                    channel_id,
+                    &reward_account,
+                    &channel
+                ),
+                Error::<T>::WithdrawalAmountExceedsChannelAccountWithdrawableBalance
+            );
+
+            // Call to ProjectToken - should be the first call before MUTATION SAFE!
+            T::ProjectToken::start_buyback_program(token_id, reward_account, params)?;
+        }
+
+        /// Finalize an ended creator token buyback program
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::finalize_creator_token_buyback()]
+        pub fn finalize_creator_token_buyback(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            channel_id: T::ChannelId,
+        ) {
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            channel.ensure_has_no_active_transfer::<T>()?;
+
+            // Permissions check
+            ensure_actor_authorized_to_manage_creator_token_buyback::<T>(
+                origin,
+                &actor,
+                &channel
+            )?;
+
+            // Ensure token was issued
+            let token_id = channel.ensure_creator_token_issued::<T>()?;
+
+            // Get channel's reward account
+            let reward_account = ContentTreasury::<T>::account_for_channel(channel_id);
+
+            // Call to ProjectToken - should be the first call before MUTATION SAFE!
+            T::ProjectToken::finalize_buyback_program(
+                token_id,
+                reward_account, // send the unspent budget back to reward_account
+            )?;
+        }
+
+        /// Set (or remove, if `caps` is `None`) the caps on the creator token issuer actions
+        /// performed by the channel agent identified by `member_id`.
+        /// Can only be called by the channel owner.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::set_creator_token_agent_caps()]
+        pub fn set_creator_token_agent_caps(
+            origin,
+            channel_id: T::ChannelId,
+            member_id: T::MemberId,
+            caps: Option<CreatorTokenAgentCaps<T>>,
+        ) {
+            let sender = ensure_signed(origin)?;
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            channel.ensure_has_no_active_transfer::<T>()?;
+
+            // Permissions check
+            ensure_is_authorized_to_act_as_channel_owner::<T>(&sender, &channel.owner)?;
+
+            // Caps are never applied to the channel owner
+            ensure!(
+                channel.owner != ChannelOwner::Member(member_id),
+                Error::<T>::CreatorTokenAgentCapsTargetIsChannelOwner
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            match caps.clone() {
+                Some(caps) => CreatorTokenAgentCapsByChannelAndMember::<T>::insert(
+                    channel_id,
+                    member_id,
+                    caps
+                ),
+                None => CreatorTokenAgentCapsByChannelAndMember::<T>::remove(
+                    channel_id,
+                    member_id
+                ),
+            }
+
+            Self::deposit_event(
+                RawEvent::CreatorTokenAgentCapsUpdated(channel_id, member_id, caps)
+            );
+        }
+    }
+}
+
+impl<T: Config> Module<T> {
+    // Creator token caps of the channel agent along with the agent's member id
+    // (`None` if the actor is not a channel agent or no caps were set for the agent).
+    fn channel_agent_creator_token_caps(
+        channel_id: T::ChannelId,
+        actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+        agent_permissions: &Option<ChannelAgentPermissions>,
+    ) -> Result<Option<(T::MemberId, CreatorTokenAgentCaps<T>)>, DispatchError> {
+        if agent_permissions.is_none() {
+            return Ok(None);
+        }
+        let member_id = get_member_id_of_actor::<T>(actor)?;
+        Ok(Self::creator_token_agent_caps(channel_id, member_id).map(|caps| (member_id, caps)))
+    }
+
+    // Updates the frozen status of the channel creator token account (or the token paused status
+    // if `member_id` is `None`).
+    fn set_creator_token_frozen_status_inner(
+        channel: &Channel<T>,
+        member_id: Option<T::MemberId>,
+        frozen: bool,
+    ) -> DispatchResult {
+        // Ensure token was issued
+        let token_id = channel.ensure_creator_token_issued::<T>()?;
+
+        // Call to ProjectToken - should be the first call before MUTATION SAFE!
+        match member_id {
+            Some(member_id) => {
+                T::ProjectToken::set_account_frozen_status(token_id, member_id, frozen)
+            }
+            None => T::ProjectToken::set_token_paused_status(token_id, frozen),
+        }
+    }
+
+    // Decreases reputation of the member owning the channel targeted by a moderation action.
+    fn record_moderation_action(channel_owner: &ChannelOwner<T::MemberId, T::CuratorGroupId>) {
+        if let ChannelOwner::Member(member_id) = channel_owner {
+            T::MemberReputationHandler::record_activity(
+                member_id,
+                ReputationActivity::ModerationAction,
+            );
+        }
+    }
+
+    /// Ensure `CuratorGroup` under given id exists
+    fn ensure_curator_group_under_given_id_exists(
+        curator_group_id: &T::CuratorGroupId,
+    ) -> Result<(), Error<T>> {
+        ensure!(
+            <CuratorGroupById<T>>::contains_key(curator_group_id),
+            Error::<T>::CuratorGroupDoesNotExist
+        );
+        Ok(())
+    }
+
+    /// Ensure `CuratorGroup` under given id exists, return corresponding one
+    fn ensure_curator_group_exists(
+        curator_group_id: &T::CuratorGroupId,
+    ) -> Result<CuratorGroup<T>, Error<T>> {
+        Self::ensure_curator_group_under_given_id_exists(curator_group_id)?;
+        Ok(Self::curator_group_by_id(curator_group_id))
+    }
+
+    // Ensure given video has no associated nft
+    fn ensure_video_can_be_removed(video: &Video<T>) -> DispatchResult {
+        // Ensure nft for this video have not been issued
+        video.ensure_nft_is_not_issued::<T>()?;
+        Ok(())
+    }
+
+    fn ensure_video_exists(video_id: &T::VideoId) -> Result<Video<T>, Error<T>> {
+        ensure!(
+            VideoById::<T>::contains_key(video_id),
+            Error::<T>::VideoDoesNotExist
+        );
+        Ok(VideoById::<T>::get(video_id))
+    }
+
+    fn ensure_channel_exists(channel_id: &T::ChannelId) -> Result<Channel<T>, Error<T>> {
+        ensure!(
+            ChannelById::<T>::contains_key(channel_id),
+            Error::<T>::ChannelDoesNotExist
+        );
+        Ok(ChannelById::<T>::get(channel_id))
+    }
+
+    fn ensure_subscription_tier_exists(
+        channel_id: &T::ChannelId,
+        tier_id: SubscriptionTierId,
+    ) -> Result<SubscriptionTier<T>, Error<T>> {
+        Self::subscription_tier_by_id(channel_id, tier_id)
+            .ok_or(Error::<T>::SubscriptionTierDoesNotExist)
+    }
+
+    fn ensure_active_subscription_tier(
+        channel_id: &T::ChannelId,
+        tier_id: SubscriptionTierId,
+    ) -> Result<SubscriptionTier<T>, Error<T>> {
+        let tier = Self::ensure_subscription_tier_exists(channel_id, tier_id)?;
+        ensure!(tier.active, Error::<T>::SubscriptionTierNotActive);
+        Ok(tier)
+    }
+
+    fn ensure_subscription_exists(
+        channel_id: &T::ChannelId,
+        member_id: &T::MemberId,
+    ) -> Result<Subscription<T>, Error<T>> {
+        Self::subscription(channel_id, member_id).ok_or(Error::<T>::SubscriptionDoesNotExist)
+    }
+
+    // Block at which the subscription expires, including the renewals from the deposit.
+    fn subscription_expires_at(
+        channel_id: &T::ChannelId,
+        subscription: &Subscription<T>,
+    ) -> T::BlockNumber {
+        Self::subscription_tier_by_id(channel_id, subscription.tier_id)
+            .map_or(subscription.paid_until, |tier| {
+                subscription.expires_at(&tier)
+            })
+    }
+
+    // Settles the subscription: the periods that are due are renewed and the rest of the renewal
+    // deposit is released. Ensures the content treasury can pay both. Returns the settled
+    // subscription, the payment due to the channel account and the released deposit.
+    fn ensure_can_settle_subscription(
+        channel_id: &T::ChannelId,
+        subscription: Subscription<T>,
+        now: T::BlockNumber,
+    ) -> Result<(Subscription<T>, BalanceOf<T>, BalanceOf<T>), DispatchError> {
+        let (renewed, payment) =
+            match Self::subscription_tier_by_id(channel_id, subscription.tier_id) {
+                Some(tier) => subscription.renewed(&tier, now),
+                None => (subscription, Zero::zero()),
+            };
+
+        let released_deposit = renewed.deposit;
+
+        ensure!(
+            has_sufficient_balance_for_payment::<T>(
+                &ContentTreasury::<T>::module_account_id(),
+                payment.saturating_add(released_deposit),
+            ),
+            Error::<T>::InsufficientTreasuryBalance
+        );
+
+        Ok((
+            Subscription::<T> {
+                deposit: Zero::zero(),
+                ..renewed
+            },
+            payment,
+            released_deposit,
+        ))
+    }
+
+    // Pays the settled subscription periods to the channel account and refunds the released
+    // renewal deposit. Treasury balance must be checked beforehand.
+    fn pay_subscription_settlement(
+        channel_id: &T::ChannelId,
+        refund_account_id: &T::AccountId,
+        payment: BalanceOf<T>,
+        refund: BalanceOf<T>,
+    ) {
+        if !payment.is_zero() {
+            let _ = ContentTreasury::<T>::withdraw(
+                &ContentTreasury::<T>::account_for_channel(*channel_id),
+                payment,
+            );
+        }
+
+        if !refund.is_zero() {
+            let _ = ContentTreasury::<T>::withdraw(refund_account_id, refund);
+        }
+    }
+
+    /// Verifies whether the member is subscribed to the channel subscription tier at the block.
+    pub fn is_subscribed(
+        channel_id: T::ChannelId,
+        member_id: T::MemberId,
+        tier_id: SubscriptionTierId,
+        block: T::BlockNumber,
+    ) -> bool {
+        Self::subscription(channel_id, member_id)
+            .filter(|subscription| {
+                subscription.tier_id == tier_id && subscription.subscribed_at <= block
+            })
+            .map_or(false, |subscription| {
+                block < Self::subscription_expires_at(&channel_id, &subscription)
+            })
+    }
+
+    fn get_channel_from_video(video: &Video<T>) -> Channel<T> {
+        ChannelById::<T>::get(video.in_channel)
+    }
+
+    /// Convert InitTransactionalStatus to TransactionalStatus after checking requirements on the Auction variant
     fn ensure_valid_init_transactional_status(
         init_status: &InitTransactionalStatus<T>,
     ) -> Result<TransactionalStatus<T>, DispatchError> {
@@ -4015,6 +5131,9 @@ impl<T: Config> Module<T> {
 
         // remove channel from on chain state
         ChannelById::<T>::remove(channel_id);
+        AutoRevenueSplitPoolByChannelId::<T>::remove(channel_id);
+        CreatorTokenAgentCapsByChannelAndMember::<T>::remove_prefix(channel_id, None);
+        CreatorTokenFrozenByCouncil::<T>::remove_prefix(channel_id, None);
 
         // Slash or repay channel state bloat bond
         let channel_account = ContentTreasury::<T>::account_for_channel(channel_id);
@@ -4371,16 +5490,18 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    // Returns the amount routed to the channel automatic revenue split pool
     fn execute_channel_reward_claim(
         channel_id: T::ChannelId,
         reward_account: &T::AccountId,
         amount: BalanceOf<T>,
-    ) {
+    ) -> BalanceOf<T> {
         T::CouncilBudgetManager::withdraw(reward_account, amount);
         ChannelById::<T>::mutate(&channel_id, |channel| {
             channel.cumulative_reward_claimed =
                 channel.cumulative_reward_claimed.saturating_add(amount)
         });
+        Self::credit_auto_revenue_split_pool(channel_id, amount)
     }
 
     fn execute_channel_balance_withdrawal(
@@ -4643,12 +5764,171 @@ impl<T: Config> Module<T> {
     }
 
     fn channel_account_withdrawable_balance(
+        channel_id: T::ChannelId,
         channel_account: &T::AccountId,
         channel: &Channel<T>,
     ) -> BalanceOf<T> {
         let usable_balance = balances::Pallet::<T>::usable_balance(channel_account);
         let bloat_bond_value = channel.channel_state_bloat_bond.amount;
-        usable_balance.saturating_sub(bloat_bond_value)
+        // Earnings pending distribution in the next automatic revenue split are not withdrawable
+        let auto_revenue_split_pending_amount = Self::auto_revenue_split_pool(channel_id)
+            .map_or_else(Zero::zero, |pool| pool.pending_amount);
+        usable_balance
+            .saturating_sub(bloat_bond_value)
+            .saturating_sub(auto_revenue_split_pending_amount)
+    }
+
+    fn validate_auto_revenue_split_params(
+        params: &AutoRevenueSplitParameters<T>,
+    ) -> DispatchResult {
+        ensure!(
+            !params.pool_rate.is_zero(),
+            Error::<T>::AutoRevenueSplitPoolRateIsZero
+        );
+
+        ensure!(
+            params.threshold.is_some() || params.period.is_some(),
+            Error::<T>::AutoRevenueSplitTriggerNotProvided
+        );
+
+        ensure!(
+            params
+                .threshold
+                .map_or(true, |threshold| !threshold.is_zero()),
+            Error::<T>::AutoRevenueSplitThresholdIsZero
+        );
+
+        ensure!(
+            params.period.map_or(true, |period| !period.is_zero()),
+            Error::<T>::AutoRevenueSplitPeriodIsZero
+        );
+
+        Ok(())
+    }
+
+    // Routes the pool rate share of the channel `earnings` (already deposited into the channel
+    // account) to the automatic revenue split pool. If the split is due, it is scheduled
+    // to be issued in `on_initialize` of the next block. Returns the amount routed to the pool.
+    pub(crate) fn credit_auto_revenue_split_pool(
+        channel_id: T::ChannelId,
+        earnings: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+        let mut pool = match Self::auto_revenue_split_pool(channel_id) {
+            Some(pool) => pool,
+            None => return Zero::zero(),
+        };
+
+        let routed_amount = pool.params.pool_rate.mul_floor(earnings);
+        if routed_amount.is_zero() {
+            return routed_amount;
+        }
+
+        pool.pending_amount = pool.pending_amount.saturating_add(routed_amount);
+
+        Self::deposit_event(RawEvent::AutoRevenueSplitPoolCredited(
+            channel_id,
+            routed_amount,
+        ));
+
+        let now = <frame_system::Pallet<T>>::block_number();
+        // also covers periodic splits that could not be scheduled in time
+        if pool.is_split_due(now) {
+            Self::schedule_auto_revenue_split(
+                channel_id,
+                &mut pool,
+                now.saturating_add(One::one()),
+            );
+        }
+
+        AutoRevenueSplitPoolByChannelId::<T>::insert(channel_id, pool);
+
+        routed_amount
+    }
+
+    // Issues the scheduled automatic revenue split of the channel (if due at block `now`)
+    // and schedules the next one. Returns whether a revenue split was issued.
+    fn process_due_auto_revenue_split(channel_id: T::ChannelId, now: T::BlockNumber) -> bool {
+        let mut pool = match Self::auto_revenue_split_pool(channel_id) {
+            // ignore outdated schedules
+            Some(pool) if pool.next_split_at == Some(now) => pool,
+            _ => return false,
+        };
+
+        let issued =
+            !pool.pending_amount.is_zero() && Self::issue_auto_revenue_split(channel_id, &mut pool);
+
+        Self::reschedule_auto_revenue_split(channel_id, &mut pool, now);
+        AutoRevenueSplitPoolByChannelId::<T>::insert(channel_id, pool);
+
+        issued
+    }
+
+    // Issues a revenue split for the pool pending amount. The whole pending amount is allocated
+    // to the split: the pool rate was already applied when the pool was credited, so the token's
+    // revenue split rate is not applied again.
+    // Returns whether the revenue split was issued.
+    fn issue_auto_revenue_split(
+        channel_id: T::ChannelId,
+        pool: &mut AutoRevenueSplitPool<T>,
+    ) -> bool {
+        let token_id = match Self::channel_by_id(channel_id).creator_token_id {
+            Some(token_id) => token_id,
+            None => return false,
+        };
+        let reward_account = ContentTreasury::<T>::account_for_channel(channel_id);
+
+        // Previous revenue split must be finalized first, leftovers are sent back to the
+        // channel account. Failure means there is no ended split to finalize.
+        let _ = T::ProjectToken::finalize_revenue_split(token_id, reward_account.clone());
+
+        let issued = T::ProjectToken::issue_revenue_split_with_rate(
+            token_id,
+            None,
+            pool.params.split_duration,
+            reward_account,
+            pool.pending_amount,
+            Permill::from_percent(100),
+        )
+        .is_ok();
+
+        if issued {
+            Self::deposit_event(RawEvent::AutoRevenueSplitIssued(
+                channel_id,
+                pool.pending_amount,
+            ));
+            pool.pending_amount = Zero::zero();
+        }
+
+        issued
+    }
+
+    // Sets the next periodic automatic revenue split block (if `period` is set). If too many
+    // splits are already scheduled at that block, the split is scheduled with the next pool credit.
+    fn reschedule_auto_revenue_split(
+        channel_id: T::ChannelId,
+        pool: &mut AutoRevenueSplitPool<T>,
+        now: T::BlockNumber,
+    ) {
+        match pool.params.period {
+            Some(period) => {
+                Self::schedule_auto_revenue_split(channel_id, pool, now.saturating_add(period))
+            }
+            None => pool.next_split_at = None,
+        }
+    }
+
+    // Schedules the automatic revenue split of the channel to be issued at block `at`
+    fn schedule_auto_revenue_split(
+        channel_id: T::ChannelId,
+        pool: &mut AutoRevenueSplitPool<T>,
+        at: T::BlockNumber,
+    ) {
+        pool.next_split_at = Some(at);
+        AutoRevenueSplitsDueAt::<T>::mutate(at, |channels| {
+            if !channels.contains(&channel_id) {
+                let _ = channels.try_push(channel_id);
+            }
+        });
     }
 
     // Channel bloat bonds are paid into the channel account and serve as an existential deposit
@@ -4711,6 +5991,7 @@ impl<T: Config> Module<T> {
         //rationale
         let c = to_kb((*rationale).len() as u32);
         WeightInfoContent::<T>::delete_channel_assets_as_moderator(a, b, c)
+            .saturating_add(T::MemberReputationHandler::record_activity_weight())
     }
 
     // Calculates weight for delete_channel_as_moderator extrinsic.
@@ -4731,6 +6012,7 @@ impl<T: Config> Module<T> {
         //rationale
         let d = to_kb((*rationale).len() as u32);
         WeightInfoContent::<T>::delete_channel_as_moderator(a, b, c, d)
+            .saturating_add(T::MemberReputationHandler::record_activity_weight())
     }
 
     // Calculates weight for set_channel_visibility_as_moderator extrinsic.
@@ -4738,6 +6020,7 @@ impl<T: Config> Module<T> {
         let a = to_kb((*rationale).len() as u32);
 
         WeightInfoContent::<T>::set_channel_visibility_as_moderator(a)
+            .saturating_add(T::MemberReputationHandler::record_activity_weight())
     }
 
     // Calculates weight for set_video_visibility_as_moderator extrinsic.
@@ -4745,6 +6028,7 @@ impl<T: Config> Module<T> {
         let a = to_kb((*rationale).len() as u32);
 
         WeightInfoContent::<T>::set_video_visibility_as_moderator(a)
+            .saturating_add(T::MemberReputationHandler::record_activity_weight())
     }
 
     // Calculates weight for delete_video_assets_as_moderator extrinsic.
@@ -4763,6 +6047,7 @@ impl<T: Config> Module<T> {
         let c = to_kb((*rationale).len() as u32);
 
         WeightInfoContent::<T>::delete_video_assets_as_moderator(a, b, c)
+            .saturating_add(T::MemberReputationHandler::record_activity_weight())
     }
 
     // Calculates weight for delete_video_as_moderator extrinsic.
@@ -4771,7 +6056,7 @@ impl<T: Config> Module<T> {
         storage_buckets_num_witness: &Option<u32>,
         rationale: &Vec<u8>,
     ) -> Weight {
-        if (*num_objects_to_delete) > 0 {
+        let delete_video_weight = if (*num_objects_to_delete) > 0 {
             //assets_to_remove
             let a = (*num_objects_to_delete) as u32;
 
@@ -4787,7 +6072,9 @@ impl<T: Config> Module<T> {
             let a = to_kb((*rationale).len() as u32);
 
             WeightInfoContent::<T>::delete_video_as_moderator_without_assets(a)
-        }
+        };
+
+        delete_video_weight.saturating_add(T::MemberReputationHandler::record_activity_weight())
     }
 
     // Calculates weight for accept_channel_transfer extrinsic.
@@ -4836,6 +6123,10 @@ decl_event!(
         UpdateChannelPayoutsParameters = UpdateChannelPayoutsParameters<T>,
         TokenId = <T as project_token::Config>::TokenId,
         ChannelFundsDestination = ChannelFundsDestination<<T as frame_system::Config>::AccountId>,
+        SubscriptionTierParameters = SubscriptionTierParameters<T>,
+        AutoRevenueSplitParameters = AutoRevenueSplitParameters<T>,
+        BlockNumber = <T as frame_system::Config>::BlockNumber,
+        CreatorTokenAgentCaps = CreatorTokenAgentCaps<T>,
     {
         // Curators
         CuratorGroupCreated(CuratorGroupId),
@@ -4945,5 +6236,39 @@ decl_event!(
         ToggledNftLimits(bool),
         // Creator tokens
         CreatorTokenIssued(ContentActor, ChannelId, TokenId),
+        CreatorTokenAgentCapsUpdated(ChannelId, MemberId, Option<CreatorTokenAgentCaps>),
+
+        // Channel subscriptions
+        SubscriptionTierCreated(
+            ContentActor,
+            ChannelId,
+            SubscriptionTierId,
+            SubscriptionTierParameters,
+        ),
+        SubscriptionTierDeactivated(ContentActor, ChannelId, SubscriptionTierId),
+        ChannelSubscribed(
+            MemberId,
+            ChannelId,
+            SubscriptionTierId,
+            BlockNumber, /* paid until */
+            Balance,     /* renewal deposit */
+        ),
+        ChannelSubscriptionToppedUp(MemberId, ChannelId, Balance),
+        ChannelSubscriptionRenewed(
+            MemberId,
+            ChannelId,
+            BlockNumber, /* paid until */
+            Balance,
+        ),
+        ChannelSubscriptionRenewalCancelled(
+            MemberId,
+            ChannelId,
+            Balance, /* refunded deposit */
+        ),
+
+        // Automatic revenue splits
+        ChannelAutoRevenueSplitUpdated(ContentActor, ChannelId, Option<AutoRevenueSplitParameters>),
+        AutoRevenueSplitPoolCredited(ChannelId, Balance),
+        AutoRevenueSplitIssued(ChannelId, Balance /* pending amount */),
     }
 );
@@ -311,6 +311,7 @@ impl<T: Config> Module<T> {
     /// Buy nft
     pub(crate) fn buy_now(
         nft: Nft<T>,
+        channel_id: T::ChannelId,
         royalty_payment: Option<(Royalty, T::AccountId)>,
         old_owner_account_id: Option<T::AccountId>,
         new_owner_account_id: T::AccountId,
@@ -318,6 +319,7 @@ impl<T: Config> Module<T> {
     ) -> Result<Nft<T>, DispatchError> {
         if let TransactionalStatus::<T>::BuyNow(price) = &nft.transactional_status {
             Self::complete_payment(
+                channel_id,
                 royalty_payment,
                 price.to_owned(),
                 new_owner_account_id,
@@ -335,6 +337,7 @@ impl<T: Config> Module<T> {
     /// Completes nft offer
     pub(crate) fn complete_nft_offer(
         mut nft: Nft<T>,
+        channel_id: T::ChannelId,
         royalty_payment: Option<(Royalty, T::AccountId)>,
         owner_account_id: Option<T::AccountId>,
         new_owner_account_id: T::AccountId,
@@ -344,6 +347,7 @@ impl<T: Config> Module<T> {
         {
             if let Some(price) = price {
                 Self::complete_payment(
+                    channel_id,
                     royalty_payment,
                     *price,
                     new_owner_account_id,
@@ -362,7 +366,10 @@ impl<T: Config> Module<T> {
     }
 
     /// Complete payment, either auction related or buy now/offer
+    /// The royalty (and the net amount, if received by the channel account) is credited
+    /// to the channel automatic revenue split pool
     pub(crate) fn complete_payment(
+        channel_id: T::ChannelId,
         royalty_payment: Option<(Royalty, T::AccountId)>,
         amount: BalanceOf<T>,
         sender_account_id: T::AccountId,
@@ -375,6 +382,9 @@ impl<T: Config> Module<T> {
         let platform_fee_pct = Self::platform_fee_percentage();
         let platform_fee = platform_fee_pct.mul_floor(amount);
 
+        let channel_account = ContentTreasury::<T>::account_for_channel(channel_id);
+        let mut channel_earnings: BalanceOf<T> = Zero::zero();
+
         // established net amount and pay royalties if necessary
         let net_amount = if let Some((nominal_royalty_pct, creator_account)) = royalty_payment {
             // min(creator_royalty, 100% - platform_fee_percentage) is used to avoid underflow
@@ -386,6 +396,9 @@ impl<T: Config> Module<T> {
 
             // deposit to creator account
             let _ = Balances::<T>::deposit_creating(&creator_account, royalty);
+            if creator_account == channel_account {
+                channel_earnings = channel_earnings.saturating_add(royalty);
+            }
 
             amount.saturating_sub(platform_fee).saturating_sub(royalty)
         } else {
@@ -394,8 +407,13 @@ impl<T: Config> Module<T> {
 
         if let Some(ref nft_owner_account) = receiver_account_id {
             let _ = Balances::<T>::deposit_creating(nft_owner_account, net_amount);
+            if *nft_owner_account == channel_account {
+                channel_earnings = channel_earnings.saturating_add(net_amount);
+            }
         }
 
+        let _ = Self::credit_auto_revenue_split_pool(channel_id, channel_earnings);
+
         Ok(())
     }
 
@@ -410,6 +428,7 @@ impl<T: Config> Module<T> {
         let account_withdraw_from = ContentTreasury::<T>::module_account_id();
 
         Self::complete_payment(
+            video.in_channel,
             royalty_payment,
             amount,
             account_withdraw_from,
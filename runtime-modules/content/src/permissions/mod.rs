@@ -640,11 +640,12 @@ pub fn ensure_actor_authorized_to_claim_creator_token_patronage<T: Config>(
     origin: T::Origin,
     actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
     channel: &Channel<T>,
-) -> Result<T::AccountId, DispatchError> {
+) -> Result<(T::AccountId, Option<ChannelAgentPermissions>), DispatchError> {
     let sender = ensure_signed(origin)?;
     let required_permissions = vec![ChannelActionPermission::ClaimCreatorTokenPatronage];
-    ensure_actor_has_channel_permissions::<T>(&sender, actor, channel, &required_permissions)?;
-    Ok(sender)
+    let permissions =
+        ensure_actor_has_channel_permissions::<T>(&sender, actor, channel, &required_permissions)?;
+    Ok((sender, permissions))
 }
 
 pub fn ensure_actor_authorized_to_init_and_manage_creator_token_sale<T: Config>(
@@ -663,11 +664,12 @@ pub fn ensure_actor_authorized_to_perform_creator_token_issuer_transfer<T: Confi
     origin: T::Origin,
     actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
     channel: &Channel<T>,
-) -> Result<T::AccountId, DispatchError> {
+) -> Result<(T::AccountId, Option<ChannelAgentPermissions>), DispatchError> {
     let sender = ensure_signed(origin)?;
     let required_permissions = vec![ChannelActionPermission::CreatorTokenIssuerTransfer];
-    ensure_actor_has_channel_permissions::<T>(&sender, actor, channel, &required_permissions)?;
-    Ok(sender)
+    let permissions =
+        ensure_actor_has_channel_permissions::<T>(&sender, actor, channel, &required_permissions)?;
+    Ok((sender, permissions))
 }
 
 pub fn ensure_actor_authorized_to_make_creator_token_permissionless<T: Config>(
@@ -685,31 +687,78 @@ pub fn ensure_actor_authorized_to_reduce_creator_token_patronage_rate<T: Config>
     origin: T::Origin,
     actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
     channel: &Channel<T>,
-) -> Result<T::AccountId, DispatchError> {
+) -> Result<(T::AccountId, Option<ChannelAgentPermissions>), DispatchError> {
     let sender = ensure_signed(origin)?;
     let required_permissions = vec![ChannelActionPermission::ReduceCreatorTokenPatronageRate];
+    let permissions =
+        ensure_actor_has_channel_permissions::<T>(&sender, actor, channel, &required_permissions)?;
+    Ok((sender, permissions))
+}
+
+pub fn ensure_actor_authorized_to_manage_revenue_splits<T: Config>(
+    origin: T::Origin,
+    actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+    channel: &Channel<T>,
+) -> Result<(T::AccountId, Option<ChannelAgentPermissions>), DispatchError> {
+    let sender = ensure_signed(origin)?;
+    let required_permissions = vec![ChannelActionPermission::ManageRevenueSplits];
+    let permissions =
+        ensure_actor_has_channel_permissions::<T>(&sender, actor, channel, &required_permissions)?;
+    Ok((sender, permissions))
+}
+
+pub fn ensure_actor_authorized_to_deissue_creator_token<T: Config>(
+    origin: T::Origin,
+    actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+    channel: &Channel<T>,
+) -> Result<T::AccountId, DispatchError> {
+    let sender = ensure_signed(origin)?;
+    let required_permissions = vec![ChannelActionPermission::DeissueCreatorToken];
     ensure_actor_has_channel_permissions::<T>(&sender, actor, channel, &required_permissions)?;
     Ok(sender)
 }
 
-pub fn ensure_actor_authorized_to_manage_revenue_splits<T: Config>(
+pub fn ensure_actor_authorized_to_manage_creator_token_polls<T: Config>(
     origin: T::Origin,
     actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
     channel: &Channel<T>,
 ) -> Result<T::AccountId, DispatchError> {
     let sender = ensure_signed(origin)?;
-    let required_permissions = vec![ChannelActionPermission::ManageRevenueSplits];
+    let required_permissions = vec![ChannelActionPermission::ManageCreatorTokenPolls];
     ensure_actor_has_channel_permissions::<T>(&sender, actor, channel, &required_permissions)?;
     Ok(sender)
 }
 
-pub fn ensure_actor_authorized_to_deissue_creator_token<T: Config>(
+pub fn ensure_actor_authorized_to_manage_creator_token_compliance<T: Config>(
     origin: T::Origin,
     actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
     channel: &Channel<T>,
 ) -> Result<T::AccountId, DispatchError> {
     let sender = ensure_signed(origin)?;
-    let required_permissions = vec![ChannelActionPermission::DeissueCreatorToken];
+    let required_permissions = vec![ChannelActionPermission::ManageCreatorTokenCompliance];
+    ensure_actor_has_channel_permissions::<T>(&sender, actor, channel, &required_permissions)?;
+    Ok(sender)
+}
+
+pub fn ensure_actor_authorized_to_manage_creator_token_buyback<T: Config>(
+    origin: T::Origin,
+    actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+    channel: &Channel<T>,
+) -> Result<T::AccountId, DispatchError> {
+    let sender = ensure_signed(origin)?;
+    let required_permissions = vec![ChannelActionPermission::ManageCreatorTokenBuyback];
+    ensure_actor_has_channel_permissions::<T>(&sender, actor, channel, &required_permissions)?;
+    Ok(sender)
+}
+
+// Channel subscriptions
+pub fn ensure_actor_authorized_to_manage_subscription_tiers<T: Config>(
+    origin: T::Origin,
+    actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+    channel: &Channel<T>,
+) -> Result<T::AccountId, DispatchError> {
+    let sender = ensure_signed(origin)?;
+    let required_permissions = vec![ChannelActionPermission::ManageSubscriptionTiers];
     ensure_actor_has_channel_permissions::<T>(&sender, actor, channel, &required_permissions)?;
     Ok(sender)
 }
@@ -0,0 +1,23 @@
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use super::SubscriptionTierId;
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+    /// Content runtime API.
+    pub trait ContentApi<ChannelId, MemberId, BlockNumber>
+    where
+        ChannelId: Codec,
+        MemberId: Codec,
+        BlockNumber: Codec,
+    {
+        /// Whether the member is subscribed to the channel subscription tier at the block.
+        fn is_subscribed(
+            channel_id: ChannelId,
+            member_id: MemberId,
+            tier_id: SubscriptionTierId,
+            block: BlockNumber,
+        ) -> bool;
+    }
+}
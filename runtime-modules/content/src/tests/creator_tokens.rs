@@ -1,11 +1,18 @@
+mod agent_caps;
+mod airdrop;
+mod auto_revenue_split;
+mod buyback;
 mod claim_patronage_credit;
 mod deissue;
 mod finalize_creator_token_sale;
 mod finalize_revenue_split;
+mod frozen_status;
 mod init_token_sale;
 mod issue;
+mod issue_poll;
 mod issue_revenue_split;
 mod issuer_transfer;
 mod make_permissionless;
 mod reduce_patronage_rate;
+mod time_weighted_splits;
 mod update_upcoming_sale;
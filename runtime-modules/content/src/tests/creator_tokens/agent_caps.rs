@@ -0,0 +1,331 @@
+#![cfg(test)]
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::*;
+use sp_arithmetic::PerThing;
+
+fn caps() -> CreatorTokenAgentCaps<Test> {
+    CreatorTokenAgentCaps::<Test>::default()
+}
+
+fn setup_member_channel_with_collaborator_token_holder(permissions: &[ChannelActionPermission]) {
+    ContentTest::with_member_channel()
+        .with_agent_permissions(permissions)
+        .setup();
+    IssueCreatorTokenFixture::default()
+        .with_initial_allocation_to(COLLABORATOR_MEMBER_ID)
+        .call_and_assert(Ok(()));
+}
+
+#[test]
+fn unsuccessful_set_creator_token_agent_caps_non_existing_channel() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        SetCreatorTokenAgentCapsFixture::default()
+            .call_and_assert(Err(Error::<Test>::ChannelDoesNotExist.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_set_creator_token_agent_caps_by_collaborator() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_all_agent_permissions_except(&[])
+            .setup();
+        SetCreatorTokenAgentCapsFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .call_and_assert(Err(Error::<Test>::MemberAuthFailed.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_set_creator_token_agent_caps_for_channel_owner() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        SetCreatorTokenAgentCapsFixture::default()
+            .with_member_id(DEFAULT_MEMBER_ID)
+            .call_and_assert(Err(
+                Error::<Test>::CreatorTokenAgentCapsTargetIsChannelOwner.into(),
+            ));
+    })
+}
+
+#[test]
+fn unsuccessful_set_creator_token_agent_caps_during_transfer() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        InitializeChannelTransferFixture::default()
+            .with_new_member_channel_owner(THIRD_MEMBER_ID)
+            .call_and_assert(Ok(()));
+        SetCreatorTokenAgentCapsFixture::default()
+            .call_and_assert(Err(Error::<Test>::InvalidChannelTransferStatus.into()));
+    })
+}
+
+#[test]
+fn successful_set_member_channel_creator_token_agent_caps_by_owner() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        SetCreatorTokenAgentCapsFixture::default()
+            .with_caps(Some(CreatorTokenAgentCaps::<Test> {
+                issuer_transfer_amount: Some(DEFAULT_ISSUER_TRANSFER_AMOUNT),
+                ..caps()
+            }))
+            .call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn successful_set_curator_channel_creator_token_agent_caps_by_lead() {
+    with_default_mock_builder(|| {
+        ContentTest::with_curator_channel().setup();
+        SetCreatorTokenAgentCapsFixture::default()
+            .with_sender(LEAD_ACCOUNT_ID)
+            .with_member_id(DEFAULT_CURATOR_MEMBER_ID)
+            .call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn successful_remove_creator_token_agent_caps() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        SetCreatorTokenAgentCapsFixture::default().call_and_assert(Ok(()));
+        SetCreatorTokenAgentCapsFixture::default()
+            .with_caps(None)
+            .call_and_assert(Ok(()));
+    })
+}
+
+// Token sale
+
+#[test]
+fn unsuccessful_init_creator_token_sale_by_collaborator_exceeding_cap() {
+    with_default_mock_builder(|| {
+        setup_member_channel_with_collaborator_token_holder(&[
+            ChannelActionPermission::InitAndManageCreatorTokenSale,
+        ]);
+        SetCreatorTokenAgentCapsFixture::default()
+            .with_caps(Some(CreatorTokenAgentCaps::<Test> {
+                token_sale_quantity: Some(DEFAULT_CREATOR_TOKEN_ISSUANCE - 1),
+                ..caps()
+            }))
+            .call_and_assert(Ok(()));
+        InitCreatorTokenSaleFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Err(Error::<Test>::ChannelAgentTokenSaleCapExceeded.into()));
+    })
+}
+
+#[test]
+fn successful_init_creator_token_sale_by_collaborator_within_cap() {
+    with_default_mock_builder(|| {
+        setup_member_channel_with_collaborator_token_holder(&[
+            ChannelActionPermission::InitAndManageCreatorTokenSale,
+        ]);
+        SetCreatorTokenAgentCapsFixture::default()
+            .with_caps(Some(CreatorTokenAgentCaps::<Test> {
+                token_sale_quantity: Some(DEFAULT_CREATOR_TOKEN_ISSUANCE),
+                ..caps()
+            }))
+            .call_and_assert(Ok(()));
+        InitCreatorTokenSaleFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Ok(()));
+        assert_eq!(
+            Content::creator_token_agent_caps(ChannelId::one(), COLLABORATOR_MEMBER_ID)
+                .unwrap()
+                .token_sale_quantity,
+            Some(0)
+        );
+    })
+}
+
+// Issuer transfer
+
+#[test]
+fn unsuccessful_creator_token_issuer_transfer_by_collaborator_exceeding_cap() {
+    with_default_mock_builder(|| {
+        setup_member_channel_with_collaborator_token_holder(&[
+            ChannelActionPermission::CreatorTokenIssuerTransfer,
+        ]);
+        SetCreatorTokenAgentCapsFixture::default()
+            .with_caps(Some(CreatorTokenAgentCaps::<Test> {
+                issuer_transfer_amount: Some(DEFAULT_ISSUER_TRANSFER_AMOUNT - 1),
+                ..caps()
+            }))
+            .call_and_assert(Ok(()));
+        CreatorTokenIssuerTransferFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Err(
+                Error::<Test>::ChannelAgentIssuerTransferCapExceeded.into()
+            ));
+    })
+}
+
+#[test]
+fn successful_creator_token_issuer_transfers_by_collaborator_until_cap_is_used() {
+    with_default_mock_builder(|| {
+        setup_member_channel_with_collaborator_token_holder(&[
+            ChannelActionPermission::CreatorTokenIssuerTransfer,
+        ]);
+        SetCreatorTokenAgentCapsFixture::default()
+            .with_caps(Some(CreatorTokenAgentCaps::<Test> {
+                issuer_transfer_amount: Some(2 * DEFAULT_ISSUER_TRANSFER_AMOUNT),
+                ..caps()
+            }))
+            .call_and_assert(Ok(()));
+        let fixture = CreatorTokenIssuerTransferFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID));
+        fixture.call_and_assert(Ok(()));
+        fixture.call_and_assert(Ok(()));
+        fixture.call_and_assert(Err(
+            Error::<Test>::ChannelAgentIssuerTransferCapExceeded.into()
+        ));
+    })
+}
+
+#[test]
+fn successful_creator_token_issuer_transfer_by_owner_not_affected_by_agent_caps() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_agent_permissions(&[ChannelActionPermission::CreatorTokenIssuerTransfer])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetCreatorTokenAgentCapsFixture::default()
+            .with_caps(Some(CreatorTokenAgentCaps::<Test> {
+                issuer_transfer_amount: Some(0),
+                ..caps()
+            }))
+            .call_and_assert(Ok(()));
+        CreatorTokenIssuerTransferFixture::default().call_and_assert(Ok(()));
+    })
+}
+
+// Revenue splits
+
+#[test]
+fn unsuccessful_issue_revenue_split_by_collaborator_exceeding_cap() {
+    with_default_mock_builder(|| {
+        setup_member_channel_with_collaborator_token_holder(&[
+            ChannelActionPermission::ManageRevenueSplits,
+        ]);
+        increase_account_balance_helper(
+            ContentTreasury::<Test>::account_for_channel(ChannelId::one()),
+            DEFAULT_PAYOUT_EARNED,
+        );
+        SetCreatorTokenAgentCapsFixture::default()
+            .with_caps(Some(CreatorTokenAgentCaps::<Test> {
+                revenue_split_allocation: Some(
+                    DEFAULT_SPLIT_RATE.mul_floor(DEFAULT_PAYOUT_EARNED) - 1,
+                ),
+                ..caps()
+            }))
+            .call_and_assert(Ok(()));
+        IssueRevenueSplitFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Err(
+                Error::<Test>::ChannelAgentRevenueSplitCapExceeded.into()
+            ));
+    })
+}
+
+#[test]
+fn successful_issue_revenue_split_by_collaborator_within_cap() {
+    with_default_mock_builder(|| {
+        setup_member_channel_with_collaborator_token_holder(&[
+            ChannelActionPermission::ManageRevenueSplits,
+        ]);
+        increase_account_balance_helper(
+            ContentTreasury::<Test>::account_for_channel(ChannelId::one()),
+            DEFAULT_PAYOUT_EARNED,
+        );
+        SetCreatorTokenAgentCapsFixture::default()
+            .with_caps(Some(CreatorTokenAgentCaps::<Test> {
+                revenue_split_allocation: Some(DEFAULT_PAYOUT_EARNED),
+                ..caps()
+            }))
+            .call_and_assert(Ok(()));
+        IssueRevenueSplitFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Ok(()));
+        assert_eq!(
+            Content::creator_token_agent_caps(ChannelId::one(), COLLABORATOR_MEMBER_ID)
+                .unwrap()
+                .revenue_split_allocation,
+            Some(DEFAULT_PAYOUT_EARNED - DEFAULT_SPLIT_RATE.mul_floor(DEFAULT_PAYOUT_EARNED))
+        );
+    })
+}
+
+// Patronage
+
+#[test]
+fn unsuccessful_claim_creator_token_patronage_credit_by_collaborator_exceeding_cap() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_agent_permissions(&[ChannelActionPermission::ClaimCreatorTokenPatronage])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetCreatorTokenAgentCapsFixture::default()
+            .with_caps(Some(CreatorTokenAgentCaps::<Test> {
+                patronage_credit_amount: Some(0),
+                ..caps()
+            }))
+            .call_and_assert(Ok(()));
+        run_to_block(System::block_number() + DEFAULT_CREATOR_TOKEN_SALE_DURATION);
+        ClaimCreatorTokenPatronageCreditFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Err(
+                Error::<Test>::ChannelAgentPatronageCreditCapExceeded.into()
+            ));
+    })
+}
+
+#[test]
+fn unsuccessful_reduce_creator_token_patronage_rate_by_collaborator_below_min() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_agent_permissions(&[ChannelActionPermission::ReduceCreatorTokenPatronageRate])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetCreatorTokenAgentCapsFixture::default()
+            .with_caps(Some(CreatorTokenAgentCaps::<Test> {
+                min_patronage_rate: Some(DEFAULT_PATRONAGE_RATE),
+                ..caps()
+            }))
+            .call_and_assert(Ok(()));
+        ReduceCreatorTokenPatronageRateFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Err(Error::<Test>::ChannelAgentPatronageRateBelowMin.into()));
+    })
+}
+
+#[test]
+fn successful_reduce_creator_token_patronage_rate_by_collaborator_above_min() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_agent_permissions(&[ChannelActionPermission::ReduceCreatorTokenPatronageRate])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetCreatorTokenAgentCapsFixture::default()
+            .with_caps(Some(CreatorTokenAgentCaps::<Test> {
+                min_patronage_rate: Some(YearlyRate(Permill::zero())),
+                ..caps()
+            }))
+            .call_and_assert(Ok(()));
+        ReduceCreatorTokenPatronageRateFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Ok(()));
+    })
+}
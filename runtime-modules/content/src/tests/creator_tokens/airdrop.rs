@@ -0,0 +1,121 @@
+#![cfg(test)]
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::*;
+
+#[test]
+fn unsuccessful_issue_creator_token_airdrop_non_existing_channel() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        IssueCreatorTokenAirdropFixture::default()
+            .call_and_assert(Err(Error::<Test>::ChannelDoesNotExist.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_issue_creator_token_airdrop_token_not_issued() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenAirdropFixture::default()
+            .call_and_assert(Err(Error::<Test>::CreatorTokenNotIssued.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_issue_creator_token_airdrop_with_insufficient_issuer_balance() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        IssueCreatorTokenAirdropFixture::default()
+            .with_total_amount(DEFAULT_CREATOR_TOKEN_ISSUANCE + 1)
+            .call_and_assert(Err(
+                project_token::Error::<Test>::InsufficientTransferrableBalance.into(),
+            ));
+    })
+}
+
+#[test]
+fn unsuccessful_issue_member_channel_creator_token_airdrop_by_collaborator_without_permissions() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_all_agent_permissions_except(&[
+                ChannelActionPermission::CreatorTokenIssuerTransfer,
+            ])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        IssueCreatorTokenAirdropFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Err(
+                Error::<Test>::ChannelAgentInsufficientPermissions.into()
+            ));
+    })
+}
+
+#[test]
+fn successful_issue_member_channel_creator_token_airdrop_by_collaborator() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_agent_permissions(&[ChannelActionPermission::CreatorTokenIssuerTransfer])
+            .setup();
+        IssueCreatorTokenFixture::default()
+            .with_initial_allocation_to(COLLABORATOR_MEMBER_ID)
+            .call_and_assert(Ok(()));
+        IssueCreatorTokenAirdropFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn successful_issue_member_channel_creator_token_airdrop_by_owner() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        IssueCreatorTokenAirdropFixture::default().call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn unsuccessful_recover_creator_token_airdrop_not_expired() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        IssueCreatorTokenAirdropFixture::default().call_and_assert(Ok(()));
+        RecoverCreatorTokenAirdropFixture::default()
+            .call_and_assert(Err(project_token::Error::<Test>::AirdropNotExpired.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_recover_member_channel_creator_token_airdrop_by_collaborator_without_permissions() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_all_agent_permissions_except(&[
+                ChannelActionPermission::CreatorTokenIssuerTransfer,
+            ])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        IssueCreatorTokenAirdropFixture::default().call_and_assert(Ok(()));
+        run_to_block(System::block_number() + DEFAULT_TOKEN_AIRDROP_DURATION);
+        RecoverCreatorTokenAirdropFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Err(
+                Error::<Test>::ChannelAgentInsufficientPermissions.into()
+            ));
+    })
+}
+
+#[test]
+fn successful_recover_member_channel_creator_token_airdrop_by_owner() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        IssueCreatorTokenAirdropFixture::default().call_and_assert(Ok(()));
+        run_to_block(System::block_number() + DEFAULT_TOKEN_AIRDROP_DURATION);
+        RecoverCreatorTokenAirdropFixture::default().call_and_assert(Ok(()));
+    })
+}
@@ -0,0 +1,320 @@
+#![cfg(test)]
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::*;
+use common::merkle_tree::helpers::build_merkle_path_helper;
+use frame_support::assert_ok;
+use project_token::types::RevenueSplitState;
+use sp_arithmetic::PerThing;
+use sp_std::collections::btree_map::BTreeMap;
+
+fn default_auto_revenue_split_params() -> AutoRevenueSplitParameters<Test> {
+    AutoRevenueSplitParameters::<Test> {
+        pool_rate: DEFAULT_AUTO_REVENUE_SPLIT_POOL_RATE,
+        threshold: Some(DEFAULT_AUTO_REVENUE_SPLIT_THRESHOLD),
+        period: Some(DEFAULT_AUTO_REVENUE_SPLIT_PERIOD),
+        split_duration: DEFAULT_REVENUE_SPLIT_DURATION,
+    }
+}
+
+fn claim_default_channel_reward() {
+    let payments = create_some_pull_payments_helper();
+    assert_ok!(Content::claim_channel_reward(
+        Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+        ContentActor::Member(DEFAULT_MEMBER_ID),
+        build_merkle_path_helper::<Test, _>(&payments, DEFAULT_PROOF_INDEX),
+        payments[DEFAULT_PROOF_INDEX],
+    ));
+}
+
+fn token_revenue_split_allocation() -> Option<u64> {
+    let token_id = Content::channel_by_id(ChannelId::one())
+        .creator_token_id
+        .unwrap();
+    match project_token::Module::<Test>::token_info_by_id(token_id).revenue_split {
+        RevenueSplitState::Active(split_info) => Some(split_info.allocation),
+        RevenueSplitState::Inactive => None,
+    }
+}
+
+#[test]
+fn unsuccessful_set_channel_auto_revenue_split_non_existing_channel() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        SetChannelAutoRevenueSplitFixture::default()
+            .call_and_assert(Err(Error::<Test>::ChannelDoesNotExist.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_set_channel_auto_revenue_split_token_not_issued() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        SetChannelAutoRevenueSplitFixture::default()
+            .call_and_assert(Err(Error::<Test>::CreatorTokenNotIssued.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_set_member_channel_auto_revenue_split_by_collaborator_without_permissions() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_all_agent_permissions_except(&[ChannelActionPermission::ManageRevenueSplits])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetChannelAutoRevenueSplitFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Err(
+                Error::<Test>::ChannelAgentInsufficientPermissions.into()
+            ));
+    })
+}
+
+#[test]
+fn unsuccessful_set_channel_auto_revenue_split_with_zero_pool_rate() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetChannelAutoRevenueSplitFixture::default()
+            .with_params(Some(AutoRevenueSplitParameters::<Test> {
+                pool_rate: Perbill::zero(),
+                ..default_auto_revenue_split_params()
+            }))
+            .call_and_assert(Err(Error::<Test>::AutoRevenueSplitPoolRateIsZero.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_set_channel_auto_revenue_split_without_trigger() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetChannelAutoRevenueSplitFixture::default()
+            .with_params(Some(AutoRevenueSplitParameters::<Test> {
+                threshold: None,
+                period: None,
+                ..default_auto_revenue_split_params()
+            }))
+            .call_and_assert(Err(Error::<Test>::AutoRevenueSplitTriggerNotProvided.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_set_channel_auto_revenue_split_with_zero_threshold() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetChannelAutoRevenueSplitFixture::default()
+            .with_params(Some(AutoRevenueSplitParameters::<Test> {
+                threshold: Some(0),
+                ..default_auto_revenue_split_params()
+            }))
+            .call_and_assert(Err(Error::<Test>::AutoRevenueSplitThresholdIsZero.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_set_channel_auto_revenue_split_with_zero_period() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetChannelAutoRevenueSplitFixture::default()
+            .with_params(Some(AutoRevenueSplitParameters::<Test> {
+                period: Some(0),
+                ..default_auto_revenue_split_params()
+            }))
+            .call_and_assert(Err(Error::<Test>::AutoRevenueSplitPeriodIsZero.into()));
+    })
+}
+
+#[test]
+fn successful_set_member_channel_auto_revenue_split_by_collaborator() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_agent_permissions(&[ChannelActionPermission::ManageRevenueSplits])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetChannelAutoRevenueSplitFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn successful_set_member_channel_auto_revenue_split_by_owner() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetChannelAutoRevenueSplitFixture::default().call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn successful_remove_channel_auto_revenue_split() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetChannelAutoRevenueSplitFixture::default().call_and_assert(Ok(()));
+        SetChannelAutoRevenueSplitFixture::default()
+            .with_params(None)
+            .call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn auto_revenue_split_pool_credited_on_channel_reward_claim() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_claimable_reward()
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetChannelAutoRevenueSplitFixture::default()
+            .with_params(Some(AutoRevenueSplitParameters::<Test> {
+                threshold: None,
+                ..default_auto_revenue_split_params()
+            }))
+            .call_and_assert(Ok(()));
+
+        claim_default_channel_reward();
+
+        let routed_amount = DEFAULT_AUTO_REVENUE_SPLIT_POOL_RATE.mul_floor(DEFAULT_PAYOUT_CLAIMED);
+        assert_eq!(
+            Content::auto_revenue_split_pool(ChannelId::one())
+                .unwrap()
+                .pending_amount,
+            routed_amount
+        );
+        assert_eq!(token_revenue_split_allocation(), None);
+        assert!(System::events().iter().any(|record| record.event
+            == MetaEvent::Content(RawEvent::AutoRevenueSplitPoolCredited(
+                ChannelId::one(),
+                routed_amount
+            ))));
+    })
+}
+
+#[test]
+fn auto_revenue_split_issued_once_threshold_reached() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_claimable_reward()
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        let routed_amount = DEFAULT_AUTO_REVENUE_SPLIT_POOL_RATE.mul_floor(DEFAULT_PAYOUT_CLAIMED);
+        SetChannelAutoRevenueSplitFixture::default()
+            .with_params(Some(AutoRevenueSplitParameters::<Test> {
+                threshold: Some(routed_amount),
+                period: None,
+                ..default_auto_revenue_split_params()
+            }))
+            .call_and_assert(Ok(()));
+
+        claim_default_channel_reward();
+        // split is issued at the beginning of the next block
+        assert_eq!(token_revenue_split_allocation(), None);
+
+        run_to_block(System::block_number() + 1);
+
+        assert_eq!(
+            Content::auto_revenue_split_pool(ChannelId::one())
+                .unwrap()
+                .pending_amount,
+            0
+        );
+        assert_eq!(token_revenue_split_allocation(), Some(routed_amount));
+        assert!(System::events().iter().any(|record| record.event
+            == MetaEvent::Content(RawEvent::AutoRevenueSplitIssued(
+                ChannelId::one(),
+                routed_amount
+            ))));
+    })
+}
+
+#[test]
+fn auto_revenue_split_issued_once_period_elapsed() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_claimable_reward()
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetChannelAutoRevenueSplitFixture::default()
+            .with_params(Some(AutoRevenueSplitParameters::<Test> {
+                threshold: None,
+                ..default_auto_revenue_split_params()
+            }))
+            .call_and_assert(Ok(()));
+        let split_block = System::block_number() + DEFAULT_AUTO_REVENUE_SPLIT_PERIOD;
+
+        claim_default_channel_reward();
+        run_to_block(split_block - 1);
+        assert_eq!(token_revenue_split_allocation(), None);
+
+        run_to_block(split_block);
+
+        let routed_amount = DEFAULT_AUTO_REVENUE_SPLIT_POOL_RATE.mul_floor(DEFAULT_PAYOUT_CLAIMED);
+        let pool = Content::auto_revenue_split_pool(ChannelId::one()).unwrap();
+        assert_eq!(pool.pending_amount, 0);
+        assert_eq!(
+            pool.next_split_at,
+            Some(split_block + DEFAULT_AUTO_REVENUE_SPLIT_PERIOD)
+        );
+        assert_eq!(token_revenue_split_allocation(), Some(routed_amount));
+    })
+}
+
+#[test]
+fn auto_revenue_split_pool_credited_with_nft_royalty() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+        let royalty = Perbill::from_percent(DEFAULT_ROYALTY).mul_floor(DEFAULT_NFT_PRICE);
+        increase_account_balance_helper(SECOND_MEMBER_ACCOUNT_ID, ed() + DEFAULT_NFT_PRICE);
+        ContentTest::default().with_video().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetChannelAutoRevenueSplitFixture::default()
+            .with_params(Some(AutoRevenueSplitParameters::<Test> {
+                threshold: None,
+                ..default_auto_revenue_split_params()
+            }))
+            .call_and_assert(Ok(()));
+        IssueNftFixture::default()
+            .with_params(NftIssuanceParameters::<Test> {
+                royalty: Some(Perbill::from_percent(DEFAULT_ROYALTY)),
+                non_channel_owner: Some(COLLABORATOR_MEMBER_ID),
+                init_transactional_status: InitTransactionalStatus::<Test>::BuyNow(
+                    DEFAULT_NFT_PRICE,
+                ),
+                ..Default::default()
+            })
+            .call_and_assert(Ok(()));
+
+        assert_ok!(Content::buy_nft(
+            Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+            VideoId::one(),
+            SECOND_MEMBER_ID,
+            DEFAULT_NFT_PRICE,
+        ));
+
+        assert_eq!(
+            Content::auto_revenue_split_pool(ChannelId::one())
+                .unwrap()
+                .pending_amount,
+            DEFAULT_AUTO_REVENUE_SPLIT_POOL_RATE.mul_floor(royalty)
+        );
+    })
+}
+
+#[test]
+fn auto_revenue_split_pool_removed_on_creator_token_deissue() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default()
+            .with_initial_allocation(BTreeMap::new())
+            .call_and_assert(Ok(()));
+        SetChannelAutoRevenueSplitFixture::default().call_and_assert(Ok(()));
+        DeissueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        assert!(Content::auto_revenue_split_pool(ChannelId::one()).is_none());
+    })
+}
@@ -0,0 +1,202 @@
+#![cfg(test)]
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::*;
+
+fn fund_default_channel_reward_account() {
+    increase_account_balance_helper(
+        ContentTreasury::<Test>::account_for_channel(ChannelId::one()),
+        DEFAULT_PAYOUT_EARNED,
+    );
+}
+
+#[test]
+fn unsuccessful_start_creator_token_buyback_non_existing_channel() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        StartCreatorTokenBuybackFixture::default()
+            .call_and_assert(Err(Error::<Test>::ChannelDoesNotExist.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_start_creator_token_buyback_token_not_issued() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        fund_default_channel_reward_account();
+        StartCreatorTokenBuybackFixture::default()
+            .call_and_assert(Err(Error::<Test>::CreatorTokenNotIssued.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_start_creator_token_buyback_with_budget_exceeding_withdrawable_balance() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        fund_default_channel_reward_account();
+        StartCreatorTokenBuybackFixture::default()
+            .with_budget(DEFAULT_PAYOUT_EARNED + 1)
+            .call_and_assert(Err(
+                Error::<Test>::WithdrawalAmountExceedsChannelAccountWithdrawableBalance.into(),
+            ));
+    })
+}
+
+#[test]
+fn unsuccessful_start_creator_token_buyback_during_transfer() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        fund_default_channel_reward_account();
+        InitializeChannelTransferFixture::default()
+            .with_new_member_channel_owner(THIRD_MEMBER_ID)
+            .call_and_assert(Ok(()));
+        StartCreatorTokenBuybackFixture::default()
+            .call_and_assert(Err(Error::<Test>::InvalidChannelTransferStatus.into()));
+    })
+}
+
+// Member channel
+
+#[test]
+fn unsuccessful_start_member_channel_creator_token_buyback_by_collaborator_without_permissions() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_all_agent_permissions_except(&[
+                ChannelActionPermission::ManageCreatorTokenBuyback,
+            ])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        fund_default_channel_reward_account();
+        StartCreatorTokenBuybackFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Err(
+                Error::<Test>::ChannelAgentInsufficientPermissions.into()
+            ));
+    })
+}
+
+#[test]
+fn successful_start_member_channel_creator_token_buyback_by_collaborator() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_agent_permissions(&[ChannelActionPermission::ManageCreatorTokenBuyback])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        fund_default_channel_reward_account();
+        StartCreatorTokenBuybackFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn successful_start_member_channel_creator_token_buyback_by_owner() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        fund_default_channel_reward_account();
+        StartCreatorTokenBuybackFixture::default().call_and_assert(Ok(()));
+    })
+}
+
+// Curator channel
+
+#[test]
+fn successful_start_curator_channel_creator_token_buyback_by_curator() {
+    with_default_mock_builder(|| {
+        ContentTest::with_curator_channel()
+            .with_agent_permissions(&[ChannelActionPermission::ManageCreatorTokenBuyback])
+            .setup();
+        IssueCreatorTokenFixture::default()
+            .with_sender(LEAD_ACCOUNT_ID)
+            .with_actor(ContentActor::Lead)
+            .with_initial_allocation_to(LEAD_MEMBER_ID)
+            .call_and_assert(Ok(()));
+        fund_default_channel_reward_account();
+        StartCreatorTokenBuybackFixture::default()
+            .with_sender(DEFAULT_CURATOR_ACCOUNT_ID)
+            .with_actor(default_curator_actor())
+            .call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn successful_start_curator_channel_creator_token_buyback_by_lead() {
+    with_default_mock_builder(|| {
+        ContentTest::with_curator_channel().setup();
+        IssueCreatorTokenFixture::default()
+            .with_sender(LEAD_ACCOUNT_ID)
+            .with_actor(ContentActor::Lead)
+            .with_initial_allocation_to(LEAD_MEMBER_ID)
+            .call_and_assert(Ok(()));
+        fund_default_channel_reward_account();
+        StartCreatorTokenBuybackFixture::default()
+            .with_sender(LEAD_ACCOUNT_ID)
+            .with_actor(ContentActor::Lead)
+            .call_and_assert(Ok(()));
+    })
+}
+
+// Finalize
+
+#[test]
+fn unsuccessful_finalize_creator_token_buyback_token_not_issued() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        FinalizeCreatorTokenBuybackFixture::default()
+            .call_and_assert(Err(Error::<Test>::CreatorTokenNotIssued.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_finalize_creator_token_buyback_program_not_ended() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        fund_default_channel_reward_account();
+        StartCreatorTokenBuybackFixture::default().call_and_assert(Ok(()));
+        FinalizeCreatorTokenBuybackFixture::default()
+            .call_and_assert(Err(
+                project_token::Error::<Test>::BuybackProgramNotEnded.into()
+            ));
+    })
+}
+
+#[test]
+fn unsuccessful_finalize_member_channel_creator_token_buyback_by_collaborator_without_permissions()
+{
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_all_agent_permissions_except(&[
+                ChannelActionPermission::ManageCreatorTokenBuyback,
+            ])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        fund_default_channel_reward_account();
+        StartCreatorTokenBuybackFixture::default().call_and_assert(Ok(()));
+        run_to_block(1 + DEFAULT_TOKEN_BUYBACK_DURATION);
+        FinalizeCreatorTokenBuybackFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Err(
+                Error::<Test>::ChannelAgentInsufficientPermissions.into()
+            ));
+    })
+}
+
+#[test]
+fn successful_finalize_member_channel_creator_token_buyback_by_owner() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        fund_default_channel_reward_account();
+        StartCreatorTokenBuybackFixture::default().call_and_assert(Ok(()));
+        run_to_block(1 + DEFAULT_TOKEN_BUYBACK_DURATION);
+        FinalizeCreatorTokenBuybackFixture::default().call_and_assert(Ok(()));
+    })
+}
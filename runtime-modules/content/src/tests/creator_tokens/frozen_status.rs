@@ -0,0 +1,189 @@
+#![cfg(test)]
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::*;
+use sp_runtime::DispatchError;
+
+#[test]
+fn unsuccessful_set_creator_token_frozen_status_non_existing_channel() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        SetCreatorTokenFrozenStatusFixture::default()
+            .call_and_assert(Err(Error::<Test>::ChannelDoesNotExist.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_set_creator_token_frozen_status_token_not_issued() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        SetCreatorTokenFrozenStatusFixture::default()
+            .call_and_assert(Err(Error::<Test>::CreatorTokenNotIssued.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_set_creator_token_frozen_status_non_existing_token_account() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetCreatorTokenFrozenStatusFixture::default()
+            .with_member_id(Some(SECOND_MEMBER_ID))
+            .call_and_assert(Err(
+                project_token::Error::<Test>::AccountInformationDoesNotExist.into(),
+            ));
+    })
+}
+
+// Member channel
+
+#[test]
+fn unsuccessful_set_member_channel_creator_token_frozen_status_by_collaborator_without_permissions()
+{
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_all_agent_permissions_except(&[
+                ChannelActionPermission::ManageCreatorTokenCompliance,
+            ])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetCreatorTokenFrozenStatusFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Err(
+                Error::<Test>::ChannelAgentInsufficientPermissions.into()
+            ));
+    })
+}
+
+#[test]
+fn successful_set_member_channel_creator_token_frozen_status_by_collaborator() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_agent_permissions(&[ChannelActionPermission::ManageCreatorTokenCompliance])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetCreatorTokenFrozenStatusFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn successful_set_member_channel_creator_token_frozen_status_by_owner() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetCreatorTokenFrozenStatusFixture::default().call_and_assert(Ok(()));
+        SetCreatorTokenFrozenStatusFixture::default()
+            .with_frozen(false)
+            .call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn successful_pause_member_channel_creator_token_by_owner() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetCreatorTokenFrozenStatusFixture::default()
+            .with_member_id(None)
+            .call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn unsuccessful_creator_token_issuer_transfer_with_token_paused() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetCreatorTokenFrozenStatusFixture::default()
+            .with_member_id(None)
+            .call_and_assert(Ok(()));
+        CreatorTokenIssuerTransferFixture::default()
+            .call_and_assert(Err(project_token::Error::<Test>::TokenPaused.into()));
+    })
+}
+
+// Council override
+
+#[test]
+fn unsuccessful_set_creator_token_frozen_status_as_council_with_invalid_origin() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetCreatorTokenFrozenStatusFixture::as_council()
+            .with_sender(DEFAULT_MEMBER_ACCOUNT_ID)
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    })
+}
+
+#[test]
+fn successful_set_creator_token_frozen_status_as_council() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetCreatorTokenFrozenStatusFixture::as_council().call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn unsuccessful_unfreeze_creator_token_account_frozen_by_council_by_owner() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetCreatorTokenFrozenStatusFixture::as_council().call_and_assert(Ok(()));
+        SetCreatorTokenFrozenStatusFixture::default()
+            .with_frozen(false)
+            .call_and_assert(Err(Error::<Test>::CreatorTokenFrozenByCouncil.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_unpause_creator_token_paused_by_council_by_owner() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetCreatorTokenFrozenStatusFixture::as_council()
+            .with_member_id(None)
+            .call_and_assert(Ok(()));
+        SetCreatorTokenFrozenStatusFixture::default()
+            .with_member_id(None)
+            .with_frozen(false)
+            .call_and_assert(Err(Error::<Test>::CreatorTokenFrozenByCouncil.into()));
+    })
+}
+
+#[test]
+fn successful_unfreeze_creator_token_account_by_owner_after_council_unfreeze() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetCreatorTokenFrozenStatusFixture::as_council().call_and_assert(Ok(()));
+        SetCreatorTokenFrozenStatusFixture::as_council()
+            .with_frozen(false)
+            .call_and_assert(Ok(()));
+        SetCreatorTokenFrozenStatusFixture::default().call_and_assert(Ok(()));
+        SetCreatorTokenFrozenStatusFixture::default()
+            .with_frozen(false)
+            .call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn successful_unpause_creator_token_as_council_overriding_issuer() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetCreatorTokenFrozenStatusFixture::default()
+            .with_member_id(None)
+            .call_and_assert(Ok(()));
+        SetCreatorTokenFrozenStatusFixture::as_council()
+            .with_member_id(None)
+            .with_frozen(false)
+            .call_and_assert(Ok(()));
+        CreatorTokenIssuerTransferFixture::default().call_and_assert(Ok(()));
+    })
+}
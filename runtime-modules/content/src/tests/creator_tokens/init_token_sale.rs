@@ -142,6 +142,7 @@ fn init_token_sale_fails_during_channel_transfer() {
                     vesting_schedule_params: None,
                     cap_per_member: None,
                     metadata: None,
+                    tiers: vec![],
                 },
             ),
             Error::<Test>::InvalidChannelTransferStatus,
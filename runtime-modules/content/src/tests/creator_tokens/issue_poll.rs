@@ -0,0 +1,79 @@
+#![cfg(test)]
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::*;
+
+#[test]
+fn unsuccessful_issue_creator_token_poll_non_existing_channel() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        IssueCreatorTokenPollFixture::default()
+            .call_and_assert(Err(Error::<Test>::ChannelDoesNotExist.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_issue_creator_token_poll_token_not_issued() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenPollFixture::default()
+            .call_and_assert(Err(Error::<Test>::CreatorTokenNotIssued.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_issue_creator_token_poll_with_invalid_alternatives() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        IssueCreatorTokenPollFixture::default()
+            .with_alternatives(1)
+            .call_and_assert(Err(
+                project_token::Error::<Test>::InvalidNumberOfTokenPollAlternatives.into(),
+            ));
+    })
+}
+
+// Member channel
+
+#[test]
+fn unsuccessful_issue_member_channel_creator_token_poll_by_collaborator_without_permissions() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_all_agent_permissions_except(&[ChannelActionPermission::ManageCreatorTokenPolls])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        IssueCreatorTokenPollFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Err(
+                Error::<Test>::ChannelAgentInsufficientPermissions.into()
+            ));
+    })
+}
+
+#[test]
+fn successful_issue_member_channel_creator_token_poll_by_collaborator() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_agent_permissions(&[ChannelActionPermission::ManageCreatorTokenPolls])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        IssueCreatorTokenPollFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn successful_issue_member_channel_creator_token_poll_by_owner() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        IssueCreatorTokenPollFixture::default()
+            .with_alternatives(3)
+            .call_and_assert(Ok(()));
+    })
+}
@@ -0,0 +1,88 @@
+#![cfg(test)]
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::*;
+
+#[test]
+fn unsuccessful_set_creator_token_time_weighted_splits_non_existing_channel() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        SetCreatorTokenTimeWeightedSplitsFixture::default()
+            .call_and_assert(Err(Error::<Test>::ChannelDoesNotExist.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_set_creator_token_time_weighted_splits_token_not_issued() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        SetCreatorTokenTimeWeightedSplitsFixture::default()
+            .call_and_assert(Err(Error::<Test>::CreatorTokenNotIssued.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_set_creator_token_time_weighted_splits_with_zero_lookback() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetCreatorTokenTimeWeightedSplitsFixture::default()
+            .with_lookback(Some(0))
+            .call_and_assert(Err(
+                project_token::Error::<Test>::TimeWeightedSplitsLookbackIsZero.into(),
+            ));
+    })
+}
+
+#[test]
+fn unsuccessful_set_member_channel_creator_token_time_weighted_splits_by_collaborator_without_permissions(
+) {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_all_agent_permissions_except(&[ChannelActionPermission::ManageRevenueSplits])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetCreatorTokenTimeWeightedSplitsFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Err(
+                Error::<Test>::ChannelAgentInsufficientPermissions.into()
+            ));
+    })
+}
+
+#[test]
+fn successful_set_member_channel_creator_token_time_weighted_splits_by_collaborator() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_agent_permissions(&[ChannelActionPermission::ManageRevenueSplits])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetCreatorTokenTimeWeightedSplitsFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn successful_set_member_channel_creator_token_time_weighted_splits_by_owner() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetCreatorTokenTimeWeightedSplitsFixture::default().call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn successful_disable_member_channel_creator_token_time_weighted_splits_by_owner() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        SetCreatorTokenTimeWeightedSplitsFixture::default().call_and_assert(Ok(()));
+        SetCreatorTokenTimeWeightedSplitsFixture::default()
+            .with_lookback(None)
+            .call_and_assert(Ok(()));
+    })
+}
@@ -2593,6 +2593,7 @@ impl InitCreatorTokenSaleFixture {
                 vesting_schedule_params: None,
                 cap_per_member: None,
                 metadata: None,
+                tiers: vec![],
             },
         }
     }
@@ -2910,6 +2911,608 @@ impl IssueRevenueSplitFixture {
     }
 }
 
+pub struct IssueCreatorTokenPollFixture {
+    sender: AccountId,
+    actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
+    channel_id: ChannelId,
+    alternatives: u32,
+}
+
+impl IssueCreatorTokenPollFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: DEFAULT_MEMBER_ACCOUNT_ID,
+            actor: ContentActor::Member(DEFAULT_MEMBER_ID),
+            channel_id: ChannelId::one(),
+            alternatives: 2,
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_actor(self, actor: ContentActor<CuratorGroupId, CuratorId, MemberId>) -> Self {
+        Self { actor, ..self }
+    }
+
+    pub fn with_alternatives(self, alternatives: u32) -> Self {
+        Self {
+            alternatives,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let origin = Origin::signed(self.sender);
+        let token_id = Content::channel_by_id(self.channel_id).creator_token_id;
+
+        let actual_result = Content::issue_creator_token_poll(
+            origin,
+            self.actor,
+            self.channel_id,
+            project_token::types::TokenPollParams {
+                metadata: b"poll".to_vec(),
+                alternatives: self.alternatives,
+                start: None,
+                duration: DEFAULT_TOKEN_POLL_DURATION,
+            },
+        );
+
+        if expected_result.is_ok() {
+            assert_ok!(actual_result);
+            let poll = token_id
+                .and_then(project_token::Module::<Test>::token_poll_by_token_id)
+                .unwrap();
+            assert_eq!(poll.tallies.len(), self.alternatives as usize);
+        } else {
+            assert_noop!(actual_result, expected_result.err().unwrap());
+        }
+    }
+}
+
+pub struct IssueCreatorTokenAirdropFixture {
+    sender: AccountId,
+    actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
+    channel_id: ChannelId,
+    total_amount: u64,
+}
+
+impl IssueCreatorTokenAirdropFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: DEFAULT_MEMBER_ACCOUNT_ID,
+            actor: ContentActor::Member(DEFAULT_MEMBER_ID),
+            channel_id: ChannelId::one(),
+            total_amount: DEFAULT_TOKEN_AIRDROP_AMOUNT,
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_actor(self, actor: ContentActor<CuratorGroupId, CuratorId, MemberId>) -> Self {
+        Self { actor, ..self }
+    }
+
+    pub fn with_total_amount(self, total_amount: u64) -> Self {
+        Self {
+            total_amount,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let origin = Origin::signed(self.sender);
+        let token_id = Content::channel_by_id(self.channel_id).creator_token_id;
+        let airdrop_id = project_token::Module::<Test>::next_airdrop_id();
+
+        let actual_result = Content::issue_creator_token_airdrop(
+            origin,
+            self.actor,
+            self.channel_id,
+            project_token::types::AirdropParams {
+                commitment: Hashing::hash_of(b"commitment"),
+                total_amount: self.total_amount,
+                expiry: System::block_number() + DEFAULT_TOKEN_AIRDROP_DURATION,
+            },
+        );
+
+        if expected_result.is_ok() {
+            assert_ok!(actual_result);
+            let airdrop = token_id
+                .and_then(|token_id| {
+                    project_token::Module::<Test>::airdrop_by_id(token_id, airdrop_id)
+                })
+                .unwrap();
+            assert_eq!(airdrop.amount_left, self.total_amount);
+        } else {
+            assert_noop!(actual_result, expected_result.err().unwrap());
+        }
+    }
+}
+
+pub struct RecoverCreatorTokenAirdropFixture {
+    sender: AccountId,
+    actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
+    channel_id: ChannelId,
+    airdrop_id: project_token::types::AirdropId,
+}
+
+impl RecoverCreatorTokenAirdropFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: DEFAULT_MEMBER_ACCOUNT_ID,
+            actor: ContentActor::Member(DEFAULT_MEMBER_ID),
+            channel_id: ChannelId::one(),
+            airdrop_id: 0,
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_actor(self, actor: ContentActor<CuratorGroupId, CuratorId, MemberId>) -> Self {
+        Self { actor, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let origin = Origin::signed(self.sender);
+        let token_id = Content::channel_by_id(self.channel_id).creator_token_id;
+
+        let actual_result = Content::recover_creator_token_airdrop(
+            origin,
+            self.actor,
+            self.channel_id,
+            self.airdrop_id,
+        );
+
+        if expected_result.is_ok() {
+            assert_ok!(actual_result);
+            assert!(token_id
+                .and_then(|token_id| {
+                    project_token::Module::<Test>::airdrop_by_id(token_id, self.airdrop_id)
+                })
+                .is_none());
+        } else {
+            assert_noop!(actual_result, expected_result.err().unwrap());
+        }
+    }
+}
+
+pub struct SetChannelAutoRevenueSplitFixture {
+    sender: AccountId,
+    actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
+    channel_id: ChannelId,
+    params: Option<AutoRevenueSplitParameters<Test>>,
+}
+
+impl SetChannelAutoRevenueSplitFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: DEFAULT_MEMBER_ACCOUNT_ID,
+            actor: ContentActor::Member(DEFAULT_MEMBER_ID),
+            channel_id: ChannelId::one(),
+            params: Some(AutoRevenueSplitParameters::<Test> {
+                pool_rate: DEFAULT_AUTO_REVENUE_SPLIT_POOL_RATE,
+                threshold: Some(DEFAULT_AUTO_REVENUE_SPLIT_THRESHOLD),
+                period: Some(DEFAULT_AUTO_REVENUE_SPLIT_PERIOD),
+                split_duration: DEFAULT_REVENUE_SPLIT_DURATION,
+            }),
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_actor(self, actor: ContentActor<CuratorGroupId, CuratorId, MemberId>) -> Self {
+        Self { actor, ..self }
+    }
+
+    pub fn with_params(self, params: Option<AutoRevenueSplitParameters<Test>>) -> Self {
+        Self { params, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let origin = Origin::signed(self.sender);
+        let pool_pre = Content::auto_revenue_split_pool(self.channel_id);
+
+        let actual_result = Content::set_channel_auto_revenue_split(
+            origin,
+            self.actor,
+            self.channel_id,
+            self.params.clone(),
+        );
+
+        let pool_post = Content::auto_revenue_split_pool(self.channel_id);
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            match self.params.clone() {
+                Some(params) => {
+                    let pool = pool_post.unwrap();
+                    assert_eq!(
+                        pool.next_split_at,
+                        params.period.map(|period| System::block_number() + period)
+                    );
+                    assert_eq!(
+                        pool.pending_amount,
+                        pool_pre.map_or(0, |pool| pool.pending_amount)
+                    );
+                    assert_eq!(pool.params, params);
+                }
+                None => assert!(pool_post.is_none()),
+            }
+            assert_eq!(
+                System::events().last().unwrap().event,
+                MetaEvent::Content(RawEvent::ChannelAutoRevenueSplitUpdated(
+                    self.actor,
+                    self.channel_id,
+                    self.params.clone()
+                ))
+            );
+        } else {
+            assert_eq!(pool_post, pool_pre);
+        }
+    }
+}
+
+pub struct SetCreatorTokenFrozenStatusFixture {
+    origin: Origin,
+    actor: Option<ContentActor<CuratorGroupId, CuratorId, MemberId>>,
+    params: CreatorTokenFrozenStatusParameters<Test>,
+}
+
+impl SetCreatorTokenFrozenStatusFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            actor: Some(ContentActor::Member(DEFAULT_MEMBER_ID)),
+            params: CreatorTokenFrozenStatusParameters::<Test> {
+                channel_id: ChannelId::one(),
+                member_id: Some(DEFAULT_MEMBER_ID),
+                frozen: true,
+            },
+        }
+    }
+
+    // Council override through `set_creator_token_frozen_status_as_council`
+    pub fn as_council() -> Self {
+        Self {
+            origin: Origin::root(),
+            actor: None,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_origin(self, origin: Origin) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        self.with_origin(Origin::signed(sender))
+    }
+
+    pub fn with_actor(self, actor: ContentActor<CuratorGroupId, CuratorId, MemberId>) -> Self {
+        Self {
+            actor: Some(actor),
+            ..self
+        }
+    }
+
+    pub fn with_member_id(self, member_id: Option<MemberId>) -> Self {
+        Self {
+            params: CreatorTokenFrozenStatusParameters::<Test> {
+                member_id,
+                ..self.params
+            },
+            ..self
+        }
+    }
+
+    pub fn with_frozen(self, frozen: bool) -> Self {
+        Self {
+            params: CreatorTokenFrozenStatusParameters::<Test> {
+                frozen,
+                ..self.params
+            },
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result = match self.actor {
+            Some(actor) => Content::set_creator_token_frozen_status(
+                self.origin.clone(),
+                actor,
+                self.params.channel_id,
+                self.params.member_id,
+                self.params.frozen,
+            ),
+            None => Content::set_creator_token_frozen_status_as_council(
+                self.origin.clone(),
+                self.params.clone(),
+            ),
+        };
+
+        if expected_result.is_ok() {
+            assert_ok!(actual_result);
+            let token_id = Content::channel_by_id(self.params.channel_id)
+                .creator_token_id
+                .unwrap();
+            match self.params.member_id {
+                Some(member_id) => {
+                    assert_eq!(
+                        project_token::Module::<Test>::account_info_by_token_and_member(
+                            token_id, member_id
+                        )
+                        .frozen,
+                        self.params.frozen
+                    );
+                }
+                None => {
+                    assert_eq!(
+                        project_token::Module::<Test>::token_info_by_id(token_id).paused,
+                        self.params.frozen
+                    );
+                }
+            }
+            if self.actor.is_none() {
+                assert_eq!(
+                    Content::creator_token_frozen_by_council(
+                        self.params.channel_id,
+                        self.params.member_id
+                    ),
+                    self.params.frozen
+                );
+            }
+        } else {
+            assert_noop!(actual_result, expected_result.err().unwrap());
+        }
+    }
+}
+
+pub struct StartCreatorTokenBuybackFixture {
+    sender: AccountId,
+    actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
+    channel_id: ChannelId,
+    params: BuybackParamsOf<Test>,
+}
+
+impl StartCreatorTokenBuybackFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: DEFAULT_MEMBER_ACCOUNT_ID,
+            actor: ContentActor::Member(DEFAULT_MEMBER_ID),
+            channel_id: ChannelId::one(),
+            params: BuybackParamsOf::<Test> {
+                budget: DEFAULT_PAYOUT_EARNED,
+                max_unit_price: DEFAULT_TOKEN_BUYBACK_MAX_UNIT_PRICE,
+                start: None,
+                duration: DEFAULT_TOKEN_BUYBACK_DURATION,
+            },
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_actor(self, actor: ContentActor<CuratorGroupId, CuratorId, MemberId>) -> Self {
+        Self { actor, ..self }
+    }
+
+    pub fn with_budget(self, budget: u64) -> Self {
+        Self {
+            params: BuybackParamsOf::<Test> {
+                budget,
+                ..self.params
+            },
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let origin = Origin::signed(self.sender);
+        let channel_account = ContentTreasury::<Test>::account_for_channel(self.channel_id);
+        let balance_pre = Balances::<Test>::usable_balance(channel_account);
+
+        let actual_result = Content::start_creator_token_buyback(
+            origin,
+            self.actor,
+            self.channel_id,
+            self.params.clone(),
+        );
+
+        if expected_result.is_ok() {
+            assert_ok!(actual_result);
+            let token_id = Content::channel_by_id(self.channel_id)
+                .creator_token_id
+                .unwrap();
+            let program =
+                project_token::Module::<Test>::buyback_program_by_token_id(token_id).unwrap();
+            assert_eq!(program.budget, self.params.budget);
+            assert_eq!(program.max_unit_price, self.params.max_unit_price);
+            assert_eq!(
+                Balances::<Test>::usable_balance(channel_account),
+                balance_pre - self.params.budget
+            );
+        } else {
+            assert_noop!(actual_result, expected_result.err().unwrap());
+        }
+    }
+}
+
+pub struct FinalizeCreatorTokenBuybackFixture {
+    sender: AccountId,
+    actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
+    channel_id: ChannelId,
+}
+
+impl FinalizeCreatorTokenBuybackFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: DEFAULT_MEMBER_ACCOUNT_ID,
+            actor: ContentActor::Member(DEFAULT_MEMBER_ID),
+            channel_id: ChannelId::one(),
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_actor(self, actor: ContentActor<CuratorGroupId, CuratorId, MemberId>) -> Self {
+        Self { actor, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let origin = Origin::signed(self.sender);
+        let channel_account = ContentTreasury::<Test>::account_for_channel(self.channel_id);
+        let balance_pre = Balances::<Test>::usable_balance(channel_account);
+        let program_pre = Content::channel_by_id(self.channel_id)
+            .creator_token_id
+            .and_then(project_token::Module::<Test>::buyback_program_by_token_id);
+
+        let actual_result =
+            Content::finalize_creator_token_buyback(origin, self.actor, self.channel_id);
+
+        if expected_result.is_ok() {
+            assert_ok!(actual_result);
+            let token_id = Content::channel_by_id(self.channel_id)
+                .creator_token_id
+                .unwrap();
+            assert!(project_token::Module::<Test>::buyback_program_by_token_id(token_id).is_none());
+            let program_pre = program_pre.unwrap();
+            assert_eq!(
+                Balances::<Test>::usable_balance(channel_account),
+                balance_pre + program_pre.budget - program_pre.spent
+            );
+        } else {
+            assert_noop!(actual_result, expected_result.err().unwrap());
+        }
+    }
+}
+
+pub struct SetCreatorTokenTimeWeightedSplitsFixture {
+    sender: AccountId,
+    actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
+    channel_id: ChannelId,
+    lookback: Option<u64>,
+}
+
+impl SetCreatorTokenTimeWeightedSplitsFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: DEFAULT_MEMBER_ACCOUNT_ID,
+            actor: ContentActor::Member(DEFAULT_MEMBER_ID),
+            channel_id: ChannelId::one(),
+            lookback: Some(DEFAULT_TIME_WEIGHTED_SPLITS_LOOKBACK),
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_actor(self, actor: ContentActor<CuratorGroupId, CuratorId, MemberId>) -> Self {
+        Self { actor, ..self }
+    }
+
+    pub fn with_lookback(self, lookback: Option<u64>) -> Self {
+        Self { lookback, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let origin = Origin::signed(self.sender);
+
+        let actual_result = Content::set_creator_token_time_weighted_splits(
+            origin,
+            self.actor,
+            self.channel_id,
+            self.lookback,
+        );
+
+        if expected_result.is_ok() {
+            assert_ok!(actual_result);
+            let token_id = Content::channel_by_id(self.channel_id)
+                .creator_token_id
+                .unwrap();
+            assert_eq!(
+                project_token::Module::<Test>::token_info_by_id(token_id)
+                    .time_weighted_splits
+                    .map(|settings| settings.lookback),
+                self.lookback
+            );
+        } else {
+            assert_noop!(actual_result, expected_result.err().unwrap());
+        }
+    }
+}
+
+pub struct SetCreatorTokenAgentCapsFixture {
+    sender: AccountId,
+    channel_id: ChannelId,
+    member_id: MemberId,
+    caps: Option<CreatorTokenAgentCaps<Test>>,
+}
+
+impl SetCreatorTokenAgentCapsFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: DEFAULT_MEMBER_ACCOUNT_ID,
+            channel_id: ChannelId::one(),
+            member_id: COLLABORATOR_MEMBER_ID,
+            caps: Some(CreatorTokenAgentCaps::<Test>::default()),
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_member_id(self, member_id: MemberId) -> Self {
+        Self { member_id, ..self }
+    }
+
+    pub fn with_caps(self, caps: Option<CreatorTokenAgentCaps<Test>>) -> Self {
+        Self { caps, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let origin = Origin::signed(self.sender);
+
+        let actual_result = Content::set_creator_token_agent_caps(
+            origin,
+            self.channel_id,
+            self.member_id,
+            self.caps.clone(),
+        );
+
+        if expected_result.is_ok() {
+            assert_ok!(actual_result);
+            assert_eq!(
+                Content::creator_token_agent_caps(self.channel_id, self.member_id),
+                self.caps
+            );
+            assert_eq!(
+                System::events().last().unwrap().event,
+                MetaEvent::Content(RawEvent::CreatorTokenAgentCapsUpdated(
+                    self.channel_id,
+                    self.member_id,
+                    self.caps.clone()
+                ))
+            );
+        } else {
+            assert_noop!(actual_result, expected_result.err().unwrap());
+        }
+    }
+}
+
 pub struct FinalizeRevenueSplitFixture {
     sender: AccountId,
     actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
@@ -3151,6 +3754,158 @@ impl DeissueCreatorTokenFixture {
     }
 }
 
+pub struct CreateSubscriptionTierFixture {
+    sender: AccountId,
+    actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
+    channel_id: ChannelId,
+    params: SubscriptionTierParameters<Test>,
+}
+
+impl CreateSubscriptionTierFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: DEFAULT_MEMBER_ACCOUNT_ID,
+            actor: ContentActor::Member(DEFAULT_MEMBER_ID),
+            channel_id: ChannelId::one(),
+            params: SubscriptionTierParameters::<Test> {
+                price_per_period: DEFAULT_SUBSCRIPTION_PRICE,
+                period_length: DEFAULT_SUBSCRIPTION_PERIOD,
+                benefits_metadata: b"benefits".to_vec(),
+            },
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_actor(self, actor: ContentActor<CuratorGroupId, CuratorId, MemberId>) -> Self {
+        Self { actor, ..self }
+    }
+
+    pub fn with_price_per_period(self, price_per_period: BalanceOf<Test>) -> Self {
+        Self {
+            params: SubscriptionTierParameters::<Test> {
+                price_per_period,
+                ..self.params
+            },
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let origin = Origin::signed(self.sender);
+        let expected_tier_id = Content::next_subscription_tier_id(self.channel_id);
+
+        let actual_result = Content::create_subscription_tier(
+            origin,
+            self.actor,
+            self.channel_id,
+            self.params.clone(),
+        );
+
+        if expected_result.is_ok() {
+            assert_ok!(actual_result);
+            assert_eq!(
+                Content::subscription_tier_by_id(self.channel_id, expected_tier_id),
+                Some(SubscriptionTier::<Test> {
+                    price_per_period: self.params.price_per_period,
+                    period_length: self.params.period_length,
+                    active: true,
+                })
+            );
+            assert_eq!(
+                Content::next_subscription_tier_id(self.channel_id),
+                expected_tier_id + 1
+            );
+            last_event_eq!(RawEvent::SubscriptionTierCreated(
+                self.actor,
+                self.channel_id,
+                expected_tier_id,
+                self.params.clone(),
+            ));
+        } else {
+            assert_noop!(actual_result, expected_result.err().unwrap());
+        }
+    }
+}
+
+pub struct SubscribeToChannelFixture {
+    sender: AccountId,
+    member_id: MemberId,
+    channel_id: ChannelId,
+    tier_id: SubscriptionTierId,
+    periods: u32,
+    renewal_deposit: BalanceOf<Test>,
+}
+
+impl SubscribeToChannelFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: SECOND_MEMBER_ACCOUNT_ID,
+            member_id: SECOND_MEMBER_ID,
+            channel_id: ChannelId::one(),
+            tier_id: SubscriptionTierId::zero(),
+            periods: 1,
+            renewal_deposit: BalanceOf::<Test>::zero(),
+        }
+    }
+
+    pub fn with_periods(self, periods: u32) -> Self {
+        Self { periods, ..self }
+    }
+
+    pub fn with_renewal_deposit(self, renewal_deposit: BalanceOf<Test>) -> Self {
+        Self {
+            renewal_deposit,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let origin = Origin::signed(self.sender);
+        let channel_account = ContentTreasury::<Test>::account_for_channel(self.channel_id);
+        let channel_balance_pre = Balances::<Test>::usable_balance(&channel_account);
+
+        let actual_result = Content::subscribe_to_channel(
+            origin,
+            self.member_id,
+            self.channel_id,
+            self.tier_id,
+            self.periods,
+            self.renewal_deposit,
+        );
+
+        if expected_result.is_ok() {
+            assert_ok!(actual_result);
+            let paid_until =
+                System::block_number() + DEFAULT_SUBSCRIPTION_PERIOD * u64::from(self.periods);
+            assert_eq!(
+                Content::subscription(self.channel_id, self.member_id),
+                Some(Subscription::<Test> {
+                    tier_id: self.tier_id,
+                    subscribed_at: System::block_number(),
+                    paid_until,
+                    deposit: self.renewal_deposit,
+                })
+            );
+            assert_eq!(
+                Balances::<Test>::usable_balance(&channel_account),
+                channel_balance_pre + DEFAULT_SUBSCRIPTION_PRICE * u64::from(self.periods)
+            );
+            last_event_eq!(RawEvent::ChannelSubscribed(
+                self.member_id,
+                self.channel_id,
+                self.tier_id,
+                paid_until,
+                self.renewal_deposit,
+            ));
+        } else {
+            assert_noop!(actual_result, expected_result.err().unwrap());
+        }
+    }
+}
+
 pub struct CancelChannelTransferFixture {
     origin: RawOrigin<U256>,
     channel_id: u64,
@@ -119,6 +119,10 @@ pub const INITIAL_BALANCE: u64 = 1000;
 // Transfer price
 pub const DEFAULT_CHANNEL_TRANSFER_PRICE: u64 = 100;
 
+// Channel subscriptions
+pub const DEFAULT_SUBSCRIPTION_PRICE: u64 = 100;
+pub const DEFAULT_SUBSCRIPTION_PERIOD: u64 = 10;
+
 pub const MEMBERS_COUNT: u64 = 10;
 pub const PAYMENTS_NUMBER: u64 = 10;
 pub const DEFAULT_PAYOUT_CLAIMED: u64 = 100;
@@ -134,6 +138,15 @@ pub const DEFAULT_ISSUER_TRANSFER_AMOUNT: u64 = 1_000_000;
 pub const DEFAULT_PATRONAGE_RATE: YearlyRate = YearlyRate(Permill::from_percent(1));
 pub const DEFAULT_REVENUE_SPLIT_DURATION: u64 = 1000;
 pub const DEFAULT_SPLIT_RATE: Permill = Permill::from_percent(10);
+pub const DEFAULT_AUTO_REVENUE_SPLIT_POOL_RATE: Perbill = Perbill::from_percent(50);
+pub const DEFAULT_AUTO_REVENUE_SPLIT_THRESHOLD: u64 = 100;
+pub const DEFAULT_AUTO_REVENUE_SPLIT_PERIOD: u64 = 10;
+pub const DEFAULT_TOKEN_POLL_DURATION: u64 = 100;
+pub const DEFAULT_TOKEN_AIRDROP_AMOUNT: u64 = 1_000_000;
+pub const DEFAULT_TOKEN_AIRDROP_DURATION: u64 = 100;
+pub const DEFAULT_TOKEN_BUYBACK_MAX_UNIT_PRICE: u64 = 10;
+pub const DEFAULT_TOKEN_BUYBACK_DURATION: u64 = 100;
+pub const DEFAULT_TIME_WEIGHTED_SPLITS_LOOKBACK: u64 = 100;
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -373,6 +386,7 @@ parameter_types! {
     };
     pub const MinimumCashoutAllowedLimit: u64 = 1;
     pub const MaximumCashoutAllowedLimit: u64 = 1_000_000;
+    pub const MaxAutoRevenueSplitsPerBlock: u32 = 2;
     pub const MaxNftAuctionWhitelistLength: u32 = 5;
 }
 
@@ -399,6 +413,7 @@ impl Config for Test {
 
     /// membership info provider
     type MemberAuthenticator = TestMemberships;
+    type MemberReputationHandler = ();
 
     /// max number of keys per curator_group.permissions_by_level map instance
     type MaxKeysPerCuratorGroupPermissionsByLevelMap = MaxKeysPerCuratorGroupPermissionsByLevelMap;
@@ -444,6 +459,7 @@ impl Config for Test {
 
     /// Max cashout allowed limit
     type MaximumCashoutAllowedLimit = MaximumCashoutAllowedLimit;
+    type MaxAutoRevenueSplitsPerBlock = MaxAutoRevenueSplitsPerBlock;
 
     /// Max nft auction whitelist length
     type MaxNftAuctionWhitelistLength = MaxNftAuctionWhitelistLength;
@@ -490,6 +506,7 @@ impl working_group::Config<StorageWorkingGroupInstance> for Test {
     type StakingAccountValidator = membership::Module<Test>;
     type StakingHandler = staking_handler::StakingManager<Self, LockId>;
     type MemberOriginValidator = ();
+    type MemberReputationHandler = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
     type WeightInfo = ();
@@ -505,6 +522,7 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
     type StakingAccountValidator = membership::Module<Test>;
     type StakingHandler = staking_handler::StakingManager<Self, LockId2>;
     type MemberOriginValidator = ();
+    type MemberReputationHandler = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
     type WeightInfo = ();
@@ -521,6 +539,7 @@ impl working_group::Config<ContentWorkingGroupInstance> for Test {
     type StakingAccountValidator = membership::Module<Test>;
     type StakingHandler = staking_handler::StakingManager<Self, LockId3>;
     type MemberOriginValidator = ();
+    type MemberReputationHandler = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
     type WeightInfo = ();
@@ -769,6 +788,13 @@ impl membership::Config for Test {
     type CandidateStake = CandidateStake;
     type WeightInfo = ();
     type DefaultMemberInvitesCount = DefaultMemberInvitesCount;
+    type MemberReputationRules = ();
+    type MaxRecoveryGuardians = ();
+    type RecoveryDelay = ();
+    type RecoveryDeposit = ();
+    type HandleExpiryPeriod = ();
+    type HandleExpiryGracePeriod = ();
+    type MaxUplineReferralLevels = ();
 }
 
 pub const WORKING_GROUP_BUDGET: u64 = 100;
@@ -1063,6 +1089,10 @@ impl common::working_group::WorkingGroupBudgetHandler<U256, u64> for Distributio
 parameter_types! {
     pub const TokenModuleId: PalletId = PalletId(*b"m__Token");
     pub const MaxVestingSchedulesPerAccountPerToken: u32 = 3;
+    pub const MaxPollAlternatives: u32 = 5;
+    pub const MaxAllowancesPerAccount: u32 = 3;
+    pub const MaxSaleTiers: u32 = 3;
+    pub const MaxBalanceCheckpoints: u32 = 3;
     pub const BlocksPerYear: u32 = 5259487; // blocks every 6s
 }
 
@@ -1075,6 +1105,10 @@ impl project_token::Config for Test {
     type ModuleId = TokenModuleId;
     type JoyExistentialDeposit = ExistentialDeposit;
     type MaxVestingSchedulesPerAccountPerToken = MaxVestingSchedulesPerAccountPerToken;
+    type MaxPollAlternatives = MaxPollAlternatives;
+    type MaxAllowancesPerAccount = MaxAllowancesPerAccount;
+    type MaxSaleTiers = MaxSaleTiers;
+    type MaxBalanceCheckpoints = MaxBalanceCheckpoints;
     type BlocksPerYear = BlocksPerYear;
     type MemberOriginValidator = TestMemberships;
     type MembershipInfoProvider = TestMemberships;
@@ -8,5 +8,6 @@ mod merkle;
 mod metaprotocol;
 pub(crate) mod mock;
 mod nft;
+mod subscriptions;
 mod transfers;
 mod videos;
@@ -0,0 +1,226 @@
+#![cfg(test)]
+
+use super::fixtures::*;
+use super::mock::*;
+use crate::*;
+use frame_support::{assert_noop, assert_ok};
+
+fn setup_channel_with_subscription_tier() {
+    ContentTest::with_member_channel().setup();
+    CreateSubscriptionTierFixture::default().call_and_assert(Ok(()));
+    increase_account_balance_helper(SECOND_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+}
+
+#[test]
+fn unsuccessful_create_subscription_tier_by_collaborator_without_permissions() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_all_agent_permissions_except(&[ChannelActionPermission::ManageSubscriptionTiers])
+            .setup();
+        CreateSubscriptionTierFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Err(
+                Error::<Test>::ChannelAgentInsufficientPermissions.into()
+            ));
+    })
+}
+
+#[test]
+fn successful_create_subscription_tier_by_collaborator() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_agent_permissions(&[ChannelActionPermission::ManageSubscriptionTiers])
+            .setup();
+        CreateSubscriptionTierFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn unsuccessful_create_subscription_tier_with_zero_price() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        CreateSubscriptionTierFixture::default()
+            .with_price_per_period(0)
+            .call_and_assert(Err(Error::<Test>::InvalidSubscriptionTierPrice.into()));
+    })
+}
+
+#[test]
+fn successful_subscribe_to_channel() {
+    with_default_mock_builder(|| {
+        setup_channel_with_subscription_tier();
+        SubscribeToChannelFixture::default()
+            .with_periods(3)
+            .with_renewal_deposit(DEFAULT_SUBSCRIPTION_PRICE)
+            .call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn unsuccessful_subscribe_to_deactivated_subscription_tier() {
+    with_default_mock_builder(|| {
+        setup_channel_with_subscription_tier();
+        assert_ok!(Content::deactivate_subscription_tier(
+            Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            ChannelId::one(),
+            0
+        ));
+        SubscribeToChannelFixture::default()
+            .call_and_assert(Err(Error::<Test>::SubscriptionTierNotActive.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_subscribe_to_channel_when_already_subscribed() {
+    with_default_mock_builder(|| {
+        setup_channel_with_subscription_tier();
+        SubscribeToChannelFixture::default().call_and_assert(Ok(()));
+        SubscribeToChannelFixture::default()
+            .call_and_assert(Err(Error::<Test>::AlreadySubscribedToChannel.into()));
+
+        // Expired subscription can be replaced
+        run_to_block(1 + DEFAULT_SUBSCRIPTION_PERIOD);
+        SubscribeToChannelFixture::default().call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn successful_channel_subscription_renewal_from_deposit() {
+    with_default_mock_builder(|| {
+        setup_channel_with_subscription_tier();
+        SubscribeToChannelFixture::default()
+            .with_renewal_deposit(2 * DEFAULT_SUBSCRIPTION_PRICE)
+            .call_and_assert(Ok(()));
+        let channel_id = ChannelId::one();
+        let channel_account = ContentTreasury::<Test>::account_for_channel(channel_id);
+
+        assert_noop!(
+            Content::renew_channel_subscription(
+                Origin::signed(THIRD_MEMBER_ACCOUNT_ID),
+                channel_id,
+                SECOND_MEMBER_ID
+            ),
+            Error::<Test>::SubscriptionRenewalNotDue
+        );
+
+        // Renewals are made for the consecutive periods even if requested late
+        run_to_block(1 + 2 * DEFAULT_SUBSCRIPTION_PERIOD);
+        let channel_balance_pre = Balances::<Test>::usable_balance(&channel_account);
+        assert_ok!(Content::renew_channel_subscription(
+            Origin::signed(THIRD_MEMBER_ACCOUNT_ID),
+            channel_id,
+            SECOND_MEMBER_ID
+        ));
+
+        let paid_until = 1 + 3 * DEFAULT_SUBSCRIPTION_PERIOD;
+        assert_eq!(
+            Content::subscription(channel_id, SECOND_MEMBER_ID).map(|s| (s.paid_until, s.deposit)),
+            Some((paid_until, 0))
+        );
+        assert_eq!(
+            Balances::<Test>::usable_balance(&channel_account),
+            channel_balance_pre + 2 * DEFAULT_SUBSCRIPTION_PRICE
+        );
+        last_event_eq!(RawEvent::ChannelSubscriptionRenewed(
+            SECOND_MEMBER_ID,
+            channel_id,
+            paid_until,
+            2 * DEFAULT_SUBSCRIPTION_PRICE
+        ));
+    })
+}
+
+#[test]
+fn successful_cancel_channel_subscription_renewal() {
+    with_default_mock_builder(|| {
+        setup_channel_with_subscription_tier();
+        SubscribeToChannelFixture::default()
+            .with_renewal_deposit(DEFAULT_SUBSCRIPTION_PRICE)
+            .call_and_assert(Ok(()));
+        let channel_id = ChannelId::one();
+
+        assert_ok!(Content::top_up_channel_subscription(
+            Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+            SECOND_MEMBER_ID,
+            channel_id,
+            DEFAULT_SUBSCRIPTION_PRICE / 2
+        ));
+
+        // One period is due and paid from the deposit, the rest is refunded
+        run_to_block(1 + DEFAULT_SUBSCRIPTION_PERIOD);
+        let balance_pre = Balances::<Test>::usable_balance(&SECOND_MEMBER_ACCOUNT_ID);
+        assert_ok!(Content::cancel_channel_subscription_renewal(
+            Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+            SECOND_MEMBER_ID,
+            channel_id
+        ));
+
+        assert_eq!(
+            Balances::<Test>::usable_balance(&SECOND_MEMBER_ACCOUNT_ID),
+            balance_pre + DEFAULT_SUBSCRIPTION_PRICE / 2
+        );
+        last_event_eq!(RawEvent::ChannelSubscriptionRenewalCancelled(
+            SECOND_MEMBER_ID,
+            channel_id,
+            DEFAULT_SUBSCRIPTION_PRICE / 2
+        ));
+    })
+}
+
+#[test]
+fn unsuccessful_cancel_channel_subscription_renewal_with_insufficient_treasury_balance() {
+    with_default_mock_builder(|| {
+        setup_channel_with_subscription_tier();
+        SubscribeToChannelFixture::default()
+            .with_renewal_deposit(DEFAULT_SUBSCRIPTION_PRICE)
+            .call_and_assert(Ok(()));
+        let channel_id = ChannelId::one();
+
+        let _ = Balances::<Test>::make_free_balance_be(
+            &ContentTreasury::<Test>::module_account_id(),
+            0,
+        );
+
+        assert_noop!(
+            Content::cancel_channel_subscription_renewal(
+                Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+                SECOND_MEMBER_ID,
+                channel_id
+            ),
+            Error::<Test>::InsufficientTreasuryBalance
+        );
+    })
+}
+
+#[test]
+fn is_subscribed_covers_paid_and_prepaid_periods() {
+    with_default_mock_builder(|| {
+        setup_channel_with_subscription_tier();
+        SubscribeToChannelFixture::default()
+            .with_renewal_deposit(DEFAULT_SUBSCRIPTION_PRICE)
+            .call_and_assert(Ok(()));
+        let channel_id = ChannelId::one();
+        let expires_at = 1 + 2 * DEFAULT_SUBSCRIPTION_PERIOD;
+
+        assert!(Content::is_subscribed(channel_id, SECOND_MEMBER_ID, 0, 1));
+        assert!(Content::is_subscribed(
+            channel_id,
+            SECOND_MEMBER_ID,
+            0,
+            expires_at - 1
+        ));
+        assert!(!Content::is_subscribed(
+            channel_id,
+            SECOND_MEMBER_ID,
+            0,
+            expires_at
+        ));
+        assert!(!Content::is_subscribed(channel_id, SECOND_MEMBER_ID, 1, 1));
+        assert!(!Content::is_subscribed(channel_id, THIRD_MEMBER_ID, 0, 1));
+    })
+}
@@ -9,6 +9,7 @@ use frame_support::storage::{
 };
 use frame_support::PalletId;
 use scale_info::TypeInfo;
+use sp_arithmetic::traits::{CheckedDiv, UniqueSaturatedFrom, UniqueSaturatedInto};
 use sp_std::collections::btree_map::BTreeMap;
 
 /// Defines NFT limit ID type for global and channel NFT limits and counters.
@@ -238,6 +239,21 @@ pub mod iterable_enums {
         /// Allows deissuing a creator token (provided it has 0 supply)
         /// - `deissue_creator_token`
         DeissueCreatorToken,
+        /// Allows managing channel subscription tiers. Affected extrinsics:
+        /// - `create_subscription_tier`
+        /// - `deactivate_subscription_tier`
+        ManageSubscriptionTiers,
+        /// Allows issuing creator token holder polls. Affected extrinsics:
+        /// - `issue_creator_token_poll`
+        ManageCreatorTokenPolls,
+        /// Allows freezing creator token accounts and pausing the creator token.
+        /// Affected extrinsics:
+        /// - `set_creator_token_frozen_status`
+        ManageCreatorTokenCompliance,
+        /// Allows starting and finalizing creator token buyback programs. Affected extrinsics:
+        /// - `start_creator_token_buyback`
+        /// - `finalize_creator_token_buyback`
+        ManageCreatorTokenBuyback,
     }
 }
 
@@ -805,6 +821,297 @@ pub type UpdateChannelPayoutsParameters<T> = UpdateChannelPayoutsParametersRecor
     <T as frame_system::Config>::Hash,
 >;
 
+/// Channel subscription tier identifier (unique within the channel).
+pub type SubscriptionTierId = u32;
+
+/// Channel subscription tier.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct SubscriptionTierRecord<Balance, BlockNumber> {
+    /// Price paid for each subscription period.
+    pub price_per_period: Balance,
+
+    /// Subscription period length in blocks.
+    pub period_length: BlockNumber,
+
+    /// Whether the tier accepts new subscriptions.
+    pub active: bool,
+}
+
+pub type SubscriptionTier<T> =
+    SubscriptionTierRecord<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+/// Parameters of the channel subscription tier.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct SubscriptionTierParametersRecord<Balance, BlockNumber> {
+    /// Price paid for each subscription period.
+    pub price_per_period: Balance,
+
+    /// Subscription period length in blocks.
+    pub period_length: BlockNumber,
+
+    /// Tier benefits metadata.
+    pub benefits_metadata: Vec<u8>,
+}
+
+pub type SubscriptionTierParameters<T> =
+    SubscriptionTierParametersRecord<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+/// Member subscription to the channel.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct SubscriptionRecord<Balance, BlockNumber> {
+    /// Subscription tier of the channel.
+    pub tier_id: SubscriptionTierId,
+
+    /// Block at which the subscription started.
+    pub subscribed_at: BlockNumber,
+
+    /// End of the last paid subscription period.
+    pub paid_until: BlockNumber,
+
+    /// Prepaid balance used for the subscription renewals (held by the module account).
+    pub deposit: Balance,
+}
+
+impl<Balance: BaseArithmetic + Copy, BlockNumber: BaseArithmetic + Copy>
+    SubscriptionRecord<Balance, BlockNumber>
+{
+    // Number of the subscription periods that can be renewed from the deposit.
+    fn prepaid_periods(&self, tier: &SubscriptionTierRecord<Balance, BlockNumber>) -> u64 {
+        self.deposit
+            .checked_div(&tier.price_per_period)
+            .unwrap_or_else(Zero::zero)
+            .unique_saturated_into()
+    }
+
+    /// Block at which the subscription expires, including the renewals from the deposit.
+    pub fn expires_at(&self, tier: &SubscriptionTierRecord<Balance, BlockNumber>) -> BlockNumber {
+        let prepaid_periods = BlockNumber::unique_saturated_from(self.prepaid_periods(tier));
+
+        self.paid_until
+            .saturating_add(tier.period_length.saturating_mul(prepaid_periods))
+    }
+
+    /// Renews the subscription periods that are due at the `now` block from the deposit.
+    /// Returns the renewed subscription and the amount charged from the deposit.
+    pub fn renewed(
+        &self,
+        tier: &SubscriptionTierRecord<Balance, BlockNumber>,
+        now: BlockNumber,
+    ) -> (Self, Balance) {
+        if self.paid_until > now {
+            return (self.clone(), Zero::zero());
+        }
+
+        let due_periods: u64 = now
+            .saturating_sub(self.paid_until)
+            .checked_div(&tier.period_length)
+            .unwrap_or_else(Zero::zero)
+            .saturating_add(One::one())
+            .unique_saturated_into();
+
+        let periods = due_periods.min(self.prepaid_periods(tier));
+
+        let charge = tier
+            .price_per_period
+            .saturating_mul(Balance::unique_saturated_from(periods));
+
+        let renewed = Self {
+            paid_until: self.paid_until.saturating_add(
+                tier.period_length
+                    .saturating_mul(BlockNumber::unique_saturated_from(periods)),
+            ),
+            deposit: self.deposit.saturating_sub(charge),
+            ..self.clone()
+        };
+
+        (renewed, charge)
+    }
+}
+
+pub type Subscription<T> =
+    SubscriptionRecord<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+/// Parameters of the automatic revenue splits funded from channel earnings.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct AutoRevenueSplitParametersRecord<Balance, BlockNumber> {
+    /// Percentage of channel reward claims and NFT royalties/sales routed to the pending pool.
+    pub pool_rate: Perbill,
+
+    /// Pending pool amount which, once reached, triggers a revenue split.
+    pub threshold: Option<Balance>,
+
+    /// Number of blocks after which a revenue split is triggered.
+    pub period: Option<BlockNumber>,
+
+    /// Duration of the automatically issued revenue splits.
+    pub split_duration: BlockNumber,
+}
+
+pub type AutoRevenueSplitParameters<T> =
+    AutoRevenueSplitParametersRecord<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+/// Channel pool of the earnings pending distribution in the next automatic revenue split.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct AutoRevenueSplitPoolRecord<Balance, BlockNumber> {
+    /// Automatic revenue split parameters.
+    pub params: AutoRevenueSplitParametersRecord<Balance, BlockNumber>,
+
+    /// Pending amount (held by the channel account).
+    pub pending_amount: Balance,
+
+    /// Block at which the next periodic revenue split is due (if `params.period` is set).
+    pub next_split_at: Option<BlockNumber>,
+}
+
+impl<Balance: BaseArithmetic + Copy, BlockNumber: BaseArithmetic + Copy>
+    AutoRevenueSplitPoolRecord<Balance, BlockNumber>
+{
+    // Whether the pending amount reached the threshold or the period elapsed at block `now`.
+    pub(crate) fn is_split_due(&self, now: BlockNumber) -> bool {
+        let threshold_reached = self
+            .params
+            .threshold
+            .map_or(false, |threshold| self.pending_amount >= threshold);
+        let period_elapsed = self.next_split_at.map_or(false, |at| at <= now);
+        !self.pending_amount.is_zero() && (threshold_reached || period_elapsed)
+    }
+}
+
+pub type AutoRevenueSplitPool<T> =
+    AutoRevenueSplitPoolRecord<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+/// Parameters of a council-enforced creator token freeze (or unfreeze).
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct CreatorTokenFrozenStatusParametersRecord<ChannelId, MemberId> {
+    /// Channel which issued the creator token.
+    pub channel_id: ChannelId,
+
+    /// Member whose token account is (un)frozen. If `None` the whole token is (un)paused.
+    pub member_id: Option<MemberId>,
+
+    /// Whether the account (token) should be frozen (paused).
+    pub frozen: bool,
+}
+
+pub type CreatorTokenFrozenStatusParameters<T> = CreatorTokenFrozenStatusParametersRecord<
+    <T as storage::Config>::ChannelId,
+    <T as common::MembershipTypes>::MemberId,
+>;
+
+/// Caps on the amounts a channel agent can move through the creator token issuer actions.
+/// Every cap is an allowance, reduced by each use of the respective action (`None` means no cap).
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct CreatorTokenAgentCapsRecord<JoyBalance, TokenBalance> {
+    /// Tokens the agent can still put on sale through `init_creator_token_sale`.
+    pub token_sale_quantity: Option<TokenBalance>,
+
+    /// JOY the agent can still allocate to revenue splits through `issue_revenue_split`.
+    pub revenue_split_allocation: Option<JoyBalance>,
+
+    /// Tokens the agent can still transfer through `creator_token_issuer_transfer`
+    /// (or reserve for airdrops through `issue_creator_token_airdrop`).
+    pub issuer_transfer_amount: Option<TokenBalance>,
+
+    /// Patronage credit the agent can still claim through `claim_creator_token_patronage_credit`.
+    pub patronage_credit_amount: Option<TokenBalance>,
+
+    /// Lowest rate the agent can set through `reduce_creator_token_patronage_rate_to`.
+    pub min_patronage_rate: Option<YearlyRate>,
+}
+
+impl<JoyBalance: BaseArithmetic + Copy, TokenBalance: BaseArithmetic + Copy>
+    CreatorTokenAgentCapsRecord<JoyBalance, TokenBalance>
+{
+    pub(crate) fn ensure_token_sale_quantity_within_cap<T: Config>(
+        &self,
+        quantity: TokenBalance,
+    ) -> DispatchResult {
+        ensure!(
+            self.token_sale_quantity.map_or(true, |cap| quantity <= cap),
+            Error::<T>::ChannelAgentTokenSaleCapExceeded
+        );
+        Ok(())
+    }
+
+    pub(crate) fn ensure_revenue_split_allocation_within_cap<T: Config>(
+        &self,
+        allocation: JoyBalance,
+    ) -> DispatchResult {
+        ensure!(
+            self.revenue_split_allocation
+                .map_or(true, |cap| allocation <= cap),
+            Error::<T>::ChannelAgentRevenueSplitCapExceeded
+        );
+        Ok(())
+    }
+
+    pub(crate) fn ensure_issuer_transfer_amount_within_cap<T: Config>(
+        &self,
+        amount: TokenBalance,
+    ) -> DispatchResult {
+        ensure!(
+            self.issuer_transfer_amount
+                .map_or(true, |cap| amount <= cap),
+            Error::<T>::ChannelAgentIssuerTransferCapExceeded
+        );
+        Ok(())
+    }
+
+    pub(crate) fn ensure_patronage_credit_amount_within_cap<T: Config>(
+        &self,
+        amount: TokenBalance,
+    ) -> DispatchResult {
+        ensure!(
+            self.patronage_credit_amount
+                .map_or(true, |cap| amount <= cap),
+            Error::<T>::ChannelAgentPatronageCreditCapExceeded
+        );
+        Ok(())
+    }
+
+    pub(crate) fn ensure_patronage_rate_within_cap<T: Config>(
+        &self,
+        rate: YearlyRate,
+    ) -> DispatchResult {
+        ensure!(
+            self.min_patronage_rate.map_or(true, |min| rate >= min),
+            Error::<T>::ChannelAgentPatronageRateBelowMin
+        );
+        Ok(())
+    }
+
+    pub(crate) fn consume_token_sale_quantity(&mut self, quantity: TokenBalance) {
+        Self::consume(&mut self.token_sale_quantity, quantity)
+    }
+
+    pub(crate) fn consume_revenue_split_allocation(&mut self, allocation: JoyBalance) {
+        Self::consume(&mut self.revenue_split_allocation, allocation)
+    }
+
+    pub(crate) fn consume_issuer_transfer_amount(&mut self, amount: TokenBalance) {
+        Self::consume(&mut self.issuer_transfer_amount, amount)
+    }
+
+    pub(crate) fn consume_patronage_credit_amount(&mut self, amount: TokenBalance) {
+        Self::consume(&mut self.patronage_credit_amount, amount)
+    }
+
+    fn consume<Balance: BaseArithmetic + Copy>(cap: &mut Option<Balance>, amount: Balance) {
+        if let Some(cap) = cap.as_mut() {
+            *cap = cap.saturating_sub(amount);
+        }
+    }
+}
+
+pub type CreatorTokenAgentCaps<T> = CreatorTokenAgentCapsRecord<BalanceOf<T>, TokenBalanceOf<T>>;
+
 /// Operations with local pallet account.
 pub trait ModuleAccount<T: Config> {
     /// The module id, used for deriving its sovereign account ID.
@@ -121,6 +121,22 @@ pub trait WeightInfo {
 	fn nft_owner_remark(_b: u32, ) -> Weight;
 	fn update_channel_state_bloat_bond() -> Weight;
 	fn update_video_state_bloat_bond() -> Weight;
+	fn create_subscription_tier(_b: u32, ) -> Weight;
+	fn deactivate_subscription_tier() -> Weight;
+	fn subscribe_to_channel() -> Weight;
+	fn top_up_channel_subscription() -> Weight;
+	fn renew_channel_subscription() -> Weight;
+	fn cancel_channel_subscription_renewal() -> Weight;
+	fn issue_creator_token_poll(_b: u32, ) -> Weight;
+	fn issue_creator_token_airdrop() -> Weight;
+	fn recover_creator_token_airdrop() -> Weight;
+	fn set_channel_auto_revenue_split() -> Weight;
+	fn set_creator_token_frozen_status() -> Weight;
+	fn set_creator_token_frozen_status_as_council() -> Weight;
+	fn start_creator_token_buyback() -> Weight;
+	fn finalize_creator_token_buyback() -> Weight;
+	fn set_creator_token_agent_caps() -> Weight;
+	fn set_creator_token_time_weighted_splits() -> Weight;
 }
 
 /// Weights for content using the Substrate node and recommended hardware.
@@ -1233,6 +1249,166 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: Content ChannelById (r:1 w:0)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Storage: Content NextSubscriptionTierId (r:1 w:1)
+	// Storage: Content SubscriptionTierById (r:0 w:1)
+	fn create_subscription_tier(b: u32, ) -> Weight {
+		(298_412_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((1_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Content ChannelById (r:1 w:0)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Storage: Content SubscriptionTierById (r:1 w:1)
+	fn deactivate_subscription_tier() -> Weight {
+		(296_803_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Content ChannelById (r:1 w:0)
+	// Storage: Content SubscriptionTierById (r:1 w:0)
+	// Storage: Content SubscriptionByChannelAndMember (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	fn subscribe_to_channel() -> Weight {
+		(127_526_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Content SubscriptionByChannelAndMember (r:1 w:1)
+	// Storage: Content SubscriptionTierById (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn top_up_channel_subscription() -> Weight {
+		(84_155_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Content SubscriptionByChannelAndMember (r:1 w:1)
+	// Storage: Content SubscriptionTierById (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn renew_channel_subscription() -> Weight {
+		(71_920_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Content SubscriptionByChannelAndMember (r:1 w:1)
+	// Storage: Content SubscriptionTierById (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	fn cancel_channel_subscription_renewal() -> Weight {
+		(98_341_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Content ChannelById (r:1 w:0)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Storage: Token TokenInfoById (r:1 w:0)
+	// Storage: Token TokenPollByTokenId (r:1 w:1)
+	fn issue_creator_token_poll(b: u32, ) -> Weight {
+		(364_210_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((1_262_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Content ChannelById (r:1 w:0)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Storage: Token TokenInfoById (r:1 w:0)
+	// Storage: Token AccountInfoByTokenAndMember (r:1 w:1)
+	// Storage: Token NextAirdropId (r:1 w:1)
+	// Storage: Token AirdropById (r:0 w:1)
+	fn issue_creator_token_airdrop() -> Weight {
+		(61_734_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Content ChannelById (r:1 w:0)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Storage: Token TokenInfoById (r:1 w:0)
+	// Storage: Token AirdropById (r:1 w:1)
+	// Storage: Token AccountInfoByTokenAndMember (r:1 w:1)
+	fn recover_creator_token_airdrop() -> Weight {
+		(54_302_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Content ChannelById (r:1 w:0)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Storage: Content AutoRevenueSplitPoolByChannelId (r:1 w:1)
+	// Storage: Content AutoRevenueSplitsDueAt (r:1 w:1)
+	fn set_channel_auto_revenue_split() -> Weight {
+		(61_827_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Content ChannelById (r:1 w:0)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Storage: ProjectToken TokenInfoById (r:1 w:0)
+	// Storage: ProjectToken AccountInfoByTokenAndMember (r:1 w:1)
+	fn set_creator_token_frozen_status() -> Weight {
+		(48_392_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Content ChannelById (r:1 w:0)
+	// Storage: ProjectToken TokenInfoById (r:1 w:0)
+	// Storage: ProjectToken AccountInfoByTokenAndMember (r:1 w:1)
+	fn set_creator_token_frozen_status_as_council() -> Weight {
+		(31_054_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Content ChannelById (r:1 w:0)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Content AutoRevenueSplitPoolByChannelId (r:1 w:0)
+	// Storage: ProjectToken TokenInfoById (r:1 w:0)
+	// Storage: ProjectToken BuybackProgramByTokenId (r:1 w:1)
+	fn start_creator_token_buyback() -> Weight {
+		(84_913_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Content ChannelById (r:1 w:0)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Storage: ProjectToken TokenInfoById (r:1 w:0)
+	// Storage: ProjectToken BuybackProgramByTokenId (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn finalize_creator_token_buyback() -> Weight {
+		(79_462_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Content ChannelById (r:1 w:0)
+	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
+	// Storage: Content CreatorTokenAgentCapsByChannelAndMember (r:0 w:1)
+	fn set_creator_token_agent_caps() -> Weight {
+		(28_316_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Content ChannelById (r:1 w:0)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Storage: ProjectToken TokenInfoById (r:1 w:1)
+	fn set_creator_token_time_weighted_splits() -> Weight {
+		(36_817_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
 
 // Default implementation for tests
@@ -1465,4 +1641,52 @@ impl WeightInfo for () {
 	fn update_video_state_bloat_bond() -> Weight {
 		0
 	}
+	fn create_subscription_tier(b: u32, ) -> Weight {
+		0
+	}
+	fn deactivate_subscription_tier() -> Weight {
+		0
+	}
+	fn subscribe_to_channel() -> Weight {
+		0
+	}
+	fn top_up_channel_subscription() -> Weight {
+		0
+	}
+	fn renew_channel_subscription() -> Weight {
+		0
+	}
+	fn cancel_channel_subscription_renewal() -> Weight {
+		0
+	}
+	fn issue_creator_token_poll(b: u32, ) -> Weight {
+		0
+	}
+	fn issue_creator_token_airdrop() -> Weight {
+		0
+	}
+	fn recover_creator_token_airdrop() -> Weight {
+		0
+	}
+	fn set_channel_auto_revenue_split() -> Weight {
+		0
+	}
+	fn set_creator_token_frozen_status() -> Weight {
+		0
+	}
+	fn set_creator_token_frozen_status_as_council() -> Weight {
+		0
+	}
+	fn start_creator_token_buyback() -> Weight {
+		0
+	}
+	fn finalize_creator_token_buyback() -> Weight {
+		0
+	}
+	fn set_creator_token_agent_caps() -> Weight {
+		0
+	}
+	fn set_creator_token_time_weighted_splits() -> Weight {
+		0
+	}
 }
@@ -297,6 +297,34 @@ fn move_to_block_before_initialize_assert_stage<T: Config>(
     assert_eq!(Stage::<T>::get(), target_stage, "Stage not reached");
 }
 
+fn elect_council<T: Config + membership::Config>() -> (Vec<T::AccountId>, Vec<T::MemberId>)
+where
+    T::AccountId: CreateAccountId,
+    T::MemberId: From<u32>,
+{
+    let (accounts_id, candidates_id) =
+        start_period_announce_multiple_candidates::<T>(T::CouncilSize::get());
+
+    let winners = candidates_id
+        .iter()
+        .map(|candidate_id| OptionResult {
+            option_id: *candidate_id,
+            vote_power: Zero::zero(),
+        })
+        .collect::<Vec<_>>();
+
+    Council::<T>::end_announcement_period(T::CouncilSize::get());
+    Council::<T>::end_election_period(&winners[..]);
+
+    assert_eq!(
+        Council::<T>::council_members().len(),
+        candidates_id.len(),
+        "Council not elected"
+    );
+
+    (accounts_id, candidates_id)
+}
+
 const MAX_KILOBYTES_METADATA: u32 = 100;
 const MAX_CANDIDATES: u32 = 100;
 const START_ID: u32 = 5000;
@@ -407,6 +435,7 @@ benchmarks! {
                 stake: T::MinCandidateStake::get(),
                 last_payment_block: Zero::zero(),
                 unpaid_reward: Zero::zero(),
+                elected_in_cycle: AnnouncementPeriodNr::get(),
             }).collect::<Vec<_>>();
 
         assert_eq!(
@@ -742,6 +771,7 @@ benchmarks! {
                 stake: T::MinCandidateStake::get(),
                 last_payment_block: Zero::zero(),
                 unpaid_reward: Zero::zero(),
+                elected_in_cycle: AnnouncementPeriodNr::get(),
             }).collect::<Vec<_>>();
 
         assert_eq!(
@@ -754,6 +784,103 @@ benchmarks! {
     verify {
         assert_last_event::<T>(RawEvent::CouncilorRemarked(candidates_id[0], msg).into());
     }
+
+    back_recall_petition {
+        let (_, candidates_id) = elect_council::<T>();
+
+        // Worst case the petition reaches the threshold and starts the recall referendum
+        let stake = T::RecallPetitionThreshold::get().max(T::MinRecallPetitionStake::get());
+        let (account_id, member_id) =
+            member_funded_account::<T>(START_ID + T::CouncilSize::get());
+    }: _(
+        RawOrigin::Signed(account_id.clone()),
+        member_id,
+        candidates_id[0],
+        account_id.clone(),
+        stake
+    )
+    verify {
+        assert!(Council::<T>::current_recall().is_some(), "Recall referendum not started");
+        assert_last_event::<T>(RawEvent::RecallReferendumStarted(Zero::zero(), candidates_id[0]).into());
+    }
+
+    release_recall_petition_stake {
+        let (_, candidates_id) = elect_council::<T>();
+
+        let (account_id, member_id) =
+            member_funded_account::<T>(START_ID + T::CouncilSize::get());
+
+        Council::<T>::back_recall_petition(
+            RawOrigin::Signed(account_id.clone()).into(),
+            member_id,
+            candidates_id[0],
+            account_id.clone(),
+            T::MinRecallPetitionStake::get(),
+        ).unwrap();
+
+        let petition_id = Council::<T>::recall_petitions(candidates_id[0])
+            .map(|petition| petition.petition_id)
+            .unwrap();
+    }: _(RawOrigin::Signed(account_id.clone()), member_id, petition_id)
+    verify {
+        assert!(
+            Council::<T>::recall_petition_backers(petition_id, member_id).is_none(),
+            "Recall petition stake not released"
+        );
+        assert_last_event::<T>(RawEvent::RecallPetitionStakeReleased(petition_id, member_id).into());
+    }
+
+    end_recall_referendum {
+        let i in 1 .. T::MaxRunnersUp::get();
+
+        let council_size = T::CouncilSize::get();
+        let (accounts_id, candidates_id) =
+            start_period_announce_multiple_candidates::<T>(council_size + i);
+
+        let winners = candidates_id
+            .iter()
+            .map(|candidate_id| OptionResult {
+                option_id: *candidate_id,
+                vote_power: Zero::zero(),
+            })
+            .collect::<Vec<_>>();
+
+        Council::<T>::end_announcement_period(council_size + i);
+        Council::<T>::end_election_period(&winners[..]);
+
+        // Worst case only the lowest ranked runner-up is still available
+        for index in council_size..(council_size + i - 1) {
+            Council::<T>::release_candidacy_stake(
+                RawOrigin::Signed(accounts_id[index as usize].clone()).into(),
+                candidates_id[index as usize],
+            ).unwrap();
+        }
+        let replacement_id = candidates_id[(council_size + i - 1) as usize];
+
+        let stake = T::RecallPetitionThreshold::get().max(T::MinRecallPetitionStake::get());
+        let (account_id, member_id) =
+            member_funded_account::<T>(START_ID + council_size + i);
+
+        Council::<T>::back_recall_petition(
+            RawOrigin::Signed(account_id.clone()).into(),
+            member_id,
+            candidates_id[0],
+            account_id,
+            stake,
+        ).unwrap();
+
+        <Council<T> as RecallReferendumConnection<T>>::increase_recall_option_power(
+            &One::one(),
+            &One::one(),
+        );
+    }: {
+        <Council<T> as RecallReferendumConnection<T>>::recieve_recall_referendum_results(&[]);
+    }
+    verify {
+        assert_last_event::<T>(
+            RawEvent::CouncilorRecalled(candidates_id[0], Some(replacement_id)).into()
+        );
+    }
 }
 
 #[cfg(test)]
@@ -878,4 +1005,28 @@ mod tests {
             assert_ok!(Council::<Runtime>::test_benchmark_candidate_remark());
         })
     }
+
+    #[test]
+    fn test_back_recall_petition() {
+        let config = default_genesis_config();
+        build_test_externalities(config).execute_with(|| {
+            assert_ok!(Council::<Runtime>::test_benchmark_back_recall_petition());
+        })
+    }
+
+    #[test]
+    fn test_release_recall_petition_stake() {
+        let config = default_genesis_config();
+        build_test_externalities(config).execute_with(|| {
+            assert_ok!(Council::<Runtime>::test_benchmark_release_recall_petition_stake());
+        })
+    }
+
+    #[test]
+    fn test_end_recall_referendum() {
+        let config = default_genesis_config();
+        build_test_externalities(config).execute_with(|| {
+            assert_ok!(Council::<Runtime>::test_benchmark_end_recall_referendum());
+        })
+    }
 }
@@ -16,6 +16,20 @@
 //!
 //! The module supports requiring staking currency for the both candidacy and voting.
 //!
+//! Council seats can optionally be staggered: when `StaggeredSeatsPerCycle` is set, only the
+//! seats of the longest serving councilors (plus any vacant seats) are up for election in each
+//! cycle, while the rest of the council keeps their seats. Independently, `MaxConsecutiveTerms`
+//! can limit the number of consecutive terms a councilor can serve before having to step down.
+//!
+//! Elected councilors can be recalled. Members back a recall petition against a councilor by
+//! staking. When the total petition stake reaches `RecallPetitionThreshold`, a recall referendum
+//! (a separate referendum module instance) decides whether the councilor is removed. A recalled
+//! councilor's seat is given to the highest ranked runner-up of the last successful election
+//! (up to `MaxRunnersUp` runners-up are kept) that still has their candidacy stake locked and
+//! isn't a candidate in the ongoing election. The replacement inherits the recalled councilor's
+//! seat, including whether it is up for the ongoing election. When no runner-up is available,
+//! the seat stays vacant until the first election that starts after the recall.
+//!
 //! ## Implementation
 //! When implementing runtime for this module, don't forget to call all ReferendumConnection trait
 //! functions at proper places. See the trait details for more information.
@@ -30,11 +44,15 @@
 //! - [set_councilor_reward](./struct.Module.html#method.set_councilor_reward)
 //! - [funding_request](./struct.Module.html#method.funding_request)
 //! - [fund_council_budget](./struct.Module.html#method.fund_council_budget)
+//! - [back_recall_petition](./struct.Module.html#method.back_recall_petition)
+//! - [release_recall_petition_stake](./struct.Module.html#method.release_recall_petition_stake)
 //!
 //! ## Important functions
 //! These functions have to be called by the runtime for the council to work properly.
 //! - [recieve_referendum_results](./trait.ReferendumConnection.html#method.recieve_referendum_results)
 //! - [can_unlock_vote_stake](./trait.ReferendumConnection.html#method.can_unlock_vote_stake)
+//! - [recieve_recall_referendum_results](./trait.RecallReferendumConnection.html#method.recieve_recall_referendum_results)
+//! - [can_unlock_recall_vote_stake](./trait.RecallReferendumConnection.html#method.can_unlock_recall_vote_stake)
 //!
 //! ## Dependencies:
 //! - [referendum](../referendum/index.html)
@@ -85,6 +103,7 @@ use staking_handler::StakingHandler;
 
 // declared modules
 mod benchmarking;
+mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
@@ -168,6 +187,7 @@ pub struct CouncilMember<AccountId, MemberId, Balance, BlockNumber> {
     stake: Balance,
     last_payment_block: BlockNumber,
     unpaid_reward: Balance,
+    elected_in_cycle: u64,
 }
 
 impl<AccountId, MemberId, Balance, BlockNumber>
@@ -202,10 +222,51 @@ impl<AccountId, MemberId, Balance, Hash, VotePower, BlockNumber>
 
             last_payment_block: from.2,
             unpaid_reward: from.3,
+            elected_in_cycle: from.0.cycle_id,
         }
     }
 }
 
+/// Recall petition against a councilor.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, PartialEq, Eq, Debug, Clone, Default, TypeInfo, MaxEncodedLen)]
+pub struct RecallPetition<Balance> {
+    /// Unique petition id. It is also used as the recall referendum cycle id.
+    pub petition_id: u64,
+    /// Total stake of all petition backers.
+    pub total_stake: Balance,
+    /// Number of petition backers.
+    pub backers_count: u32,
+    /// The petition reached the threshold and the recall referendum was started.
+    pub referendum_started: bool,
+}
+
+/// Member backing a recall petition.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, PartialEq, Eq, Debug, Clone, TypeInfo, MaxEncodedLen)]
+pub struct RecallPetitionBacker<AccountId, MemberId, Balance> {
+    /// Councilor the petition is against.
+    pub councilor_id: MemberId,
+    /// Account holding the petition stake.
+    pub staking_account_id: AccountId,
+    /// Stake backing the petition.
+    pub stake: Balance,
+}
+
+/// State of the running recall referendum.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, PartialEq, Eq, Debug, Clone, Default, TypeInfo, MaxEncodedLen)]
+pub struct RecallReferendum<MemberId, VotePower> {
+    /// Councilor that is subject to the recall.
+    pub councilor_id: MemberId,
+    /// Petition that started the referendum.
+    pub petition_id: u64,
+    /// Vote power in favor of removing the councilor.
+    pub remove_vote_power: VotePower,
+    /// Vote power in favor of keeping the councilor.
+    pub keep_vote_power: VotePower,
+}
+
 /////////////////// Type aliases ///////////////////////////////////////////////
 
 pub type Balance<T> = <T as balances::Config>::Balance;
@@ -215,6 +276,12 @@ pub type VotePowerOf<T> = <<T as Config>::Referendum as ReferendumManager<
     <T as common::membership::MembershipTypes>::MemberId,
     <T as frame_system::Config>::Hash,
 >>::VotePower;
+pub type RecallVotePowerOf<T> = <<T as Config>::RecallReferendum as ReferendumManager<
+    <T as frame_system::Config>::Origin,
+    <T as frame_system::Config>::AccountId,
+    <T as common::membership::MembershipTypes>::MemberId,
+    <T as frame_system::Config>::Hash,
+>>::VotePower;
 pub type CastVoteOf<T> = CastVote<
     <T as frame_system::Config>::Hash,
     Balance<T>,
@@ -233,6 +300,14 @@ pub type CandidateOf<T> = Candidate<
     <T as frame_system::Config>::Hash,
     VotePowerOf<T>,
 >;
+pub type RecallPetitionOf<T> = RecallPetition<Balance<T>>;
+pub type RecallPetitionBackerOf<T> = RecallPetitionBacker<
+    <T as frame_system::Config>::AccountId,
+    <T as common::membership::MembershipTypes>::MemberId,
+    Balance<T>,
+>;
+pub type RecallReferendumOf<T> =
+    RecallReferendum<<T as common::membership::MembershipTypes>::MemberId, RecallVotePowerOf<T>>;
 pub type CouncilStageUpdateOf<T> = CouncilStageUpdate<<T as frame_system::Config>::BlockNumber>;
 pub(crate) type Balances<T> = balances::Pallet<T>;
 
@@ -250,6 +325,15 @@ pub trait Config:
     /// Referendum used for council elections.
     type Referendum: ReferendumManager<Self::Origin, Self::AccountId, Self::MemberId, Self::Hash>;
 
+    /// Referendum used for councilor recalls. It must be a different referendum instance than
+    /// the one used for council elections.
+    type RecallReferendum: ReferendumManager<
+        Self::Origin,
+        Self::AccountId,
+        Self::MemberId,
+        Self::Hash,
+    >;
+
     /// Minimum number of extra candidates needed for the valid election.
     /// Number of total candidates is equal to council size plus extra candidates.
     type MinNumberOfExtraCandidates: Get<u32>;
@@ -257,6 +341,19 @@ pub trait Config:
     /// Council member count
     type CouncilSize: Get<u32>;
 
+    /// Number of council seats up for election in every cycle. The seats of the longest serving
+    /// councilors are the first to be up for election. Zero (or any value greater than the
+    /// council size) means the whole council is replaced every cycle.
+    type StaggeredSeatsPerCycle: Get<u32>;
+
+    /// Maximum number of consecutive terms a councilor can serve. Zero means no limit.
+    type MaxConsecutiveTerms: Get<u32>;
+
+    /// Maximum number of ranked runners-up of the last election kept to replace recalled
+    /// councilors. The election referendum's `MaxWinnerTargetCount` has to be at least
+    /// `CouncilSize + MaxRunnersUp` for all of them to be ranked.
+    type MaxRunnersUp: Get<u32>;
+
     /// Minimum stake candidate has to lock
     type MinCandidateStake: Get<Balance<Self>>;
 
@@ -276,6 +373,20 @@ pub trait Config:
         LockIdentifier,
     >;
 
+    /// Identifier for currency lock used for recall petition staking.
+    type RecallPetitionLock: StakingHandler<
+        Self::AccountId,
+        Balance<Self>,
+        Self::MemberId,
+        LockIdentifier,
+    >;
+
+    /// Minimum stake a member has to lock to back a recall petition.
+    type MinRecallPetitionStake: Get<Balance<Self>>;
+
+    /// Total recall petition stake needed to start the recall referendum.
+    type RecallPetitionThreshold: Get<Balance<Self>>;
+
     /// Validates staking account ownership for a member.
     type StakingAccountValidator: common::StakingAccountValidator<Self>;
 
@@ -332,6 +443,35 @@ pub trait ReferendumConnection<T: Config> {
     fn increase_option_power(membership_id: &T::MemberId, amount: &VotePowerOf<T>);
 }
 
+/// Config with functions that MUST be called by the runtime with values received from the
+/// recall referendum module instance. Recall referendum options are `1` for removing the
+/// councilor and `0` for keeping them.
+pub trait RecallReferendumConnection<T: Config> {
+    /// Process recall referendum results. This function MUST be called in runtime's
+    /// implementation of recall referendum's `process_results()`.
+    fn recieve_recall_referendum_results(
+        winners: &[OptionResult<
+            <T as common::membership::MembershipTypes>::MemberId,
+            RecallVotePowerOf<T>,
+        >],
+    );
+
+    /// Checks that recall vote stake can be released. This function MUST be called in runtime's
+    /// implementation of recall referendum's `can_release_voting_stake()`.
+    fn can_unlock_recall_vote_stake(vote: &CastVoteOf<T>) -> Result<(), Error<T>>;
+
+    /// Checks that the option is a valid recall option and a recall referendum is running. This
+    /// function MUST be called in runtime's implementation of recall referendum's
+    /// `is_valid_option_id()`.
+    fn is_valid_recall_option_id(option_id: &T::MemberId) -> bool;
+
+    /// Return current voting power for a recall option.
+    fn get_recall_option_power(option_id: &T::MemberId) -> RecallVotePowerOf<T>;
+
+    /// Recieve vote (power) for a recall option.
+    fn increase_recall_option_power(option_id: &T::MemberId, amount: &RecallVotePowerOf<T>);
+}
+
 decl_storage! { generate_storage_info
     trait Store for Module<T: Config> as Council {
         /// Current council voting stage
@@ -362,6 +502,37 @@ decl_storage! { generate_storage_info
 
         /// Councilor reward per block
         pub CouncilorReward get(fn councilor_reward) config(): Balance<T>;
+
+        /// Councilors whose seats are up for election in the current election cycle.
+        pub OutgoingCouncilors get(fn outgoing_councilors):
+            WeakBoundedVec<T::MemberId, T::CouncilSize>;
+
+        /// Number of seats to be filled by the current election (rotated and vacant seats).
+        pub SeatsUpForElection get(fn seats_up_for_election): u32;
+
+        /// Number of consecutive terms served by the current councilors (including the current
+        /// term).
+        pub CouncilorTerms get(fn councilor_terms): map hasher(blake2_128_concat)
+            T::MemberId => u32;
+
+        /// Recall petitions against the current councilors.
+        pub RecallPetitions get(fn recall_petitions): map hasher(blake2_128_concat)
+            T::MemberId => Option<RecallPetitionOf<T>>;
+
+        /// Backers of recall petitions by petition id and backer member id.
+        pub RecallPetitionBackers get(fn recall_petition_backers): double_map
+            hasher(blake2_128_concat) u64,
+            hasher(blake2_128_concat) T::MemberId => Option<RecallPetitionBackerOf<T>>;
+
+        /// Id of the next recall petition.
+        pub NextRecallPetitionId get(fn next_recall_petition_id): u64;
+
+        /// Currently running recall referendum.
+        pub CurrentRecall get(fn current_recall): Option<RecallReferendumOf<T>>;
+
+        /// Runners-up of the last successful election ordered by their rank, used to replace
+        /// recalled councilors.
+        pub RunnersUp get(fn runners_up): WeakBoundedVec<T::MemberId, T::MaxRunnersUp>;
     }
 }
 
@@ -433,6 +604,36 @@ decl_event! {
 
         /// Candidate remark message
         CandidateRemarked(MemberId, Vec<u8>),
+
+        /// Member backed a recall petition against a councilor.
+        /// Params:
+        /// - Petition ID
+        /// - Councilor member ID
+        /// - Backer member ID
+        /// - Staking account
+        /// - Stake
+        RecallPetitionBacked(u64, MemberId, MemberId, AccountId, Balance),
+
+        /// Recall petition stake was released.
+        /// Params:
+        /// - Petition ID
+        /// - Backer member ID
+        RecallPetitionStakeReleased(u64, MemberId),
+
+        /// Recall petition reached the threshold and the recall referendum started.
+        /// Params:
+        /// - Petition ID
+        /// - Councilor member ID
+        RecallReferendumStarted(u64, MemberId),
+
+        /// Councilor was removed by a recall referendum.
+        /// Params:
+        /// - Recalled councilor member ID
+        /// - Member ID of the next-ranked candidate that took over the seat (if any)
+        CouncilorRecalled(MemberId, Option<MemberId>),
+
+        /// Recall referendum decided to keep the councilor.
+        CouncilorRecallRejected(MemberId),
     }
 }
 
@@ -513,6 +714,30 @@ decl_error! {
 
         /// Cannot withdraw: insufficient budget balance.
         InsufficientBalanceForTransfer,
+
+        /// Councilor can't announce candidacy while their seat is not up for election.
+        CouncilorSeatNotUpForElection,
+
+        /// Councilor has served the maximum number of consecutive terms.
+        ConsecutiveTermsLimitReached,
+
+        /// Recall petition stake is lower than the minimum.
+        RecallPetitionStakeTooLow,
+
+        /// Member is already backing the recall petition.
+        AlreadyBackingRecallPetition,
+
+        /// Staking account is already used for another recall petition.
+        RecallPetitionStakingAccountInUse,
+
+        /// Recall petitions can't be backed while a recall referendum is running.
+        RecallReferendumRunning,
+
+        /// Member is not backing the recall petition.
+        NotBackingRecallPetition,
+
+        /// Recall petition stake is locked until the recall referendum ends.
+        RecallPetitionStakeStillNeeded,
     }
 }
 
@@ -539,6 +764,15 @@ decl_module! {
         /// Council member count
         const CouncilSize: u32 = T::CouncilSize::get();
 
+        /// Number of council seats up for election in every cycle.
+        const StaggeredSeatsPerCycle: u32 = T::StaggeredSeatsPerCycle::get();
+
+        /// Maximum number of consecutive terms a councilor can serve.
+        const MaxConsecutiveTerms: u32 = T::MaxConsecutiveTerms::get();
+
+        /// Maximum number of runners-up kept to replace recalled councilors.
+        const MaxRunnersUp: u32 = T::MaxRunnersUp::get();
+
         /// Minimum stake candidate has to lock
         const MinCandidateStake: Balance<T> = T::MinCandidateStake::get();
 
@@ -560,6 +794,15 @@ decl_module! {
         /// Exports const - councilor lock id.
         const CouncilorLockId: LockIdentifier = T::CouncilorLock::lock_id();
 
+        /// Exports const - recall petition lock id.
+        const RecallPetitionLockId: LockIdentifier = T::RecallPetitionLock::lock_id();
+
+        /// Minimum stake a member has to lock to back a recall petition.
+        const MinRecallPetitionStake: Balance<T> = T::MinRecallPetitionStake::get();
+
+        /// Total recall petition stake needed to start the recall referendum.
+        const RecallPetitionThreshold: Balance<T> = T::RecallPetitionThreshold::get();
+
         /////////////////// Lifetime ///////////////////////////////////////////
 
         // No origin so this is a priviledged call
@@ -991,6 +1234,100 @@ decl_module! {
 
             Self::deposit_event(RawEvent::CandidateRemarked(candidate_id, msg));
         }
+
+        /////////////////// Recall-related /////////////////////////////////////
+
+        /// Back a recall petition against a councilor. Starts the recall referendum once the
+        /// total petition stake reaches the threshold. If the councilor is recalled while no
+        /// runner-up of the last election is available to take over, the seat is left vacant
+        /// for the rest of the term.
+        ///
+        /// # <weight>
+        ///
+        /// ## weight
+        /// `O (C)` where:
+        /// `C` is the council size (the councilor is looked up among the council members)
+        /// - db:
+        ///    - `O(1)` doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = CouncilWeightInfo::<T>::back_recall_petition()]
+        pub fn back_recall_petition(
+            origin,
+            member_id: T::MemberId,
+            councilor_id: T::MemberId,
+            staking_account_id: T::AccountId,
+            stake: Balance<T>,
+        ) -> Result<(), Error<T>> {
+            let petition = EnsureChecks::<T>::can_back_recall_petition(
+                origin,
+                &member_id,
+                &councilor_id,
+                &staking_account_id,
+                &stake,
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let petition = Mutations::<T>::back_recall_petition(
+                &member_id,
+                &councilor_id,
+                &staking_account_id,
+                &stake,
+                petition,
+            );
+
+            Self::deposit_event(RawEvent::RecallPetitionBacked(
+                petition.petition_id,
+                councilor_id,
+                member_id,
+                staking_account_id,
+                stake,
+            ));
+
+            let petition_id = petition.petition_id;
+            if petition.total_stake >= T::RecallPetitionThreshold::get() {
+                Mutations::<T>::start_recall_referendum(&councilor_id, petition);
+
+                Self::deposit_event(RawEvent::RecallReferendumStarted(petition_id, councilor_id));
+            }
+
+            Ok(())
+        }
+
+        /// Release recall petition stake. Petition support can be withdrawn while the petition
+        /// hasn't reached the threshold yet.
+        ///
+        /// # <weight>
+        ///
+        /// ## weight
+        /// `O (1)`
+        /// - db:
+        ///    - `O(1)` doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = CouncilWeightInfo::<T>::release_recall_petition_stake()]
+        pub fn release_recall_petition_stake(
+            origin,
+            member_id: T::MemberId,
+            petition_id: u64,
+        ) -> Result<(), Error<T>> {
+            let backer = EnsureChecks::<T>::can_release_recall_petition_stake(
+                origin,
+                &member_id,
+                &petition_id,
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Mutations::<T>::release_recall_petition_stake(&member_id, &petition_id, &backer);
+
+            Self::deposit_event(RawEvent::RecallPetitionStakeReleased(petition_id, member_id));
+
+            Ok(())
+        }
     }
 }
 
@@ -1039,7 +1376,7 @@ impl<T: Config> Module<T> {
     // Finish voting and start ravealing.
     fn end_announcement_period(candidates_count: u32) {
         let min_candidate_count =
-            T::CouncilSize::get().saturating_add(T::MinNumberOfExtraCandidates::get());
+            Self::seats_up_for_election().saturating_add(T::MinNumberOfExtraCandidates::get());
 
         // reset announcing period when not enough candidates registered
         if candidates_count < min_candidate_count {
@@ -1067,8 +1404,8 @@ impl<T: Config> Module<T> {
             VotePowerOf<T>,
         >],
     ) {
-        let council_size = T::CouncilSize::get();
-        if winners.len() as u32 != council_size {
+        let seats_up_for_election = Self::seats_up_for_election();
+        if (winners.len() as u32) < seats_up_for_election {
             // reset candidacy announcement period
             let new_announcing_period_end_block = Mutations::<T>::start_announcing_period();
 
@@ -1082,6 +1419,14 @@ impl<T: Config> Module<T> {
 
         let now: T::BlockNumber = <frame_system::Pallet<T>>::block_number();
 
+        // winners beyond the seats up for election are the ranked runners-up
+        let (winners, runners_up) = winners.split_at(seats_up_for_election.saturated_into());
+        let runners_up: Vec<T::MemberId> = runners_up
+            .iter()
+            .map(|item| item.option_id)
+            .take(T::MaxRunnersUp::get().saturated_into())
+            .collect();
+
         // prepare candidates that got elected
         let elected_members: Vec<CouncilMemberOf<T>> = winners
             .iter()
@@ -1101,7 +1446,8 @@ impl<T: Config> Module<T> {
             .collect();
 
         // update state
-        Mutations::<T>::elect_new_council(elected_members.as_slice(), now);
+        let new_council =
+            Mutations::<T>::elect_new_council(elected_members.as_slice(), runners_up, now);
 
         // emit event
         Self::deposit_event(RawEvent::NewCouncilElected(
@@ -1110,7 +1456,7 @@ impl<T: Config> Module<T> {
         ));
 
         // trigger new-council-elected hook
-        T::new_council_elected(elected_members.as_slice());
+        T::new_council_elected(new_council.as_slice());
     }
 
     // Finish idle period and start new council election cycle (announcing period).
@@ -1124,6 +1470,55 @@ impl<T: Config> Module<T> {
         ));
     }
 
+    /////////////////// Recall-related /////////////////////////////////////
+
+    // Conclude the recall referendum and remove the councilor when the majority of the vote power
+    // is in favor of the removal.
+    fn end_recall_referendum(recall: RecallReferendumOf<T>) {
+        Mutations::<T>::finalize_recall_petition(&recall);
+
+        let is_councilor = CouncilMembers::<T>::get()
+            .iter()
+            .any(|council_member| council_member.membership_id == recall.councilor_id);
+
+        // the councilor might have already lost their seat in the regular election
+        if !is_councilor || recall.remove_vote_power <= recall.keep_vote_power {
+            Self::deposit_event(RawEvent::CouncilorRecallRejected(recall.councilor_id));
+
+            return;
+        }
+
+        let replacement_id = Mutations::<T>::recall_councilor(&recall.councilor_id);
+
+        Self::deposit_event(RawEvent::CouncilorRecalled(
+            recall.councilor_id,
+            replacement_id,
+        ));
+    }
+
+    // Finds the highest ranked runner-up of the last successful election that still has their
+    // candidacy stake locked and isn't a candidate in the ongoing election.
+    fn next_runner_up() -> Option<(T::MemberId, CandidateOf<T>)> {
+        let is_idle = matches!(Stage::<T>::get().stage, CouncilStage::Idle(_));
+        let cycle_id = AnnouncementPeriodNr::get();
+
+        RunnersUp::<T>::get().iter().find_map(|membership_id| {
+            Candidates::<T>::get(membership_id)
+                .filter(|candidate| is_idle || candidate.cycle_id != cycle_id)
+                .map(|candidate| (*membership_id, candidate))
+        })
+    }
+
+    // Recall vote option representing the councilor removal.
+    fn recall_option_remove() -> T::MemberId {
+        One::one()
+    }
+
+    // Recall vote option representing keeping the councilor.
+    fn recall_option_keep() -> T::MemberId {
+        Zero::zero()
+    }
+
     /////////////////// Budget-related /////////////////////////////////////
 
     // Refill (increase) the budget's balance.
@@ -1222,6 +1617,39 @@ impl<T: Config> Module<T> {
         }
     }
 
+    // Number of councilors replaced in every election cycle.
+    fn seats_rotated_per_cycle() -> u32 {
+        let council_size = T::CouncilSize::get();
+        let staggered_seats = T::StaggeredSeatsPerCycle::get();
+
+        if staggered_seats.is_zero() || staggered_seats > council_size {
+            council_size
+        } else {
+            staggered_seats
+        }
+    }
+
+    // Splits current council members into those whose seats are up for election and those who
+    // keep their seats for the next term. Councilors elected in the oldest cycle go first.
+    fn split_council_by_seat_rotation() -> (Vec<CouncilMemberOf<T>>, Vec<CouncilMemberOf<T>>) {
+        let mut council_members = CouncilMembers::<T>::get().to_vec();
+
+        // stable sort keeps the election rank order of councilors elected in the same cycle
+        council_members.sort_by_key(|council_member| council_member.elected_in_cycle);
+
+        let outgoing_count = council_members
+            .len()
+            .min(Self::seats_rotated_per_cycle().saturated_into());
+        let staying_members = council_members.split_off(outgoing_count);
+
+        (council_members, staying_members)
+    }
+
+    // Checks that the councilor's seat is up for election in the current election cycle.
+    fn is_outgoing_councilor(membership_id: &T::MemberId) -> bool {
+        OutgoingCouncilors::<T>::get().contains(membership_id)
+    }
+
     fn calculate_on_initialize_weight(mb_candidate_count: Option<u32>) -> Weight {
         // Minimum weight for progress stage
         let weight = CouncilWeightInfo::<T>::try_progress_stage_idle()
@@ -1242,11 +1670,16 @@ impl<T: Config> Module<T> {
         };
 
         // Total weight = try progress weight + refill budget weight
-        //      + pay council member rewards weight
+        //      + pay council member rewards weight + recall referendum end weight
+        // (recall referendum results are processed in the recall referendum's hook, so the worst
+        // case of replacing the recalled councilor is accounted for here)
         CouncilWeightInfo::<T>::try_process_budget_refill_budget_only()
             .saturating_add(
                 CouncilWeightInfo::<T>::try_process_budget_payout_council_members_only(),
             )
+            .saturating_add(CouncilWeightInfo::<T>::end_recall_referendum(
+                T::MaxRunnersUp::get(),
+            ))
             .saturating_add(weight)
     }
 }
@@ -1325,6 +1758,66 @@ impl<T: Config> ReferendumConnection<T> for Module<T> {
     }
 }
 
+impl<T: Config> RecallReferendumConnection<T> for Module<T> {
+    // Process recall results recieved from the recall referendum. The outcome is decided by the
+    // vote power tallies kept by this module.
+    fn recieve_recall_referendum_results(
+        _winners: &[OptionResult<
+            <T as common::membership::MembershipTypes>::MemberId,
+            RecallVotePowerOf<T>,
+        >],
+    ) {
+        //
+        // == MUTATION SAFE ==
+        //
+
+        if let Some(recall) = CurrentRecall::<T>::get() {
+            Self::end_recall_referendum(recall);
+        }
+    }
+
+    // Recall vote stake can be released unless the recall referendum it was cast in is running.
+    fn can_unlock_recall_vote_stake(vote: &CastVoteOf<T>) -> Result<(), Error<T>> {
+        match CurrentRecall::<T>::get() {
+            Some(recall) if recall.petition_id == vote.cycle_id => Err(Error::CantReleaseStakeNow),
+            _ => Ok(()),
+        }
+    }
+
+    // Checks that a recall referendum is running and the option is valid.
+    fn is_valid_recall_option_id(option_id: &T::MemberId) -> bool {
+        CurrentRecall::<T>::exists()
+            && (*option_id == Self::recall_option_remove()
+                || *option_id == Self::recall_option_keep())
+    }
+
+    // Return current voting power for a recall option.
+    fn get_recall_option_power(option_id: &T::MemberId) -> RecallVotePowerOf<T> {
+        CurrentRecall::<T>::get().map_or(Zero::zero(), |recall| {
+            if *option_id == Self::recall_option_remove() {
+                recall.remove_vote_power
+            } else {
+                recall.keep_vote_power
+            }
+        })
+    }
+
+    // Recieve vote (power) for a recall option.
+    fn increase_recall_option_power(option_id: &T::MemberId, amount: &RecallVotePowerOf<T>) {
+        let remove = *option_id == Self::recall_option_remove();
+
+        CurrentRecall::<T>::mutate(|maybe_recall| {
+            if let Some(recall) = maybe_recall {
+                if remove {
+                    recall.remove_vote_power = recall.remove_vote_power.saturating_add(*amount);
+                } else {
+                    recall.keep_vote_power = recall.keep_vote_power.saturating_add(*amount);
+                }
+            }
+        });
+    }
+}
+
 /////////////////// Calculations ///////////////////////////////////////////////
 
 struct Calculations<T: Config> {
@@ -1395,12 +1888,28 @@ impl<T: Config> Mutations<T> {
         // increase anouncement cycle id
         AnnouncementPeriodNr::mutate(|value| *value = value.saturating_add(1));
 
+        // decide which seats are up for election (rotated and vacant seats)
+        let (outgoing_members, staying_members) = Module::<T>::split_council_by_seat_rotation();
+        OutgoingCouncilors::<T>::put(WeakBoundedVec::<_, _>::force_from(
+            outgoing_members
+                .iter()
+                .map(|council_member| council_member.membership_id)
+                .collect(),
+            Some("OutgoingCouncilors"),
+        ));
+        SeatsUpForElection::put(
+            T::CouncilSize::get().saturating_sub(staying_members.len().saturated_into()),
+        );
+
         ends_at
     }
 
     // Change the council stage from the announcing to the election stage.
     fn finalize_announcing_period(candidates_count: u32) {
-        let extra_winning_target_count = T::CouncilSize::get().saturating_sub(1);
+        // runners-up are ranked as well to replace recalled councilors
+        let extra_winning_target_count = Module::<T>::seats_up_for_election()
+            .saturating_add(T::MaxRunnersUp::get())
+            .saturating_sub(1);
 
         // start referendum
         T::Referendum::force_start(extra_winning_target_count, AnnouncementPeriodNr::get());
@@ -1414,8 +1923,13 @@ impl<T: Config> Mutations<T> {
         });
     }
 
-    // Elect new council after successful election.
-    fn elect_new_council(elected_members: &[CouncilMemberOf<T>], now: T::BlockNumber) {
+    // Elect new council after successful election. Returns the whole new council - the newly
+    // elected members follow the councilors whose seats were not up for election.
+    fn elect_new_council(
+        elected_members: &[CouncilMemberOf<T>],
+        runners_up: Vec<T::MemberId>,
+        now: T::BlockNumber,
+    ) -> Vec<CouncilMemberOf<T>> {
         // change council state
         Stage::<T>::mutate(|value| {
             *value = CouncilStageUpdate {
@@ -1429,22 +1943,72 @@ impl<T: Config> Mutations<T> {
         // try to pay any unpaid rewards (any unpaid rewards after this will be discarded call)
         Module::<T>::pay_elected_member_rewards(now);
 
-        // release stakes for previous council members
-        for council_member in CouncilMembers::<T>::get() {
+        // split council after the reward payment so that staying members keep their updated
+        // reward records
+        let (outgoing_members, staying_members): (Vec<_>, Vec<_>) = CouncilMembers::<T>::get()
+            .to_vec()
+            .into_iter()
+            .partition(|council_member| {
+                Module::<T>::is_outgoing_councilor(&council_member.membership_id)
+            });
+
+        // release stakes for council members whose seats were up for election
+        for council_member in outgoing_members.iter() {
             T::CouncilorLock::unlock(&council_member.staking_account_id);
         }
 
+        // update consecutive terms counters
+        Self::update_councilor_terms(&outgoing_members, elected_members);
+
+        // recall petitions don't carry over to the next term
+        Self::clear_recall_petitions(&outgoing_members);
+
         // set new council
+        let new_council: Vec<CouncilMemberOf<T>> = staying_members
+            .into_iter()
+            .chain(elected_members.iter().cloned())
+            .collect();
         CouncilMembers::<T>::put(WeakBoundedVec::<_, _>::force_from(
-            elected_members.to_vec(),
+            new_council.clone(),
             Some("CouncilMembers"),
         ));
 
         // setup elected member lock for new council's members
-        for council_member in CouncilMembers::<T>::get() {
+        for council_member in elected_members {
             // lock council member stake
             T::CouncilorLock::lock(&council_member.staking_account_id, council_member.stake);
         }
+
+        // keep runners-up to replace recalled councilors
+        RunnersUp::<T>::put(WeakBoundedVec::<_, _>::force_from(
+            runners_up,
+            Some("RunnersUp"),
+        ));
+
+        new_council
+    }
+
+    // Increase consecutive terms of (re)elected councilors and reset terms of outgoing
+    // councilors that weren't reelected.
+    fn update_councilor_terms(
+        outgoing_members: &[CouncilMemberOf<T>],
+        elected_members: &[CouncilMemberOf<T>],
+    ) {
+        for council_member in outgoing_members {
+            let reelected = elected_members
+                .iter()
+                .any(|elected| elected.membership_id == council_member.membership_id);
+
+            if !reelected {
+                CouncilorTerms::<T>::remove(council_member.membership_id);
+            }
+        }
+
+        for council_member in elected_members {
+            CouncilorTerms::<T>::mutate(council_member.membership_id, |terms| {
+                *terms = terms.saturating_add(1)
+            });
+        }
     }
 
     // Announce user's candidacy.
@@ -1538,6 +2102,197 @@ impl<T: Config> Mutations<T> {
         Candidates::<T>::remove(membership_id);
     }
 
+    /////////////////// Recall-related /////////////////////////////////////////
+
+    // Remove recall petitions that didn't reach the threshold against outgoing councilors. Their
+    // backers can release their stakes right away.
+    fn clear_recall_petitions(outgoing_members: &[CouncilMemberOf<T>]) {
+        for council_member in outgoing_members {
+            let referendum_started = RecallPetitions::<T>::get(council_member.membership_id)
+                .map_or(false, |petition| petition.referendum_started);
+
+            if !referendum_started {
+                RecallPetitions::<T>::remove(council_member.membership_id);
+            }
+        }
+    }
+
+    // Back (or create) a recall petition against a councilor.
+    fn back_recall_petition(
+        member_id: &T::MemberId,
+        councilor_id: &T::MemberId,
+        staking_account_id: &T::AccountId,
+        stake: &Balance<T>,
+        petition: Option<RecallPetitionOf<T>>,
+    ) -> RecallPetitionOf<T> {
+        let petition = petition.unwrap_or_else(|| {
+            let petition_id = NextRecallPetitionId::get();
+            NextRecallPetitionId::put(petition_id.saturating_add(1));
+
+            RecallPetition {
+                petition_id,
+                ..Default::default()
+            }
+        });
+
+        let petition = RecallPetition {
+            total_stake: petition.total_stake.saturating_add(*stake),
+            backers_count: petition.backers_count.saturating_add(1),
+            ..petition
+        };
+
+        T::RecallPetitionLock::lock(staking_account_id, *stake);
+
+        RecallPetitionBackers::<T>::insert(
+            petition.petition_id,
+            member_id,
+            RecallPetitionBacker {
+                councilor_id: *councilor_id,
+                staking_account_id: staking_account_id.clone(),
+                stake: *stake,
+            },
+        );
+        RecallPetitions::<T>::insert(councilor_id, petition.clone());
+
+        petition
+    }
+
+    // Release recall petition backer's stake and withdraw their support if the petition is still
+    // collecting stake.
+    fn release_recall_petition_stake(
+        member_id: &T::MemberId,
+        petition_id: &u64,
+        backer: &RecallPetitionBackerOf<T>,
+    ) {
+        T::RecallPetitionLock::unlock(&backer.staking_account_id);
+
+        RecallPetitionBackers::<T>::remove(petition_id, member_id);
+
+        RecallPetitions::<T>::mutate_exists(backer.councilor_id, |maybe_petition| {
+            let petition = match maybe_petition {
+                Some(petition) if petition.petition_id == *petition_id => petition,
+                _ => return,
+            };
+
+            petition.total_stake = petition.total_stake.saturating_sub(backer.stake);
+            petition.backers_count = petition.backers_count.saturating_sub(1);
+
+            if petition.backers_count.is_zero() {
+                *maybe_petition = None;
+            }
+        });
+    }
+
+    // Start the recall referendum for the petition that reached the threshold.
+    fn start_recall_referendum(councilor_id: &T::MemberId, petition: RecallPetitionOf<T>) {
+        // two winners are requested so both options are ranked
+        T::RecallReferendum::force_start(1, petition.petition_id);
+
+        CurrentRecall::<T>::put(RecallReferendum {
+            councilor_id: *councilor_id,
+            petition_id: petition.petition_id,
+            remove_vote_power: Zero::zero(),
+            keep_vote_power: Zero::zero(),
+        });
+
+        RecallPetitions::<T>::insert(
+            councilor_id,
+            RecallPetition {
+                referendum_started: true,
+                ..petition
+            },
+        );
+    }
+
+    // Clear the concluded recall referendum and its petition so its backers can release stakes.
+    fn finalize_recall_petition(recall: &RecallReferendumOf<T>) {
+        CurrentRecall::<T>::kill();
+
+        let is_recall_petition = RecallPetitions::<T>::get(recall.councilor_id)
+            .map_or(false, |petition| petition.petition_id == recall.petition_id);
+
+        if is_recall_petition {
+            RecallPetitions::<T>::remove(recall.councilor_id);
+        }
+    }
+
+    // Remove the councilor from the council and give their seat to the highest ranked available
+    // runner-up of the last successful election if there is any. Otherwise the seat is left
+    // vacant for the rest of the term and is up for the first election that starts after the
+    // recall. Unpaid councilor reward is discarded.
+    fn recall_councilor(councilor_id: &T::MemberId) -> Option<T::MemberId> {
+        let mut council_members = CouncilMembers::<T>::get().to_vec();
+
+        let recalled_member = council_members
+            .iter()
+            .find(|council_member| council_member.membership_id == *councilor_id)
+            .cloned();
+
+        if let Some(council_member) = recalled_member.as_ref() {
+            T::CouncilorLock::unlock(&council_member.staking_account_id);
+        }
+
+        council_members.retain(|council_member| council_member.membership_id != *councilor_id);
+        CouncilorTerms::<T>::remove(councilor_id);
+
+        let replacement = Module::<T>::next_runner_up();
+
+        let replacement_id = replacement.map(|(membership_id, candidate)| {
+            let now: T::BlockNumber = <frame_system::Pallet<T>>::block_number();
+
+            // move the candidacy stake to the councilor lock
+            Self::clear_candidate(&membership_id, &candidate);
+            T::CouncilorLock::lock(&candidate.staking_account_id, candidate.stake);
+
+            RunnersUp::<T>::mutate(|runners_up| {
+                *runners_up = WeakBoundedVec::<_, _>::force_from(
+                    runners_up
+                        .iter()
+                        .filter(|runner_up_id| **runner_up_id != membership_id)
+                        .cloned()
+                        .collect(),
+                    Some("RunnersUp"),
+                );
+            });
+
+            // the replacement inherits the seat of the recalled councilor (including whether it
+            // is up for the ongoing election)
+            OutgoingCouncilors::<T>::mutate(|outgoing_councilors| {
+                *outgoing_councilors = WeakBoundedVec::<_, _>::force_from(
+                    outgoing_councilors
+                        .iter()
+                        .map(|outgoing_id| {
+                            if outgoing_id == councilor_id {
+                                membership_id
+                            } else {
+                                *outgoing_id
+                            }
+                        })
+                        .collect(),
+                    Some("OutgoingCouncilors"),
+                );
+            });
+
+            let mut council_member: CouncilMemberOf<T> =
+                (candidate, membership_id, now, Zero::zero()).into();
+            if let Some(recalled_member) = recalled_member.as_ref() {
+                council_member.elected_in_cycle = recalled_member.elected_in_cycle;
+            }
+
+            CouncilorTerms::<T>::insert(membership_id, 1);
+            council_members.push(council_member);
+
+            membership_id
+        });
+
+        CouncilMembers::<T>::put(WeakBoundedVec::<_, _>::force_from(
+            council_members,
+            Some("CouncilMembers"),
+        ));
+
+        replacement_id
+    }
+
     /////////////////// Budget-related /////////////////////////////////////////
 
     // Set budget balance
@@ -1667,6 +2422,22 @@ impl<T: Config> EnsureChecks<T> {
             existing_staking_account_id = Some(candidate.staking_account_id);
         }
 
+        // ensure a councilor only candidates when their seat is up for election
+        let is_councilor = CouncilMembers::<T>::get()
+            .iter()
+            .any(|council_member| council_member.membership_id == *membership_id);
+        if is_councilor && !Module::<T>::is_outgoing_councilor(membership_id) {
+            return Err(Error::CouncilorSeatNotUpForElection);
+        }
+
+        // ensure councilor hasn't reached the consecutive terms limit
+        let max_consecutive_terms = T::MaxConsecutiveTerms::get();
+        if !max_consecutive_terms.is_zero()
+            && Module::<T>::councilor_terms(membership_id) >= max_consecutive_terms
+        {
+            return Err(Error::ConsecutiveTermsLimitReached);
+        }
+
         // ensure stake is above minimal threshold
         if stake < &T::MinCandidateStake::get() {
             return Err(Error::CandidacyStakeTooLow);
@@ -1750,6 +2521,92 @@ impl<T: Config> EnsureChecks<T> {
         })
     }
 
+    // Ensures there is no problem in backing a recall petition. Returns the existing petition
+    // against the councilor.
+    fn can_back_recall_petition(
+        origin: T::Origin,
+        member_id: &T::MemberId,
+        councilor_id: &T::MemberId,
+        staking_account_id: &T::AccountId,
+        stake: &Balance<T>,
+    ) -> Result<Option<RecallPetitionOf<T>>, Error<T>> {
+        // ensure user's membership
+        Self::ensure_user_membership(origin, member_id)?;
+
+        // ensure staking account's membership
+        if !T::StakingAccountValidator::is_member_staking_account(member_id, staking_account_id) {
+            return Err(Error::MemberIdNotMatchAccount);
+        }
+
+        // ensure the recall target is a councilor
+        if !CouncilMembers::<T>::get()
+            .iter()
+            .any(|council_member| council_member.membership_id == *councilor_id)
+        {
+            return Err(Error::NotCouncilor);
+        }
+
+        // only one recall referendum can run at a time
+        if CurrentRecall::<T>::exists() {
+            return Err(Error::RecallReferendumRunning);
+        }
+
+        let petition = RecallPetitions::<T>::get(councilor_id);
+
+        // prevent member from backing the same petition twice
+        if let Some(petition) = petition.as_ref() {
+            if RecallPetitionBackers::<T>::contains_key(petition.petition_id, member_id) {
+                return Err(Error::AlreadyBackingRecallPetition);
+            }
+        }
+
+        // ensure stake is above minimal threshold
+        if stake < &T::MinRecallPetitionStake::get() {
+            return Err(Error::RecallPetitionStakeTooLow);
+        }
+
+        // the staking account can back only a single petition at a time
+        if !T::RecallPetitionLock::current_stake(staking_account_id).is_zero() {
+            return Err(Error::RecallPetitionStakingAccountInUse);
+        }
+
+        // ensure there are no conflicting stake types for the account
+        if !T::RecallPetitionLock::is_account_free_of_conflicting_stakes(staking_account_id) {
+            return Err(Error::ConflictingStake);
+        }
+
+        // ensure user has enough balance
+        if !T::RecallPetitionLock::is_enough_balance_for_stake(staking_account_id, *stake) {
+            return Err(Error::InsufficientBalanceForStaking);
+        }
+
+        Ok(petition)
+    }
+
+    // Ensures there is no problem in releasing recall petition stake.
+    fn can_release_recall_petition_stake(
+        origin: T::Origin,
+        member_id: &T::MemberId,
+        petition_id: &u64,
+    ) -> Result<RecallPetitionBackerOf<T>, Error<T>> {
+        // ensure user's membership
+        Self::ensure_user_membership(origin, member_id)?;
+
+        let backer = RecallPetitionBackers::<T>::get(petition_id, member_id)
+            .ok_or(Error::NotBackingRecallPetition)?;
+
+        // prevent releasing stake while the recall referendum started by the petition is running
+        let referendum_started = RecallPetitions::<T>::get(backer.councilor_id)
+            .map_or(false, |petition| {
+                petition.petition_id == *petition_id && petition.referendum_started
+            });
+        if referendum_started {
+            return Err(Error::RecallPetitionStakeStillNeeded);
+        }
+
+        Ok(backer)
+    }
+
     // Ensures there is no problem in setting the budget balance.
     fn can_set_budget(origin: T::Origin) -> Result<(), Error<T>> {
         ensure_root(origin)?;
@@ -0,0 +1,58 @@
+// Storage migrations of the council pallet.
+
+use codec::{Decode, Encode};
+use frame_support::storage::weak_bounded_vec::WeakBoundedVec;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use frame_support::StorageValue;
+use sp_std::vec::Vec;
+
+use crate::{AnnouncementPeriodNr, Balance, Config, CouncilMemberOf, CouncilMembers, Module};
+
+/// Council member stored before the staggered council seats were introduced
+#[derive(Encode, Decode)]
+pub(crate) struct LegacyCouncilMember<AccountId, MemberId, Balance, BlockNumber> {
+    pub staking_account_id: AccountId,
+    pub reward_account_id: AccountId,
+    pub membership_id: MemberId,
+    pub stake: Balance,
+    pub last_payment_block: BlockNumber,
+    pub unpaid_reward: Balance,
+}
+
+pub(crate) type LegacyCouncilMemberOf<T> = LegacyCouncilMember<
+    <T as frame_system::Config>::AccountId,
+    <T as common::membership::MembershipTypes>::MemberId,
+    Balance<T>,
+    <T as frame_system::Config>::BlockNumber,
+>;
+
+impl<T: Config> Module<T> {
+    /// Translates the stored council members to the current `CouncilMember` format. The sitting
+    /// councilors are all considered elected in the current announcement cycle.
+    pub fn migrate_council_members() -> Weight {
+        let elected_in_cycle = AnnouncementPeriodNr::get();
+
+        let _ = CouncilMembers::<T>::translate::<Vec<LegacyCouncilMemberOf<T>>, _>(|members| {
+            members.map(|members| {
+                WeakBoundedVec::force_from(
+                    members
+                        .into_iter()
+                        .map(|member| CouncilMemberOf::<T> {
+                            staking_account_id: member.staking_account_id,
+                            reward_account_id: member.reward_account_id,
+                            membership_id: member.membership_id,
+                            stake: member.stake,
+                            last_payment_block: member.last_payment_block,
+                            unpaid_reward: member.unpaid_reward,
+                            elected_in_cycle,
+                        })
+                        .collect(),
+                    Some("CouncilMembers"),
+                )
+            })
+        });
+
+        T::DbWeight::get().reads_writes(2, 1)
+    }
+}
@@ -6,7 +6,7 @@ use crate::{
     AnnouncementPeriodNr, Balance, Budget, BudgetIncrement, CandidateOf, Candidates, Config,
     CouncilMemberOf, CouncilMembers, CouncilStage, CouncilStageAnnouncing, CouncilStageElection,
     CouncilStageIdle, CouncilStageUpdate, CouncilorReward, Error, Module, NextBudgetRefill,
-    RawEvent, ReferendumConnection, Stage,
+    RawEvent, RecallReferendumConnection, ReferendumConnection, Stage,
 };
 
 use frame_support::dispatch::{DispatchError, DispatchResult};
@@ -15,7 +15,7 @@ use frame_support::traits::{
     OnInitialize, WithdrawReasons,
 };
 
-use frame_support::{ensure, parameter_types, StorageMap, StorageValue};
+use frame_support::{assert_ok, ensure, parameter_types, StorageMap, StorageValue};
 use frame_system::{ensure_signed, EnsureRoot, EnsureSigned, EventRecord, Phase, RawOrigin};
 use rand::Rng;
 use referendum::{
@@ -50,6 +50,24 @@ pub const TOPUP_MULTIPLIER: u64 = 10;
 thread_local! {
     // new council elected recieved by `new_council_elected hook`
     pub static LAST_COUNCIL_ELECTED_OK: RefCell<(bool, )> = RefCell::new((false, ));
+
+    // council seat rotation settings; whole council replaced and no term limit by default
+    pub static STAGGERED_SEATS_PER_CYCLE: RefCell<u32> = RefCell::new(0);
+    pub static MAX_CONSECUTIVE_TERMS: RefCell<u32> = RefCell::new(0);
+}
+
+pub struct StaggeredSeatsPerCycle;
+impl Get<u32> for StaggeredSeatsPerCycle {
+    fn get() -> u32 {
+        STAGGERED_SEATS_PER_CYCLE.with(|value| *value.borrow())
+    }
+}
+
+pub struct MaxConsecutiveTerms;
+impl Get<u32> for MaxConsecutiveTerms {
+    fn get() -> u32 {
+        MAX_CONSECUTIVE_TERMS.with(|value| *value.borrow())
+    }
 }
 
 parameter_types! {
@@ -60,6 +78,10 @@ parameter_types! {
     pub const MinCandidateStake: u64 = 11000;
     pub const CandidacyLockId: LockIdentifier = *b"council1";
     pub const CouncilorLockId: LockIdentifier = *b"council2";
+    pub const RecallPetitionLockId: LockIdentifier = *b"council3";
+    pub const MinRecallPetitionStake: u64 = 1000;
+    pub const RecallPetitionThreshold: u64 = 3000;
+    pub const MaxRunnersUp: u32 = 3;
     pub const ElectedMemberRewardPeriod: u64 = 10;
     // intentionally high number that prevents side-effecting tests other than  budget refill tests
     pub const BudgetRefillPeriod: u64 = 1000;
@@ -74,15 +96,22 @@ impl Config for Runtime {
     type Event = Event;
 
     type Referendum = referendum::Module<Runtime, ReferendumInstance>;
+    type RecallReferendum = referendum::Module<Runtime, RecallReferendumInstance>;
 
     type MinNumberOfExtraCandidates = MinNumberOfExtraCandidates;
     type CouncilSize = CouncilSize;
+    type StaggeredSeatsPerCycle = StaggeredSeatsPerCycle;
+    type MaxConsecutiveTerms = MaxConsecutiveTerms;
+    type MaxRunnersUp = MaxRunnersUp;
     type AnnouncingPeriodDuration = AnnouncingPeriodDuration;
     type IdlePeriodDuration = IdlePeriodDuration;
     type MinCandidateStake = MinCandidateStake;
 
     type CandidacyLock = StakingManager<Self, CandidacyLockId>;
     type CouncilorLock = StakingManager<Self, CouncilorLockId>;
+    type RecallPetitionLock = StakingManager<Self, RecallPetitionLockId>;
+    type MinRecallPetitionStake = MinRecallPetitionStake;
+    type RecallPetitionThreshold = RecallPetitionThreshold;
 
     type ElectedMemberRewardPeriod = ElectedMemberRewardPeriod;
 
@@ -144,6 +173,7 @@ frame_support::construct_runtime!(
         Council: council::{Pallet, Call, Storage, Event<T>},
         Membership: membership::{Pallet, Call, Storage, Event<T>},
         Referendum: referendum::<Instance1>::{Pallet, Call, Storage, Event<T>},
+        RecallReferendum: referendum::<Instance2>::{Pallet, Call, Storage, Event<T>},
         Balances: balances::{Pallet, Call, Storage, Config<T>, Event<T>},
     }
 );
@@ -182,6 +212,7 @@ impl frame_system::Config for Runtime {
 /////////////////// Election module ////////////////////////////////////////////
 
 pub type ReferendumInstance = referendum::Instance1;
+pub type RecallReferendumInstance = referendum::Instance2;
 
 thread_local! {
     // global switch for stake locking features; use it to simulate lock fails
@@ -197,6 +228,7 @@ parameter_types! {
     pub const MinimumVotingStake: u64 = 10000;
     pub const MaxSaltLength: u64 = 32; // use some multiple of 8 for ez testing
     pub const VotingLockId: LockIdentifier = *b"referend";
+    pub const RecallVotingLockId: LockIdentifier = *b"recallvo";
     pub const DefaultMembershipPrice: u64 = 100;
     pub const DefaultInitialInvitationBalance: u64 = 100;
     pub const DefaultMemberInvitesCount: u32 = 2;
@@ -225,6 +257,7 @@ impl referendum::Config<ReferendumInstance> for Runtime {
     type WeightInfo = ();
 
     type MaxWinnerTargetCount = MaxWinnerTargetCount;
+    type MaxDelegatorsPerDelegate = ConstU32<10>;
 
     fn calculate_vote_power(
         account_id: &<Self as frame_system::Config>::AccountId,
@@ -279,6 +312,60 @@ impl referendum::Config<ReferendumInstance> for Runtime {
     }
 }
 
+impl referendum::Config<RecallReferendumInstance> for Runtime {
+    type Event = Event;
+
+    type MaxSaltLength = MaxSaltLength;
+
+    type ManagerOrigin = EnsureOneOf<EnsureSigned<Self::AccountId>, EnsureRoot<Self::AccountId>>;
+
+    type VotePower = u64;
+
+    type VoteStageDuration = VoteStageDuration;
+    type StakingHandler = staking_handler::StakingManager<Self, RecallVotingLockId>;
+    type RevealStageDuration = RevealStageDuration;
+
+    type MinimumStake = MinimumVotingStake;
+    type WeightInfo = ();
+
+    type MaxWinnerTargetCount = ConstU32<2>;
+    type MaxDelegatorsPerDelegate = ConstU32<10>;
+
+    fn calculate_vote_power(
+        _account_id: &<Self as frame_system::Config>::AccountId,
+        stake: &Balance<Self>,
+    ) -> Self::VotePower {
+        *stake
+    }
+
+    fn can_unlock_vote_stake(vote: &CastVote<Self::Hash, Balance<Self>, Self::MemberId>) -> bool {
+        <Module<Runtime> as RecallReferendumConnection<Runtime>>::can_unlock_recall_vote_stake(vote)
+            .is_ok()
+    }
+
+    fn process_results(winners: &[OptionResult<Self::MemberId, Self::VotePower>]) {
+        <Module<Runtime> as RecallReferendumConnection<Runtime>>::recieve_recall_referendum_results(
+            winners,
+        );
+    }
+
+    fn is_valid_option_id(option_index: &u64) -> bool {
+        <Module<Runtime> as RecallReferendumConnection<Runtime>>::is_valid_recall_option_id(
+            option_index,
+        )
+    }
+
+    fn get_option_power(option_id: &u64) -> Self::VotePower {
+        <Module<Runtime> as RecallReferendumConnection<Runtime>>::get_recall_option_power(option_id)
+    }
+
+    fn increase_option_power(option_id: &u64, amount: &Self::VotePower) {
+        <Module<Runtime> as RecallReferendumConnection<Runtime>>::increase_recall_option_power(
+            option_id, amount,
+        );
+    }
+}
+
 impl balances::Config for Runtime {
     type Balance = u64;
     type DustRemoval = ();
@@ -303,6 +390,13 @@ impl membership::Config for Runtime {
         staking_handler::StakingManager<Self, StakingCandidateLockId>;
     type CandidateStake = CandidateStake;
     type DefaultMemberInvitesCount = DefaultMemberInvitesCount;
+    type MemberReputationRules = ();
+    type MaxRecoveryGuardians = ();
+    type RecoveryDelay = ();
+    type RecoveryDeposit = ();
+    type HandleExpiryPeriod = ();
+    type HandleExpiryGracePeriod = ();
+    type MaxUplineReferralLevels = ();
 }
 
 pub struct Wg;
@@ -583,6 +677,9 @@ where
             <referendum::Module<Runtime, ReferendumInstance> as OnFinalize<
                 <Runtime as frame_system::Config>::BlockNumber,
             >>::on_finalize(block_number.into());
+            <referendum::Module<Runtime, RecallReferendumInstance> as OnFinalize<
+                <Runtime as frame_system::Config>::BlockNumber,
+            >>::on_finalize(block_number.into());
 
             block_number += 1.into();
             frame_system::Pallet::<T>::set_block_number(block_number);
@@ -591,6 +688,9 @@ where
             <referendum::Module<Runtime, ReferendumInstance> as OnInitialize<
                 <Runtime as frame_system::Config>::BlockNumber,
             >>::on_initialize(block_number.into());
+            <referendum::Module<Runtime, RecallReferendumInstance> as OnInitialize<
+                <Runtime as frame_system::Config>::BlockNumber,
+            >>::on_initialize(block_number.into());
         }
     }
 
@@ -1016,6 +1116,110 @@ where
         );
     }
 
+    pub fn back_recall_petition(
+        origin: OriginType<T::AccountId>,
+        member_id: T::MemberId,
+        councilor_id: T::MemberId,
+        stake: Balance<T>,
+        expected_result: Result<(), Error<T>>,
+    ) {
+        let staking_account_id: T::AccountId = member_id.into();
+
+        // check method returns expected result
+        assert_eq!(
+            Module::<T>::back_recall_petition(
+                InstanceMockUtils::<T>::mock_origin(origin),
+                member_id,
+                councilor_id,
+                staking_account_id.clone(),
+                stake,
+            ),
+            expected_result,
+        );
+
+        if expected_result.is_err() {
+            return;
+        }
+
+        assert_eq!(
+            T::RecallPetitionLock::current_stake(&staking_account_id),
+            stake,
+        );
+    }
+
+    pub fn release_recall_petition_stake(
+        origin: OriginType<T::AccountId>,
+        member_id: T::MemberId,
+        petition_id: u64,
+        expected_result: Result<(), Error<T>>,
+    ) {
+        // check method returns expected result
+        assert_eq!(
+            Module::<T>::release_recall_petition_stake(
+                InstanceMockUtils::<T>::mock_origin(origin),
+                member_id,
+                petition_id,
+            ),
+            expected_result,
+        );
+
+        if expected_result.is_err() {
+            return;
+        }
+
+        assert_eq!(
+            frame_system::Pallet::<Runtime>::events()
+                .last()
+                .unwrap()
+                .event,
+            Event::Council(RawEvent::RecallPetitionStakeReleased(
+                petition_id,
+                member_id.into(),
+            )),
+        );
+        assert_eq!(
+            T::RecallPetitionLock::current_stake(&member_id.into()),
+            0.into(),
+        );
+    }
+
+    pub fn vote_for_recall(
+        account_id: u64,
+        petition_id: u64,
+        vote_option: u64,
+        stake: u64,
+    ) -> Vec<u8> {
+        let salt = InstanceMockUtils::<Runtime>::generate_salt();
+        let commitment = <Runtime as Config>::RecallReferendum::calculate_commitment(
+            &account_id,
+            &salt,
+            &petition_id,
+            &vote_option,
+        );
+
+        let _ = balances::Pallet::<Runtime>::deposit_creating(&account_id, stake);
+
+        assert_ok!(
+            referendum::Module::<Runtime, RecallReferendumInstance>::vote(
+                RawOrigin::Signed(account_id).into(),
+                commitment,
+                stake,
+            )
+        );
+
+        salt
+    }
+
+    pub fn reveal_recall_vote(account_id: u64, salt: Vec<u8>, vote_option: u64) {
+        assert_ok!(
+            referendum::Module::<Runtime, RecallReferendumInstance>::reveal_vote(
+                RawOrigin::Signed(account_id).into(),
+                salt,
+                vote_option,
+            )
+        );
+    }
+
     pub fn set_budget(
         origin: OriginType<T::AccountId>,
         amount: Balance<T>,
@@ -1243,7 +1447,7 @@ where
 
         // referendum - start revealing period
         Self::check_referendum_revealing(
-            settings.council_size,
+            settings.council_size + T::MaxRunnersUp::get(),
             vec![],
             BTreeMap::new(), //<u64, T::VotePower>,
             params.cycle_start_block_number
@@ -2,7 +2,8 @@
 
 use super::{
     AnnouncementPeriodNr, Budget, BudgetIncrement, Config, CouncilMemberOf, CouncilMembers,
-    CouncilStageAnnouncing, Error, Module,
+    CouncilStageAnnouncing, CouncilorTerms, Error, Module, Mutations, RawEvent,
+    RecallReferendumConnection,
 };
 use crate::mock::*;
 use common::council::CouncilBudgetManager;
@@ -604,6 +605,648 @@ fn council_cant_candidate_repeatedly() {
     });
 }
 
+// Mock council with each councilor elected in the given cycle and start a new announcing period.
+fn put_council_elected_in_cycles(councilors: &[(u64, u64)]) {
+    let council_members = councilors
+        .iter()
+        .map(
+            |(membership_id, elected_in_cycle)| CouncilMemberOf::<Runtime> {
+                staking_account_id: *membership_id,
+                reward_account_id: *membership_id,
+                membership_id: *membership_id,
+                stake: 0,
+                last_payment_block: 0,
+                unpaid_reward: 0,
+                elected_in_cycle: *elected_in_cycle,
+            },
+        )
+        .collect::<Vec<_>>();
+
+    CouncilMembers::<Runtime>::put(WeakBoundedVec::force_from(council_members, None));
+
+    Mutations::<Runtime>::start_announcing_period();
+}
+
+// Test that only the longest serving councilors are up for election with staggered seats.
+#[test]
+fn council_staggered_seats_up_for_election() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        STAGGERED_SEATS_PER_CYCLE.with(|value| *value.borrow_mut() = 1);
+
+        let council_settings = CouncilSettings::<Runtime>::extract_settings();
+        let senior_councilor =
+            MockUtils::generate_candidate(0, council_settings.min_candidate_stake);
+        let junior_councilor =
+            MockUtils::generate_candidate(1, council_settings.min_candidate_stake);
+
+        put_council_elected_in_cycles(&[
+            (junior_councilor.membership_id, 2),
+            (senior_councilor.membership_id, 1),
+            (CANDIDATE_BASE_ID + 2, 2),
+        ]);
+
+        assert_eq!(Council::seats_up_for_election(), 1);
+
+        Mocks::announce_candidacy(
+            junior_councilor.origin.clone(),
+            junior_councilor.membership_id,
+            council_settings.min_candidate_stake,
+            Err(Error::<Runtime>::CouncilorSeatNotUpForElection.into()),
+        );
+        Mocks::announce_candidacy(
+            senior_councilor.origin.clone(),
+            senior_councilor.membership_id,
+            council_settings.min_candidate_stake,
+            Ok(()),
+        );
+    });
+}
+
+// Test that vacant seats are up for election together with the rotated seats.
+#[test]
+fn council_staggered_seats_fill_vacancies() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        STAGGERED_SEATS_PER_CYCLE.with(|value| *value.borrow_mut() = 1);
+
+        put_council_elected_in_cycles(&[]);
+
+        assert_eq!(Council::seats_up_for_election(), CouncilSize::get());
+
+        put_council_elected_in_cycles(&[(CANDIDATE_BASE_ID, 1)]);
+
+        assert_eq!(Council::seats_up_for_election(), CouncilSize::get());
+
+        put_council_elected_in_cycles(&[(CANDIDATE_BASE_ID, 1), (CANDIDATE_BASE_ID + 1, 2)]);
+
+        assert_eq!(Council::seats_up_for_election(), 2);
+    });
+}
+
+// Test that councilor can't candidate after serving the maximum number of consecutive terms.
+#[test]
+fn council_consecutive_terms_limit_enforced() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        MAX_CONSECUTIVE_TERMS.with(|value| *value.borrow_mut() = 2);
+
+        let council_settings = CouncilSettings::<Runtime>::extract_settings();
+        let councilor = MockUtils::generate_candidate(0, council_settings.min_candidate_stake);
+
+        put_council_elected_in_cycles(&[(councilor.membership_id, 1)]);
+        CouncilorTerms::<Runtime>::insert(councilor.membership_id, 2);
+
+        Mocks::announce_candidacy(
+            councilor.origin.clone(),
+            councilor.membership_id,
+            council_settings.min_candidate_stake,
+            Err(Error::<Runtime>::ConsecutiveTermsLimitReached.into()),
+        );
+
+        CouncilorTerms::<Runtime>::insert(councilor.membership_id, 1);
+
+        Mocks::announce_candidacy(
+            councilor.origin.clone(),
+            councilor.membership_id,
+            council_settings.min_candidate_stake,
+            Ok(()),
+        );
+    });
+}
+
+// Test that consecutive terms are counted for reelected councilors only.
+#[test]
+fn council_consecutive_terms_counted() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let council_settings = CouncilSettings::<Runtime>::extract_settings();
+
+        let params = Mocks::run_full_council_cycle(1, &[], 0);
+        for council_member in params.expected_final_council_members.iter() {
+            assert_eq!(Council::councilor_terms(council_member.member_id()), 1);
+        }
+
+        let tmp_council_members: Vec<CouncilMemberOf<Runtime>> =
+            Council::council_members().to_vec();
+
+        // same candidates get reelected in the second cycle
+        let params = Mocks::run_full_council_cycle(
+            params.cycle_start_block_number + council_settings.cycle_duration,
+            tmp_council_members.as_slice(),
+            0,
+        );
+        for council_member in params.expected_final_council_members.iter() {
+            assert_eq!(Council::councilor_terms(council_member.member_id()), 2);
+        }
+
+        let tmp_council_members: Vec<CouncilMemberOf<Runtime>> =
+            Council::council_members().to_vec();
+
+        // different candidates get elected in the third cycle
+        let users_offset = 100;
+        Mocks::run_full_council_cycle(
+            params.cycle_start_block_number + council_settings.cycle_duration,
+            tmp_council_members.as_slice(),
+            users_offset,
+        );
+        for council_member in tmp_council_members.iter() {
+            assert_eq!(Council::councilor_terms(council_member.member_id()), 0);
+        }
+    });
+}
+
+/////////////////// Recall-related /////////////////////////////////////////////
+
+const RECALL_BACKER_BASE_ID: u64 = 100;
+const RECALL_VOTER_BASE_ID: u64 = 200;
+
+// Generate recall petition backers with enough balance to back a petition.
+fn generate_recall_backers(count: u64) -> Vec<u64> {
+    (0..count)
+        .map(|index| {
+            let member_id = RECALL_BACKER_BASE_ID + index;
+            let _ = Balances::<Runtime>::deposit_creating(
+                &member_id,
+                <Runtime as Config>::RecallPetitionThreshold::get(),
+            );
+
+            member_id
+        })
+        .collect()
+}
+
+// Back the recall petition by the given backers with the minimal stake each.
+fn back_recall_petition(backers: &[u64], councilor_id: u64) {
+    for backer in backers {
+        Mocks::back_recall_petition(
+            OriginType::Signed(*backer),
+            *backer,
+            councilor_id,
+            <Runtime as Config>::MinRecallPetitionStake::get(),
+            Ok(()),
+        );
+    }
+}
+
+// Test that recall petition stake is gathered and the recall referendum started at threshold.
+#[test]
+fn council_recall_petition_starts_referendum() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let params = Mocks::run_council_cycle_with_interrupt(
+            1,
+            &[],
+            0,
+            Some(CouncilCycleInterrupt::AfterElectionComplete),
+        );
+        let councilor_id = *params.expected_final_council_members[0].member_id();
+        let backers = generate_recall_backers(3);
+        let min_stake = <Runtime as Config>::MinRecallPetitionStake::get();
+
+        back_recall_petition(&backers[..2], councilor_id);
+
+        let petition = Council::recall_petitions(councilor_id).unwrap();
+        assert_eq!(petition.total_stake, 2 * min_stake);
+        assert_eq!(petition.backers_count, 2);
+        assert!(!petition.referendum_started);
+        assert!(Council::current_recall().is_none());
+
+        back_recall_petition(&backers[2..], councilor_id);
+
+        EventFixture::assert_last_crate_event(RawEvent::RecallReferendumStarted(
+            petition.petition_id,
+            councilor_id,
+        ));
+        assert!(
+            Council::recall_petitions(councilor_id)
+                .unwrap()
+                .referendum_started
+        );
+        assert_eq!(
+            Council::current_recall().map(|recall| recall.councilor_id),
+            Some(councilor_id)
+        );
+
+        // petition stake is needed until the recall referendum ends
+        Mocks::release_recall_petition_stake(
+            OriginType::Signed(backers[0]),
+            backers[0],
+            petition.petition_id,
+            Err(Error::RecallPetitionStakeStillNeeded),
+        );
+    });
+}
+
+// Test that recall petition can't be backed with invalid parameters.
+#[test]
+fn council_recall_petition_invalid_backing() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let params = Mocks::run_council_cycle_with_interrupt(
+            1,
+            &[],
+            0,
+            Some(CouncilCycleInterrupt::AfterElectionComplete),
+        );
+        let councilor_id = *params.expected_final_council_members[0].member_id();
+        let backers = generate_recall_backers(4);
+        let min_stake = <Runtime as Config>::MinRecallPetitionStake::get();
+
+        Mocks::back_recall_petition(
+            OriginType::Signed(backers[0]),
+            backers[0],
+            backers[1],
+            min_stake,
+            Err(Error::NotCouncilor),
+        );
+
+        Mocks::back_recall_petition(
+            OriginType::Signed(backers[0]),
+            backers[0],
+            councilor_id,
+            min_stake - 1,
+            Err(Error::RecallPetitionStakeTooLow),
+        );
+
+        back_recall_petition(&backers[..1], councilor_id);
+
+        Mocks::back_recall_petition(
+            OriginType::Signed(backers[0]),
+            backers[0],
+            councilor_id,
+            min_stake,
+            Err(Error::AlreadyBackingRecallPetition),
+        );
+
+        back_recall_petition(&backers[1..3], councilor_id);
+
+        // only one recall referendum can run at a time
+        Mocks::back_recall_petition(
+            OriginType::Signed(backers[3]),
+            backers[3],
+            *params.expected_final_council_members[1].member_id(),
+            min_stake,
+            Err(Error::RecallReferendumRunning),
+        );
+    });
+}
+
+// Test that recall petition stake can be released before the petition reaches the threshold.
+#[test]
+fn council_recall_petition_stake_released() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let params = Mocks::run_council_cycle_with_interrupt(
+            1,
+            &[],
+            0,
+            Some(CouncilCycleInterrupt::AfterElectionComplete),
+        );
+        let councilor_id = *params.expected_final_council_members[0].member_id();
+        let backers = generate_recall_backers(2);
+
+        back_recall_petition(&backers, councilor_id);
+
+        let petition_id = Council::recall_petitions(councilor_id).unwrap().petition_id;
+
+        Mocks::release_recall_petition_stake(
+            OriginType::Signed(backers[0]),
+            backers[0],
+            petition_id,
+            Ok(()),
+        );
+
+        Mocks::release_recall_petition_stake(
+            OriginType::Signed(backers[0]),
+            backers[0],
+            petition_id,
+            Err(Error::NotBackingRecallPetition),
+        );
+
+        let petition = Council::recall_petitions(councilor_id).unwrap();
+        assert_eq!(
+            petition.total_stake,
+            <Runtime as Config>::MinRecallPetitionStake::get()
+        );
+        assert_eq!(petition.backers_count, 1);
+
+        // petition is removed with its last backer
+        Mocks::release_recall_petition_stake(
+            OriginType::Signed(backers[1]),
+            backers[1],
+            petition_id,
+            Ok(()),
+        );
+
+        assert!(Council::recall_petitions(councilor_id).is_none());
+    });
+}
+
+// Test that councilor is removed when the recall referendum ends in favor of the removal.
+#[test]
+fn council_recall_referendum_removes_councilor() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let params = Mocks::run_council_cycle_with_interrupt(
+            1,
+            &[],
+            0,
+            Some(CouncilCycleInterrupt::AfterElectionComplete),
+        );
+        let councilor = params.expected_final_council_members[0].clone();
+        let councilor_id = *councilor.member_id();
+        let runner_up = params.candidates_announcing[2].clone();
+        let backers = generate_recall_backers(3);
+        let vote_stake =
+            <Runtime as referendum::Config<RecallReferendumInstance>>::MinimumStake::get();
+
+        back_recall_petition(&backers, councilor_id);
+
+        let petition_id = Council::recall_petitions(councilor_id).unwrap().petition_id;
+
+        // option `1` removes the councilor, option `0` keeps them
+        let votes = [1, 1, 0];
+        let salts: Vec<Vec<u8>> = votes
+            .iter()
+            .enumerate()
+            .map(|(index, vote_option)| {
+                Mocks::vote_for_recall(
+                    RECALL_VOTER_BASE_ID + index as u64,
+                    petition_id,
+                    *vote_option,
+                    vote_stake,
+                )
+            })
+            .collect();
+
+        MockUtils::increase_block_number(VoteStageDuration::get());
+
+        for (index, salt) in salts.into_iter().enumerate() {
+            Mocks::reveal_recall_vote(RECALL_VOTER_BASE_ID + index as u64, salt, votes[index]);
+        }
+
+        MockUtils::increase_block_number(RevealStageDuration::get());
+
+        // election of the next council has already started, the runner-up of the last election
+        // takes the seat that is up for the ongoing election
+        assert!(frame_system::Pallet::<Runtime>::events()
+            .iter()
+            .any(|record| record.event
+                == Event::Council(RawEvent::CouncilorRecalled(
+                    councilor_id,
+                    Some(runner_up.membership_id)
+                ))));
+        assert!(Council::outgoing_councilors().contains(&runner_up.membership_id));
+        assert!(!Council::outgoing_councilors().contains(&councilor_id));
+        assert!(Council::current_recall().is_none());
+        assert!(Council::recall_petitions(councilor_id).is_none());
+        assert!(!Council::council_members()
+            .iter()
+            .any(|council_member| *council_member.member_id() == councilor_id));
+        assert_eq!(
+            CouncilorLock::current_stake(&councilor.staking_account_id),
+            0
+        );
+
+        // backers can release their stake after the recall referendum ended
+        for backer in backers {
+            Mocks::release_recall_petition_stake(
+                OriginType::Signed(backer),
+                backer,
+                petition_id,
+                Ok(()),
+            );
+        }
+    });
+}
+
+// Test that councilor keeps the seat when the recall referendum doesn't end in favor of the
+// removal.
+#[test]
+fn council_recall_referendum_rejected() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let params = Mocks::run_council_cycle_with_interrupt(
+            1,
+            &[],
+            0,
+            Some(CouncilCycleInterrupt::AfterElectionComplete),
+        );
+        let councilor_id = *params.expected_final_council_members[0].member_id();
+        let backers = generate_recall_backers(3);
+        let vote_stake =
+            <Runtime as referendum::Config<RecallReferendumInstance>>::MinimumStake::get();
+
+        back_recall_petition(&backers, councilor_id);
+
+        let petition_id = Council::recall_petitions(councilor_id).unwrap().petition_id;
+
+        let votes = [1, 0, 0];
+        let salts: Vec<Vec<u8>> = votes
+            .iter()
+            .enumerate()
+            .map(|(index, vote_option)| {
+                Mocks::vote_for_recall(
+                    RECALL_VOTER_BASE_ID + index as u64,
+                    petition_id,
+                    *vote_option,
+                    vote_stake,
+                )
+            })
+            .collect();
+
+        MockUtils::increase_block_number(VoteStageDuration::get());
+
+        for (index, salt) in salts.into_iter().enumerate() {
+            Mocks::reveal_recall_vote(RECALL_VOTER_BASE_ID + index as u64, salt, votes[index]);
+        }
+
+        MockUtils::increase_block_number(RevealStageDuration::get());
+
+        assert!(frame_system::Pallet::<Runtime>::events()
+            .iter()
+            .any(|record| record.event
+                == Event::Council(RawEvent::CouncilorRecallRejected(councilor_id))));
+        assert!(Council::current_recall().is_none());
+        assert!(Council::council_members()
+            .iter()
+            .any(|council_member| *council_member.member_id() == councilor_id));
+    });
+}
+
+// Test that recalled councilor's seat is given to the next-ranked candidate of the last election.
+#[test]
+fn council_recalled_councilor_replaced_by_next_candidate() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let params = Mocks::run_council_cycle_with_interrupt(
+            1,
+            &[],
+            0,
+            Some(CouncilCycleInterrupt::AfterElectionComplete),
+        );
+        let councilor_id = *params.expected_final_council_members[0].member_id();
+        let runner_up = params.candidates_announcing[2].clone();
+        let backers = generate_recall_backers(3);
+
+        back_recall_petition(&backers, councilor_id);
+
+        // conclude the recall referendum while the council is still idle
+        <Module<Runtime> as RecallReferendumConnection<Runtime>>::increase_recall_option_power(
+            &1, &1,
+        );
+        <Module<Runtime> as RecallReferendumConnection<Runtime>>::recieve_recall_referendum_results(
+            &[],
+        );
+
+        EventFixture::assert_last_crate_event(RawEvent::CouncilorRecalled(
+            councilor_id,
+            Some(runner_up.membership_id),
+        ));
+
+        let council_members = Council::council_members();
+        assert_eq!(council_members.len(), CouncilSize::get() as usize);
+        assert!(!council_members
+            .iter()
+            .any(|council_member| *council_member.member_id() == councilor_id));
+        assert!(council_members
+            .iter()
+            .any(|council_member| *council_member.member_id() == runner_up.membership_id));
+
+        // candidacy stake became councilor stake
+        assert_eq!(CandidacyLock::current_stake(&runner_up.account_id), 0);
+        assert_eq!(
+            CouncilorLock::current_stake(&runner_up.account_id),
+            runner_up.candidate.stake
+        );
+        assert!(Council::candidates(runner_up.membership_id).is_none());
+        assert!(Council::runners_up().is_empty());
+    });
+}
+
+// Test that recalled councilor's seat is left vacant for the rest of the term when no runner-up
+// of the last election is left and that the seat is up for the next election.
+#[test]
+fn council_recalled_councilor_seat_left_vacant_without_runners_up() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        STAGGERED_SEATS_PER_CYCLE.with(|value| *value.borrow_mut() = 1);
+
+        let params = Mocks::run_council_cycle_with_interrupt(
+            1,
+            &[],
+            0,
+            Some(CouncilCycleInterrupt::AfterElectionComplete),
+        );
+        let first_councilor_id = *params.expected_final_council_members[0].member_id();
+        let second_councilor_id = *params.expected_final_council_members[1].member_id();
+        let backers = generate_recall_backers(3);
+
+        // the only runner-up takes over the seat of the first recalled councilor
+        back_recall_petition(&backers, first_councilor_id);
+        let petition_id = Council::recall_petitions(first_councilor_id)
+            .unwrap()
+            .petition_id;
+        <Module<Runtime> as RecallReferendumConnection<Runtime>>::increase_recall_option_power(
+            &1, &1,
+        );
+        <Module<Runtime> as RecallReferendumConnection<Runtime>>::recieve_recall_referendum_results(
+            &[],
+        );
+        assert!(Council::runners_up().is_empty());
+
+        for backer in backers.iter() {
+            Mocks::release_recall_petition_stake(
+                OriginType::Signed(*backer),
+                *backer,
+                petition_id,
+                Ok(()),
+            );
+        }
+
+        back_recall_petition(&backers, second_councilor_id);
+        <Module<Runtime> as RecallReferendumConnection<Runtime>>::increase_recall_option_power(
+            &1, &1,
+        );
+        <Module<Runtime> as RecallReferendumConnection<Runtime>>::recieve_recall_referendum_results(
+            &[],
+        );
+
+        EventFixture::assert_last_crate_event(RawEvent::CouncilorRecalled(
+            second_councilor_id,
+            None,
+        ));
+        assert_eq!(
+            Council::council_members().len(),
+            CouncilSize::get() as usize - 1
+        );
+
+        // the rotated seat and the vacant seat are up for the next election
+        MockUtils::increase_block_number(IdlePeriodDuration::get() + 1);
+        assert_eq!(Council::seats_up_for_election(), 2);
+    });
+}
+
+// Test that recalled councilor's seat stays vacant when the runners-up of the last election
+// are candidates in the ongoing election.
+#[test]
+fn council_recalled_councilor_not_replaced_by_candidate_of_ongoing_election() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let params = Mocks::run_council_cycle_with_interrupt(
+            1,
+            &[],
+            0,
+            Some(CouncilCycleInterrupt::AfterElectionComplete),
+        );
+        let councilor_id = *params.expected_final_council_members[0].member_id();
+        let runner_up = params.candidates_announcing[2].clone();
+        let backers = generate_recall_backers(3);
+
+        back_recall_petition(&backers, councilor_id);
+
+        // runner-up announces candidacy in the next election
+        MockUtils::increase_block_number(IdlePeriodDuration::get() + 1);
+        Mocks::announce_candidacy(
+            runner_up.origin.clone(),
+            runner_up.account_id,
+            runner_up.candidate.stake,
+            Ok(()),
+        );
+
+        <Module<Runtime> as RecallReferendumConnection<Runtime>>::increase_recall_option_power(
+            &1, &1,
+        );
+        <Module<Runtime> as RecallReferendumConnection<Runtime>>::recieve_recall_referendum_results(
+            &[],
+        );
+
+        EventFixture::assert_last_crate_event(RawEvent::CouncilorRecalled(councilor_id, None));
+
+        assert_eq!(
+            Council::council_members().len(),
+            CouncilSize::get() as usize - 1
+        );
+        assert_eq!(
+            Council::runners_up().to_vec(),
+            vec![runner_up.membership_id]
+        );
+    });
+}
+
 // Test that candidate's stake is truly locked.
 #[test]
 fn council_candidate_stake_is_locked() {
@@ -1680,6 +2323,7 @@ fn council_origin_validator_succeeds() {
             stake: 0,
             last_payment_block: 0,
             unpaid_reward: 0,
+            elected_in_cycle: 0,
         };
 
         CouncilMembers::<Runtime>::put(WeakBoundedVec::force_from(vec![councilor1], None));
@@ -2097,3 +2741,39 @@ fn candidate_remark_unsuccessful_with_invalid_origin() {
         );
     });
 }
+
+#[test]
+fn migrate_council_members_ok() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let legacy_councilor = crate::migrations::LegacyCouncilMemberOf::<Runtime> {
+            staking_account_id: 1,
+            reward_account_id: 2,
+            membership_id: 3,
+            stake: 100,
+            last_payment_block: 4,
+            unpaid_reward: 5,
+        };
+        frame_support::storage::unhashed::put(
+            &CouncilMembers::<Runtime>::hashed_key(),
+            &vec![legacy_councilor],
+        );
+        AnnouncementPeriodNr::put(7);
+
+        Council::migrate_council_members();
+
+        assert_eq!(
+            CouncilMembers::<Runtime>::get().into_inner(),
+            vec![CouncilMemberOf::<Runtime> {
+                staking_account_id: 1,
+                reward_account_id: 2,
+                membership_id: 3,
+                stake: 100,
+                last_payment_block: 4,
+                unpaid_reward: 5,
+                elected_in_cycle: 7,
+            }],
+        );
+    });
+}
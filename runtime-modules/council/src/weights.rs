@@ -62,6 +62,9 @@ pub trait WeightInfo {
 	fn fund_council_budget() -> Weight;
 	fn candidate_remark() -> Weight;
 	fn councilor_remark() -> Weight;
+	fn back_recall_petition() -> Weight;
+	fn release_recall_petition_stake() -> Weight;
+	fn end_recall_referendum(_i: u32, ) -> Weight;
 }
 
 /// Weights for council using the Substrate node and recommended hardware.
@@ -205,6 +208,49 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		(49_349_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Council CouncilMembers (r:1 w:0)
+	// Storage: Council CurrentRecall (r:1 w:1)
+	// Storage: Council RecallPetitions (r:1 w:1)
+	// Storage: Council RecallPetitionBackers (r:1 w:1)
+	// Storage: Council NextRecallPetitionId (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RecallReferendum Stage (r:1 w:1)
+	fn back_recall_petition() -> Weight {
+		(142_318_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Council RecallPetitionBackers (r:1 w:1)
+	// Storage: Council RecallPetitions (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn release_recall_petition_stake() -> Weight {
+		(94_615_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Council CurrentRecall (r:1 w:1)
+	// Storage: Council RecallPetitions (r:1 w:1)
+	// Storage: Council CouncilMembers (r:1 w:1)
+	// Storage: Balances Locks (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Council CouncilorTerms (r:0 w:2)
+	// Storage: Council Stage (r:1 w:0)
+	// Storage: Council AnnouncementPeriodNr (r:1 w:0)
+	// Storage: Council RunnersUp (r:1 w:1)
+	// Storage: Council Candidates (r:1 w:1)
+	// Storage: Council OutgoingCouncilors (r:1 w:1)
+	fn end_recall_referendum(i: u32, ) -> Weight {
+		(131_054_000 as Weight)
+			// Standard Error: 21_000
+			.saturating_add((6_207_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+	}
 }
 
 // Default implementation for tests
@@ -260,4 +306,13 @@ impl WeightInfo for () {
 	fn councilor_remark() -> Weight {
 		0
 	}
+	fn back_recall_petition() -> Weight {
+		0
+	}
+	fn release_recall_petition_stake() -> Weight {
+		0
+	}
+	fn end_recall_referendum(i: u32, ) -> Weight {
+		0
+	}
 }
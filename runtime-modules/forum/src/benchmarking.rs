@@ -265,11 +265,97 @@ fn create_new_thread<T: Config>(
         category_id,
         title,
         text,
+        None,
     )
     .unwrap();
     Module::<T>::next_thread_id() - T::ThreadId::one()
 }
 
+fn create_post_report<T: Config>(
+    account_id: T::AccountId,
+    forum_user_id: crate::ForumUserId<T>,
+    category_id: T::CategoryId,
+) -> ReportId {
+    let text = vec![1u8].repeat((MAX_KILOBYTES_METADATA * 1000) as usize);
+
+    let thread_id = create_new_thread::<T>(
+        account_id.clone(),
+        forum_user_id,
+        category_id,
+        text.clone(),
+        text.clone(),
+    );
+    let post_id = add_thread_post::<T>(
+        account_id.clone(),
+        forum_user_id,
+        category_id,
+        thread_id,
+        text,
+    );
+
+    let report_id = Module::<T>::next_report_id();
+    Module::<T>::report_post(
+        RawOrigin::Signed(account_id).into(),
+        forum_user_id,
+        category_id,
+        thread_id,
+        post_id,
+        1,
+    )
+    .unwrap();
+
+    report_id
+}
+
+// Attaches `reactions` reactions of distinct forum users to the post.
+fn add_post_reactions<T: Config>(post_id: T::PostId, reactions: u32) {
+    let mut counters = Module::<T>::post_reaction_counters_by_post_id(post_id);
+    for forum_user_id in 0..reactions {
+        let forum_user_id: crate::ForumUserId<T> = (forum_user_id as u64).saturated_into();
+        assert!(counters.try_replace(
+            None,
+            Some(PostReaction::Like),
+            T::MaxReactionsPerPost::get()
+        ));
+        <PostReactionByForumUser<T>>::insert(post_id, forum_user_id, PostReaction::Like);
+    }
+    <PostReactionCountersByPostId<T>>::insert(post_id, counters);
+}
+
+fn worst_case_posting_requirements<T: Config>() -> PostingRequirementsOf<T> {
+    PostingRequirements {
+        verified_members_only: true,
+        min_membership_age: Some(One::one()),
+        creator_token: Some(Default::default()),
+        working_group: Some(WorkingGroup::Forum),
+    }
+}
+
+// Posting requirements met by the forum leader, the working group requirement being the most
+// expensive one to check.
+fn met_posting_requirements<T: Config>() -> PostingRequirementsOf<T> {
+    PostingRequirements {
+        verified_members_only: false,
+        min_membership_age: Some(Zero::zero()),
+        creator_token: None,
+        working_group: Some(WorkingGroup::Forum),
+    }
+}
+
+fn generate_poll_input<T: Config>(
+    voting_mode: PollVotingMode,
+    alternatives_number: u32,
+) -> PollInputOf<T> {
+    PollInput {
+        description: vec![0u8],
+        end_block: System::<T>::block_number() + T::BlockNumber::from(10u32),
+        voting_mode,
+        poll_alternatives: (0..alternatives_number)
+            .map(|index| index.to_be_bytes().to_vec())
+            .collect(),
+    }
+}
+
 fn add_thread_post<T: Config>(
     account_id: T::AccountId,
     forum_user_id: crate::ForumUserId<T>,
@@ -565,6 +651,65 @@ benchmarks! {
         );
     }
 
+    update_category_posting_requirements_lead{
+        let lead_id = 0;
+
+        let caller_id =
+            insert_a_leader::<T>(lead_id);
+
+        let i in 1 .. T::MaxCategoryDepth::get() as u32;
+
+        let requirements = worst_case_posting_requirements::<T>();
+
+        // Generate categories tree
+        let (category_id, _) = generate_categories_tree::<T>(caller_id.clone(), i, None);
+    }: update_category_posting_requirements(
+        RawOrigin::Signed(caller_id),
+        PrivilegedActor::Lead,
+        category_id,
+        requirements.clone()
+    )
+    verify {
+        assert_eq!(Module::<T>::category_posting_requirements(category_id), requirements);
+        assert_last_event::<T>(
+            RawEvent::CategoryPostingRequirementsUpdated(
+                category_id,
+                requirements,
+                PrivilegedActor::Lead
+            ).into()
+        );
+    }
+
+    update_category_posting_requirements_moderator{
+        let moderator_id = 0;
+
+        let caller_id =
+            insert_a_leader::<T>(moderator_id);
+
+        let i in 1 .. T::MaxCategoryDepth::get() as u32;
+
+        let requirements = worst_case_posting_requirements::<T>();
+
+        // Generate categories tree
+        let moderator_id = ModeratorId::<T>::from(moderator_id.try_into().unwrap());
+        let (category_id, _) = generate_categories_tree::<T>(caller_id.clone(), i, Some(moderator_id));
+    }: update_category_posting_requirements(
+        RawOrigin::Signed(caller_id),
+        PrivilegedActor::Moderator(moderator_id),
+        category_id,
+        requirements.clone()
+    )
+    verify {
+        assert_eq!(Module::<T>::category_posting_requirements(category_id), requirements);
+        assert_last_event::<T>(
+            RawEvent::CategoryPostingRequirementsUpdated(
+                category_id,
+                requirements,
+                PrivilegedActor::Moderator(moderator_id)
+            ).into()
+        );
+    }
+
     update_category_title_lead{
         let lead_id = 0;
 
@@ -821,19 +966,32 @@ benchmarks! {
 
         let k in 0 .. MAX_KILOBYTES_METADATA;
 
+        let z in 2 .. T::MaxPollAlternatives::get();
+
         // Generate categories tree
         let (category_id, _) = generate_categories_tree::<T>(caller_id.clone(), i, None);
         let mut category = Module::<T>::category_by_id(category_id);
 
+        <CategoryPostingRequirements<T>>::insert(category_id, met_posting_requirements::<T>());
+
         let metadata = vec![0u8].repeat((j * 1000) as usize);
 
         let text = vec![0u8].repeat((k * 1000) as usize);
 
+        let poll_input = generate_poll_input::<T>(PollVotingMode::StakeWeighted, z);
+
         let next_thread_id = Module::<T>::next_thread_id();
         let next_post_id = Module::<T>::next_post_id();
         let initial_balance = Balances::<T>::usable_balance(&caller_id);
 
-    }: _ (RawOrigin::Signed(caller_id.clone()), forum_user_id.saturated_into(), category_id, metadata.clone(), text.clone())
+    }: _ (
+        RawOrigin::Signed(caller_id.clone()),
+        forum_user_id.saturated_into(),
+        category_id,
+        metadata.clone(),
+        text.clone(),
+        Some(poll_input.clone())
+    )
     verify {
 
         assert_eq!(
@@ -860,6 +1018,7 @@ benchmarks! {
             author_id: forum_user_id.saturated_into(),
             cleanup_pay_off: RepayableBloatBond::new(T::ThreadDeposit::get(), None),
             number_of_editable_posts: 1,
+            poll: Some(Module::<T>::ensure_valid_poll(&poll_input).unwrap()),
         };
 
         assert_eq!(Module::<T>::thread_by_id(category_id, next_thread_id), new_thread);
@@ -881,6 +1040,120 @@ benchmarks! {
                 forum_user_id.saturated_into(),
                 metadata,
                 text,
+                Some(poll_input),
+            ).into()
+        );
+    }
+
+    vote_on_poll {
+        let forum_user_id = 0;
+        let caller_id =
+            insert_a_leader::<T>(forum_user_id);
+
+        let i in 1 .. T::MaxCategoryDepth::get() as u32;
+
+        let j in 2 .. T::MaxPollAlternatives::get();
+
+        // Generate categories tree
+        let (category_id, _) = generate_categories_tree::<T>(caller_id.clone(), i, None);
+
+        // Create thread with a stake weighted poll
+        Module::<T>::create_thread(
+            RawOrigin::Signed(caller_id.clone()).into(),
+            forum_user_id.saturated_into(),
+            category_id,
+            vec![0u8],
+            vec![0u8],
+            Some(generate_poll_input::<T>(PollVotingMode::StakeWeighted, j)),
+        )
+        .unwrap();
+        let thread_id = Module::<T>::next_thread_id() - T::ThreadId::one();
+
+        // The leader account is a confirmed staking account
+        let vote_weight: BalanceOf<T> = 1000u32.into();
+        let stake_parameters = PollVoteStakeParameters {
+            staking_account_id: caller_id.clone(),
+            stake: vote_weight,
+        };
+        let index = j - 1;
+
+    }: _ (
+        RawOrigin::Signed(caller_id.clone()),
+        forum_user_id.saturated_into(),
+        category_id,
+        thread_id,
+        index,
+        Some(stake_parameters)
+    )
+    verify {
+        let poll = Module::<T>::thread_by_id(category_id, thread_id).poll.unwrap();
+        assert_eq!(poll.poll_alternatives[index as usize].vote_count, vote_weight);
+        let forum_user_id: crate::ForumUserId<T> = forum_user_id.saturated_into();
+        assert!(<PollVotes<T>>::contains_key(thread_id, forum_user_id));
+        assert_eq!(
+            Module::<T>::poll_vote_lock_by_account(&caller_id),
+            Some(PollVoteLock { amount: vote_weight, locked_until: poll.end_block })
+        );
+        assert_eq!(T::StakingHandler::current_stake(&caller_id), vote_weight);
+
+        assert_last_event::<T>(
+            RawEvent::VoteOnPoll(
+                thread_id,
+                index,
+                forum_user_id,
+                category_id,
+                vote_weight,
+            ).into()
+        );
+    }
+
+    release_poll_vote_stake {
+        let forum_user_id = 0;
+        let caller_id =
+            insert_a_leader::<T>(forum_user_id);
+
+        let (category_id, _) = generate_categories_tree::<T>(caller_id.clone(), 1, None);
+
+        let poll_input = generate_poll_input::<T>(
+            PollVotingMode::StakeWeighted,
+            T::MaxPollAlternatives::get()
+        );
+        let end_block = poll_input.end_block;
+
+        Module::<T>::create_thread(
+            RawOrigin::Signed(caller_id.clone()).into(),
+            forum_user_id.saturated_into(),
+            category_id,
+            vec![0u8],
+            vec![0u8],
+            Some(poll_input),
+        )
+        .unwrap();
+        let thread_id = Module::<T>::next_thread_id() - T::ThreadId::one();
+
+        let stake: BalanceOf<T> = 1000u32.into();
+        Module::<T>::vote_on_poll(
+            RawOrigin::Signed(caller_id.clone()).into(),
+            forum_user_id.saturated_into(),
+            category_id,
+            thread_id,
+            0,
+            Some(PollVoteStakeParameters { staking_account_id: caller_id.clone(), stake }),
+        )
+        .unwrap();
+
+        System::<T>::set_block_number(end_block);
+
+    }: _ (RawOrigin::Signed(caller_id.clone()), forum_user_id.saturated_into(), caller_id.clone())
+    verify {
+        assert!(!<PollVoteLockByAccount<T>>::contains_key(&caller_id));
+        assert!(T::StakingHandler::current_stake(&caller_id).is_zero());
+
+        assert_last_event::<T>(
+            RawEvent::PollVoteStakeReleased(
+                forum_user_id.saturated_into(),
+                caller_id,
+                stake,
             ).into()
         );
     }
@@ -1244,6 +1517,8 @@ benchmarks! {
         let thread = Module::<T>::thread_by_id(category_id, thread_id);
         let post_id = Module::<T>::next_post_id();
 
+        <CategoryPostingRequirements<T>>::insert(category_id, met_posting_requirements::<T>());
+
         let initial_balance = Balances::<T>::usable_balance(&caller_id);
     }: _ (RawOrigin::Signed(caller_id.clone()), forum_user_id.saturated_into(), category_id, thread_id, text.clone(), true)
     verify {
@@ -1338,6 +1613,8 @@ benchmarks! {
 
         let j in 0 .. MAX_KILOBYTES_METADATA;
 
+        let r in 0 .. T::MaxReactionsPerPost::get();
+
         // Generate categories tree
         let (category_id, _) = generate_categories_tree::<T>(caller_id.clone(), i, None);
 
@@ -1351,6 +1628,7 @@ benchmarks! {
             text.clone(), text.clone()
         );
         let post_id = add_thread_post::<T>(caller_id.clone(), forum_user_id.saturated_into(), category_id, thread_id, text);
+        add_post_reactions::<T>(post_id, r);
 
         let mut thread = Module::<T>::thread_by_id(category_id, thread_id);
 
@@ -1362,6 +1640,7 @@ benchmarks! {
         assert_eq!(Module::<T>::thread_by_id(category_id, thread_id), thread);
 
         assert!(!<PostById<T>>::contains_key(thread_id, post_id));
+        assert!(<PostReactionByForumUser<T>>::iter_prefix(post_id).next().is_none());
 
         assert_last_event::<T>(
             RawEvent::PostModerated(
@@ -1374,6 +1653,221 @@ benchmarks! {
         );
     }
 
+    report_post {
+        let forum_user_id = 0;
+        let caller_id =
+            insert_a_leader::<T>(forum_user_id);
+
+        let i in 1 .. T::MaxCategoryDepth::get() as u32;
+
+        // Generate categories tree
+        let (category_id, _) = generate_categories_tree::<T>(caller_id.clone(), i, None);
+
+        let text = vec![1u8].repeat((MAX_KILOBYTES_METADATA * 1000) as usize);
+
+        let thread_id = create_new_thread::<T>(
+            caller_id.clone(), forum_user_id.saturated_into(), category_id,
+            text.clone(), text.clone()
+        );
+        let post_id = add_thread_post::<T>(caller_id.clone(), forum_user_id.saturated_into(), category_id, thread_id, text);
+
+        let report_id = Module::<T>::next_report_id();
+        let reason_code = 1;
+        let initial_balance = Balances::<T>::usable_balance(&caller_id);
+    }: _ (RawOrigin::Signed(caller_id.clone()), forum_user_id.saturated_into(), category_id, thread_id, post_id, reason_code)
+    verify {
+        let forum_user_id: crate::ForumUserId<T> = forum_user_id.saturated_into();
+
+        assert_eq!(
+            Balances::<T>::usable_balance(&caller_id),
+            initial_balance - T::ReportDeposit::get(),
+        );
+        assert!(<PostReportById<T>>::contains_key(category_id, report_id));
+        assert_eq!(Module::<T>::report_rate_limit_by_forum_user(forum_user_id).reports_in_period, 1);
+
+        assert_last_event::<T>(
+            RawEvent::PostReported(
+                report_id,
+                forum_user_id,
+                category_id,
+                thread_id,
+                post_id,
+                reason_code,
+            ).into()
+        );
+    }
+
+    resolve_post_report_lead {
+        let forum_user_id = 0;
+        let caller_id =
+            insert_a_leader::<T>(forum_user_id);
+
+        let i in 1 .. T::MaxCategoryDepth::get() as u32;
+
+        let j in 0 .. MAX_KILOBYTES_METADATA;
+
+        let r in 0 .. T::MaxReactionsPerPost::get();
+
+        // Generate categories tree
+        let (category_id, _) = generate_categories_tree::<T>(caller_id.clone(), i, None);
+
+        let report_id = create_post_report::<T>(caller_id.clone(), forum_user_id.saturated_into(), category_id);
+        let post_id = Module::<T>::post_report_by_id(category_id, report_id).unwrap().post_id;
+        add_post_reactions::<T>(post_id, r);
+        let rationale = vec![0u8].repeat((j * 1000) as usize);
+        let reward = T::ReportReward::get();
+        T::WorkingGroup::set_budget(reward);
+    }: resolve_post_report(
+        RawOrigin::Signed(caller_id),
+        PrivilegedActor::Lead,
+        category_id,
+        report_id,
+        ReportResolution::Moderate,
+        rationale
+    )
+    verify {
+        assert!(!<PostReportById<T>>::contains_key(category_id, report_id));
+        assert!(<PostReactionByForumUser<T>>::iter_prefix(post_id).next().is_none());
+
+        assert_last_event::<T>(
+            RawEvent::PostReportResolved(
+                report_id,
+                category_id,
+                ReportResolution::Moderate,
+                PrivilegedActor::Lead,
+                reward,
+            ).into()
+        );
+    }
+
+    resolve_post_report_moderator {
+        let forum_user_id = 0;
+        let caller_id =
+            insert_a_leader::<T>(forum_user_id);
+
+        let i in 1 .. T::MaxCategoryDepth::get() as u32;
+
+        let j in 0 .. MAX_KILOBYTES_METADATA;
+
+        let r in 0 .. T::MaxReactionsPerPost::get();
+
+        // Generate categories tree
+        let moderator_id = ModeratorId::<T>::from(forum_user_id.try_into().unwrap());
+        let (category_id, _) = generate_categories_tree::<T>(caller_id.clone(), i, Some(moderator_id));
+
+        let report_id = create_post_report::<T>(caller_id.clone(), forum_user_id.saturated_into(), category_id);
+        let post_id = Module::<T>::post_report_by_id(category_id, report_id).unwrap().post_id;
+        add_post_reactions::<T>(post_id, r);
+        let rationale = vec![0u8].repeat((j * 1000) as usize);
+        let reward = T::ReportReward::get();
+        T::WorkingGroup::set_budget(reward);
+    }: resolve_post_report(
+        RawOrigin::Signed(caller_id),
+        PrivilegedActor::Moderator(moderator_id),
+        category_id,
+        report_id,
+        ReportResolution::Moderate,
+        rationale
+    )
+    verify {
+        assert!(!<PostReportById<T>>::contains_key(category_id, report_id));
+        assert!(<PostReactionByForumUser<T>>::iter_prefix(post_id).next().is_none());
+
+        assert_last_event::<T>(
+            RawEvent::PostReportResolved(
+                report_id,
+                category_id,
+                ReportResolution::Moderate,
+                PrivilegedActor::Moderator(moderator_id),
+                reward,
+            ).into()
+        );
+    }
+
+    react_post {
+        let forum_user_id = 0;
+        let caller_id =
+            insert_a_leader::<T>(forum_user_id);
+
+        let i in 1 .. T::MaxCategoryDepth::get() as u32;
+
+        // Generate categories tree
+        let (category_id, _) = generate_categories_tree::<T>(caller_id.clone(), i, None);
+
+        let text = vec![1u8].repeat((MAX_KILOBYTES_METADATA * 1000) as usize);
+
+        let thread_id = create_new_thread::<T>(
+            caller_id.clone(), forum_user_id.saturated_into(), category_id,
+            text.clone(), text.clone()
+        );
+        let post_id = add_thread_post::<T>(caller_id.clone(), forum_user_id.saturated_into(), category_id, thread_id, text);
+
+        // Worst case scenario: previous reaction is replaced
+        Module::<T>::react_post(
+            RawOrigin::Signed(caller_id.clone()).into(),
+            forum_user_id.saturated_into(),
+            category_id,
+            thread_id,
+            post_id,
+            Some(PostReaction::Like),
+        ).unwrap();
+    }: _ (RawOrigin::Signed(caller_id), forum_user_id.saturated_into(), category_id, thread_id, post_id, Some(PostReaction::Celebrate))
+    verify {
+        let forum_user_id: crate::ForumUserId<T> = forum_user_id.saturated_into();
+
+        let counters = Module::<T>::post_reaction_counters_by_post_id(post_id);
+        assert_eq!(counters.count(PostReaction::Like), 0);
+        assert_eq!(counters.count(PostReaction::Celebrate), 1);
+
+        assert_last_event::<T>(
+            RawEvent::PostReacted(
+                forum_user_id,
+                category_id,
+                thread_id,
+                post_id,
+                Some(PostReaction::Celebrate),
+            ).into()
+        );
+    }
+
+    tip_post_author {
+        let forum_user_id = 0;
+        let author_account_id =
+            insert_a_leader::<T>(forum_user_id);
+
+        // Generate categories tree
+        let (category_id, _) = generate_categories_tree::<T>(author_account_id.clone(), 1, None);
+
+        let text = vec![1u8].repeat((MAX_KILOBYTES_METADATA * 1000) as usize);
+
+        let thread_id = create_new_thread::<T>(
+            author_account_id.clone(), forum_user_id.saturated_into(), category_id,
+            text.clone(), text.clone()
+        );
+        let post_id = add_thread_post::<T>(author_account_id.clone(), forum_user_id.saturated_into(), category_id, thread_id, text);
+
+        let (caller_id, tipper_id) = member_funded_account::<T>(1);
+        let amount = T::PostDeposit::get();
+        let initial_author_balance = Balances::<T>::free_balance(&author_account_id);
+    }: _ (RawOrigin::Signed(caller_id), tipper_id, category_id, thread_id, post_id, amount)
+    verify {
+        assert_eq!(
+            Balances::<T>::free_balance(&author_account_id),
+            initial_author_balance + amount,
+        );
+
+        assert_last_event::<T>(
+            RawEvent::PostAuthorTipped(
+                tipper_id,
+                category_id,
+                thread_id,
+                post_id,
+                forum_user_id.saturated_into(),
+                amount,
+            ).into()
+        );
+    }
+
     moderate_post_moderator {
         let forum_user_id = 0;
         let caller_id =
@@ -1383,6 +1877,8 @@ benchmarks! {
 
         let j in 0 .. MAX_KILOBYTES_METADATA;
 
+        let r in 0 .. T::MaxReactionsPerPost::get();
+
         // Generate categories tree
         let moderator_id = ModeratorId::<T>::from(forum_user_id.try_into().unwrap());
         let (category_id, _) = generate_categories_tree::<T>(caller_id.clone(), i, Some(moderator_id));
@@ -1397,6 +1893,7 @@ benchmarks! {
             text.clone(), text.clone()
         );
         let post_id = add_thread_post::<T>(caller_id.clone(), forum_user_id.saturated_into(), category_id, thread_id, text);
+        add_post_reactions::<T>(post_id, r);
 
         let mut thread = Module::<T>::thread_by_id(category_id, thread_id);
 
@@ -1408,6 +1905,7 @@ benchmarks! {
         assert_eq!(Module::<T>::thread_by_id(category_id, thread_id), thread);
 
         assert!(!<PostById<T>>::contains_key(thread_id, post_id));
+        assert!(<PostReactionByForumUser<T>>::iter_prefix(post_id).next().is_none());
 
         assert_last_event::<T>(
             RawEvent::PostModerated(
@@ -1431,6 +1929,8 @@ benchmarks! {
 
         let k in 1 .. MAX_POSTS;
 
+        let r in 0 .. T::MaxReactionsPerPost::get();
+
         // Generate categories tree
         let moderator_id = ModeratorId::<T>::from(forum_user_id.try_into().unwrap());
         let (category_id, _) = generate_categories_tree::<T>(caller_id.clone(), i, Some(moderator_id));
@@ -1461,6 +1961,10 @@ benchmarks! {
             );
         }
 
+        // Reactions removal cost doesn't depend on the post they are attached to
+        let reacted_post_id = posts.keys().next().unwrap().post_id;
+        add_post_reactions::<T>(reacted_post_id, r);
+
         let post_id = add_thread_post::<T>(caller_id.clone(), forum_user_id.saturated_into(), category_id, thread_id, text);
 
         let mut thread = Module::<T>::thread_by_id(category_id, thread_id);
@@ -1480,6 +1984,7 @@ benchmarks! {
         for extended_post in posts.keys() {
             assert!(!<PostById<T>>::contains_key(extended_post.thread_id, extended_post.post_id));
         }
+        assert!(<PostReactionByForumUser<T>>::iter_prefix(reacted_post_id).next().is_none());
 
         assert_last_event::<T>(
             RawEvent::PostDeleted(
@@ -1609,6 +2114,22 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_update_category_posting_requirements_lead() {
+        with_test_externalities(|| {
+            assert_ok!(TestForumModule::test_benchmark_update_category_posting_requirements_lead());
+        });
+    }
+
+    #[test]
+    fn test_update_category_posting_requirements_moderator() {
+        with_test_externalities(|| {
+            assert_ok!(
+                TestForumModule::test_benchmark_update_category_posting_requirements_moderator()
+            );
+        });
+    }
+
     #[test]
     fn test_delete_category_lead() {
         with_test_externalities(|| {
@@ -1748,4 +2269,53 @@ mod tests {
             assert_ok!(TestForumModule::test_benchmark_delete_posts());
         });
     }
+
+    #[test]
+    fn test_report_post() {
+        with_test_externalities(|| {
+            assert_ok!(TestForumModule::test_benchmark_report_post());
+        });
+    }
+
+    #[test]
+    fn test_resolve_post_report_lead() {
+        with_test_externalities(|| {
+            assert_ok!(TestForumModule::test_benchmark_resolve_post_report_lead());
+        });
+    }
+
+    #[test]
+    fn test_resolve_post_report_moderator() {
+        with_test_externalities(|| {
+            assert_ok!(TestForumModule::test_benchmark_resolve_post_report_moderator());
+        });
+    }
+
+    #[test]
+    fn test_react_post() {
+        with_test_externalities(|| {
+            assert_ok!(TestForumModule::test_benchmark_react_post());
+        });
+    }
+
+    #[test]
+    fn test_tip_post_author() {
+        with_test_externalities(|| {
+            assert_ok!(TestForumModule::test_benchmark_tip_post_author());
+        });
+    }
+
+    #[test]
+    fn test_vote_on_poll() {
+        with_test_externalities(|| {
+            assert_ok!(TestForumModule::test_benchmark_vote_on_poll());
+        });
+    }
+
+    #[test]
+    fn test_release_poll_vote_stake() {
+        with_test_externalities(|| {
+            assert_ok!(TestForumModule::test_benchmark_release_poll_vote_stake());
+        });
+    }
 }
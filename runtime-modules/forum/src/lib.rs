@@ -20,9 +20,12 @@
 extern crate common;
 
 use common::bloat_bond::{RepayableBloatBond, RepayableBloatBondOf};
-use common::costs::{burn_from_usable, has_sufficient_balance_for_fees, pay_fee};
+use common::costs::{
+    burn_from_usable, has_sufficient_balance_for_fees, has_sufficient_balance_for_payment, pay_fee,
+};
+use common::post_reactions::{tip_post_author, PostReaction, PostReactionCounters};
 use common::to_kb;
-use frame_support::BoundedBTreeSet;
+use frame_support::{BoundedBTreeSet, BoundedVec};
 #[cfg(feature = "std")]
 pub use serde::{Deserialize, Serialize};
 
@@ -30,7 +33,9 @@ use codec::{Codec, Decode, Encode, MaxEncodedLen};
 pub use frame_support::dispatch::DispatchResult;
 
 use frame_support::{
-    decl_error, decl_event, decl_module, decl_storage, ensure, traits::Get, PalletId, Parameter,
+    decl_error, decl_event, decl_module, decl_storage, ensure,
+    traits::{Get, LockIdentifier},
+    IterableStorageDoubleMap, PalletId, Parameter,
 };
 use frame_system::ensure_signed;
 use scale_info::TypeInfo;
@@ -45,10 +50,15 @@ use sp_std::convert::TryInto;
 use sp_std::fmt::Debug;
 use sp_std::prelude::*;
 
-use common::membership::MemberOriginValidator;
-use common::working_group::WorkingGroupAuthenticator;
+use common::membership::{
+    MemberOriginValidator, MemberReputationHandler, MembershipInfoProvider, ReputationActivity,
+    StakingAccountValidator,
+};
+use common::working_group::{WorkingGroup, WorkingGroupAuthenticator, WorkingGroupBudgetHandler};
+use staking_handler::StakingHandler;
 
 mod benchmarking;
+mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
@@ -77,7 +87,53 @@ pub type CategoryOf<T> =
     Category<<T as Config>::CategoryId, <T as frame_system::Config>::Hash, StickiedThreadIds<T>>;
 
 /// Alias for the thread
-pub type ThreadOf<T> = Thread<ForumUserId<T>, <T as Config>::CategoryId, RepayableBloatBondOf<T>>;
+pub type ThreadOf<T> =
+    Thread<ForumUserId<T>, <T as Config>::CategoryId, RepayableBloatBondOf<T>, PollOf<T>>;
+
+/// Alias for the poll alternatives (bounded vector)
+pub type PollAlternativesOf<T> = BoundedVec<
+    PollAlternative<<T as frame_system::Config>::Hash, BalanceOf<T>>,
+    <T as Config>::MaxPollAlternatives,
+>;
+
+/// Alias for the poll
+pub type PollOf<T> = Poll<
+    <T as frame_system::Config>::BlockNumber,
+    <T as frame_system::Config>::Hash,
+    PollAlternativesOf<T>,
+>;
+
+/// Alias for the poll input
+pub type PollInputOf<T> = PollInput<<T as frame_system::Config>::BlockNumber>;
+
+/// Alias for the poll vote stake parameters
+pub type PollVoteStakeParametersOf<T> =
+    PollVoteStakeParameters<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
+/// Alias for the poll vote lock
+pub type PollVoteLockOf<T> = PollVoteLock<<T as frame_system::Config>::BlockNumber, BalanceOf<T>>;
+
+/// Type for the post report identifier
+pub type ReportId = u64;
+
+/// Type for the post report reason code
+pub type ReportReasonCode = u8;
+
+/// Alias for the post report
+pub type PostReportOf<T> = PostReport<
+    ForumUserId<T>,
+    <T as Config>::ThreadId,
+    <T as Config>::PostId,
+    <T as frame_system::Config>::AccountId,
+    RepayableBloatBondOf<T>,
+>;
+
+/// Alias for the report rate limit
+pub type ReportRateLimitOf<T> = ReportRateLimit<<T as frame_system::Config>::BlockNumber>;
+
+/// Alias for the category posting requirements
+pub type PostingRequirementsOf<T> =
+    PostingRequirements<<T as frame_system::Config>::BlockNumber, <T as Config>::TokenId>;
 
 /// Alias for the post
 pub type PostOf<T> = Post<
@@ -145,6 +201,16 @@ pub trait Config:
         + Into<u64>
         + MaxEncodedLen;
 
+    /// Creator token identifier used by the category posting requirements
+    type TokenId: Parameter
+        + Member
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerialize
+        + PartialEq
+        + MaxEncodedLen;
+
     /// Base deposit for any thread (note: thread creation also needs a `PostDeposit` since
     /// creating a thread means also creating a post)
     type ThreadDeposit: Get<Self::Balance>;
@@ -152,6 +218,18 @@ pub trait Config:
     /// Deposit needed to create a post
     type PostDeposit: Get<Self::Balance>;
 
+    /// Deposit needed to report a post, refunded if the report leads to moderation
+    type ReportDeposit: Get<Self::Balance>;
+
+    /// Reward paid from the forum working group budget for a report leading to moderation
+    type ReportReward: Get<Self::Balance>;
+
+    /// Maximum number of reports per forum user in the rate limit period
+    type MaxReportsPerPeriod: Get<u32>;
+
+    /// Length of the report rate limit period in blocks
+    type ReportRateLimitPeriod: Get<Self::BlockNumber>;
+
     /// Maximum depth for nested categories
     type MaxCategoryDepth: Get<u64>;
 
@@ -161,6 +239,12 @@ pub trait Config:
     /// Maximum number of blocks before a post can be erased by anyone
     type PostLifeTime: Get<Self::BlockNumber>;
 
+    /// Maximum number of alternatives in a thread poll
+    type MaxPollAlternatives: Get<u32>;
+
+    /// Maximum number of reactions attached to a single post
+    type MaxReactionsPerPost: Get<u32>;
+
     /// Type defining the limits for different Storage items in the forum pallet
     type MapLimits: StorageLimits;
 
@@ -171,7 +255,8 @@ pub trait Config:
     type WeightInfo: WeightInfo;
 
     /// Working group pallet integration.
-    type WorkingGroup: common::working_group::WorkingGroupAuthenticator<Self>;
+    type WorkingGroup: common::working_group::WorkingGroupAuthenticator<Self>
+        + common::working_group::WorkingGroupBudgetHandler<Self::AccountId, BalanceOf<Self>>;
 
     /// Validates member id and origin combination
     type MemberOriginValidator: MemberOriginValidator<
@@ -180,7 +265,40 @@ pub trait Config:
         Self::AccountId,
     >;
 
+    /// Validates staking account ownership for a member (stake weighted poll votes).
+    type StakingAccountValidator: StakingAccountValidator<Self>;
+
+    /// Locks the stake of the stake weighted poll votes.
+    type StakingHandler: StakingHandler<
+        Self::AccountId,
+        BalanceOf<Self>,
+        ForumUserId<Self>,
+        LockIdentifier,
+    >;
+
+    /// Provides the controller accounts of the post authors (post tips).
+    type MembershipInfoProvider: MembershipInfoProvider<Self>;
+
+    /// Updates author reputation on moderation actions.
+    type MemberReputationHandler: MemberReputationHandler<Self>;
+
     fn calculate_hash(text: &[u8]) -> Self::Hash;
+
+    /// Checks whether the member identity is verified
+    fn is_verified_member(member_id: &common::MemberId<Self>) -> bool;
+
+    /// Returns the block at which the membership was created
+    fn membership_created_at(member_id: &common::MemberId<Self>) -> Self::BlockNumber;
+
+    /// Checks whether the member holds the given creator token
+    fn is_creator_token_holder(token_id: Self::TokenId, member_id: &common::MemberId<Self>)
+        -> bool;
+
+    /// Checks whether the member is a worker (or the leader) of the given working group
+    fn is_working_group_worker(
+        working_group: WorkingGroup,
+        member_id: &common::MemberId<Self>,
+    ) -> bool;
 }
 
 /// Upper bounds for storage maps and double maps.
@@ -217,10 +335,158 @@ pub struct Post<ForumUserId, ThreadId, Hash, BlockNumber, RepayableBloatBond> {
     pub last_edited: BlockNumber,
 }
 
+/// Represents a post report waiting for the moderators resolution
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct PostReport<ForumUserId, ThreadId, PostId, AccountId, RepayableBloatBond> {
+    /// Thread of the reported post
+    pub thread_id: ThreadId,
+
+    /// Reported post
+    pub post_id: PostId,
+
+    /// Reason of the report
+    pub reason_code: ReportReasonCode,
+
+    /// Forum user who reported the post
+    pub reporter_id: ForumUserId,
+
+    /// Account which paid the report deposit
+    pub reporter_account: AccountId,
+
+    /// Report deposit
+    pub deposit: RepayableBloatBond,
+}
+
+/// Number of reports made by a forum user in the current rate limit period
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct ReportRateLimit<BlockNumber> {
+    /// Block at which the current period started
+    pub period_start: BlockNumber,
+
+    /// Number of reports made in the current period
+    pub reports_in_period: u32,
+}
+
+/// Resolution of a post report
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo)]
+pub enum ReportResolution {
+    /// Report is rejected, the report deposit is slashed
+    Dismiss,
+
+    /// Reported post is moderated, the report deposit is refunded and the reporter is rewarded
+    Moderate,
+}
+
+/// Requirements a forum user has to meet to create threads and posts in a category
+/// and all of its subcategories
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct PostingRequirements<BlockNumber, TokenId> {
+    /// Only verified members can post
+    pub verified_members_only: bool,
+
+    /// Minimum number of blocks passed since the membership creation
+    pub min_membership_age: Option<BlockNumber>,
+
+    /// Only holders of the creator token can post
+    pub creator_token: Option<TokenId>,
+
+    /// Only workers of the working group can post
+    pub working_group: Option<WorkingGroup>,
+}
+
+/// Defines how the votes on a poll are counted
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum PollVotingMode {
+    /// Every member vote has the weight of one
+    OneVotePerMember,
+
+    /// Every member vote is weighted by the stake locked on the chosen staking account
+    /// until the poll ends
+    StakeWeighted,
+}
+
+impl Default for PollVotingMode {
+    fn default() -> Self {
+        Self::OneVotePerMember
+    }
+}
+
+/// Represents a poll alternative
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct PollAlternative<Hash, VoteWeight> {
+    /// Hash of the alternative description
+    pub alternative_text_hash: Hash,
+
+    /// Total weight of the votes for the alternative
+    pub vote_count: VoteWeight,
+}
+
+/// Represents a thread poll
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Poll<BlockNumber, Hash, PollAlternatives> {
+    /// Hash of the poll description
+    pub description_hash: Hash,
+
+    /// Block at which the poll is closed
+    pub end_block: BlockNumber,
+
+    /// How the votes are counted
+    pub voting_mode: PollVotingMode,
+
+    /// Alternatives with their vote tallies
+    pub poll_alternatives: PollAlternatives,
+}
+
+/// Poll parameters provided on the thread creation
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct PollInput<BlockNumber> {
+    /// Poll description
+    pub description: Vec<u8>,
+
+    /// Block at which the poll is closed
+    pub end_block: BlockNumber,
+
+    /// How the votes are counted
+    pub voting_mode: PollVotingMode,
+
+    /// Descriptions of the poll alternatives
+    pub poll_alternatives: Vec<Vec<u8>>,
+}
+
+/// Stake parameters of the stake weighted poll vote
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct PollVoteStakeParameters<AccountId, Balance> {
+    /// Staking account of the voter
+    pub staking_account_id: AccountId,
+
+    /// Stake to lock, which is the weight of the vote
+    pub stake: Balance,
+}
+
+/// Stake locked on the staking account by the stake weighted poll vote
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct PollVoteLock<BlockNumber, Balance> {
+    /// Locked stake
+    pub amount: Balance,
+
+    /// End block of the poll, the stake can be released starting from this block
+    pub locked_until: BlockNumber,
+}
+
 /// Represents a thread
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq, TypeInfo, MaxEncodedLen)]
-pub struct Thread<ForumUserId, CategoryId, RepayableBloatBond> {
+pub struct Thread<ForumUserId, CategoryId, RepayableBloatBond, Poll> {
     /// Category in which this thread lives
     pub category_id: CategoryId,
 
@@ -232,6 +498,9 @@ pub struct Thread<ForumUserId, CategoryId, RepayableBloatBond> {
 
     /// Number of posts in the thread
     pub number_of_editable_posts: NumberOfPosts,
+
+    /// Optional poll
+    pub poll: Option<Poll>,
 }
 
 /// Represents a category
@@ -377,6 +646,104 @@ decl_error! {
 
         /// Maximum number of stickied threads per category exceeded
         MaxNumberOfStickiedThreadsExceeded,
+
+        // Errors about poll.
+
+        /// Poll should have at least two alternatives
+        PollAlternativesTooShort,
+
+        /// Maximum number of poll alternatives exceeded
+        MaxPollAlternativesExceeded,
+
+        /// Poll end block should be in the future
+        InvalidPollEndBlock,
+
+        /// Thread has no poll
+        PollDoesNotExist,
+
+        /// Poll is closed
+        PollClosed,
+
+        /// Poll alternative with the given index does not exist
+        InvalidPollAlternativeIndex,
+
+        /// Forum user has already voted on the poll
+        AlreadyVotedOnPoll,
+
+        /// Stake weighted poll vote requires stake parameters
+        PollStakingAccountNotProvided,
+
+        /// Staking account doesn't belong to the forum user
+        InvalidPollStakingAccount,
+
+        /// Poll vote stake should be greater than zero
+        PollVoteStakeIsZero,
+
+        /// Staking account already has the stake of another poll vote locked
+        PollVoteStakeAlreadyLocked,
+
+        /// Staking account has conflicting stakes
+        PollVoteConflictingStakes,
+
+        /// Insufficient balance on the staking account for the poll vote stake
+        InsufficientBalanceForPollVoteStake,
+
+        /// Staking account has no poll vote stake locked
+        PollVoteStakeNotFound,
+
+        /// Poll vote stake stays locked until the poll ends
+        PollVoteStakeStillLocked,
+
+        // Errors about category posting requirements.
+
+        /// Posting in the category is restricted to verified members
+        PostingRequiresVerifiedMembership,
+
+        /// Membership is too recent to post in the category
+        PostingRequiresMinimumMembershipAge,
+
+        /// Posting in the category is restricted to the creator token holders
+        PostingRequiresCreatorTokenHolding,
+
+        /// Posting in the category is restricted to the working group workers
+        PostingRequiresWorkingGroupWorker,
+
+        // Errors about post reports.
+
+        /// Report deposit cannot be paid
+        InsufficientBalanceForReport,
+
+        /// Forum user has reached the maximum number of reports in the current period
+        ReportRateLimitExceeded,
+
+        /// Report does not exist
+        ReportDoesNotExist,
+
+        /// Category has pending reports
+        CategoryHasPendingReports,
+
+        /// Module account cannot cover the report deposit
+        InsufficientBalanceForReportDeposit,
+
+        /// Thread account cannot cover the deposit of the moderated post
+        InsufficientBalanceForPostDepositSlash,
+
+        // Errors about post reactions and tips.
+
+        /// Post has reached the maximum number of reactions
+        MaxReactionsPerPostExceeded,
+
+        /// Forum user has already reacted to the post with the same reaction
+        PostReactionUnchanged,
+
+        /// Tip amount cannot be zero
+        TipAmountCannotBeZero,
+
+        /// Forum user cannot tip their own post
+        CannotTipOwnPost,
+
+        /// Account has insufficient balance to pay the tip
+        InsufficientBalanceForTip,
     }
 }
 
@@ -408,6 +775,39 @@ decl_storage! { generate_storage_info
         /// Map post identifier to corresponding post.
         pub PostById get(fn post_by_id): double_map hasher(blake2_128_concat) T::ThreadId,
             hasher(blake2_128_concat) T::PostId => PostOf<T>;
+
+        /// Requirements to create threads and posts in the category and its subcategories.
+        pub CategoryPostingRequirements get(fn category_posting_requirements):
+            map hasher(blake2_128_concat) T::CategoryId => PostingRequirementsOf<T>;
+
+        /// Report identifier value to be used for the next report.
+        pub NextReportId get(fn next_report_id): ReportId;
+
+        /// Post reports queue of the category.
+        pub PostReportById get(fn post_report_by_id): double_map
+            hasher(blake2_128_concat) T::CategoryId,
+            hasher(blake2_128_concat) ReportId => Option<PostReportOf<T>>;
+
+        /// Reports rate limit state of the forum user.
+        pub ReportRateLimitByForumUser get(fn report_rate_limit_by_forum_user):
+            map hasher(blake2_128_concat) ForumUserId<T> => ReportRateLimitOf<T>;
+
+        /// Forum users which have voted on the thread poll.
+        pub PollVotes get(fn poll_votes): double_map hasher(blake2_128_concat) T::ThreadId,
+            hasher(blake2_128_concat) ForumUserId<T> => ();
+
+        /// Stakes of the stake weighted poll votes locked on the staking accounts.
+        pub PollVoteLockByAccount get(fn poll_vote_lock_by_account):
+            map hasher(blake2_128_concat) T::AccountId => Option<PollVoteLockOf<T>>;
+
+        /// Number of reactions of each kind attached to the post.
+        pub PostReactionCountersByPostId get(fn post_reaction_counters_by_post_id):
+            map hasher(blake2_128_concat) T::PostId => PostReactionCounters;
+
+        /// Reaction of the forum user to the post.
+        pub PostReactionByForumUser get(fn post_reaction_by_forum_user): double_map
+            hasher(blake2_128_concat) T::PostId,
+            hasher(blake2_128_concat) ForumUserId<T> => Option<PostReaction>;
     }
 }
 
@@ -422,6 +822,10 @@ decl_event!(
         ForumUserId = ForumUserId<T>,
         PrivilegedActor = PrivilegedActor<T>,
         ExtendedPostId = ExtendedPostId<T>,
+        PollInput = PollInputOf<T>,
+        Balance = BalanceOf<T>,
+        <T as frame_system::Config>::AccountId,
+        PostingRequirements = PostingRequirementsOf<T>,
     {
         /// A category was introduced
         CategoryCreated(CategoryId, Option<CategoryId>, Vec<u8>, Vec<u8>),
@@ -443,7 +847,8 @@ decl_event!(
 
         /// A thread with given id was created.
         /// A third argument reflects the initial post id of the thread.
-        ThreadCreated(CategoryId, ThreadId, PostId, ForumUserId, Vec<u8>, Vec<u8>),
+        /// The last argument is the optional thread poll.
+        ThreadCreated(CategoryId, ThreadId, PostId, ForumUserId, Vec<u8>, Vec<u8>, Option<PollInput>),
 
         /// A thread with given id was moderated.
         ThreadModerated(ThreadId, Vec<u8>, PrivilegedActor, CategoryId),
@@ -479,6 +884,67 @@ decl_event!(
 
         /// An moderator ability to moderate a category and its subcategories updated
         CategoryMembershipOfModeratorUpdated(ModeratorId, CategoryId, bool),
+
+        /// Vote on poll
+        /// Params:
+        /// - thread id
+        /// - index of the poll alternative
+        /// - forum user id of the voter
+        /// - category id
+        /// - weight of the vote
+        VoteOnPoll(ThreadId, u32, ForumUserId, CategoryId, Balance),
+
+        /// Poll vote stake was released
+        /// Params:
+        /// - forum user id of the voter
+        /// - staking account id
+        /// - released stake
+        PollVoteStakeReleased(ForumUserId, AccountId, Balance),
+
+        /// Category posting requirements were updated
+        /// Params:
+        /// - category id
+        /// - new posting requirements
+        /// - actor
+        CategoryPostingRequirementsUpdated(CategoryId, PostingRequirements, PrivilegedActor),
+
+        /// Post was reported
+        /// Params:
+        /// - report id
+        /// - forum user id of the reporter
+        /// - category id
+        /// - thread id
+        /// - post id
+        /// - reason code
+        PostReported(ReportId, ForumUserId, CategoryId, ThreadId, PostId, ReportReasonCode),
+
+        /// Post report was resolved
+        /// Params:
+        /// - report id
+        /// - category id
+        /// - resolution
+        /// - actor
+        /// - reward paid to the reporter
+        PostReportResolved(ReportId, CategoryId, ReportResolution, PrivilegedActor, Balance),
+
+        /// Forum user reacted to the post
+        /// Params:
+        /// - forum user id
+        /// - category id
+        /// - thread id
+        /// - post id
+        /// - new reaction (`None` if the reaction was removed)
+        PostReacted(ForumUserId, CategoryId, ThreadId, PostId, Option<PostReaction>),
+
+        /// Post author was tipped
+        /// Params:
+        /// - forum user id of the tipper
+        /// - category id
+        /// - thread id
+        /// - post id
+        /// - forum user id of the post author
+        /// - tip amount
+        PostAuthorTipped(ForumUserId, CategoryId, ThreadId, PostId, ForumUserId, Balance),
     }
 );
 
@@ -498,12 +964,30 @@ decl_module! {
         /// Deposit needed to create a thread
         const ThreadDeposit: BalanceOf<T> = T::ThreadDeposit::get();
 
+        /// Deposit needed to report a post
+        const ReportDeposit: BalanceOf<T> = T::ReportDeposit::get();
+
+        /// Reward for a report leading to moderation
+        const ReportReward: BalanceOf<T> = T::ReportReward::get();
+
+        /// Maximum number of reports per forum user in the rate limit period
+        const MaxReportsPerPeriod: u32 = T::MaxReportsPerPeriod::get();
+
+        /// Length of the report rate limit period in blocks
+        const ReportRateLimitPeriod: T::BlockNumber = T::ReportRateLimitPeriod::get();
+
         /// MaxDirectSubcategoriesInCategory
         const MaxDirectSubcategoriesInCategory: u64 = <T::MapLimits as StorageLimits>::MaxDirectSubcategoriesInCategory::get();
 
         /// MaxTotalCategories
         const MaxTotalCategories: u64 = <T::MapLimits as StorageLimits>::MaxTotalCategories::get();
 
+        /// Maximum number of alternatives in a thread poll
+        const MaxPollAlternatives: u32 = T::MaxPollAlternatives::get();
+
+        /// Maximum number of reactions attached to a single post
+        const MaxReactionsPerPost: u32 = T::MaxReactionsPerPost::get();
+
         /// Enable a moderator can moderate a category and its sub categories.
         ///
         /// <weight>
@@ -665,6 +1149,47 @@ decl_module! {
             Ok(())
         }
 
+        /// Update category posting requirements. The requirements are set by the lead and can be
+        /// overridden by the category moderators.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W)` where:
+        /// - `W` is the category depth
+        /// - DB:
+        ///    - O(W)
+        /// # </weight>
+        #[weight = WeightInfoForum::<T>::update_category_posting_requirements_lead(
+            T::MaxCategoryDepth::get() as u32,
+        ).max(WeightInfoForum::<T>::update_category_posting_requirements_moderator(
+            T::MaxCategoryDepth::get() as u32,
+        ))]
+        fn update_category_posting_requirements(
+            origin,
+            actor: PrivilegedActor<T>,
+            category_id: T::CategoryId,
+            requirements: PostingRequirementsOf<T>,
+        ) -> DispatchResult {
+            let account_id = ensure_signed(origin)?;
+
+            // Ensure actor can update category
+            Self::ensure_can_moderate_category(&account_id, &actor, &category_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <CategoryPostingRequirements<T>>::insert(category_id, requirements.clone());
+
+            // Generate event
+            Self::deposit_event(
+                RawEvent::CategoryPostingRequirementsUpdated(category_id, requirements, actor)
+            );
+
+            Ok(())
+        }
+
         /// Update category title
         ///
         /// <weight>
@@ -783,6 +1308,7 @@ decl_module! {
 
             // Delete category
             <CategoryById<T>>::remove(category_id);
+            <CategoryPostingRequirements<T>>::remove(category_id);
             if let Some(parent_category_id) = category.parent_category_id {
                 <CategoryById<T>>::try_mutate(parent_category_id, |tmp_category| {
                     tmp_category.num_direct_subcategories = tmp_category.num_direct_subcategories
@@ -801,15 +1327,16 @@ decl_module! {
             Ok(())
         }
 
-        /// Create new thread in category
+        /// Create new thread in category with an optional poll
         ///
         /// <weight>
         ///
         /// ## Weight
-        /// `O (W + V + X)` where:
+        /// `O (W + V + X + Z)` where:
         /// - `W` is the category depth
         /// - `V` is the size of the thread title in kilobytes.
         /// - `X` is the size of the thread text in kilobytes.
+        /// - `Z` is the number of poll alternatives.
         /// - DB:
         ///    - O(W)
         /// # </weight>
@@ -817,6 +1344,9 @@ decl_module! {
             T::MaxCategoryDepth::get() as u32,
             to_kb(metadata.len().saturated_into()),
             to_kb(text.len().saturated_into()),
+            poll_input
+                .as_ref()
+                .map_or(0, |poll| poll.poll_alternatives.len().saturated_into()),
         )]
         fn create_thread(
             origin,
@@ -824,11 +1354,14 @@ decl_module! {
             category_id: T::CategoryId,
             metadata: Vec<u8>,
             text: Vec<u8>,
+            poll_input: Option<PollInputOf<T>>,
         ) -> DispatchResult {
             let account_id = ensure_signed(origin)?;
 
             Self::ensure_can_create_thread(&account_id, &forum_user_id, &category_id)?;
 
+            let poll = poll_input.as_ref().map(Self::ensure_valid_poll).transpose()?;
+
             //
             // == MUTATION SAFE ==
             //
@@ -846,6 +1379,7 @@ decl_module! {
                 author_id: forum_user_id,
                 cleanup_pay_off: repaybale_thread_bloat_bond,
                 number_of_editable_posts: 0,
+                poll,
             };
 
             // Store thread
@@ -882,6 +1416,7 @@ decl_module! {
                     forum_user_id,
                     metadata,
                     text,
+                    poll_input,
                 )
             );
 
@@ -1059,7 +1594,7 @@ decl_module! {
                 T::MaxCategoryDepth::get() as u32,
                 to_kb(rationale.len().saturated_into()),
             )
-        )]
+        ).saturating_add(T::MemberReputationHandler::record_activity_weight())]
         fn moderate_thread(origin, actor: PrivilegedActor<T>, category_id: T::CategoryId, thread_id: T::ThreadId, rationale: Vec<u8>) -> DispatchResult {
             let account_id = ensure_signed(origin)?;
 
@@ -1075,6 +1610,11 @@ decl_module! {
             // Delete thread
             Self::delete_thread_inner(thread.category_id, thread_id)?;
 
+            T::MemberReputationHandler::record_activity(
+                &thread.author_id,
+                ReputationActivity::ModerationAction,
+            );
+
             // Generate event
             Self::deposit_event(
                 RawEvent::ThreadModerated(thread_id, rationale, actor, category_id)
@@ -1207,19 +1747,22 @@ decl_module! {
         /// <weight>
         ///
         /// ## Weight
-        /// `O (W + V)` where:
+        /// `O (W + V + R)` where:
         /// - `W` is the category depth,
         /// - `V` is the size of the rationale in kilobytes
+        /// - `R` is the maximum number of reactions per post
         /// - DB:
-        ///    - O(W)
+        ///    - O(W + R)
         /// # </weight>
         #[weight = WeightInfoForum::<T>::moderate_post_lead(
             T::MaxCategoryDepth::get() as u32,
             to_kb(rationale.len().saturated_into()),
+            T::MaxReactionsPerPost::get(),
         ).max(WeightInfoForum::<T>::moderate_post_moderator(
             T::MaxCategoryDepth::get() as u32,
             to_kb(rationale.len().saturated_into()),
-        ))]
+            T::MaxReactionsPerPost::get(),
+        )).saturating_add(T::MemberReputationHandler::record_activity_weight())]
         fn moderate_post(origin, actor: PrivilegedActor<T>, category_id: T::CategoryId, thread_id: T::ThreadId, post_id: T::PostId, rationale: Vec<u8>) -> DispatchResult {
             let account_id = ensure_signed(origin)?;
 
@@ -1240,6 +1783,11 @@ decl_module! {
 
             Self::delete_post_inner(category_id, thread_id, post_id);
 
+            T::MemberReputationHandler::record_activity(
+                &post.author_id,
+                ReputationActivity::ModerationAction,
+            );
+
             // Generate event
             Self::deposit_event(
                 RawEvent::PostModerated(post_id, rationale, actor, category_id, thread_id)
@@ -1254,17 +1802,19 @@ decl_module! {
         /// where the last bool is whether you want to hide it apart from deleting it
         ///
         /// ## Weight
-        /// `O (W + V + P)` where:
+        /// `O (W + V + P * R)` where:
         /// - `W` is the category depth,
         /// - `V` is the size of the rationale in kilobytes
         /// - `P` is the number of posts to delete
+        /// - `R` is the maximum number of reactions per post
         /// - DB:
-        ///    - O(W + P)
+        ///    - O(W + P * R)
         /// # </weight>
         #[weight = WeightInfoForum::<T>::delete_posts(
             T::MaxCategoryDepth::get() as u32,
             to_kb(rationale.len().saturated_into()),
             posts.len().saturated_into(),
+            T::MaxReactionsPerPost::get().saturating_mul(posts.len().saturated_into()),
         )]
         fn delete_posts(
             origin,
@@ -1353,42 +1903,448 @@ decl_module! {
 
             Ok(())
         }
-    }
-}
 
-impl<T: Config> Module<T> {
-    fn thread_account(thread_id: T::ThreadId) -> T::AccountId {
-        T::ModuleId::get().into_sub_account_truncating(thread_id)
-    }
+        /// Vote on the thread poll. Stake weighted polls require stake parameters of the voter,
+        /// the stake is locked on the staking account until the poll ends and weights the vote.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W + V)` where:
+        /// - `W` is the category depth,
+        /// - `V` is the number of poll alternatives.
+        /// - DB:
+        ///    - O(W)
+        /// # </weight>
+        #[weight = WeightInfoForum::<T>::vote_on_poll(
+            T::MaxCategoryDepth::get() as u32,
+            T::MaxPollAlternatives::get(),
+        )]
+        fn vote_on_poll(
+            origin,
+            forum_user_id: ForumUserId<T>,
+            category_id: T::CategoryId,
+            thread_id: T::ThreadId,
+            index: u32,
+            stake_parameters: Option<PollVoteStakeParametersOf<T>>,
+        ) -> DispatchResult {
+            let account_id = ensure_signed(origin)?;
 
-    fn slash_thread_account(thread_id: T::ThreadId, amount: BalanceOf<T>) -> DispatchResult {
-        let thread_account_id = Self::thread_account(thread_id);
-        burn_from_usable::<T>(&thread_account_id, amount).map(|_| ())
-    }
+            let (vote_weight, poll_vote_lock) = Self::ensure_can_vote_on_poll(
+                &account_id,
+                &forum_user_id,
+                &category_id,
+                &thread_id,
+                index,
+                stake_parameters.as_ref(),
+            )?;
 
-    fn pay_post_deposit(
-        thread_id: T::ThreadId,
-        account_id: &T::AccountId,
-    ) -> Result<RepayableBloatBondOf<T>, DispatchError> {
-        let post_deposit = T::PostDeposit::get();
-        let thread_account_id = Self::thread_account(thread_id);
-        let locked_balance_used = pay_fee::<T>(account_id, Some(&thread_account_id), post_deposit)?;
+            //
+            // == MUTATION SAFE ==
+            //
 
-        // construct RepayableBloatBond based on pay_fee result
-        Ok(match locked_balance_used.is_zero() {
-            true => RepayableBloatBond::new(post_deposit, None),
-            false => RepayableBloatBond::new(post_deposit, Some(account_id.clone())),
-        })
-    }
+            <ThreadById<T>>::mutate(category_id, thread_id, |thread| {
+                if let Some(alternative) = thread
+                    .poll
+                    .as_mut()
+                    .and_then(|poll| poll.poll_alternatives.get_mut(index as usize))
+                {
+                    alternative.vote_count = alternative.vote_count.saturating_add(vote_weight);
+                }
+            });
 
-    fn pay_thread_and_post_deposit(
-        thread_id: T::ThreadId,
-        account_id: &T::AccountId,
-    ) -> Result<(RepayableBloatBondOf<T>, RepayableBloatBondOf<T>), DispatchError> {
-        let thread_deposit = T::ThreadDeposit::get();
-        let post_deposit = T::PostDeposit::get();
-        let total_deposit = thread_deposit.saturating_add(post_deposit);
-        let thread_account_id = Self::thread_account(thread_id);
+            <PollVotes<T>>::insert(thread_id, forum_user_id, ());
+
+            if let Some((stake_parameters, poll_vote_lock)) =
+                stake_parameters.zip(poll_vote_lock)
+            {
+                let staking_account_id = stake_parameters.staking_account_id;
+
+                T::StakingHandler::lock(&staking_account_id, poll_vote_lock.amount);
+
+                <PollVoteLockByAccount<T>>::insert(staking_account_id, poll_vote_lock);
+            }
+
+            // Generate event
+            Self::deposit_event(
+                RawEvent::VoteOnPoll(thread_id, index, forum_user_id, category_id, vote_weight)
+            );
+
+            Ok(())
+        }
+
+        /// Release the stake of the stake weighted poll vote after the poll ended.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoForum::<T>::release_poll_vote_stake()]
+        fn release_poll_vote_stake(
+            origin,
+            forum_user_id: ForumUserId<T>,
+            staking_account_id: T::AccountId,
+        ) -> DispatchResult {
+            let account_id = ensure_signed(origin)?;
+
+            Self::ensure_is_forum_user(&account_id, &forum_user_id)?;
+
+            ensure!(
+                T::StakingAccountValidator::is_member_staking_account(
+                    &forum_user_id,
+                    &staking_account_id
+                ),
+                Error::<T>::InvalidPollStakingAccount
+            );
+
+            let lock = Self::poll_vote_lock_by_account(&staking_account_id)
+                .ok_or(Error::<T>::PollVoteStakeNotFound)?;
+
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= lock.locked_until,
+                Error::<T>::PollVoteStakeStillLocked
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            T::StakingHandler::unlock(&staking_account_id);
+
+            <PollVoteLockByAccount<T>>::remove(&staking_account_id);
+
+            Self::deposit_event(
+                RawEvent::PollVoteStakeReleased(forum_user_id, staking_account_id, lock.amount)
+            );
+
+            Ok(())
+        }
+
+        /// Report a post to the category moderators. Requires a refundable report deposit.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W)` where:
+        /// - `W` is the category depth
+        /// - DB:
+        ///    - O(W)
+        /// # </weight>
+        #[weight = WeightInfoForum::<T>::report_post(
+            T::MaxCategoryDepth::get() as u32,
+        )]
+        fn report_post(
+            origin,
+            forum_user_id: ForumUserId<T>,
+            category_id: T::CategoryId,
+            thread_id: T::ThreadId,
+            post_id: T::PostId,
+            reason_code: ReportReasonCode,
+        ) -> DispatchResult {
+            let account_id = ensure_signed(origin)?;
+
+            let rate_limit = Self::ensure_can_report_post(
+                &account_id,
+                &forum_user_id,
+                &category_id,
+                &thread_id,
+                &post_id,
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let report_deposit = T::ReportDeposit::get();
+            let locked_balance_used =
+                pay_fee::<T>(&account_id, Some(&Self::module_account_id()), report_deposit)?;
+            let deposit = match locked_balance_used.is_zero() {
+                true => RepayableBloatBond::new(report_deposit, None),
+                false => RepayableBloatBond::new(report_deposit, Some(account_id.clone())),
+            };
+
+            let report_id = Self::next_report_id();
+            <NextReportId>::put(report_id.saturating_add(1));
+
+            <PostReportById<T>>::insert(category_id, report_id, PostReport {
+                thread_id,
+                post_id,
+                reason_code,
+                reporter_id: forum_user_id,
+                reporter_account: account_id,
+                deposit,
+            });
+
+            <ReportRateLimitByForumUser<T>>::insert(forum_user_id, rate_limit);
+
+            // Generate event
+            Self::deposit_event(
+                RawEvent::PostReported(
+                    report_id,
+                    forum_user_id,
+                    category_id,
+                    thread_id,
+                    post_id,
+                    reason_code,
+                )
+            );
+
+            Ok(())
+        }
+
+        /// Resolve a post report. Dismissing the report slashes the report deposit, moderating
+        /// the reported post refunds the deposit and rewards the reporter from the forum working
+        /// group budget. If the reported post no longer exists when the report is resolved with
+        /// moderation, the deposit is refunded without a reward.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W + V + R)` where:
+        /// - `W` is the category depth
+        /// - `V` is the size of the rationale in kilobytes
+        /// - `R` is the maximum number of reactions per post
+        /// - DB:
+        ///    - O(W + R)
+        /// # </weight>
+        #[weight = WeightInfoForum::<T>::resolve_post_report_lead(
+            T::MaxCategoryDepth::get() as u32,
+            to_kb(rationale.len().saturated_into()),
+            T::MaxReactionsPerPost::get(),
+        ).max(WeightInfoForum::<T>::resolve_post_report_moderator(
+            T::MaxCategoryDepth::get() as u32,
+            to_kb(rationale.len().saturated_into()),
+            T::MaxReactionsPerPost::get(),
+        )).saturating_add(T::MemberReputationHandler::record_activity_weight())]
+        fn resolve_post_report(
+            origin,
+            actor: PrivilegedActor<T>,
+            category_id: T::CategoryId,
+            report_id: ReportId,
+            resolution: ReportResolution,
+            rationale: Vec<u8>,
+        ) -> DispatchResult {
+            let account_id = ensure_signed(origin)?;
+
+            let (report, post_to_moderate) = Self::ensure_can_resolve_post_report(
+                &account_id,
+                &actor,
+                &category_id,
+                report_id,
+                resolution,
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let module_account_id = Self::module_account_id();
+            let reward = match resolution {
+                ReportResolution::Dismiss => {
+                    // Module account balance was checked above
+                    let _ = burn_from_usable::<T>(&module_account_id, report.deposit.amount);
+
+                    Zero::zero()
+                }
+                ReportResolution::Moderate => {
+                    // Module account balance was checked above
+                    let _ = report.deposit.repay::<T>(
+                        &module_account_id,
+                        &report.reporter_account,
+                        true
+                    );
+
+                    if let Some(post) = post_to_moderate {
+                        // Thread account balance was checked above
+                        let _ = Self::slash_thread_account(
+                            report.thread_id,
+                            post.cleanup_pay_off.amount
+                        );
+
+                        Self::delete_post_inner(category_id, report.thread_id, report.post_id);
+
+                        T::MemberReputationHandler::record_activity(
+                            &post.author_id,
+                            ReputationActivity::ModerationAction,
+                        );
+
+                        Self::deposit_event(
+                            RawEvent::PostModerated(
+                                report.post_id,
+                                rationale,
+                                actor.clone(),
+                                category_id,
+                                report.thread_id,
+                            )
+                        );
+
+                        // Reward is paid only if the working group budget allows it
+                        let reward = T::ReportReward::get();
+                        T::WorkingGroup::try_withdraw(&report.reporter_account, reward)
+                            .map_or(Zero::zero(), |_| reward)
+                    } else {
+                        // The post was already moderated or deleted: only the deposit is refunded
+                        Zero::zero()
+                    }
+                }
+            };
+
+            <PostReportById<T>>::remove(category_id, report_id);
+
+            // Generate event
+            Self::deposit_event(
+                RawEvent::PostReportResolved(report_id, category_id, resolution, actor, reward)
+            );
+
+            Ok(())
+        }
+
+        /// React to a post with one of the predefined reactions. Providing `None` removes
+        /// the previous reaction of the forum user.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W)` where:
+        /// - `W` is the category depth
+        /// - DB:
+        ///    - O(W)
+        /// # </weight>
+        #[weight = WeightInfoForum::<T>::react_post(
+            T::MaxCategoryDepth::get() as u32,
+        )]
+        fn react_post(
+            origin,
+            forum_user_id: ForumUserId<T>,
+            category_id: T::CategoryId,
+            thread_id: T::ThreadId,
+            post_id: T::PostId,
+            reaction: Option<PostReaction>,
+        ) -> DispatchResult {
+            let account_id = ensure_signed(origin)?;
+
+            let counters = Self::ensure_can_react_post(
+                &account_id,
+                &forum_user_id,
+                &category_id,
+                &thread_id,
+                &post_id,
+                reaction,
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <PostReactionCountersByPostId<T>>::insert(post_id, counters);
+
+            match reaction {
+                Some(reaction) => {
+                    <PostReactionByForumUser<T>>::insert(post_id, forum_user_id, reaction)
+                }
+                None => <PostReactionByForumUser<T>>::remove(post_id, forum_user_id),
+            }
+
+            // Generate event
+            Self::deposit_event(
+                RawEvent::PostReacted(forum_user_id, category_id, thread_id, post_id, reaction)
+            );
+
+            Ok(())
+        }
+
+        /// Tip the post author. The tip is transferred to the controller account of the author.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoForum::<T>::tip_post_author()]
+        fn tip_post_author(
+            origin,
+            forum_user_id: ForumUserId<T>,
+            category_id: T::CategoryId,
+            thread_id: T::ThreadId,
+            post_id: T::PostId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let account_id = ensure_signed(origin)?;
+
+            let (author_id, author_account) = Self::ensure_can_tip_post_author(
+                &account_id,
+                &forum_user_id,
+                &category_id,
+                &thread_id,
+                &post_id,
+                amount,
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            tip_post_author::<T>(&account_id, &author_account, amount)?;
+
+            // Generate event
+            Self::deposit_event(
+                RawEvent::PostAuthorTipped(
+                    forum_user_id,
+                    category_id,
+                    thread_id,
+                    post_id,
+                    author_id,
+                    amount,
+                )
+            );
+
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Module<T> {
+    fn thread_account(thread_id: T::ThreadId) -> T::AccountId {
+        T::ModuleId::get().into_sub_account_truncating(thread_id)
+    }
+
+    fn module_account_id() -> T::AccountId {
+        T::ModuleId::get().into_account_truncating()
+    }
+
+    fn slash_thread_account(thread_id: T::ThreadId, amount: BalanceOf<T>) -> DispatchResult {
+        let thread_account_id = Self::thread_account(thread_id);
+        burn_from_usable::<T>(&thread_account_id, amount).map(|_| ())
+    }
+
+    fn pay_post_deposit(
+        thread_id: T::ThreadId,
+        account_id: &T::AccountId,
+    ) -> Result<RepayableBloatBondOf<T>, DispatchError> {
+        let post_deposit = T::PostDeposit::get();
+        let thread_account_id = Self::thread_account(thread_id);
+        let locked_balance_used = pay_fee::<T>(account_id, Some(&thread_account_id), post_deposit)?;
+
+        // construct RepayableBloatBond based on pay_fee result
+        Ok(match locked_balance_used.is_zero() {
+            true => RepayableBloatBond::new(post_deposit, None),
+            false => RepayableBloatBond::new(post_deposit, Some(account_id.clone())),
+        })
+    }
+
+    fn pay_thread_and_post_deposit(
+        thread_id: T::ThreadId,
+        account_id: &T::AccountId,
+    ) -> Result<(RepayableBloatBondOf<T>, RepayableBloatBondOf<T>), DispatchError> {
+        let thread_deposit = T::ThreadDeposit::get();
+        let post_deposit = T::PostDeposit::get();
+        let total_deposit = thread_deposit.saturating_add(post_deposit);
+        let thread_account_id = Self::thread_account(thread_id);
         let locked_balance_used =
             pay_fee::<T>(account_id, Some(&thread_account_id), total_deposit)?;
 
@@ -1451,6 +2407,9 @@ impl<T: Config> Module<T> {
         // Delete thread
         <ThreadById<T>>::remove(category_id, thread_id);
 
+        // Delete poll votes
+        <PollVotes<T>>::remove_prefix(thread_id, None);
+
         // decrease category's thread counter
         <CategoryById<T>>::try_mutate(category_id, |category| {
             category.num_direct_threads = category
@@ -1472,6 +2431,10 @@ impl<T: Config> Module<T> {
         }
 
         <PostById<T>>::remove(thread_id, post_id);
+
+        // Delete post reactions
+        <PostReactionCountersByPostId<T>>::remove(post_id);
+        <PostReactionByForumUser<T>>::remove_prefix(post_id, None);
     }
 
     fn ensure_post_is_mutable(
@@ -1834,6 +2797,12 @@ impl<T: Config> Module<T> {
             category.num_direct_subcategories == 0,
             Error::<T>::CategoryNotEmptyCategories,
         );
+        ensure!(
+            <PostReportById<T>>::iter_prefix(category_id)
+                .next()
+                .is_none(),
+            Error::<T>::CategoryHasPendingReports,
+        );
 
         // check moderator's privilege
         if let Some(parent_category_id) = category.parent_category_id {
@@ -1968,6 +2937,8 @@ impl<T: Config> Module<T> {
 
         Self::ensure_category_is_mutable(category_id)?;
 
+        Self::ensure_posting_requirements_met(forum_user_id, category_id)?;
+
         // Check if the costs associated with thread and post creation are coverable
         let fees = T::ThreadDeposit::get().saturating_add(T::PostDeposit::get());
         ensure!(
@@ -1978,6 +2949,125 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    fn ensure_valid_poll(poll_input: &PollInputOf<T>) -> Result<PollOf<T>, DispatchError> {
+        ensure!(
+            poll_input.poll_alternatives.len() >= 2,
+            Error::<T>::PollAlternativesTooShort
+        );
+
+        ensure!(
+            poll_input.end_block > frame_system::Pallet::<T>::block_number(),
+            Error::<T>::InvalidPollEndBlock
+        );
+
+        let poll_alternatives: PollAlternativesOf<T> = poll_input
+            .poll_alternatives
+            .iter()
+            .map(|text| PollAlternative {
+                alternative_text_hash: T::calculate_hash(text),
+                vote_count: Zero::zero(),
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .map_err(|_| Error::<T>::MaxPollAlternativesExceeded)?;
+
+        Ok(Poll {
+            description_hash: T::calculate_hash(&poll_input.description),
+            end_block: poll_input.end_block,
+            voting_mode: poll_input.voting_mode,
+            poll_alternatives,
+        })
+    }
+
+    fn ensure_can_vote_on_poll(
+        account_id: &T::AccountId,
+        forum_user_id: &ForumUserId<T>,
+        category_id: &T::CategoryId,
+        thread_id: &T::ThreadId,
+        index: u32,
+        stake_parameters: Option<&PollVoteStakeParametersOf<T>>,
+    ) -> Result<(BalanceOf<T>, Option<PollVoteLockOf<T>>), DispatchError> {
+        // Check that account is forum member
+        Self::ensure_is_forum_user(account_id, forum_user_id)?;
+
+        let (_, thread) = Self::ensure_thread_is_mutable(category_id, thread_id)?;
+
+        let poll = thread.poll.ok_or(Error::<T>::PollDoesNotExist)?;
+
+        ensure!(
+            frame_system::Pallet::<T>::block_number() < poll.end_block,
+            Error::<T>::PollClosed
+        );
+
+        ensure!(
+            (index as usize) < poll.poll_alternatives.len(),
+            Error::<T>::InvalidPollAlternativeIndex
+        );
+
+        ensure!(
+            !<PollVotes<T>>::contains_key(thread_id, forum_user_id),
+            Error::<T>::AlreadyVotedOnPoll
+        );
+
+        match poll.voting_mode {
+            PollVotingMode::OneVotePerMember => Ok((One::one(), None)),
+            PollVotingMode::StakeWeighted => {
+                let stake_parameters =
+                    stake_parameters.ok_or(Error::<T>::PollStakingAccountNotProvided)?;
+
+                Self::ensure_can_lock_poll_vote_stake(forum_user_id, stake_parameters)?;
+
+                let poll_vote_lock = PollVoteLock {
+                    amount: stake_parameters.stake,
+                    locked_until: poll.end_block,
+                };
+
+                Ok((stake_parameters.stake, Some(poll_vote_lock)))
+            }
+        }
+    }
+
+    fn ensure_can_lock_poll_vote_stake(
+        forum_user_id: &ForumUserId<T>,
+        stake_parameters: &PollVoteStakeParametersOf<T>,
+    ) -> DispatchResult {
+        let staking_account_id = &stake_parameters.staking_account_id;
+
+        ensure!(
+            T::StakingAccountValidator::is_member_staking_account(
+                forum_user_id,
+                staking_account_id
+            ),
+            Error::<T>::InvalidPollStakingAccount
+        );
+
+        ensure!(
+            !stake_parameters.stake.is_zero(),
+            Error::<T>::PollVoteStakeIsZero
+        );
+
+        // One poll vote stake per staking account: it is released only after the poll ends.
+        ensure!(
+            !<PollVoteLockByAccount<T>>::contains_key(staking_account_id),
+            Error::<T>::PollVoteStakeAlreadyLocked
+        );
+
+        ensure!(
+            T::StakingHandler::is_account_free_of_conflicting_stakes(staking_account_id),
+            Error::<T>::PollVoteConflictingStakes
+        );
+
+        ensure!(
+            T::StakingHandler::is_enough_balance_for_stake(
+                staking_account_id,
+                stake_parameters.stake
+            ),
+            Error::<T>::InsufficientBalanceForPollVoteStake
+        );
+
+        Ok(())
+    }
+
     fn ensure_can_add_post(
         account_id: &T::AccountId,
         forum_user_id: &ForumUserId<T>,
@@ -1989,9 +3079,212 @@ impl<T: Config> Module<T> {
 
         let (category, thread) = Self::ensure_thread_is_mutable(category_id, thread_id)?;
 
+        Self::ensure_posting_requirements_met(forum_user_id, category_id)?;
+
         Ok((category, thread))
     }
 
+    fn ensure_can_report_post(
+        account_id: &T::AccountId,
+        forum_user_id: &ForumUserId<T>,
+        category_id: &T::CategoryId,
+        thread_id: &T::ThreadId,
+        post_id: &T::PostId,
+    ) -> Result<ReportRateLimitOf<T>, DispatchError> {
+        // Check that account is forum member
+        Self::ensure_is_forum_user(account_id, forum_user_id)?;
+
+        // Make sure post exists and is mutable
+        Self::ensure_post_is_mutable(category_id, thread_id, post_id)?;
+
+        // Start a new rate limit period if the current one has passed
+        let now = frame_system::Pallet::<T>::block_number();
+        let rate_limit = Self::report_rate_limit_by_forum_user(forum_user_id);
+        let rate_limit = if now
+            >= rate_limit
+                .period_start
+                .saturating_add(T::ReportRateLimitPeriod::get())
+        {
+            ReportRateLimit {
+                period_start: now,
+                reports_in_period: 0,
+            }
+        } else {
+            rate_limit
+        };
+
+        ensure!(
+            rate_limit.reports_in_period < T::MaxReportsPerPeriod::get(),
+            Error::<T>::ReportRateLimitExceeded
+        );
+
+        ensure!(
+            has_sufficient_balance_for_fees::<T>(account_id, T::ReportDeposit::get()),
+            Error::<T>::InsufficientBalanceForReport
+        );
+
+        Ok(ReportRateLimit {
+            reports_in_period: rate_limit.reports_in_period.saturating_add(1),
+            ..rate_limit
+        })
+    }
+
+    fn ensure_can_react_post(
+        account_id: &T::AccountId,
+        forum_user_id: &ForumUserId<T>,
+        category_id: &T::CategoryId,
+        thread_id: &T::ThreadId,
+        post_id: &T::PostId,
+        reaction: Option<PostReaction>,
+    ) -> Result<PostReactionCounters, DispatchError> {
+        // Check that account is forum member
+        Self::ensure_is_forum_user(account_id, forum_user_id)?;
+
+        // Make sure post exists and is mutable
+        Self::ensure_post_is_mutable(category_id, thread_id, post_id)?;
+
+        let previous_reaction = Self::post_reaction_by_forum_user(post_id, forum_user_id);
+        ensure!(
+            previous_reaction != reaction,
+            Error::<T>::PostReactionUnchanged
+        );
+
+        let mut counters = Self::post_reaction_counters_by_post_id(post_id);
+        ensure!(
+            counters.try_replace(previous_reaction, reaction, T::MaxReactionsPerPost::get()),
+            Error::<T>::MaxReactionsPerPostExceeded
+        );
+
+        Ok(counters)
+    }
+
+    fn ensure_can_tip_post_author(
+        account_id: &T::AccountId,
+        forum_user_id: &ForumUserId<T>,
+        category_id: &T::CategoryId,
+        thread_id: &T::ThreadId,
+        post_id: &T::PostId,
+        amount: BalanceOf<T>,
+    ) -> Result<(ForumUserId<T>, T::AccountId), DispatchError> {
+        // Check that account is forum member
+        Self::ensure_is_forum_user(account_id, forum_user_id)?;
+
+        let post = Self::ensure_post_exists(category_id, thread_id, post_id)?;
+
+        ensure!(!amount.is_zero(), Error::<T>::TipAmountCannotBeZero);
+
+        ensure!(
+            post.author_id != *forum_user_id,
+            Error::<T>::CannotTipOwnPost
+        );
+
+        ensure!(
+            has_sufficient_balance_for_payment::<T>(account_id, amount),
+            Error::<T>::InsufficientBalanceForTip
+        );
+
+        let author_account = T::MembershipInfoProvider::controller_account_id(post.author_id)?;
+
+        Ok((post.author_id, author_account))
+    }
+
+    fn ensure_can_resolve_post_report(
+        account_id: &T::AccountId,
+        actor: &PrivilegedActor<T>,
+        category_id: &T::CategoryId,
+        report_id: ReportId,
+        resolution: ReportResolution,
+    ) -> Result<(PostReportOf<T>, Option<PostOf<T>>), DispatchError> {
+        // Ensure actor can moderate the category
+        Self::ensure_can_moderate_category(account_id, actor, category_id)?;
+
+        let report = Self::ensure_report_exists(category_id, report_id)?;
+
+        // The deposit is either burned or repaid from the module account
+        ensure!(
+            has_sufficient_balance_for_payment::<T>(
+                &Self::module_account_id(),
+                report.deposit.amount
+            ),
+            Error::<T>::InsufficientBalanceForReportDeposit
+        );
+
+        let post_to_moderate = match resolution {
+            ReportResolution::Dismiss => None,
+            // The post might have been already moderated or deleted
+            ReportResolution::Moderate => {
+                Self::ensure_post_is_mutable(category_id, &report.thread_id, &report.post_id).ok()
+            }
+        };
+
+        if let Some(post) = &post_to_moderate {
+            ensure!(
+                has_sufficient_balance_for_payment::<T>(
+                    &Self::thread_account(report.thread_id),
+                    post.cleanup_pay_off.amount
+                ),
+                Error::<T>::InsufficientBalanceForPostDepositSlash
+            );
+        }
+
+        Ok((report, post_to_moderate))
+    }
+
+    fn ensure_report_exists(
+        category_id: &T::CategoryId,
+        report_id: ReportId,
+    ) -> Result<PostReportOf<T>, Error<T>> {
+        Self::post_report_by_id(category_id, report_id).ok_or(Error::<T>::ReportDoesNotExist)
+    }
+
+    // The requirements of the category and all of its ancestors have to be met
+    fn ensure_posting_requirements_met(
+        forum_user_id: &ForumUserId<T>,
+        category_id: &T::CategoryId,
+    ) -> Result<(), Error<T>> {
+        for (path_category_id, _) in Self::build_category_tree_path(category_id) {
+            let requirements = Self::category_posting_requirements(path_category_id);
+            Self::ensure_category_posting_requirements_met(forum_user_id, requirements)?;
+        }
+
+        Ok(())
+    }
+
+    fn ensure_category_posting_requirements_met(
+        forum_user_id: &ForumUserId<T>,
+        requirements: PostingRequirementsOf<T>,
+    ) -> Result<(), Error<T>> {
+        ensure!(
+            !requirements.verified_members_only || T::is_verified_member(forum_user_id),
+            Error::<T>::PostingRequiresVerifiedMembership
+        );
+
+        if let Some(min_membership_age) = requirements.min_membership_age {
+            let membership_age = frame_system::Pallet::<T>::block_number()
+                .saturating_sub(T::membership_created_at(forum_user_id));
+            ensure!(
+                membership_age >= min_membership_age,
+                Error::<T>::PostingRequiresMinimumMembershipAge
+            );
+        }
+
+        if let Some(token_id) = requirements.creator_token {
+            ensure!(
+                T::is_creator_token_holder(token_id, forum_user_id),
+                Error::<T>::PostingRequiresCreatorTokenHolding
+            );
+        }
+
+        if let Some(working_group) = requirements.working_group {
+            ensure!(
+                T::is_working_group_worker(working_group, forum_user_id),
+                Error::<T>::PostingRequiresWorkingGroupWorker
+            );
+        }
+
+        Ok(())
+    }
+
     fn ensure_can_set_stickied_threads(
         account_id: T::AccountId,
         actor: &PrivilegedActor<T>,
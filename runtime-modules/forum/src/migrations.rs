@@ -0,0 +1,39 @@
+// Storage migrations of the forum pallet.
+
+use codec::{Decode, Encode};
+use frame_support::{traits::Get, weights::Weight, IterableStorageDoubleMap};
+
+use crate::{Config, ForumUserId, Module, NumberOfPosts, ThreadById, ThreadOf};
+use common::bloat_bond::RepayableBloatBondOf;
+
+/// Thread stored before the thread polls were introduced
+#[derive(Encode, Decode)]
+pub(crate) struct LegacyThread<ForumUserId, CategoryId, RepayableBloatBond> {
+    pub category_id: CategoryId,
+    pub author_id: ForumUserId,
+    pub cleanup_pay_off: RepayableBloatBond,
+    pub number_of_editable_posts: NumberOfPosts,
+}
+
+pub(crate) type LegacyThreadOf<T> =
+    LegacyThread<ForumUserId<T>, <T as Config>::CategoryId, RepayableBloatBondOf<T>>;
+
+impl<T: Config> Module<T> {
+    /// Translates the stored threads to the current `Thread` format (without a poll).
+    pub fn migrate_threads() -> Weight {
+        let mut translated: u64 = 0;
+
+        ThreadById::<T>::translate::<LegacyThreadOf<T>, _>(|_, _, thread| {
+            translated = translated.saturating_add(1);
+            Some(ThreadOf::<T> {
+                category_id: thread.category_id,
+                author_id: thread.author_id,
+                cleanup_pay_off: thread.cleanup_pay_off,
+                number_of_editable_posts: thread.number_of_editable_posts,
+                poll: None,
+            })
+        });
+
+        T::DbWeight::get().reads_writes(translated, translated)
+    }
+}
@@ -7,7 +7,9 @@ pub use frame_support::{assert_err, assert_noop};
 use sp_core::H256;
 
 use crate::Config;
-use common::locks::{BoundStakingAccountLockId, ForumGroupLockId, InvitedMemberLockId};
+use common::locks::{
+    BoundStakingAccountLockId, ForumGroupLockId, ForumPollLockId, InvitedMemberLockId,
+};
 use frame_support::traits::{
     ConstU16, ConstU32, ConstU64, Currency, LockIdentifier, OnFinalize, OnInitialize,
     WithdrawReasons,
@@ -117,6 +119,7 @@ impl working_group::Config<ForumWorkingGroupInstance> for Runtime {
     type StakingAccountValidator = membership::Module<Runtime>;
     type StakingHandler = staking_handler::StakingManager<Self, ForumGroupLockId>;
     type MemberOriginValidator = ();
+    type MemberReputationHandler = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
     type WeightInfo = ();
@@ -137,12 +140,22 @@ pub const WORKING_GROUP_BUDGET: u64 = 100;
 
 thread_local! {
     pub static WG_BUDGET: RefCell<u64> = RefCell::new(WORKING_GROUP_BUDGET);
+    pub static VERIFIED_MEMBERS: RefCell<Vec<u128>> = RefCell::new(Vec::new());
+    pub static CREATOR_TOKEN_HOLDERS: RefCell<Vec<(u64, u128)>> = RefCell::new(Vec::new());
 }
 
 pub struct Wg;
 impl common::working_group::WorkingGroupBudgetHandler<u128, u64> for Wg {
-    fn try_withdraw(_account_id: &u128, _amount: u64) -> DispatchResult {
-        unimplemented!()
+    fn try_withdraw(account_id: &u128, amount: u64) -> DispatchResult {
+        ensure!(
+            Self::get_budget() >= amount,
+            DispatchError::Other("Insufficient working group budget")
+        );
+
+        let _ = balances::Pallet::<Runtime>::deposit_creating(account_id, amount);
+        Self::decrease_budget(amount);
+
+        Ok(())
     }
 
     fn get_budget() -> u64 {
@@ -168,6 +181,13 @@ impl membership::Config for Runtime {
         staking_handler::StakingManager<Self, BoundStakingAccountLockId>;
     type CandidateStake = CandidateStake;
     type DefaultMemberInvitesCount = DefaultMemberInvitesCount;
+    type MemberReputationRules = ();
+    type MaxRecoveryGuardians = ();
+    type RecoveryDelay = ();
+    type RecoveryDeposit = ();
+    type HandleExpiryPeriod = ();
+    type HandleExpiryGracePeriod = ();
+    type MaxUplineReferralLevels = ();
 }
 
 parameter_types! {
@@ -180,6 +200,12 @@ parameter_types! {
     pub const ThreadDeposit: u64 = 100;
     pub const PostDeposit: u64 = 10;
     pub const MaxStickiedThreads: u32 = 10;
+    pub const MaxPollAlternatives: u32 = 5;
+    pub const MaxReactionsPerPost: u32 = 2;
+    pub const ReportDeposit: u64 = 20;
+    pub const ReportReward: u64 = 5;
+    pub const MaxReportsPerPeriod: u32 = 2;
+    pub const ReportRateLimitPeriod: u64 = 10;
     pub const ForumModuleId: PalletId = PalletId(*b"m0:forum"); // module : forum
 }
 
@@ -196,6 +222,7 @@ impl Config for Runtime {
     type CategoryId = u64;
     type ThreadId = u64;
     type PostId = u64;
+    type TokenId = u64;
     type MaxCategoryDepth = MaxCategoryDepth;
     type PostLifeTime = PostLifeTime;
 
@@ -204,7 +231,17 @@ impl Config for Runtime {
     type MemberOriginValidator = ();
     type ThreadDeposit = ThreadDeposit;
     type PostDeposit = PostDeposit;
+    type ReportDeposit = ReportDeposit;
+    type ReportReward = ReportReward;
+    type MaxReportsPerPeriod = MaxReportsPerPeriod;
+    type ReportRateLimitPeriod = ReportRateLimitPeriod;
     type MaxStickiedThreads = MaxStickiedThreads;
+    type MaxPollAlternatives = MaxPollAlternatives;
+    type MaxReactionsPerPost = MaxReactionsPerPost;
+    type StakingAccountValidator = StakingAccountValidatorMock;
+    type StakingHandler = staking_handler::StakingManager<Self, ForumPollLockId>;
+    type MembershipInfoProvider = MembershipInfoProviderMock;
+    type MemberReputationHandler = ();
 
     type ModuleId = ForumModuleId;
 
@@ -212,9 +249,36 @@ impl Config for Runtime {
         Self::Hashing::hash(text)
     }
 
+    fn is_verified_member(member_id: &u128) -> bool {
+        VERIFIED_MEMBERS.with(|members| members.borrow().contains(member_id))
+    }
+
+    fn membership_created_at(_member_id: &u128) -> u64 {
+        0
+    }
+
+    fn is_creator_token_holder(token_id: u64, member_id: &u128) -> bool {
+        CREATOR_TOKEN_HOLDERS.with(|holders| holders.borrow().contains(&(token_id, *member_id)))
+    }
+
+    fn is_working_group_worker(
+        working_group: common::working_group::WorkingGroup,
+        member_id: &u128,
+    ) -> bool {
+        working_group == common::working_group::WorkingGroup::Forum && Wg::worker_exists(member_id)
+    }
+
     type WeightInfo = ();
 }
 
+pub fn set_verified_member(member_id: u128) {
+    VERIFIED_MEMBERS.with(|members| members.borrow_mut().push(member_id));
+}
+
+pub fn set_creator_token_holder(token_id: u64, member_id: u128) {
+    CREATOR_TOKEN_HOLDERS.with(|holders| holders.borrow_mut().push((token_id, member_id)));
+}
+
 impl common::membership::MemberOriginValidator<Origin, u128, u128> for () {
     fn ensure_member_controller_account_origin(
         origin: Origin,
@@ -248,6 +312,22 @@ impl common::membership::MemberOriginValidator<Origin, u128, u128> for () {
     }
 }
 
+pub struct StakingAccountValidatorMock;
+
+impl common::membership::StakingAccountValidator<Runtime> for StakingAccountValidatorMock {
+    fn is_member_staking_account(member_id: &u128, account_id: &u128) -> bool {
+        *member_id == *account_id
+    }
+}
+
+pub struct MembershipInfoProviderMock;
+
+impl common::membership::MembershipInfoProvider<Runtime> for MembershipInfoProviderMock {
+    fn controller_account_id(member_id: u128) -> Result<u128, DispatchError> {
+        Ok(member_id)
+    }
+}
+
 impl common::working_group::WorkingGroupAuthenticator<Runtime> for Wg {
     fn ensure_worker_origin(
         _origin: <Runtime as frame_system::Config>::Origin,
@@ -399,6 +479,15 @@ pub fn good_post_new_text() -> Vec<u8> {
 }
 
 /// Get a good moderation rationale
+pub fn good_poll_input(voting_mode: PollVotingMode) -> PollInputOf<Runtime> {
+    PollInput {
+        description: b"Poll description".to_vec(),
+        end_block: 10,
+        voting_mode,
+        poll_alternatives: vec![b"Yes".to_vec(), b"No".to_vec()],
+    }
+}
+
 pub fn good_moderation_rationale() -> Vec<u8> {
     b"Moderation rationale".to_vec()
 }
@@ -445,6 +534,29 @@ pub fn create_thread_mock(
     title: Vec<u8>,
     text: Vec<u8>,
     result: DispatchResult,
+) -> <Runtime as Config>::ThreadId {
+    create_thread_with_poll_mock(
+        origin,
+        account_id,
+        forum_user_id,
+        category_id,
+        title,
+        text,
+        None,
+        result,
+    )
+}
+
+/// Create thread with poll mock
+pub fn create_thread_with_poll_mock(
+    origin: OriginType,
+    account_id: <Runtime as frame_system::Config>::AccountId,
+    forum_user_id: ForumUserId<Runtime>,
+    category_id: <Runtime as Config>::CategoryId,
+    title: Vec<u8>,
+    text: Vec<u8>,
+    poll_input: Option<PollInputOf<Runtime>>,
+    result: DispatchResult,
 ) -> <Runtime as Config>::ThreadId {
     let thread_id = TestForumModule::next_thread_id();
     let initial_balance = balances::Pallet::<Runtime>::free_balance(&account_id);
@@ -456,6 +568,7 @@ pub fn create_thread_mock(
         category_id,
         title.clone(),
         text.clone(),
+        poll_input.clone(),
     );
     assert_eq!(actual_result, result);
     if result.is_ok() {
@@ -469,6 +582,7 @@ pub fn create_thread_mock(
                 forum_user_id,
                 title,
                 text,
+                poll_input,
             ))
         );
 
@@ -484,6 +598,102 @@ pub fn create_thread_mock(
     thread_id
 }
 
+/// Create vote on poll mock
+pub fn vote_on_poll_mock(
+    origin: OriginType,
+    forum_user_id: ForumUserId<Runtime>,
+    category_id: <Runtime as Config>::CategoryId,
+    thread_id: <Runtime as Config>::ThreadId,
+    index: u32,
+    stake_parameters: Option<PollVoteStakeParametersOf<Runtime>>,
+    vote_weight: BalanceOf<Runtime>,
+    result: DispatchResult,
+) {
+    let vote_count = |thread: ThreadOf<Runtime>| {
+        thread
+            .poll
+            .and_then(|poll| poll.poll_alternatives.get(index as usize).cloned())
+            .map(|alternative| alternative.vote_count)
+            .unwrap_or_default()
+    };
+    let initial_vote_count = vote_count(TestForumModule::thread_by_id(category_id, thread_id));
+
+    assert_eq!(
+        TestForumModule::vote_on_poll(
+            mock_origin(origin),
+            forum_user_id,
+            category_id,
+            thread_id,
+            index,
+            stake_parameters,
+        ),
+        result
+    );
+    if result.is_ok() {
+        assert_eq!(
+            vote_count(TestForumModule::thread_by_id(category_id, thread_id)),
+            initial_vote_count + vote_weight
+        );
+        assert!(PollVotes::<Runtime>::contains_key(thread_id, forum_user_id));
+        assert_eq!(
+            System::events().last().unwrap().event,
+            Event::TestForumModule(RawEvent::VoteOnPoll(
+                thread_id,
+                index,
+                forum_user_id,
+                category_id,
+                vote_weight
+            ))
+        );
+    } else {
+        assert_eq!(
+            vote_count(TestForumModule::thread_by_id(category_id, thread_id)),
+            initial_vote_count
+        );
+    }
+}
+
+/// Create release poll vote stake mock
+pub fn release_poll_vote_stake_mock(
+    origin: OriginType,
+    forum_user_id: ForumUserId<Runtime>,
+    staking_account_id: <Runtime as frame_system::Config>::AccountId,
+    result: DispatchResult,
+) {
+    let poll_vote_lock = TestForumModule::poll_vote_lock_by_account(&staking_account_id);
+
+    assert_eq!(
+        TestForumModule::release_poll_vote_stake(
+            mock_origin(origin),
+            forum_user_id,
+            staking_account_id,
+        ),
+        result
+    );
+    if result.is_ok() {
+        assert!(!PollVoteLockByAccount::<Runtime>::contains_key(
+            &staking_account_id
+        ));
+        assert_eq!(
+            <Runtime as Config>::StakingHandler::current_stake(&staking_account_id),
+            0
+        );
+        assert_eq!(
+            System::events().last().unwrap().event,
+            Event::TestForumModule(RawEvent::PollVoteStakeReleased(
+                forum_user_id,
+                staking_account_id,
+                poll_vote_lock.unwrap_or_default().amount
+            ))
+        );
+    } else {
+        assert_eq!(
+            TestForumModule::poll_vote_lock_by_account(&staking_account_id),
+            poll_vote_lock
+        );
+    }
+}
+
 /// Create edit thread metadata mock
 pub fn edit_thread_metadata_mock(
     origin: OriginType,
@@ -843,6 +1053,228 @@ pub fn update_category_archival_status_mock(
     }
 }
 
+/// Create update category posting requirements mock
+pub fn update_category_posting_requirements_mock(
+    origin: OriginType,
+    actor: PrivilegedActor<Runtime>,
+    category_id: <Runtime as Config>::CategoryId,
+    requirements: PostingRequirementsOf<Runtime>,
+    result: DispatchResult,
+) {
+    assert_eq!(
+        TestForumModule::update_category_posting_requirements(
+            mock_origin(origin),
+            actor.clone(),
+            category_id,
+            requirements.clone()
+        ),
+        result
+    );
+    if result.is_ok() {
+        assert_eq!(
+            TestForumModule::category_posting_requirements(category_id),
+            requirements
+        );
+        assert_eq!(
+            System::events().last().unwrap().event,
+            Event::TestForumModule(RawEvent::CategoryPostingRequirementsUpdated(
+                category_id,
+                requirements,
+                actor
+            ))
+        );
+    }
+}
+
+/// Create report post mock
+pub fn report_post_mock(
+    origin: OriginType,
+    account_id: <Runtime as frame_system::Config>::AccountId,
+    forum_user_id: ForumUserId<Runtime>,
+    category_id: <Runtime as Config>::CategoryId,
+    thread_id: <Runtime as Config>::ThreadId,
+    post_id: <Runtime as Config>::PostId,
+    reason_code: ReportReasonCode,
+    result: DispatchResult,
+) -> ReportId {
+    let report_id = TestForumModule::next_report_id();
+    let initial_balance = balances::Pallet::<Runtime>::free_balance(account_id);
+    assert_eq!(
+        TestForumModule::report_post(
+            mock_origin(origin),
+            forum_user_id,
+            category_id,
+            thread_id,
+            post_id,
+            reason_code,
+        ),
+        result
+    );
+    if result.is_ok() {
+        assert_eq!(TestForumModule::next_report_id(), report_id + 1);
+        assert_eq!(
+            TestForumModule::post_report_by_id(category_id, report_id),
+            Some(PostReport {
+                thread_id,
+                post_id,
+                reason_code,
+                reporter_id: forum_user_id,
+                reporter_account: account_id,
+                deposit: RepayableBloatBond::new(<Runtime as Config>::ReportDeposit::get(), None),
+            })
+        );
+        assert_eq!(
+            balances::Pallet::<Runtime>::free_balance(account_id),
+            initial_balance - <Runtime as Config>::ReportDeposit::get()
+        );
+        assert_eq!(
+            System::events().last().unwrap().event,
+            Event::TestForumModule(RawEvent::PostReported(
+                report_id,
+                forum_user_id,
+                category_id,
+                thread_id,
+                post_id,
+                reason_code
+            ))
+        );
+    } else {
+        assert_eq!(TestForumModule::next_report_id(), report_id);
+    }
+    report_id
+}
+
+/// Create resolve post report mock
+pub fn resolve_post_report_mock(
+    origin: OriginType,
+    actor: PrivilegedActor<Runtime>,
+    category_id: <Runtime as Config>::CategoryId,
+    report_id: ReportId,
+    resolution: ReportResolution,
+    expected_reward: BalanceOf<Runtime>,
+    result: DispatchResult,
+) {
+    assert_eq!(
+        TestForumModule::resolve_post_report(
+            mock_origin(origin),
+            actor.clone(),
+            category_id,
+            report_id,
+            resolution,
+            good_moderation_rationale(),
+        ),
+        result
+    );
+    if result.is_ok() {
+        assert!(!PostReportById::<Runtime>::contains_key(
+            category_id,
+            report_id
+        ));
+        assert_eq!(
+            System::events().last().unwrap().event,
+            Event::TestForumModule(RawEvent::PostReportResolved(
+                report_id,
+                category_id,
+                resolution,
+                actor,
+                expected_reward
+            ))
+        );
+    }
+}
+
+/// Create react post mock
+pub fn react_post_mock(
+    origin: OriginType,
+    forum_user_id: ForumUserId<Runtime>,
+    category_id: <Runtime as Config>::CategoryId,
+    thread_id: <Runtime as Config>::ThreadId,
+    post_id: <Runtime as Config>::PostId,
+    reaction: Option<PostReaction>,
+    result: DispatchResult,
+) {
+    let counters = TestForumModule::post_reaction_counters_by_post_id(post_id);
+    assert_eq!(
+        TestForumModule::react_post(
+            mock_origin(origin),
+            forum_user_id,
+            category_id,
+            thread_id,
+            post_id,
+            reaction,
+        ),
+        result
+    );
+    if result.is_ok() {
+        assert_eq!(
+            TestForumModule::post_reaction_by_forum_user(post_id, forum_user_id),
+            reaction
+        );
+        assert_eq!(
+            System::events().last().unwrap().event,
+            Event::TestForumModule(RawEvent::PostReacted(
+                forum_user_id,
+                category_id,
+                thread_id,
+                post_id,
+                reaction
+            ))
+        );
+    } else {
+        assert_eq!(
+            TestForumModule::post_reaction_counters_by_post_id(post_id),
+            counters
+        );
+    }
+}
+
+/// Create tip post author mock
+pub fn tip_post_author_mock(
+    origin: OriginType,
+    forum_user_id: ForumUserId<Runtime>,
+    category_id: <Runtime as Config>::CategoryId,
+    thread_id: <Runtime as Config>::ThreadId,
+    post_id: <Runtime as Config>::PostId,
+    amount: BalanceOf<Runtime>,
+    result: DispatchResult,
+) {
+    let author_id = TestForumModule::post_by_id(thread_id, post_id).author_id;
+    let initial_author_balance = balances::Pallet::<Runtime>::free_balance(author_id);
+    assert_eq!(
+        TestForumModule::tip_post_author(
+            mock_origin(origin),
+            forum_user_id,
+            category_id,
+            thread_id,
+            post_id,
+            amount,
+        ),
+        result
+    );
+    if result.is_ok() {
+        assert_eq!(
+            balances::Pallet::<Runtime>::free_balance(author_id),
+            initial_author_balance + amount
+        );
+        assert_eq!(
+            System::events().last().unwrap().event,
+            Event::TestForumModule(RawEvent::PostAuthorTipped(
+                forum_user_id,
+                category_id,
+                thread_id,
+                post_id,
+                author_id,
+                amount
+            ))
+        );
+    } else {
+        assert_eq!(
+            balances::Pallet::<Runtime>::free_balance(author_id),
+            initial_author_balance
+        );
+    }
+}
+
 /// Create update category title mock
 pub fn update_category_title_mock(
     origin: OriginType,
@@ -872,6 +872,303 @@ fn delete_category_root_by_lead() {
     });
 }
 
+/*
+ ** update_category_posting_requirements
+ */
+
+#[test]
+// test posting requirements are set by the lead and can be overridden by category moderators
+fn update_category_posting_requirements_origin() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    with_test_externalities(|| {
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        let requirements = PostingRequirements {
+            verified_members_only: true,
+            ..Default::default()
+        };
+
+        update_category_posting_requirements_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            PrivilegedActor::Lead,
+            category_id,
+            requirements.clone(),
+            Err(Error::<Runtime>::OriginNotForumLead.into()),
+        );
+        update_category_posting_requirements_mock(
+            origin.clone(),
+            PrivilegedActor::Lead,
+            category_id,
+            requirements,
+            Ok(()),
+        );
+
+        // unprivileged moderator will fail to override requirements
+        update_category_posting_requirements_mock(
+            FORUM_MODERATOR_ORIGIN,
+            PrivilegedActor::Moderator(FORUM_MODERATOR_ORIGIN_ID),
+            category_id,
+            PostingRequirements::default(),
+            Err(Error::<Runtime>::ModeratorCantUpdateCategory.into()),
+        );
+
+        update_category_membership_of_moderator_mock(
+            origin.clone(),
+            FORUM_MODERATOR_ORIGIN_ID,
+            category_id,
+            true,
+            Ok(()),
+        );
+
+        // moderator associated with category will succeed
+        update_category_posting_requirements_mock(
+            FORUM_MODERATOR_ORIGIN,
+            PrivilegedActor::Moderator(FORUM_MODERATOR_ORIGIN_ID),
+            category_id,
+            PostingRequirements::default(),
+            Ok(()),
+        );
+    });
+}
+
+#[test]
+// test thread and post creation is restricted by the category posting requirements
+fn posting_requirements_restrict_posting() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let forum_user = NOT_FORUM_LEAD_ORIGIN_ID;
+    let forum_user_origin = NOT_FORUM_LEAD_ORIGIN;
+    let token_id = 1;
+    with_test_externalities(|| {
+        balances::Pallet::<Runtime>::make_free_balance_be(&forum_lead, 10_000_000);
+        balances::Pallet::<Runtime>::make_free_balance_be(&forum_user, 10_000_000);
+
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        let thread_id = create_thread_mock(
+            forum_user_origin.clone(),
+            forum_user,
+            forum_user,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            Ok(()),
+        );
+
+        let test_cases = [
+            (
+                PostingRequirements {
+                    verified_members_only: true,
+                    ..Default::default()
+                },
+                Error::<Runtime>::PostingRequiresVerifiedMembership,
+            ),
+            (
+                PostingRequirements {
+                    min_membership_age: Some(5),
+                    ..Default::default()
+                },
+                Error::<Runtime>::PostingRequiresMinimumMembershipAge,
+            ),
+            (
+                PostingRequirements {
+                    creator_token: Some(token_id),
+                    ..Default::default()
+                },
+                Error::<Runtime>::PostingRequiresCreatorTokenHolding,
+            ),
+            (
+                PostingRequirements {
+                    working_group: Some(WorkingGroup::Forum),
+                    ..Default::default()
+                },
+                Error::<Runtime>::PostingRequiresWorkingGroupWorker,
+            ),
+        ];
+        for (requirements, error) in test_cases {
+            let result: DispatchResult = Err(error.into());
+            update_category_posting_requirements_mock(
+                origin.clone(),
+                PrivilegedActor::Lead,
+                category_id,
+                requirements,
+                Ok(()),
+            );
+            create_thread_mock(
+                forum_user_origin.clone(),
+                forum_user,
+                forum_user,
+                category_id,
+                good_thread_metadata(),
+                good_thread_text(),
+                result,
+            );
+            create_post_mock(
+                forum_user_origin.clone(),
+                forum_user,
+                forum_user,
+                category_id,
+                thread_id,
+                good_post_text(),
+                true,
+                result,
+            );
+        }
+
+        // the forum lead is a worker of the forum working group
+        create_post_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            thread_id,
+            good_post_text(),
+            true,
+            Ok(()),
+        );
+
+        set_verified_member(forum_user);
+        set_creator_token_holder(token_id, forum_user);
+        run_to_block(5);
+
+        update_category_posting_requirements_mock(
+            origin.clone(),
+            PrivilegedActor::Lead,
+            category_id,
+            PostingRequirements {
+                verified_members_only: true,
+                min_membership_age: Some(5),
+                creator_token: Some(token_id),
+                working_group: None,
+            },
+            Ok(()),
+        );
+        create_thread_mock(
+            forum_user_origin.clone(),
+            forum_user,
+            forum_user,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            Ok(()),
+        );
+        create_post_mock(
+            forum_user_origin,
+            forum_user,
+            forum_user,
+            category_id,
+            thread_id,
+            good_post_text(),
+            true,
+            Ok(()),
+        );
+    });
+}
+
+#[test]
+// test thread and post creation in a subcategory is restricted by the ancestor posting requirements
+fn posting_requirements_of_ancestor_categories_restrict_posting() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let forum_user = NOT_FORUM_LEAD_ORIGIN_ID;
+    let forum_user_origin = NOT_FORUM_LEAD_ORIGIN;
+    with_test_externalities(|| {
+        balances::Pallet::<Runtime>::make_free_balance_be(&forum_lead, 10_000_000);
+        balances::Pallet::<Runtime>::make_free_balance_be(&forum_user, 10_000_000);
+
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        let subcategory_id = create_category_mock(
+            origin.clone(),
+            Some(category_id),
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        let thread_id = create_thread_mock(
+            forum_user_origin.clone(),
+            forum_user,
+            forum_user,
+            subcategory_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            Ok(()),
+        );
+
+        // subcategory has no requirements of its own
+        update_category_posting_requirements_mock(
+            origin.clone(),
+            PrivilegedActor::Lead,
+            category_id,
+            PostingRequirements {
+                verified_members_only: true,
+                ..Default::default()
+            },
+            Ok(()),
+        );
+
+        let result: DispatchResult =
+            Err(Error::<Runtime>::PostingRequiresVerifiedMembership.into());
+        create_thread_mock(
+            forum_user_origin.clone(),
+            forum_user,
+            forum_user,
+            subcategory_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            result,
+        );
+        create_post_mock(
+            forum_user_origin.clone(),
+            forum_user,
+            forum_user,
+            subcategory_id,
+            thread_id,
+            good_post_text(),
+            true,
+            result,
+        );
+
+        set_verified_member(forum_user);
+
+        create_thread_mock(
+            forum_user_origin.clone(),
+            forum_user,
+            forum_user,
+            subcategory_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            Ok(()),
+        );
+        create_post_mock(
+            forum_user_origin,
+            forum_user,
+            forum_user,
+            subcategory_id,
+            thread_id,
+            good_post_text(),
+            true,
+            Ok(()),
+        );
+    });
+}
+
 /*
  ** create_thread
  */
@@ -1494,28 +1791,16 @@ fn create_thread_fails_on_non_existing_category() {
 }
 
 /*
- ** update_category
+ ** vote_on_poll
  */
 
 #[test]
-// test if thread creator can delete thread
-fn delete_thread() {
+// test thread can be created with a valid poll only
+fn create_thread_with_poll() {
     let forum_lead = FORUM_LEAD_ORIGIN_ID;
     let origin = OriginType::Signed(forum_lead);
-    let initial_balance = 10_000_000;
     with_test_externalities(|| {
-        balances::Pallet::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
-        balances::Pallet::<Runtime>::make_free_balance_be(
-            &NOT_FORUM_LEAD_ORIGIN_ID,
-            initial_balance,
-        );
-
-        let mut current_balance = initial_balance;
-
-        assert_eq!(
-            balances::Pallet::<Runtime>::free_balance(&forum_lead),
-            current_balance
-        );
+        balances::Pallet::<Runtime>::make_free_balance_be(&forum_lead, 10_000_000);
 
         let category_id = create_category_mock(
             origin.clone(),
@@ -1525,309 +1810,436 @@ fn delete_thread() {
             Ok(()),
         );
 
-        let thread_id = create_thread_mock(
-            NOT_FORUM_LEAD_ORIGIN.clone(),
-            NOT_FORUM_LEAD_ORIGIN_ID,
-            NOT_FORUM_LEAD_ORIGIN_ID,
+        let too_short_poll = PollInput {
+            poll_alternatives: vec![b"Yes".to_vec()],
+            ..good_poll_input(PollVotingMode::OneVotePerMember)
+        };
+        let too_long_poll = PollInput {
+            poll_alternatives: vec![b"Yes".to_vec(); MaxPollAlternatives::get() as usize + 1],
+            ..good_poll_input(PollVotingMode::OneVotePerMember)
+        };
+        let closed_poll = PollInput {
+            end_block: 1,
+            ..good_poll_input(PollVotingMode::OneVotePerMember)
+        };
+        let test_cases = [
+            (too_short_poll, Error::<Runtime>::PollAlternativesTooShort),
+            (too_long_poll, Error::<Runtime>::MaxPollAlternativesExceeded),
+            (closed_poll, Error::<Runtime>::InvalidPollEndBlock),
+        ];
+        for (poll_input, error) in test_cases {
+            create_thread_with_poll_mock(
+                origin.clone(),
+                forum_lead,
+                forum_lead,
+                category_id,
+                good_thread_metadata(),
+                good_thread_text(),
+                Some(poll_input),
+                Err(error.into()),
+            );
+        }
+
+        let poll_input = good_poll_input(PollVotingMode::OneVotePerMember);
+        let thread_id = create_thread_with_poll_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
             category_id,
             good_thread_metadata(),
             good_thread_text(),
+            Some(poll_input.clone()),
             Ok(()),
         );
 
-        current_balance -= <Runtime as Config>::ThreadDeposit::get();
-        current_balance -= <Runtime as Config>::PostDeposit::get();
-
+        let poll = TestForumModule::thread_by_id(category_id, thread_id)
+            .poll
+            .unwrap();
         assert_eq!(
-            balances::Pallet::<Runtime>::free_balance(&NOT_FORUM_LEAD_ORIGIN_ID),
-            current_balance
-        );
-
-        // Delete original post first to allow thread deletion
-        delete_post_mock(
-            &NOT_FORUM_LEAD_ORIGIN_ID,
-            NOT_FORUM_LEAD_ORIGIN_ID,
-            category_id,
-            thread_id,
-            TestForumModule::next_post_id() - 1,
-            Ok(()),
-            false,
-        );
+            poll.description_hash,
+            <Runtime as Config>::calculate_hash(&poll_input.description)
+        );
+        assert_eq!(poll.end_block, poll_input.end_block);
+        assert_eq!(poll.voting_mode, PollVotingMode::OneVotePerMember);
+        assert_eq!(poll.poll_alternatives.len(), 2);
+        assert!(poll
+            .poll_alternatives
+            .iter()
+            .all(|alternative| alternative.vote_count == 0));
+    });
+}
 
-        current_balance += <Runtime as Config>::PostDeposit::get();
+#[test]
+// test forum users can vote once on an open poll
+fn vote_on_poll_one_vote_per_member() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    with_test_externalities(|| {
+        balances::Pallet::<Runtime>::make_free_balance_be(&forum_lead, 10_000_000);
 
-        assert_eq!(
-            balances::Pallet::<Runtime>::free_balance(&NOT_FORUM_LEAD_ORIGIN_ID),
-            current_balance
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
         );
-
-        update_category_membership_of_moderator_mock(
+        let thread_without_poll_id = create_thread_mock(
             origin.clone(),
-            FORUM_MODERATOR_ORIGIN_ID,
+            forum_lead,
+            forum_lead,
             category_id,
-            true,
+            good_thread_metadata(),
+            good_thread_text(),
             Ok(()),
         );
-
-        // check number of category's threads match before delete
-        assert_eq!(
-            <CategoryById<Runtime>>::get(category_id).num_direct_threads,
-            1
+        let thread_id = create_thread_with_poll_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            Some(good_poll_input(PollVotingMode::OneVotePerMember)),
+            Ok(()),
         );
 
-        // regular user will fail to delete the thread
-        delete_thread_mock(
-            &NOT_FORUM_MODERATOR_ORIGIN_ID,
-            NOT_FORUM_MODERATOR_ORIGIN_ID,
+        vote_on_poll_mock(
+            NOT_FORUM_MEMBER_ORIGIN,
+            NOT_FORUM_MEMBER_ORIGIN_ID,
             category_id,
             thread_id,
+            0,
+            None,
+            1,
             Err(Error::<Runtime>::ForumUserIdNotMatchAccount.into()),
         );
-
-        // moderator not associated with thread will fail to delete it
-        delete_thread_mock(
-            &FORUM_MODERATOR_2_ORIGIN_ID,
-            FORUM_MODERATOR_2_ORIGIN_ID,
+        vote_on_poll_mock(
+            origin.clone(),
+            forum_lead,
             category_id,
-            thread_id,
-            Err(Error::<Runtime>::ForumUserIdNotMatchAccount.into()),
+            thread_without_poll_id,
+            0,
+            None,
+            1,
+            Err(Error::<Runtime>::PollDoesNotExist.into()),
         );
-
-        // moderator will not delete thread
-        delete_thread_mock(
-            &FORUM_MODERATOR_ORIGIN_ID,
-            FORUM_MODERATOR_ORIGIN_ID,
+        vote_on_poll_mock(
+            origin.clone(),
+            forum_lead,
             category_id,
             thread_id,
-            Err(Error::<Runtime>::ForumUserIdNotMatchAccount.into()),
+            2,
+            None,
+            1,
+            Err(Error::<Runtime>::InvalidPollAlternativeIndex.into()),
         );
-
-        // forum lead will not delete thread
-        delete_thread_mock(
-            &forum_lead,
+        vote_on_poll_mock(
+            origin.clone(),
             forum_lead,
             category_id,
             thread_id,
-            Err(Error::<Runtime>::AccountDoesNotMatchThreadAuthor.into()),
+            0,
+            None,
+            1,
+            Ok(()),
         );
-
-        // another user will not delete thread
-        delete_thread_mock(
-            &NOT_FORUM_LEAD_2_ORIGIN_ID,
-            NOT_FORUM_LEAD_2_ORIGIN_ID,
+        vote_on_poll_mock(
+            origin.clone(),
+            forum_lead,
             category_id,
             thread_id,
-            Err(Error::<Runtime>::AccountDoesNotMatchThreadAuthor.into()),
+            1,
+            None,
+            1,
+            Err(Error::<Runtime>::AlreadyVotedOnPoll.into()),
         );
-
-        // thread creator will delete thread
-        delete_thread_mock(
-            &NOT_FORUM_LEAD_ORIGIN_ID,
+        vote_on_poll_mock(
+            NOT_FORUM_LEAD_ORIGIN,
             NOT_FORUM_LEAD_ORIGIN_ID,
             category_id,
             thread_id,
+            0,
+            None,
+            1,
             Ok(()),
         );
 
-        current_balance += <Runtime as Config>::ThreadDeposit::get();
-
-        assert_eq!(
-            balances::Pallet::<Runtime>::free_balance(&NOT_FORUM_LEAD_ORIGIN_ID),
-            current_balance
-        );
+        run_to_block(10);
 
-        // check category's thread count was decreased
-        assert_eq!(
-            <CategoryById<Runtime>>::get(category_id).num_direct_threads,
-            0
+        vote_on_poll_mock(
+            NOT_FORUM_LEAD_2_ORIGIN,
+            NOT_FORUM_LEAD_2_ORIGIN_ID,
+            category_id,
+            thread_id,
+            0,
+            None,
+            1,
+            Err(Error::<Runtime>::PollClosed.into()),
         );
     });
 }
 
 #[test]
-fn delete_thread_fails_with_outstanding_posts() {
-    let initial_balance = 10_000_000;
+// test stake weighted poll votes lock the voted stake until the poll ends
+fn vote_on_poll_stake_weighted() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let stake = 500;
+    let stake_parameters = |staking_account_id, stake| {
+        Some(PollVoteStakeParameters {
+            staking_account_id,
+            stake,
+        })
+    };
     with_test_externalities(|| {
-        balances::Pallet::<Runtime>::make_free_balance_be(
-            &NOT_FORUM_LEAD_ORIGIN_ID,
-            initial_balance,
-        );
+        balances::Pallet::<Runtime>::make_free_balance_be(&forum_lead, 10_000_000);
+        balances::Pallet::<Runtime>::make_free_balance_be(&NOT_FORUM_LEAD_ORIGIN_ID, 10_000_000);
 
         let category_id = create_category_mock(
-            FORUM_LEAD_ORIGIN.clone(),
+            origin.clone(),
             None,
             good_category_title(),
             good_category_description(),
             Ok(()),
         );
-
-        let thread_id = create_thread_mock(
-            NOT_FORUM_LEAD_ORIGIN.clone(),
-            NOT_FORUM_LEAD_ORIGIN_ID,
-            NOT_FORUM_LEAD_ORIGIN_ID,
+        let thread_id = create_thread_with_poll_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
             category_id,
             good_thread_metadata(),
             good_thread_text(),
+            Some(good_poll_input(PollVotingMode::StakeWeighted)),
             Ok(()),
         );
 
-        delete_thread_mock(
-            &NOT_FORUM_LEAD_ORIGIN_ID,
-            NOT_FORUM_LEAD_ORIGIN_ID,
+        vote_on_poll_mock(
+            origin.clone(),
+            forum_lead,
             category_id,
             thread_id,
-            Err(Error::<Runtime>::CannotDeleteThreadWithOutstandingPosts.into()),
+            0,
+            None,
+            stake,
+            Err(Error::<Runtime>::PollStakingAccountNotProvided.into()),
+        );
+        vote_on_poll_mock(
+            origin.clone(),
+            forum_lead,
+            category_id,
+            thread_id,
+            0,
+            stake_parameters(NOT_FORUM_LEAD_ORIGIN_ID, stake),
+            stake,
+            Err(Error::<Runtime>::InvalidPollStakingAccount.into()),
+        );
+        vote_on_poll_mock(
+            origin.clone(),
+            forum_lead,
+            category_id,
+            thread_id,
+            0,
+            stake_parameters(forum_lead, 0),
+            0,
+            Err(Error::<Runtime>::PollVoteStakeIsZero.into()),
+        );
+        vote_on_poll_mock(
+            origin.clone(),
+            forum_lead,
+            category_id,
+            thread_id,
+            0,
+            stake_parameters(forum_lead, 20_000_000),
+            20_000_000,
+            Err(Error::<Runtime>::InsufficientBalanceForPollVoteStake.into()),
+        );
+
+        // Unrelated locks on the staking account don't weight the vote
+        set_staking_candidate_lock(&forum_lead, 5_000);
+
+        vote_on_poll_mock(
+            origin.clone(),
+            forum_lead,
+            category_id,
+            thread_id,
+            0,
+            stake_parameters(forum_lead, stake),
+            stake,
+            Ok(()),
+        );
+        assert_eq!(
+            <Runtime as Config>::StakingHandler::current_stake(&forum_lead),
+            stake
+        );
+        assert_eq!(
+            TestForumModule::poll_vote_lock_by_account(&forum_lead),
+            Some(PollVoteLock {
+                amount: stake,
+                locked_until: good_poll_input(PollVotingMode::StakeWeighted).end_block,
+            })
         );
     });
 }
 
 #[test]
-// test if moderator can move thread between two categories he moderates
-fn move_thread_moderator_permissions() {
-    let moderators = [FORUM_MODERATOR_ORIGIN_ID, FORUM_MODERATOR_2_ORIGIN_ID];
-    let origins = [FORUM_MODERATOR_ORIGIN, FORUM_MODERATOR_2_ORIGIN];
-
+// test the poll vote stake can be released only after the poll ends
+fn release_poll_vote_stake() {
     let forum_lead = FORUM_LEAD_ORIGIN_ID;
     let origin = OriginType::Signed(forum_lead);
-    let initial_balance = 10_000_000;
+    let stake = 500;
     with_test_externalities(|| {
-        balances::Pallet::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+        balances::Pallet::<Runtime>::make_free_balance_be(&forum_lead, 10_000_000);
 
-        let category_id_1 = create_category_mock(
+        let category_id = create_category_mock(
             origin.clone(),
             None,
             good_category_title(),
             good_category_description(),
             Ok(()),
         );
-        let category_id_2 = create_category_mock(
+        let poll_input = good_poll_input(PollVotingMode::StakeWeighted);
+        let thread_id = create_thread_with_poll_mock(
             origin.clone(),
-            None,
-            good_category_title(),
-            good_category_description(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            Some(poll_input.clone()),
             Ok(()),
         );
-
-        // sanity check
-        assert_ne!(category_id_1, category_id_2);
-
-        let thread_id = create_thread_mock(
+        let another_thread_id = create_thread_with_poll_mock(
             origin.clone(),
             forum_lead,
             forum_lead,
-            category_id_1,
+            category_id,
             good_thread_metadata(),
             good_thread_text(),
+            Some(poll_input.clone()),
             Ok(()),
         );
 
-        // moderator not associated with any category will fail to move thread
-        move_thread_mock(
-            origins[0].clone(),
-            moderators[0],
-            category_id_1,
-            thread_id,
-            category_id_2,
-            Err(Error::<Runtime>::ModeratorModerateOriginCategory.into()),
+        release_poll_vote_stake_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            Err(Error::<Runtime>::PollVoteStakeNotFound.into()),
         );
 
-        // set incomplete permissions for first user (only category 1)
-        update_category_membership_of_moderator_mock(
+        vote_on_poll_mock(
             origin.clone(),
-            moderators[0],
-            category_id_1,
-            true,
+            forum_lead,
+            category_id,
+            thread_id,
+            0,
+            Some(PollVoteStakeParameters {
+                staking_account_id: forum_lead,
+                stake,
+            }),
+            stake,
             Ok(()),
         );
-        // set incomplete permissions for second user (only category 2)
-        update_category_membership_of_moderator_mock(
+        vote_on_poll_mock(
             origin.clone(),
-            moderators[1],
-            category_id_2,
-            true,
-            Ok(()),
-        );
-
-        // moderator associated only with the first category will fail to move thread
-        move_thread_mock(
-            origins[1].clone(),
-            moderators[1],
-            category_id_1,
-            thread_id,
-            category_id_2,
-            Err(Error::<Runtime>::ModeratorModerateOriginCategory.into()),
+            forum_lead,
+            category_id,
+            another_thread_id,
+            0,
+            Some(PollVoteStakeParameters {
+                staking_account_id: forum_lead,
+                stake,
+            }),
+            stake,
+            Err(Error::<Runtime>::PollVoteStakeAlreadyLocked.into()),
         );
 
-        // moderator associated only with the second category will fail to move thread
-        move_thread_mock(
-            origins[0].clone(),
-            moderators[0],
-            category_id_1,
-            thread_id,
-            category_id_2,
-            Err(Error::<Runtime>::ModeratorModerateDestinationCategory.into()),
+        release_poll_vote_stake_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            Err(Error::<Runtime>::PollVoteStakeStillLocked.into()),
         );
-
-        // give the rest of necessary permissions to the first moderator
-        update_category_membership_of_moderator_mock(
+        release_poll_vote_stake_mock(
             origin.clone(),
-            moderators[0],
-            category_id_2,
-            true,
-            Ok(()),
+            forum_lead,
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            Err(Error::<Runtime>::InvalidPollStakingAccount.into()),
         );
 
-        // check counters of threads in category
-        assert_eq!(
-            <CategoryById<Runtime>>::get(category_id_1).num_direct_threads,
-            1,
-        );
-        assert_eq!(
-            <CategoryById<Runtime>>::get(category_id_2).num_direct_threads,
-            0,
-        );
+        run_to_block(poll_input.end_block);
 
-        // moderator associated with both categories will succeed to move thread
-        move_thread_mock(
-            origins[0].clone(),
-            moderators[0],
-            category_id_1,
-            thread_id,
-            category_id_2,
+        release_poll_vote_stake_mock(origin.clone(), forum_lead, forum_lead, Ok(()));
+    });
+}
+
+#[test]
+// test poll votes are removed along with the thread
+fn delete_thread_removes_poll_votes() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    with_test_externalities(|| {
+        balances::Pallet::<Runtime>::make_free_balance_be(&forum_lead, 10_000_000);
+
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
             Ok(()),
         );
-
-        // check counters of threads in category
-        assert_eq!(
-            <CategoryById<Runtime>>::get(category_id_1).num_direct_threads,
-            0,
+        let thread_id = create_thread_with_poll_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            Some(good_poll_input(PollVotingMode::OneVotePerMember)),
+            Ok(()),
         );
-        assert_eq!(
-            <CategoryById<Runtime>>::get(category_id_2).num_direct_threads,
+        vote_on_poll_mock(
+            origin.clone(),
+            forum_lead,
+            category_id,
+            thread_id,
+            0,
+            None,
             1,
+            Ok(()),
         );
+
+        delete_thread_mock(&forum_lead, forum_lead, category_id, thread_id, Ok(()));
+
+        assert!(!PollVotes::<Runtime>::contains_key(thread_id, forum_lead));
     });
 }
 
-#[test]
-fn category_updated_successfully_on_thread_moving() {
-    let moderator = FORUM_MODERATOR_ORIGIN_ID;
-    let moderator_origin = FORUM_MODERATOR_ORIGIN;
+/*
+ ** update_category
+ */
 
+#[test]
+// test if thread creator can delete thread
+fn delete_thread() {
     let forum_lead = FORUM_LEAD_ORIGIN_ID;
     let origin = OriginType::Signed(forum_lead);
     let initial_balance = 10_000_000;
     with_test_externalities(|| {
         balances::Pallet::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+        balances::Pallet::<Runtime>::make_free_balance_be(
+            &NOT_FORUM_LEAD_ORIGIN_ID,
+            initial_balance,
+        );
 
-        let category_id_1 = create_category_mock(
-            origin.clone(),
-            None,
-            good_category_title(),
-            good_category_description(),
-            Ok(()),
+        let mut current_balance = initial_balance;
+
+        assert_eq!(
+            balances::Pallet::<Runtime>::free_balance(&forum_lead),
+            current_balance
         );
-        let category_id_2 = create_category_mock(
+
+        let category_id = create_category_mock(
             origin.clone(),
             None,
             good_category_title(),
@@ -1835,113 +2247,136 @@ fn category_updated_successfully_on_thread_moving() {
             Ok(()),
         );
 
-        // sanity check
-        assert_ne!(category_id_1, category_id_2);
-
         let thread_id = create_thread_mock(
-            origin.clone(),
-            forum_lead,
-            forum_lead,
-            category_id_1,
+            NOT_FORUM_LEAD_ORIGIN.clone(),
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            category_id,
             good_thread_metadata(),
             good_thread_text(),
             Ok(()),
         );
 
-        // set incomplete permissions for first user (only category 1)
-        update_category_membership_of_moderator_mock(
-            origin.clone(),
-            moderator,
-            category_id_1,
-            true,
+        current_balance -= <Runtime as Config>::ThreadDeposit::get();
+        current_balance -= <Runtime as Config>::PostDeposit::get();
+
+        assert_eq!(
+            balances::Pallet::<Runtime>::free_balance(&NOT_FORUM_LEAD_ORIGIN_ID),
+            current_balance
+        );
+
+        // Delete original post first to allow thread deletion
+        delete_post_mock(
+            &NOT_FORUM_LEAD_ORIGIN_ID,
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            category_id,
+            thread_id,
+            TestForumModule::next_post_id() - 1,
             Ok(()),
+            false,
+        );
+
+        current_balance += <Runtime as Config>::PostDeposit::get();
+
+        assert_eq!(
+            balances::Pallet::<Runtime>::free_balance(&NOT_FORUM_LEAD_ORIGIN_ID),
+            current_balance
         );
 
-        // give the rest of necessary permissions to the first moderator
         update_category_membership_of_moderator_mock(
             origin.clone(),
-            moderator,
-            category_id_2,
+            FORUM_MODERATOR_ORIGIN_ID,
+            category_id,
             true,
             Ok(()),
         );
 
-        // check counters of threads in category
+        // check number of category's threads match before delete
         assert_eq!(
-            <CategoryById<Runtime>>::get(category_id_1).num_direct_threads,
-            1,
+            <CategoryById<Runtime>>::get(category_id).num_direct_threads,
+            1
         );
-        assert_eq!(
-            <CategoryById<Runtime>>::get(category_id_2).num_direct_threads,
-            0,
+
+        // regular user will fail to delete the thread
+        delete_thread_mock(
+            &NOT_FORUM_MODERATOR_ORIGIN_ID,
+            NOT_FORUM_MODERATOR_ORIGIN_ID,
+            category_id,
+            thread_id,
+            Err(Error::<Runtime>::ForumUserIdNotMatchAccount.into()),
         );
 
-        // move in one direction
-        move_thread_mock(
-            moderator_origin.clone(),
-            moderator,
-            category_id_1,
+        // moderator not associated with thread will fail to delete it
+        delete_thread_mock(
+            &FORUM_MODERATOR_2_ORIGIN_ID,
+            FORUM_MODERATOR_2_ORIGIN_ID,
+            category_id,
             thread_id,
-            category_id_2,
-            Ok(()),
+            Err(Error::<Runtime>::ForumUserIdNotMatchAccount.into()),
         );
 
-        assert_eq!(
-            TestForumModule::thread_by_id(category_id_2, thread_id).category_id,
-            category_id_2
+        // moderator will not delete thread
+        delete_thread_mock(
+            &FORUM_MODERATOR_ORIGIN_ID,
+            FORUM_MODERATOR_ORIGIN_ID,
+            category_id,
+            thread_id,
+            Err(Error::<Runtime>::ForumUserIdNotMatchAccount.into()),
         );
 
-        // check counters of threads in category
-        assert_eq!(
-            <CategoryById<Runtime>>::get(category_id_1).num_direct_threads,
-            0,
+        // forum lead will not delete thread
+        delete_thread_mock(
+            &forum_lead,
+            forum_lead,
+            category_id,
+            thread_id,
+            Err(Error::<Runtime>::AccountDoesNotMatchThreadAuthor.into()),
         );
-        assert_eq!(
-            <CategoryById<Runtime>>::get(category_id_2).num_direct_threads,
-            1,
+
+        // another user will not delete thread
+        delete_thread_mock(
+            &NOT_FORUM_LEAD_2_ORIGIN_ID,
+            NOT_FORUM_LEAD_2_ORIGIN_ID,
+            category_id,
+            thread_id,
+            Err(Error::<Runtime>::AccountDoesNotMatchThreadAuthor.into()),
         );
 
-        // move in opposite direction
-        move_thread_mock(
-            moderator_origin.clone(),
-            moderator,
-            category_id_2,
+        // thread creator will delete thread
+        delete_thread_mock(
+            &NOT_FORUM_LEAD_ORIGIN_ID,
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            category_id,
             thread_id,
-            category_id_1,
             Ok(()),
         );
 
-        // check counters of threads in category
-        assert_eq!(
-            <CategoryById<Runtime>>::get(category_id_1).num_direct_threads,
-            1,
-        );
+        current_balance += <Runtime as Config>::ThreadDeposit::get();
+
         assert_eq!(
-            <CategoryById<Runtime>>::get(category_id_2).num_direct_threads,
-            0,
+            balances::Pallet::<Runtime>::free_balance(&NOT_FORUM_LEAD_ORIGIN_ID),
+            current_balance
         );
 
+        // check category's thread count was decreased
         assert_eq!(
-            TestForumModule::thread_by_id(category_id_1, thread_id).category_id,
-            category_id_1
+            <CategoryById<Runtime>>::get(category_id).num_direct_threads,
+            0
         );
     });
 }
 
 #[test]
-// test if error is thrown when origin and destination category is the same
-fn move_thread_invalid_move() {
-    let moderators = [FORUM_MODERATOR_ORIGIN_ID];
-    let origins = [FORUM_MODERATOR_ORIGIN];
-
-    let forum_lead = FORUM_LEAD_ORIGIN_ID;
-    let origin = OriginType::Signed(forum_lead);
+fn delete_thread_fails_with_outstanding_posts() {
     let initial_balance = 10_000_000;
     with_test_externalities(|| {
-        balances::Pallet::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+        balances::Pallet::<Runtime>::make_free_balance_be(
+            &NOT_FORUM_LEAD_ORIGIN_ID,
+            initial_balance,
+        );
 
         let category_id = create_category_mock(
-            origin.clone(),
+            FORUM_LEAD_ORIGIN.clone(),
             None,
             good_category_title(),
             good_category_description(),
@@ -1949,49 +2384,569 @@ fn move_thread_invalid_move() {
         );
 
         let thread_id = create_thread_mock(
-            origin.clone(),
-            forum_lead,
-            forum_lead,
+            NOT_FORUM_LEAD_ORIGIN.clone(),
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            NOT_FORUM_LEAD_ORIGIN_ID,
             category_id,
             good_thread_metadata(),
             good_thread_text(),
             Ok(()),
         );
 
-        // set permissions
-        update_category_membership_of_moderator_mock(
-            origin.clone(),
-            moderators[0],
-            category_id,
-            true,
-            Ok(()),
-        );
-
-        move_thread_mock(
-            origins[0].clone(),
-            moderators[0],
+        delete_thread_mock(
+            &NOT_FORUM_LEAD_ORIGIN_ID,
+            NOT_FORUM_LEAD_ORIGIN_ID,
             category_id,
             thread_id,
-            category_id,
-            Err(Error::<Runtime>::ThreadMoveInvalid.into()),
+            Err(Error::<Runtime>::CannotDeleteThreadWithOutstandingPosts.into()),
         );
     });
 }
 
-/*
- ** moderate_thread
- */
-
 #[test]
-// test if thread moderator registered as valid moderator
-fn moderate_thread_origin_ok() {
-    let forum_lead = FORUM_LEAD_ORIGIN_ID;
-    let origin = OriginType::Signed(forum_lead);
+// test if moderator can move thread between two categories he moderates
+fn move_thread_moderator_permissions() {
+    let moderators = [FORUM_MODERATOR_ORIGIN_ID, FORUM_MODERATOR_2_ORIGIN_ID];
+    let origins = [FORUM_MODERATOR_ORIGIN, FORUM_MODERATOR_2_ORIGIN];
+
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let initial_balance = 10_000_000;
+    with_test_externalities(|| {
+        balances::Pallet::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+
+        let category_id_1 = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        let category_id_2 = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+
+        // sanity check
+        assert_ne!(category_id_1, category_id_2);
+
+        let thread_id = create_thread_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id_1,
+            good_thread_metadata(),
+            good_thread_text(),
+            Ok(()),
+        );
+
+        // moderator not associated with any category will fail to move thread
+        move_thread_mock(
+            origins[0].clone(),
+            moderators[0],
+            category_id_1,
+            thread_id,
+            category_id_2,
+            Err(Error::<Runtime>::ModeratorModerateOriginCategory.into()),
+        );
+
+        // set incomplete permissions for first user (only category 1)
+        update_category_membership_of_moderator_mock(
+            origin.clone(),
+            moderators[0],
+            category_id_1,
+            true,
+            Ok(()),
+        );
+        // set incomplete permissions for second user (only category 2)
+        update_category_membership_of_moderator_mock(
+            origin.clone(),
+            moderators[1],
+            category_id_2,
+            true,
+            Ok(()),
+        );
+
+        // moderator associated only with the first category will fail to move thread
+        move_thread_mock(
+            origins[1].clone(),
+            moderators[1],
+            category_id_1,
+            thread_id,
+            category_id_2,
+            Err(Error::<Runtime>::ModeratorModerateOriginCategory.into()),
+        );
+
+        // moderator associated only with the second category will fail to move thread
+        move_thread_mock(
+            origins[0].clone(),
+            moderators[0],
+            category_id_1,
+            thread_id,
+            category_id_2,
+            Err(Error::<Runtime>::ModeratorModerateDestinationCategory.into()),
+        );
+
+        // give the rest of necessary permissions to the first moderator
+        update_category_membership_of_moderator_mock(
+            origin.clone(),
+            moderators[0],
+            category_id_2,
+            true,
+            Ok(()),
+        );
+
+        // check counters of threads in category
+        assert_eq!(
+            <CategoryById<Runtime>>::get(category_id_1).num_direct_threads,
+            1,
+        );
+        assert_eq!(
+            <CategoryById<Runtime>>::get(category_id_2).num_direct_threads,
+            0,
+        );
+
+        // moderator associated with both categories will succeed to move thread
+        move_thread_mock(
+            origins[0].clone(),
+            moderators[0],
+            category_id_1,
+            thread_id,
+            category_id_2,
+            Ok(()),
+        );
+
+        // check counters of threads in category
+        assert_eq!(
+            <CategoryById<Runtime>>::get(category_id_1).num_direct_threads,
+            0,
+        );
+        assert_eq!(
+            <CategoryById<Runtime>>::get(category_id_2).num_direct_threads,
+            1,
+        );
+    });
+}
+
+#[test]
+fn category_updated_successfully_on_thread_moving() {
+    let moderator = FORUM_MODERATOR_ORIGIN_ID;
+    let moderator_origin = FORUM_MODERATOR_ORIGIN;
+
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let initial_balance = 10_000_000;
+    with_test_externalities(|| {
+        balances::Pallet::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+
+        let category_id_1 = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        let category_id_2 = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+
+        // sanity check
+        assert_ne!(category_id_1, category_id_2);
+
+        let thread_id = create_thread_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id_1,
+            good_thread_metadata(),
+            good_thread_text(),
+            Ok(()),
+        );
+
+        // set incomplete permissions for first user (only category 1)
+        update_category_membership_of_moderator_mock(
+            origin.clone(),
+            moderator,
+            category_id_1,
+            true,
+            Ok(()),
+        );
+
+        // give the rest of necessary permissions to the first moderator
+        update_category_membership_of_moderator_mock(
+            origin.clone(),
+            moderator,
+            category_id_2,
+            true,
+            Ok(()),
+        );
+
+        // check counters of threads in category
+        assert_eq!(
+            <CategoryById<Runtime>>::get(category_id_1).num_direct_threads,
+            1,
+        );
+        assert_eq!(
+            <CategoryById<Runtime>>::get(category_id_2).num_direct_threads,
+            0,
+        );
+
+        // move in one direction
+        move_thread_mock(
+            moderator_origin.clone(),
+            moderator,
+            category_id_1,
+            thread_id,
+            category_id_2,
+            Ok(()),
+        );
+
+        assert_eq!(
+            TestForumModule::thread_by_id(category_id_2, thread_id).category_id,
+            category_id_2
+        );
+
+        // check counters of threads in category
+        assert_eq!(
+            <CategoryById<Runtime>>::get(category_id_1).num_direct_threads,
+            0,
+        );
+        assert_eq!(
+            <CategoryById<Runtime>>::get(category_id_2).num_direct_threads,
+            1,
+        );
+
+        // move in opposite direction
+        move_thread_mock(
+            moderator_origin.clone(),
+            moderator,
+            category_id_2,
+            thread_id,
+            category_id_1,
+            Ok(()),
+        );
+
+        // check counters of threads in category
+        assert_eq!(
+            <CategoryById<Runtime>>::get(category_id_1).num_direct_threads,
+            1,
+        );
+        assert_eq!(
+            <CategoryById<Runtime>>::get(category_id_2).num_direct_threads,
+            0,
+        );
+
+        assert_eq!(
+            TestForumModule::thread_by_id(category_id_1, thread_id).category_id,
+            category_id_1
+        );
+    });
+}
+
+#[test]
+// test if error is thrown when origin and destination category is the same
+fn move_thread_invalid_move() {
+    let moderators = [FORUM_MODERATOR_ORIGIN_ID];
+    let origins = [FORUM_MODERATOR_ORIGIN];
+
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let initial_balance = 10_000_000;
+    with_test_externalities(|| {
+        balances::Pallet::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+
+        let thread_id = create_thread_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            Ok(()),
+        );
+
+        // set permissions
+        update_category_membership_of_moderator_mock(
+            origin.clone(),
+            moderators[0],
+            category_id,
+            true,
+            Ok(()),
+        );
+
+        move_thread_mock(
+            origins[0].clone(),
+            moderators[0],
+            category_id,
+            thread_id,
+            category_id,
+            Err(Error::<Runtime>::ThreadMoveInvalid.into()),
+        );
+    });
+}
+
+/*
+ ** moderate_thread
+ */
+
+#[test]
+// test if thread moderator registered as valid moderator
+fn moderate_thread_origin_ok() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let initial_balance = 10_000_000;
+    with_test_externalities(|| {
+        balances::Pallet::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+
+        let moderator_id = forum_lead;
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        update_category_membership_of_moderator_mock(
+            origin.clone(),
+            moderator_id,
+            category_id,
+            true,
+            Ok(()),
+        );
+        let thread_id = create_thread_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            Ok(()),
+        );
+        // Delete original post first to allow thread deletion
+        delete_post_mock(
+            &forum_lead,
+            forum_lead,
+            category_id,
+            thread_id,
+            TestForumModule::next_post_id() - 1,
+            Ok(()),
+            false,
+        );
+        moderate_thread_mock(
+            origin,
+            moderator_id,
+            category_id,
+            thread_id,
+            good_moderation_rationale(),
+            Ok(()),
+        );
+    });
+}
+
+#[test]
+fn moderate_thread_fails_with_outstanding_posts() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let initial_balance = 10_000_000;
+    with_test_externalities(|| {
+        balances::Pallet::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+
+        let moderator_id = forum_lead;
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        update_category_membership_of_moderator_mock(
+            origin.clone(),
+            moderator_id,
+            category_id,
+            true,
+            Ok(()),
+        );
+        let thread_id = create_thread_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            Ok(()),
+        );
+        moderate_thread_mock(
+            origin,
+            moderator_id,
+            category_id,
+            thread_id,
+            good_moderation_rationale(),
+            Err(Error::<Runtime>::CannotDeleteThreadWithOutstandingPosts.into()),
+        );
+    });
+}
+
+/*
+ ** add_post
+ */
+
+#[test]
+// test if post origin registered as forum user
+fn add_post_origin() {
+    let origins = vec![FORUM_LEAD_ORIGIN, NOT_FORUM_MEMBER_ORIGIN];
+    let accounts = vec![FORUM_LEAD_ORIGIN_ID, NOT_FORUM_MEMBER_ORIGIN_ID];
+    let results = vec![
+        Ok(()),
+        Err(Error::<Runtime>::ForumUserIdNotMatchAccount.into()),
+    ];
+    for index in 0..origins.len() {
+        let forum_lead = FORUM_LEAD_ORIGIN_ID;
+        let origin = OriginType::Signed(forum_lead);
+        let initial_balance = 10_000_000;
+        with_test_externalities(|| {
+            balances::Pallet::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+            balances::Pallet::<Runtime>::make_free_balance_be(
+                &NOT_FORUM_MEMBER_ORIGIN_ID,
+                initial_balance,
+            );
+            let category_id = create_category_mock(
+                origin.clone(),
+                None,
+                good_category_title(),
+                good_category_description(),
+                Ok(()),
+            );
+
+            let thread_id = create_thread_mock(
+                origin.clone(),
+                forum_lead,
+                forum_lead,
+                category_id,
+                good_thread_metadata(),
+                good_thread_text(),
+                Ok(()),
+            );
+            create_post_mock(
+                origins[index].clone(),
+                accounts[index],
+                forum_lead,
+                category_id,
+                thread_id,
+                good_post_text(),
+                true,
+                results[index],
+            );
+        });
+    }
+}
+
+#[test]
+// test that we can't add editable posst without enough balance but we can add a non-editable one.
+fn add_post_balance() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    with_test_externalities(|| {
+        let initial_balance = <Runtime as Config>::PostDeposit::get()
+            + <Runtime as Config>::ThreadDeposit::get()
+            + <Runtime as balances::Config>::ExistentialDeposit::get();
+
+        balances::Pallet::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+
+        assert_eq!(
+            balances::Pallet::<Runtime>::free_balance(&forum_lead),
+            initial_balance
+        );
+
+        let thread_id = create_thread_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            Ok(()),
+        );
+
+        assert_eq!(
+            balances::Pallet::<Runtime>::free_balance(&forum_lead),
+            <Runtime as balances::Config>::ExistentialDeposit::get()
+        );
+
+        balances::Pallet::<Runtime>::make_free_balance_be(
+            &forum_lead,
+            <Runtime as Config>::PostDeposit::get() - 1,
+        );
+
+        // Can't create an editable post without enough balance
+        create_post_mock(
+            FORUM_LEAD_ORIGIN,
+            forum_lead,
+            forum_lead,
+            category_id,
+            thread_id,
+            good_post_text(),
+            true,
+            Err(Error::<Runtime>::InsufficientBalanceForPost.into()),
+        );
+
+        // No balance requirements for non-editable posts
+        create_post_mock(
+            FORUM_LEAD_ORIGIN,
+            forum_lead,
+            forum_lead,
+            category_id,
+            thread_id,
+            good_post_text(),
+            false,
+            Ok(()),
+        );
+    });
+}
+
+#[test]
+// test if post text can be edited by author
+fn edit_post_text() {
+    let forum_users = [NOT_FORUM_LEAD_ORIGIN_ID, NOT_FORUM_LEAD_2_ORIGIN_ID];
+    let origins = [NOT_FORUM_LEAD_ORIGIN, NOT_FORUM_LEAD_2_ORIGIN];
+
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+
     let initial_balance = 10_000_000;
     with_test_externalities(|| {
         balances::Pallet::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+        balances::Pallet::<Runtime>::make_free_balance_be(
+            &NOT_FORUM_LEAD_ORIGIN_ID,
+            initial_balance,
+        );
+        balances::Pallet::<Runtime>::make_free_balance_be(
+            &NOT_FORUM_LEAD_2_ORIGIN_ID,
+            initial_balance,
+        );
 
-        let moderator_id = forum_lead;
+        // prepare category and thread
         let category_id = create_category_mock(
             origin.clone(),
             None,
@@ -1999,13 +2954,6 @@ fn moderate_thread_origin_ok() {
             good_category_description(),
             Ok(()),
         );
-        update_category_membership_of_moderator_mock(
-            origin.clone(),
-            moderator_id,
-            category_id,
-            true,
-            Ok(()),
-        );
         let thread_id = create_thread_mock(
             origin.clone(),
             forum_lead,
@@ -2015,36 +2963,58 @@ fn moderate_thread_origin_ok() {
             good_thread_text(),
             Ok(()),
         );
-        // Delete original post first to allow thread deletion
-        delete_post_mock(
-            &forum_lead,
-            forum_lead,
+
+        // create post by author
+        let post_id = create_post_mock(
+            origins[0].clone(),
+            forum_users[0],
+            forum_users[0],
             category_id,
             thread_id,
-            TestForumModule::next_post_id() - 1,
+            good_post_text(),
+            true,
             Ok(()),
-            false,
         );
-        moderate_thread_mock(
-            origin,
-            moderator_id,
+
+        // check author can edit text
+        edit_post_text_mock(
+            origins[0].clone(),
+            forum_users[0],
             category_id,
             thread_id,
-            good_moderation_rationale(),
+            post_id,
+            good_post_new_text(),
             Ok(()),
         );
+
+        // check non-author is forbidden from editing text
+        edit_post_text_mock(
+            origins[1].clone(),
+            forum_users[1],
+            category_id,
+            thread_id,
+            post_id,
+            good_post_new_text(),
+            Err(Error::<Runtime>::AccountDoesNotMatchPostAuthor.into()),
+        );
     });
 }
 
 #[test]
-fn moderate_thread_fails_with_outstanding_posts() {
+// can't edit non-editable post
+fn edit_non_editable_post_text() {
     let forum_lead = FORUM_LEAD_ORIGIN_ID;
     let origin = OriginType::Signed(forum_lead);
+
     let initial_balance = 10_000_000;
     with_test_externalities(|| {
         balances::Pallet::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+        balances::Pallet::<Runtime>::make_free_balance_be(
+            &NOT_FORUM_LEAD_ORIGIN_ID,
+            initial_balance,
+        );
 
-        let moderator_id = forum_lead;
+        // prepare category and thread
         let category_id = create_category_mock(
             origin.clone(),
             None,
@@ -2052,285 +3022,549 @@ fn moderate_thread_fails_with_outstanding_posts() {
             good_category_description(),
             Ok(()),
         );
-        update_category_membership_of_moderator_mock(
+        let thread_id = create_thread_mock(
             origin.clone(),
-            moderator_id,
+            forum_lead,
+            forum_lead,
             category_id,
-            true,
+            good_thread_metadata(),
+            good_thread_text(),
             Ok(()),
         );
-        let thread_id = create_thread_mock(
+
+        // create post by author
+        let post_id = create_post_mock(
             origin.clone(),
             forum_lead,
             forum_lead,
             category_id,
-            good_thread_metadata(),
-            good_thread_text(),
+            thread_id,
+            good_post_text(),
+            false,
             Ok(()),
         );
-        moderate_thread_mock(
-            origin,
-            moderator_id,
+
+        // check non-author is forbidden from editing text
+        edit_post_text_mock(
+            origin.clone(),
+            forum_lead,
             category_id,
             thread_id,
-            good_moderation_rationale(),
-            Err(Error::<Runtime>::CannotDeleteThreadWithOutstandingPosts.into()),
+            post_id,
+            good_post_new_text(),
+            Err(Error::<Runtime>::PostDoesNotExist.into()),
         );
     });
 }
 
 /*
- ** add_post
+ ** report_post
  */
 
-#[test]
-// test if post origin registered as forum user
-fn add_post_origin() {
-    let origins = vec![FORUM_LEAD_ORIGIN, NOT_FORUM_MEMBER_ORIGIN];
-    let accounts = vec![FORUM_LEAD_ORIGIN_ID, NOT_FORUM_MEMBER_ORIGIN_ID];
-    let results = vec![
+// Creates a category with a post of the forum lead, returns (category_id, thread_id, post_id)
+fn create_reportable_post() -> (
+    <Runtime as Config>::CategoryId,
+    <Runtime as Config>::ThreadId,
+    <Runtime as Config>::PostId,
+) {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    balances::Pallet::<Runtime>::make_free_balance_be(&forum_lead, 10_000_000);
+    balances::Pallet::<Runtime>::make_free_balance_be(&NOT_FORUM_LEAD_ORIGIN_ID, 10_000_000);
+
+    let category_id = create_category_mock(
+        origin.clone(),
+        None,
+        good_category_title(),
+        good_category_description(),
         Ok(()),
-        Err(Error::<Runtime>::ForumUserIdNotMatchAccount.into()),
-    ];
-    for index in 0..origins.len() {
-        let forum_lead = FORUM_LEAD_ORIGIN_ID;
-        let origin = OriginType::Signed(forum_lead);
-        let initial_balance = 10_000_000;
-        with_test_externalities(|| {
-            balances::Pallet::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
-            balances::Pallet::<Runtime>::make_free_balance_be(
-                &NOT_FORUM_MEMBER_ORIGIN_ID,
-                initial_balance,
-            );
-            let category_id = create_category_mock(
-                origin.clone(),
-                None,
-                good_category_title(),
-                good_category_description(),
-                Ok(()),
-            );
+    );
+    let thread_id = create_thread_mock(
+        origin.clone(),
+        forum_lead,
+        forum_lead,
+        category_id,
+        good_thread_metadata(),
+        good_thread_text(),
+        Ok(()),
+    );
+    let post_id = create_post_mock(
+        origin,
+        forum_lead,
+        forum_lead,
+        category_id,
+        thread_id,
+        good_post_text(),
+        true,
+        Ok(()),
+    );
 
-            let thread_id = create_thread_mock(
-                origin.clone(),
-                forum_lead,
-                forum_lead,
-                category_id,
-                good_thread_metadata(),
-                good_thread_text(),
-                Ok(()),
-            );
-            create_post_mock(
-                origins[index].clone(),
-                accounts[index],
-                forum_lead,
+    (category_id, thread_id, post_id)
+}
+
+#[test]
+// test report can be made for existing posts only and requires the report deposit
+fn report_post_fails_with_invalid_parameters() {
+    with_test_externalities(|| {
+        let (category_id, thread_id, post_id) = create_reportable_post();
+
+        report_post_mock(
+            NOT_FORUM_MEMBER_ORIGIN,
+            NOT_FORUM_MEMBER_ORIGIN_ID,
+            NOT_FORUM_MEMBER_ORIGIN_ID,
+            category_id,
+            thread_id,
+            post_id,
+            1,
+            Err(Error::<Runtime>::ForumUserIdNotMatchAccount.into()),
+        );
+        report_post_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            category_id,
+            thread_id,
+            post_id + 1,
+            1,
+            Err(Error::<Runtime>::PostDoesNotExist.into()),
+        );
+        report_post_mock(
+            NOT_FORUM_LEAD_2_ORIGIN,
+            NOT_FORUM_LEAD_2_ORIGIN_ID,
+            NOT_FORUM_LEAD_2_ORIGIN_ID,
+            category_id,
+            thread_id,
+            post_id,
+            1,
+            Err(Error::<Runtime>::InsufficientBalanceForReport.into()),
+        );
+    });
+}
+
+#[test]
+// test reports are rate limited per forum user
+fn report_post_rate_limit() {
+    with_test_externalities(|| {
+        let (category_id, thread_id, post_id) = create_reportable_post();
+
+        for _ in 0..MaxReportsPerPeriod::get() {
+            report_post_mock(
+                NOT_FORUM_LEAD_ORIGIN,
+                NOT_FORUM_LEAD_ORIGIN_ID,
+                NOT_FORUM_LEAD_ORIGIN_ID,
                 category_id,
                 thread_id,
-                good_post_text(),
-                true,
-                results[index],
+                post_id,
+                1,
+                Ok(()),
             );
-        });
-    }
+        }
+        report_post_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            category_id,
+            thread_id,
+            post_id,
+            1,
+            Err(Error::<Runtime>::ReportRateLimitExceeded.into()),
+        );
+
+        run_to_block(ReportRateLimitPeriod::get());
+
+        report_post_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            category_id,
+            thread_id,
+            post_id,
+            1,
+            Ok(()),
+        );
+    });
 }
 
 #[test]
-// test that we can't add editable posst without enough balance but we can add a non-editable one.
-fn add_post_balance() {
-    let forum_lead = FORUM_LEAD_ORIGIN_ID;
-    let origin = OriginType::Signed(forum_lead);
+// test dismissed report deposit is slashed
+fn resolve_post_report_dismiss() {
     with_test_externalities(|| {
-        let initial_balance = <Runtime as Config>::PostDeposit::get()
-            + <Runtime as Config>::ThreadDeposit::get()
-            + <Runtime as balances::Config>::ExistentialDeposit::get();
+        let (category_id, thread_id, post_id) = create_reportable_post();
+        let reporter = NOT_FORUM_LEAD_ORIGIN_ID;
 
-        balances::Pallet::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
-        let category_id = create_category_mock(
-            origin.clone(),
-            None,
-            good_category_title(),
-            good_category_description(),
+        let report_id = report_post_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            reporter,
+            reporter,
+            category_id,
+            thread_id,
+            post_id,
+            1,
+            Ok(()),
+        );
+        let reporter_balance = balances::Pallet::<Runtime>::free_balance(reporter);
+
+        resolve_post_report_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            PrivilegedActor::Lead,
+            category_id,
+            report_id,
+            ReportResolution::Dismiss,
+            0,
+            Err(Error::<Runtime>::OriginNotForumLead.into()),
+        );
+        resolve_post_report_mock(
+            FORUM_MODERATOR_ORIGIN,
+            PrivilegedActor::Moderator(FORUM_MODERATOR_ORIGIN_ID),
+            category_id,
+            report_id,
+            ReportResolution::Dismiss,
+            0,
+            Err(Error::<Runtime>::ModeratorCantUpdateCategory.into()),
+        );
+        resolve_post_report_mock(
+            FORUM_LEAD_ORIGIN,
+            PrivilegedActor::Lead,
+            category_id,
+            report_id + 1,
+            ReportResolution::Dismiss,
+            0,
+            Err(Error::<Runtime>::ReportDoesNotExist.into()),
+        );
+        resolve_post_report_mock(
+            FORUM_LEAD_ORIGIN,
+            PrivilegedActor::Lead,
+            category_id,
+            report_id,
+            ReportResolution::Dismiss,
+            0,
             Ok(()),
         );
 
         assert_eq!(
-            balances::Pallet::<Runtime>::free_balance(&forum_lead),
-            initial_balance
+            balances::Pallet::<Runtime>::free_balance(reporter),
+            reporter_balance
+        );
+        assert!(PostById::<Runtime>::contains_key(thread_id, post_id));
+    });
+}
+
+#[test]
+// test report leading to moderation is refunded and rewarded
+fn resolve_post_report_moderate() {
+    with_test_externalities(|| {
+        let (category_id, thread_id, post_id) = create_reportable_post();
+        let reporter = NOT_FORUM_LEAD_ORIGIN_ID;
+        let reward = <Runtime as Config>::ReportReward::get();
+        let initial_budget = WORKING_GROUP_BUDGET;
+        let reporter_balance = balances::Pallet::<Runtime>::free_balance(reporter);
+
+        let report_id = report_post_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            reporter,
+            reporter,
+            category_id,
+            thread_id,
+            post_id,
+            1,
+            Ok(()),
+        );
+
+        update_category_membership_of_moderator_mock(
+            FORUM_LEAD_ORIGIN,
+            FORUM_MODERATOR_ORIGIN_ID,
+            category_id,
+            true,
+            Ok(()),
         );
 
-        let thread_id = create_thread_mock(
-            origin.clone(),
-            forum_lead,
-            forum_lead,
+        resolve_post_report_mock(
+            FORUM_MODERATOR_ORIGIN,
+            PrivilegedActor::Moderator(FORUM_MODERATOR_ORIGIN_ID),
             category_id,
-            good_thread_metadata(),
-            good_thread_text(),
+            report_id,
+            ReportResolution::Moderate,
+            reward,
             Ok(()),
         );
 
+        assert!(!PostById::<Runtime>::contains_key(thread_id, post_id));
         assert_eq!(
-            balances::Pallet::<Runtime>::free_balance(&forum_lead),
-            <Runtime as balances::Config>::ExistentialDeposit::get()
+            balances::Pallet::<Runtime>::free_balance(reporter),
+            reporter_balance + reward
         );
+        assert_eq!(Wg::get_budget(), initial_budget - reward);
+    });
+}
 
-        balances::Pallet::<Runtime>::make_free_balance_be(
-            &forum_lead,
-            <Runtime as Config>::PostDeposit::get() - 1,
+#[test]
+// test report of a post that no longer exists is refunded but not rewarded on moderation
+fn resolve_post_report_moderate_with_post_already_moderated() {
+    with_test_externalities(|| {
+        let (category_id, thread_id, post_id) = create_reportable_post();
+        let reporter = NOT_FORUM_LEAD_ORIGIN_ID;
+        let initial_budget = WORKING_GROUP_BUDGET;
+        let reporter_balance = balances::Pallet::<Runtime>::free_balance(reporter);
+
+        let report_id = report_post_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            reporter,
+            reporter,
+            category_id,
+            thread_id,
+            post_id,
+            1,
+            Ok(()),
         );
 
-        // Can't create an editable post without enough balance
-        create_post_mock(
+        update_category_membership_of_moderator_mock(
             FORUM_LEAD_ORIGIN,
-            forum_lead,
-            forum_lead,
+            FORUM_MODERATOR_ORIGIN_ID,
             category_id,
-            thread_id,
-            good_post_text(),
             true,
-            Err(Error::<Runtime>::InsufficientBalanceForPost.into()),
+            Ok(()),
         );
 
-        // No balance requirements for non-editable posts
-        create_post_mock(
-            FORUM_LEAD_ORIGIN,
-            forum_lead,
-            forum_lead,
+        moderate_post_mock(
+            FORUM_MODERATOR_ORIGIN,
+            FORUM_MODERATOR_ORIGIN_ID,
             category_id,
             thread_id,
-            good_post_text(),
-            false,
+            post_id,
+            good_moderation_rationale(),
+            Ok(()),
+        );
+
+        resolve_post_report_mock(
+            FORUM_MODERATOR_ORIGIN,
+            PrivilegedActor::Moderator(FORUM_MODERATOR_ORIGIN_ID),
+            category_id,
+            report_id,
+            ReportResolution::Moderate,
+            0,
             Ok(()),
         );
+
+        assert_eq!(
+            balances::Pallet::<Runtime>::free_balance(reporter),
+            reporter_balance
+        );
+        assert_eq!(Wg::get_budget(), initial_budget);
     });
 }
 
 #[test]
-// test if post text can be edited by author
-fn edit_post_text() {
-    let forum_users = [NOT_FORUM_LEAD_ORIGIN_ID, NOT_FORUM_LEAD_2_ORIGIN_ID];
-    let origins = [NOT_FORUM_LEAD_ORIGIN, NOT_FORUM_LEAD_2_ORIGIN];
+// test report resolution fails before any mutation when the deposits cannot be covered
+fn resolve_post_report_fails_with_insufficient_deposit_balances() {
+    with_test_externalities(|| {
+        let (category_id, thread_id, post_id) = create_reportable_post();
+        let reporter = NOT_FORUM_LEAD_ORIGIN_ID;
 
-    let forum_lead = FORUM_LEAD_ORIGIN_ID;
-    let origin = OriginType::Signed(forum_lead);
+        let report_id = report_post_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            reporter,
+            reporter,
+            category_id,
+            thread_id,
+            post_id,
+            1,
+            Ok(()),
+        );
 
-    let initial_balance = 10_000_000;
-    with_test_externalities(|| {
-        balances::Pallet::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
-        balances::Pallet::<Runtime>::make_free_balance_be(
-            &NOT_FORUM_LEAD_ORIGIN_ID,
-            initial_balance,
+        let module_account_id = TestForumModule::module_account_id();
+        let module_balance = balances::Pallet::<Runtime>::free_balance(&module_account_id);
+        balances::Pallet::<Runtime>::make_free_balance_be(&module_account_id, 0);
+
+        resolve_post_report_mock(
+            FORUM_LEAD_ORIGIN,
+            PrivilegedActor::Lead,
+            category_id,
+            report_id,
+            ReportResolution::Dismiss,
+            0,
+            Err(Error::<Runtime>::InsufficientBalanceForReportDeposit.into()),
         );
+
+        balances::Pallet::<Runtime>::make_free_balance_be(&module_account_id, module_balance);
         balances::Pallet::<Runtime>::make_free_balance_be(
-            &NOT_FORUM_LEAD_2_ORIGIN_ID,
-            initial_balance,
+            &TestForumModule::thread_account(thread_id),
+            0,
         );
 
-        // prepare category and thread
-        let category_id = create_category_mock(
-            origin.clone(),
-            None,
-            good_category_title(),
-            good_category_description(),
+        resolve_post_report_mock(
+            FORUM_LEAD_ORIGIN,
+            PrivilegedActor::Lead,
+            category_id,
+            report_id,
+            ReportResolution::Moderate,
+            0,
+            Err(Error::<Runtime>::InsufficientBalanceForPostDepositSlash.into()),
+        );
+
+        assert!(PostById::<Runtime>::contains_key(thread_id, post_id));
+        assert!(PostReportById::<Runtime>::contains_key(
+            category_id,
+            report_id
+        ));
+    });
+}
+
+/*
+ ** react_post
+ */
+
+#[test]
+// test reactions are counted per post and bounded by the maximum number of reactions
+fn react_post_counts_reactions() {
+    with_test_externalities(|| {
+        let (category_id, thread_id, post_id) = create_reportable_post();
+
+        react_post_mock(
+            NOT_FORUM_MEMBER_ORIGIN,
+            NOT_FORUM_MEMBER_ORIGIN_ID,
+            category_id,
+            thread_id,
+            post_id,
+            Some(PostReaction::Like),
+            Err(Error::<Runtime>::ForumUserIdNotMatchAccount.into()),
+        );
+        react_post_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            category_id,
+            thread_id,
+            post_id + 1,
+            Some(PostReaction::Like),
+            Err(Error::<Runtime>::PostDoesNotExist.into()),
+        );
+        react_post_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            category_id,
+            thread_id,
+            post_id,
+            Some(PostReaction::Like),
             Ok(()),
         );
-        let thread_id = create_thread_mock(
-            origin.clone(),
-            forum_lead,
-            forum_lead,
+        react_post_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            NOT_FORUM_LEAD_ORIGIN_ID,
             category_id,
-            good_thread_metadata(),
-            good_thread_text(),
+            thread_id,
+            post_id,
+            Some(PostReaction::Like),
+            Err(Error::<Runtime>::PostReactionUnchanged.into()),
+        );
+        react_post_mock(
+            FORUM_LEAD_ORIGIN,
+            FORUM_LEAD_ORIGIN_ID,
+            category_id,
+            thread_id,
+            post_id,
+            Some(PostReaction::Like),
             Ok(()),
         );
+        react_post_mock(
+            NOT_FORUM_LEAD_2_ORIGIN,
+            NOT_FORUM_LEAD_2_ORIGIN_ID,
+            category_id,
+            thread_id,
+            post_id,
+            Some(PostReaction::Funny),
+            Err(Error::<Runtime>::MaxReactionsPerPostExceeded.into()),
+        );
 
-        // create post by author
-        let post_id = create_post_mock(
-            origins[0].clone(),
-            forum_users[0],
-            forum_users[0],
+        // Existing reactions can be changed when the limit is reached
+        react_post_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            NOT_FORUM_LEAD_ORIGIN_ID,
             category_id,
             thread_id,
-            good_post_text(),
-            true,
+            post_id,
+            Some(PostReaction::Insightful),
             Ok(()),
         );
+        let counters = TestForumModule::post_reaction_counters_by_post_id(post_id);
+        assert_eq!(counters.count(PostReaction::Like), 1);
+        assert_eq!(counters.count(PostReaction::Insightful), 1);
 
-        // check author can edit text
-        edit_post_text_mock(
-            origins[0].clone(),
-            forum_users[0],
+        // Removed reaction frees the slot
+        react_post_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            NOT_FORUM_LEAD_ORIGIN_ID,
             category_id,
             thread_id,
             post_id,
-            good_post_new_text(),
+            None,
             Ok(()),
         );
-
-        // check non-author is forbidden from editing text
-        edit_post_text_mock(
-            origins[1].clone(),
-            forum_users[1],
+        react_post_mock(
+            NOT_FORUM_LEAD_2_ORIGIN,
+            NOT_FORUM_LEAD_2_ORIGIN_ID,
             category_id,
             thread_id,
             post_id,
-            good_post_new_text(),
-            Err(Error::<Runtime>::AccountDoesNotMatchPostAuthor.into()),
+            Some(PostReaction::Funny),
+            Ok(()),
         );
+        let counters = TestForumModule::post_reaction_counters_by_post_id(post_id);
+        assert_eq!(counters.count(PostReaction::Insightful), 0);
+        assert_eq!(counters.count(PostReaction::Funny), 1);
+        assert_eq!(counters.total(), 2);
     });
 }
 
 #[test]
-// can't edit non-editable post
-fn edit_non_editable_post_text() {
-    let forum_lead = FORUM_LEAD_ORIGIN_ID;
-    let origin = OriginType::Signed(forum_lead);
-
-    let initial_balance = 10_000_000;
+// test post author receives the tip on the controller account
+fn tip_post_author() {
     with_test_externalities(|| {
-        balances::Pallet::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
-        balances::Pallet::<Runtime>::make_free_balance_be(
-            &NOT_FORUM_LEAD_ORIGIN_ID,
-            initial_balance,
-        );
+        let (category_id, thread_id, post_id) = create_reportable_post();
+        let amount = 100;
 
-        // prepare category and thread
-        let category_id = create_category_mock(
-            origin.clone(),
-            None,
-            good_category_title(),
-            good_category_description(),
-            Ok(()),
+        tip_post_author_mock(
+            NOT_FORUM_MEMBER_ORIGIN,
+            NOT_FORUM_MEMBER_ORIGIN_ID,
+            category_id,
+            thread_id,
+            post_id,
+            amount,
+            Err(Error::<Runtime>::ForumUserIdNotMatchAccount.into()),
         );
-        let thread_id = create_thread_mock(
-            origin.clone(),
-            forum_lead,
-            forum_lead,
+        tip_post_author_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            NOT_FORUM_LEAD_ORIGIN_ID,
             category_id,
-            good_thread_metadata(),
-            good_thread_text(),
-            Ok(()),
+            thread_id,
+            post_id,
+            0,
+            Err(Error::<Runtime>::TipAmountCannotBeZero.into()),
         );
-
-        // create post by author
-        let post_id = create_post_mock(
-            origin.clone(),
-            forum_lead,
-            forum_lead,
+        tip_post_author_mock(
+            FORUM_LEAD_ORIGIN,
+            FORUM_LEAD_ORIGIN_ID,
             category_id,
             thread_id,
-            good_post_text(),
-            false,
-            Ok(()),
+            post_id,
+            amount,
+            Err(Error::<Runtime>::CannotTipOwnPost.into()),
         );
-
-        // check non-author is forbidden from editing text
-        edit_post_text_mock(
-            origin.clone(),
-            forum_lead,
+        tip_post_author_mock(
+            NOT_FORUM_LEAD_2_ORIGIN,
+            NOT_FORUM_LEAD_2_ORIGIN_ID,
             category_id,
             thread_id,
             post_id,
-            good_post_new_text(),
-            Err(Error::<Runtime>::PostDoesNotExist.into()),
+            amount,
+            Err(Error::<Runtime>::InsufficientBalanceForTip.into()),
+        );
+        tip_post_author_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            category_id,
+            thread_id,
+            post_id,
+            amount,
+            Ok(()),
         );
     });
 }
@@ -3009,3 +4243,48 @@ fn add_non_editable_post_should_not_increase_thread_number_of_editable_posts() {
         )
     })
 }
+
+#[test]
+fn migrate_threads_ok() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    with_test_externalities(|| {
+        use frame_support::storage::StorageDoubleMap;
+
+        balances::Pallet::<Runtime>::make_free_balance_be(&forum_lead, 10_000_000);
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        let thread_id = create_thread_mock(
+            origin,
+            forum_lead,
+            forum_lead,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            Ok(()),
+        );
+        let thread = TestForumModule::thread_by_id(category_id, thread_id);
+        frame_support::storage::unhashed::put_raw(
+            &<ThreadById<Runtime>>::hashed_key_for(category_id, thread_id),
+            &migrations::LegacyThreadOf::<Runtime> {
+                category_id: thread.category_id,
+                author_id: thread.author_id,
+                cleanup_pay_off: thread.cleanup_pay_off.clone(),
+                number_of_editable_posts: thread.number_of_editable_posts,
+            }
+            .encode(),
+        );
+
+        TestForumModule::migrate_threads();
+
+        assert_eq!(
+            TestForumModule::thread_by_id(category_id, thread_id),
+            thread
+        );
+    });
+}
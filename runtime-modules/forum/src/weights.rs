@@ -50,13 +50,15 @@ pub trait WeightInfo {
 	fn update_category_membership_of_moderator_old() -> Weight;
 	fn update_category_archival_status_lead(_i: u32, ) -> Weight;
 	fn update_category_archival_status_moderator(_i: u32, ) -> Weight;
+	fn update_category_posting_requirements_lead(_i: u32, ) -> Weight;
+	fn update_category_posting_requirements_moderator(_i: u32, ) -> Weight;
 	fn update_category_title_lead(_i: u32, _j: u32, ) -> Weight;
 	fn update_category_title_moderator(_i: u32, _j: u32, ) -> Weight;
 	fn update_category_description_lead(_i: u32, _j: u32, ) -> Weight;
 	fn update_category_description_moderator(_i: u32, _j: u32, ) -> Weight;
 	fn delete_category_lead(_i: u32, ) -> Weight;
 	fn delete_category_moderator(_i: u32, ) -> Weight;
-	fn create_thread(_i: u32, _j: u32, _k: u32, ) -> Weight;
+	fn create_thread(_i: u32, _j: u32, _k: u32, _z: u32, ) -> Weight;
 	fn edit_thread_metadata(_i: u32, _j: u32, ) -> Weight;
 	fn delete_thread(_i: u32, ) -> Weight;
 	fn move_thread_to_category_lead(_i: u32, ) -> Weight;
@@ -65,11 +67,18 @@ pub trait WeightInfo {
 	fn moderate_thread_moderator(_i: u32, _k: u32, ) -> Weight;
 	fn add_post(_i: u32, _j: u32, ) -> Weight;
 	fn edit_post_text(_i: u32, _j: u32, ) -> Weight;
-	fn moderate_post_lead(_i: u32, _j: u32, ) -> Weight;
-	fn moderate_post_moderator(_i: u32, _j: u32, ) -> Weight;
-	fn delete_posts(_i: u32, _j: u32, _k: u32, ) -> Weight;
+	fn moderate_post_lead(_i: u32, _j: u32, _r: u32, ) -> Weight;
+	fn moderate_post_moderator(_i: u32, _j: u32, _r: u32, ) -> Weight;
+	fn delete_posts(_i: u32, _j: u32, _k: u32, _r: u32, ) -> Weight;
 	fn set_stickied_threads_lead(_i: u32, _j: u32, ) -> Weight;
 	fn set_stickied_threads_moderator(_i: u32, _j: u32, ) -> Weight;
+	fn vote_on_poll(_i: u32, _j: u32, ) -> Weight;
+	fn release_poll_vote_stake() -> Weight;
+	fn report_post(_i: u32, ) -> Weight;
+	fn resolve_post_report_lead(_i: u32, _j: u32, _r: u32, ) -> Weight;
+	fn resolve_post_report_moderator(_i: u32, _j: u32, _r: u32, ) -> Weight;
+	fn react_post(_i: u32, ) -> Weight;
+	fn tip_post_author() -> Weight;
 }
 
 /// Weights for forum using the Substrate node and recommended hardware.
@@ -134,6 +143,30 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	// Storage: Instance1WorkingGroup CurrentLead (r:1 w:0)
 	// Storage: Instance1WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Forum_1_1 CategoryById (r:1 w:0)
+	// Storage: Forum_1_1 CategoryPostingRequirements (r:0 w:1)
+	fn update_category_posting_requirements_lead(i: u32, ) -> Weight {
+		(28_917_000 as Weight)
+			// Standard Error: 33_000
+			.saturating_add((4_301_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Instance1WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Forum_1_1 CategoryById (r:1 w:0)
+	// Storage: Forum_1_1 CategoryByModerator (r:1 w:0)
+	// Storage: Forum_1_1 CategoryPostingRequirements (r:0 w:1)
+	fn update_category_posting_requirements_moderator(i: u32, ) -> Weight {
+		(26_984_000 as Weight)
+			// Standard Error: 44_000
+			.saturating_add((7_910_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Instance1WorkingGroup CurrentLead (r:1 w:0)
+	// Storage: Instance1WorkingGroup WorkerById (r:1 w:0)
 	// Storage: Forum_1_1 CategoryById (r:1 w:1)
 	fn update_category_title_lead(i: u32, j: u32, ) -> Weight {
 		(28_858_000 as Weight)
@@ -210,20 +243,25 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Storage: Forum_1_1 CategoryById (r:1 w:1)
+	// Storage: Forum_1_1 CategoryPostingRequirements (r:1 w:0)
+	// Storage: Membership MembershipCreatedAt (r:1 w:0)
+	// Storage: Instance1WorkingGroup WorkerCountByMemberId (r:1 w:0)
 	// Storage: System Account (r:2 w:2)
 	// Storage: Forum_1_1 NextThreadId (r:1 w:1)
 	// Storage: Forum_1_1 ThreadById (r:1 w:1)
 	// Storage: Forum_1_1 NextPostId (r:1 w:1)
 	// Storage: Forum_1_1 PostById (r:0 w:1)
-	fn create_thread(i: u32, j: u32, k: u32, ) -> Weight {
-		(96_321_000 as Weight)
+	fn create_thread(i: u32, j: u32, k: u32, z: u32, ) -> Weight {
+		(103_517_000 as Weight)
 			// Standard Error: 345_000
 			.saturating_add((7_680_000 as Weight).saturating_mul(i as Weight))
 			// Standard Error: 8_000
 			.saturating_add((610_000 as Weight).saturating_mul(j as Weight))
 			// Standard Error: 8_000
 			.saturating_add((1_693_000 as Weight).saturating_mul(k as Weight))
-			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			// Standard Error: 19_000
+			.saturating_add((1_238_000 as Weight).saturating_mul(z as Weight))
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
 			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
 			.saturating_add(T::DbWeight::get().writes(7 as Weight))
 	}
@@ -308,16 +346,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Storage: Forum_1_1 ThreadById (r:1 w:1)
 	// Storage: Forum_1_1 CategoryById (r:1 w:0)
+	// Storage: Forum_1_1 CategoryPostingRequirements (r:1 w:0)
+	// Storage: Membership MembershipCreatedAt (r:1 w:0)
+	// Storage: Instance1WorkingGroup WorkerCountByMemberId (r:1 w:0)
 	// Storage: System Account (r:2 w:2)
 	// Storage: Forum_1_1 NextPostId (r:1 w:1)
 	// Storage: Forum_1_1 PostById (r:0 w:1)
 	fn add_post(i: u32, j: u32, ) -> Weight {
-		(85_914_000 as Weight)
+		(92_706_000 as Weight)
 			// Standard Error: 109_000
 			.saturating_add((4_693_000 as Weight).saturating_mul(i as Weight))
 			// Standard Error: 3_000
 			.saturating_add((1_679_000 as Weight).saturating_mul(j as Weight))
-			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
 			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
 			.saturating_add(T::DbWeight::get().writes(5 as Weight))
 	}
@@ -341,15 +382,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Forum_1_1 ThreadById (r:1 w:1)
 	// Storage: Forum_1_1 PostById (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
-	fn moderate_post_lead(i: u32, j: u32, ) -> Weight {
+	// Storage: Forum_1_1 PostReactionCountersByPostId (r:0 w:1)
+	// Storage: Forum_1_1 PostReactionByForumUser (r:0 w:10)
+	fn moderate_post_lead(i: u32, j: u32, r: u32, ) -> Weight {
 		(72_664_000 as Weight)
 			// Standard Error: 63_000
 			.saturating_add((6_328_000 as Weight).saturating_mul(i as Weight))
 			// Standard Error: 1_000
 			.saturating_add((727_000 as Weight).saturating_mul(j as Weight))
+			// Standard Error: 4_000
+			.saturating_add((1_192_000 as Weight).saturating_mul(r as Weight))
 			.saturating_add(T::DbWeight::get().reads(5 as Weight))
 			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
 	}
 	// Storage: Instance1WorkingGroup WorkerById (r:1 w:0)
 	// Storage: Forum_1_1 CategoryById (r:1 w:0)
@@ -357,22 +403,29 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Forum_1_1 ThreadById (r:1 w:1)
 	// Storage: Forum_1_1 PostById (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
-	fn moderate_post_moderator(i: u32, j: u32, ) -> Weight {
+	// Storage: Forum_1_1 PostReactionCountersByPostId (r:0 w:1)
+	// Storage: Forum_1_1 PostReactionByForumUser (r:0 w:10)
+	fn moderate_post_moderator(i: u32, j: u32, r: u32, ) -> Weight {
 		(76_596_000 as Weight)
 			// Standard Error: 122_000
 			.saturating_add((9_194_000 as Weight).saturating_mul(i as Weight))
 			// Standard Error: 3_000
 			.saturating_add((726_000 as Weight).saturating_mul(j as Weight))
+			// Standard Error: 4_000
+			.saturating_add((1_207_000 as Weight).saturating_mul(r as Weight))
 			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(i as Weight)))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
 	}
 	// Storage: Forum_1_1 ThreadById (r:1 w:1)
 	// Storage: Forum_1_1 PostById (r:20 w:20)
 	// Storage: Forum_1_1 CategoryById (r:1 w:0)
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Storage: System Account (r:2 w:2)
-	fn delete_posts(i: u32, j: u32, k: u32, ) -> Weight {
+	// Storage: Forum_1_1 PostReactionCountersByPostId (r:0 w:20)
+	// Storage: Forum_1_1 PostReactionByForumUser (r:0 w:10)
+	fn delete_posts(i: u32, j: u32, k: u32, r: u32, ) -> Weight {
 		(0 as Weight)
 			// Standard Error: 316_000
 			.saturating_add((36_264_000 as Weight).saturating_mul(i as Weight))
@@ -380,11 +433,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((739_000 as Weight).saturating_mul(j as Weight))
 			// Standard Error: 56_000
 			.saturating_add((49_827_000 as Weight).saturating_mul(k as Weight))
+			// Standard Error: 4_000
+			.saturating_add((1_236_000 as Weight).saturating_mul(r as Weight))
 			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
 			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(k as Weight)))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(k as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(k as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
 	}
 	// Storage: Instance1WorkingGroup CurrentLead (r:1 w:0)
 	// Storage: Instance1WorkingGroup WorkerById (r:1 w:0)
@@ -416,6 +472,118 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(j as Weight)))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Forum_1_1 ThreadById (r:1 w:1)
+	// Storage: Forum_1_1 CategoryById (r:1 w:0)
+	// Storage: Forum_1_1 PollVotes (r:1 w:1)
+	// Storage: Membership StakingAccountIdMemberStatus (r:1 w:0)
+	// Storage: Forum_1_1 PollVoteLockByAccount (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	fn vote_on_poll(i: u32, j: u32, ) -> Weight {
+		(58_416_000 as Weight)
+			// Standard Error: 63_000
+			.saturating_add((4_917_000 as Weight).saturating_mul(i as Weight))
+			// Standard Error: 28_000
+			.saturating_add((371_000 as Weight).saturating_mul(j as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Membership StakingAccountIdMemberStatus (r:1 w:0)
+	// Storage: Forum_1_1 PollVoteLockByAccount (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	fn release_poll_vote_stake() -> Weight {
+		(34_729_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Forum_1_1 ThreadById (r:1 w:0)
+	// Storage: Forum_1_1 PostById (r:1 w:0)
+	// Storage: Forum_1_1 CategoryById (r:1 w:0)
+	// Storage: Forum_1_1 ReportRateLimitByForumUser (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Forum_1_1 NextReportId (r:1 w:1)
+	// Storage: Forum_1_1 PostReportById (r:0 w:1)
+	fn report_post(i: u32, ) -> Weight {
+		(68_412_000 as Weight)
+			// Standard Error: 58_000
+			.saturating_add((4_733_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Instance1WorkingGroup CurrentLead (r:1 w:0)
+	// Storage: Instance1WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Forum_1_1 CategoryById (r:1 w:0)
+	// Storage: Forum_1_1 PostReportById (r:1 w:1)
+	// Storage: Forum_1_1 ThreadById (r:1 w:1)
+	// Storage: Forum_1_1 PostById (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Instance1WorkingGroup Budget (r:1 w:1)
+	// Storage: Forum_1_1 PostReactionCountersByPostId (r:0 w:1)
+	// Storage: Forum_1_1 PostReactionByForumUser (r:0 w:10)
+	fn resolve_post_report_lead(i: u32, j: u32, r: u32, ) -> Weight {
+		(104_519_000 as Weight)
+			// Standard Error: 71_000
+			.saturating_add((6_412_000 as Weight).saturating_mul(i as Weight))
+			// Standard Error: 1_000
+			.saturating_add((729_000 as Weight).saturating_mul(j as Weight))
+			// Standard Error: 4_000
+			.saturating_add((1_188_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
+	}
+	// Storage: Instance1WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Forum_1_1 CategoryById (r:1 w:0)
+	// Storage: Forum_1_1 CategoryByModerator (r:1 w:0)
+	// Storage: Forum_1_1 PostReportById (r:1 w:1)
+	// Storage: Forum_1_1 ThreadById (r:1 w:1)
+	// Storage: Forum_1_1 PostById (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Instance1WorkingGroup Budget (r:1 w:1)
+	// Storage: Forum_1_1 PostReactionCountersByPostId (r:0 w:1)
+	// Storage: Forum_1_1 PostReactionByForumUser (r:0 w:10)
+	fn resolve_post_report_moderator(i: u32, j: u32, r: u32, ) -> Weight {
+		(108_247_000 as Weight)
+			// Standard Error: 76_000
+			.saturating_add((8_019_000 as Weight).saturating_mul(i as Weight))
+			// Standard Error: 1_000
+			.saturating_add((731_000 as Weight).saturating_mul(j as Weight))
+			// Standard Error: 4_000
+			.saturating_add((1_214_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
+	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Forum_1_1 ThreadById (r:1 w:0)
+	// Storage: Forum_1_1 PostById (r:1 w:0)
+	// Storage: Forum_1_1 CategoryById (r:1 w:0)
+	// Storage: Forum_1_1 PostReactionByForumUser (r:1 w:1)
+	// Storage: Forum_1_1 PostReactionCountersByPostId (r:1 w:1)
+	fn react_post(i: u32, ) -> Weight {
+		(36_904_000 as Weight)
+			// Standard Error: 52_000
+			.saturating_add((4_618_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Membership MembershipById (r:2 w:0)
+	// Storage: Forum_1_1 ThreadById (r:1 w:0)
+	// Storage: Forum_1_1 PostById (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn tip_post_author() -> Weight {
+		(61_235_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
 }
 
 // Default implementation for tests
@@ -435,6 +603,12 @@ impl WeightInfo for () {
 	fn update_category_archival_status_moderator(i: u32, ) -> Weight {
 		0
 	}
+	fn update_category_posting_requirements_lead(i: u32, ) -> Weight {
+		0
+	}
+	fn update_category_posting_requirements_moderator(i: u32, ) -> Weight {
+		0
+	}
 	fn update_category_title_lead(i: u32, j: u32, ) -> Weight {
 		0
 	}
@@ -453,7 +627,7 @@ impl WeightInfo for () {
 	fn delete_category_moderator(i: u32, ) -> Weight {
 		0
 	}
-	fn create_thread(i: u32, j: u32, k: u32, ) -> Weight {
+	fn create_thread(i: u32, j: u32, k: u32, z: u32, ) -> Weight {
 		0
 	}
 	fn edit_thread_metadata(i: u32, j: u32, ) -> Weight {
@@ -480,13 +654,13 @@ impl WeightInfo for () {
 	fn edit_post_text(i: u32, j: u32, ) -> Weight {
 		0
 	}
-	fn moderate_post_lead(i: u32, j: u32, ) -> Weight {
+	fn moderate_post_lead(i: u32, j: u32, r: u32, ) -> Weight {
 		0
 	}
-	fn moderate_post_moderator(i: u32, j: u32, ) -> Weight {
+	fn moderate_post_moderator(i: u32, j: u32, r: u32, ) -> Weight {
 		0
 	}
-	fn delete_posts(i: u32, j: u32, k: u32, ) -> Weight {
+	fn delete_posts(i: u32, j: u32, k: u32, r: u32, ) -> Weight {
 		0
 	}
 	fn set_stickied_threads_lead(i: u32, j: u32, ) -> Weight {
@@ -495,4 +669,25 @@ impl WeightInfo for () {
 	fn set_stickied_threads_moderator(i: u32, j: u32, ) -> Weight {
 		0
 	}
+	fn vote_on_poll(i: u32, j: u32, ) -> Weight {
+		0
+	}
+	fn release_poll_vote_stake() -> Weight {
+		0
+	}
+	fn report_post(i: u32, ) -> Weight {
+		0
+	}
+	fn resolve_post_report_lead(i: u32, j: u32, r: u32, ) -> Weight {
+		0
+	}
+	fn resolve_post_report_moderator(i: u32, j: u32, r: u32, ) -> Weight {
+		0
+	}
+	fn react_post(i: u32, ) -> Weight {
+		0
+	}
+	fn tip_post_author() -> Weight {
+		0
+	}
 }
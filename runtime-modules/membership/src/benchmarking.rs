@@ -1,8 +1,10 @@
 #![cfg(feature = "runtime-benchmarks")]
 use super::*;
 use crate::{
-    BuyMembershipParameters, Config, InviteMembershipParameters, MemberIdByHandleHash, Membership,
-    MembershipById, MembershipObject, StakingAccountIdMemberStatus, StakingAccountMemberBinding,
+    ActiveRecoveryByMemberId, BuyMembershipParameters, Config, HandleSaleOffer,
+    HandleSaleOfferByMemberId, InviteMembershipParameters, MemberIdByHandleHash, Membership,
+    MembershipById, MembershipObject, RecoveryConfigByMemberId, RecoveryConfigParameters,
+    ReferralCampaign, StakingAccountIdMemberStatus, StakingAccountMemberBinding,
 };
 use balances::Pallet as Balances;
 use core::convert::TryInto;
@@ -67,6 +69,34 @@ fn member_funded_account<T: Config + balances::Config>(
     (account_id, member_id)
 }
 
+// Creates a member (id 0) with the maximum number of recovery guardians (ids starting from 1)
+// and the recovery threshold equal to the number of guardians.
+fn member_with_recovery_config<T: Config + balances::Config>(
+) -> (T::AccountId, T::MemberId, Vec<(T::AccountId, T::MemberId)>) {
+    let (account_id, member_id) = member_funded_account::<T>("member", 0);
+
+    let guardians = (1..=T::MaxRecoveryGuardians::get())
+        .map(|id| member_funded_account::<T>("guardian", id))
+        .collect::<Vec<_>>();
+
+    let params = RecoveryConfigParameters {
+        guardians: guardians
+            .iter()
+            .map(|(_, guardian_id)| *guardian_id)
+            .collect(),
+        threshold: T::MaxRecoveryGuardians::get(),
+    };
+
+    Module::<T>::set_recovery_config(
+        RawOrigin::Signed(account_id.clone()).into(),
+        member_id,
+        Some(params),
+    )
+    .unwrap();
+
+    (account_id, member_id, guardians)
+}
+
 // Method to generate a distintic valid handle
 // for a membership. For each index.
 fn handle_from_id<T: Config>(id: u32) -> Vec<u8> {
@@ -154,6 +184,8 @@ benchmarks! {
 
         let j in 0 .. MAX_KILOBYTES_METADATA;
 
+        let k in 0 .. T::MaxUplineReferralLevels::get();
+
         let member_id = 0;
 
         let account_id = account::<T::AccountId>("member", member_id, SEED);
@@ -180,9 +212,37 @@ benchmarks! {
 
         Module::<T>::set_referral_cut(RawOrigin::Root.into(), referral_cut).unwrap();
 
-        let member_id = T::MemberId::from(member_id.try_into().unwrap());
+        let mut referrer_id = T::MemberId::from(member_id.try_into().unwrap());
+
+        // Worst case scenario: the referrer has `k` upline referrers, all of them rewarded
+        for level in 0 .. k {
+            params.referrer_id = Some(referrer_id);
+            params.handle = Some(handle_from_id::<T>(i * 1000 + 2 + level));
+
+            Module::<T>::buy_membership(
+                RawOrigin::Signed(account_id.clone()).into(),
+                params.clone()
+            ).unwrap();
+
+            referrer_id += T::MemberId::one();
+        }
+
+        let upline_referral_cut = 1u8; //percents
+
+        Module::<T>::set_upline_referral_cuts(
+            RawOrigin::Root.into(),
+            vec![upline_referral_cut; k as usize]
+        ).unwrap();
+
+        // Worst case scenario: the referral campaign bonus is paid from the working group budget
+        let campaign = ReferralCampaign {
+            bonus: fee,
+            ends_at: System::<T>::block_number() + One::one(),
+        };
+        ActiveReferralCampaign::<T>::put(campaign.clone());
+        T::WorkingGroup::set_budget(campaign.bonus);
 
-        params.referrer_id = Some(member_id);
+        params.referrer_id = Some(referrer_id);
         let second_handle = handle_from_id::<T>(i * 1000 + 1);
 
         params.handle = Some(second_handle.clone());
@@ -193,14 +253,21 @@ benchmarks! {
     verify {
 
         // Ensure membership for given member_id is successfully bought.
-        assert_eq!(Module::<T>::members_created(), member_id + T::MemberId::one() + T::MemberId::one());
+        assert_eq!(Module::<T>::members_created(), referrer_id + T::MemberId::one() + T::MemberId::one());
 
-        // Same account id gets reward for being referral.
+        // Same account id gets reward for being referral, upline referral and the campaign
+        // bonus.
         let referral_cut_balance = Perbill::from_percent(referral_cut.into()) * fee;
+        let upline_referral_cut_balance =
+            Perbill::from_percent(upline_referral_cut.into()) * fee;
         assert_eq!(
             Balances::<T>::free_balance(&account_id),
-            free_balance - fee + referral_cut_balance
+            free_balance - fee
+                + referral_cut_balance
+                + upline_referral_cut_balance * k.into()
+                + campaign.bonus
         );
+        assert_eq!(T::WorkingGroup::get_budget(), Zero::zero());
 
         let second_handle_hash = T::Hashing::hash(&second_handle);
 
@@ -214,7 +281,7 @@ benchmarks! {
             invites,
         };
 
-        let second_member_id = member_id + T::MemberId::one();
+        let second_member_id = referrer_id + T::MemberId::one();
 
         assert_eq!(MemberIdByHandleHash::<T>::get(second_handle_hash), second_member_id);
 
@@ -813,6 +880,233 @@ benchmarks! {
         );
     }
 
+    set_recovery_config {
+        let i in 1 .. T::MaxRecoveryGuardians::get();
+
+        let (account_id, member_id) = member_funded_account::<T>("member", 0);
+
+        let guardians = (1..=i)
+            .map(|id| member_funded_account::<T>("guardian", id).1)
+            .collect();
+
+        let params = RecoveryConfigParameters {
+            guardians,
+            threshold: i,
+        };
+
+    }: _(RawOrigin::Signed(account_id.clone()), member_id, Some(params.clone()))
+    verify {
+        assert!(RecoveryConfigByMemberId::<T>::contains_key(member_id));
+
+        assert_last_event::<T>(RawEvent::RecoveryConfigUpdated(member_id, Some(params)).into());
+    }
+
+    initiate_recovery {
+        let (_, member_id, guardians) = member_with_recovery_config::<T>();
+        let (guardian_account_id, guardian_id) = guardians[0].clone();
+
+        let new_root_account = account::<T::AccountId>("new_root", 0, SEED);
+
+    }: _(RawOrigin::Signed(guardian_account_id), guardian_id, member_id, new_root_account.clone())
+    verify {
+        assert!(ActiveRecoveryByMemberId::<T>::contains_key(member_id));
+
+        assert_last_event::<T>(
+            RawEvent::RecoveryInitiated(member_id, guardian_id, new_root_account).into()
+        );
+    }
+
+    approve_recovery {
+        let (_, member_id, guardians) = member_with_recovery_config::<T>();
+        let (initiator_account_id, initiator_id) = guardians[0].clone();
+        let (guardian_account_id, guardian_id) = guardians[1].clone();
+
+        let new_root_account = account::<T::AccountId>("new_root", 0, SEED);
+
+        Module::<T>::initiate_recovery(
+            RawOrigin::Signed(initiator_account_id).into(),
+            initiator_id,
+            member_id,
+            new_root_account,
+        ).unwrap();
+
+    }: _(RawOrigin::Signed(guardian_account_id), guardian_id, member_id)
+    verify {
+        assert!(Module::<T>::active_recovery(member_id).unwrap().approvals.contains(&guardian_id));
+
+        assert_last_event::<T>(RawEvent::RecoveryApproved(member_id, guardian_id).into());
+    }
+
+    finalize_recovery {
+        let (account_id, member_id, guardians) = member_with_recovery_config::<T>();
+        let (initiator_account_id, initiator_id) = guardians[0].clone();
+
+        let new_root_account = account::<T::AccountId>("new_root", 0, SEED);
+
+        Module::<T>::initiate_recovery(
+            RawOrigin::Signed(initiator_account_id).into(),
+            initiator_id,
+            member_id,
+            new_root_account.clone(),
+        ).unwrap();
+
+        for (guardian_account_id, guardian_id) in guardians.iter().skip(1) {
+            Module::<T>::approve_recovery(
+                RawOrigin::Signed(guardian_account_id.clone()).into(),
+                *guardian_id,
+                member_id,
+            ).unwrap();
+        }
+
+        System::<T>::set_block_number(System::<T>::block_number() + T::RecoveryDelay::get());
+
+    }: _(RawOrigin::Signed(account_id), member_id)
+    verify {
+        assert!(!ActiveRecoveryByMemberId::<T>::contains_key(member_id));
+
+        assert_eq!(
+            Module::<T>::membership(member_id).unwrap().root_account,
+            new_root_account
+        );
+
+        assert_last_event::<T>(RawEvent::RecoveryFinalized(member_id, new_root_account).into());
+    }
+
+    cancel_recovery {
+        let (account_id, member_id, guardians) = member_with_recovery_config::<T>();
+        let (initiator_account_id, initiator_id) = guardians[0].clone();
+
+        let new_root_account = account::<T::AccountId>("new_root", 0, SEED);
+
+        Module::<T>::initiate_recovery(
+            RawOrigin::Signed(initiator_account_id).into(),
+            initiator_id,
+            member_id,
+            new_root_account,
+        ).unwrap();
+
+    }: _(RawOrigin::Signed(account_id), member_id)
+    verify {
+        assert!(!ActiveRecoveryByMemberId::<T>::contains_key(member_id));
+
+        assert_last_event::<T>(RawEvent::RecoveryCancelled(member_id).into());
+    }
+
+    offer_handle_for_sale {
+        let (seller_account_id, seller_id) = member_funded_account::<T>("seller", 0);
+        let (_, buyer_id) = member_funded_account::<T>("buyer", 1);
+
+        let price: BalanceOf<T> = 100u32.into();
+
+    }: _(RawOrigin::Signed(seller_account_id), seller_id, price, Some(buyer_id))
+    verify {
+        assert_eq!(
+            Module::<T>::handle_sale_offer(seller_id),
+            Some(HandleSaleOffer { price, buyer_id: Some(buyer_id) })
+        );
+
+        assert_last_event::<T>(
+            RawEvent::HandleOfferedForSale(seller_id, price, Some(buyer_id)).into()
+        );
+    }
+
+    cancel_handle_sale {
+        let (seller_account_id, seller_id) = member_funded_account::<T>("seller", 0);
+
+        Module::<T>::offer_handle_for_sale(
+            RawOrigin::Signed(seller_account_id.clone()).into(),
+            seller_id,
+            100u32.into(),
+            None,
+        ).unwrap();
+
+    }: _(RawOrigin::Signed(seller_account_id), seller_id)
+    verify {
+        assert!(!HandleSaleOfferByMemberId::<T>::contains_key(seller_id));
+
+        assert_last_event::<T>(RawEvent::HandleSaleCancelled(seller_id).into());
+    }
+
+    buy_handle {
+        let (seller_account_id, seller_id) = member_funded_account::<T>("seller", 0);
+        let (buyer_account_id, buyer_id) = member_funded_account::<T>("buyer", 1);
+
+        let price: BalanceOf<T> = 100u32.into();
+
+        Module::<T>::offer_handle_for_sale(
+            RawOrigin::Signed(seller_account_id).into(),
+            seller_id,
+            price,
+            Some(buyer_id),
+        ).unwrap();
+
+        let seller_handle_hash = Module::<T>::membership(seller_id).unwrap().handle_hash;
+
+    }: _(RawOrigin::Signed(buyer_account_id), buyer_id, seller_id, price)
+    verify {
+        assert_eq!(Module::<T>::membership(buyer_id).unwrap().handle_hash, seller_handle_hash);
+        assert_eq!(MemberIdByHandleHash::<T>::get(seller_handle_hash), buyer_id);
+
+        assert_last_event::<T>(RawEvent::HandleSold(seller_id, buyer_id, price).into());
+    }
+
+    renew_handle {
+        let (account_id, member_id) = member_funded_account::<T>("member", 0);
+
+        let now = System::<T>::block_number() + One::one();
+        System::<T>::set_block_number(now);
+
+    }: _(RawOrigin::Signed(account_id), member_id)
+    verify {
+        assert_eq!(Module::<T>::handle_renewed_at(member_id), now);
+
+        assert_last_event::<T>(RawEvent::HandleRenewed(member_id, now).into());
+    }
+
+    set_upline_referral_cuts {
+        let i in 0 .. T::MaxUplineReferralLevels::get();
+
+        let cuts = vec![0u8; i as usize];
+
+    }: _(RawOrigin::Root, cuts.clone())
+    verify {
+        assert_eq!(Module::<T>::upline_referral_cuts().to_vec(), cuts);
+
+        assert_last_event::<T>(RawEvent::UplineReferralCutsUpdated(cuts).into());
+    }
+
+    set_referral_campaign {
+        let (account_id, member_id) = member_funded_account::<T>("member", 0);
+
+        T::insert_a_lead(0, &account_id, member_id);
+
+        let campaign = ReferralCampaign {
+            bonus: 100u32.into(),
+            ends_at: System::<T>::block_number() + One::one(),
+        };
+
+    }: _(RawOrigin::Signed(account_id), Some(campaign.clone()))
+    verify {
+        assert_eq!(Module::<T>::referral_campaign(), Some(campaign.clone()));
+
+        assert_last_event::<T>(RawEvent::ReferralCampaignUpdated(Some(campaign)).into());
+    }
+
+    record_activity {
+        let (_, member_id) = member_funded_account::<T>("member", 0);
+
+        // Worst case: the reputation is changed and the event is emitted
+        let activity = ReputationActivity::SuccessfulBountyEntry;
+        let new_reputation = T::MemberReputationRules::get().apply(
+            Module::<T>::member_reputation(member_id),
+            activity,
+        );
+
+    }: { <Module<T> as MemberReputationHandler<T>>::record_activity(&member_id, activity) }
+    verify {
+        assert_eq!(Module::<T>::member_reputation(member_id), new_reputation);
+    }
+
     // impl_benchmark_test_suite!(Module, tests::mock::build_test_externalities(), tests::mock::Test)
     impl_benchmark_test_suite!(
         Module,
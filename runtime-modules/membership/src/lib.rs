@@ -38,6 +38,35 @@
 //! updates the referral cut percent value.
 //! - [transfer_invites](./struct.Module.html#method.transfer_invites) - transfers the invites
 //! from one member to another.
+//! - [set_recovery_config](./struct.Module.html#method.set_recovery_config) - sets guardians
+//! and threshold for the root account social recovery.
+//! - [initiate_recovery](./struct.Module.html#method.initiate_recovery) - guardian initiates
+//! the root account recovery.
+//! - [approve_recovery](./struct.Module.html#method.approve_recovery) - guardian approves
+//! the root account recovery.
+//! - [finalize_recovery](./struct.Module.html#method.finalize_recovery) - sets the recovered
+//! root account after the recovery delay.
+//! - [cancel_recovery](./struct.Module.html#method.cancel_recovery) - current root account
+//! cancels the recovery.
+//! - [offer_handle_for_sale](./struct.Module.html#method.offer_handle_for_sale) - lists
+//! the member handle for sale.
+//! - [cancel_handle_sale](./struct.Module.html#method.cancel_handle_sale) - removes the member
+//! handle sale offer.
+//! - [buy_handle](./struct.Module.html#method.buy_handle) - swaps the handles of the buyer and
+//! the seller for the offered price.
+//! - [renew_handle](./struct.Module.html#method.renew_handle) - renews the member handle.
+//! - [set_upline_referral_cuts](./struct.Module.html#method.set_upline_referral_cuts) -
+//! updates the referral cut percent values of the upline referrers.
+//! - [set_referral_campaign](./struct.Module.html#method.set_referral_campaign) - starts or stops
+//! the referral bonus campaign paid from the working group budget.
+//!
+//! Handles not renewed for the `HandleExpiryPeriod` can be claimed by another member
+//! using [update_profile](./struct.Module.html#method.update_profile). Handles of the members
+//! created before the handle expiry was introduced expire after the `HandleExpiryGracePeriod`
+//! unless renewed.
+//!
+//! Member reputation is updated by other modules through the
+//! `common::membership::MemberReputationHandler` trait according to the `ReputationRules`.
 //!
 //! [Joystream handbook description](https://joystream.gitbook.io/joystream-handbook/subsystems/membership)
 
@@ -69,9 +98,14 @@ pub use weights::WeightInfo;
 
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::dispatch::DispatchError;
-use frame_support::traits::{Currency, ExistenceRequirement, Get, LockIdentifier, WithdrawReasons};
+use frame_support::traits::{
+    Currency, ExistenceRequirement, Get, LockIdentifier, ReservableCurrency, WithdrawReasons,
+};
 pub use frame_support::weights::Weight;
-use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure};
+use frame_support::{
+    decl_error, decl_event, decl_module, decl_storage, ensure,
+    storage::bounded_btree_set::BoundedBTreeSet, storage::bounded_vec::BoundedVec,
+};
 use frame_system::{ensure_root, ensure_signed};
 use scale_info::TypeInfo;
 use sp_arithmetic::{
@@ -82,11 +116,15 @@ use sp_runtime::{
     traits::{Hash, Saturating},
     SaturatedConversion,
 };
+use sp_std::collections::btree_set::BTreeSet;
 use sp_std::convert::TryInto;
 use sp_std::vec::Vec;
 
 use common::costs::{burn_from_usable, has_sufficient_balance_for_payment};
-use common::membership::{MemberOriginValidator, MembershipInfoProvider};
+use common::membership::{
+    MemberOriginValidator, MemberReputationHandler, MembershipInfoProvider, Reputation,
+    ReputationActivity,
+};
 use common::to_kb;
 use common::working_group::{WorkingGroupAuthenticator, WorkingGroupBudgetHandler};
 use staking_handler::StakingHandler;
@@ -145,8 +183,58 @@ pub trait Config:
 
     /// Default number of invites a paid membership recieves.
     type DefaultMemberInvitesCount: Get<u32>;
+
+    /// Reputation changes applied on member activities.
+    type MemberReputationRules: Get<ReputationRules>;
+
+    /// Maximum number of guardians of the member root account recovery.
+    type MaxRecoveryGuardians: Get<u32>;
+
+    /// Number of blocks between the recovery initiation and its finalization.
+    type RecoveryDelay: Get<Self::BlockNumber>;
+
+    /// Deposit reserved from the guardian initiating the recovery. Slashed if the recovery
+    /// gets cancelled.
+    type RecoveryDeposit: Get<BalanceOf<Self>>;
+
+    /// Number of blocks without the handle renewal after which the member handle can be
+    /// claimed by another member. Zero disables the handle expiry.
+    type HandleExpiryPeriod: Get<Self::BlockNumber>;
+
+    /// Number of blocks since the handle expiry introduction after which the handles of the
+    /// members created before it can be claimed, unless renewed.
+    type HandleExpiryGracePeriod: Get<Self::BlockNumber>;
+
+    /// Maximum number of the upline referrer levels rewarded on buying the membership
+    /// (besides the direct referrer).
+    type MaxUplineReferralLevels: Get<u32>;
 }
 
+/// Referral cut percent values of the upline referrers, starting from the referrer of
+/// the direct referrer.
+pub type UplineReferralCuts<T> = BoundedVec<u8, <T as Config>::MaxUplineReferralLevels>;
+
+/// Referral bonus campaign alias.
+pub type ReferralCampaignOf<T> =
+    ReferralCampaign<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+/// Guardians of the member root account recovery.
+pub type RecoveryGuardians<T> = BoundedBTreeSet<
+    <T as common::membership::MembershipTypes>::MemberId,
+    <T as Config>::MaxRecoveryGuardians,
+>;
+
+/// Root account recovery configuration alias.
+pub type RecoveryConfig<T> = RecoveryConfigRecord<RecoveryGuardians<T>>;
+
+/// Active root account recovery alias.
+pub type ActiveRecovery<T> = ActiveRecoveryRecord<
+    <T as frame_system::Config>::AccountId,
+    <T as frame_system::Config>::BlockNumber,
+    BalanceOf<T>,
+    RecoveryGuardians<T>,
+>;
+
 /// Public membership profile alias.
 pub type Membership<T> =
     MembershipObject<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::Hash>;
@@ -176,6 +264,105 @@ pub struct MembershipObject<AccountId: Ord, Hash> {
     pub invites: u32,
 }
 
+/// Reputation changes applied on member activities.
+#[derive(Encode, Decode, Default, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct ReputationRules {
+    /// Reputation gained when a work entry of the member wins a bounty.
+    pub successful_bounty_entry_reward: Reputation,
+
+    /// Reputation gained when a proposal created by the member is executed.
+    pub executed_proposal_reward: Reputation,
+
+    /// Reputation gained for each reward period served as a worker.
+    pub worker_tenure_reward: Reputation,
+
+    /// Reputation lost when a moderation action is taken against the member.
+    pub moderation_action_penalty: Reputation,
+}
+
+impl ReputationRules {
+    // Applies the reputation change defined for the activity.
+    fn apply(&self, reputation: Reputation, activity: ReputationActivity) -> Reputation {
+        match activity {
+            ReputationActivity::SuccessfulBountyEntry => {
+                reputation.saturating_add(self.successful_bounty_entry_reward)
+            }
+            ReputationActivity::ProposalExecuted => {
+                reputation.saturating_add(self.executed_proposal_reward)
+            }
+            ReputationActivity::WorkerTenure => {
+                reputation.saturating_add(self.worker_tenure_reward)
+            }
+            ReputationActivity::ModerationAction => {
+                reputation.saturating_sub(self.moderation_action_penalty)
+            }
+        }
+    }
+}
+
+/// Social recovery configuration of the member root account.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct RecoveryConfigRecord<RecoveryGuardians> {
+    /// Members allowed to initiate and approve the recovery.
+    pub guardians: RecoveryGuardians,
+
+    /// Number of guardian approvals required to finalize the recovery.
+    pub threshold: u32,
+}
+
+/// Root account recovery in progress.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct ActiveRecoveryRecord<AccountId, BlockNumber, Balance, RecoveryGuardians> {
+    /// Root account to be set on the recovery finalization.
+    pub new_root_account: AccountId,
+
+    /// Account the recovery deposit was reserved from.
+    pub depositor: AccountId,
+
+    /// Reserved recovery deposit.
+    pub deposit: Balance,
+
+    /// Block at which the recovery was initiated.
+    pub initiated_at: BlockNumber,
+
+    /// Guardians that approved the recovery (including the initiator).
+    pub approvals: RecoveryGuardians,
+}
+
+/// Successful referrals of the member.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct ReferralStats {
+    /// Number of memberships bought with the member as the direct referrer.
+    pub direct_referrals: u32,
+
+    /// Number of memberships bought with the member as an upline referrer.
+    pub indirect_referrals: u32,
+}
+
+/// Time-boxed referral bonus campaign paid from the membership working group budget.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct ReferralCampaign<Balance, BlockNumber> {
+    /// Bonus paid to the direct referrer for each membership bought during the campaign.
+    pub bonus: Balance,
+
+    /// Block at which the campaign ends.
+    pub ends_at: BlockNumber,
+}
+
+/// Member handle sale offer.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct HandleSaleOffer<MemberId, Balance> {
+    /// Price paid by the buyer to the seller.
+    pub price: Balance,
+
+    /// Member allowed to buy the handle. Any member can buy the handle if not set.
+    pub buyer_id: Option<MemberId>,
+}
+
+/// Member handle sale offer alias.
+pub type HandleSaleOfferOf<T> =
+    HandleSaleOffer<<T as common::membership::MembershipTypes>::MemberId, BalanceOf<T>>;
+
 // Contain staking account to member binding and its confirmation.
 #[derive(Encode, Decode, Default, Debug, PartialEq, TypeInfo, MaxEncodedLen)]
 pub struct StakingAccountMemberBinding<MemberId> {
@@ -234,6 +421,16 @@ pub struct GiftMembershipParameters<AccountId, Balance> {
     pub apply_root_account_invitation_lock: Option<Balance>,
 }
 
+/// Parameters for the set_recovery_config extrinsic.
+#[derive(Encode, Decode, Clone, PartialEq, Debug, Eq, TypeInfo)]
+pub struct RecoveryConfigParameters<MemberId: Ord> {
+    /// Members allowed to initiate and approve the recovery.
+    pub guardians: BTreeSet<MemberId>,
+
+    /// Number of guardian approvals required to finalize the recovery.
+    pub threshold: u32,
+}
+
 /// Parameters for the invite_member extrinsic.
 #[derive(Encode, Decode, Clone, PartialEq, Debug, Eq, TypeInfo)]
 pub struct InviteMembershipParameters<AccountId, MemberId> {
@@ -338,6 +535,66 @@ decl_error! {
 
         /// Gifter doesn't have sufficient balance to credit
         InsufficientBalanceToGift,
+
+        /// Recovery requires at least one guardian.
+        NoRecoveryGuardians,
+
+        /// Number of recovery guardians exceeds the limit.
+        MaxRecoveryGuardiansExceeded,
+
+        /// Member cannot be its own recovery guardian.
+        CannotBeOwnRecoveryGuardian,
+
+        /// Recovery guardian is not a member.
+        RecoveryGuardianIsNotMember,
+
+        /// Recovery threshold should be between one and the number of guardians.
+        InvalidRecoveryThreshold,
+
+        /// Recovery is not configured for the member.
+        RecoveryNotConfigured,
+
+        /// Member is not a recovery guardian.
+        NotRecoveryGuardian,
+
+        /// Recovery is already in progress.
+        RecoveryInProgress,
+
+        /// No recovery in progress.
+        NoRecoveryInProgress,
+
+        /// Guardian has already approved the recovery.
+        RecoveryAlreadyApproved,
+
+        /// Not enough guardians approved the recovery.
+        InsufficientRecoveryApprovals,
+
+        /// Recovery delay has not passed yet.
+        RecoveryDelayNotPassed,
+
+        /// Insufficient balance to cover the recovery deposit.
+        InsufficientBalanceForRecoveryDeposit,
+
+        /// Member handle is not offered for sale.
+        HandleSaleOfferNotFound,
+
+        /// Member handle sale offer is restricted to another buyer.
+        HandleSaleRestrictedToAnotherBuyer,
+
+        /// Provided price doesn't match the member handle sale offer price.
+        HandleSalePriceMismatch,
+
+        /// Member cannot buy its own handle.
+        CannotBuyOwnHandle,
+
+        /// Insufficient balance to buy the member handle.
+        InsufficientBalanceToBuyHandle,
+
+        /// Number of the upline referral cuts exceeds the maximum number of upline levels.
+        MaxUplineReferralLevelsExceeded,
+
+        /// Referral campaign must end in the future.
+        InvalidReferralCampaignEnd,
     }
 }
 
@@ -351,6 +608,10 @@ decl_storage! { generate_storage_info
         pub MembershipById get(fn membership) : map hasher(blake2_128_concat)
             T::MemberId => Option<Membership<T>>;
 
+        /// Block at which the membership was created.
+        pub MembershipCreatedAt get(fn membership_created_at) : map hasher(blake2_128_concat)
+            T::MemberId => T::BlockNumber;
+
         /// Registered unique handles hash and their mapping to their owner.
         pub MemberIdByHandleHash get(fn handles) : map hasher(blake2_128_concat)
             T::Hash => T::MemberId;
@@ -372,6 +633,45 @@ decl_storage! { generate_storage_info
         /// Double of a staking account id and member id to the confirmation status.
         pub(crate) StakingAccountIdMemberStatus get(fn staking_account_id_member_status):
             map hasher(blake2_128_concat) T::AccountId => StakingAccountMemberBinding<T::MemberId>;
+
+        /// Reputation score of the member derived from the on-chain activity.
+        pub MemberReputation get(fn member_reputation) : map hasher(blake2_128_concat)
+            T::MemberId => Reputation;
+
+        /// Root account recovery configuration of the member.
+        pub RecoveryConfigByMemberId get(fn recovery_config) : map hasher(blake2_128_concat)
+            T::MemberId => Option<RecoveryConfig<T>>;
+
+        /// Root account recovery in progress for the member.
+        pub ActiveRecoveryByMemberId get(fn active_recovery) : map hasher(blake2_128_concat)
+            T::MemberId => Option<ActiveRecovery<T>>;
+
+        /// Member handle sale offers.
+        pub HandleSaleOfferByMemberId get(fn handle_sale_offer) : map hasher(blake2_128_concat)
+            T::MemberId => Option<HandleSaleOfferOf<T>>;
+
+        /// Block at which the member handle was last renewed. Membership creation block is used
+        /// if the handle was never renewed.
+        pub HandleRenewedAt get(fn handle_renewed_at) : map hasher(blake2_128_concat)
+            T::MemberId => T::BlockNumber;
+
+        /// Block at which the handle expiry was introduced. Members created before it have
+        /// neither the membership creation nor the handle renewal block recorded.
+        pub HandleExpiryIntroducedAt get(fn handle_expiry_introduced_at): T::BlockNumber;
+
+        /// Direct referrer of the member.
+        pub ReferrerByMemberId get(fn referrer) : map hasher(blake2_128_concat)
+            T::MemberId => Option<T::MemberId>;
+
+        /// Successful referrals of the member.
+        pub ReferralStatsByMemberId get(fn referral_stats) : map hasher(blake2_128_concat)
+            T::MemberId => ReferralStats;
+
+        /// Referral cut percent values of the upline referrers.
+        pub UplineReferralCutsPercent get(fn upline_referral_cuts) : UplineReferralCuts<T>;
+
+        /// Referral bonus campaign in progress.
+        pub ActiveReferralCampaign get(fn referral_campaign) : Option<ReferralCampaignOf<T>>;
     }
     add_extra_genesis {
         build(|_| {
@@ -385,6 +685,7 @@ decl_event! {
         <T as common::membership::MembershipTypes>::MemberId,
         Balance = BalanceOf<T>,
         <T as frame_system::Config>::AccountId,
+        <T as frame_system::Config>::BlockNumber,
         BuyMembershipParameters = BuyMembershipParameters<
             <T as frame_system::Config>::AccountId,
             <T as common::membership::MembershipTypes>::MemberId,
@@ -401,6 +702,10 @@ decl_event! {
         <T as frame_system::Config>::AccountId,
         BalanceOf<T>,
       >,
+        RecoveryConfigParameters = RecoveryConfigParameters<
+            <T as common::membership::MembershipTypes>::MemberId,
+        >,
+        ReferralCampaign = ReferralCampaignOf<T>,
     {
         MemberInvited(MemberId, InviteMembershipParameters),
         MembershipGifted(MemberId, GiftMembershipParameters),
@@ -423,6 +728,59 @@ decl_event! {
         StakingAccountConfirmed(AccountId, MemberId),
         MemberRemarked(MemberId, Vec<u8>),
         MemberCreated(MemberId, CreateMemberParameters, u32),
+        MemberReputationUpdated(MemberId, ReputationActivity, Reputation),
+        RecoveryConfigUpdated(MemberId, Option<RecoveryConfigParameters>),
+        RecoveryInitiated(MemberId, MemberId, AccountId),
+        RecoveryApproved(MemberId, MemberId),
+        RecoveryFinalized(MemberId, AccountId),
+        RecoveryCancelled(MemberId),
+
+        /// Emits on listing the member handle for sale.
+        /// Params:
+        /// - seller member id
+        /// - price
+        /// - buyer member id (if the sale is restricted to the buyer)
+        HandleOfferedForSale(MemberId, Balance, Option<MemberId>),
+
+        /// Emits on cancelling the member handle sale offer.
+        /// Params:
+        /// - seller member id
+        HandleSaleCancelled(MemberId),
+
+        /// Emits on buying the member handle. Buyer and seller handles were swapped.
+        /// Params:
+        /// - seller member id
+        /// - buyer member id
+        /// - price
+        HandleSold(MemberId, MemberId, Balance),
+
+        /// Emits on the member handle renewal.
+        /// Params:
+        /// - member id
+        /// - renewal block
+        HandleRenewed(MemberId, BlockNumber),
+
+        /// Emits on claiming the expired member handle.
+        /// Params:
+        /// - previous handle owner member id
+        /// - new handle owner member id
+        HandleReclaimed(MemberId, MemberId),
+
+        /// Emits on updating the upline referral cuts.
+        /// Params:
+        /// - referral cut percent values of the upline referrers
+        UplineReferralCutsUpdated(Vec<u8>),
+
+        /// Emits on starting or stopping the referral bonus campaign.
+        /// Params:
+        /// - new referral campaign (if started)
+        ReferralCampaignUpdated(Option<ReferralCampaign>),
+
+        /// Emits on paying the referral campaign bonus to the referrer.
+        /// Params:
+        /// - referrer member id
+        /// - bonus
+        ReferralBonusPaid(MemberId, Balance),
     }
 }
 
@@ -452,16 +810,39 @@ decl_module! {
         /// Exports const - staking candidate lock id.
         const StakingCandidateLockId: LockIdentifier = T::StakingCandidateStakingHandler::lock_id();
 
+        /// Exports const - reputation changes applied on member activities.
+        const MemberReputationRules: ReputationRules = T::MemberReputationRules::get();
+
+        /// Exports const - maximum number of the root account recovery guardians.
+        const MaxRecoveryGuardians: u32 = T::MaxRecoveryGuardians::get();
+
+        /// Exports const - number of blocks between the recovery initiation and finalization.
+        const RecoveryDelay: T::BlockNumber = T::RecoveryDelay::get();
+
+        /// Exports const - deposit reserved from the guardian initiating the recovery.
+        const RecoveryDeposit: BalanceOf<T> = T::RecoveryDeposit::get();
+
+        /// Exports const - number of blocks without renewal after which the handle can be claimed.
+        const HandleExpiryPeriod: T::BlockNumber = T::HandleExpiryPeriod::get();
+
+        /// Exports const - number of blocks since the handle expiry introduction after which
+        /// the handles of the members created before it can be claimed.
+        const HandleExpiryGracePeriod: T::BlockNumber = T::HandleExpiryGracePeriod::get();
+
+        /// Exports const - maximum number of the rewarded upline referrer levels.
+        const MaxUplineReferralLevels: u32 = T::MaxUplineReferralLevels::get();
+
         /// Non-members can buy membership.
         ///
         /// <weight>
         ///
         /// ## Weight
-        /// `O (W + M)` where:
+        /// `O (W + M + L)` where:
         /// - `W` is the handle size in kilobytes
         /// - `M` is the metadata size in kilobytes
+        /// - `L` is the maximum number of the rewarded upline referrer levels
         /// - DB:
-        ///    - O(1)
+        ///    - O(L)
         /// # </weight>
         #[weight = Module::<T>::calculate_weight_for_buy_membership(params)]
         pub fn buy_membership(
@@ -505,16 +886,9 @@ decl_module! {
             // Collect membership fee (just burn it).
             burn_from_usable::<T>(&who, fee)?;
 
-            // Reward the referring member.
-            if let Some(referrer) = referrer {
-                let referral_cut: BalanceOf<T> = Self::get_referral_bonus();
-
-                if referral_cut > Zero::zero() {
-                    let _ = balances::Pallet::<T>::deposit_creating(
-                        &referrer.controller_account,
-                        referral_cut
-                    );
-                }
+            // Reward the referring member and its upline.
+            if let Some((referrer_id, referrer)) = params.referrer_id.zip(referrer) {
+                Self::reward_referrers(member_id, referrer_id, referrer);
             }
 
             // Fire the event.
@@ -554,26 +928,36 @@ decl_module! {
 
             let membership = Self::ensure_membership(member_id)?;
 
-            let new_handle_hash = handle.clone()
-                .map(|handle| Self::get_handle_hash(&Some(handle)))
+            let new_handle = handle
+                .as_ref()
+                .map(|handle| Self::ensure_handle_can_be_claimed(&member_id, handle))
                 .transpose()?;
 
             //
             // == MUTATION SAFE ==
             //
 
-            if let Some(new_handle_hash) = new_handle_hash {
+            if let Some((new_handle_hash, expired_handle_owner)) = new_handle {
+                if let Some(expired_handle_owner_id) = expired_handle_owner {
+                    Self::reclaim_expired_handle(expired_handle_owner_id, new_handle_hash);
+
+                    Self::deposit_event(
+                        RawEvent::HandleReclaimed(expired_handle_owner_id, member_id)
+                    );
+                }
+
                 // remove old handle hash
                 <MemberIdByHandleHash<T>>::remove(&membership.handle_hash);
 
-                <MemberIdByHandleHash<T>>::insert(new_handle_hash, member_id);
+                // the sold handle is no longer owned by the member
+                <HandleSaleOfferByMemberId<T>>::remove(member_id);
 
-                <MembershipById<T>>::insert(&member_id, Membership::<T> {
-                    handle_hash: new_handle_hash,
-                    ..membership
-                });
+                Self::set_member_handle(member_id, membership, new_handle_hash);
             }
 
+            // Profile update counts as the member activity
+            <HandleRenewedAt<T>>::insert(member_id, frame_system::Pallet::<T>::block_number());
+
             Self::deposit_event(RawEvent::MemberProfileUpdated(member_id, handle, metadata));
         }
 
@@ -669,10 +1053,10 @@ decl_module! {
         pub fn set_referral_cut(origin, percent_value: u8) {
             ensure_root(origin)?;
 
-            ensure!(
-                percent_value <= T::ReferralCutMaximumPercent::get(),
-                Error::<T>::CannotExceedReferralCutPercentLimit
-            );
+            Self::ensure_total_referral_cut_within_limit(
+                percent_value,
+                &Self::upline_referral_cuts(),
+            )?;
 
             //
             // == MUTATION SAFE ==
@@ -1236,6 +1620,466 @@ decl_module! {
                 RawEvent::MemberCreated(member_id, params, initial_invitation_count)
             );
         }
+
+        /// Sets guardians and threshold for the member root account recovery.
+        /// Removes the recovery configuration if `params` is `None`.
+        /// Requires the member root account origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (G)` where:
+        /// - `G` is the number of guardians
+        /// - DB:
+        ///    - O(G)
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::set_recovery_config(
+            params.as_ref().map_or(0, |params| params.guardians.len().saturated_into()),
+        )]
+        pub fn set_recovery_config(
+            origin,
+            member_id: T::MemberId,
+            params: Option<RecoveryConfigParameters<T::MemberId>>,
+        ) {
+            let sender = ensure_signed(origin)?;
+            let membership = Self::ensure_membership(member_id)?;
+
+            ensure!(membership.root_account == sender, Error::<T>::RootAccountRequired);
+
+            ensure!(
+                !<ActiveRecoveryByMemberId<T>>::contains_key(member_id),
+                Error::<T>::RecoveryInProgress
+            );
+
+            let recovery_config = params
+                .as_ref()
+                .map(|params| Self::ensure_valid_recovery_config(&member_id, params))
+                .transpose()?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            match recovery_config {
+                Some(recovery_config) => {
+                    <RecoveryConfigByMemberId<T>>::insert(member_id, recovery_config)
+                }
+                None => <RecoveryConfigByMemberId<T>>::remove(member_id),
+            }
+
+            Self::deposit_event(RawEvent::RecoveryConfigUpdated(member_id, params));
+        }
+
+        /// Guardian initiates the member root account recovery to the new root account.
+        /// Reserves the recovery deposit from the guardian controller account.
+        /// Requires the guardian controller account origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::initiate_recovery()]
+        pub fn initiate_recovery(
+            origin,
+            guardian_id: T::MemberId,
+            member_id: T::MemberId,
+            new_root_account: T::AccountId,
+        ) {
+            let guardian = Self::ensure_member_controller_account_origin_signed(origin, &guardian_id)?;
+
+            Self::ensure_membership(member_id)?;
+
+            Self::ensure_recovery_guardian(&member_id, &guardian_id)?;
+
+            ensure!(
+                !<ActiveRecoveryByMemberId<T>>::contains_key(member_id),
+                Error::<T>::RecoveryInProgress
+            );
+
+            let deposit = T::RecoveryDeposit::get();
+            let depositor = guardian.controller_account;
+
+            ensure!(
+                balances::Pallet::<T>::can_reserve(&depositor, deposit),
+                Error::<T>::InsufficientBalanceForRecoveryDeposit
+            );
+
+            let mut approvals = RecoveryGuardians::<T>::default();
+            approvals
+                .try_insert(guardian_id)
+                .map_err(|_| Error::<T>::MaxRecoveryGuardiansExceeded)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            balances::Pallet::<T>::reserve(&depositor, deposit)?;
+
+            <ActiveRecoveryByMemberId<T>>::insert(member_id, ActiveRecovery::<T> {
+                new_root_account: new_root_account.clone(),
+                depositor,
+                deposit,
+                initiated_at: frame_system::Pallet::<T>::block_number(),
+                approvals,
+            });
+
+            Self::deposit_event(
+                RawEvent::RecoveryInitiated(member_id, guardian_id, new_root_account)
+            );
+        }
+
+        /// Guardian approves the member root account recovery in progress.
+        /// Requires the guardian controller account origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::approve_recovery()]
+        pub fn approve_recovery(origin, guardian_id: T::MemberId, member_id: T::MemberId) {
+            Self::ensure_member_controller_account_origin_signed(origin, &guardian_id)?;
+
+            Self::ensure_recovery_guardian(&member_id, &guardian_id)?;
+
+            let mut active_recovery = Self::ensure_active_recovery(&member_id)?;
+
+            ensure!(
+                !active_recovery.approvals.contains(&guardian_id),
+                Error::<T>::RecoveryAlreadyApproved
+            );
+
+            active_recovery
+                .approvals
+                .try_insert(guardian_id)
+                .map_err(|_| Error::<T>::MaxRecoveryGuardiansExceeded)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <ActiveRecoveryByMemberId<T>>::insert(member_id, active_recovery);
+
+            Self::deposit_event(RawEvent::RecoveryApproved(member_id, guardian_id));
+        }
+
+        /// Sets the new root account of the member once the recovery is approved by
+        /// the threshold of guardians and the recovery delay has passed.
+        /// Unreserves the recovery deposit. Requires a signed origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::finalize_recovery()]
+        pub fn finalize_recovery(origin, member_id: T::MemberId) {
+            ensure_signed(origin)?;
+
+            let membership = Self::ensure_membership(member_id)?;
+
+            let active_recovery = Self::ensure_active_recovery(&member_id)?;
+
+            let recovery_config = Self::recovery_config(member_id)
+                .ok_or(Error::<T>::RecoveryNotConfigured)?;
+
+            ensure!(
+                active_recovery.approvals.len() >= recovery_config.threshold.saturated_into(),
+                Error::<T>::InsufficientRecoveryApprovals
+            );
+
+            let recoverable_at = active_recovery
+                .initiated_at
+                .saturating_add(T::RecoveryDelay::get());
+
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= recoverable_at,
+                Error::<T>::RecoveryDelayNotPassed
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            balances::Pallet::<T>::unreserve(&active_recovery.depositor, active_recovery.deposit);
+
+            <ActiveRecoveryByMemberId<T>>::remove(member_id);
+
+            <MembershipById<T>>::insert(member_id, Membership::<T> {
+                root_account: active_recovery.new_root_account.clone(),
+                ..membership
+            });
+
+            Self::deposit_event(
+                RawEvent::RecoveryFinalized(member_id, active_recovery.new_root_account)
+            );
+        }
+
+        /// Cancels the member root account recovery in progress and slashes the recovery
+        /// deposit. Requires the current member root account origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::cancel_recovery()]
+        pub fn cancel_recovery(origin, member_id: T::MemberId) {
+            let sender = ensure_signed(origin)?;
+            let membership = Self::ensure_membership(member_id)?;
+
+            ensure!(membership.root_account == sender, Error::<T>::RootAccountRequired);
+
+            let active_recovery = Self::ensure_active_recovery(&member_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let _ = balances::Pallet::<T>::slash_reserved(
+                &active_recovery.depositor,
+                active_recovery.deposit
+            );
+
+            <ActiveRecoveryByMemberId<T>>::remove(member_id);
+
+            Self::deposit_event(RawEvent::RecoveryCancelled(member_id));
+        }
+
+        /// Lists the member handle for sale for the price. Replaces the existing offer.
+        /// The sale can be restricted to a single buyer.
+        /// Requires the member controller account origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::offer_handle_for_sale()]
+        pub fn offer_handle_for_sale(
+            origin,
+            member_id: T::MemberId,
+            price: BalanceOf<T>,
+            buyer_id: Option<T::MemberId>,
+        ) {
+            Self::ensure_member_controller_account_origin_signed(origin, &member_id)?;
+
+            if let Some(buyer_id) = buyer_id {
+                ensure!(buyer_id != member_id, Error::<T>::CannotBuyOwnHandle);
+
+                Self::ensure_membership(buyer_id)?;
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <HandleSaleOfferByMemberId<T>>::insert(member_id, HandleSaleOffer { price, buyer_id });
+
+            Self::deposit_event(RawEvent::HandleOfferedForSale(member_id, price, buyer_id));
+        }
+
+        /// Removes the member handle sale offer.
+        /// Requires the member controller account origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::cancel_handle_sale()]
+        pub fn cancel_handle_sale(origin, member_id: T::MemberId) {
+            Self::ensure_member_controller_account_origin_signed(origin, &member_id)?;
+
+            ensure!(
+                <HandleSaleOfferByMemberId<T>>::contains_key(member_id),
+                Error::<T>::HandleSaleOfferNotFound
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <HandleSaleOfferByMemberId<T>>::remove(member_id);
+
+            Self::deposit_event(RawEvent::HandleSaleCancelled(member_id));
+        }
+
+        /// Buys the offered member handle: the buyer and the seller handles are swapped
+        /// atomically and the price is transferred from the buyer controller account
+        /// to the seller controller account. The expected price protects the buyer from
+        /// the offer changes. Requires the buyer controller account origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::buy_handle()]
+        pub fn buy_handle(
+            origin,
+            buyer_id: T::MemberId,
+            seller_id: T::MemberId,
+            price: BalanceOf<T>,
+        ) {
+            let buyer = Self::ensure_member_controller_account_origin_signed(origin, &buyer_id)?;
+
+            ensure!(buyer_id != seller_id, Error::<T>::CannotBuyOwnHandle);
+
+            let seller = Self::ensure_membership(seller_id)?;
+
+            let offer = Self::handle_sale_offer(seller_id)
+                .ok_or(Error::<T>::HandleSaleOfferNotFound)?;
+
+            ensure!(
+                offer.buyer_id.map_or(true, |offer_buyer_id| offer_buyer_id == buyer_id),
+                Error::<T>::HandleSaleRestrictedToAnotherBuyer
+            );
+
+            ensure!(offer.price == price, Error::<T>::HandleSalePriceMismatch);
+
+            ensure!(
+                has_sufficient_balance_for_payment::<T>(&buyer.controller_account, price),
+                Error::<T>::InsufficientBalanceToBuyHandle
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <balances::Pallet<T> as Currency<T::AccountId>>::transfer(
+                &buyer.controller_account,
+                &seller.controller_account,
+                price,
+                ExistenceRequirement::KeepAlive,
+            )?;
+
+            <HandleSaleOfferByMemberId<T>>::remove(seller_id);
+            <HandleSaleOfferByMemberId<T>>::remove(buyer_id);
+
+            let buyer_handle_hash = buyer.handle_hash;
+            let seller_handle_hash = seller.handle_hash;
+
+            Self::set_member_handle(buyer_id, buyer, seller_handle_hash);
+            Self::set_member_handle(seller_id, seller, buyer_handle_hash);
+
+            let now = frame_system::Pallet::<T>::block_number();
+            <HandleRenewedAt<T>>::insert(buyer_id, now);
+            <HandleRenewedAt<T>>::insert(seller_id, now);
+
+            Self::deposit_event(RawEvent::HandleSold(seller_id, buyer_id, price));
+        }
+
+        /// Renews the member handle preventing it from being claimed by another member.
+        /// Requires the member controller account origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::renew_handle()]
+        pub fn renew_handle(origin, member_id: T::MemberId) {
+            Self::ensure_member_controller_account_origin_signed(origin, &member_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let now = frame_system::Pallet::<T>::block_number();
+
+            <HandleRenewedAt<T>>::insert(member_id, now);
+
+            Self::deposit_event(RawEvent::HandleRenewed(member_id, now));
+        }
+
+        /// Updates the referral cut percent values of the upline referrers: the first value
+        /// is paid to the referrer of the direct referrer and so on. The total referral cut
+        /// cannot exceed the `ReferralCutMaximumPercent`. Requires root origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (L)` where:
+        /// - `L` is the number of upline levels
+        /// - DB:
+        ///    - O(1)
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::set_upline_referral_cuts(
+            cuts.len().saturated_into(),
+        )]
+        pub fn set_upline_referral_cuts(origin, cuts: Vec<u8>) {
+            ensure_root(origin)?;
+
+            let upline_referral_cuts: UplineReferralCuts<T> = cuts
+                .clone()
+                .try_into()
+                .map_err(|_| Error::<T>::MaxUplineReferralLevelsExceeded)?;
+
+            Self::ensure_total_referral_cut_within_limit(
+                Self::referral_cut(),
+                &upline_referral_cuts,
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <UplineReferralCutsPercent<T>>::put(upline_referral_cuts);
+
+            Self::deposit_event(RawEvent::UplineReferralCutsUpdated(cuts));
+        }
+
+        /// Starts the referral bonus campaign (replacing the current one) or stops it
+        /// if `campaign` is `None`. Campaign bonuses are paid from the membership
+        /// working group budget. Requires the membership working group leader origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::set_referral_campaign()]
+        pub fn set_referral_campaign(origin, campaign: Option<ReferralCampaignOf<T>>) {
+            T::WorkingGroup::ensure_leader_origin(origin)?;
+
+            if let Some(campaign) = campaign.as_ref() {
+                ensure!(
+                    campaign.ends_at > frame_system::Pallet::<T>::block_number(),
+                    Error::<T>::InvalidReferralCampaignEnd
+                );
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            match campaign.clone() {
+                Some(campaign) => <ActiveReferralCampaign<T>>::put(campaign),
+                None => <ActiveReferralCampaign<T>>::kill(),
+            }
+
+            Self::deposit_event(RawEvent::ReferralCampaignUpdated(campaign));
+        }
     }
 }
 
@@ -1262,6 +2106,7 @@ impl<T: Config> Module<T> {
             WeightInfoMembership::<T>::buy_membership_with_referrer(
                 to_kb(Self::text_length_unwrap_or_default(&params.handle)),
                 to_kb(params.metadata.len().saturated_into()),
+                T::MaxUplineReferralLevels::get(),
             )
         } else {
             WeightInfoMembership::<T>::buy_membership_without_referrer(
@@ -1317,6 +2162,102 @@ impl<T: Config> Module<T> {
         Ok(hash)
     }
 
+    // Validate the new handle of the member and return its hash. The handle must not be
+    // registered unless it has expired. Returns the expired handle owner id in the latter case.
+    fn ensure_handle_can_be_claimed(
+        member_id: &T::MemberId,
+        handle: &[u8],
+    ) -> Result<(T::Hash, Option<T::MemberId>), Error<T>> {
+        ensure!(
+            !handle.is_empty(),
+            Error::<T>::HandleMustBeProvidedDuringRegistration
+        );
+
+        let hash = T::Hashing::hash(handle);
+
+        if !<MemberIdByHandleHash<T>>::contains_key(&hash) {
+            return Ok((hash, None));
+        }
+
+        let owner_id = Self::handles(hash);
+
+        ensure!(
+            owner_id != *member_id
+                && Self::is_handle_expired(&owner_id)
+                && hash != Self::reclaimed_handle_hash(&owner_id),
+            Error::<T>::HandleAlreadyRegistered
+        );
+
+        Ok((hash, Some(owner_id)))
+    }
+
+    /// Verifies whether the member handle was not renewed for the `HandleExpiryPeriod`.
+    pub fn is_handle_expired(member_id: &T::MemberId) -> bool {
+        let expiry_period = T::HandleExpiryPeriod::get();
+
+        if expiry_period.is_zero() {
+            return false;
+        }
+
+        let expires_at = if <HandleRenewedAt<T>>::contains_key(member_id)
+            || <MembershipCreatedAt<T>>::contains_key(member_id)
+        {
+            Self::handle_renewed_at(member_id)
+                .max(Self::membership_created_at(member_id))
+                .saturating_add(expiry_period)
+        } else {
+            // Member was created before the handle expiry was introduced.
+            Self::handle_expiry_introduced_at().saturating_add(T::HandleExpiryGracePeriod::get())
+        };
+
+        frame_system::Pallet::<T>::block_number() >= expires_at
+    }
+
+    /// Records the handle expiry introduction block to start the grace period for the handles
+    /// of the existing members. Must be called on the runtime upgrade introducing the handle
+    /// expiry, subsequent calls have no effect.
+    pub fn introduce_handle_expiry() -> Weight {
+        if Self::handle_expiry_introduced_at().is_zero() {
+            <HandleExpiryIntroducedAt<T>>::put(frame_system::Pallet::<T>::block_number());
+
+            T::DbWeight::get().reads_writes(1, 1)
+        } else {
+            T::DbWeight::get().reads(1)
+        }
+    }
+
+    // Placeholder handle hash assigned to the member whose expired handle was claimed.
+    fn reclaimed_handle_hash(member_id: &T::MemberId) -> T::Hash {
+        T::Hashing::hash_of(&(b"reclaimed-handle", member_id))
+    }
+
+    // Replaces the expired handle of the member with the placeholder handle.
+    fn reclaim_expired_handle(member_id: T::MemberId, handle_hash: T::Hash) {
+        <MemberIdByHandleHash<T>>::remove(&handle_hash);
+        <HandleSaleOfferByMemberId<T>>::remove(member_id);
+
+        if let Some(membership) = Self::membership(member_id) {
+            Self::set_member_handle(
+                member_id,
+                membership,
+                Self::reclaimed_handle_hash(&member_id),
+            );
+        }
+    }
+
+    // Sets the new handle hash of the member. The old handle hash mapping is not removed.
+    fn set_member_handle(member_id: T::MemberId, membership: Membership<T>, handle_hash: T::Hash) {
+        <MemberIdByHandleHash<T>>::insert(handle_hash, member_id);
+
+        <MembershipById<T>>::insert(
+            &member_id,
+            Membership::<T> {
+                handle_hash,
+                ..membership
+            },
+        );
+    }
+
     // Inserts a member using a validated information. Sets handle, accounts caches, etc..
     fn insert_member(
         root_account: &T::AccountId,
@@ -1337,6 +2278,7 @@ impl<T: Config> Module<T> {
 
         <MembershipById<T>>::insert(new_member_id, membership);
         <MemberIdByHandleHash<T>>::insert(handle_hash, new_member_id);
+        <MembershipCreatedAt<T>>::insert(new_member_id, frame_system::Pallet::<T>::block_number());
 
         <NextMemberId<T>>::put(new_member_id + One::one());
 
@@ -1369,6 +2311,137 @@ impl<T: Config> Module<T> {
         Ok(membership)
     }
 
+    // Validate recovery configuration parameters and return the recovery configuration.
+    fn ensure_valid_recovery_config(
+        member_id: &T::MemberId,
+        params: &RecoveryConfigParameters<T::MemberId>,
+    ) -> Result<RecoveryConfig<T>, Error<T>> {
+        ensure!(
+            !params.guardians.is_empty(),
+            Error::<T>::NoRecoveryGuardians
+        );
+
+        let guardians: RecoveryGuardians<T> = params
+            .guardians
+            .clone()
+            .try_into()
+            .map_err(|_| Error::<T>::MaxRecoveryGuardiansExceeded)?;
+
+        ensure!(
+            !guardians.contains(member_id),
+            Error::<T>::CannotBeOwnRecoveryGuardian
+        );
+
+        for guardian_id in guardians.iter() {
+            Self::ensure_membership_with_error(
+                *guardian_id,
+                Error::<T>::RecoveryGuardianIsNotMember,
+            )?;
+        }
+
+        ensure!(
+            params.threshold > 0 && params.threshold as usize <= guardians.len(),
+            Error::<T>::InvalidRecoveryThreshold
+        );
+
+        Ok(RecoveryConfig::<T> {
+            guardians,
+            threshold: params.threshold,
+        })
+    }
+
+    // Ensure the member is a recovery guardian of the recovered member.
+    fn ensure_recovery_guardian(
+        member_id: &T::MemberId,
+        guardian_id: &T::MemberId,
+    ) -> Result<RecoveryConfig<T>, Error<T>> {
+        let recovery_config =
+            Self::recovery_config(member_id).ok_or(Error::<T>::RecoveryNotConfigured)?;
+
+        ensure!(
+            recovery_config.guardians.contains(guardian_id),
+            Error::<T>::NotRecoveryGuardian
+        );
+
+        Ok(recovery_config)
+    }
+
+    // Ensure the recovery is in progress for the member and return it.
+    fn ensure_active_recovery(member_id: &T::MemberId) -> Result<ActiveRecovery<T>, Error<T>> {
+        Self::active_recovery(member_id).ok_or(Error::<T>::NoRecoveryInProgress)
+    }
+
+    // Ensure the total referral cut of the direct referrer and the upline doesn't exceed
+    // the limit.
+    fn ensure_total_referral_cut_within_limit(
+        referral_cut: u8,
+        upline_referral_cuts: &[u8],
+    ) -> Result<(), Error<T>> {
+        let total_referral_cut = upline_referral_cuts
+            .iter()
+            .fold(u32::from(referral_cut), |total, cut| {
+                total.saturating_add((*cut).into())
+            });
+
+        ensure!(
+            total_referral_cut <= T::ReferralCutMaximumPercent::get().into(),
+            Error::<T>::CannotExceedReferralCutPercentLimit
+        );
+
+        Ok(())
+    }
+
+    // Records the referrer of the new member, updates the referral statistics and pays
+    // the referral rewards to the direct referrer and its upline.
+    fn reward_referrers(member_id: T::MemberId, referrer_id: T::MemberId, referrer: Membership<T>) {
+        <ReferrerByMemberId<T>>::insert(member_id, referrer_id);
+
+        <ReferralStatsByMemberId<T>>::mutate(referrer_id, |stats| {
+            stats.direct_referrals = stats.direct_referrals.saturating_add(1);
+        });
+
+        let referral_cut: BalanceOf<T> = Self::get_referral_bonus();
+
+        if referral_cut > Zero::zero() {
+            let _ =
+                balances::Pallet::<T>::deposit_creating(&referrer.controller_account, referral_cut);
+        }
+
+        if let Some(campaign) = Self::referral_campaign() {
+            if frame_system::Pallet::<T>::block_number() < campaign.ends_at
+                && T::WorkingGroup::try_withdraw(&referrer.controller_account, campaign.bonus)
+                    .is_ok()
+            {
+                Self::deposit_event(RawEvent::ReferralBonusPaid(referrer_id, campaign.bonus));
+            }
+        }
+
+        let membership_fee = Self::membership_price();
+        let mut upline_id = referrer_id;
+
+        for cut in Self::upline_referral_cuts().iter() {
+            upline_id = match Self::referrer(upline_id) {
+                Some(upline_id) => upline_id,
+                None => break,
+            };
+
+            <ReferralStatsByMemberId<T>>::mutate(upline_id, |stats| {
+                stats.indirect_referrals = stats.indirect_referrals.saturating_add(1);
+            });
+
+            let upline_referral_cut = Perbill::from_percent((*cut).into()) * membership_fee;
+
+            if let Some(upline) = Self::membership(upline_id) {
+                if upline_referral_cut > Zero::zero() {
+                    let _ = balances::Pallet::<T>::deposit_creating(
+                        &upline.controller_account,
+                        upline_referral_cut,
+                    );
+                }
+            }
+        }
+    }
+
     // Calculate current referral bonus as a percent of the membership fee.
     pub(crate) fn get_referral_bonus() -> BalanceOf<T> {
         let membership_fee = Self::membership_price();
@@ -1449,3 +2522,34 @@ impl<T: Config> MembershipInfoProvider<T> for Module<T> {
         Ok(membership.controller_account)
     }
 }
+
+impl<T: Config> MemberReputationHandler<T> for Module<T> {
+    fn reputation(member_id: &T::MemberId) -> Reputation {
+        Self::member_reputation(member_id)
+    }
+
+    fn record_activity(member_id: &T::MemberId, activity: ReputationActivity) {
+        if !<MembershipById<T>>::contains_key(member_id) {
+            return;
+        }
+
+        let old_reputation = Self::member_reputation(member_id);
+        let new_reputation = T::MemberReputationRules::get().apply(old_reputation, activity);
+
+        if new_reputation == old_reputation {
+            return;
+        }
+
+        <MemberReputation<T>>::insert(member_id, new_reputation);
+
+        Self::deposit_event(RawEvent::MemberReputationUpdated(
+            *member_id,
+            activity,
+            new_reputation,
+        ));
+    }
+
+    fn record_activity_weight() -> Weight {
+        WeightInfoMembership::<T>::record_activity()
+    }
+}
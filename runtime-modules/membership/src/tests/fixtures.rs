@@ -2,13 +2,14 @@ use super::mock::*;
 use crate::Event as MembershipEvent;
 use crate::{
     BalanceOf, BuyMembershipParameters, CreateMemberParameters, GiftMembershipParameters,
-    InviteMembershipParameters, MembershipObject,
+    HandleSaleOffer, InviteMembershipParameters, MembershipObject, RecoveryConfigParameters,
 };
 use frame_support::dispatch::DispatchResult;
 use frame_support::traits::{OnFinalize, OnInitialize, WithdrawReasons};
 use frame_support::{assert_noop, assert_ok, StorageMap};
 use frame_system::{EventRecord, Phase, RawOrigin};
 use sp_runtime::traits::Hash;
+use sp_std::collections::btree_set::BTreeSet;
 use staking_handler::StakingHandler;
 
 // Recommendation from Parity on testing on_finalize
@@ -834,6 +835,17 @@ impl CreateMemberFixture {
         }
     }
 
+    pub fn with_accounts(self, account_id: u64) -> Self {
+        Self {
+            params: CreateMemberParameters {
+                root_account: account_id,
+                controller_account: account_id,
+                ..self.params
+            },
+            ..self
+        }
+    }
+
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
         let expected_member_id = Membership::members_created();
         let actual_result =
@@ -868,3 +880,446 @@ impl CreateMemberFixture {
         }
     }
 }
+
+pub const GUARDIAN_MEMBER_IDS: [u64; 3] = [1, 2, 3];
+pub const GUARDIAN_ACCOUNT_IDS: [u64; 3] = [11, 12, 13];
+pub const ALICE_RECOVERED_ROOT_ACCOUNT_ID: u64 = 20;
+
+// Creates Alice membership and the memberships of her recovery guardians.
+pub fn create_alice_with_guardians() {
+    CreateMemberFixture::default().call_and_assert(Ok(()));
+
+    for (index, account_id) in GUARDIAN_ACCOUNT_IDS.iter().enumerate() {
+        CreateMemberFixture::default()
+            .with_accounts(*account_id)
+            .with_handle(format!("guardian{}", index).into_bytes())
+            .call_and_assert(Ok(()));
+
+        let _ = Balances::deposit_creating(account_id, ed() + RecoveryDeposit::get());
+    }
+}
+
+pub struct SetRecoveryConfigFixture {
+    origin: RawOrigin<u64>,
+    member_id: u64,
+    params: Option<RecoveryConfigParameters<u64>>,
+}
+
+impl Default for SetRecoveryConfigFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(ALICE_ACCOUNT_ID),
+            member_id: ALICE_MEMBER_ID,
+            params: Some(RecoveryConfigParameters {
+                guardians: GUARDIAN_MEMBER_IDS.iter().copied().collect(),
+                threshold: 2,
+            }),
+        }
+    }
+}
+
+impl SetRecoveryConfigFixture {
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_params(self, params: Option<RecoveryConfigParameters<u64>>) -> Self {
+        Self { params, ..self }
+    }
+
+    pub fn with_guardians(self, guardians: Vec<u64>, threshold: u32) -> Self {
+        self.with_params(Some(RecoveryConfigParameters {
+            guardians: guardians.into_iter().collect(),
+            threshold,
+        }))
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_recovery_config = Membership::recovery_config(self.member_id);
+
+        let actual_result = Membership::set_recovery_config(
+            self.origin.clone().into(),
+            self.member_id,
+            self.params.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let recovery_config = Membership::recovery_config(self.member_id);
+
+        if actual_result.is_ok() {
+            assert_eq!(
+                recovery_config.map(|config| (
+                    config.guardians.into_iter().collect::<BTreeSet<_>>(),
+                    config.threshold
+                )),
+                self.params
+                    .clone()
+                    .map(|params| (params.guardians, params.threshold))
+            );
+
+            EventFixture::assert_last_crate_event(MembershipEvent::<Test>::RecoveryConfigUpdated(
+                self.member_id,
+                self.params.clone(),
+            ));
+        } else {
+            assert_eq!(recovery_config, old_recovery_config);
+        }
+    }
+}
+
+pub struct InitiateRecoveryFixture {
+    origin: RawOrigin<u64>,
+    guardian_id: u64,
+    member_id: u64,
+    new_root_account: u64,
+}
+
+impl Default for InitiateRecoveryFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(GUARDIAN_ACCOUNT_IDS[0]),
+            guardian_id: GUARDIAN_MEMBER_IDS[0],
+            member_id: ALICE_MEMBER_ID,
+            new_root_account: ALICE_RECOVERED_ROOT_ACCOUNT_ID,
+        }
+    }
+}
+
+impl InitiateRecoveryFixture {
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_guardian_id(self, guardian_id: u64) -> Self {
+        Self {
+            guardian_id,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let account_id = match self.origin {
+            RawOrigin::Signed(account_id) => account_id,
+            _ => 0,
+        };
+        let old_reserved_balance = Balances::reserved_balance(&account_id);
+
+        let actual_result = Membership::initiate_recovery(
+            self.origin.clone().into(),
+            self.guardian_id,
+            self.member_id,
+            self.new_root_account,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            let active_recovery = Membership::active_recovery(self.member_id).unwrap();
+
+            assert_eq!(active_recovery.new_root_account, self.new_root_account);
+            assert_eq!(active_recovery.depositor, account_id);
+            assert!(active_recovery.approvals.contains(&self.guardian_id));
+            assert_eq!(
+                Balances::reserved_balance(&account_id),
+                old_reserved_balance + RecoveryDeposit::get()
+            );
+
+            EventFixture::assert_last_crate_event(MembershipEvent::<Test>::RecoveryInitiated(
+                self.member_id,
+                self.guardian_id,
+                self.new_root_account,
+            ));
+        } else {
+            assert_eq!(
+                Balances::reserved_balance(&account_id),
+                old_reserved_balance
+            );
+        }
+    }
+}
+
+pub struct ApproveRecoveryFixture {
+    origin: RawOrigin<u64>,
+    guardian_id: u64,
+    member_id: u64,
+}
+
+impl Default for ApproveRecoveryFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(GUARDIAN_ACCOUNT_IDS[1]),
+            guardian_id: GUARDIAN_MEMBER_IDS[1],
+            member_id: ALICE_MEMBER_ID,
+        }
+    }
+}
+
+impl ApproveRecoveryFixture {
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_guardian_id(self, guardian_id: u64) -> Self {
+        Self {
+            guardian_id,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result = Membership::approve_recovery(
+            self.origin.clone().into(),
+            self.guardian_id,
+            self.member_id,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            let active_recovery = Membership::active_recovery(self.member_id).unwrap();
+
+            assert!(active_recovery.approvals.contains(&self.guardian_id));
+
+            EventFixture::assert_last_crate_event(MembershipEvent::<Test>::RecoveryApproved(
+                self.member_id,
+                self.guardian_id,
+            ));
+        }
+    }
+}
+
+pub struct FinalizeRecoveryFixture {
+    origin: RawOrigin<u64>,
+    member_id: u64,
+}
+
+impl Default for FinalizeRecoveryFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(ALICE_RECOVERED_ROOT_ACCOUNT_ID),
+            member_id: ALICE_MEMBER_ID,
+        }
+    }
+}
+
+impl FinalizeRecoveryFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_membership = get_membership_by_id(self.member_id);
+        let active_recovery = Membership::active_recovery(self.member_id);
+
+        let actual_result =
+            Membership::finalize_recovery(self.origin.clone().into(), self.member_id);
+
+        assert_eq!(actual_result, expected_result);
+
+        let membership = get_membership_by_id(self.member_id);
+
+        if actual_result.is_ok() {
+            let active_recovery = active_recovery.unwrap();
+
+            assert_eq!(membership.root_account, active_recovery.new_root_account);
+            assert_eq!(
+                membership.controller_account,
+                old_membership.controller_account
+            );
+            assert!(Membership::active_recovery(self.member_id).is_none());
+            assert_eq!(Balances::reserved_balance(&active_recovery.depositor), 0);
+
+            EventFixture::assert_last_crate_event(MembershipEvent::<Test>::RecoveryFinalized(
+                self.member_id,
+                active_recovery.new_root_account,
+            ));
+        } else {
+            assert_eq!(membership, old_membership);
+            assert_eq!(Membership::active_recovery(self.member_id), active_recovery);
+        }
+    }
+}
+
+pub struct CancelRecoveryFixture {
+    origin: RawOrigin<u64>,
+    member_id: u64,
+}
+
+impl Default for CancelRecoveryFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(ALICE_ACCOUNT_ID),
+            member_id: ALICE_MEMBER_ID,
+        }
+    }
+}
+
+impl CancelRecoveryFixture {
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let active_recovery = Membership::active_recovery(self.member_id);
+
+        let actual_result = Membership::cancel_recovery(self.origin.clone().into(), self.member_id);
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            let active_recovery = active_recovery.unwrap();
+            let total_balance = Balances::total_balance(&active_recovery.depositor);
+
+            assert!(Membership::active_recovery(self.member_id).is_none());
+            assert_eq!(Balances::reserved_balance(&active_recovery.depositor), 0);
+            assert_eq!(total_balance, ed());
+
+            EventFixture::assert_last_crate_event(MembershipEvent::<Test>::RecoveryCancelled(
+                self.member_id,
+            ));
+        } else {
+            assert_eq!(Membership::active_recovery(self.member_id), active_recovery);
+        }
+    }
+}
+
+pub const HANDLE_PRICE: u64 = 100;
+pub const CHARLIE_ACCOUNT_ID: u64 = 30;
+
+// Creates Alice membership and the Bob membership with the "bob" handle.
+pub fn create_alice_and_bob() {
+    CreateMemberFixture::default().call_and_assert(Ok(()));
+
+    CreateMemberFixture::default()
+        .with_accounts(BOB_ACCOUNT_ID)
+        .with_handle(b"bob".to_vec())
+        .call_and_assert(Ok(()));
+}
+
+pub struct OfferHandleForSaleFixture {
+    origin: RawOrigin<u64>,
+    member_id: u64,
+    price: u64,
+    buyer_id: Option<u64>,
+}
+
+impl Default for OfferHandleForSaleFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(ALICE_ACCOUNT_ID),
+            member_id: ALICE_MEMBER_ID,
+            price: HANDLE_PRICE,
+            buyer_id: Some(BOB_MEMBER_ID),
+        }
+    }
+}
+
+impl OfferHandleForSaleFixture {
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_buyer_id(self, buyer_id: Option<u64>) -> Self {
+        Self { buyer_id, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_offer = Membership::handle_sale_offer(self.member_id);
+
+        let actual_result = Membership::offer_handle_for_sale(
+            self.origin.clone().into(),
+            self.member_id,
+            self.price,
+            self.buyer_id,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(
+                Membership::handle_sale_offer(self.member_id),
+                Some(HandleSaleOffer {
+                    price: self.price,
+                    buyer_id: self.buyer_id,
+                })
+            );
+
+            EventFixture::assert_last_crate_event(MembershipEvent::<Test>::HandleOfferedForSale(
+                self.member_id,
+                self.price,
+                self.buyer_id,
+            ));
+        } else {
+            assert_eq!(Membership::handle_sale_offer(self.member_id), old_offer);
+        }
+    }
+}
+
+pub struct BuyHandleFixture {
+    origin: RawOrigin<u64>,
+    buyer_id: u64,
+    seller_id: u64,
+    price: u64,
+}
+
+impl Default for BuyHandleFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(BOB_ACCOUNT_ID),
+            buyer_id: BOB_MEMBER_ID,
+            seller_id: ALICE_MEMBER_ID,
+            price: HANDLE_PRICE,
+        }
+    }
+}
+
+impl BuyHandleFixture {
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_buyer_id(self, buyer_id: u64) -> Self {
+        Self { buyer_id, ..self }
+    }
+
+    pub fn with_price(self, price: u64) -> Self {
+        Self { price, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let buyer = Membership::membership(self.buyer_id);
+        let seller = Membership::membership(self.seller_id);
+
+        let actual_result = Membership::buy_handle(
+            self.origin.clone().into(),
+            self.buyer_id,
+            self.seller_id,
+            self.price,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            let buyer_handle_hash = buyer.unwrap().handle_hash;
+            let seller_handle_hash = seller.unwrap().handle_hash;
+
+            assert_eq!(
+                get_membership_by_id(self.buyer_id).handle_hash,
+                seller_handle_hash
+            );
+            assert_eq!(
+                get_membership_by_id(self.seller_id).handle_hash,
+                buyer_handle_hash
+            );
+            assert_eq!(Membership::handles(seller_handle_hash), self.buyer_id);
+            assert_eq!(Membership::handles(buyer_handle_hash), self.seller_id);
+            assert!(Membership::handle_sale_offer(self.seller_id).is_none());
+
+            EventFixture::assert_last_crate_event(MembershipEvent::<Test>::HandleSold(
+                self.seller_id,
+                self.buyer_id,
+                self.price,
+            ));
+        } else {
+            assert_eq!(Membership::membership(self.buyer_id), buyer);
+            assert_eq!(Membership::membership(self.seller_id), seller);
+        }
+    }
+}
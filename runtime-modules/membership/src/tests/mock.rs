@@ -111,6 +111,18 @@ parameter_types! {
     pub const MinimumApplicationStake: u32 = 50;
     pub const LeaderOpeningStake: u32 = 20;
     pub const DefaultMemberInvitesCount: u32 = 2;
+    pub const MemberReputationRules: crate::ReputationRules = crate::ReputationRules {
+        successful_bounty_entry_reward: 10,
+        executed_proposal_reward: 5,
+        worker_tenure_reward: 1,
+        moderation_action_penalty: 7,
+    };
+    pub const MaxRecoveryGuardians: u32 = 5;
+    pub const RecoveryDelay: u64 = 10;
+    pub const RecoveryDeposit: u64 = 50;
+    pub const HandleExpiryPeriod: u64 = 100;
+    pub const HandleExpiryGracePeriod: u64 = 300;
+    pub const MaxUplineReferralLevels: u32 = 2;
 }
 
 impl LockComparator<u64> for Test {
@@ -150,6 +162,13 @@ impl Config for Test {
     type CandidateStake = CandidateStake;
     type WeightInfo = ();
     type DefaultMemberInvitesCount = DefaultMemberInvitesCount;
+    type MemberReputationRules = MemberReputationRules;
+    type MaxRecoveryGuardians = MaxRecoveryGuardians;
+    type RecoveryDelay = RecoveryDelay;
+    type RecoveryDeposit = RecoveryDeposit;
+    type HandleExpiryPeriod = HandleExpiryPeriod;
+    type HandleExpiryGracePeriod = HandleExpiryGracePeriod;
+    type MaxUplineReferralLevels = MaxUplineReferralLevels;
 }
 
 pub const WORKING_GROUP_BUDGET: u64 = 100;
@@ -204,8 +223,19 @@ impl common::working_group::WorkingGroupAuthenticator<Test> for Wg {
         }
     }
 
-    fn ensure_leader_origin(_origin: <Test as frame_system::Config>::Origin) -> DispatchResult {
-        unimplemented!()
+    fn ensure_leader_origin(origin: <Test as frame_system::Config>::Origin) -> DispatchResult {
+        let raw_origin: Result<RawOrigin<u64>, <Test as frame_system::Config>::Origin> =
+            origin.into();
+
+        if let RawOrigin::Signed(_) = raw_origin.unwrap() {
+            if Self::get_leader_member_id().is_some() {
+                Ok(())
+            } else {
+                Err(DispatchError::Other("leader is not set"))
+            }
+        } else {
+            Err(DispatchError::BadOrigin)
+        }
     }
 
     fn get_leader_member_id() -> Option<<Test as common::membership::MembershipTypes>::MemberId> {
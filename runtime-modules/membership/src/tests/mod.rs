@@ -7,7 +7,9 @@ use crate::{BalanceOf, Error, Event};
 pub use fixtures::*;
 pub use mock::*;
 
-use common::membership::{MemberOriginValidator, MembershipInfoProvider};
+use common::membership::{
+    MemberOriginValidator, MemberReputationHandler, MembershipInfoProvider, ReputationActivity,
+};
 use common::working_group::WorkingGroupBudgetHandler;
 use common::StakingAccountValidator;
 use frame_support::{assert_err, assert_ok, StorageMap, StorageValue};
@@ -1463,3 +1465,686 @@ fn create_member_fails_with_non_unique_handle() {
             .call_and_assert(Err(Error::<Test>::HandleAlreadyRegistered.into()));
     });
 }
+
+#[test]
+fn record_activity_updates_member_reputation() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        set_alice_free_balance(ed() + DefaultMembershipPrice::get());
+        assert_ok!(buy_default_membership_as_alice());
+
+        let rules = MemberReputationRules::get();
+
+        Membership::record_activity(&ALICE_MEMBER_ID, ReputationActivity::SuccessfulBountyEntry);
+        Membership::record_activity(&ALICE_MEMBER_ID, ReputationActivity::ProposalExecuted);
+        Membership::record_activity(&ALICE_MEMBER_ID, ReputationActivity::WorkerTenure);
+
+        let expected_reputation = rules.successful_bounty_entry_reward
+            + rules.executed_proposal_reward
+            + rules.worker_tenure_reward;
+        assert_eq!(
+            Membership::reputation(&ALICE_MEMBER_ID),
+            expected_reputation
+        );
+        assert!(Membership::has_min_reputation(
+            &ALICE_MEMBER_ID,
+            expected_reputation
+        ));
+        assert!(!Membership::has_min_reputation(
+            &ALICE_MEMBER_ID,
+            expected_reputation + 1
+        ));
+
+        Membership::record_activity(&ALICE_MEMBER_ID, ReputationActivity::ModerationAction);
+
+        let expected_reputation = expected_reputation - rules.moderation_action_penalty;
+        assert_eq!(
+            Membership::reputation(&ALICE_MEMBER_ID),
+            expected_reputation
+        );
+
+        EventFixture::assert_last_crate_event(Event::<Test>::MemberReputationUpdated(
+            ALICE_MEMBER_ID,
+            ReputationActivity::ModerationAction,
+            expected_reputation,
+        ));
+
+        // Reputation cannot go below zero.
+        Membership::record_activity(&ALICE_MEMBER_ID, ReputationActivity::ModerationAction);
+        Membership::record_activity(&ALICE_MEMBER_ID, ReputationActivity::ModerationAction);
+
+        assert_eq!(Membership::reputation(&ALICE_MEMBER_ID), 0);
+    });
+}
+
+#[test]
+fn record_activity_ignores_non_existing_member() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        Membership::record_activity(&ALICE_MEMBER_ID, ReputationActivity::ProposalExecuted);
+
+        assert_eq!(Membership::reputation(&ALICE_MEMBER_ID), 0);
+        assert!(System::events().is_empty());
+    });
+}
+
+#[test]
+fn set_recovery_config_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        create_alice_with_guardians();
+
+        SetRecoveryConfigFixture::default().call_and_assert(Ok(()));
+
+        SetRecoveryConfigFixture::default()
+            .with_params(None)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn set_recovery_config_fails_with_invalid_params() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        create_alice_with_guardians();
+
+        SetRecoveryConfigFixture::default()
+            .with_origin(RawOrigin::Signed(BOB_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test>::RootAccountRequired.into()));
+
+        SetRecoveryConfigFixture::default()
+            .with_guardians(Vec::new(), 1)
+            .call_and_assert(Err(Error::<Test>::NoRecoveryGuardians.into()));
+
+        SetRecoveryConfigFixture::default()
+            .with_guardians((1..=MaxRecoveryGuardians::get() as u64 + 1).collect(), 1)
+            .call_and_assert(Err(Error::<Test>::MaxRecoveryGuardiansExceeded.into()));
+
+        SetRecoveryConfigFixture::default()
+            .with_guardians(vec![ALICE_MEMBER_ID, GUARDIAN_MEMBER_IDS[0]], 1)
+            .call_and_assert(Err(Error::<Test>::CannotBeOwnRecoveryGuardian.into()));
+
+        SetRecoveryConfigFixture::default()
+            .with_guardians(vec![GUARDIAN_MEMBER_IDS[0], 100], 1)
+            .call_and_assert(Err(Error::<Test>::RecoveryGuardianIsNotMember.into()));
+
+        SetRecoveryConfigFixture::default()
+            .with_guardians(GUARDIAN_MEMBER_IDS.to_vec(), 0)
+            .call_and_assert(Err(Error::<Test>::InvalidRecoveryThreshold.into()));
+
+        SetRecoveryConfigFixture::default()
+            .with_guardians(GUARDIAN_MEMBER_IDS.to_vec(), 4)
+            .call_and_assert(Err(Error::<Test>::InvalidRecoveryThreshold.into()));
+    });
+}
+
+#[test]
+fn set_recovery_config_fails_with_recovery_in_progress() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        create_alice_with_guardians();
+
+        SetRecoveryConfigFixture::default().call_and_assert(Ok(()));
+        InitiateRecoveryFixture::default().call_and_assert(Ok(()));
+
+        SetRecoveryConfigFixture::default()
+            .with_params(None)
+            .call_and_assert(Err(Error::<Test>::RecoveryInProgress.into()));
+    });
+}
+
+#[test]
+fn root_account_recovery_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        create_alice_with_guardians();
+
+        SetRecoveryConfigFixture::default().call_and_assert(Ok(()));
+        InitiateRecoveryFixture::default().call_and_assert(Ok(()));
+        ApproveRecoveryFixture::default().call_and_assert(Ok(()));
+
+        run_to_block(starting_block + RecoveryDelay::get());
+
+        FinalizeRecoveryFixture::default().call_and_assert(Ok(()));
+
+        assert_eq!(
+            get_membership_by_id(ALICE_MEMBER_ID).root_account,
+            ALICE_RECOVERED_ROOT_ACCOUNT_ID
+        );
+    });
+}
+
+#[test]
+fn initiate_recovery_fails_with_invalid_guardian() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        create_alice_with_guardians();
+
+        InitiateRecoveryFixture::default()
+            .call_and_assert(Err(Error::<Test>::RecoveryNotConfigured.into()));
+
+        SetRecoveryConfigFixture::default()
+            .with_guardians(GUARDIAN_MEMBER_IDS[1..].to_vec(), 1)
+            .call_and_assert(Ok(()));
+
+        InitiateRecoveryFixture::default()
+            .call_and_assert(Err(Error::<Test>::NotRecoveryGuardian.into()));
+
+        InitiateRecoveryFixture::default()
+            .with_guardian_id(GUARDIAN_MEMBER_IDS[1])
+            .call_and_assert(Err(Error::<Test>::ControllerAccountRequired.into()));
+    });
+}
+
+#[test]
+fn initiate_recovery_fails_with_recovery_in_progress() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        create_alice_with_guardians();
+
+        SetRecoveryConfigFixture::default().call_and_assert(Ok(()));
+        InitiateRecoveryFixture::default().call_and_assert(Ok(()));
+
+        InitiateRecoveryFixture::default()
+            .with_origin(RawOrigin::Signed(GUARDIAN_ACCOUNT_IDS[1]))
+            .with_guardian_id(GUARDIAN_MEMBER_IDS[1])
+            .call_and_assert(Err(Error::<Test>::RecoveryInProgress.into()));
+    });
+}
+
+#[test]
+fn initiate_recovery_fails_with_insufficient_deposit_balance() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        create_alice_with_guardians();
+
+        SetRecoveryConfigFixture::default().call_and_assert(Ok(()));
+
+        let _ = Balances::slash(&GUARDIAN_ACCOUNT_IDS[0], RecoveryDeposit::get());
+
+        InitiateRecoveryFixture::default().call_and_assert(Err(
+            Error::<Test>::InsufficientBalanceForRecoveryDeposit.into(),
+        ));
+    });
+}
+
+#[test]
+fn approve_recovery_fails_with_invalid_params() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        create_alice_with_guardians();
+
+        SetRecoveryConfigFixture::default().call_and_assert(Ok(()));
+
+        ApproveRecoveryFixture::default()
+            .call_and_assert(Err(Error::<Test>::NoRecoveryInProgress.into()));
+
+        InitiateRecoveryFixture::default().call_and_assert(Ok(()));
+
+        ApproveRecoveryFixture::default()
+            .with_origin(RawOrigin::Signed(GUARDIAN_ACCOUNT_IDS[0]))
+            .with_guardian_id(GUARDIAN_MEMBER_IDS[0])
+            .call_and_assert(Err(Error::<Test>::RecoveryAlreadyApproved.into()));
+
+        ApproveRecoveryFixture::default()
+            .with_origin(RawOrigin::Signed(ALICE_ACCOUNT_ID))
+            .with_guardian_id(ALICE_MEMBER_ID)
+            .call_and_assert(Err(Error::<Test>::NotRecoveryGuardian.into()));
+    });
+}
+
+#[test]
+fn finalize_recovery_fails_with_insufficient_approvals_or_delay() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        create_alice_with_guardians();
+
+        SetRecoveryConfigFixture::default().call_and_assert(Ok(()));
+
+        FinalizeRecoveryFixture::default()
+            .call_and_assert(Err(Error::<Test>::NoRecoveryInProgress.into()));
+
+        InitiateRecoveryFixture::default().call_and_assert(Ok(()));
+
+        run_to_block(starting_block + RecoveryDelay::get());
+
+        FinalizeRecoveryFixture::default()
+            .call_and_assert(Err(Error::<Test>::InsufficientRecoveryApprovals.into()));
+
+        CancelRecoveryFixture::default().call_and_assert(Ok(()));
+
+        let _ = Balances::deposit_creating(&GUARDIAN_ACCOUNT_IDS[0], RecoveryDeposit::get());
+
+        InitiateRecoveryFixture::default().call_and_assert(Ok(()));
+        ApproveRecoveryFixture::default().call_and_assert(Ok(()));
+
+        FinalizeRecoveryFixture::default()
+            .call_and_assert(Err(Error::<Test>::RecoveryDelayNotPassed.into()));
+    });
+}
+
+#[test]
+fn cancel_recovery_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        create_alice_with_guardians();
+
+        SetRecoveryConfigFixture::default().call_and_assert(Ok(()));
+        InitiateRecoveryFixture::default().call_and_assert(Ok(()));
+
+        CancelRecoveryFixture::default()
+            .with_origin(RawOrigin::Signed(GUARDIAN_ACCOUNT_IDS[0]))
+            .call_and_assert(Err(Error::<Test>::RootAccountRequired.into()));
+
+        CancelRecoveryFixture::default().call_and_assert(Ok(()));
+
+        CancelRecoveryFixture::default()
+            .call_and_assert(Err(Error::<Test>::NoRecoveryInProgress.into()));
+    });
+}
+
+#[test]
+fn handle_sale_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        create_alice_and_bob();
+        let _ = Balances::deposit_creating(&BOB_ACCOUNT_ID, ed() + HANDLE_PRICE);
+
+        OfferHandleForSaleFixture::default().call_and_assert(Ok(()));
+        BuyHandleFixture::default().call_and_assert(Ok(()));
+
+        assert_eq!(Balances::usable_balance(&ALICE_ACCOUNT_ID), HANDLE_PRICE);
+        assert_eq!(Balances::usable_balance(&BOB_ACCOUNT_ID), ed());
+    });
+}
+
+#[test]
+fn offer_handle_for_sale_fails_with_invalid_params() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        create_alice_and_bob();
+
+        OfferHandleForSaleFixture::default()
+            .with_origin(RawOrigin::Signed(BOB_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test>::ControllerAccountRequired.into()));
+
+        OfferHandleForSaleFixture::default()
+            .with_buyer_id(Some(ALICE_MEMBER_ID))
+            .call_and_assert(Err(Error::<Test>::CannotBuyOwnHandle.into()));
+
+        OfferHandleForSaleFixture::default()
+            .with_buyer_id(Some(BOB_MEMBER_ID + 1))
+            .call_and_assert(Err(Error::<Test>::MemberProfileNotFound.into()));
+    });
+}
+
+#[test]
+fn buy_handle_fails_with_invalid_params() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        create_alice_and_bob();
+        CreateMemberFixture::default()
+            .with_accounts(CHARLIE_ACCOUNT_ID)
+            .with_handle(b"charlie".to_vec())
+            .call_and_assert(Ok(()));
+
+        BuyHandleFixture::default()
+            .call_and_assert(Err(Error::<Test>::HandleSaleOfferNotFound.into()));
+
+        OfferHandleForSaleFixture::default().call_and_assert(Ok(()));
+
+        BuyHandleFixture::default()
+            .with_origin(RawOrigin::Signed(CHARLIE_ACCOUNT_ID))
+            .with_buyer_id(BOB_MEMBER_ID + 1)
+            .call_and_assert(Err(Error::<Test>::HandleSaleRestrictedToAnotherBuyer.into()));
+
+        BuyHandleFixture::default()
+            .with_origin(RawOrigin::Signed(ALICE_ACCOUNT_ID))
+            .with_buyer_id(ALICE_MEMBER_ID)
+            .call_and_assert(Err(Error::<Test>::CannotBuyOwnHandle.into()));
+
+        BuyHandleFixture::default()
+            .with_price(HANDLE_PRICE - 1)
+            .call_and_assert(Err(Error::<Test>::HandleSalePriceMismatch.into()));
+
+        BuyHandleFixture::default()
+            .call_and_assert(Err(Error::<Test>::InsufficientBalanceToBuyHandle.into()));
+    });
+}
+
+#[test]
+fn cancel_handle_sale_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        create_alice_and_bob();
+
+        assert_err!(
+            Membership::cancel_handle_sale(
+                RawOrigin::Signed(ALICE_ACCOUNT_ID).into(),
+                ALICE_MEMBER_ID
+            ),
+            Error::<Test>::HandleSaleOfferNotFound
+        );
+
+        OfferHandleForSaleFixture::default().call_and_assert(Ok(()));
+
+        assert_ok!(Membership::cancel_handle_sale(
+            RawOrigin::Signed(ALICE_ACCOUNT_ID).into(),
+            ALICE_MEMBER_ID
+        ));
+
+        assert!(Membership::handle_sale_offer(ALICE_MEMBER_ID).is_none());
+        EventFixture::assert_last_crate_event(Event::<Test>::HandleSaleCancelled(ALICE_MEMBER_ID));
+
+        BuyHandleFixture::default()
+            .call_and_assert(Err(Error::<Test>::HandleSaleOfferNotFound.into()));
+    });
+}
+
+#[test]
+fn expired_handle_can_be_claimed_with_update_profile() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        create_alice_and_bob();
+        OfferHandleForSaleFixture::default().call_and_assert(Ok(()));
+
+        let alice_handle = get_alice_info().handle.unwrap();
+        let alice_handle_hash = get_membership_by_id(ALICE_MEMBER_ID).handle_hash;
+        let bob_handle_hash = get_membership_by_id(BOB_MEMBER_ID).handle_hash;
+
+        run_to_block(starting_block + HandleExpiryPeriod::get() - 1);
+
+        assert_err!(
+            Membership::update_profile(
+                RawOrigin::Signed(BOB_ACCOUNT_ID).into(),
+                BOB_MEMBER_ID,
+                Some(alice_handle.clone()),
+                None
+            ),
+            Error::<Test>::HandleAlreadyRegistered
+        );
+
+        assert_ok!(Membership::renew_handle(
+            RawOrigin::Signed(ALICE_ACCOUNT_ID).into(),
+            ALICE_MEMBER_ID
+        ));
+        EventFixture::assert_last_crate_event(Event::<Test>::HandleRenewed(
+            ALICE_MEMBER_ID,
+            System::block_number(),
+        ));
+
+        run_to_block(starting_block + HandleExpiryPeriod::get());
+
+        assert!(!Membership::is_handle_expired(&ALICE_MEMBER_ID));
+
+        run_to_block(System::block_number() + HandleExpiryPeriod::get());
+
+        assert_ok!(Membership::update_profile(
+            RawOrigin::Signed(BOB_ACCOUNT_ID).into(),
+            BOB_MEMBER_ID,
+            Some(alice_handle),
+            None
+        ));
+
+        let alice_new_handle_hash = get_membership_by_id(ALICE_MEMBER_ID).handle_hash;
+
+        assert_eq!(
+            get_membership_by_id(BOB_MEMBER_ID).handle_hash,
+            alice_handle_hash
+        );
+        assert_eq!(Membership::handles(alice_handle_hash), BOB_MEMBER_ID);
+        assert_eq!(Membership::handles(alice_new_handle_hash), ALICE_MEMBER_ID);
+        assert_ne!(alice_new_handle_hash, alice_handle_hash);
+        assert!(!<crate::MemberIdByHandleHash<Test>>::contains_key(
+            bob_handle_hash
+        ));
+        assert!(Membership::handle_sale_offer(ALICE_MEMBER_ID).is_none());
+        assert!(!Membership::is_handle_expired(&BOB_MEMBER_ID));
+    });
+}
+
+#[test]
+fn handles_of_members_created_before_handle_expiry_get_grace_period() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        set_alice_as_initial_member();
+
+        // Simulate the membership created before the handle expiry introduction.
+        <crate::MembershipCreatedAt<Test>>::remove(ALICE_MEMBER_ID);
+
+        let introduced_at = starting_block + 10;
+        run_to_block(introduced_at);
+        Membership::introduce_handle_expiry();
+
+        assert_eq!(Membership::handle_expiry_introduced_at(), introduced_at);
+
+        // Subsequent calls don't restart the grace period.
+        run_to_block(introduced_at + 1);
+        Membership::introduce_handle_expiry();
+
+        assert_eq!(Membership::handle_expiry_introduced_at(), introduced_at);
+
+        run_to_block(introduced_at + HandleExpiryPeriod::get());
+
+        assert!(!Membership::is_handle_expired(&ALICE_MEMBER_ID));
+
+        run_to_block(introduced_at + HandleExpiryGracePeriod::get() - 1);
+
+        assert!(!Membership::is_handle_expired(&ALICE_MEMBER_ID));
+
+        run_to_block(introduced_at + HandleExpiryGracePeriod::get());
+
+        assert!(Membership::is_handle_expired(&ALICE_MEMBER_ID));
+    });
+}
+
+#[test]
+fn buy_membership_rewards_upline_referrers() {
+    build_test_externalities().execute_with(|| {
+        set_alice_as_initial_member();
+
+        let referral_cut_percent = 10u8;
+        let upline_referral_cuts = vec![5u8, 2u8];
+        let dave_account_id = 40;
+
+        assert_ok!(Membership::set_referral_cut(
+            RawOrigin::Root.into(),
+            referral_cut_percent
+        ));
+        assert_ok!(Membership::set_upline_referral_cuts(
+            RawOrigin::Root.into(),
+            upline_referral_cuts.clone()
+        ));
+        EventFixture::assert_last_crate_event(Event::<Test>::UplineReferralCutsUpdated(
+            upline_referral_cuts,
+        ));
+
+        for (referrer_id, account_id) in [BOB_ACCOUNT_ID, CHARLIE_ACCOUNT_ID, dave_account_id]
+            .iter()
+            .enumerate()
+        {
+            increase_total_balance_issuance_using_account_id(*account_id, 10000);
+
+            BuyMembershipFixture::default()
+                .with_handle(account_id.to_string().into_bytes())
+                .with_accounts(*account_id)
+                .with_origin(RawOrigin::Signed(*account_id))
+                .with_referrer_id(referrer_id as u64)
+                .call_and_assert(Ok(()));
+        }
+
+        let fee = DefaultMembershipPrice::get();
+        let alice_rewards = Perbill::from_percent(10) * fee
+            + Perbill::from_percent(5) * fee
+            + Perbill::from_percent(2) * fee;
+
+        assert_eq!(
+            Balances::usable_balance(&ALICE_ACCOUNT_ID),
+            ed() + alice_rewards
+        );
+        assert_eq!(
+            Membership::referrer(BOB_MEMBER_ID + 2),
+            Some(BOB_MEMBER_ID + 1)
+        );
+        assert_eq!(
+            Membership::referral_stats(ALICE_MEMBER_ID),
+            crate::ReferralStats {
+                direct_referrals: 1,
+                indirect_referrals: 2,
+            }
+        );
+        assert_eq!(
+            Membership::referral_stats(BOB_MEMBER_ID),
+            crate::ReferralStats {
+                direct_referrals: 1,
+                indirect_referrals: 1,
+            }
+        );
+    });
+}
+
+#[test]
+fn set_upline_referral_cuts_fails_with_invalid_params() {
+    build_test_externalities().execute_with(|| {
+        let max_levels = MaxUplineReferralLevels::get() as usize;
+
+        assert_err!(
+            Membership::set_upline_referral_cuts(
+                RawOrigin::Signed(ALICE_ACCOUNT_ID).into(),
+                vec![]
+            ),
+            DispatchError::BadOrigin
+        );
+
+        assert_err!(
+            Membership::set_upline_referral_cuts(RawOrigin::Root.into(), vec![1; max_levels + 1]),
+            Error::<Test>::MaxUplineReferralLevelsExceeded
+        );
+
+        let referral_cut_percent = 10u8;
+        assert_ok!(Membership::set_referral_cut(
+            RawOrigin::Root.into(),
+            referral_cut_percent
+        ));
+
+        let max_upline_cut = ReferralCutMaximumPercent::get() - referral_cut_percent;
+
+        assert_err!(
+            Membership::set_upline_referral_cuts(RawOrigin::Root.into(), vec![max_upline_cut + 1]),
+            Error::<Test>::CannotExceedReferralCutPercentLimit
+        );
+
+        assert_ok!(Membership::set_upline_referral_cuts(
+            RawOrigin::Root.into(),
+            vec![max_upline_cut]
+        ));
+
+        SetReferralCutFixture::default()
+            .with_referral_cut(referral_cut_percent + 1)
+            .call_and_assert(Err(
+                Error::<Test>::CannotExceedReferralCutPercentLimit.into()
+            ));
+    });
+}
+
+#[test]
+fn referral_campaign_bonus_is_paid_from_working_group_budget() {
+    build_test_externalities_with_lead_set().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        set_alice_as_initial_member();
+
+        let bonus = 100;
+        let budget = 1000;
+        let campaign = crate::ReferralCampaign {
+            bonus,
+            ends_at: starting_block + 10,
+        };
+
+        <Test as Config>::WorkingGroup::set_budget(budget);
+
+        assert_err!(
+            Membership::set_referral_campaign(
+                RawOrigin::Signed(ALICE_ACCOUNT_ID).into(),
+                Some(crate::ReferralCampaign {
+                    bonus,
+                    ends_at: starting_block,
+                })
+            ),
+            Error::<Test>::InvalidReferralCampaignEnd
+        );
+
+        assert_ok!(Membership::set_referral_campaign(
+            RawOrigin::Signed(ALICE_ACCOUNT_ID).into(),
+            Some(campaign.clone())
+        ));
+        EventFixture::assert_last_crate_event(Event::<Test>::ReferralCampaignUpdated(Some(
+            campaign.clone(),
+        )));
+
+        increase_total_balance_issuance_using_account_id(BOB_ACCOUNT_ID, 10000);
+        BuyMembershipFixture::default()
+            .with_handle(b"bob".to_vec())
+            .with_accounts(BOB_ACCOUNT_ID)
+            .with_origin(RawOrigin::Signed(BOB_ACCOUNT_ID))
+            .with_referrer_id(ALICE_MEMBER_ID)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(Balances::usable_balance(&ALICE_ACCOUNT_ID), ed() + bonus);
+        assert_eq!(<Test as Config>::WorkingGroup::get_budget(), budget - bonus);
+
+        run_to_block(campaign.ends_at);
+
+        increase_total_balance_issuance_using_account_id(CHARLIE_ACCOUNT_ID, 10000);
+        BuyMembershipFixture::default()
+            .with_handle(b"charlie".to_vec())
+            .with_accounts(CHARLIE_ACCOUNT_ID)
+            .with_origin(RawOrigin::Signed(CHARLIE_ACCOUNT_ID))
+            .with_referrer_id(ALICE_MEMBER_ID)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(Balances::usable_balance(&ALICE_ACCOUNT_ID), ed() + bonus);
+        assert_eq!(<Test as Config>::WorkingGroup::get_budget(), budget - bonus);
+        assert_eq!(
+            Membership::referral_stats(ALICE_MEMBER_ID).direct_referrals,
+            2
+        );
+    });
+}
@@ -46,7 +46,7 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for membership.
 pub trait WeightInfo {
 	fn buy_membership_without_referrer(_i: u32, _j: u32, ) -> Weight;
-	fn buy_membership_with_referrer(_i: u32, _j: u32, ) -> Weight;
+	fn buy_membership_with_referrer(_i: u32, _j: u32, _k: u32, ) -> Weight;
 	fn update_profile(_i: u32, _j: u32, ) -> Weight;
 	fn update_accounts_none() -> Weight;
 	fn update_accounts_root() -> Weight;
@@ -66,6 +66,18 @@ pub trait WeightInfo {
 	fn remove_staking_account() -> Weight;
 	fn member_remark() -> Weight;
 	fn create_member(_i: u32, _j: u32, ) -> Weight;
+	fn set_recovery_config(_i: u32, ) -> Weight;
+	fn initiate_recovery() -> Weight;
+	fn approve_recovery() -> Weight;
+	fn finalize_recovery() -> Weight;
+	fn cancel_recovery() -> Weight;
+	fn offer_handle_for_sale() -> Weight;
+	fn cancel_handle_sale() -> Weight;
+	fn buy_handle() -> Weight;
+	fn renew_handle() -> Weight;
+	fn set_upline_referral_cuts(_i: u32, ) -> Weight;
+	fn set_referral_campaign() -> Weight;
+	fn record_activity() -> Weight;
 }
 
 /// Weights for membership using the Substrate node and recommended hardware.
@@ -87,20 +99,29 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 	}
 	// Storage: Membership MembershipPrice (r:1 w:0)
-	// Storage: System Account (r:1 w:1)
+	// Storage: System Account (r:4 w:4)
 	// Storage: Membership MemberIdByHandleHash (r:1 w:1)
-	// Storage: Membership MembershipById (r:1 w:1)
+	// Storage: Membership MembershipById (r:4 w:1)
 	// Storage: Membership InitialInvitationCount (r:1 w:0)
 	// Storage: Membership NextMemberId (r:1 w:1)
 	// Storage: Membership ReferralCut (r:1 w:0)
-	fn buy_membership_with_referrer(i: u32, j: u32, ) -> Weight {
-		(160_318_000 as Weight)
+	// Storage: Membership ReferralStatsByMemberId (r:4 w:4)
+	// Storage: Membership ActiveReferralCampaign (r:1 w:0)
+	// Storage: Instance6WorkingGroup Budget (r:1 w:1)
+	// Storage: Membership UplineReferralCutsPercent (r:1 w:0)
+	// Storage: Membership ReferrerByMemberId (r:3 w:1)
+	fn buy_membership_with_referrer(i: u32, j: u32, k: u32, ) -> Weight {
+		(196_042_000 as Weight)
 			// Standard Error: 10_000
 			.saturating_add((1_563_000 as Weight).saturating_mul(i as Weight))
 			// Standard Error: 10_000
 			.saturating_add((784_000 as Weight).saturating_mul(j as Weight))
-			.saturating_add(T::DbWeight::get().reads(7 as Weight))
-			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			// Standard Error: 342_000
+			.saturating_add((27_918_000 as Weight).saturating_mul(k as Weight))
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(k as Weight)))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(k as Weight)))
 	}
 	// Storage: Membership MembershipById (r:1 w:1)
 	// Storage: Membership MemberIdByHandleHash (r:1 w:2)
@@ -249,6 +270,106 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Membership ActiveRecoveryByMemberId (r:1 w:0)
+	// Storage: Membership RecoveryConfigByMemberId (r:0 w:1)
+	fn set_recovery_config(i: u32, ) -> Weight {
+		(41_207_000 as Weight)
+			// Standard Error: 4_000
+			.saturating_add((5_684_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Membership MembershipById (r:2 w:0)
+	// Storage: Membership RecoveryConfigByMemberId (r:1 w:0)
+	// Storage: Membership ActiveRecoveryByMemberId (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn initiate_recovery() -> Weight {
+		(78_344_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Membership RecoveryConfigByMemberId (r:1 w:0)
+	// Storage: Membership ActiveRecoveryByMemberId (r:1 w:1)
+	fn approve_recovery() -> Weight {
+		(52_918_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Membership MembershipById (r:1 w:1)
+	// Storage: Membership ActiveRecoveryByMemberId (r:1 w:1)
+	// Storage: Membership RecoveryConfigByMemberId (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn finalize_recovery() -> Weight {
+		(71_502_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Membership ActiveRecoveryByMemberId (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn cancel_recovery() -> Weight {
+		(66_731_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Membership MembershipById (r:2 w:0)
+	// Storage: Membership HandleSaleOfferByMemberId (r:0 w:1)
+	fn offer_handle_for_sale() -> Weight {
+		(44_127_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Membership HandleSaleOfferByMemberId (r:1 w:1)
+	fn cancel_handle_sale() -> Weight {
+		(41_856_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Membership MembershipById (r:2 w:2)
+	// Storage: Membership HandleSaleOfferByMemberId (r:1 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Membership MemberIdByHandleHash (r:0 w:2)
+	// Storage: Membership HandleRenewedAt (r:0 w:2)
+	fn buy_handle() -> Weight {
+		(98_431_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Membership HandleRenewedAt (r:0 w:1)
+	fn renew_handle() -> Weight {
+		(36_209_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Membership ReferralCut (r:1 w:0)
+	// Storage: Membership UplineReferralCutsPercent (r:0 w:1)
+	fn set_upline_referral_cuts(i: u32, ) -> Weight {
+		(19_783_000 as Weight)
+			// Standard Error: 4_000
+			.saturating_add((41_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Instance6WorkingGroup CurrentLead (r:1 w:0)
+	// Storage: Instance6WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Membership ActiveReferralCampaign (r:0 w:1)
+	fn set_referral_campaign() -> Weight {
+		(39_512_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Membership MemberReputation (r:1 w:1)
+	fn record_activity() -> Weight {
+		(24_613_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
 
 // Default implementation for tests
@@ -256,7 +377,7 @@ impl WeightInfo for () {
 	fn buy_membership_without_referrer(i: u32, j: u32, ) -> Weight {
 		0
 	}
-	fn buy_membership_with_referrer(i: u32, j: u32, ) -> Weight {
+	fn buy_membership_with_referrer(i: u32, j: u32, k: u32, ) -> Weight {
 		0
 	}
 	fn update_profile(i: u32, j: u32, ) -> Weight {
@@ -316,4 +437,40 @@ impl WeightInfo for () {
 	fn create_member(i: u32, j: u32, ) -> Weight {
 		0
 	}
+	fn set_recovery_config(i: u32, ) -> Weight {
+		0
+	}
+	fn initiate_recovery() -> Weight {
+		0
+	}
+	fn approve_recovery() -> Weight {
+		0
+	}
+	fn finalize_recovery() -> Weight {
+		0
+	}
+	fn cancel_recovery() -> Weight {
+		0
+	}
+	fn offer_handle_for_sale() -> Weight {
+		0
+	}
+	fn cancel_handle_sale() -> Weight {
+		0
+	}
+	fn buy_handle() -> Weight {
+		0
+	}
+	fn renew_handle() -> Weight {
+		0
+	}
+	fn set_upline_referral_cuts(i: u32, ) -> Weight {
+		0
+	}
+	fn set_referral_campaign() -> Weight {
+		0
+	}
+	fn record_activity() -> Weight {
+		0
+	}
 }
@@ -35,6 +35,16 @@ const DEFAULT_SPLIT_PAYOUT: u64 = 2_000_000;
 const DEFAULT_SPLIT_PARTICIPATION: u64 =
     DEFAULT_SPLIT_PAYOUT * DEFAULT_TOKEN_ISSUANCE / DEFAULT_SPLIT_ALLOCATION;
 
+// Polls
+const DEFAULT_POLL_DURATION: u32 = 100;
+const DEFAULT_POLL_VOTE: u64 = 1_000;
+// Airdrops
+const DEFAULT_AIRDROP_CLAIM: u64 = 1_000;
+const DEFAULT_AIRDROP_DURATION: u32 = 100;
+// Buybacks
+const MAX_BUYBACK_SWAP_OFFERS: u32 = 100;
+const DEFAULT_BUYBACK_MAX_UNIT_PRICE: u32 = 1_000;
+const DEFAULT_BUYBACK_DURATION: u32 = 100;
 // Patronage
 const DEFAULT_PATRONAGE: YearlyRate = YearlyRate(Permill::from_percent(1));
 // Metadata
@@ -97,6 +107,13 @@ fn create_owner<T: Config>() -> (<T as MembershipTypes>::MemberId, T::AccountId)
 
 fn issue_token<T: Config>(
     transfer_policy: TransferPolicyParamsOf<T>,
+) -> Result<T::TokenId, DispatchError> {
+    issue_token_with_symbol::<T>(transfer_policy, b"CRT")
+}
+
+fn issue_token_with_symbol<T: Config>(
+    transfer_policy: TransferPolicyParamsOf<T>,
+    symbol: &[u8],
 ) -> Result<T::TokenId, DispatchError> {
     let bloat_bond = BloatBond::<T>::get();
 
@@ -118,7 +135,7 @@ fn issue_token<T: Config>(
             .iter()
             .cloned()
             .collect(),
-            symbol: <T as frame_system::Config>::Hashing::hash_of(b"CRT"),
+            symbol: <T as frame_system::Config>::Hashing::hash_of(&symbol),
             transfer_policy,
             patronage_rate: DEFAULT_PATRONAGE,
             revenue_split_rate: DEFAULT_REVENUE_SPLIT_RATE,
@@ -131,8 +148,22 @@ fn issue_token<T: Config>(
     Ok(token_id)
 }
 
+// Initializes a sale with `T::MaxSaleTiers` tiers, such that a purchase of
+// `DEFAULT_SALE_PURCHASE` tokens spans all of them
 fn init_token_sale<T: Config>(token_id: T::TokenId) -> Result<TokenSaleId, DispatchError> {
     let sale_id = Token::<T>::token_info_by_id(token_id).next_sale_id;
+    let additional_tiers_num = T::MaxSaleTiers::get().saturating_sub(1);
+    let tiers = (1..=additional_tiers_num)
+        .map(|i| TokenSaleTierParamsOf::<T> {
+            unit_price: DEFAULT_SALE_UNIT_PRICE.into(),
+            quantity: if i == additional_tiers_num {
+                DEFAULT_TOKENS_ON_SALE.into()
+            } else {
+                1u32.into()
+            },
+            vesting_schedule_params: None,
+        })
+        .collect::<Vec<_>>();
     Token::<T>::init_token_sale(
         token_id,
         T::MemberId::zero(),
@@ -140,7 +171,11 @@ fn init_token_sale<T: Config>(token_id: T::TokenId) -> Result<TokenSaleId, Dispa
         false,
         TokenSaleParamsOf::<T> {
             unit_price: DEFAULT_SALE_UNIT_PRICE.into(),
-            upper_bound_quantity: DEFAULT_TOKENS_ON_SALE.into(),
+            upper_bound_quantity: if tiers.is_empty() {
+                DEFAULT_TOKENS_ON_SALE.into()
+            } else {
+                1u32.into()
+            },
             starts_at: None,
             duration: default_sale_duration::<T>(),
             cap_per_member: Some(DEFAULT_SALE_PURCHASE.into()),
@@ -150,6 +185,7 @@ fn init_token_sale<T: Config>(token_id: T::TokenId) -> Result<TokenSaleId, Dispa
                 linear_vesting_duration: 0u32.into(),
             }),
             metadata: None,
+            tiers,
         },
     )?;
     Ok(sale_id)
@@ -185,6 +221,32 @@ fn issue_revenue_split<T: Config>(token_id: T::TokenId, forced_id: Option<u32>)
     Ok(())
 }
 
+fn issue_token_poll<T: Config>(
+    token_id: T::TokenId,
+    alternatives: u32,
+    forced_id: Option<u32>,
+) -> DispatchResult {
+    Token::<T>::issue_poll(
+        token_id,
+        TokenPollParams {
+            metadata: Vec::new(),
+            alternatives,
+            start: None,
+            duration: DEFAULT_POLL_DURATION.into(),
+        },
+    )?;
+
+    if let Some(forced_id) = forced_id {
+        TokenPollByTokenId::<T>::mutate(token_id, |poll| {
+            if let Some(poll) = poll.as_mut() {
+                poll.poll_id = forced_id
+            }
+        })
+    }
+
+    Ok(())
+}
+
 fn setup_account_with_max_number_of_locks<T: Config>(
     token_id: T::TokenId,
     member_id: &T::MemberId,
@@ -209,6 +271,11 @@ fn setup_account_with_max_number_of_locks<T: Config>(
             split_id: 0u32,
             amount: TokenBalanceOf::<T>::one(),
         });
+        a.poll_vote = Some(PollVote {
+            poll_id: 0u32,
+            alternative: 0u32,
+            amount: TokenBalanceOf::<T>::one(),
+        });
         a.increase_amount_by(TokenBalanceOf::<T>::one() + usable_balance.unwrap_or_default());
     });
 }
@@ -304,6 +371,7 @@ benchmarks! {
     // Worst case scenario:
     // - account's bloat_bond is non-zero
     // - token policy is Permissioned
+    // - account granted T::MaxAllowancesPerAccount allowances
     dust_account {
         let (owner_member_id, owner_account) = create_owner::<T>();
         let bloat_bond: JoyBalanceOf<T> = T::JoyExistentialDeposit::get();
@@ -323,6 +391,18 @@ benchmarks! {
             T::JoyExistentialDeposit::get()
         );
 
+        // Grant max number of allowances
+        for i in 0..T::MaxAllowancesPerAccount::get() {
+            Token::<T>::approve(
+                RawOrigin::Signed(owner_account.clone()).into(),
+                token_id,
+                owner_member_id,
+                AllowanceSpender::Account(account::<T::AccountId>("spender", i, SEED)),
+                DEFAULT_TX_AMOUNT.into(),
+                None
+            )?;
+        }
+
         // Burn all owner tokens
         Token::<T>::burn(
             RawOrigin::Signed(owner_account.clone()).into(),
@@ -337,6 +417,12 @@ benchmarks! {
     )
     verify {
         assert!(!AccountInfoByTokenAndMember::<T>::contains_key(token_id, &owner_member_id));
+        for i in 0..T::MaxAllowancesPerAccount::get() {
+            assert!(Token::<T>::allowance(
+                (token_id, owner_member_id),
+                AllowanceSpender::Account(account::<T::AccountId>("spender", i, SEED))
+            ).is_none());
+        }
         assert_last_event::<T>(
             RawEvent::AccountDustedBy(
                 token_id,
@@ -404,6 +490,7 @@ benchmarks! {
     // - bloat_bond is non-zero
     // - platform_fee is set
     // - sale.earnings_destination.is_some()
+    // - purchase spans T::MaxSaleTiers sale tiers
     purchase_tokens_on_sale {
         create_owner::<T>();
         let participant = account::<T::AccountId>("participant", 0, SEED);
@@ -446,6 +533,10 @@ benchmarks! {
                 last_sale_total_purchased_amount: Some((sale_id, DEFAULT_SALE_PURCHASE.into())),
                 next_vesting_transfer_id: 0,
                 bloat_bond: RepayableBloatBond::new(bloat_bond, None),
+                poll_vote: None,
+                allowances_number: 0,
+                frozen: false,
+                balance_checkpoints: Default::default(),
             }
         );
         assert_last_event::<T>(
@@ -585,6 +676,541 @@ benchmarks! {
             ).into()
         );
     }
+
+    // Worst case scenario:
+    // - voter.vesting_schedules.len() is T::MaxVestingSchedulesPerAccountPerToken
+    // - voter.split_staking_status is Some(_)
+    // - voter.poll_vote is Some(_) (vote in a past poll)
+    vote_in_token_poll {
+        let (owner_member_id, owner_account) = create_owner::<T>();
+
+        let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+
+        let voter_acc = account::<T::AccountId>("voter", 0, SEED);
+        let voter_id = create_member::<T>(&voter_acc, b"voter");
+        setup_account_with_max_number_of_locks::<T>(token_id, &voter_id, Some(DEFAULT_POLL_VOTE.into()));
+
+        // Issue poll
+        // Note: We need to force poll_id==1, because
+        // setup_account_with_max_number_of_locks will setup a poll_vote with poll_id == 0
+        issue_token_poll::<T>(token_id, T::MaxPollAlternatives::get(), Some(1))?;
+        let alternative = T::MaxPollAlternatives::get() - 1;
+    }: _(
+        RawOrigin::Signed(voter_acc.clone()),
+        token_id,
+        voter_id,
+        alternative,
+        DEFAULT_POLL_VOTE.into()
+    )
+    verify {
+        assert_eq!(
+            Token::<T>::account_info_by_token_and_member(token_id, &voter_id).locked_for_poll(),
+            DEFAULT_POLL_VOTE.into()
+        );
+        assert_last_event::<T>(
+            RawEvent::VotedInTokenPoll(
+                token_id,
+                voter_id,
+                1u32,
+                alternative,
+                DEFAULT_POLL_VOTE.into()
+            ).into()
+        );
+    }
+
+    // Worst case scenario:
+    // - voter.vesting_schedules.len() is T::MaxVestingSchedulesPerAccountPerToken
+    // - voter.split_staking_status is Some(_)
+    // - unlocking vote in the current poll, which is no longer ongoing
+    unlock_token_poll_vote {
+        let (owner_member_id, owner_account) = create_owner::<T>();
+
+        let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+
+        let voter_acc = account::<T::AccountId>("voter", 0, SEED);
+        let voter_id = create_member::<T>(&voter_acc, b"voter");
+        setup_account_with_max_number_of_locks::<T>(token_id, &voter_id, Some(DEFAULT_POLL_VOTE.into()));
+
+        issue_token_poll::<T>(token_id, T::MaxPollAlternatives::get(), Some(1))?;
+        Token::<T>::vote_in_token_poll(
+            RawOrigin::Signed(voter_acc.clone()).into(),
+            token_id,
+            voter_id,
+            0u32,
+            DEFAULT_POLL_VOTE.into(),
+        )?;
+        // Go to: Poll end
+        System::<T>::set_block_number(System::<T>::block_number() + DEFAULT_POLL_DURATION.into());
+    }: _(
+        RawOrigin::Signed(voter_acc.clone()),
+        token_id,
+        voter_id
+    )
+    verify {
+        assert!(Token::<T>::account_info_by_token_and_member(token_id, &voter_id).poll_vote.is_none());
+        assert_last_event::<T>(
+            RawEvent::TokenPollTokensUnlocked(
+                token_id,
+                voter_id,
+                DEFAULT_POLL_VOTE.into()
+            ).into()
+        );
+    }
+
+    // Worst case scenario:
+    // - poll has `a` alternatives, all of them with non-zero tallies
+    finalize_token_poll {
+        let a in 2 .. T::MaxPollAlternatives::get();
+
+        let (owner_member_id, owner_account) = create_owner::<T>();
+        let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+        issue_token_poll::<T>(token_id, a, None)?;
+        TokenPollByTokenId::<T>::mutate(token_id, |poll| {
+            if let Some(poll) = poll.as_mut() {
+                poll.tallies = (0..a)
+                    .map(|i| (DEFAULT_POLL_VOTE + i as u64).into())
+                    .collect::<Vec<_>>()
+                    .try_into()
+                    .unwrap();
+            }
+        });
+        // Go to: Poll end
+        System::<T>::set_block_number(System::<T>::block_number() + DEFAULT_POLL_DURATION.into());
+    }: _(
+        RawOrigin::Signed(owner_account.clone()),
+        token_id
+    )
+    verify {
+        let poll = Token::<T>::token_poll_by_token_id(token_id).unwrap();
+        assert!(poll.finalized);
+        assert_eq!(poll.winning_alternative, Some(a - 1));
+        assert_last_event::<T>(
+            RawEvent::TokenPollFinalized(
+                token_id,
+                0u32,
+                Some(a - 1)
+            ).into()
+        );
+    }
+
+    // Worst case scenario:
+    // - airdrop commitment has 2^h leaves, so the proof consists of `h` hashes
+    // - claimer account does not exist (needs to be created)
+    // - claimed tokens are subject to a vesting schedule
+    // - bloat_bond is non-zero
+    claim_airdrop {
+        let h in 1 .. MAX_MERKLE_PROOF_HASHES;
+
+        let (owner_member_id, _) = create_owner::<T>();
+        let acc = account::<T::AccountId>("claimer", 0, SEED);
+        let member_id = create_member::<T>(&acc, b"claimer");
+        let vesting_schedule = Some(VestingScheduleParams {
+            blocks_before_cliff: 100u32.into(),
+            cliff_amount_percentage: Permill::from_percent(50),
+            linear_vesting_duration: 100u32.into(),
+        });
+        let entries: Vec<(T::MemberId, TokenBalanceOf<T>, Option<VestingScheduleParamsOf<T>>)> =
+            (0..2u32.pow(h)).map(|i| {
+                let entry_member_id = if i == 0 { member_id } else { (i + 1000).saturated_into() };
+                (entry_member_id, DEFAULT_AIRDROP_CLAIM.into(), vesting_schedule.clone())
+            }).collect();
+        let commitment = generate_merkle_root_helper::<T, _>(&entries).pop().unwrap();
+        let proof = MerkleProof::<<T as frame_system::Config>::Hashing>(
+            build_merkle_path_helper::<T, _>(&entries, 0)
+        );
+        let bloat_bond: JoyBalanceOf<T> = T::JoyExistentialDeposit::get();
+
+        // Make sure that proof.0.len() is h
+        assert_eq!(proof.0.len() as u32, h);
+
+        let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+        let airdrop_id = Token::<T>::issue_airdrop(
+            token_id,
+            owner_member_id,
+            AirdropParams {
+                commitment,
+                total_amount: DEFAULT_AIRDROP_CLAIM.into(),
+                expiry: System::<T>::block_number() + DEFAULT_AIRDROP_DURATION.into(),
+            }
+        )?;
+        let _ = Joy::<T>::deposit_creating(&acc, bloat_bond);
+        BloatBond::<T>::set(bloat_bond);
+    }: _(
+        RawOrigin::Signed(acc.clone()),
+        token_id,
+        airdrop_id,
+        member_id,
+        DEFAULT_AIRDROP_CLAIM.into(),
+        vesting_schedule,
+        proof
+    )
+    verify {
+        assert_eq!(
+            Token::<T>::account_info_by_token_and_member(token_id, &member_id).amount,
+            DEFAULT_AIRDROP_CLAIM.into()
+        );
+        assert!(Token::<T>::airdrop_claimed(airdrop_id, member_id));
+        assert_last_event::<T>(
+            RawEvent::AirdropClaimed(
+                token_id,
+                airdrop_id,
+                member_id,
+                DEFAULT_AIRDROP_CLAIM.into()
+            ).into()
+        );
+        // Ensure bloat_bond was transferred
+        assert_eq!(
+            Joy::<T>::usable_balance(&acc),
+            T::JoyExistentialDeposit::get()
+        );
+    }
+
+    // Worst case scenario:
+    // - spender has no allowance yet (account's number of allowances needs to be increased)
+    // - allowance has an expiry
+    approve {
+        let (owner_member_id, owner_account) = create_owner::<T>();
+        let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+        let spender = AllowanceSpender::Account(account::<T::AccountId>("spender", 0, SEED));
+        let expiry = Some(System::<T>::block_number() + DEFAULT_AIRDROP_DURATION.into());
+    }: _(
+        RawOrigin::Signed(owner_account.clone()),
+        token_id,
+        owner_member_id,
+        spender.clone(),
+        DEFAULT_TX_AMOUNT.into(),
+        expiry
+    )
+    verify {
+        assert_eq!(
+            Token::<T>::account_info_by_token_and_member(token_id, &owner_member_id)
+                .allowances_number,
+            1
+        );
+        assert_last_event::<T>(
+            RawEvent::AllowanceApproved(
+                token_id,
+                owner_member_id,
+                spender,
+                AllowanceOf::<T> { amount: DEFAULT_TX_AMOUNT.into(), expiry }
+            ).into()
+        );
+    }
+
+    // Worst case scenario:
+    // - spender is a member (member controller origin validation)
+    // - source_accout.vesting_schedules.len() is T::MaxVestingSchedulesPerAccountPerToken
+    // - source_account.split_staking_status is Some(_)
+    // - destination accounts do not exist (need to be created)
+    // - bloat_bond is non-zero
+    // - allowance is fully used (needs to be removed)
+    transfer_from {
+        let o in 1 .. MAX_TX_OUTPUTS;
+        let m in 1 .. MAX_KILOBYTES_METADATA;
+
+        let (owner_member_id, owner_account) = create_owner::<T>();
+        let spender_account = account::<T::AccountId>("spender", 0, SEED);
+        let spender_member_id = create_member::<T>(&spender_account, b"spender");
+        let outputs = Transfers::<_, _>(
+            (0..o)
+            .map(|i| {
+                let member_id = create_member::<T>(
+                    &account::<T::AccountId>("dst", i, SEED),
+                    &uniq_handle("dst_member", i)
+                );
+                (
+                    member_id,
+                    Payment::<<T as Config>::Balance> {
+                        amount: DEFAULT_TX_AMOUNT.into()
+                    }
+                )
+            })
+            .collect()
+        );
+        let bloat_bond: JoyBalanceOf<T> = T::JoyExistentialDeposit::get();
+        let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+        setup_account_with_max_number_of_locks::<T>(token_id, &owner_member_id, None);
+        let spender = AllowanceSpender::Member(spender_member_id);
+        Token::<T>::approve(
+            RawOrigin::Signed(owner_account.clone()).into(),
+            token_id,
+            owner_member_id,
+            spender.clone(),
+            (DEFAULT_TX_AMOUNT * o).into(),
+            None
+        )?;
+        BloatBond::<T>::set(bloat_bond);
+        let _ = Joy::<T>::deposit_creating(
+            &spender_account,
+            bloat_bond * o.into()
+        );
+        let metadata = vec![0xf].repeat((m * 1000) as usize);
+    }: _(
+        RawOrigin::Signed(spender_account.clone()),
+        Some(spender_member_id),
+        token_id,
+        owner_member_id,
+        outputs.clone(),
+        metadata.clone()
+    )
+    verify {
+        outputs.0.keys().for_each(|m| {
+            assert_eq!(
+                AccountInfoByTokenAndMember::<T>::get(token_id, m).amount,
+                DEFAULT_TX_AMOUNT.into()
+            );
+        });
+        assert!(Token::<T>::allowance((token_id, owner_member_id), &spender).is_none());
+        assert_last_event::<T>(
+            RawEvent::TokenAmountTransferredFrom(
+                token_id,
+                owner_member_id,
+                spender,
+                Transfers(
+                    outputs
+                        .0
+                        .iter()
+                        .map(|(m, p)| (Validated::NonExisting(*m), ValidatedPayment::from(PaymentWithVesting::from(p.clone()))))
+                        .collect()
+                ),
+                metadata
+            ).into()
+        );
+        // Ensure bloat_bond was transferred
+        assert_eq!(
+            Joy::<T>::usable_balance(&spender_account),
+            T::JoyExistentialDeposit::get()
+        );
+    }
+
+    // Worst case scenario:
+    // - wanted asset is a token (needs to be checked for existence)
+    // - account.vesting_schedules.len() is T::MaxVestingSchedulesPerAccountPerToken
+    create_swap_offer {
+        let (owner_member_id, owner_account) = create_owner::<T>();
+        let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+        let wanted_token_id = issue_token_with_symbol::<T>(
+            TransferPolicyParams::Permissionless,
+            b"CRT2"
+        )?;
+        setup_account_with_max_number_of_locks::<T>(token_id, &owner_member_id, None);
+        let offer_id = Token::<T>::next_swap_offer_id();
+        let wanted = SwapAsset::Token(wanted_token_id, DEFAULT_TX_AMOUNT.into());
+    }: _(
+        RawOrigin::Signed(owner_account.clone()),
+        token_id,
+        owner_member_id,
+        DEFAULT_TX_AMOUNT.into(),
+        wanted
+    )
+    verify {
+        assert!(Token::<T>::swap_offer_by_id(token_id, offer_id).is_some());
+        assert_eq!(Token::<T>::next_swap_offer_id(), offer_id + 1);
+        assert_last_event::<T>(
+            RawEvent::SwapOfferCreated(
+                token_id,
+                offer_id,
+                owner_member_id,
+                DEFAULT_TX_AMOUNT.into(),
+                wanted
+            ).into()
+        );
+    }
+
+    // Worst case scenario:
+    // - payment is made in tokens (transfer validation)
+    // - taker's payment token account.vesting_schedules.len() is
+    //   T::MaxVestingSchedulesPerAccountPerToken
+    // - maker's payment token account does not exist (needs to be created)
+    // - taker's offered token account does not exist (needs to be created)
+    // - bloat_bond is non-zero
+    // - offer is only partially filled (needs to be updated)
+    fill_swap_offer {
+        let (owner_member_id, owner_account) = create_owner::<T>();
+        let taker_account = account::<T::AccountId>("taker", 0, SEED);
+        let taker_member_id = create_member::<T>(&taker_account, b"taker");
+        let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+        let wanted_token_id = issue_token_with_symbol::<T>(
+            TransferPolicyParams::Permissionless,
+            b"CRT2"
+        )?;
+        // Move the payment token allocation from the owner to the taker
+        let allocation =
+            AccountInfoByTokenAndMember::<T>::take(wanted_token_id, &owner_member_id);
+        AccountInfoByTokenAndMember::<T>::insert(wanted_token_id, &taker_member_id, allocation);
+        setup_account_with_max_number_of_locks::<T>(wanted_token_id, &taker_member_id, None);
+        let offer_id = Token::<T>::next_swap_offer_id();
+        Token::<T>::create_swap_offer(
+            RawOrigin::Signed(owner_account.clone()).into(),
+            token_id,
+            owner_member_id,
+            (2 * DEFAULT_TX_AMOUNT).into(),
+            SwapAsset::Token(wanted_token_id, (2 * DEFAULT_TX_AMOUNT).into())
+        )?;
+        let bloat_bond: JoyBalanceOf<T> = T::JoyExistentialDeposit::get();
+        BloatBond::<T>::set(bloat_bond);
+        let _ = Joy::<T>::deposit_creating(
+            &taker_account,
+            bloat_bond * 2u32.into()
+        );
+    }: _(
+        RawOrigin::Signed(taker_account.clone()),
+        token_id,
+        offer_id,
+        taker_member_id,
+        DEFAULT_TX_AMOUNT.into()
+    )
+    verify {
+        assert_eq!(
+            Token::<T>::account_info_by_token_and_member(token_id, &taker_member_id).amount,
+            DEFAULT_TX_AMOUNT.into()
+        );
+        assert_eq!(
+            Token::<T>::account_info_by_token_and_member(wanted_token_id, &owner_member_id).amount,
+            DEFAULT_TX_AMOUNT.into()
+        );
+        assert!(Token::<T>::swap_offer_by_id(token_id, offer_id).is_some());
+        assert_last_event::<T>(
+            RawEvent::SwapOfferFilled(
+                token_id,
+                offer_id,
+                taker_member_id,
+                DEFAULT_TX_AMOUNT.into(),
+                SwapAsset::Token(wanted_token_id, DEFAULT_TX_AMOUNT.into())
+            ).into()
+        );
+        // Ensure bloat_bond was transferred
+        assert_eq!(
+            Joy::<T>::usable_balance(&taker_account),
+            T::JoyExistentialDeposit::get()
+        );
+    }
+
+    // Worst case scenario:
+    // - offer has tokens left in escrow
+    cancel_swap_offer {
+        let (owner_member_id, owner_account) = create_owner::<T>();
+        let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+        let offer_id = Token::<T>::next_swap_offer_id();
+        Token::<T>::create_swap_offer(
+            RawOrigin::Signed(owner_account.clone()).into(),
+            token_id,
+            owner_member_id,
+            DEFAULT_TX_AMOUNT.into(),
+            SwapAsset::Joy(DEFAULT_TX_AMOUNT.into())
+        )?;
+    }: _(
+        RawOrigin::Signed(owner_account.clone()),
+        token_id,
+        offer_id
+    )
+    verify {
+        assert!(Token::<T>::swap_offer_by_id(token_id, offer_id).is_none());
+        assert_eq!(
+            Token::<T>::account_info_by_token_and_member(token_id, &owner_member_id).amount,
+            DEFAULT_TOKEN_ISSUANCE.into()
+        );
+        assert_last_event::<T>(
+            RawEvent::SwapOfferCancelled(token_id, offer_id, DEFAULT_TX_AMOUNT.into()).into()
+        );
+    }
+
+    // Worst case scenario:
+    // - all of the `n` swap offers request JOY and are made by different members
+    // - all of the offers are filled
+    execute_buyback {
+        let n in 1 .. MAX_BUYBACK_SWAP_OFFERS;
+
+        let (owner_member_id, owner_account) = create_owner::<T>();
+        let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+        let makers = (0..n)
+            .map(|i| {
+                let maker_account = account::<T::AccountId>("maker", i, SEED);
+                let maker_member_id =
+                    create_member::<T>(&maker_account, &uniq_handle("maker", i));
+                (maker_member_id, maker_account)
+            })
+            .collect::<Vec<_>>();
+        Token::<T>::transfer(
+            RawOrigin::Signed(owner_account.clone()).into(),
+            owner_member_id,
+            token_id,
+            Transfers::<_, _>(
+                makers
+                    .iter()
+                    .map(|(member_id, _)| {
+                        (*member_id, Payment { amount: DEFAULT_TX_AMOUNT.into() })
+                    })
+                    .collect()
+            ),
+            vec![]
+        )?;
+        let unit_price: JoyBalanceOf<T> = DEFAULT_BUYBACK_MAX_UNIT_PRICE.into();
+        let payment = unit_price * DEFAULT_TX_AMOUNT.into();
+        let mut offer_ids = Vec::new();
+        for (member_id, account_id) in makers.iter() {
+            offer_ids.push(Token::<T>::next_swap_offer_id());
+            Token::<T>::create_swap_offer(
+                RawOrigin::Signed(account_id.clone()).into(),
+                token_id,
+                *member_id,
+                DEFAULT_TX_AMOUNT.into(),
+                SwapAsset::Joy(payment)
+            )?;
+        }
+        let budget = payment * n.into();
+        let _ = Joy::<T>::deposit_creating(
+            &token_owner_account::<T>(),
+            T::JoyExistentialDeposit::get() + budget
+        );
+        Token::<T>::start_buyback_program(
+            token_id,
+            token_owner_account::<T>(),
+            BuybackParamsOf::<T> {
+                budget,
+                max_unit_price: unit_price,
+                start: None,
+                duration: DEFAULT_BUYBACK_DURATION.into(),
+            }
+        )?;
+        // Release the whole budget
+        System::<T>::set_block_number(
+            System::<T>::block_number() + (DEFAULT_BUYBACK_DURATION - 1).into()
+        );
+        let supply_pre = Token::<T>::token_info_by_id(token_id).total_supply;
+    }: _(
+        RawOrigin::Signed(owner_account.clone()),
+        token_id,
+        n
+    )
+    verify {
+        assert_eq!(
+            Token::<T>::token_info_by_id(token_id).total_supply,
+            supply_pre - (DEFAULT_TX_AMOUNT * n).into()
+        );
+        assert_eq!(
+            Token::<T>::buyback_program_by_token_id(token_id).unwrap().spent,
+            budget
+        );
+        assert_last_event::<T>(
+            RawEvent::TokensBoughtBackAndBurned(
+                token_id,
+                offer_ids[(n - 1) as usize],
+                makers[(n - 1) as usize].0,
+                DEFAULT_TX_AMOUNT.into(),
+                payment
+            ).into()
+        );
+        for offer_id in offer_ids {
+            assert!(Token::<T>::swap_offer_by_id(token_id, offer_id).is_none());
+        }
+        for (_, account_id) in makers {
+            assert_eq!(
+                Joy::<T>::usable_balance(&account_id),
+                T::JoyExistentialDeposit::get() + payment
+            );
+        }
+    }
 }
 
 #[cfg(test)]
@@ -641,4 +1267,74 @@ mod tests {
             assert_ok!(Token::test_benchmark_burn());
         });
     }
+
+    #[test]
+    fn test_vote_in_token_poll() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_vote_in_token_poll());
+        });
+    }
+
+    #[test]
+    fn test_unlock_token_poll_vote() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_unlock_token_poll_vote());
+        });
+    }
+
+    #[test]
+    fn test_finalize_token_poll() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_finalize_token_poll());
+        });
+    }
+
+    #[test]
+    fn test_claim_airdrop() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_claim_airdrop());
+        });
+    }
+
+    #[test]
+    fn test_approve() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_approve());
+        });
+    }
+
+    #[test]
+    fn test_transfer_from() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_transfer_from());
+        });
+    }
+
+    #[test]
+    fn test_create_swap_offer() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_create_swap_offer());
+        });
+    }
+
+    #[test]
+    fn test_fill_swap_offer() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_fill_swap_offer());
+        });
+    }
+
+    #[test]
+    fn test_cancel_swap_offer() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_cancel_swap_offer());
+        });
+    }
+
+    #[test]
+    fn test_execute_buyback() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_execute_buyback());
+        });
+    }
 }
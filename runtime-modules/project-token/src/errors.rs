@@ -108,6 +108,12 @@ decl_error! {
         /// Token's unit price cannot be zero
         SaleUnitPriceIsZero,
 
+        /// Quantity of tokens in a sale tier cannot be zero
+        SaleTierQuantityIsZero,
+
+        /// Number of sale tiers exceeds MaxSaleTiers
+        MaxSaleTiersExceeded,
+
         /// Amount of tokens to purchase on sale cannot be zero
         SalePurchaseAmountIsZero,
 
@@ -159,5 +165,168 @@ decl_error! {
 
         /// Amount of tokens to burn exceeds total amount of tokens owned by the account
         BurnAmountGreaterThanAccountTokensAmount,
+
+        // ------ Polls --------------------------------------------------------
+
+        /// Attempt to issue a poll while the previous one is not finalized
+        PreviousTokenPollNotFinalized,
+
+        /// Number of poll alternatives must be in [2, MaxPollAlternatives]
+        InvalidNumberOfTokenPollAlternatives,
+
+        /// Poll duration cannot be zero
+        TokenPollDurationIsZero,
+
+        /// Poll starting block is in the past
+        TokenPollStartInThePast,
+
+        /// Token has no poll
+        NoTokenPoll,
+
+        /// Poll exists, but voting is not ongoing
+        TokenPollNotOngoing,
+
+        /// Poll voting period has not ended yet
+        TokenPollDidNotEnd,
+
+        /// Poll results are already finalized
+        TokenPollAlreadyFinalized,
+
+        /// Alternative index out of the poll alternatives range
+        InvalidTokenPollAlternative,
+
+        /// Attempt to vote with zero tokens
+        TokenPollVoteAmountIsZero,
+
+        /// User already voted in the current poll
+        UserAlreadyVotedInTokenPoll,
+
+        /// User does not posses enough balance to vote with the provided amount
+        InsufficientBalanceForTokenPollVote,
+
+        /// User is not voting in any poll
+        UserNotVotingInAnyTokenPoll,
+
+        // ------ Airdrops -----------------------------------------------------
+
+        /// Attempt to issue an airdrop with zero tokens
+        AirdropAmountIsZero,
+
+        /// Airdrop expiry block must be in the future
+        AirdropExpiryInThePast,
+
+        /// Airdrop does not exist
+        AirdropDoesNotExist,
+
+        /// Airdrop can no longer be claimed
+        AirdropExpired,
+
+        /// Airdrop has not expired yet
+        AirdropNotExpired,
+
+        /// Member already claimed the airdrop
+        AirdropAlreadyClaimed,
+
+        /// Attempt to claim zero tokens from an airdrop
+        AirdropClaimAmountIsZero,
+
+        /// Airdrop has not enough tokens left to cover the claim
+        InsufficientAirdropFunds,
+
+        // ------ Allowances ---------------------------------------------------
+
+        /// Allowance expiry block must be in the future
+        AllowanceExpiryInThePast,
+
+        /// Spender has no allowance for the account
+        AllowanceDoesNotExist,
+
+        /// Spender's allowance has expired
+        AllowanceExpired,
+
+        /// Spender's allowance is lower than the amount to transfer
+        InsufficientAllowance,
+
+        /// Account reached the maximum number of allowances it can grant
+        MaxAllowancesPerAccountReached,
+
+        // ------ Compliance ---------------------------------------------------
+
+        /// Account is frozen
+        AccountFrozen,
+
+        /// Token is paused
+        TokenPaused,
+
+        // ------ Swap offers --------------------------------------------------
+
+        /// Attempt to offer zero tokens in a swap offer
+        SwapOfferAmountIsZero,
+
+        /// Attempt to request a zero amount in exchange for the offered tokens
+        SwapOfferWantedAmountIsZero,
+
+        /// Requested token must be different from the offered one
+        SwapOfferWantedTokenIsOfferedToken,
+
+        /// Swap offer does not exist
+        SwapOfferDoesNotExist,
+
+        /// Attempt to fill zero tokens of a swap offer
+        SwapOfferFillAmountIsZero,
+
+        /// Swap offer has not enough tokens left to cover the fill
+        InsufficientSwapOfferAmountLeft,
+
+        /// Swap offer cannot be filled by its maker
+        SwapOfferFilledByMaker,
+
+        // ------ Buyback programs ---------------------------------------------
+
+        /// Token already has a buyback program which was not finalized
+        BuybackProgramAlreadyExists,
+
+        /// Attempt to start a buyback program with zero budget
+        BuybackBudgetIsZero,
+
+        /// Attempt to start a buyback program with zero max unit price
+        BuybackMaxUnitPriceIsZero,
+
+        /// Attempt to start a buyback program with zero duration
+        BuybackDurationIsZero,
+
+        /// Buyback program starting block is in the past
+        BuybackStartInThePast,
+
+        /// Token has no buyback program
+        BuybackProgramDoesNotExist,
+
+        /// Buyback program did not start yet or has already ended
+        BuybackProgramNotOngoing,
+
+        /// Attempt to finalize a buyback program which did not end yet
+        BuybackProgramNotEnded,
+
+        /// Token has more swap offers than the `swap_offers_number` provided
+        SwapOffersNumberExceeded,
+
+        /// Buyback program's budget released so far cannot buy any of the tokens offered
+        /// for JOY at or below its max unit price
+        NoSwapOffersToBuyBack,
+
+        /// Cannot deissue a token with an unfinalized buyback program
+        CannotDeissueTokenWithUnfinalizedBuybackProgram,
+
+        /// Cannot deissue a token with pending (not recovered) airdrops
+        CannotDeissueTokenWithPendingAirdrops,
+
+        /// Cannot deissue a token with open swap offers
+        CannotDeissueTokenWithOpenSwapOffers,
+
+        /// Account held no tokens throughout the time-weighted revenue split eligibility window
+        RevenueSplitEligibleAmountIsZero,
+
+        /// Attempt to enable time-weighted revenue splits with zero lookback
+        TimeWeightedSplitsLookbackIsZero,
     }
 }
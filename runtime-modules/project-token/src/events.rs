@@ -1,7 +1,9 @@
 #![allow(clippy::unused_unit)]
 
 use crate::types::{
-    JoyBalanceOf, RevenueSplitId, TokenIssuanceParametersOf, TokenSaleId, TokenSaleOf,
+    AirdropId, AirdropParamsOf, AllowanceOf, AllowanceSpenderOf, BuybackProgramOf, JoyBalanceOf,
+    RevenueSplitId, SwapAssetOf, SwapOfferId, TimeWeightedSplitsSettingsOf,
+    TokenIssuanceParametersOf, TokenPollId, TokenPollParamsOf, TokenSaleId, TokenSaleOf,
     TransferPolicyOf, ValidatedTransfersOf,
 };
 use common::MembershipTypes;
@@ -22,6 +24,13 @@ decl_event! {
         TokenIssuanceParameters = TokenIssuanceParametersOf<T>,
         ValidatedTransfers = ValidatedTransfersOf<T>,
         TokenSale = TokenSaleOf<T>,
+        TokenPollParams = TokenPollParamsOf<T>,
+        AirdropParams = AirdropParamsOf<T>,
+        AllowanceSpender = AllowanceSpenderOf<T>,
+        Allowance = AllowanceOf<T>,
+        SwapAsset = SwapAssetOf<T>,
+        BuybackProgram = BuybackProgramOf<T>,
+        TimeWeightedSplitsSettings = TimeWeightedSplitsSettingsOf<T>,
 
     {
         /// Token amount is transferred from src to dst
@@ -155,5 +164,141 @@ decl_event! {
         /// - member id
         /// - number of tokens burned
         TokensBurned(TokenId, MemberId, Balance),
+
+        /// Token holder poll issued
+        /// Params:
+        /// - token id
+        /// - poll id
+        /// - poll parameters
+        TokenPollIssued(TokenId, TokenPollId, TokenPollParams),
+
+        /// User voted in a token holder poll
+        /// Params:
+        /// - token id
+        /// - member id
+        /// - poll id
+        /// - alternative voted for
+        /// - amount of tokens locked for the vote
+        VotedInTokenPoll(TokenId, MemberId, TokenPollId, u32, Balance),
+
+        /// Tokens locked for a poll vote unlocked
+        /// Params:
+        /// - token id
+        /// - member id
+        /// - amount of tokens unlocked
+        TokenPollTokensUnlocked(TokenId, MemberId, Balance),
+
+        /// Token holder poll finalized
+        /// Params:
+        /// - token id
+        /// - poll id
+        /// - winning alternative (None in case of a tie or no votes)
+        TokenPollFinalized(TokenId, TokenPollId, Option<u32>),
+
+        /// Airdrop issued
+        /// Params:
+        /// - token id
+        /// - airdrop id
+        /// - member id of the tokens source
+        /// - airdrop parameters
+        AirdropIssued(TokenId, AirdropId, MemberId, AirdropParams),
+
+        /// Airdrop claimed
+        /// Params:
+        /// - token id
+        /// - airdrop id
+        /// - claimer's member id
+        /// - amount of tokens claimed
+        AirdropClaimed(TokenId, AirdropId, MemberId, Balance),
+
+        /// Unclaimed airdrop tokens recovered by the tokens source
+        /// Params:
+        /// - token id
+        /// - airdrop id
+        /// - amount of tokens recovered
+        UnclaimedAirdropRecovered(TokenId, AirdropId, Balance),
+
+        /// Spending allowance approved (revoked in case the allowance amount is 0)
+        /// Params:
+        /// - token id
+        /// - member id of the account owner
+        /// - spender
+        /// - allowance
+        AllowanceApproved(TokenId, MemberId, AllowanceSpender, Allowance),
+
+        /// Token amount transferred by a spender on behalf of the account owner
+        /// Params:
+        /// - token id
+        /// - member id of the source account owner
+        /// - spender
+        /// - map containing validated outputs (amount indexed by (member_id + account existance))
+        /// - transfer's metadata
+        TokenAmountTransferredFrom(TokenId, MemberId, AllowanceSpender, ValidatedTransfers, Vec<u8>),
+
+        /// Account frozen status updated
+        /// Params:
+        /// - token id
+        /// - member id
+        /// - whether the account is frozen
+        AccountFrozenStatusUpdated(TokenId, MemberId, bool),
+
+        /// Token paused status updated
+        /// Params:
+        /// - token id
+        /// - whether the token is paused
+        TokenPausedStatusUpdated(TokenId, bool),
+
+        /// Swap offer created
+        /// Params:
+        /// - token id of the offered tokens
+        /// - swap offer id
+        /// - maker's member id
+        /// - amount of tokens offered (held in escrow)
+        /// - asset requested in exchange
+        SwapOfferCreated(TokenId, SwapOfferId, MemberId, Balance, SwapAsset),
+
+        /// Swap offer (partially) filled
+        /// Params:
+        /// - token id of the offered tokens
+        /// - swap offer id
+        /// - taker's member id
+        /// - amount of offered tokens bought
+        /// - asset paid to the maker
+        SwapOfferFilled(TokenId, SwapOfferId, MemberId, Balance, SwapAsset),
+
+        /// Swap offer cancelled by the maker
+        /// Params:
+        /// - token id of the offered tokens
+        /// - swap offer id
+        /// - amount of tokens refunded from escrow
+        SwapOfferCancelled(TokenId, SwapOfferId, Balance),
+
+        /// Buyback program started
+        /// Params:
+        /// - token identifier
+        /// - buyback program
+        BuybackProgramStarted(TokenId, BuybackProgram),
+
+        /// Tokens offered in a swap offer bought back by the buyback program and burned
+        /// Params:
+        /// - token identifier
+        /// - swap offer id
+        /// - member id of the offer's maker
+        /// - number of tokens burned
+        /// - JOY paid to the maker
+        TokensBoughtBackAndBurned(TokenId, SwapOfferId, MemberId, Balance, JoyBalance),
+
+        /// Buyback program finalized
+        /// Params:
+        /// - token identifier
+        /// - recovery account for the unspent budget
+        /// - unspent budget
+        BuybackProgramFinalized(TokenId, AccountId, JoyBalance),
+
+        /// Time-weighted revenue splits settings updated
+        /// Params:
+        /// - token identifier
+        /// - new settings (`None` if time-weighted revenue splits were disabled)
+        TimeWeightedSplitsUpdated(TokenId, Option<TimeWeightedSplitsSettings>),
     }
 }
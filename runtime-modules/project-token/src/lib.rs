@@ -29,6 +29,7 @@ use frame_support::{
     decl_module, decl_storage,
     dispatch::{fmt::Debug, marker::Copy, DispatchError, DispatchResult},
     ensure,
+    storage::IterableStorageDoubleMap,
     traits::{Currency, ExistenceRequirement, Get},
     PalletId,
 };
@@ -50,6 +51,8 @@ use storage::UploadParameters;
 mod benchmarking;
 mod errors;
 mod events;
+mod migrations;
+pub mod runtime_api;
 mod tests;
 pub mod traits;
 pub mod types;
@@ -130,6 +133,19 @@ pub trait Config:
 
     /// Membership info provider
     type MembershipInfoProvider: MembershipInfoProvider<Self>;
+
+    /// Maximum number of alternatives in a token holder poll
+    type MaxPollAlternatives: Get<u32>;
+
+    /// Maximum number of spending allowances an account can grant
+    type MaxAllowancesPerAccount: Get<u32>;
+
+    /// Maximum number of price tiers in a token sale
+    type MaxSaleTiers: Get<u32>;
+
+    /// Maximum number of balance checkpoints tracked per account
+    /// (used by time-weighted revenue splits)
+    type MaxBalanceCheckpoints: Get<u32>;
 }
 
 decl_storage! { generate_storage_info
@@ -166,6 +182,45 @@ decl_storage! { generate_storage_info
 
         /// Platform fee (percentage) charged on top of each sale purchase (in JOY) and burned
         pub SalePlatformFee get(fn sale_platform_fee) config(): Permill;
+
+        /// map TokenId => TokenPoll for the latest token holder poll of the token
+        pub TokenPollByTokenId get(fn token_poll_by_token_id):
+        map
+            hasher(blake2_128_concat) T::TokenId => Option<TokenPollOf<T>>;
+
+        /// Airdrop Id nonce
+        pub NextAirdropId get(fn next_airdrop_id): AirdropId;
+
+        /// Double map TokenId x AirdropId => Airdrop
+        pub AirdropById get(fn airdrop_by_id):
+        double_map
+            hasher(blake2_128_concat) T::TokenId,
+            hasher(blake2_128_concat) AirdropId => Option<AirdropOf<T>>;
+
+        /// Double map AirdropId x MemberId => whether the member claimed the airdrop
+        pub AirdropClaimed get(fn airdrop_claimed):
+        double_map
+            hasher(blake2_128_concat) AirdropId,
+            hasher(blake2_128_concat) T::MemberId => bool;
+
+        /// Double map (TokenId, MemberId) x AllowanceSpender => Allowance
+        pub AllowanceByTokenAndMember get(fn allowance):
+        double_map
+            hasher(blake2_128_concat) (T::TokenId, T::MemberId),
+            hasher(blake2_128_concat) AllowanceSpenderOf<T> => Option<AllowanceOf<T>>;
+
+        /// Swap offer Id nonce
+        pub NextSwapOfferId get(fn next_swap_offer_id): SwapOfferId;
+
+        /// Double map TokenId (of the offered tokens) x SwapOfferId => SwapOffer
+        pub SwapOfferById get(fn swap_offer_by_id):
+        double_map
+            hasher(blake2_128_concat) T::TokenId,
+            hasher(blake2_128_concat) SwapOfferId => Option<SwapOfferOf<T>>;
+
+        /// Map TokenId => BuybackProgram (unfinalized buyback program of the token)
+        pub BuybackProgramByTokenId get(fn buyback_program_by_token_id):
+        map hasher(blake2_128_concat) T::TokenId => Option<BuybackProgramOf<T>>;
     }
 
     add_extra_genesis {
@@ -216,7 +271,9 @@ decl_module! {
         /// Preconditions:
         /// - origin signer must be `src_member_id` controller account
         /// - token by `token_id` must exists
+        /// - token by `token_id` must not be paused
         /// - account of `src_member_id` must exist for `token_id`
+        /// - account of `src_member_id` must not be frozen
         /// - sender must have enough JOYs to cover the total bloat bond required in case of
         ///   destination(s) not existing.
         /// - source account must have enough token funds to cover all the transfer(s)
@@ -272,7 +329,9 @@ decl_module! {
         /// - `amount` is > 0
         /// - origin signer is a controller account of `member_id` member
         /// - token by `token_id` exists
+        /// - token by `token_id` is not paused
         /// - an account exists for `token_id` x `member_id`
+        /// - `token_id` x `member_id` account is not frozen
         /// - account's tokens amount is >= `amount`
         /// - token supply can be modified (there is no active revenue split)
         ///
@@ -310,11 +369,13 @@ decl_module! {
                 member_id
             )?;
 
-            // Ensure token exists
+            // Ensure token exists and is not paused
             let token_info = Self::ensure_token_exists(token_id)?;
+            token_info.ensure_not_paused::<T>()?;
 
-            // Ensure token account data exists by `token_id` x `member_id`
+            // Ensure token account data exists by `token_id` x `member_id` and is not frozen
             let account_info = Self::ensure_account_data_exists(token_id, &member_id)?;
+            account_info.ensure_not_frozen::<T>()?;
 
             // Ensure burn amount doesn't exceed account's tokens amount
             ensure!(
@@ -330,6 +391,11 @@ decl_module! {
 
             // Burn tokens from the account
             AccountInfoByTokenAndMember::<T>::try_mutate(token_id, member_id, |account| {
+                account.checkpoint_balance(
+                    account.amount,
+                    now,
+                    token_info.balance_checkpoints_relevant_since(now)
+                );
                 account.burn::<T>(amount, now)?;
                 DispatchResult::Ok(())
             })?;
@@ -355,6 +421,7 @@ decl_module! {
         ///   (`account_data.amount` == 0)
         /// Postconditions:
         /// - Account information for `token_id` x `member_id` removed from storage
+        /// - allowances granted by the account removed from storage
         /// - bloat bond refunded to `member_id` controller account
         ///   (or `bloat_bond.repayment_restricted_to` account)
         ///
@@ -362,7 +429,7 @@ decl_module! {
         ///
         /// `O (1)`
         /// - DB:
-        ///   - `O(1)` - doesn't depend on the state or parameters
+        ///   - `O(1)` - the number of removed allowances is bounded by `MaxAllowancesPerAccount`
         /// # </weight>
         #[weight = WeightInfoToken::<T>::dust_account()]
         pub fn dust_account(origin, token_id: T::TokenId, member_id: T::MemberId) -> DispatchResult {
@@ -382,6 +449,13 @@ decl_module! {
             // == MUTATION SAFE ==
             AccountInfoByTokenAndMember::<T>::remove(token_id, &member_id);
 
+            if account_to_remove_info.allowances_number > 0 {
+                AllowanceByTokenAndMember::<T>::remove_prefix(
+                    (token_id, member_id),
+                    Some(T::MaxAllowancesPerAccount::get())
+                );
+            }
+
             TokenInfoById::<T>::mutate(token_id, |token_info| {
                 token_info.decrement_accounts_number();
             });
@@ -463,53 +537,57 @@ decl_module! {
         ///
         /// Preconditions:
         /// - token by `token_id` must exist
+        /// - token by `token_id` must not be paused
         /// - token by `token_id` must be in OfferingState::Sale
         /// - `amount` cannot exceed number of tokens remaining on sale
         /// - `origin` signer must be controller account of `member_id` member
+        /// - let `joy_amount` be the cost of `amount` tokens purchased from the sale tiers
+        ///   filled sequentially (each part priced at its tier's `unit_price`)
         /// - sender's available JOY balance must be:
-        ///   - >= `joy_existential_deposit + amount * sale.unit_price`
+        ///   - >= `joy_existential_deposit + joy_amount`
         ///     if AccountData already exist
-        ///   - >= `joy_existential_deposit + amount * sale.unit_price + bloat_bond`
+        ///   - >= `joy_existential_deposit + joy_amount + bloat_bond`
         ///     if AccountData does not exist
-        /// - let `fee_amount` be `sale_platform_fee.mul_floor(amount * sale.unit_price)`
+        /// - let `fee_amount` be `sale_platform_fee.mul_floor(joy_amount)`
         /// - if `sale.earnings_destination.is_some()` and `sale.earnings_destination` account has
         ///   zero balance:
         ///   - the amount to be transferred from `sender` to `sale.earnings_destination`,
-        ///     which is equal to `amount * sale.unit_price - fee_amount`, must be greater than
+        ///     which is equal to `joy_amount - fee_amount`, must be greater than
         ///     `joy_existential_deposit`
         /// - total number of tokens already purchased by the member on the current sale
         ///   PLUS `amount` must not exceed sale's purchase cap per member
         /// - if Permissioned token:
         ///   - AccountInfoByTokenAndMember(token_id, &member_id) must exist
-        /// - if `sale.vesting_schedule.is_some()`:
-        ///   - number of sender account's ongoing vesting schedules
-        ///     must be < MaxVestingSchedulesPerAccountPerToken
+        /// - AccountInfoByTokenAndMember(token_id, &member_id) (if exists) must not be frozen
+        /// - if any part of the purchase is subject to vesting:
+        ///   - number of sender account's ongoing vesting schedules (including the new ones)
+        ///     must be <= MaxVestingSchedulesPerAccountPerToken
         ///
         /// Postconditions:
         /// - if `sale.earnings_destination.is_some()`:
-        ///   - `amount * sale.unit_price - fee_amount` JOY tokens are transfered from `sender`
+        ///   - `joy_amount - fee_amount` JOY tokens are transfered from `sender`
         ///     to `sale.earnings_destination`
         ///   - `fee_amount` JOY is slashed from `sender` balance
         /// - if `sale.earnings_destination.is_none()`:
-        ///   - `amount * sale.unit_price` JOY is slashed from `sender` balance
+        ///   - `joy_amount` JOY is slashed from `sender` balance
         /// - if new token account created: `bloat_bond` transferred from `sender` to treasury
-        /// - if `sale.vesting_schedule.is_some()`:
-        ///   - if buyer has no `vesting_schedule` related to the current sale:
-        ///     - a new vesting schedule (`sale.get_vesting_schedule(purchase_amount)`) is added to
-        ///       buyer's `vesing_schedules`
+        /// - for each vesting source of the purchased tokens (`VestingSource::SaleTier` for tiers
+        ///   with own `vesting_schedule_params`, `VestingSource::Sale` for the remaining tiers
+        ///   if `sale.vesting_schedule_params.is_some()`):
+        ///   - if buyer has no `vesting_schedule` related to the source:
+        ///     - a new vesting schedule (constructed from the params for the amount purchased
+        ///       under the source) is added to buyer's `vesing_schedules`
         ///     - some finished vesting schedule is removed from buyer's account_data in case the
         ///       number of buyer's vesting_schedules was == MaxVestingSchedulesPerAccountPerToken
-        ///   - if buyer already has a `vesting_schedule` related to the current sale:
-        ///     - current vesting schedule's `cliff_amount` is increased by
-        ///       `sale.get_vesting_schedule(purchase_amount).cliff_amount`
-        ///     - current vesting schedule's `post_cliff_total_amount` is increased by
-        ///       `sale.get_vesting_schedule(purchase_amount).post_cliff_total_amount`
-        /// - if `sale.vesting_schedule.is_none()`:
-        ///   - buyer's account token amount increased by `amount`
+        ///   - if buyer already has a `vesting_schedule` related to the source:
+        ///     - current vesting schedule's `cliff_amount` and `post_cliff_total_amount`
+        ///       are increased accordingly
+        /// - buyer's account token amount increased by `amount`
         /// - if `token_data.sale.quantity_left - amount == 0` and `sale.auto_finalize` is `true`
-        ///   `token_data.sale` is set to None, otherwise `token_data.sale.quantity_left` is
-        ///   decreased by `amount` and `token_data.sale.funds_collected` in increased by
-        ///   `amount * sale.unit_price`
+        ///   `token_data.sale` is set to None, otherwise `token_data.sale.quantity_left`
+        ///   (and `quantity_left` of the affected tiers) is decreased by `amount`,
+        ///   `token_data.sale.funds_collected` in increased by `joy_amount` and
+        ///   `token_data.sale.unit_price` is set to the price of the currently active tier
         ///
         /// <weight>
         ///
@@ -534,12 +612,17 @@ decl_module! {
             )?;
             let current_block = Self::current_block();
             let token_data = Self::ensure_token_exists(token_id)?;
+            token_data.ensure_not_paused::<T>()?;
             let sale = OfferingStateOf::<T>::ensure_sale_of::<T>(&token_data)?;
             let sale_id = token_data.next_sale_id
                 .checked_sub(1)
                 .ok_or(Error::<T>::ArithmeticError)?;
             let platform_fee = Self::sale_platform_fee();
-            let joy_amount = sale.unit_price.saturating_mul(amount.into());
+            // Ensure enough tokens are available on sale
+            let purchases = sale.split_purchase::<T>(sale_id, amount)?;
+            let joy_amount = purchases
+                .iter()
+                .fold(JoyBalanceOf::<T>::zero(), |acc, p| acc.saturating_add(p.cost));
             let burn_amount = if sale.earnings_destination.is_some() {
                 platform_fee.mul_floor(joy_amount)
             } else {
@@ -547,8 +630,11 @@ decl_module! {
             };
             let transfer_amount = joy_amount.saturating_sub(burn_amount);
             let account_data = Self::ensure_account_data_exists(token_id, &member_id).ok();
+            if let Some(account_data) = account_data.as_ref() {
+                account_data.ensure_not_frozen::<T>()?;
+            }
             let bloat_bond = Self::bloat_bond();
-            let vesting_schedule = sale.get_vesting_schedule(amount);
+            let vesting_schedules = sale.get_purchase_vesting_schedules(&purchases);
             let treasury = Self::module_treasury_account();
 
             // Ensure buyer can cover the total cost of the transaction
@@ -562,12 +648,6 @@ decl_module! {
                 Error::<T>::InsufficientJoyBalance
             );
 
-            // Ensure enough tokens are available on sale
-            ensure!(
-                sale.quantity_left >= amount,
-                Error::<T>::NotEnoughTokensOnSale
-            );
-
             // Ensure participant's cap is not exceeded
             if let Some(cap) = sale.cap_per_member {
                 Self::ensure_purchase_cap_not_exceeded(
@@ -583,17 +663,27 @@ decl_module! {
                 ensure!(account_data.is_some(), Error::<T>::AccountInformationDoesNotExist);
             }
 
-            let vesting_cleanup_key = if vesting_schedule.is_some() {
-                    // Ensure vesting schedule can added if doesn't already exist
-                    // (MaxVestingSchedulesPerAccountPerToken not exceeded)
-                    let acc_data = AccountInfoByTokenAndMember::<T>::get(token_id, &member_id);
-                    acc_data.ensure_can_add_or_update_vesting_schedule::<T>(
-                        current_block,
-                        VestingSource::Sale(sale_id)
-                    )
-                } else {
-                    Ok(None)
-                }?;
+            // Ensure vesting schedules can be added if they don't already exist
+            // (MaxVestingSchedulesPerAccountPerToken not exceeded)
+            let mut updated_account_data = account_data.clone().unwrap_or_else(|| {
+                AccountDataOf::<T>::new_with_amount_and_bond(
+                    TokenBalanceOf::<T>::zero(),
+                    // No restrictions on repayable bloat bond,
+                    // since only usable balance is allowed
+                    RepayableBloatBond::new(bloat_bond, None)
+                )
+            });
+            updated_account_data.checkpoint_balance(
+                updated_account_data.amount,
+                current_block,
+                token_data.balance_checkpoints_relevant_since(current_block)
+            );
+            updated_account_data.process_sale_purchase::<T>(
+                sale_id,
+                amount,
+                vesting_schedules,
+                current_block
+            )?;
 
             // == MUTATION SAFE ==
 
@@ -606,33 +696,13 @@ decl_module! {
             }
 
             if account_data.is_some() {
-                AccountInfoByTokenAndMember::<T>::try_mutate(token_id, &member_id, |acc_data| {
-                    acc_data.process_sale_purchase::<T>(
-                        sale_id,
-                        amount,
-                        vesting_schedule,
-                        vesting_cleanup_key
-                    )?;
-                    DispatchResult::Ok(())
-                })?;
+                AccountInfoByTokenAndMember::<T>::insert(token_id, &member_id, updated_account_data);
             } else {
                 Self::transfer_joy(&sender, &treasury, bloat_bond)?;
-                let mut account = AccountDataOf::<T>::new_with_amount_and_bond(
-                        TokenBalanceOf::<T>::zero(),
-                        // No restrictions on repayable bloat bond,
-                        // since only usable balance is allowed
-                        RepayableBloatBond::new(bloat_bond, None)
-                    );
-                account.process_sale_purchase::<T>(
-                        sale_id,
-                        amount,
-                        vesting_schedule,
-                        vesting_cleanup_key
-                    )?;
                 Self::do_insert_new_account_for_token(
                     token_id,
                     &member_id,
-                    account
+                    updated_account_data
                 );
             }
 
@@ -641,8 +711,7 @@ decl_module! {
                 if updated_sale_quantity.is_zero() && sale.auto_finalize {
                     t.sale = None;
                 } else if let Some(s) = t.sale.as_mut() {
-                    s.quantity_left = updated_sale_quantity;
-                    s.funds_collected = s.funds_collected.saturating_add(joy_amount);
+                    s.process_purchase(&purchases);
                 }
             });
 
@@ -654,14 +723,19 @@ decl_module! {
         /// Participate in the *latest* token revenue split (if ongoing)
         /// Preconditions:
         /// - `token` must exist for `token_id`
+        /// - `token` must not be paused
         /// - `origin` signer must be `member_id` member controller account
         /// - `amount` must be > 0
         /// - `account` must exist  for `(token_id, member_id)`
+        /// - `account` must not be frozen
         /// - `token.split_status` must be active AND THEN current_block in
         ///    [split.start, split.start + split_duration)
         /// - `account.staking_status.is_none()` OR `account.staking_status.split_id` refers to a past split
         /// - `account.amount` >= `amount`
-        /// - let `dividend = split_allocation * account.staked_amount / token.supply``
+        /// - let `eligible_amount` be `amount` capped (for time-weighted splits) by the account's
+        ///    time-weighted average balance over `split.eligibility_window`, then
+        ///    `eligible_amount` must be > 0
+        /// - let `dividend = split_allocation * eligible_amount / token.supply``
         ///    then `treasury` must be able to transfer `dividend` amount of JOY.
         ///    (This condition technically, should always be satisfied)
         ///
@@ -695,6 +769,7 @@ decl_module! {
             );
 
             let token_info = Self::ensure_token_exists(token_id)?;
+            token_info.ensure_not_paused::<T>()?;
             let split_info = token_info.revenue_split.ensure_active::<T>()?;
             let split_id = token_info.next_revenue_split_id
                 .checked_sub(1)
@@ -707,13 +782,25 @@ decl_module! {
             );
 
             let account_info = Self::ensure_account_data_exists(token_id, &member_id)?;
+            account_info.ensure_not_frozen::<T>()?;
 
             account_info.ensure_can_stake::<T>(amount, token_info.next_revenue_split_id)?;
 
+            // Only the tokens held throughout the eligibility window count towards the dividend
+            // of a time-weighted split
+            let eligible_amount = split_info.eligible_amount(
+                amount,
+                |window| account_info.time_weighted_balance(window)
+            );
+            ensure!(
+                !eligible_amount.is_zero(),
+                Error::<T>::RevenueSplitEligibleAmountIsZero
+            );
+
             // it should not really be possible to have supply == 0 with staked amount > 0
             debug_assert!(!token_info.total_supply.is_zero());
             let dividend_amount = Self::compute_revenue_split_dividend(
-                amount,
+                eligible_amount,
                 token_info.total_supply,
                 split_info.allocation,
             );
@@ -803,146 +890,963 @@ decl_module! {
             Self::deposit_event(RawEvent::RevenueSplitLeft(token_id, member_id, staking_info.amount));
             Ok(())
         }
-    }
-}
 
-impl<T: Config>
-    PalletToken<
-        T::TokenId,
-        T::MemberId,
-        <T as frame_system::Config>::AccountId,
-        JoyBalanceOf<T>,
-        TokenIssuanceParametersOf<T>,
-        T::BlockNumber,
-        TokenSaleParamsOf<T>,
-        UploadContextOf<T>,
-        TransfersWithVestingOf<T>,
-    > for Module<T>
-{
-    /// Establish whether there's an unfinalized revenue split
-    /// Postconditions: true if token @ token_id has an unfinalized revenue split, false otherwise
-    fn is_revenue_split_inactive(token_id: T::TokenId) -> bool {
-        if let Ok(token_info) = Self::ensure_token_exists(token_id) {
-            return token_info.revenue_split.ensure_inactive::<T>().is_ok();
-        }
-        true
-    }
+        /// Vote in the current token holder poll by locking tokens
+        ///
+        /// Preconditions
+        /// - `amount` must be > 0
+        /// - `origin` signer must be `member_id` member controller account
+        /// - `token` must exist for `token_id`
+        /// - token must have a poll AND current_block in [poll.start, poll.start + duration)
+        /// - `alternative` must be < number of poll alternatives
+        /// - `account` must exist for `(token_id, member_id)`
+        /// - `account.poll_vote.is_none()` OR `account.poll_vote.poll_id` refers to a past poll
+        /// - `account.amount` >= `amount`
+        ///
+        /// Postconditions
+        /// - poll tally of `alternative` increased by `amount`
+        /// - `account.poll_vote` set to Some(..) with `amount`, `alternative` and `poll.poll_id`
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///   - `O(1)` - doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoToken::<T>::vote_in_token_poll()]
+        pub fn vote_in_token_poll(
+            origin,
+            token_id: T::TokenId,
+            member_id: T::MemberId,
+            alternative: u32,
+            amount: TokenBalanceOf<T>,
+        ) -> DispatchResult {
+            T::MemberOriginValidator::ensure_member_controller_account_origin(
+                origin,
+                member_id
+            )?;
 
-    /// Establish whether there is an unfinalized token sale
-    /// Postconditions: true if token @ token_id has an unfinalized sale, false otherwise
-    fn is_sale_unscheduled(token_id: T::TokenId) -> bool {
-        if let Ok(token_info) = Self::ensure_token_exists(token_id) {
-            return token_info.sale.is_none();
-        }
-        true
-    }
+            ensure!(
+                !amount.is_zero(),
+                Error::<T>::TokenPollVoteAmountIsZero,
+            );
 
-    /// Change to permissionless
-    /// Preconditions:
-    /// - token by `token_id` must exist
-    /// Postconditions
-    /// - transfer policy of `token_id` changed to permissionless
-    fn change_to_permissionless(token_id: T::TokenId) -> DispatchResult {
-        Self::ensure_token_exists(token_id).map(|_| ())?;
+            Self::ensure_token_exists(token_id)?;
+            let poll = Self::ensure_token_poll_exists(token_id)?;
+            poll.ensure_can_vote::<T>(Self::current_block(), alternative)?;
 
-        // == MUTATION SAFE ==
+            let account_info = Self::ensure_account_data_exists(token_id, &member_id)?;
+            account_info.ensure_can_vote_in_poll::<T>(amount, poll.poll_id)?;
 
-        TokenInfoById::<T>::mutate(token_id, |token_info| {
-            token_info.transfer_policy = TransferPolicyOf::<T>::Permissionless;
-        });
+            // == MUTATION SAFE ==
 
-        Self::deposit_event(RawEvent::TransferPolicyChangedToPermissionless(token_id));
-        Ok(())
-    }
+            TokenPollByTokenId::<T>::mutate(token_id, |poll| {
+                if let Some(poll) = poll.as_mut() {
+                    poll.account_for_vote(alternative, amount);
+                }
+            });
 
-    /// Reduce patronage rate by amount
-    /// Preconditions:
-    /// - token by `token_id` must exists
-    /// - `decrement` must be less or equal than current patronage rate for `token_id`
-    ///
-    /// Postconditions:
-    /// - patronage rate for `token_id` reduced by `decrement`
-    /// - no-op if `target_rate` is equal to the current patronage rate
-    fn reduce_patronage_rate_to(token_id: T::TokenId, target_rate: YearlyRate) -> DispatchResult {
-        let token_info = Self::ensure_token_exists(token_id)?;
-        let target_rate_per_block =
-            BlockRate::from_yearly_rate(target_rate, T::BlocksPerYear::get());
+            AccountInfoByTokenAndMember::<T>::mutate(token_id, &member_id, |account_info| {
+                account_info.lock_for_poll(poll.poll_id, alternative, amount);
+            });
 
-        if token_info.patronage_info.rate == target_rate_per_block {
-            return Ok(());
+            Self::deposit_event(RawEvent::VotedInTokenPoll(
+                token_id,
+                member_id,
+                poll.poll_id,
+                alternative,
+                amount,
+            ));
+
+            Ok(())
         }
 
-        ensure!(
-            token_info.patronage_info.rate > target_rate_per_block,
-            Error::<T>::TargetPatronageRateIsHigherThanCurrentRate,
-        );
+        /// Unlock tokens locked for a token holder poll vote
+        ///
+        /// Preconditions
+        /// - `origin` signer must be `member_id` member controller account
+        /// - `token` must exist for `token_id`
+        /// - `account` must exist for `(token_id, member_id)`
+        /// - `account.poll_vote.is_some()`
+        /// - if `account.poll_vote.poll_id` refers to the token's latest poll, then
+        ///   the poll voting period must be ended
+        ///
+        /// Postconditions
+        /// - `account.poll_vote` set to None
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///   - `O(1)` - doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoToken::<T>::unlock_token_poll_vote()]
+        pub fn unlock_token_poll_vote(origin, token_id: T::TokenId, member_id: T::MemberId) -> DispatchResult {
+            T::MemberOriginValidator::ensure_member_controller_account_origin(
+                origin,
+                member_id
+            )?;
 
-        // == MUTATION SAFE ==
+            Self::ensure_token_exists(token_id)?;
 
-        let now = Self::current_block();
-        TokenInfoById::<T>::mutate(token_id, |token_info| {
-            token_info.set_new_patronage_rate_at_block(target_rate_per_block, now);
-        });
+            let account_info = Self::ensure_account_data_exists(token_id, &member_id)?;
+            let poll_vote = account_info.ensure_account_is_poll_voter::<T>()?;
 
-        let new_yearly_rate =
-            target_rate_per_block.to_yearly_rate_representation(T::BlocksPerYear::get());
-        Self::deposit_event(RawEvent::PatronageRateDecreasedTo(
-            token_id,
-            new_yearly_rate,
-        ));
+            if let Some(poll) = Self::token_poll_by_token_id(token_id) {
+                if poll.poll_id == poll_vote.poll_id {
+                    ensure!(
+                        poll.timeline.is_ended(Self::current_block()),
+                        Error::<T>::TokenPollDidNotEnd,
+                    );
+                }
+            }
 
-        Ok(())
-    }
+            // == MUTATION SAFE ==
 
-    /// Allow creator to receive credit into his account
-    ///
-    /// Preconditions:
-    /// - token by `token_id` must exists
-    /// - `member_id` x `token_id` account must exist
-    /// - `token.revenue_split` has Inactive status
-    ///
-    /// Postconditions:
-    /// - outstanding patronage credit for `token_id` transferred to `member_id` account
-    /// - outstanding patronage credit subsequently set to 0
-    /// no-op if outstanding credit is zero
-    fn claim_patronage_credit(token_id: T::TokenId, member_id: T::MemberId) -> DispatchResult {
-        let token_info = Self::ensure_token_exists(token_id)?;
-        token_info.ensure_can_modify_supply::<T>()?;
+            AccountInfoByTokenAndMember::<T>::mutate(token_id, &member_id, |account_info| {
+                account_info.unlock_poll_tokens();
+            });
 
-        Self::ensure_account_data_exists(token_id, &member_id).map(|_| ())?;
+            Self::deposit_event(RawEvent::TokenPollTokensUnlocked(token_id, member_id, poll_vote.amount));
+            Ok(())
+        }
 
-        let now = Self::current_block();
-        let unclaimed_patronage = token_info.unclaimed_patronage_at_block(now);
+        /// Finalize the results of an ended token holder poll
+        ///
+        /// Preconditions
+        /// - `origin` must be signed
+        /// - `token` must exist for `token_id`
+        /// - token must have a poll which is not yet finalized
+        /// - poll voting period must be ended
+        ///
+        /// Postconditions
+        /// - `poll.finalized` set to true
+        /// - `poll.winning_alternative` set to the alternative with the strictly highest
+        ///   non-zero tally (None otherwise)
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (A)` where:
+        /// - `A` is the maximum number of poll alternatives
+        /// - DB:
+        ///   - `O(1)` - doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoToken::<T>::finalize_token_poll(T::MaxPollAlternatives::get())]
+        pub fn finalize_token_poll(origin, token_id: T::TokenId) -> DispatchResult {
+            ensure_signed(origin)?;
 
-        if unclaimed_patronage.is_zero() {
-            return Ok(());
-        }
+            Self::ensure_token_exists(token_id)?;
+            let poll = Self::ensure_token_poll_exists(token_id)?;
 
-        // == MUTATION SAFE ==
+            ensure!(!poll.finalized, Error::<T>::TokenPollAlreadyFinalized);
+            ensure!(
+                poll.timeline.is_ended(Self::current_block()),
+                Error::<T>::TokenPollDidNotEnd,
+            );
 
-        AccountInfoByTokenAndMember::<T>::mutate(token_id, &member_id, |account_info| {
-            account_info.increase_amount_by(unclaimed_patronage)
-        });
+            // == MUTATION SAFE ==
 
-        TokenInfoById::<T>::mutate(token_id, |token_info| {
-            token_info.increase_supply_by(unclaimed_patronage);
-            token_info.set_unclaimed_tally_patronage_at_block(<T as Config>::Balance::zero(), now);
-        });
+            let winning_alternative = poll.leading_alternative();
 
-        Self::deposit_event(RawEvent::PatronageCreditClaimed(
-            token_id,
-            unclaimed_patronage,
-            member_id,
-        ));
+            TokenPollByTokenId::<T>::mutate(token_id, |poll| {
+                if let Some(poll) = poll.as_mut() {
+                    poll.finalized = true;
+                    poll.winning_alternative = winning_alternative;
+                }
+            });
 
-        Ok(())
-    }
+            Self::deposit_event(RawEvent::TokenPollFinalized(
+                token_id,
+                poll.poll_id,
+                winning_alternative,
+            ));
 
-    /// Issue token with specified characteristics
-    ///
-    /// Preconditions:
-    /// - `symbol` specified in the parameters must NOT exists in `SymbolsUsed`
-    /// - `issuer_account` usable balance in JOYs >=
+            Ok(())
+        }
+
+        /// Claim tokens from an airdrop
+        ///
+        /// Preconditions:
+        /// - `amount` must be > 0
+        /// - `origin` signer must be a controller account of `member_id`
+        /// - token by `token_id` must exist and must not be paused
+        /// - airdrop by `airdrop_id` must exist for `token_id` and must not be expired
+        /// - account for `member_id` (if it exists) must not be frozen
+        /// - if token transfer policy is permissioned: account for `member_id` must exist
+        /// - `member_id` must not have claimed the airdrop yet
+        /// - airdrop must have at least `amount` tokens left
+        /// - `proof` must prove that `(member_id, amount, vesting_schedule)` is part of the
+        ///   airdrop commitment
+        /// - if account for `member_id` does not exist: sender must be able to cover the
+        ///   bloat bond
+        /// - if `vesting_schedule` is provided: account's vesting schedules limit must not be
+        ///   exceeded
+        ///
+        /// Postconditions:
+        /// - account for `member_id` created (if it didn't exist) and the bloat bond transferred
+        ///   from sender to the treasury account
+        /// - account's tokens amount increased by `amount` (subject to vesting in case
+        ///   `vesting_schedule` is provided)
+        /// - airdrop's amount left decreased by `amount`
+        /// - airdrop marked as claimed by `member_id`
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (H)` where:
+        /// - `H` is the length of `proof.0`
+        /// - DB:
+        ///   - `O(1)` - doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoToken::<T>::claim_airdrop(proof.0.len() as u32)]
+        pub fn claim_airdrop(
+            origin,
+            token_id: T::TokenId,
+            airdrop_id: AirdropId,
+            member_id: T::MemberId,
+            amount: TokenBalanceOf<T>,
+            vesting_schedule: Option<VestingScheduleParamsOf<T>>,
+            proof: MerkleProofOf<T>,
+        ) -> DispatchResult {
+            let sender = T::MemberOriginValidator::ensure_member_controller_account_origin(
+                origin,
+                member_id
+            )?;
+
+            ensure!(!amount.is_zero(), Error::<T>::AirdropClaimAmountIsZero);
+
+            let token_info = Self::ensure_token_exists(token_id)?;
+            token_info.ensure_not_paused::<T>()?;
+            let airdrop = Self::ensure_airdrop_exists(token_id, airdrop_id)?;
+
+            let current_block = Self::current_block();
+            airdrop.ensure_can_claim::<T>(current_block, amount)?;
+
+            ensure!(
+                !Self::airdrop_claimed(airdrop_id, member_id),
+                Error::<T>::AirdropAlreadyClaimed
+            );
+
+            proof.verify::<T, _>(
+                &(member_id, amount, vesting_schedule.clone()),
+                airdrop.commitment,
+            )?;
+
+            let vesting_source = VestingSource::Airdrop(airdrop_id);
+            let vesting_schedule = vesting_schedule.map(|vsp| {
+                VestingSchedule::from_params(current_block, amount, vsp)
+            });
+
+            let account_info =
+                if AccountInfoByTokenAndMember::<T>::contains_key(token_id, &member_id) {
+                    Some(Self::account_info_by_token_and_member(token_id, &member_id))
+                } else {
+                    None
+                };
+
+            if let Some(account_info) = account_info.as_ref() {
+                account_info.ensure_not_frozen::<T>()?;
+            }
+
+            // Claiming is subject to the same destination rules as transfers
+            // (in a permissioned token only the existing accounts can receive tokens)
+            Self::validate_destination(
+                member_id,
+                &account_info,
+                &token_info.transfer_policy,
+                false,
+            )?;
+
+            let vesting_cleanup_candidate =
+                match (account_info.as_ref(), vesting_schedule.as_ref()) {
+                    (Some(account_info), Some(_)) => account_info
+                        .ensure_can_add_or_update_vesting_schedule::<T>(
+                            current_block,
+                            vesting_source.clone(),
+                        )?,
+                    _ => None,
+                };
+
+            if account_info.is_none() {
+                // Ensure sender can cover the bloat bond
+                ensure!(
+                    has_sufficient_balance_for_fees::<T>(&sender, Self::bloat_bond()),
+                    Error::<T>::InsufficientJoyBalance
+                );
+            }
+
+            // == MUTATION SAFE ==
+
+            let relevant_since = token_info.balance_checkpoints_relevant_since(current_block);
+            if account_info.is_some() {
+                AccountInfoByTokenAndMember::<T>::try_mutate(token_id, &member_id, |account_data| {
+                    account_data.checkpoint_balance(
+                        account_data.amount,
+                        current_block,
+                        relevant_since,
+                    );
+                    if let Some(vs) = vesting_schedule {
+                        account_data.add_or_update_vesting_schedule::<T>(
+                            vesting_source,
+                            vs,
+                            vesting_cleanup_candidate,
+                        )
+                    } else {
+                        account_data.increase_amount_by(amount);
+                        Ok(())
+                    }
+                })?;
+            } else {
+                let repayable_bloat_bond = Self::pay_bloat_bond(&sender)?;
+                let mut account_data = if let Some(vs) = vesting_schedule {
+                    AccountDataOf::<T>::new_with_vesting_and_bond::<T>(
+                        vesting_source,
+                        vs,
+                        repayable_bloat_bond,
+                    )?
+                } else {
+                    AccountDataOf::<T>::new_with_amount_and_bond(amount, repayable_bloat_bond)
+                };
+                account_data.checkpoint_balance(Zero::zero(), current_block, relevant_since);
+                Self::do_insert_new_account_for_token(token_id, &member_id, account_data);
+            }
+
+            AirdropById::<T>::mutate(token_id, airdrop_id, |airdrop| {
+                if let Some(airdrop) = airdrop.as_mut() {
+                    airdrop.amount_left = airdrop.amount_left.saturating_sub(amount);
+                }
+            });
+
+            AirdropClaimed::<T>::insert(airdrop_id, member_id, true);
+
+            Self::deposit_event(RawEvent::AirdropClaimed(token_id, airdrop_id, member_id, amount));
+
+            Ok(())
+        }
+
+        /// Allow `spender` to transfer up to `amount` tokens from `member_id` account.
+        /// Approving an `amount` of 0 revokes the existing allowance.
+        ///
+        /// Preconditions:
+        /// - `origin` signer must be a controller account of `member_id`
+        /// - token by `token_id` must exist
+        /// - account for `member_id` must exist
+        /// - `expiry` (if provided) must be > current block
+        /// - if `spender` has no allowance yet: account's number of allowances must be
+        ///   < `MaxAllowancesPerAccount`
+        ///
+        /// Postconditions:
+        /// - allowance of `spender` set to `amount` with `expiry` (or removed if `amount` is 0)
+        /// - account's number of allowances updated accordingly
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///   - `O(1)` - doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoToken::<T>::approve()]
+        pub fn approve(
+            origin,
+            token_id: T::TokenId,
+            member_id: T::MemberId,
+            spender: AllowanceSpenderOf<T>,
+            amount: TokenBalanceOf<T>,
+            expiry: Option<T::BlockNumber>,
+        ) -> DispatchResult {
+            T::MemberOriginValidator::ensure_member_controller_account_origin(
+                origin,
+                member_id
+            )?;
+
+            Self::do_approve(token_id, member_id, spender, AllowanceOf::<T> { amount, expiry })
+        }
+
+        /// Transfer tokens from `src_member_id` account to the various `outputs` beneficiaries
+        /// using an allowance granted by `src_member_id`.
+        /// If `spender_member_id` is provided the allowance of that member is used,
+        /// otherwise the allowance of the origin signer account is used.
+        ///
+        /// Preconditions:
+        /// - if `spender_member_id` is provided: `origin` signer must be its controller account
+        /// - allowance of the spender must exist for `token_id` x `src_member_id`,
+        ///   must not be expired and must be >= total amount of `outputs`
+        /// - same preconditions as `transfer` with `src_member_id` as the source account
+        ///   (the origin signer covers the bloat bonds of the new destination accounts)
+        ///
+        /// Postconditions:
+        /// - same postconditions as `transfer`
+        /// - spender's allowance decreased by the total amount of `outputs`
+        ///   (and removed in case it reaches 0)
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (T + M)` where:
+        /// - `T` is the length of `outputs`
+        /// - `M` is the size of `metadata` in kilobytes
+        /// - DB:
+        ///   - `O(T)` - from the the generated weights
+        /// # </weight>
+        #[weight = WeightInfoToken::<T>::transfer_from(outputs.0.len() as u32, to_kb(metadata.len() as u32))]
+        pub fn transfer_from(
+            origin,
+            spender_member_id: Option<T::MemberId>,
+            token_id: T::TokenId,
+            src_member_id: T::MemberId,
+            outputs: TransfersOf<T>,
+            metadata: Vec<u8>
+        ) -> DispatchResult {
+            let (sender, spender) = if let Some(spender_member_id) = spender_member_id {
+                let sender = T::MemberOriginValidator::ensure_member_controller_account_origin(
+                    origin,
+                    spender_member_id
+                )?;
+                (sender, AllowanceSpender::Member(spender_member_id))
+            } else {
+                let sender = ensure_signed(origin)?;
+                (sender.clone(), AllowanceSpender::Account(sender))
+            };
+
+            Self::do_transfer_from(spender, &sender, token_id, src_member_id, outputs, metadata)
+        }
+
+        /// Create an offer to swap `amount` of tokens for the `wanted` asset.
+        /// The offered tokens are held in escrow until the offer is filled or cancelled.
+        ///
+        /// Preconditions:
+        /// - `origin` signer must be a controller account of `member_id`
+        /// - `amount` and the `wanted` amount must be > 0
+        /// - token by `token_id` must exist and must not be paused
+        /// - if `wanted` is a token: it must exist and must be different from `token_id`
+        /// - account for `member_id` must exist and must not be frozen
+        /// - account's transferrable balance must be >= `amount`
+        ///
+        /// Postconditions:
+        /// - account's tokens amount decreased by `amount`
+        /// - swap offer with `amount_left = amount` and `wanted_left = wanted` added to storage
+        /// - `NextSwapOfferId` increased by 1
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///   - `O(1)` - doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoToken::<T>::create_swap_offer()]
+        pub fn create_swap_offer(
+            origin,
+            token_id: T::TokenId,
+            member_id: T::MemberId,
+            amount: TokenBalanceOf<T>,
+            wanted: SwapAssetOf<T>,
+        ) -> DispatchResult {
+            T::MemberOriginValidator::ensure_member_controller_account_origin(
+                origin,
+                member_id
+            )?;
+
+            ensure!(!amount.is_zero(), Error::<T>::SwapOfferAmountIsZero);
+            ensure!(!wanted.is_zero(), Error::<T>::SwapOfferWantedAmountIsZero);
+
+            let token_info = Self::ensure_token_exists(token_id)?;
+            token_info.ensure_not_paused::<T>()?;
+
+            if let SwapAsset::Token(wanted_token_id, _) = wanted {
+                ensure!(
+                    wanted_token_id != token_id,
+                    Error::<T>::SwapOfferWantedTokenIsOfferedToken
+                );
+                Self::ensure_token_exists(wanted_token_id)?;
+            }
+
+            let account_info = Self::ensure_account_data_exists(token_id, &member_id)?;
+            account_info.ensure_not_frozen::<T>()?;
+            account_info.ensure_can_transfer::<T>(Self::current_block(), amount)?;
+
+            // == MUTATION SAFE ==
+
+            let offer_id = Self::next_swap_offer_id();
+
+            AccountInfoByTokenAndMember::<T>::mutate(token_id, &member_id, |account_data| {
+                account_data.checkpoint_balance(
+                    account_data.amount,
+                    Self::current_block(),
+                    token_info.balance_checkpoints_relevant_since(Self::current_block())
+                );
+                account_data.decrease_amount_by(amount);
+            });
+
+            SwapOfferById::<T>::insert(
+                token_id,
+                offer_id,
+                SwapOfferOf::<T> {
+                    maker: member_id,
+                    amount_left: amount,
+                    wanted_left: wanted,
+                },
+            );
+
+            NextSwapOfferId::put(offer_id.saturating_add(1));
+
+            Self::deposit_event(
+                RawEvent::SwapOfferCreated(token_id, offer_id, member_id, amount, wanted)
+            );
+
+            Ok(())
+        }
+
+        /// Buy `amount` of the tokens offered in a swap offer, paying the maker
+        /// a proportional amount of the requested asset (rounded up in favor of the maker).
+        /// If the payment covers all of the asset still requested, all of the tokens left
+        /// in the offer are bought and the offer is closed.
+        ///
+        /// Preconditions:
+        /// - `origin` signer must be a controller account of `member_id`
+        /// - `amount` must be > 0
+        /// - token by `token_id` must exist and must not be paused
+        /// - swap offer by `offer_id` must exist for `token_id`
+        /// - `member_id` must not be the offer's maker
+        /// - maker's account (if it exists) must not be frozen
+        /// - offer's `amount_left` must be >= `amount`
+        /// - if `token_id` is permissioned: account for `member_id` must exist
+        /// - if the payment is in tokens: same preconditions as `transfer` of the payment
+        ///   from `member_id` to the maker (including transfer policy and vesting constraints)
+        /// - if the payment is in JOY: `origin` signer usable JOY balance must cover it
+        /// - `origin` signer must cover the bloat bonds of the accounts to be created
+        ///
+        /// Postconditions:
+        /// - payment transferred from `member_id` (or `origin` signer in case of JOY)
+        ///   to the maker (account is created if it doesn't exist)
+        /// - account for `member_id` created (if it didn't exist) and its tokens amount
+        ///   increased by the amount bought
+        /// - offer's amounts decreased accordingly (offer is removed when nothing is left)
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///   - `O(1)` - doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoToken::<T>::fill_swap_offer()]
+        pub fn fill_swap_offer(
+            origin,
+            token_id: T::TokenId,
+            offer_id: SwapOfferId,
+            member_id: T::MemberId,
+            amount: TokenBalanceOf<T>,
+        ) -> DispatchResult {
+            let sender = T::MemberOriginValidator::ensure_member_controller_account_origin(
+                origin,
+                member_id
+            )?;
+
+            ensure!(!amount.is_zero(), Error::<T>::SwapOfferFillAmountIsZero);
+
+            let token_info = Self::ensure_token_exists(token_id)?;
+            token_info.ensure_not_paused::<T>()?;
+
+            let mut offer = Self::ensure_swap_offer_exists(token_id, offer_id)?;
+            ensure!(offer.maker != member_id, Error::<T>::SwapOfferFilledByMaker);
+
+            if let Ok(maker_account_info) = Self::ensure_account_data_exists(token_id, &offer.maker) {
+                maker_account_info.ensure_not_frozen::<T>()?;
+            }
+
+            let (amount, payment) = offer.ensure_can_fill::<T>(amount)?;
+
+            // Validate the taker as destination of the offered tokens
+            let taker_account_info = Self::ensure_account_data_exists(token_id, &member_id).ok();
+            let validated_taker = Self::validate_destination(
+                member_id,
+                &taker_account_info,
+                &token_info.transfer_policy,
+                false
+            )?;
+            let taker_bloat_bond = match validated_taker {
+                Validated::Existing(_) => JoyBalanceOf::<T>::zero(),
+                Validated::NonExisting(_) => Self::bloat_bond(),
+            };
+
+            // Validate the payment to the maker
+            let (validated_token_payment, joy_payment) = match payment {
+                SwapAsset::Token(wanted_token_id, wanted_amount) => {
+                    let transfers: TransfersOf<T> = Transfers::<_, _>(
+                        [(offer.maker, Payment { amount: wanted_amount })].iter().cloned().collect()
+                    );
+                    let validated_transfers = Self::ensure_can_transfer(
+                        wanted_token_id,
+                        &sender,
+                        &member_id,
+                        transfers.into(),
+                        false
+                    )?;
+                    ensure!(
+                        has_sufficient_balance_for_fees::<T>(
+                            &sender,
+                            Self::compute_bloat_bond(&validated_transfers).saturating_add(taker_bloat_bond)
+                        ),
+                        Error::<T>::InsufficientJoyBalance
+                    );
+                    (Some((wanted_token_id, validated_transfers)), None)
+                }
+                SwapAsset::Joy(joy_amount) => {
+                    let maker_account = T::MembershipInfoProvider::controller_account_id(offer.maker)?;
+                    Self::ensure_can_transfer_joy(&sender, joy_amount.saturating_add(taker_bloat_bond))?;
+                    (None, Some((maker_account, joy_amount)))
+                }
+            };
+
+            // == MUTATION SAFE ==
+
+            if let Some((wanted_token_id, validated_transfers)) = validated_token_payment.as_ref() {
+                Self::do_transfer(*wanted_token_id, &sender, &member_id, validated_transfers)?;
+            }
+
+            if let Some((maker_account, joy_amount)) = joy_payment.as_ref() {
+                Self::transfer_joy(&sender, maker_account, *joy_amount)?;
+            }
+
+            let now = Self::current_block();
+            let relevant_since = token_info.balance_checkpoints_relevant_since(now);
+            if taker_account_info.is_some() {
+                AccountInfoByTokenAndMember::<T>::mutate(token_id, &member_id, |account_data| {
+                    account_data.checkpoint_balance(account_data.amount, now, relevant_since);
+                    account_data.increase_amount_by(amount);
+                });
+            } else {
+                let repayable_bloat_bond = Self::pay_bloat_bond(&sender)?;
+                let mut account_data =
+                    AccountDataOf::<T>::new_with_amount_and_bond(amount, repayable_bloat_bond);
+                account_data.checkpoint_balance(Zero::zero(), now, relevant_since);
+                Self::do_insert_new_account_for_token(token_id, &member_id, account_data);
+            }
+
+            offer.process_fill(amount, &payment);
+            if offer.amount_left.is_zero() {
+                SwapOfferById::<T>::remove(token_id, offer_id);
+            } else {
+                SwapOfferById::<T>::insert(token_id, offer_id, offer);
+            }
+
+            Self::deposit_event(
+                RawEvent::SwapOfferFilled(token_id, offer_id, member_id, amount, payment)
+            );
+
+            Ok(())
+        }
+
+        /// Cancel a swap offer and refund the tokens left in escrow to the maker
+        ///
+        /// Preconditions:
+        /// - token by `token_id` must exist
+        /// - swap offer by `offer_id` must exist for `token_id`
+        /// - `origin` signer must be a controller account of the offer's maker
+        /// - if maker's account does not exist: sender must be able to cover the bloat bond
+        ///
+        /// Postconditions:
+        /// - maker's account tokens amount increased by `offer.amount_left`
+        ///   (account is re-created in case it was removed in the meantime and the bloat bond
+        ///   transferred from sender to the treasury account)
+        /// - swap offer removed from storage
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///   - `O(1)` - doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoToken::<T>::cancel_swap_offer()]
+        pub fn cancel_swap_offer(
+            origin,
+            token_id: T::TokenId,
+            offer_id: SwapOfferId,
+        ) -> DispatchResult {
+            let token_info = Self::ensure_token_exists(token_id)?;
+            let offer = Self::ensure_swap_offer_exists(token_id, offer_id)?;
+
+            let sender = T::MemberOriginValidator::ensure_member_controller_account_origin(
+                origin,
+                offer.maker
+            )?;
+
+            let maker_account_exists =
+                AccountInfoByTokenAndMember::<T>::contains_key(token_id, &offer.maker);
+            if !maker_account_exists {
+                // Ensure sender can cover the bloat bond of the re-created account
+                ensure!(
+                    has_sufficient_balance_for_fees::<T>(&sender, Self::bloat_bond()),
+                    Error::<T>::InsufficientJoyBalance
+                );
+            }
+
+            // == MUTATION SAFE ==
+
+            let now = Self::current_block();
+            let relevant_since = token_info.balance_checkpoints_relevant_since(now);
+            if maker_account_exists {
+                AccountInfoByTokenAndMember::<T>::mutate(token_id, &offer.maker, |account_data| {
+                    account_data.checkpoint_balance(account_data.amount, now, relevant_since);
+                    account_data.increase_amount_by(offer.amount_left);
+                });
+            } else {
+                let repayable_bloat_bond = Self::pay_bloat_bond(&sender)?;
+                let mut account_data = AccountDataOf::<T>::new_with_amount_and_bond(
+                    offer.amount_left,
+                    repayable_bloat_bond,
+                );
+                account_data.checkpoint_balance(Zero::zero(), now, relevant_since);
+                Self::do_insert_new_account_for_token(token_id, &offer.maker, account_data);
+            }
+
+            SwapOfferById::<T>::remove(token_id, offer_id);
+
+            Self::deposit_event(
+                RawEvent::SwapOfferCancelled(token_id, offer_id, offer.amount_left)
+            );
+
+            Ok(())
+        }
+
+        /// Buy back the tokens offered for JOY in swap offers of `token_id` using the budget
+        /// of the token's buyback program released so far, and burn them.
+        /// Offers priced at or below the program's `max_unit_price` are filled cheapest first,
+        /// the last one partially if the budget does not cover it. Can be triggered by anyone.
+        ///
+        /// Preconditions:
+        /// - `origin` must be signed
+        /// - token by `token_id` must exist and must not be paused
+        /// - token must not have an active revenue split
+        /// - buyback program must exist for `token_id` and must be ongoing
+        /// - number of swap offers of `token_id` must be <= `swap_offers_number`
+        /// - budget released so far and not spent yet (the budget is released linearly over
+        ///   the program's duration) must buy at least one token offered for JOY at or below
+        ///   `program.max_unit_price` by a maker whose account is not frozen
+        ///
+        /// Postconditions:
+        /// - for each offer filled (in order of increasing unit price):
+        ///   - payment transferred from `treasury_account` to the maker's controller account
+        ///   - offer's amounts decreased accordingly (offer is removed when nothing is left)
+        ///   - `program.spent` increased by the payment
+        /// - token supply decreased by the tokens bought (patronage accrued so far is tallied)
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (N)`
+        /// - DB:
+        ///   - `O(N)` - where N is `swap_offers_number`
+        /// # </weight>
+        #[weight = WeightInfoToken::<T>::execute_buyback(*swap_offers_number)]
+        pub fn execute_buyback(
+            origin,
+            token_id: T::TokenId,
+            swap_offers_number: u32,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let token_info = Self::ensure_token_exists(token_id)?;
+            token_info.ensure_not_paused::<T>()?;
+            token_info.ensure_can_modify_supply::<T>()?;
+
+            let now = Self::current_block();
+            let mut program = Self::ensure_buyback_program_exists(token_id)?;
+            program.ensure_ongoing::<T>(now)?;
+
+            let fills = Self::buyback_fills(token_id, &program, now, swap_offers_number)?;
+            ensure!(!fills.is_empty(), Error::<T>::NoSwapOffersToBuyBack);
+
+            // == MUTATION SAFE ==
+
+            let treasury_account = Self::module_treasury_account();
+            let mut amount_burned = TokenBalanceOf::<T>::zero();
+            for (offer_id, offer, maker_account, amount, payment) in fills {
+                Self::transfer_joy(&treasury_account, &maker_account, payment)?;
+
+                let maker = offer.maker;
+                if offer.amount_left.is_zero() {
+                    SwapOfferById::<T>::remove(token_id, offer_id);
+                } else {
+                    SwapOfferById::<T>::insert(token_id, offer_id, offer);
+                }
+
+                program.account_for_buyback(payment);
+                amount_burned = amount_burned.saturating_add(amount);
+
+                Self::deposit_event(
+                    RawEvent::TokensBoughtBackAndBurned(
+                        token_id,
+                        offer_id,
+                        maker,
+                        amount,
+                        payment
+                    )
+                );
+            }
+
+            TokenInfoById::<T>::mutate(token_id, |token| {
+                token.burn_supply_at_block(amount_burned, now);
+            });
+
+            BuybackProgramByTokenId::<T>::insert(token_id, program);
+
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config>
+    PalletToken<
+        T::TokenId,
+        T::MemberId,
+        <T as frame_system::Config>::AccountId,
+        JoyBalanceOf<T>,
+        TokenIssuanceParametersOf<T>,
+        T::BlockNumber,
+        TokenSaleParamsOf<T>,
+        UploadContextOf<T>,
+        TransfersWithVestingOf<T>,
+        AirdropParamsOf<T>,
+        AllowanceOf<T>,
+        TransfersOf<T>,
+    > for Module<T>
+{
+    /// Establish whether there's an unfinalized revenue split
+    /// Postconditions: true if token @ token_id has an unfinalized revenue split, false otherwise
+    fn is_revenue_split_inactive(token_id: T::TokenId) -> bool {
+        if let Ok(token_info) = Self::ensure_token_exists(token_id) {
+            return token_info.revenue_split.ensure_inactive::<T>().is_ok();
+        }
+        true
+    }
+
+    /// Establish whether there is an unfinalized token sale
+    /// Postconditions: true if token @ token_id has an unfinalized sale, false otherwise
+    fn is_sale_unscheduled(token_id: T::TokenId) -> bool {
+        if let Ok(token_info) = Self::ensure_token_exists(token_id) {
+            return token_info.sale.is_none();
+        }
+        true
+    }
+
+    /// Change to permissionless
+    /// Preconditions:
+    /// - token by `token_id` must exist
+    /// Postconditions
+    /// - transfer policy of `token_id` changed to permissionless
+    fn change_to_permissionless(token_id: T::TokenId) -> DispatchResult {
+        Self::ensure_token_exists(token_id).map(|_| ())?;
+
+        // == MUTATION SAFE ==
+
+        TokenInfoById::<T>::mutate(token_id, |token_info| {
+            token_info.transfer_policy = TransferPolicyOf::<T>::Permissionless;
+        });
+
+        Self::deposit_event(RawEvent::TransferPolicyChangedToPermissionless(token_id));
+        Ok(())
+    }
+
+    /// Reduce patronage rate by amount
+    /// Preconditions:
+    /// - token by `token_id` must exists
+    /// - `decrement` must be less or equal than current patronage rate for `token_id`
+    ///
+    /// Postconditions:
+    /// - patronage rate for `token_id` reduced by `decrement`
+    /// - no-op if `target_rate` is equal to the current patronage rate
+    fn reduce_patronage_rate_to(token_id: T::TokenId, target_rate: YearlyRate) -> DispatchResult {
+        let token_info = Self::ensure_token_exists(token_id)?;
+        let target_rate_per_block =
+            BlockRate::from_yearly_rate(target_rate, T::BlocksPerYear::get());
+
+        if token_info.patronage_info.rate == target_rate_per_block {
+            return Ok(());
+        }
+
+        ensure!(
+            token_info.patronage_info.rate > target_rate_per_block,
+            Error::<T>::TargetPatronageRateIsHigherThanCurrentRate,
+        );
+
+        // == MUTATION SAFE ==
+
+        let now = Self::current_block();
+        TokenInfoById::<T>::mutate(token_id, |token_info| {
+            token_info.set_new_patronage_rate_at_block(target_rate_per_block, now);
+        });
+
+        let new_yearly_rate =
+            target_rate_per_block.to_yearly_rate_representation(T::BlocksPerYear::get());
+        Self::deposit_event(RawEvent::PatronageRateDecreasedTo(
+            token_id,
+            new_yearly_rate,
+        ));
+
+        Ok(())
+    }
+
+    /// Allow creator to receive credit into his account
+    ///
+    /// Preconditions:
+    /// - token by `token_id` must exists
+    /// - `member_id` x `token_id` account must exist
+    /// - `token.revenue_split` has Inactive status
+    ///
+    /// Postconditions:
+    /// - outstanding patronage credit for `token_id` transferred to `member_id` account
+    /// - outstanding patronage credit subsequently set to 0
+    /// no-op if outstanding credit is zero
+    fn claim_patronage_credit(token_id: T::TokenId, member_id: T::MemberId) -> DispatchResult {
+        let token_info = Self::ensure_token_exists(token_id)?;
+        token_info.ensure_can_modify_supply::<T>()?;
+
+        Self::ensure_account_data_exists(token_id, &member_id).map(|_| ())?;
+
+        let now = Self::current_block();
+        let unclaimed_patronage = token_info.unclaimed_patronage_at_block(now);
+
+        if unclaimed_patronage.is_zero() {
+            return Ok(());
+        }
+
+        // == MUTATION SAFE ==
+
+        AccountInfoByTokenAndMember::<T>::mutate(token_id, &member_id, |account_info| {
+            account_info.checkpoint_balance(
+                account_info.amount,
+                now,
+                token_info.balance_checkpoints_relevant_since(now),
+            );
+            account_info.increase_amount_by(unclaimed_patronage)
+        });
+
+        TokenInfoById::<T>::mutate(token_id, |token_info| {
+            token_info.increase_supply_by(unclaimed_patronage);
+            token_info.set_unclaimed_tally_patronage_at_block(<T as Config>::Balance::zero(), now);
+        });
+
+        Self::deposit_event(RawEvent::PatronageCreditClaimed(
+            token_id,
+            unclaimed_patronage,
+            member_id,
+        ));
+
+        Ok(())
+    }
+
+    /// Issue token with specified characteristics
+    ///
+    /// Preconditions:
+    /// - `symbol` specified in the parameters must NOT exists in `SymbolsUsed`
+    /// - `issuer_account` usable balance in JOYs >=
     ///   `initial_allocation.len() * bloat_bond + JoyExistentialDeposit`
     ///
     /// Postconditions:
@@ -1059,11 +1963,11 @@ impl<T: Config>
     /// - previous sale has been finalized (token_data.sale.is_none())
     /// - `token_id` x `member_id` account exists
     /// - `token_id` x `member_id` account has transferrable CRT balance
-    ///   >= `sale_params.upper_bound_quantity`
+    ///   >= total quantity of tokens on sale (across all tiers)
     ///
     /// Postconditions:
     /// - `token_id` x `member_id` account balance is decreased by
-    ///   `sale_params.upper_bound_quantity`
+    ///   total quantity of tokens on sale (across all tiers)
     /// - token's `sale` is set
     /// - token's `next_sale_id` is incremented
     fn init_token_sale(
@@ -1093,10 +1997,15 @@ impl<T: Config>
 
         // == MUTATION SAFE ==
 
-        // Decrease source account's tokens number by sale_params.upper_bound_quantity
+        // Decrease source account's tokens number by total quantity of tokens on sale
         // (unsold tokens can be later recovered with `finalize_token_sale`)
         AccountInfoByTokenAndMember::<T>::mutate(token_id, &member_id, |ad| {
-            ad.decrease_amount_by(sale_params.upper_bound_quantity);
+            ad.checkpoint_balance(
+                ad.amount,
+                current_block,
+                token_data.balance_checkpoints_relevant_since(current_block),
+            );
+            ad.decrease_amount_by(sale.quantity_left);
         });
 
         TokenInfoById::<T>::mutate(token_id, |t| {
@@ -1209,6 +2118,9 @@ impl<T: Config>
     ///    - `current_block + MinRevenuSplitTimeToStart`
     ///    - specfied `Some(start)``
     /// - `token.revenue_split.allocation = allocation`
+    /// - if time-weighted revenue splits are enabled for the token:
+    ///   `token.revenue_split.eligibility_window` covers the `lookback` blocks preceding
+    ///   `current_block` (limited to the period during which balance checkpoints were tracked)
     /// - `token.latest_split` incremented by 1
     fn issue_revenue_split(
         token_id: T::TokenId,
@@ -1218,99 +2130,490 @@ impl<T: Config>
         revenue_amount: JoyBalanceOf<T>,
     ) -> Result<JoyBalanceOf<T>, DispatchError> {
         let token_info = Self::ensure_token_exists(token_id)?;
-        token_info.revenue_split.ensure_inactive::<T>()?;
+        let split_rate = token_info.revenue_split_rate;
+        Self::do_issue_revenue_split(
+            token_id,
+            token_info,
+            start,
+            duration,
+            revenue_source_account,
+            revenue_amount,
+            split_rate,
+        )
+    }
+
+    /// Issue a revenue split for the token allocating `split_rate` of `revenue_amount`
+    /// (instead of the token's `revenue_split_rate`).
+    /// Same preconditions and postconditions as `issue_revenue_split`
+    fn issue_revenue_split_with_rate(
+        token_id: T::TokenId,
+        start: Option<T::BlockNumber>,
+        duration: T::BlockNumber,
+        revenue_source_account: T::AccountId,
+        revenue_amount: JoyBalanceOf<T>,
+        split_rate: Permill,
+    ) -> Result<JoyBalanceOf<T>, DispatchError> {
+        let token_info = Self::ensure_token_exists(token_id)?;
+        Self::do_issue_revenue_split(
+            token_id,
+            token_info,
+            start,
+            duration,
+            revenue_source_account,
+            revenue_amount,
+            split_rate,
+        )
+    }
+
+    /// Finalize revenue split once it is ended
+    /// Preconditions
+    /// - `token` at `token_id`
+    /// - `token.revenue_split` is active
+    /// - `token.revenue_split` has ended
+    ///
+    /// Postconditions
+    /// - `token.revenue_split.leftovers()` of JOYs transferred to `account_id`
+    /// - `token.revenue_split` status set to Inactive
+    fn finalize_revenue_split(token_id: T::TokenId, account_id: T::AccountId) -> DispatchResult {
+        let token_info = Self::ensure_token_exists(token_id)?;
+
+        let split_info = token_info.revenue_split.ensure_active::<T>()?;
+
+        let current_block = Self::current_block();
+        ensure!(
+            split_info.timeline.is_ended(current_block),
+            Error::<T>::RevenueSplitDidNotEnd
+        );
+
+        // = MUTATION SAFE =
+
+        let treasury_account = Self::module_treasury_account();
+        let amount_to_withdraw = split_info.leftovers();
+
+        Self::transfer_joy(&treasury_account, &account_id, amount_to_withdraw)?;
+
+        TokenInfoById::<T>::mutate(token_id, |token_info| token_info.deactivate_revenue_split());
+
+        Self::deposit_event(RawEvent::RevenueSplitFinalized(
+            token_id,
+            account_id,
+            amount_to_withdraw,
+        ));
+
+        Ok(())
+    }
+
+    /// Issue a token holder poll
+    /// Preconditions:
+    /// - `token` must exist for `token_id`
+    /// - previous token poll (if any) must be finalized
+    /// - `params.alternatives` must be in [2, MaxPollAlternatives]
+    /// - `params.duration` must be > 0
+    /// - if Some(start) specified: `start >= System::block_number()`
+    ///
+    /// Postconditions:
+    /// - new poll with zero tallies stored as the token's poll, replacing the previous one
+    /// - poll id is the previous poll id incremented by 1 (0 for the first poll)
+    /// - poll timeline is [start, start + duration), with `start` one of:
+    ///    - `current_block`
+    ///    - specified `Some(start)`
+    fn issue_poll(
+        token_id: T::TokenId,
+        params: TokenPollParamsOf<T>,
+    ) -> Result<TokenPollId, DispatchError> {
+        Self::ensure_token_exists(token_id)?;
+
+        let previous_poll = Self::token_poll_by_token_id(token_id);
+        if let Some(poll) = previous_poll.as_ref() {
+            ensure!(poll.finalized, Error::<T>::PreviousTokenPollNotFinalized);
+        }
 
-        let allocation_amount = token_info.revenue_split_rate.mul_floor(revenue_amount);
+        ensure!(
+            params.alternatives >= 2 && params.alternatives <= T::MaxPollAlternatives::get(),
+            Error::<T>::InvalidNumberOfTokenPollAlternatives
+        );
+
+        ensure!(
+            !params.duration.is_zero(),
+            Error::<T>::TokenPollDurationIsZero
+        );
+
+        let current_block = Self::current_block();
+        let start = params.start.unwrap_or(current_block);
+        ensure!(start >= current_block, Error::<T>::TokenPollStartInThePast);
+
+        let tallies = vec![TokenBalanceOf::<T>::zero(); params.alternatives as usize]
+            .try_into()
+            .map_err(|_| Error::<T>::InvalidNumberOfTokenPollAlternatives)?;
+
+        let poll_id = previous_poll.map_or(0, |poll| poll.poll_id.saturating_add(1));
+
+        // == MUTATION SAFE ==
+
+        TokenPollByTokenId::<T>::insert(
+            token_id,
+            TokenPollOf::<T> {
+                poll_id,
+                timeline: TimelineOf::<T>::from_params(start, params.duration),
+                tallies,
+                finalized: false,
+                winning_alternative: None,
+            },
+        );
+
+        Self::deposit_event(RawEvent::TokenPollIssued(token_id, poll_id, params));
+
+        Ok(poll_id)
+    }
+
+    /// Issue an airdrop funded from the account of `member_id`
+    /// Preconditions:
+    /// - token by `token_id` must exist and must not be paused
+    /// - `params.total_amount` must be > 0
+    /// - `params.expiry` must be > current block
+    /// - account for `member_id` must exist and must not be frozen
+    /// - `member_id` account transferrable balance must be >= `params.total_amount`
+    ///
+    /// Postconditions:
+    /// - `member_id` account tokens amount decreased by `params.total_amount`
+    /// - airdrop with `amount_left = params.total_amount` added to storage
+    /// - `NextAirdropId` increased by 1
+    fn issue_airdrop(
+        token_id: T::TokenId,
+        member_id: T::MemberId,
+        params: AirdropParamsOf<T>,
+    ) -> Result<AirdropId, DispatchError> {
+        let token_info = Self::ensure_token_exists(token_id)?;
+        token_info.ensure_not_paused::<T>()?;
+
+        ensure!(
+            !params.total_amount.is_zero(),
+            Error::<T>::AirdropAmountIsZero
+        );
+
+        let current_block = Self::current_block();
+        ensure!(
+            params.expiry > current_block,
+            Error::<T>::AirdropExpiryInThePast
+        );
+
+        let account_info = Self::ensure_account_data_exists(token_id, &member_id)?;
+        account_info.ensure_not_frozen::<T>()?;
+        account_info.ensure_can_transfer::<T>(current_block, params.total_amount)?;
+
+        // == MUTATION SAFE ==
+
+        let airdrop_id = Self::next_airdrop_id();
+
+        AccountInfoByTokenAndMember::<T>::mutate(token_id, &member_id, |account_data| {
+            account_data.checkpoint_balance(
+                account_data.amount,
+                current_block,
+                token_info.balance_checkpoints_relevant_since(current_block),
+            );
+            account_data.decrease_amount_by(params.total_amount);
+        });
+
+        AirdropById::<T>::insert(
+            token_id,
+            airdrop_id,
+            AirdropOf::<T> {
+                commitment: params.commitment,
+                tokens_source: member_id,
+                amount_left: params.total_amount,
+                expiry: params.expiry,
+            },
+        );
+
+        NextAirdropId::put(airdrop_id.saturating_add(1));
+
+        Self::deposit_event(RawEvent::AirdropIssued(
+            token_id, airdrop_id, member_id, params,
+        ));
+
+        Ok(airdrop_id)
+    }
+
+    fn approve(
+        token_id: T::TokenId,
+        member_id: T::MemberId,
+        spender: AllowanceSpenderOf<T>,
+        allowance: AllowanceOf<T>,
+    ) -> DispatchResult {
+        Self::do_approve(token_id, member_id, spender, allowance)
+    }
+
+    fn transfer_from(
+        spender: AllowanceSpenderOf<T>,
+        bloat_bond_payer: T::AccountId,
+        token_id: T::TokenId,
+        src_member_id: T::MemberId,
+        outputs: TransfersOf<T>,
+        metadata: Vec<u8>,
+    ) -> DispatchResult {
+        Self::do_transfer_from(
+            spender,
+            &bloat_bond_payer,
+            token_id,
+            src_member_id,
+            outputs,
+            metadata,
+        )
+    }
+
+    /// Recover the unclaimed tokens of an expired airdrop
+    /// Preconditions:
+    /// - token by `token_id` must exist
+    /// - airdrop by `airdrop_id` must exist for `token_id`
+    /// - airdrop must be expired
+    /// - if `airdrop.tokens_source` account does not exist: `bloat_bond_payer` must be able
+    ///   to cover the bloat bond
+    ///
+    /// Postconditions:
+    /// - `airdrop.tokens_source` account tokens amount increased by `airdrop.amount_left`
+    ///   (account is re-created in case it was removed in the meantime and the bloat bond
+    ///   transferred from `bloat_bond_payer` to the treasury account)
+    /// - airdrop and its claims removed from storage
+    fn recover_unclaimed_airdrop(
+        token_id: T::TokenId,
+        airdrop_id: AirdropId,
+        bloat_bond_payer: T::AccountId,
+    ) -> DispatchResult {
+        let token_info = Self::ensure_token_exists(token_id)?;
+        let airdrop = Self::ensure_airdrop_exists(token_id, airdrop_id)?;
+
+        let now = Self::current_block();
+        ensure!(airdrop.is_expired(now), Error::<T>::AirdropNotExpired);
+
+        let source_account_exists =
+            AccountInfoByTokenAndMember::<T>::contains_key(token_id, &airdrop.tokens_source);
+        if !source_account_exists {
+            ensure!(
+                has_sufficient_balance_for_fees::<T>(&bloat_bond_payer, Self::bloat_bond()),
+                Error::<T>::InsufficientJoyBalance
+            );
+        }
+
+        // == MUTATION SAFE ==
+
+        let relevant_since = token_info.balance_checkpoints_relevant_since(now);
+        if source_account_exists {
+            AccountInfoByTokenAndMember::<T>::mutate(token_id, &airdrop.tokens_source, |ad| {
+                ad.checkpoint_balance(ad.amount, now, relevant_since);
+                ad.increase_amount_by(airdrop.amount_left);
+            });
+        } else {
+            let repayable_bloat_bond = Self::pay_bloat_bond(&bloat_bond_payer)?;
+            let mut account_data = AccountDataOf::<T>::new_with_amount_and_bond(
+                airdrop.amount_left,
+                repayable_bloat_bond,
+            );
+            account_data.checkpoint_balance(Zero::zero(), now, relevant_since);
+            Self::do_insert_new_account_for_token(token_id, &airdrop.tokens_source, account_data);
+        }
+
+        AirdropById::<T>::remove(token_id, airdrop_id);
+        AirdropClaimed::<T>::remove_prefix(airdrop_id, None);
+
+        Self::deposit_event(RawEvent::UnclaimedAirdropRecovered(
+            token_id,
+            airdrop_id,
+            airdrop.amount_left,
+        ));
+
+        Ok(())
+    }
+
+    /// Freeze (or unfreeze) an account
+    /// Preconditions:
+    /// - token by `token_id` must exist
+    /// - account must exist for `token_id` x `member_id`
+    ///
+    /// Postconditions:
+    /// - `token_id` x `member_id` account frozen status set to `frozen`
+    ///   (frozen account can't transfer, burn, participate in revenue splits
+    ///   or purchase tokens on sale)
+    fn set_account_frozen_status(
+        token_id: T::TokenId,
+        member_id: T::MemberId,
+        frozen: bool,
+    ) -> DispatchResult {
+        Self::ensure_token_exists(token_id)?;
+        Self::ensure_account_data_exists(token_id, &member_id)?;
+
+        // == MUTATION SAFE ==
+
+        AccountInfoByTokenAndMember::<T>::mutate(token_id, &member_id, |account_info| {
+            account_info.frozen = frozen;
+        });
+
+        Self::deposit_event(RawEvent::AccountFrozenStatusUpdated(
+            token_id, member_id, frozen,
+        ));
+
+        Ok(())
+    }
+
+    /// Pause (or unpause) a token
+    /// Preconditions:
+    /// - token by `token_id` must exist
+    ///
+    /// Postconditions:
+    /// - `token_id` paused status set to `paused`
+    ///   (no account of a paused token can transfer, burn, participate in revenue splits
+    ///   or purchase tokens on sale)
+    fn set_token_paused_status(token_id: T::TokenId, paused: bool) -> DispatchResult {
+        Self::ensure_token_exists(token_id)?;
+
+        // == MUTATION SAFE ==
+
+        TokenInfoById::<T>::mutate(token_id, |token_info| {
+            token_info.paused = paused;
+        });
+
+        Self::deposit_event(RawEvent::TokenPausedStatusUpdated(token_id, paused));
+
+        Ok(())
+    }
+
+    /// Start a buyback program funded from `budget_source_account`
+    /// Preconditions:
+    /// - token by `token_id` must exist
+    /// - token must not have an unfinalized buyback program
+    /// - `params.budget`, `params.max_unit_price` and `params.duration` must be > 0
+    /// - if Some(start) specified: `start >= System::block_number()`
+    /// - `budget_source_account` must be able to *transfer* `params.budget` amount of JOY
+    ///
+    /// Postconditions:
+    /// - `params.budget` transferred from `budget_source_account` to `treasury_account`
+    /// - buyback program stored for `token_id`
+    /// - program timeline is [start, start + duration), with `start` one of:
+    ///    - `current_block`
+    ///    - specified `Some(start)`
+    fn start_buyback_program(
+        token_id: T::TokenId,
+        budget_source_account: T::AccountId,
+        params: BuybackParamsOf<T>,
+    ) -> DispatchResult {
+        Self::ensure_token_exists(token_id)?;
 
         ensure!(
-            !allocation_amount.is_zero(),
-            Error::<T>::CannotIssueSplitWithZeroAllocationAmount,
+            !BuybackProgramByTokenId::<T>::contains_key(token_id),
+            Error::<T>::BuybackProgramAlreadyExists
         );
 
+        ensure!(!params.budget.is_zero(), Error::<T>::BuybackBudgetIsZero);
         ensure!(
-            duration >= Self::min_revenue_split_duration(),
-            Error::<T>::RevenueSplitDurationTooShort
+            !params.max_unit_price.is_zero(),
+            Error::<T>::BuybackMaxUnitPriceIsZero
+        );
+        ensure!(
+            !params.duration.is_zero(),
+            Error::<T>::BuybackDurationIsZero
         );
 
         let current_block = Self::current_block();
-        if let Some(starting_block) = start {
-            ensure!(
-                starting_block.saturating_sub(current_block)
-                    >= Self::min_revenue_split_time_to_start(),
-                Error::<T>::RevenueSplitTimeToStartTooShort,
-            );
-        }
-
-        let revenue_split_start = start.unwrap_or_else(|| {
-            current_block.saturating_add(Self::min_revenue_split_time_to_start())
-        });
-        let timeline = TimelineOf::<T>::from_params(revenue_split_start, duration);
-
-        let treasury_account = Self::module_treasury_account();
+        let start = params.start.unwrap_or(current_block);
+        ensure!(start >= current_block, Error::<T>::BuybackStartInThePast);
 
-        Self::ensure_can_transfer_joy(&revenue_source_account, allocation_amount)?;
+        Self::ensure_can_transfer_joy(&budget_source_account, params.budget)?;
 
         // == MUTATION SAFE ==
 
-        // tranfer allocation keeping the source account alive
         Self::transfer_joy(
-            &revenue_source_account,
-            &treasury_account,
-            allocation_amount,
+            &budget_source_account,
+            &Self::module_treasury_account(),
+            params.budget,
         )?;
 
-        TokenInfoById::<T>::mutate(token_id, |token_info| {
-            token_info.activate_new_revenue_split(allocation_amount, timeline);
-        });
+        let program = BuybackProgramOf::<T> {
+            budget: params.budget,
+            max_unit_price: params.max_unit_price,
+            spent: JoyBalanceOf::<T>::zero(),
+            timeline: TimelineOf::<T>::from_params(start, params.duration),
+        };
 
-        Self::deposit_event(RawEvent::RevenueSplitIssued(
-            token_id,
-            revenue_split_start,
-            duration,
-            allocation_amount,
-        ));
+        BuybackProgramByTokenId::<T>::insert(token_id, program.clone());
 
-        Ok(revenue_amount.saturating_sub(allocation_amount))
+        Self::deposit_event(RawEvent::BuybackProgramStarted(token_id, program));
+
+        Ok(())
     }
 
-    /// Finalize revenue split once it is ended
-    /// Preconditions
-    /// - `token` at `token_id`
-    /// - `token.revenue_split` is active
-    /// - `token.revenue_split` has ended
+    /// Finalize buyback program once it is ended
+    /// Preconditions:
+    /// - token by `token_id` must exist
+    /// - token must have a buyback program
+    /// - buyback program has ended
     ///
-    /// Postconditions
-    /// - `token.revenue_split.leftovers()` of JOYs transferred to `account_id`
-    /// - `token.revenue_split` status set to Inactive
-    fn finalize_revenue_split(token_id: T::TokenId, account_id: T::AccountId) -> DispatchResult {
-        let token_info = Self::ensure_token_exists(token_id)?;
+    /// Postconditions:
+    /// - unspent budget transferred from `treasury_account` to `account_id`
+    /// - buyback program removed from storage
+    fn finalize_buyback_program(token_id: T::TokenId, account_id: T::AccountId) -> DispatchResult {
+        Self::ensure_token_exists(token_id)?;
 
-        let split_info = token_info.revenue_split.ensure_active::<T>()?;
+        let program = Self::ensure_buyback_program_exists(token_id)?;
 
-        let current_block = Self::current_block();
         ensure!(
-            split_info.timeline.is_ended(current_block),
-            Error::<T>::RevenueSplitDidNotEnd
+            program.timeline.is_ended(Self::current_block()),
+            Error::<T>::BuybackProgramNotEnded
         );
 
-        // = MUTATION SAFE =
+        // == MUTATION SAFE ==
 
-        let treasury_account = Self::module_treasury_account();
-        let amount_to_withdraw = split_info.leftovers();
+        let leftovers = program.leftovers();
 
-        Self::transfer_joy(&treasury_account, &account_id, amount_to_withdraw)?;
+        Self::transfer_joy(&Self::module_treasury_account(), &account_id, leftovers)?;
 
-        TokenInfoById::<T>::mutate(token_id, |token_info| token_info.deactivate_revenue_split());
+        BuybackProgramByTokenId::<T>::remove(token_id);
 
-        Self::deposit_event(RawEvent::RevenueSplitFinalized(
-            token_id,
-            account_id,
-            amount_to_withdraw,
+        Self::deposit_event(RawEvent::BuybackProgramFinalized(
+            token_id, account_id, leftovers,
         ));
 
         Ok(())
     }
 
+    /// Enable (or disable) time-weighted revenue splits for the token
+    /// Preconditions:
+    /// - token by `token_id` must exist
+    /// - token must not have an active revenue split (the balance checkpoints must keep
+    ///   covering its eligibility window)
+    /// - if Some(lookback) specified: `lookback > 0`
+    ///
+    /// Postconditions:
+    /// - `token.time_weighted_splits` set to `None` if `lookback` is `None`, otherwise to
+    ///   settings with the specified `lookback` and `tracked_since` equal to:
+    ///    - the previous `tracked_since` if time-weighted revenue splits were already enabled
+    ///    - `current_block` otherwise
+    /// - while enabled, accounts record balance checkpoints on every balance change
+    ///   and the dividends of the revenue splits issued are based on the time-weighted
+    ///   average balances over the `lookback` blocks preceding the split issuance
+    fn set_time_weighted_revenue_splits(
+        token_id: T::TokenId,
+        lookback: Option<T::BlockNumber>,
+    ) -> DispatchResult {
+        let mut token_info = Self::ensure_token_exists(token_id)?;
+        token_info.revenue_split.ensure_inactive::<T>()?;
+
+        if let Some(lookback) = lookback {
+            ensure!(
+                !lookback.is_zero(),
+                Error::<T>::TimeWeightedSplitsLookbackIsZero
+            );
+        }
+
+        // == MUTATION SAFE ==
+
+        token_info.set_time_weighted_splits(lookback, Self::current_block());
+        let settings = token_info.time_weighted_splits.clone();
+        TokenInfoById::<T>::insert(token_id, token_info);
+
+        Self::deposit_event(RawEvent::TimeWeightedSplitsUpdated(token_id, settings));
+
+        Ok(())
+    }
+
     /// Allows the issuer to finalize an ended creator token sale and recover any leftover
     /// tokens that were not sold.
     ///
@@ -1328,6 +2631,7 @@ impl<T: Config>
     fn finalize_token_sale(token_id: T::TokenId) -> Result<JoyBalanceOf<T>, DispatchError> {
         let token_info = Self::ensure_token_exists(token_id)?;
         OfferingStateOf::<T>::ensure_idle_of::<T>(&token_info)?;
+        let relevant_since = token_info.balance_checkpoints_relevant_since(Self::current_block());
         let sale = token_info.sale.ok_or(Error::<T>::NoTokensToRecover)?;
         let sale_id = token_info
             .next_sale_id
@@ -1336,6 +2640,7 @@ impl<T: Config>
 
         // == MUTATION SAFE ==
         AccountInfoByTokenAndMember::<T>::mutate(token_id, &sale.tokens_source, |ad| {
+            ad.checkpoint_balance(ad.amount, Self::current_block(), relevant_since);
             ad.increase_amount_by(sale.quantity_left);
         });
         TokenInfoById::<T>::mutate(token_id, |token_info| {
@@ -1380,9 +2685,235 @@ impl<T: Config> Module<T> {
     pub(crate) fn do_deissue_token(symbol: T::Hash, token_id: T::TokenId) {
         SymbolsUsed::<T>::remove(symbol);
         TokenInfoById::<T>::remove(token_id);
+        TokenPollByTokenId::<T>::remove(token_id);
+        AirdropById::<T>::remove_prefix(token_id, None);
+        SwapOfferById::<T>::remove_prefix(token_id, None);
         // TODO: add extra state removal as implementation progresses
     }
 
+    pub(crate) fn ensure_token_poll_exists(
+        token_id: T::TokenId,
+    ) -> Result<TokenPollOf<T>, DispatchError> {
+        Self::token_poll_by_token_id(token_id).ok_or_else(|| Error::<T>::NoTokenPoll.into())
+    }
+
+    pub(crate) fn ensure_airdrop_exists(
+        token_id: T::TokenId,
+        airdrop_id: AirdropId,
+    ) -> Result<AirdropOf<T>, DispatchError> {
+        Self::airdrop_by_id(token_id, airdrop_id)
+            .ok_or_else(|| Error::<T>::AirdropDoesNotExist.into())
+    }
+
+    pub(crate) fn ensure_buyback_program_exists(
+        token_id: T::TokenId,
+    ) -> Result<BuybackProgramOf<T>, DispatchError> {
+        Self::buyback_program_by_token_id(token_id)
+            .ok_or_else(|| Error::<T>::BuybackProgramDoesNotExist.into())
+    }
+
+    pub(crate) fn ensure_swap_offer_exists(
+        token_id: T::TokenId,
+        offer_id: SwapOfferId,
+    ) -> Result<SwapOfferOf<T>, DispatchError> {
+        Self::swap_offer_by_id(token_id, offer_id)
+            .ok_or_else(|| Error::<T>::SwapOfferDoesNotExist.into())
+    }
+
+    /// Swap offers of `token_id` filled by the buyback `program` at block `b`: the offers
+    /// requesting JOY at or below `program.max_unit_price` per token (except the ones made by
+    /// frozen accounts) are filled cheapest first until the budget released so far runs out.
+    /// Returns the filled offers (with their amounts decreased) together with the maker's
+    /// controller account, the amount of tokens bought and the JOY paid for them.
+    pub(crate) fn buyback_fills(
+        token_id: T::TokenId,
+        program: &BuybackProgramOf<T>,
+        b: T::BlockNumber,
+        swap_offers_number: u32,
+    ) -> Result<Vec<BuybackFillOf<T>>, DispatchError> {
+        let mut offers_found: u32 = 0;
+        let mut candidates = Vec::new();
+        for (offer_id, offer) in SwapOfferById::<T>::iter_prefix(token_id) {
+            offers_found = offers_found.saturating_add(1);
+            ensure!(
+                offers_found <= swap_offers_number,
+                Error::<T>::SwapOffersNumberExceeded
+            );
+
+            let maker_frozen = Self::ensure_account_data_exists(token_id, &offer.maker)
+                .map_or(false, |account_info| account_info.frozen);
+            if let (SwapAsset::Joy(wanted), false) = (offer.wanted_left, maker_frozen) {
+                if program.accepts_price(wanted, offer.amount_left.into()) {
+                    candidates.push((offer.joy_unit_price(), offer_id, offer));
+                }
+            }
+        }
+        // cheapest first, older offers first in case of equal prices
+        candidates.sort_by_key(|(unit_price, offer_id, _)| (*unit_price, *offer_id));
+
+        let mut budget_left = program.available_budget_at_block(b);
+        let mut fills = Vec::new();
+        for (_, offer_id, mut offer) in candidates {
+            let amount = offer.amount_affordable_with(budget_left);
+            // all of the remaining offers are at least as expensive
+            if amount.is_zero() {
+                break;
+            }
+
+            let (amount, payment) = offer.ensure_can_fill::<T>(amount)?;
+            if let SwapAsset::Joy(joy_amount) = payment {
+                let maker_account = T::MembershipInfoProvider::controller_account_id(offer.maker)?;
+                offer.process_fill(amount, &payment);
+                budget_left = budget_left.saturating_sub(joy_amount);
+                fills.push((offer_id, offer, maker_account, amount, joy_amount));
+            }
+        }
+
+        Ok(fills)
+    }
+
+    /// Set the allowance of `spender` for `token_id` x `member_id` account
+    /// Preconditions:
+    /// - token by `token_id` must exist
+    /// - account for `member_id` must exist
+    /// - `allowance.expiry` (if provided) must be > current block
+    /// - if `spender` has no allowance yet and `allowance.amount` is > 0:
+    ///   account's number of allowances must be < `MaxAllowancesPerAccount`
+    ///
+    /// Postconditions:
+    /// - allowance of `spender` set to `allowance` (or removed if `allowance.amount` is 0)
+    /// - account's number of allowances updated accordingly
+    pub(crate) fn do_approve(
+        token_id: T::TokenId,
+        member_id: T::MemberId,
+        spender: AllowanceSpenderOf<T>,
+        allowance: AllowanceOf<T>,
+    ) -> DispatchResult {
+        Self::ensure_token_exists(token_id)?;
+        let account_info = Self::ensure_account_data_exists(token_id, &member_id)?;
+
+        if let Some(expiry) = allowance.expiry {
+            ensure!(
+                expiry > Self::current_block(),
+                Error::<T>::AllowanceExpiryInThePast
+            );
+        }
+
+        let allowance_exists =
+            AllowanceByTokenAndMember::<T>::contains_key((token_id, member_id), &spender);
+        let revoked = allowance.amount.is_zero();
+
+        if !allowance_exists && !revoked {
+            account_info.ensure_can_add_allowance::<T>()?;
+        }
+
+        // == MUTATION SAFE ==
+
+        if revoked {
+            AllowanceByTokenAndMember::<T>::remove((token_id, member_id), &spender);
+        } else {
+            AllowanceByTokenAndMember::<T>::insert(
+                (token_id, member_id),
+                &spender,
+                allowance.clone(),
+            );
+        }
+
+        if allowance_exists && revoked {
+            AccountInfoByTokenAndMember::<T>::mutate(token_id, &member_id, |account_data| {
+                account_data.decrement_allowances_number();
+            });
+        } else if !allowance_exists && !revoked {
+            AccountInfoByTokenAndMember::<T>::mutate(token_id, &member_id, |account_data| {
+                account_data.increment_allowances_number();
+            });
+        }
+
+        Self::deposit_event(RawEvent::AllowanceApproved(
+            token_id, member_id, spender, allowance,
+        ));
+
+        Ok(())
+    }
+
+    /// Transfer tokens from `src_member_id` account using the allowance of `spender`
+    /// Preconditions:
+    /// - allowance of `spender` must exist for `token_id` x `src_member_id`
+    /// - allowance must not be expired and must be >= total amount of `outputs`
+    /// - transfer preconditions (see `ensure_can_transfer`) with `bloat_bond_payer`
+    ///   covering the bloat bonds of the new destination accounts
+    ///
+    /// Postconditions:
+    /// - transfer postconditions (see `do_transfer`)
+    /// - allowance decreased by the total amount of `outputs` (removed if it reaches 0)
+    pub(crate) fn do_transfer_from(
+        spender: AllowanceSpenderOf<T>,
+        bloat_bond_payer: &T::AccountId,
+        token_id: T::TokenId,
+        src_member_id: T::MemberId,
+        outputs: TransfersOf<T>,
+        metadata: Vec<u8>,
+    ) -> DispatchResult {
+        let allowance = Self::allowance((token_id, src_member_id), &spender)
+            .ok_or(Error::<T>::AllowanceDoesNotExist)?;
+
+        let transfers: TransfersWithVestingOf<T> = outputs.into();
+        let total_amount = transfers.total_amount();
+        allowance.ensure_can_spend::<T>(Self::current_block(), total_amount)?;
+
+        // Currency transfer preconditions
+        let validated_transfers = Self::ensure_can_transfer(
+            token_id,
+            bloat_bond_payer,
+            &src_member_id,
+            transfers,
+            false,
+        )?;
+
+        // == MUTATION SAFE ==
+
+        Self::do_transfer(
+            token_id,
+            bloat_bond_payer,
+            &src_member_id,
+            &validated_transfers,
+        )?;
+
+        let amount_left = allowance.amount.saturating_sub(total_amount);
+        if amount_left.is_zero() {
+            AllowanceByTokenAndMember::<T>::remove((token_id, src_member_id), &spender);
+            AccountInfoByTokenAndMember::<T>::mutate(token_id, &src_member_id, |account_data| {
+                account_data.decrement_allowances_number();
+            });
+        } else {
+            AllowanceByTokenAndMember::<T>::insert(
+                (token_id, src_member_id),
+                &spender,
+                AllowanceOf::<T> {
+                    amount: amount_left,
+                    ..allowance
+                },
+            );
+        }
+
+        Self::deposit_event(RawEvent::TokenAmountTransferredFrom(
+            token_id,
+            src_member_id,
+            spender,
+            validated_transfers,
+            metadata,
+        ));
+
+        Ok(())
+    }
+
+    /// Id and tallies of the latest token holder poll (used by the runtime api)
+    pub fn token_poll_tallies(
+        token_id: T::TokenId,
+    ) -> Option<(TokenPollId, Vec<TokenBalanceOf<T>>)> {
+        Self::token_poll_by_token_id(token_id).map(|poll| (poll.poll_id, poll.tallies.into_inner()))
+    }
+
     /// Transfer preconditions
     pub(crate) fn ensure_can_transfer(
         token_id: T::TokenId,
@@ -1393,9 +2924,11 @@ impl<T: Config> Module<T> {
     ) -> Result<ValidatedTransfersOf<T>, DispatchError> {
         // ensure token validity
         let token_info = Self::ensure_token_exists(token_id)?;
+        token_info.ensure_not_paused::<T>()?;
 
         // ensure src account id validity
         let src_account_info = Self::ensure_account_data_exists(token_id, src_member_id)?;
+        src_account_info.ensure_not_frozen::<T>()?;
 
         // ensure src account can cover total transfers amount
         src_account_info
@@ -1423,6 +2956,8 @@ impl<T: Config> Module<T> {
         validated_transfers: &ValidatedTransfersOf<T>,
     ) -> DispatchResult {
         let current_block = Self::current_block();
+        let relevant_since =
+            Self::token_info_by_id(token_id).balance_checkpoints_relevant_since(current_block);
 
         let validated_transfers_with_bloat_bonds =
             Self::pay_transfer_bloat_bonds(bloat_bond_payer, validated_transfers)?;
@@ -1446,6 +2981,11 @@ impl<T: Config> Module<T> {
                         token_id,
                         &dst_member_id,
                         |account_data| {
+                            account_data.checkpoint_balance(
+                                account_data.amount,
+                                current_block,
+                                relevant_since,
+                            );
                             if let Some(vs) = vesting_schedule {
                                 account_data.add_or_update_vesting_schedule::<T>(
                                     VestingSource::IssuerTransfer(
@@ -1462,27 +3002,26 @@ impl<T: Config> Module<T> {
                     )?;
                 }
                 ValidatedWithBloatBond::NonExisting(dst_member_id, repayable_bloat_bond) => {
-                    Self::do_insert_new_account_for_token(
-                        token_id,
-                        dst_member_id,
-                        if let Some(vs) = vesting_schedule {
-                            AccountDataOf::<T>::new_with_vesting_and_bond::<T>(
-                                VestingSource::IssuerTransfer(0),
-                                vs,
-                                repayable_bloat_bond.clone(),
-                            )?
-                        } else {
-                            AccountDataOf::<T>::new_with_amount_and_bond(
-                                validated_payment.payment.amount,
-                                repayable_bloat_bond.clone(),
-                            )
-                        },
-                    );
+                    let mut account_data = if let Some(vs) = vesting_schedule {
+                        AccountDataOf::<T>::new_with_vesting_and_bond::<T>(
+                            VestingSource::IssuerTransfer(0),
+                            vs,
+                            repayable_bloat_bond.clone(),
+                        )?
+                    } else {
+                        AccountDataOf::<T>::new_with_amount_and_bond(
+                            validated_payment.payment.amount,
+                            repayable_bloat_bond.clone(),
+                        )
+                    };
+                    account_data.checkpoint_balance(Zero::zero(), current_block, relevant_since);
+                    Self::do_insert_new_account_for_token(token_id, dst_member_id, account_data);
                 }
             }
         }
 
         AccountInfoByTokenAndMember::<T>::mutate(token_id, &src_member_id, |account_data| {
+            account_data.checkpoint_balance(account_data.amount, current_block, relevant_since);
             account_data.decrease_amount_by(validated_transfers.total_amount());
         });
 
@@ -1506,6 +3045,73 @@ impl<T: Config> Module<T> {
         perc_of_the_supply.mul_floor(split_allocation)
     }
 
+    /// Issue a revenue split for `token_info` allocating `split_rate` of `revenue_amount`
+    /// and return the part of `revenue_amount` not allocated to the split
+    pub(crate) fn do_issue_revenue_split(
+        token_id: T::TokenId,
+        token_info: TokenDataOf<T>,
+        start: Option<T::BlockNumber>,
+        duration: T::BlockNumber,
+        revenue_source_account: T::AccountId,
+        revenue_amount: JoyBalanceOf<T>,
+        split_rate: Permill,
+    ) -> Result<JoyBalanceOf<T>, DispatchError> {
+        token_info.revenue_split.ensure_inactive::<T>()?;
+
+        let allocation_amount = split_rate.mul_floor(revenue_amount);
+
+        ensure!(
+            !allocation_amount.is_zero(),
+            Error::<T>::CannotIssueSplitWithZeroAllocationAmount,
+        );
+
+        ensure!(
+            duration >= Self::min_revenue_split_duration(),
+            Error::<T>::RevenueSplitDurationTooShort
+        );
+
+        let current_block = Self::current_block();
+        if let Some(starting_block) = start {
+            ensure!(
+                starting_block.saturating_sub(current_block)
+                    >= Self::min_revenue_split_time_to_start(),
+                Error::<T>::RevenueSplitTimeToStartTooShort,
+            );
+        }
+
+        let revenue_split_start = start.unwrap_or_else(|| {
+            current_block.saturating_add(Self::min_revenue_split_time_to_start())
+        });
+        let timeline = TimelineOf::<T>::from_params(revenue_split_start, duration);
+
+        let treasury_account = Self::module_treasury_account();
+
+        Self::ensure_can_transfer_joy(&revenue_source_account, allocation_amount)?;
+
+        // == MUTATION SAFE ==
+
+        // tranfer allocation keeping the source account alive
+        Self::transfer_joy(
+            &revenue_source_account,
+            &treasury_account,
+            allocation_amount,
+        )?;
+
+        TokenInfoById::<T>::mutate(token_id, |token_info| {
+            let eligibility_window = token_info.revenue_split_eligibility_window(current_block);
+            token_info.activate_new_revenue_split(allocation_amount, timeline, eligibility_window);
+        });
+
+        Self::deposit_event(RawEvent::RevenueSplitIssued(
+            token_id,
+            revenue_split_start,
+            duration,
+            allocation_amount,
+        ));
+
+        Ok(revenue_amount.saturating_sub(allocation_amount))
+    }
+
     pub(crate) fn ensure_can_init_sale(
         token_id: T::TokenId,
         member_id: T::MemberId,
@@ -1532,7 +3138,7 @@ impl<T: Config> Module<T> {
         let account_data = Self::ensure_account_data_exists(token_id, &member_id)?;
 
         // Ensure source account has enough transferrable tokens
-        account_data.ensure_can_transfer::<T>(current_block, sale_params.upper_bound_quantity)?;
+        account_data.ensure_can_transfer::<T>(current_block, sale_params.total_quantity())?;
 
         Ok(())
     }
@@ -1588,6 +3194,23 @@ impl<T: Config> Module<T> {
             Error::<T>::CannotDeissueTokenWithOutstandingAccounts,
         );
 
+        ensure!(
+            !BuybackProgramByTokenId::<T>::contains_key(token_id),
+            Error::<T>::CannotDeissueTokenWithUnfinalizedBuybackProgram,
+        );
+
+        // Airdropped tokens are held outside of the token accounts
+        ensure!(
+            AirdropById::<T>::iter_prefix(token_id).next().is_none(),
+            Error::<T>::CannotDeissueTokenWithPendingAirdrops,
+        );
+
+        // Offered tokens are held in escrow until the offer is filled or cancelled
+        ensure!(
+            SwapOfferById::<T>::iter_prefix(token_id).next().is_none(),
+            Error::<T>::CannotDeissueTokenWithOpenSwapOffers,
+        );
+
         // This is a extra, since when no account exists -> total_supply == 0
         debug_assert!(token_info.total_supply.is_zero());
 
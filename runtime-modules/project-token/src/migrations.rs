@@ -0,0 +1,196 @@
+// Storage migrations of the project token pallet.
+
+use codec::{Decode, Encode};
+use frame_support::{
+    storage::{IterableStorageDoubleMap, IterableStorageMap},
+    traits::Get,
+    weights::Weight,
+    BoundedVec,
+};
+use sp_runtime::Permill;
+use sp_std::convert::TryInto;
+use sp_std::vec;
+
+use crate::types::*;
+use crate::{AccountInfoByTokenAndMember, Config, Module, TokenInfoById};
+use common::bloat_bond::RepayableBloatBond;
+use common::MembershipTypes;
+
+/// Account data stored before the token holder polls, allowances, account freezing
+/// and time-weighted revenue splits were introduced
+#[derive(Encode, Decode)]
+pub(crate) struct LegacyAccountData<Balance, StakingStatus, RepayableBloatBond, VestingSchedules> {
+    pub vesting_schedules: VestingSchedules,
+    pub amount: Balance,
+    pub split_staking_status: Option<StakingStatus>,
+    pub bloat_bond: RepayableBloatBond,
+    pub next_vesting_transfer_id: u64,
+    pub last_sale_total_purchased_amount: Option<(TokenSaleId, Balance)>,
+}
+
+pub(crate) type LegacyAccountDataOf<T> = LegacyAccountData<
+    TokenBalanceOf<T>,
+    StakingStatusOf<T>,
+    RepayableBloatBond<<T as frame_system::Config>::AccountId, JoyBalanceOf<T>>,
+    VestingSchedulesOf<T>,
+>;
+
+/// Token sale stored before the sale tiers were introduced
+#[derive(Encode, Decode)]
+pub(crate) struct LegacyTokenSale<
+    JoyBalance,
+    Balance,
+    BlockNumber,
+    VestingScheduleParams,
+    MemberId,
+    AccountId,
+> {
+    pub unit_price: JoyBalance,
+    pub quantity_left: Balance,
+    pub funds_collected: JoyBalance,
+    pub tokens_source: MemberId,
+    pub earnings_destination: Option<AccountId>,
+    pub start_block: BlockNumber,
+    pub duration: BlockNumber,
+    pub vesting_schedule_params: Option<VestingScheduleParams>,
+    pub cap_per_member: Option<Balance>,
+    pub auto_finalize: bool,
+}
+
+pub(crate) type LegacyTokenSaleOf<T> = LegacyTokenSale<
+    JoyBalanceOf<T>,
+    TokenBalanceOf<T>,
+    <T as frame_system::Config>::BlockNumber,
+    VestingScheduleParamsOf<T>,
+    <T as MembershipTypes>::MemberId,
+    <T as frame_system::Config>::AccountId,
+>;
+
+/// Revenue split stored before the time-weighted revenue splits were introduced
+#[derive(Encode, Decode)]
+pub(crate) struct LegacyRevenueSplitInfo<JoyBalance, BlockNumber> {
+    pub allocation: JoyBalance,
+    pub timeline: Timeline<BlockNumber>,
+    pub dividends_claimed: JoyBalance,
+}
+
+/// Revenue split state stored before the time-weighted revenue splits were introduced
+#[derive(Encode, Decode)]
+pub(crate) enum LegacyRevenueSplitState<JoyBalance, BlockNumber> {
+    Inactive,
+    Active(LegacyRevenueSplitInfo<JoyBalance, BlockNumber>),
+}
+
+/// Token data stored before the sale tiers, token pausing and time-weighted revenue splits
+/// were introduced
+#[derive(Encode, Decode)]
+pub(crate) struct LegacyTokenData<Balance, Hash, BlockNumber, TokenSale, RevenueSplitState> {
+    pub total_supply: Balance,
+    pub tokens_issued: Balance,
+    pub next_sale_id: TokenSaleId,
+    pub sale: Option<TokenSale>,
+    pub transfer_policy: TransferPolicy<Hash>,
+    pub symbol: Hash,
+    pub patronage_info: PatronageData<Balance, BlockNumber>,
+    pub accounts_number: u64,
+    pub revenue_split_rate: Permill,
+    pub revenue_split: RevenueSplitState,
+    pub next_revenue_split_id: RevenueSplitId,
+}
+
+pub(crate) type LegacyTokenDataOf<T> = LegacyTokenData<
+    TokenBalanceOf<T>,
+    <T as frame_system::Config>::Hash,
+    <T as frame_system::Config>::BlockNumber,
+    LegacyTokenSaleOf<T>,
+    LegacyRevenueSplitState<JoyBalanceOf<T>, <T as frame_system::Config>::BlockNumber>,
+>;
+
+impl<T: Config> Module<T> {
+    /// Translates the stored token accounts to the current `AccountData` format:
+    /// no poll vote, no allowances granted, not frozen and no balance checkpoints.
+    pub fn migrate_account_data() -> Weight {
+        let mut translated: u64 = 0;
+
+        AccountInfoByTokenAndMember::<T>::translate::<LegacyAccountDataOf<T>, _>(
+            |_, _, account| {
+                translated = translated.saturating_add(1);
+                Some(AccountDataOf::<T> {
+                    vesting_schedules: account.vesting_schedules,
+                    amount: account.amount,
+                    split_staking_status: account.split_staking_status,
+                    bloat_bond: account.bloat_bond,
+                    next_vesting_transfer_id: account.next_vesting_transfer_id,
+                    last_sale_total_purchased_amount: account.last_sale_total_purchased_amount,
+                    poll_vote: None,
+                    allowances_number: 0,
+                    frozen: false,
+                    balance_checkpoints: BoundedVec::default(),
+                })
+            },
+        );
+
+        T::DbWeight::get().reads_writes(translated, translated)
+    }
+
+    /// Translates the stored tokens to the current `TokenData` format: the current sale
+    /// (if any) gets a single tier with its price and quantity left, the tokens are not paused
+    /// and time-weighted revenue splits are disabled.
+    pub fn migrate_token_data() -> Weight {
+        let mut translated: u64 = 0;
+
+        TokenInfoById::<T>::translate::<LegacyTokenDataOf<T>, _>(|_, token| {
+            translated = translated.saturating_add(1);
+            Some(TokenDataOf::<T> {
+                total_supply: token.total_supply,
+                tokens_issued: token.tokens_issued,
+                next_sale_id: token.next_sale_id,
+                sale: token.sale.map(Self::migrate_token_sale),
+                transfer_policy: token.transfer_policy,
+                symbol: token.symbol,
+                patronage_info: token.patronage_info,
+                accounts_number: token.accounts_number,
+                revenue_split_rate: token.revenue_split_rate,
+                revenue_split: match token.revenue_split {
+                    LegacyRevenueSplitState::Inactive => RevenueSplitState::Inactive,
+                    LegacyRevenueSplitState::Active(info) => {
+                        RevenueSplitState::Active(RevenueSplitInfo {
+                            allocation: info.allocation,
+                            timeline: info.timeline,
+                            dividends_claimed: info.dividends_claimed,
+                            eligibility_window: None,
+                        })
+                    }
+                },
+                next_revenue_split_id: token.next_revenue_split_id,
+                paused: false,
+                time_weighted_splits: None,
+            })
+        });
+
+        T::DbWeight::get().reads_writes(translated, translated)
+    }
+
+    fn migrate_token_sale(sale: LegacyTokenSaleOf<T>) -> TokenSaleOf<T> {
+        // The whole sale is a single tier vested according to sale's `vesting_schedule_params`
+        let initial_tier = TokenSaleTier {
+            unit_price: sale.unit_price,
+            quantity_left: sale.quantity_left,
+            vesting_schedule_params: None,
+        };
+
+        TokenSaleOf::<T> {
+            unit_price: sale.unit_price,
+            quantity_left: sale.quantity_left,
+            funds_collected: sale.funds_collected,
+            tokens_source: sale.tokens_source,
+            earnings_destination: sale.earnings_destination,
+            start_block: sale.start_block,
+            duration: sale.duration,
+            vesting_schedule_params: sale.vesting_schedule_params,
+            cap_per_member: sale.cap_per_member,
+            auto_finalize: sale.auto_finalize,
+            tiers: vec![initial_tier].try_into().unwrap_or_default(),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use crate::types::TokenPollId;
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// Project token runtime API.
+    pub trait ProjectTokenApi<TokenId, Balance>
+    where
+        TokenId: Codec,
+        Balance: Codec,
+    {
+        /// Id and per-alternative tallies of the latest token holder poll of the token.
+        fn token_poll_tallies(token_id: TokenId) -> Option<(TokenPollId, Vec<Balance>)>;
+    }
+}
@@ -0,0 +1,442 @@
+#![cfg(test)]
+use frame_support::{assert_err, assert_ok, StorageValue};
+use sp_runtime::{traits::Hash, DispatchError, Permill};
+
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::traits::PalletToken;
+use crate::types::{MerkleProof, Transfers, VestingSchedule, VestingSource};
+use crate::{joy, last_event_eq, member, origin, BloatBond, Error, RawEvent};
+
+#[test]
+fn issue_airdrop_fails_with_invalid_token_id() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        let result = IssueAirdropFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenDoesNotExist);
+    })
+}
+
+#[test]
+fn issue_airdrop_fails_with_zero_amount() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = IssueAirdropFixture::default()
+            .with_total_amount(0)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::AirdropAmountIsZero);
+    })
+}
+
+#[test]
+fn issue_airdrop_fails_with_expiry_in_the_past() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = IssueAirdropFixture::default()
+            .with_expiry(Token::current_block())
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::AirdropExpiryInThePast);
+    })
+}
+
+#[test]
+fn issue_airdrop_fails_with_insufficient_issuer_balance() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = IssueAirdropFixture::default()
+            .with_total_amount(DEFAULT_INITIAL_ISSUANCE + 1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientTransferrableBalance);
+    })
+}
+
+#[test]
+fn issue_airdrop_ok_with_issuer_funds_reserved_and_event_deposited() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        let fixture = IssueAirdropFixture::default();
+
+        let airdrop_id = fixture.execute_call().unwrap();
+
+        let airdrop = Token::airdrop_by_id(1u64, airdrop_id).unwrap();
+        assert_eq!(airdrop_id, 0u64);
+        assert_eq!(Token::next_airdrop_id(), 1u64);
+        assert_eq!(airdrop.amount_left, DEFAULT_AIRDROP_AMOUNT);
+        assert_eq!(airdrop.tokens_source, member!(1).0);
+        assert_eq!(
+            Token::account_info_by_token_and_member(1u64, member!(1).0).amount,
+            DEFAULT_INITIAL_ISSUANCE - DEFAULT_AIRDROP_AMOUNT
+        );
+        last_event_eq!(RawEvent::AirdropIssued(
+            1u64,
+            airdrop_id,
+            member!(1).0,
+            fixture.params()
+        ));
+    })
+}
+
+#[test]
+fn claim_airdrop_fails_with_invalid_airdrop_id() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        IssueAirdropFixture::default().execute_call().unwrap();
+
+        let result = ClaimAirdropFixture::default()
+            .with_airdrop_id(1u64)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::AirdropDoesNotExist);
+    })
+}
+
+#[test]
+fn claim_airdrop_fails_with_zero_amount() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        let entries = vec![(member!(2).0, 0u128, None), (member!(3).0, 0u128, None)];
+        IssueTokenFixture::default().execute_call().unwrap();
+        IssueAirdropFixture::default()
+            .with_entries(entries.clone())
+            .execute_call()
+            .unwrap();
+
+        let result = ClaimAirdropFixture::default()
+            .with_entries(entries)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::AirdropClaimAmountIsZero);
+    })
+}
+
+#[test]
+fn claim_airdrop_fails_with_invalid_member_controller() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        IssueAirdropFixture::default().execute_call().unwrap();
+
+        let result = ClaimAirdropFixture::default()
+            .with_sender(member!(3).1)
+            .execute_call();
+
+        assert_err!(
+            result,
+            DispatchError::Other("origin signer not a member controller account")
+        );
+    })
+}
+
+#[test]
+fn claim_airdrop_fails_with_airdrop_expired() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        IssueAirdropFixture::default().execute_call().unwrap();
+        increase_block_number_by(DEFAULT_AIRDROP_DURATION);
+
+        let result = ClaimAirdropFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::AirdropExpired);
+    })
+}
+
+#[test]
+fn claim_airdrop_fails_with_insufficient_airdrop_funds() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        IssueAirdropFixture::default()
+            .with_total_amount(DEFAULT_AIRDROP_CLAIM - 1)
+            .execute_call()
+            .unwrap();
+
+        let result = ClaimAirdropFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientAirdropFunds);
+    })
+}
+
+#[test]
+fn claim_airdrop_fails_with_invalid_proof() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        IssueAirdropFixture::default().execute_call().unwrap();
+
+        let result = ClaimAirdropFixture::default()
+            .with_proof(MerkleProof::<Hashing>(vec![]))
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::MerkleProofVerificationFailure);
+    })
+}
+
+#[test]
+fn claim_airdrop_fails_with_entry_not_in_commitment() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        IssueAirdropFixture::default().execute_call().unwrap();
+
+        let result = ClaimAirdropFixture::default()
+            .with_entries(vec![
+                (member!(2).0, DEFAULT_AIRDROP_CLAIM + 1, None),
+                (member!(3).0, DEFAULT_AIRDROP_CLAIM, None),
+            ])
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::MerkleProofVerificationFailure);
+    })
+}
+
+#[test]
+fn claim_airdrop_fails_with_airdrop_already_claimed() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        IssueAirdropFixture::default().execute_call().unwrap();
+        ClaimAirdropFixture::default().execute_call().unwrap();
+
+        let result = ClaimAirdropFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::AirdropAlreadyClaimed);
+    })
+}
+
+#[test]
+fn claim_airdrop_ok_with_new_account_created() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        IssueAirdropFixture::default().execute_call().unwrap();
+
+        ClaimAirdropFixture::default().execute_call().unwrap();
+
+        let account = Token::account_info_by_token_and_member(1u64, member!(2).0);
+        assert_eq!(account.amount, DEFAULT_AIRDROP_CLAIM);
+        assert_eq!(
+            account.transferrable::<Test>(Token::current_block()),
+            DEFAULT_AIRDROP_CLAIM
+        );
+        assert_eq!(Token::token_info_by_id(1u64).accounts_number, 2);
+    })
+}
+
+#[test]
+fn claim_airdrop_ok_with_existing_account_amount_increased() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap();
+        IssueAirdropFixture::default().execute_call().unwrap();
+
+        ClaimAirdropFixture::default().execute_call().unwrap();
+
+        assert_eq!(
+            Token::account_info_by_token_and_member(1u64, member!(2).0).amount,
+            DEFAULT_SPLIT_PARTICIPATION + DEFAULT_AIRDROP_CLAIM
+        );
+    })
+}
+
+#[test]
+fn claim_airdrop_ok_with_vesting_schedule_added() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        IssueAirdropFixture::default().execute_call().unwrap();
+        let current_block = Token::current_block();
+
+        ClaimAirdropFixture::default()
+            .with_sender(member!(3).1)
+            .with_entry_index(1)
+            .execute_call()
+            .unwrap();
+
+        let account = Token::account_info_by_token_and_member(1u64, member!(3).0);
+        assert_eq!(account.amount, DEFAULT_AIRDROP_CLAIM);
+        assert_eq!(account.transferrable::<Test>(current_block), 0);
+        assert_eq!(
+            account.vesting_schedules.get(&VestingSource::Airdrop(0)),
+            Some(&VestingSchedule {
+                linear_vesting_start_block: current_block + 100,
+                linear_vesting_duration: 100,
+                cliff_amount: Permill::from_percent(50) * DEFAULT_AIRDROP_CLAIM,
+                post_cliff_total_amount: DEFAULT_AIRDROP_CLAIM
+                    - Permill::from_percent(50) * DEFAULT_AIRDROP_CLAIM,
+                burned_amount: 0,
+            })
+        );
+    })
+}
+
+#[test]
+fn claim_airdrop_ok_with_airdrop_updated_and_event_deposited() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        IssueAirdropFixture::default().execute_call().unwrap();
+
+        ClaimAirdropFixture::default().execute_call().unwrap();
+
+        assert!(Token::airdrop_claimed(0u64, member!(2).0));
+        assert_eq!(
+            Token::airdrop_by_id(1u64, 0u64).unwrap().amount_left,
+            DEFAULT_AIRDROP_AMOUNT - DEFAULT_AIRDROP_CLAIM
+        );
+        last_event_eq!(RawEvent::AirdropClaimed(
+            1u64,
+            0u64,
+            member!(2).0,
+            DEFAULT_AIRDROP_CLAIM
+        ));
+    })
+}
+
+#[test]
+fn claim_airdrop_fails_with_permissioned_token_and_non_existing_account() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default()
+            .with_transfer_policy(TransferPolicyParams::Permissioned(WhitelistParams {
+                commitment: Hashing::hash_of(b"commitment"),
+                payload: None,
+            }))
+            .execute_call()
+            .unwrap();
+        IssueAirdropFixture::default().execute_call().unwrap();
+
+        let result = ClaimAirdropFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::AccountInformationDoesNotExist);
+    })
+}
+
+#[test]
+fn claim_airdrop_ok_with_permissioned_token_and_existing_account() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default()
+            .with_transfer_policy(TransferPolicyParams::Permissioned(WhitelistParams {
+                commitment: Hashing::hash_of(b"commitment"),
+                payload: None,
+            }))
+            .execute_call()
+            .unwrap();
+        Token::issuer_transfer(
+            1u64,
+            member!(1).0,
+            member!(1).1,
+            Transfers::<_, _>::new_issuer(vec![(member!(2).0, DEFAULT_SPLIT_PARTICIPATION, None)]),
+            vec![],
+        )
+        .unwrap();
+        IssueAirdropFixture::default().execute_call().unwrap();
+
+        let result = ClaimAirdropFixture::default().execute_call();
+
+        assert_ok!(result);
+    })
+}
+
+#[test]
+fn recover_unclaimed_airdrop_fails_with_invalid_airdrop_id() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = RecoverUnclaimedAirdropFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::AirdropDoesNotExist);
+    })
+}
+
+#[test]
+fn recover_unclaimed_airdrop_fails_with_airdrop_not_expired() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        IssueAirdropFixture::default().execute_call().unwrap();
+        increase_block_number_by(DEFAULT_AIRDROP_DURATION - 1);
+
+        let result = RecoverUnclaimedAirdropFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::AirdropNotExpired);
+    })
+}
+
+#[test]
+fn recover_unclaimed_airdrop_ok_with_tokens_returned_to_issuer() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        IssueAirdropFixture::default().execute_call().unwrap();
+        ClaimAirdropFixture::default().execute_call().unwrap();
+        increase_block_number_by(DEFAULT_AIRDROP_DURATION);
+
+        RecoverUnclaimedAirdropFixture::default()
+            .execute_call()
+            .unwrap();
+
+        assert!(Token::airdrop_by_id(1u64, 0u64).is_none());
+        assert!(!Token::airdrop_claimed(0u64, member!(2).0));
+        assert_eq!(
+            Token::account_info_by_token_and_member(1u64, member!(1).0).amount,
+            DEFAULT_INITIAL_ISSUANCE - DEFAULT_AIRDROP_CLAIM
+        );
+        last_event_eq!(RawEvent::UnclaimedAirdropRecovered(
+            1u64,
+            0u64,
+            DEFAULT_AIRDROP_AMOUNT - DEFAULT_AIRDROP_CLAIM
+        ));
+    })
+}
+
+#[test]
+fn recover_unclaimed_airdrop_fails_with_insufficient_joy_balance_for_source_account() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        IssueAirdropFixture::default()
+            .with_total_amount(DEFAULT_INITIAL_ISSUANCE)
+            .execute_call()
+            .unwrap();
+        Token::dust_account(origin!(member!(1).1), 1u64, member!(1).0).unwrap();
+        BloatBond::<Test>::put(joy!(100));
+        increase_block_number_by(DEFAULT_AIRDROP_DURATION);
+
+        let result = RecoverUnclaimedAirdropFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientJoyBalance);
+    })
+}
+
+#[test]
+fn recover_unclaimed_airdrop_ok_with_source_account_recreated_and_bloat_bond_paid() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        IssueAirdropFixture::default()
+            .with_total_amount(DEFAULT_INITIAL_ISSUANCE)
+            .execute_call()
+            .unwrap();
+        Token::dust_account(origin!(member!(1).1), 1u64, member!(1).0).unwrap();
+        BloatBond::<Test>::put(joy!(100));
+        increase_account_balance(&member!(1).1, ExistentialDeposit::get() + joy!(100));
+        increase_block_number_by(DEFAULT_AIRDROP_DURATION);
+
+        RecoverUnclaimedAirdropFixture::default()
+            .execute_call()
+            .unwrap();
+
+        let account = Token::account_info_by_token_and_member(1u64, member!(1).0);
+        assert_eq!(account.amount, DEFAULT_INITIAL_ISSUANCE);
+        assert_eq!(account.bloat_bond.amount, joy!(100));
+    })
+}
+
+#[test]
+fn deissue_token_fails_with_pending_airdrop() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        IssueAirdropFixture::default()
+            .with_total_amount(DEFAULT_INITIAL_ISSUANCE)
+            .execute_call()
+            .unwrap();
+        // All the tokens are airdropped, the issuer account can be removed
+        Token::dust_account(origin!(member!(1).1), 1u64, member!(1).0).unwrap();
+
+        let result = Token::deissue_token(1u64);
+
+        assert_err!(result, Error::<Test>::CannotDeissueTokenWithPendingAirdrops);
+    })
+}
@@ -0,0 +1,367 @@
+#![cfg(test)]
+use frame_support::{assert_err, assert_ok};
+use sp_runtime::{traits::Hash, DispatchError};
+
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::types::{Allowance, AllowanceSpender, Transfers, Validated};
+use crate::{account, last_event_eq, member, origin, Error, RawEvent};
+
+#[test]
+fn approve_fails_with_invalid_token_id() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        let result = ApproveFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenDoesNotExist);
+    })
+}
+
+#[test]
+fn approve_fails_with_non_existing_account() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = ApproveFixture::default()
+            .with_member(member!(2).0, member!(2).1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::AccountInformationDoesNotExist);
+    })
+}
+
+#[test]
+fn approve_fails_with_invalid_member_controller() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = ApproveFixture::default()
+            .with_member(member!(1).0, member!(2).1)
+            .execute_call();
+
+        assert_err!(
+            result,
+            DispatchError::Other("origin signer not a member controller account")
+        );
+    })
+}
+
+#[test]
+fn approve_fails_with_expiry_in_the_past() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = ApproveFixture::default()
+            .with_expiry(Token::current_block())
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::AllowanceExpiryInThePast);
+    })
+}
+
+#[test]
+fn approve_fails_with_max_allowances_per_account_reached() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        for i in 0..MaxAllowancesPerAccount::get() {
+            ApproveFixture::default()
+                .with_spender(AllowanceSpender::Account(account!(i)))
+                .execute_call()
+                .unwrap();
+        }
+
+        let result = ApproveFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::MaxAllowancesPerAccountReached);
+    })
+}
+
+#[test]
+fn approve_ok_with_allowance_stored_and_event_deposited() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        let expiry = Token::current_block() + DEFAULT_ALLOWANCE_DURATION;
+        let allowance = Allowance {
+            amount: DEFAULT_ALLOWANCE_AMOUNT,
+            expiry: Some(expiry),
+        };
+
+        ApproveFixture::default()
+            .with_expiry(expiry)
+            .execute_call()
+            .unwrap();
+
+        assert_eq!(
+            Token::allowance((1u64, member!(1).0), AllowanceSpender::Member(member!(2).0)),
+            Some(allowance.clone())
+        );
+        assert_eq!(
+            Token::account_info_by_token_and_member(1u64, member!(1).0).allowances_number,
+            1
+        );
+        last_event_eq!(RawEvent::AllowanceApproved(
+            1u64,
+            member!(1).0,
+            AllowanceSpender::Member(member!(2).0),
+            allowance
+        ));
+    })
+}
+
+#[test]
+fn approve_ok_with_existing_allowance_overwritten() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ApproveFixture::default().execute_call().unwrap();
+
+        ApproveFixture::default()
+            .with_amount(DEFAULT_ALLOWANCE_AMOUNT * 2)
+            .execute_call()
+            .unwrap();
+
+        assert_eq!(
+            Token::allowance((1u64, member!(1).0), AllowanceSpender::Member(member!(2).0))
+                .unwrap()
+                .amount,
+            DEFAULT_ALLOWANCE_AMOUNT * 2
+        );
+        assert_eq!(
+            Token::account_info_by_token_and_member(1u64, member!(1).0).allowances_number,
+            1
+        );
+    })
+}
+
+#[test]
+fn approve_ok_with_zero_amount_revoking_allowance() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ApproveFixture::default().execute_call().unwrap();
+
+        ApproveFixture::default()
+            .with_amount(0)
+            .execute_call()
+            .unwrap();
+
+        assert!(
+            Token::allowance((1u64, member!(1).0), AllowanceSpender::Member(member!(2).0))
+                .is_none()
+        );
+        assert_eq!(
+            Token::account_info_by_token_and_member(1u64, member!(1).0).allowances_number,
+            0
+        );
+    })
+}
+
+#[test]
+fn transfer_from_fails_with_no_allowance() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = TransferFromFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::AllowanceDoesNotExist);
+    })
+}
+
+#[test]
+fn transfer_from_fails_with_allowance_of_other_spender() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ApproveFixture::default()
+            .with_spender(AllowanceSpender::Account(member!(2).1))
+            .execute_call()
+            .unwrap();
+
+        let result = TransferFromFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::AllowanceDoesNotExist);
+    })
+}
+
+#[test]
+fn transfer_from_fails_with_invalid_spender_member_controller() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ApproveFixture::default().execute_call().unwrap();
+
+        let result = TransferFromFixture::default()
+            .with_sender(member!(3).1)
+            .execute_call();
+
+        assert_err!(
+            result,
+            DispatchError::Other("origin signer not a member controller account")
+        );
+    })
+}
+
+#[test]
+fn transfer_from_fails_with_allowance_expired() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ApproveFixture::default()
+            .with_expiry(Token::current_block() + DEFAULT_ALLOWANCE_DURATION)
+            .execute_call()
+            .unwrap();
+        increase_block_number_by(DEFAULT_ALLOWANCE_DURATION);
+
+        let result = TransferFromFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::AllowanceExpired);
+    })
+}
+
+#[test]
+fn transfer_from_fails_with_insufficient_allowance() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ApproveFixture::default().execute_call().unwrap();
+
+        let result = TransferFromFixture::default()
+            .with_output(member!(3).0, DEFAULT_ALLOWANCE_AMOUNT + 1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientAllowance);
+    })
+}
+
+#[test]
+fn transfer_from_fails_with_source_tokens_locked() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        IssueTokenPollFixture::default().execute_call().unwrap();
+        VoteInTokenPollFixture::default()
+            .with_member(member!(1).0, member!(1).1)
+            .with_amount(DEFAULT_INITIAL_ISSUANCE)
+            .execute_call()
+            .unwrap();
+        ApproveFixture::default().execute_call().unwrap();
+
+        let result = TransferFromFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientTransferrableBalance);
+    })
+}
+
+#[test]
+fn transfer_from_fails_with_permissioned_token_and_non_existing_destination() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default()
+            .with_transfer_policy(TransferPolicyParams::Permissioned(WhitelistParams {
+                commitment: Hashing::hash_of(b"commitment"),
+                payload: None,
+            }))
+            .execute_call()
+            .unwrap();
+        ApproveFixture::default().execute_call().unwrap();
+
+        let result = TransferFromFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::AccountInformationDoesNotExist);
+    })
+}
+
+#[test]
+fn transfer_from_ok_with_member_spender() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ApproveFixture::default().execute_call().unwrap();
+
+        TransferFromFixture::default().execute_call().unwrap();
+
+        assert_eq!(
+            Token::account_info_by_token_and_member(1u64, member!(1).0).amount,
+            DEFAULT_INITIAL_ISSUANCE - DEFAULT_ALLOWANCE_TRANSFER
+        );
+        assert_eq!(
+            Token::account_info_by_token_and_member(1u64, member!(3).0).amount,
+            DEFAULT_ALLOWANCE_TRANSFER
+        );
+        assert_eq!(
+            Token::allowance((1u64, member!(1).0), AllowanceSpender::Member(member!(2).0))
+                .unwrap()
+                .amount,
+            DEFAULT_ALLOWANCE_AMOUNT - DEFAULT_ALLOWANCE_TRANSFER
+        );
+        last_event_eq!(RawEvent::TokenAmountTransferredFrom(
+            1u64,
+            member!(1).0,
+            AllowanceSpender::Member(member!(2).0),
+            Transfers::<_, _>::new_validated(vec![(
+                Validated::<_>::NonExisting(member!(3).0),
+                DEFAULT_ALLOWANCE_TRANSFER,
+                None,
+                None
+            )]),
+            b"metadata".to_vec()
+        ));
+    })
+}
+
+#[test]
+fn transfer_from_ok_with_account_spender() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ApproveFixture::default()
+            .with_spender(AllowanceSpender::Account(account!(100)))
+            .execute_call()
+            .unwrap();
+
+        let result = TransferFromFixture::default()
+            .with_sender(account!(100))
+            .with_spender_member_id(None)
+            .execute_call();
+
+        assert_ok!(result);
+    })
+}
+
+#[test]
+fn transfer_from_ok_with_allowance_used_up_and_removed() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ApproveFixture::default().execute_call().unwrap();
+
+        TransferFromFixture::default()
+            .with_output(member!(3).0, DEFAULT_ALLOWANCE_AMOUNT)
+            .execute_call()
+            .unwrap();
+
+        assert!(
+            Token::allowance((1u64, member!(1).0), AllowanceSpender::Member(member!(2).0))
+                .is_none()
+        );
+        assert_eq!(
+            Token::account_info_by_token_and_member(1u64, member!(1).0).allowances_number,
+            0
+        );
+    })
+}
+
+#[test]
+fn dust_account_ok_with_allowances_removed() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap();
+        ApproveFixture::default()
+            .with_member(member!(2).0, member!(2).1)
+            .with_spender(AllowanceSpender::Member(member!(3).0))
+            .execute_call()
+            .unwrap();
+        Token::burn(
+            origin!(member!(2).1),
+            1u64,
+            member!(2).0,
+            DEFAULT_SPLIT_PARTICIPATION,
+        )
+        .unwrap();
+
+        Token::dust_account(origin!(member!(2).1), 1u64, member!(2).0).unwrap();
+
+        assert!(
+            Token::allowance((1u64, member!(2).0), AllowanceSpender::Member(member!(3).0))
+                .is_none()
+        );
+    })
+}
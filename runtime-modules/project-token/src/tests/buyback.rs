@@ -0,0 +1,528 @@
+#![cfg(test)]
+use frame_support::assert_err;
+
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::traits::PalletToken;
+use crate::types::{
+    BuybackProgram, Joy, SwapAsset, SwapOffer, Timeline, TokenIssuanceParametersOf,
+};
+use crate::{balance, last_event_eq, member, yearly_rate, Error, RawEvent};
+
+// Issues the token, sends `DEFAULT_SPLIT_PARTICIPATION` tokens to member 2
+// and starts the default buyback program funded by member 1
+fn issue_token_and_start_buyback_program() {
+    IssueTokenFixture::default().execute_call().unwrap();
+    TransferFixture::default().execute_call().unwrap();
+    StartBuybackProgramFixture::default()
+        .execute_call()
+        .unwrap();
+}
+
+// Member 2 offers `amount` of tokens for `wanted` JOY
+fn create_buyback_swap_offer(amount: Balance, wanted: JoyBalance) {
+    CreateSwapOfferFixture::default()
+        .with_member(member!(2).0, member!(2).1)
+        .with_amount(amount)
+        .with_wanted(SwapAsset::Joy(wanted))
+        .execute_call()
+        .unwrap();
+}
+
+fn buyback_test_externalities() -> TestExternalities {
+    build_default_test_externalities_with_balances(vec![(
+        member!(1).1,
+        DEFAULT_BUYBACK_BUDGET + ExistentialDeposit::get(),
+    )])
+}
+
+#[test]
+fn start_buyback_program_fails_with_invalid_token_id() {
+    buyback_test_externalities().execute_with(|| {
+        let result = StartBuybackProgramFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenDoesNotExist);
+    })
+}
+
+#[test]
+fn start_buyback_program_fails_with_zero_budget() {
+    buyback_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = StartBuybackProgramFixture::default()
+            .with_budget(0)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::BuybackBudgetIsZero);
+    })
+}
+
+#[test]
+fn start_buyback_program_fails_with_zero_max_unit_price() {
+    buyback_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = StartBuybackProgramFixture::default()
+            .with_max_unit_price(0)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::BuybackMaxUnitPriceIsZero);
+    })
+}
+
+#[test]
+fn start_buyback_program_fails_with_zero_duration() {
+    buyback_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = StartBuybackProgramFixture::default()
+            .with_duration(0)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::BuybackDurationIsZero);
+    })
+}
+
+#[test]
+fn start_buyback_program_fails_with_start_in_the_past() {
+    buyback_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        increase_block_number_by(10);
+
+        let result = StartBuybackProgramFixture::default()
+            .with_start(System::block_number() - 1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::BuybackStartInThePast);
+    })
+}
+
+#[test]
+fn start_buyback_program_fails_with_source_having_insufficient_balance() {
+    buyback_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = StartBuybackProgramFixture::default()
+            .with_budget(DEFAULT_BUYBACK_BUDGET + 1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientJoyBalance);
+    })
+}
+
+#[test]
+fn start_buyback_program_fails_with_unfinalized_program() {
+    buyback_test_externalities().execute_with(|| {
+        issue_token_and_start_buyback_program();
+        increase_block_number_by(DEFAULT_BUYBACK_DURATION);
+
+        let result = StartBuybackProgramFixture::default()
+            .with_budget(ExistentialDeposit::get())
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::BuybackProgramAlreadyExists);
+    })
+}
+
+#[test]
+fn start_buyback_program_ok_with_budget_transferred_and_event_deposited() {
+    buyback_test_externalities().execute_with(|| {
+        let treasury_account = Token::module_treasury_account();
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        StartBuybackProgramFixture::default()
+            .execute_call()
+            .unwrap();
+
+        let program = BuybackProgram {
+            budget: DEFAULT_BUYBACK_BUDGET,
+            max_unit_price: DEFAULT_BUYBACK_MAX_UNIT_PRICE,
+            spent: 0,
+            timeline: Timeline::from_params(System::block_number(), DEFAULT_BUYBACK_DURATION),
+        };
+        assert_eq!(
+            Token::buyback_program_by_token_id(1u64),
+            Some(program.clone())
+        );
+        assert_eq!(
+            Joy::<Test>::usable_balance(treasury_account),
+            DEFAULT_BUYBACK_BUDGET + ExistentialDeposit::get()
+        );
+        assert_eq!(
+            Joy::<Test>::usable_balance(member!(1).1),
+            ExistentialDeposit::get()
+        );
+        last_event_eq!(RawEvent::BuybackProgramStarted(1u64, program));
+    })
+}
+
+#[test]
+fn execute_buyback_fails_with_no_buyback_program() {
+    buyback_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap();
+        create_buyback_swap_offer(DEFAULT_BUYBACK_OFFER_AMOUNT, DEFAULT_BUYBACK_OFFER_WANTED);
+
+        let result = ExecuteBuybackFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::BuybackProgramDoesNotExist);
+    })
+}
+
+#[test]
+fn execute_buyback_fails_with_program_not_started() {
+    buyback_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap();
+        StartBuybackProgramFixture::default()
+            .with_start(System::block_number() + 10)
+            .execute_call()
+            .unwrap();
+        create_buyback_swap_offer(DEFAULT_BUYBACK_OFFER_AMOUNT, DEFAULT_BUYBACK_OFFER_WANTED);
+
+        let result = ExecuteBuybackFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::BuybackProgramNotOngoing);
+    })
+}
+
+#[test]
+fn execute_buyback_fails_with_program_ended() {
+    buyback_test_externalities().execute_with(|| {
+        issue_token_and_start_buyback_program();
+        create_buyback_swap_offer(DEFAULT_BUYBACK_OFFER_AMOUNT, DEFAULT_BUYBACK_OFFER_WANTED);
+        increase_block_number_by(DEFAULT_BUYBACK_DURATION);
+
+        let result = ExecuteBuybackFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::BuybackProgramNotOngoing);
+    })
+}
+
+#[test]
+fn execute_buyback_fails_with_token_paused() {
+    buyback_test_externalities().execute_with(|| {
+        issue_token_and_start_buyback_program();
+        create_buyback_swap_offer(DEFAULT_BUYBACK_OFFER_AMOUNT, DEFAULT_BUYBACK_OFFER_WANTED);
+        increase_block_number_by(10);
+        SetTokenPausedStatusFixture::default()
+            .execute_call()
+            .unwrap();
+
+        let result = ExecuteBuybackFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenPaused);
+    })
+}
+
+#[test]
+fn execute_buyback_fails_with_active_revenue_split() {
+    build_default_test_externalities_with_balances(vec![(
+        member!(1).1,
+        DEFAULT_BUYBACK_BUDGET + DEFAULT_SPLIT_REVENUE + ExistentialDeposit::get(),
+    )])
+    .execute_with(|| {
+        issue_token_and_start_buyback_program();
+        create_buyback_swap_offer(DEFAULT_BUYBACK_OFFER_AMOUNT, DEFAULT_BUYBACK_OFFER_WANTED);
+        IssueRevenueSplitFixture::default().execute_call().unwrap();
+        increase_block_number_by(10);
+
+        let result = ExecuteBuybackFixture::default().execute_call();
+
+        assert_err!(
+            result,
+            Error::<Test>::CannotModifySupplyWhenRevenueSplitsAreActive
+        );
+    })
+}
+
+#[test]
+fn execute_buyback_fails_with_swap_offers_number_exceeded() {
+    buyback_test_externalities().execute_with(|| {
+        issue_token_and_start_buyback_program();
+        create_buyback_swap_offer(DEFAULT_BUYBACK_OFFER_AMOUNT, DEFAULT_BUYBACK_OFFER_WANTED);
+        increase_block_number_by(10);
+
+        let result = ExecuteBuybackFixture::default()
+            .with_swap_offers_number(0)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::SwapOffersNumberExceeded);
+    })
+}
+
+#[test]
+fn execute_buyback_fails_with_no_budget_released() {
+    buyback_test_externalities().execute_with(|| {
+        issue_token_and_start_buyback_program();
+        create_buyback_swap_offer(DEFAULT_BUYBACK_OFFER_AMOUNT, DEFAULT_BUYBACK_OFFER_WANTED);
+
+        let result = ExecuteBuybackFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::NoSwapOffersToBuyBack);
+    })
+}
+
+#[test]
+fn execute_buyback_fails_with_offer_priced_above_max_unit_price() {
+    buyback_test_externalities().execute_with(|| {
+        issue_token_and_start_buyback_program();
+        create_buyback_swap_offer(
+            DEFAULT_BUYBACK_OFFER_AMOUNT,
+            DEFAULT_BUYBACK_OFFER_WANTED + 1,
+        );
+        increase_block_number_by(20);
+
+        let result = ExecuteBuybackFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::NoSwapOffersToBuyBack);
+    })
+}
+
+#[test]
+fn execute_buyback_fails_with_offer_requesting_tokens() {
+    buyback_test_externalities().execute_with(|| {
+        issue_token_and_start_buyback_program();
+        IssueTokenFixture::default()
+            .with_symbol(b"CRT2")
+            .execute_call()
+            .unwrap();
+        CreateSwapOfferFixture::default()
+            .with_member(member!(2).0, member!(2).1)
+            .with_amount(DEFAULT_BUYBACK_OFFER_AMOUNT)
+            .with_wanted(SwapAsset::Token(2u64, DEFAULT_BUYBACK_OFFER_AMOUNT))
+            .execute_call()
+            .unwrap();
+        increase_block_number_by(10);
+
+        let result = ExecuteBuybackFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::NoSwapOffersToBuyBack);
+    })
+}
+
+#[test]
+fn execute_buyback_fails_with_maker_account_frozen() {
+    buyback_test_externalities().execute_with(|| {
+        issue_token_and_start_buyback_program();
+        create_buyback_swap_offer(DEFAULT_BUYBACK_OFFER_AMOUNT, DEFAULT_BUYBACK_OFFER_WANTED);
+        increase_block_number_by(10);
+        SetAccountFrozenStatusFixture::default()
+            .execute_call()
+            .unwrap();
+
+        let result = ExecuteBuybackFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::NoSwapOffersToBuyBack);
+    })
+}
+
+#[test]
+fn execute_buyback_ok_with_offer_filled_tokens_burned_and_maker_paid() {
+    buyback_test_externalities().execute_with(|| {
+        issue_token_and_start_buyback_program();
+        create_buyback_swap_offer(DEFAULT_BUYBACK_OFFER_AMOUNT, DEFAULT_BUYBACK_OFFER_WANTED);
+        // 10% of the budget released
+        increase_block_number_by(10);
+
+        ExecuteBuybackFixture::default().execute_call().unwrap();
+
+        assert!(Token::swap_offer_by_id(1u64, 0u64).is_none());
+        assert_eq!(
+            Token::account_info_by_token_and_member(1u64, member!(2).0).amount,
+            DEFAULT_SPLIT_PARTICIPATION - DEFAULT_BUYBACK_OFFER_AMOUNT
+        );
+        assert_eq!(
+            Token::token_info_by_id(1u64).total_supply,
+            DEFAULT_INITIAL_ISSUANCE - DEFAULT_BUYBACK_OFFER_AMOUNT
+        );
+        assert_eq!(
+            Joy::<Test>::usable_balance(member!(2).1),
+            DEFAULT_BUYBACK_OFFER_WANTED
+        );
+        assert_eq!(
+            Token::buyback_program_by_token_id(1u64).unwrap().spent,
+            DEFAULT_BUYBACK_OFFER_WANTED
+        );
+        last_event_eq!(RawEvent::TokensBoughtBackAndBurned(
+            1u64,
+            0u64,
+            member!(2).0,
+            DEFAULT_BUYBACK_OFFER_AMOUNT,
+            DEFAULT_BUYBACK_OFFER_WANTED
+        ));
+    })
+}
+
+#[test]
+fn execute_buyback_ok_with_offer_partially_filled_with_budget_released() {
+    buyback_test_externalities().execute_with(|| {
+        issue_token_and_start_buyback_program();
+        create_buyback_swap_offer(DEFAULT_BUYBACK_OFFER_AMOUNT, DEFAULT_BUYBACK_OFFER_WANTED);
+        // 5% of the budget released: enough for a half of the offer
+        increase_block_number_by(5);
+
+        ExecuteBuybackFixture::default().execute_call().unwrap();
+
+        assert_eq!(
+            Token::swap_offer_by_id(1u64, 0u64),
+            Some(SwapOffer {
+                maker: member!(2).0,
+                amount_left: DEFAULT_BUYBACK_OFFER_AMOUNT / 2,
+                wanted_left: SwapAsset::Joy(DEFAULT_BUYBACK_OFFER_WANTED / 2),
+            })
+        );
+        assert_eq!(
+            Token::token_info_by_id(1u64).total_supply,
+            DEFAULT_INITIAL_ISSUANCE - DEFAULT_BUYBACK_OFFER_AMOUNT / 2
+        );
+        assert_eq!(
+            Joy::<Test>::usable_balance(member!(2).1),
+            DEFAULT_BUYBACK_OFFER_WANTED / 2
+        );
+        assert_eq!(
+            Token::buyback_program_by_token_id(1u64).unwrap().spent,
+            DEFAULT_BUYBACK_OFFER_WANTED / 2
+        );
+    })
+}
+
+#[test]
+fn execute_buyback_ok_with_cheapest_offers_filled_first() {
+    buyback_test_externalities().execute_with(|| {
+        issue_token_and_start_buyback_program();
+        // offer 0 at the max unit price, offer 1 at a half of it
+        create_buyback_swap_offer(DEFAULT_BUYBACK_OFFER_AMOUNT, DEFAULT_BUYBACK_OFFER_WANTED);
+        create_buyback_swap_offer(
+            DEFAULT_BUYBACK_OFFER_AMOUNT,
+            DEFAULT_BUYBACK_OFFER_WANTED / 2,
+        );
+        // enough to fill offer 1 and a half of offer 0
+        increase_block_number_by(10);
+
+        ExecuteBuybackFixture::default().execute_call().unwrap();
+
+        assert!(Token::swap_offer_by_id(1u64, 1u64).is_none());
+        assert_eq!(
+            Token::swap_offer_by_id(1u64, 0u64),
+            Some(SwapOffer {
+                maker: member!(2).0,
+                amount_left: DEFAULT_BUYBACK_OFFER_AMOUNT / 2,
+                wanted_left: SwapAsset::Joy(DEFAULT_BUYBACK_OFFER_WANTED / 2),
+            })
+        );
+        assert_eq!(
+            Token::token_info_by_id(1u64).total_supply,
+            DEFAULT_INITIAL_ISSUANCE - DEFAULT_BUYBACK_OFFER_AMOUNT * 3 / 2
+        );
+        assert_eq!(
+            Joy::<Test>::usable_balance(member!(2).1),
+            DEFAULT_BUYBACK_OFFER_WANTED
+        );
+        assert_eq!(
+            Token::buyback_program_by_token_id(1u64).unwrap().spent,
+            DEFAULT_BUYBACK_OFFER_WANTED
+        );
+        last_event_eq!(RawEvent::TokensBoughtBackAndBurned(
+            1u64,
+            0u64,
+            member!(2).0,
+            DEFAULT_BUYBACK_OFFER_AMOUNT / 2,
+            DEFAULT_BUYBACK_OFFER_WANTED / 2
+        ));
+    })
+}
+
+#[test]
+fn execute_buyback_ok_with_patronage_tallied_before_supply_decrease() {
+    let (owner_id, owner_acc) = member!(1);
+    let params = TokenIssuanceParametersOf::<Test> {
+        patronage_rate: yearly_rate!(20),
+        ..Default::default()
+    }
+    .with_allocation(&owner_id, balance!(1_000_000_000), None);
+
+    build_default_test_externalities_with_balances(vec![(
+        owner_acc,
+        DEFAULT_BUYBACK_BUDGET + ExistentialDeposit::get(),
+    )])
+    .execute_with(|| {
+        Token::issue_token(owner_acc, params, default_upload_context()).unwrap();
+        StartBuybackProgramFixture::default()
+            .execute_call()
+            .unwrap();
+        CreateSwapOfferFixture::default()
+            .with_amount(DEFAULT_BUYBACK_OFFER_AMOUNT)
+            .with_wanted(SwapAsset::Joy(DEFAULT_BUYBACK_OFFER_WANTED))
+            .execute_call()
+            .unwrap();
+        increase_block_number_by(10);
+        let now = System::block_number();
+        let expected_patronage = Token::token_info_by_id(1u64).unclaimed_patronage_at_block(now);
+        assert!(expected_patronage > 0);
+
+        ExecuteBuybackFixture::default().execute_call().unwrap();
+
+        let patronage_info = Token::token_info_by_id(1u64).patronage_info;
+        assert_eq!(
+            patronage_info.unclaimed_patronage_tally_amount,
+            expected_patronage
+        );
+        assert_eq!(patronage_info.last_unclaimed_patronage_tally_block, now);
+    })
+}
+
+#[test]
+fn finalize_buyback_program_fails_with_no_buyback_program() {
+    buyback_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = FinalizeBuybackProgramFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::BuybackProgramDoesNotExist);
+    })
+}
+
+#[test]
+fn finalize_buyback_program_fails_with_program_not_ended() {
+    buyback_test_externalities().execute_with(|| {
+        issue_token_and_start_buyback_program();
+        increase_block_number_by(DEFAULT_BUYBACK_DURATION - 1);
+
+        let result = FinalizeBuybackProgramFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::BuybackProgramNotEnded);
+    })
+}
+
+#[test]
+fn finalize_buyback_program_ok_with_leftovers_transferred_and_event_deposited() {
+    buyback_test_externalities().execute_with(|| {
+        let treasury_account = Token::module_treasury_account();
+        issue_token_and_start_buyback_program();
+        create_buyback_swap_offer(DEFAULT_BUYBACK_OFFER_AMOUNT, DEFAULT_BUYBACK_OFFER_WANTED);
+        increase_block_number_by(10);
+        ExecuteBuybackFixture::default().execute_call().unwrap();
+        increase_block_number_by(DEFAULT_BUYBACK_DURATION);
+
+        FinalizeBuybackProgramFixture::default()
+            .execute_call()
+            .unwrap();
+
+        assert!(Token::buyback_program_by_token_id(1u64).is_none());
+        assert_eq!(
+            Joy::<Test>::usable_balance(treasury_account),
+            ExistentialDeposit::get()
+        );
+        assert_eq!(
+            Joy::<Test>::usable_balance(member!(1).1),
+            DEFAULT_BUYBACK_BUDGET - DEFAULT_BUYBACK_OFFER_WANTED + ExistentialDeposit::get()
+        );
+        last_event_eq!(RawEvent::BuybackProgramFinalized(
+            1u64,
+            member!(1).1,
+            DEFAULT_BUYBACK_BUDGET - DEFAULT_BUYBACK_OFFER_WANTED
+        ));
+    })
+}
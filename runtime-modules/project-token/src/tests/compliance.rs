@@ -0,0 +1,330 @@
+#![cfg(test)]
+use frame_support::assert_err;
+
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::{last_event_eq, member, origin, Error, RawEvent};
+
+#[test]
+fn set_account_frozen_status_fails_with_invalid_token_id() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        let result = SetAccountFrozenStatusFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenDoesNotExist);
+    })
+}
+
+#[test]
+fn set_account_frozen_status_fails_with_non_existing_account() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = SetAccountFrozenStatusFixture::default()
+            .with_member_id(member!(3).0)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::AccountInformationDoesNotExist);
+    })
+}
+
+#[test]
+fn set_account_frozen_status_ok_with_event_deposited() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap();
+
+        SetAccountFrozenStatusFixture::default()
+            .execute_call()
+            .unwrap();
+
+        assert!(Token::account_info_by_token_and_member(1u64, member!(2).0).frozen);
+        last_event_eq!(RawEvent::AccountFrozenStatusUpdated(
+            1u64,
+            member!(2).0,
+            true
+        ));
+    })
+}
+
+#[test]
+fn set_token_paused_status_fails_with_invalid_token_id() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        let result = SetTokenPausedStatusFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenDoesNotExist);
+    })
+}
+
+#[test]
+fn set_token_paused_status_ok_with_event_deposited() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        SetTokenPausedStatusFixture::default()
+            .execute_call()
+            .unwrap();
+
+        assert!(Token::token_info_by_id(1u64).paused);
+        last_event_eq!(RawEvent::TokenPausedStatusUpdated(1u64, true));
+    })
+}
+
+#[test]
+fn transfer_fails_with_frozen_source_account() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        SetAccountFrozenStatusFixture::default()
+            .with_member_id(member!(1).0)
+            .execute_call()
+            .unwrap();
+
+        let result = TransferFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::AccountFrozen);
+    })
+}
+
+#[test]
+fn transfer_ok_to_frozen_destination_account() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap();
+        SetAccountFrozenStatusFixture::default()
+            .execute_call()
+            .unwrap();
+
+        TransferFixture::default().execute_call().unwrap();
+
+        assert_eq!(
+            Token::account_info_by_token_and_member(1u64, member!(2).0).amount,
+            2 * DEFAULT_SPLIT_PARTICIPATION
+        );
+    })
+}
+
+#[test]
+fn transfer_ok_after_account_unfrozen() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        SetAccountFrozenStatusFixture::default()
+            .with_member_id(member!(1).0)
+            .execute_call()
+            .unwrap();
+        SetAccountFrozenStatusFixture::default()
+            .with_member_id(member!(1).0)
+            .with_frozen(false)
+            .execute_call()
+            .unwrap();
+
+        TransferFixture::default().execute_call().unwrap();
+    })
+}
+
+#[test]
+fn transfer_fails_with_token_paused() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        SetTokenPausedStatusFixture::default()
+            .execute_call()
+            .unwrap();
+
+        let result = TransferFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenPaused);
+    })
+}
+
+#[test]
+fn transfer_ok_after_token_unpaused() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        SetTokenPausedStatusFixture::default()
+            .execute_call()
+            .unwrap();
+        SetTokenPausedStatusFixture::default()
+            .with_paused(false)
+            .execute_call()
+            .unwrap();
+
+        TransferFixture::default().execute_call().unwrap();
+    })
+}
+
+#[test]
+fn burn_fails_with_frozen_account() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap();
+        SetAccountFrozenStatusFixture::default()
+            .execute_call()
+            .unwrap();
+
+        let result = Token::burn(
+            origin!(member!(2).1),
+            1u64,
+            member!(2).0,
+            DEFAULT_SPLIT_PARTICIPATION,
+        );
+
+        assert_err!(result, Error::<Test>::AccountFrozen);
+    })
+}
+
+#[test]
+fn burn_fails_with_token_paused() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap();
+        SetTokenPausedStatusFixture::default()
+            .execute_call()
+            .unwrap();
+
+        let result = Token::burn(
+            origin!(member!(2).1),
+            1u64,
+            member!(2).0,
+            DEFAULT_SPLIT_PARTICIPATION,
+        );
+
+        assert_err!(result, Error::<Test>::TokenPaused);
+    })
+}
+
+#[test]
+fn participate_in_split_fails_with_frozen_account() {
+    build_default_test_externalities_with_balances(vec![(
+        member!(1).1,
+        DEFAULT_SPLIT_REVENUE + ExistentialDeposit::get(),
+    )])
+    .execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap();
+        IssueRevenueSplitFixture::default().execute_call().unwrap();
+        increase_block_number_by(MIN_REVENUE_SPLIT_TIME_TO_START);
+        SetAccountFrozenStatusFixture::default()
+            .execute_call()
+            .unwrap();
+
+        let result = ParticipateInSplitFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::AccountFrozen);
+    })
+}
+
+#[test]
+fn participate_in_split_fails_with_token_paused() {
+    build_default_test_externalities_with_balances(vec![(
+        member!(1).1,
+        DEFAULT_SPLIT_REVENUE + ExistentialDeposit::get(),
+    )])
+    .execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap();
+        IssueRevenueSplitFixture::default().execute_call().unwrap();
+        increase_block_number_by(MIN_REVENUE_SPLIT_TIME_TO_START);
+        SetTokenPausedStatusFixture::default()
+            .execute_call()
+            .unwrap();
+
+        let result = ParticipateInSplitFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenPaused);
+    })
+}
+
+#[test]
+fn purchase_tokens_on_sale_fails_with_frozen_buyer_account() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap();
+        SetAccountFrozenStatusFixture::default()
+            .execute_call()
+            .unwrap();
+        InitTokenSaleFixture::default().call_and_assert(Ok(()));
+        increase_account_balance(
+            &member!(2).1,
+            ExistentialDeposit::get() + DEFAULT_SALE_UNIT_PRICE * DEFAULT_SALE_PURCHASE_AMOUNT,
+        );
+
+        PurchaseTokensOnSaleFixture::default()
+            .call_and_assert(Err(Error::<Test>::AccountFrozen.into()));
+    })
+}
+
+#[test]
+fn purchase_tokens_on_sale_fails_with_token_paused() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        InitTokenSaleFixture::default().call_and_assert(Ok(()));
+        SetTokenPausedStatusFixture::default()
+            .execute_call()
+            .unwrap();
+        increase_account_balance(
+            &member!(2).1,
+            ExistentialDeposit::get() + DEFAULT_SALE_UNIT_PRICE * DEFAULT_SALE_PURCHASE_AMOUNT,
+        );
+
+        PurchaseTokensOnSaleFixture::default()
+            .call_and_assert(Err(Error::<Test>::TokenPaused.into()));
+    })
+}
+
+#[test]
+fn issue_airdrop_fails_with_frozen_source_account() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        SetAccountFrozenStatusFixture::default()
+            .with_member_id(member!(1).0)
+            .execute_call()
+            .unwrap();
+
+        let result = IssueAirdropFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::AccountFrozen);
+    })
+}
+
+#[test]
+fn issue_airdrop_fails_with_token_paused() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        SetTokenPausedStatusFixture::default()
+            .execute_call()
+            .unwrap();
+
+        let result = IssueAirdropFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenPaused);
+    })
+}
+
+#[test]
+fn claim_airdrop_fails_with_frozen_account() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap();
+        IssueAirdropFixture::default().execute_call().unwrap();
+        SetAccountFrozenStatusFixture::default()
+            .execute_call()
+            .unwrap();
+
+        let result = ClaimAirdropFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::AccountFrozen);
+    })
+}
+
+#[test]
+fn claim_airdrop_fails_with_token_paused() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        IssueAirdropFixture::default().execute_call().unwrap();
+        SetTokenPausedStatusFixture::default()
+            .execute_call()
+            .unwrap();
+
+        let result = ClaimAirdropFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenPaused);
+    })
+}
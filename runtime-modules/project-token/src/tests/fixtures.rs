@@ -1,7 +1,11 @@
 #![cfg(test)]
 
 use crate::tests::mock::*;
-use crate::types::{Joy, Payment, Transfers, TransfersOf};
+use crate::types::{
+    AirdropId, AirdropParams, AirdropParamsOf, AllowanceSpender, AllowanceSpenderOf, Joy,
+    MerkleProof, MerkleProofOf, Payment, SwapAsset, SwapAssetOf, SwapOfferId, TokenPollId,
+    TokenPollParams, Transfers, TransfersOf,
+};
 use crate::{
     last_event_eq, member, yearly_rate, AccountInfoByTokenAndMember, RawEvent, YearlyRate,
 };
@@ -52,6 +56,7 @@ pub fn default_token_sale_params() -> TokenSaleParams {
             cliff_amount_percentage: Permill::from_percent(0),
         }),
         cap_per_member: None,
+        tiers: vec![],
     }
 }
 
@@ -111,6 +116,16 @@ impl IssueTokenFixture {
             ..self
         }
     }
+
+    pub fn with_symbol(self, symbol: &[u8]) -> Self {
+        Self {
+            params: IssuanceParams {
+                symbol: Hashing::hash_of(&symbol),
+                ..self.params
+            },
+            ..self
+        }
+    }
 }
 
 impl Fixture<IssueTokenFixtureStateSnapshot> for IssueTokenFixture {
@@ -257,6 +272,16 @@ impl InitTokenSaleFixture {
             ..self
         }
     }
+
+    pub fn with_tiers(self, tiers: Vec<TokenSaleTierParams>) -> Self {
+        Self {
+            params: TokenSaleParams {
+                tiers,
+                ..self.params
+            },
+            ..self
+        }
+    }
 }
 
 impl Fixture<InitTokenSaleFixtureStateSnapshot> for InitTokenSaleFixture {
@@ -330,7 +355,7 @@ impl Fixture<InitTokenSaleFixtureStateSnapshot> for InitTokenSaleFixture {
             snapshot_pre
                 .source_account_data
                 .amount
-                .saturating_sub(self.params.upper_bound_quantity)
+                .saturating_sub(self.params.total_quantity())
         );
 
         // Event emitted
@@ -528,8 +553,12 @@ impl Fixture<PurchaseTokensOnSaleFixtureStateSnapshot> for PurchaseTokensOnSaleF
             self.member_id
         ));
         let platform_fee = Token::sale_platform_fee();
+        let sale_id = snapshot_pre.token_data.next_sale_id - 1;
         let sale_pre = snapshot_pre.token_data.sale.clone().unwrap();
-        let joy_amount = self.amount * sale_pre.unit_price;
+        let purchases = sale_pre
+            .split_purchase::<Test>(sale_id, self.amount)
+            .unwrap();
+        let joy_amount: JoyBalance = purchases.iter().map(|p| p.cost).sum();
         let fee_amount = platform_fee.mul_floor(joy_amount);
 
         let expected_quantity_left = sale_pre.quantity_left.saturating_sub(self.amount);
@@ -538,13 +567,20 @@ impl Fixture<PurchaseTokensOnSaleFixtureStateSnapshot> for PurchaseTokensOnSaleF
             assert!(snapshot_post.token_data.sale.is_none());
         } else {
             // `quantity_left` decreased and `funds_collected` increased
+            let sale_post = snapshot_post.token_data.sale.clone().unwrap();
+            assert_eq!(sale_post.quantity_left, expected_quantity_left);
             assert_eq!(
-                snapshot_post.token_data.sale.clone().unwrap(),
-                TokenSale {
-                    quantity_left: expected_quantity_left,
-                    funds_collected: sale_pre.funds_collected.saturating_add(joy_amount),
-                    ..sale_pre.clone()
-                }
+                sale_post.funds_collected,
+                sale_pre.funds_collected.saturating_add(joy_amount)
+            );
+            // tiers' `quantity_left` decreased
+            assert_eq!(
+                sale_post
+                    .tiers
+                    .iter()
+                    .map(|t| t.quantity_left)
+                    .sum::<Balance>(),
+                expected_quantity_left
             );
         }
 
@@ -570,12 +606,21 @@ impl Fixture<PurchaseTokensOnSaleFixtureStateSnapshot> for PurchaseTokensOnSaleF
             );
         }
 
-        if let Some(vesting_schedule) = snapshot_pre
-            .token_data
-            .sale
-            .as_ref()
-            .unwrap()
-            .get_vesting_schedule(self.amount)
+        // amount of tokens vested according to sale's vesting schedule params
+        let sale_vested_amount: Balance = purchases
+            .iter()
+            .filter(|p| matches!(p.vesting, Some((VestingSource::Sale(_), _))))
+            .map(|p| p.amount)
+            .sum();
+        // amount of tokens not subject to vesting
+        let non_vested_amount: Balance = purchases
+            .iter()
+            .filter(|p| p.vesting.is_none())
+            .map(|p| p.amount)
+            .sum();
+        if let Some(vesting_schedule) = sale_pre
+            .get_vesting_schedule(sale_vested_amount)
+            .filter(|_| sale_vested_amount > 0)
         {
             // buyer's vesting schedule is correct
             assert_eq!(
@@ -600,29 +645,18 @@ impl Fixture<PurchaseTokensOnSaleFixtureStateSnapshot> for PurchaseTokensOnSaleF
                     burned_amount: 0
                 }
             );
-            // buyer's transferrable balance is unchanged
-            assert_eq!(
-                snapshot_post
-                    .buyer_account_data
-                    .transferrable::<Test>(System::block_number()),
-                snapshot_pre
-                    .buyer_account_data
-                    .transferrable::<Test>(System::block_number())
-            );
-        } else {
-            // buyer's transferrable balance is increased
-            assert_eq!(
-                snapshot_post
-                    .buyer_account_data
-                    .transferrable::<Test>(System::block_number()),
-                snapshot_pre
-                    .buyer_account_data
-                    .transferrable::<Test>(System::block_number())
-                    .saturating_add(self.amount)
-            );
         }
+        // buyer's transferrable balance is increased by the amount not subject to vesting
+        assert_eq!(
+            snapshot_post
+                .buyer_account_data
+                .transferrable::<Test>(System::block_number()),
+            snapshot_pre
+                .buyer_account_data
+                .transferrable::<Test>(System::block_number())
+                .saturating_add(non_vested_amount)
+        );
         // last_sale_purchased_amount is increased
-        let sale_id = snapshot_pre.token_data.next_sale_id - 1;
         assert_eq!(
             snapshot_post
                 .buyer_account_data
@@ -753,6 +787,7 @@ pub struct IssueRevenueSplitFixture {
     duration: BlockNumber,
     revenue_source_account: AccountId,
     revenue_amount: JoyBalance,
+    split_rate: Option<Permill>,
 }
 
 impl IssueRevenueSplitFixture {
@@ -763,6 +798,14 @@ impl IssueRevenueSplitFixture {
             duration: BlockNumber::from(DEFAULT_SPLIT_DURATION),
             revenue_source_account: member!(1).1,
             revenue_amount: Balance::from(DEFAULT_SPLIT_REVENUE),
+            split_rate: None,
+        }
+    }
+
+    pub fn with_split_rate(self, split_rate: Permill) -> Self {
+        Self {
+            split_rate: Some(split_rate),
+            ..self
         }
     }
 
@@ -796,13 +839,23 @@ impl IssueRevenueSplitFixture {
 
     pub fn execute_call(&self) -> Result<JoyBalance, DispatchError> {
         let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
-        let result = Token::issue_revenue_split(
-            self.token_id,
-            self.start,
-            self.duration,
-            self.revenue_source_account,
-            self.revenue_amount,
-        );
+        let result = match self.split_rate {
+            Some(split_rate) => Token::issue_revenue_split_with_rate(
+                self.token_id,
+                self.start,
+                self.duration,
+                self.revenue_source_account,
+                self.revenue_amount,
+                split_rate,
+            ),
+            None => Token::issue_revenue_split(
+                self.token_id,
+                self.start,
+                self.duration,
+                self.revenue_source_account,
+                self.revenue_amount,
+            ),
+        };
         let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
 
         // no-op in case of error
@@ -939,6 +992,21 @@ impl TransferFixture {
         }
     }
 
+    pub fn with_token_id(self, token_id: TokenId) -> Self {
+        Self { token_id, ..self }
+    }
+
+    pub fn with_amount(self, amount: Balance) -> Self {
+        let outputs = Transfers::<_, _>(
+            self.outputs
+                .0
+                .into_iter()
+                .map(|(dst, _)| (dst, Payment::<Balance> { amount }))
+                .collect(),
+        );
+        Self { outputs, ..self }
+    }
+
     pub fn execute_call(&self) -> DispatchResult {
         let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
         let result = Token::transfer(
@@ -1006,3 +1074,893 @@ impl ExitRevenueSplitFixture {
         result
     }
 }
+
+pub struct IssueTokenPollFixture {
+    token_id: TokenId,
+    alternatives: u32,
+    start: Option<BlockNumber>,
+    duration: BlockNumber,
+}
+
+impl IssueTokenPollFixture {
+    pub fn default() -> Self {
+        Self {
+            token_id: TokenId::one(),
+            alternatives: DEFAULT_POLL_ALTERNATIVES,
+            start: None,
+            duration: DEFAULT_POLL_DURATION,
+        }
+    }
+
+    pub fn with_alternatives(self, alternatives: u32) -> Self {
+        Self {
+            alternatives,
+            ..self
+        }
+    }
+
+    pub fn with_starting_block(self, start: u64) -> Self {
+        Self {
+            start: Some(start),
+            ..self
+        }
+    }
+
+    pub fn with_duration(self, duration: u64) -> Self {
+        Self { duration, ..self }
+    }
+
+    pub fn execute_call(&self) -> Result<TokenPollId, DispatchError> {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::issue_poll(
+            self.token_id,
+            TokenPollParams {
+                metadata: b"poll".to_vec(),
+                alternatives: self.alternatives,
+                start: self.start,
+                duration: self.duration,
+            },
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct VoteInTokenPollFixture {
+    sender: AccountId,
+    token_id: TokenId,
+    member_id: MemberId,
+    alternative: u32,
+    amount: Balance,
+}
+
+impl VoteInTokenPollFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: member!(2).1,
+            token_id: TokenId::one(),
+            member_id: member!(2).0,
+            alternative: 0,
+            amount: DEFAULT_POLL_VOTE,
+        }
+    }
+
+    pub fn with_member(self, member_id: MemberId, sender: AccountId) -> Self {
+        Self {
+            member_id,
+            sender,
+            ..self
+        }
+    }
+
+    pub fn with_alternative(self, alternative: u32) -> Self {
+        Self {
+            alternative,
+            ..self
+        }
+    }
+
+    pub fn with_amount(self, amount: u128) -> Self {
+        Self { amount, ..self }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::vote_in_token_poll(
+            Origin::signed(self.sender),
+            self.token_id,
+            self.member_id,
+            self.alternative,
+            self.amount,
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct UnlockTokenPollVoteFixture {
+    sender: AccountId,
+    token_id: TokenId,
+    member_id: MemberId,
+}
+
+impl UnlockTokenPollVoteFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: member!(2).1,
+            token_id: TokenId::one(),
+            member_id: member!(2).0,
+        }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::unlock_token_poll_vote(
+            Origin::signed(self.sender),
+            self.token_id,
+            self.member_id,
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct FinalizeTokenPollFixture {
+    sender: AccountId,
+    token_id: TokenId,
+}
+
+impl FinalizeTokenPollFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: member!(1).1,
+            token_id: TokenId::one(),
+        }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::finalize_token_poll(Origin::signed(self.sender), self.token_id);
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub type AirdropEntry = (MemberId, Balance, Option<VestingScheduleParams>);
+
+pub fn default_airdrop_entries() -> Vec<AirdropEntry> {
+    vec![
+        (member!(2).0, DEFAULT_AIRDROP_CLAIM, None),
+        (
+            member!(3).0,
+            DEFAULT_AIRDROP_CLAIM,
+            Some(VestingScheduleParams {
+                blocks_before_cliff: 100,
+                cliff_amount_percentage: Permill::from_percent(50),
+                linear_vesting_duration: 100,
+            }),
+        ),
+    ]
+}
+
+pub struct IssueAirdropFixture {
+    token_id: TokenId,
+    member_id: MemberId,
+    entries: Vec<AirdropEntry>,
+    total_amount: Balance,
+    expiry: BlockNumber,
+}
+
+impl IssueAirdropFixture {
+    pub fn default() -> Self {
+        Self {
+            token_id: TokenId::one(),
+            member_id: member!(1).0,
+            entries: default_airdrop_entries(),
+            total_amount: DEFAULT_AIRDROP_AMOUNT,
+            expiry: Token::current_block() + DEFAULT_AIRDROP_DURATION,
+        }
+    }
+
+    pub fn with_entries(self, entries: Vec<AirdropEntry>) -> Self {
+        Self { entries, ..self }
+    }
+
+    pub fn with_total_amount(self, total_amount: Balance) -> Self {
+        Self {
+            total_amount,
+            ..self
+        }
+    }
+
+    pub fn with_expiry(self, expiry: BlockNumber) -> Self {
+        Self { expiry, ..self }
+    }
+
+    pub fn params(&self) -> AirdropParamsOf<Test> {
+        AirdropParams {
+            commitment: generate_merkle_root_helper::<Test, _>(&self.entries)
+                .pop()
+                .unwrap(),
+            total_amount: self.total_amount,
+            expiry: self.expiry,
+        }
+    }
+
+    pub fn execute_call(&self) -> Result<AirdropId, DispatchError> {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::issue_airdrop(self.token_id, self.member_id, self.params());
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct ClaimAirdropFixture {
+    sender: AccountId,
+    token_id: TokenId,
+    airdrop_id: AirdropId,
+    entries: Vec<AirdropEntry>,
+    entry_index: usize,
+    proof: Option<MerkleProofOf<Test>>,
+}
+
+impl ClaimAirdropFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: member!(2).1,
+            token_id: TokenId::one(),
+            airdrop_id: 0,
+            entries: default_airdrop_entries(),
+            entry_index: 0,
+            proof: None,
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_airdrop_id(self, airdrop_id: AirdropId) -> Self {
+        Self { airdrop_id, ..self }
+    }
+
+    pub fn with_entries(self, entries: Vec<AirdropEntry>) -> Self {
+        Self { entries, ..self }
+    }
+
+    pub fn with_entry_index(self, entry_index: usize) -> Self {
+        Self {
+            entry_index,
+            ..self
+        }
+    }
+
+    pub fn with_proof(self, proof: MerkleProofOf<Test>) -> Self {
+        Self {
+            proof: Some(proof),
+            ..self
+        }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let (member_id, amount, vesting_schedule) = self.entries[self.entry_index].clone();
+        let proof = self.proof.clone().unwrap_or_else(|| {
+            MerkleProof::<Hashing>(build_merkle_path_helper::<Test, _>(
+                &self.entries,
+                self.entry_index,
+            ))
+        });
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::claim_airdrop(
+            Origin::signed(self.sender),
+            self.token_id,
+            self.airdrop_id,
+            member_id,
+            amount,
+            vesting_schedule,
+            proof,
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct RecoverUnclaimedAirdropFixture {
+    token_id: TokenId,
+    airdrop_id: AirdropId,
+    bloat_bond_payer: AccountId,
+}
+
+impl RecoverUnclaimedAirdropFixture {
+    pub fn default() -> Self {
+        Self {
+            token_id: TokenId::one(),
+            airdrop_id: 0,
+            bloat_bond_payer: member!(1).1,
+        }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result =
+            Token::recover_unclaimed_airdrop(self.token_id, self.airdrop_id, self.bloat_bond_payer);
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct ApproveFixture {
+    sender: AccountId,
+    token_id: TokenId,
+    member_id: MemberId,
+    spender: AllowanceSpenderOf<Test>,
+    amount: Balance,
+    expiry: Option<BlockNumber>,
+}
+
+impl ApproveFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: member!(1).1,
+            token_id: TokenId::one(),
+            member_id: member!(1).0,
+            spender: AllowanceSpender::Member(member!(2).0),
+            amount: DEFAULT_ALLOWANCE_AMOUNT,
+            expiry: None,
+        }
+    }
+
+    pub fn with_member(self, member_id: MemberId, sender: AccountId) -> Self {
+        Self {
+            member_id,
+            sender,
+            ..self
+        }
+    }
+
+    pub fn with_spender(self, spender: AllowanceSpenderOf<Test>) -> Self {
+        Self { spender, ..self }
+    }
+
+    pub fn with_amount(self, amount: Balance) -> Self {
+        Self { amount, ..self }
+    }
+
+    pub fn with_expiry(self, expiry: BlockNumber) -> Self {
+        Self {
+            expiry: Some(expiry),
+            ..self
+        }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::approve(
+            Origin::signed(self.sender),
+            self.token_id,
+            self.member_id,
+            self.spender.clone(),
+            self.amount,
+            self.expiry,
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct TransferFromFixture {
+    sender: AccountId,
+    spender_member_id: Option<MemberId>,
+    token_id: TokenId,
+    src_member_id: MemberId,
+    outputs: TransfersOf<Test>,
+    metadata: Vec<u8>,
+}
+
+impl TransferFromFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: member!(2).1,
+            spender_member_id: Some(member!(2).0),
+            token_id: TokenId::one(),
+            src_member_id: member!(1).0,
+            outputs: Transfers::<_, _>(
+                vec![(
+                    member!(3).0,
+                    Payment::<Balance> {
+                        amount: DEFAULT_ALLOWANCE_TRANSFER,
+                    },
+                )]
+                .into_iter()
+                .collect(),
+            ),
+            metadata: b"metadata".to_vec(),
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_spender_member_id(self, spender_member_id: Option<MemberId>) -> Self {
+        Self {
+            spender_member_id,
+            ..self
+        }
+    }
+
+    pub fn with_output(self, dst_member_id: MemberId, amount: Balance) -> Self {
+        Self {
+            outputs: Transfers::<_, _>(
+                vec![(dst_member_id, Payment::<Balance> { amount })]
+                    .into_iter()
+                    .collect(),
+            ),
+            ..self
+        }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::transfer_from(
+            Origin::signed(self.sender),
+            self.spender_member_id,
+            self.token_id,
+            self.src_member_id,
+            self.outputs.clone(),
+            self.metadata.clone(),
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct SetAccountFrozenStatusFixture {
+    token_id: TokenId,
+    member_id: MemberId,
+    frozen: bool,
+}
+
+impl SetAccountFrozenStatusFixture {
+    pub fn default() -> Self {
+        Self {
+            token_id: TokenId::one(),
+            member_id: member!(2).0,
+            frozen: true,
+        }
+    }
+
+    pub fn with_token_id(self, token_id: TokenId) -> Self {
+        Self { token_id, ..self }
+    }
+
+    pub fn with_member_id(self, member_id: MemberId) -> Self {
+        Self { member_id, ..self }
+    }
+
+    pub fn with_frozen(self, frozen: bool) -> Self {
+        Self { frozen, ..self }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::set_account_frozen_status(self.token_id, self.member_id, self.frozen);
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct SetTokenPausedStatusFixture {
+    token_id: TokenId,
+    paused: bool,
+}
+
+impl SetTokenPausedStatusFixture {
+    pub fn default() -> Self {
+        Self {
+            token_id: TokenId::one(),
+            paused: true,
+        }
+    }
+
+    pub fn with_token_id(self, token_id: TokenId) -> Self {
+        Self { token_id, ..self }
+    }
+
+    pub fn with_paused(self, paused: bool) -> Self {
+        Self { paused, ..self }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::set_token_paused_status(self.token_id, self.paused);
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct CreateSwapOfferFixture {
+    sender: AccountId,
+    token_id: TokenId,
+    member_id: MemberId,
+    amount: Balance,
+    wanted: SwapAssetOf<Test>,
+}
+
+impl CreateSwapOfferFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: member!(1).1,
+            token_id: TokenId::one(),
+            member_id: member!(1).0,
+            amount: DEFAULT_SWAP_OFFER_AMOUNT,
+            wanted: SwapAsset::Token(2u64, DEFAULT_SWAP_OFFER_WANTED),
+        }
+    }
+
+    pub fn with_member(self, member_id: MemberId, sender: AccountId) -> Self {
+        Self {
+            member_id,
+            sender,
+            ..self
+        }
+    }
+
+    pub fn with_amount(self, amount: Balance) -> Self {
+        Self { amount, ..self }
+    }
+
+    pub fn with_wanted(self, wanted: SwapAssetOf<Test>) -> Self {
+        Self { wanted, ..self }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::create_swap_offer(
+            Origin::signed(self.sender),
+            self.token_id,
+            self.member_id,
+            self.amount,
+            self.wanted,
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct FillSwapOfferFixture {
+    sender: AccountId,
+    token_id: TokenId,
+    offer_id: SwapOfferId,
+    member_id: MemberId,
+    amount: Balance,
+}
+
+impl FillSwapOfferFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: member!(2).1,
+            token_id: TokenId::one(),
+            offer_id: 0,
+            member_id: member!(2).0,
+            amount: DEFAULT_SWAP_OFFER_FILL,
+        }
+    }
+
+    pub fn with_member(self, member_id: MemberId, sender: AccountId) -> Self {
+        Self {
+            member_id,
+            sender,
+            ..self
+        }
+    }
+
+    pub fn with_offer_id(self, offer_id: SwapOfferId) -> Self {
+        Self { offer_id, ..self }
+    }
+
+    pub fn with_amount(self, amount: Balance) -> Self {
+        Self { amount, ..self }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::fill_swap_offer(
+            Origin::signed(self.sender),
+            self.token_id,
+            self.offer_id,
+            self.member_id,
+            self.amount,
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct CancelSwapOfferFixture {
+    sender: AccountId,
+    token_id: TokenId,
+    offer_id: SwapOfferId,
+}
+
+impl CancelSwapOfferFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: member!(1).1,
+            token_id: TokenId::one(),
+            offer_id: 0,
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result =
+            Token::cancel_swap_offer(Origin::signed(self.sender), self.token_id, self.offer_id);
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct StartBuybackProgramFixture {
+    token_id: TokenId,
+    budget_source_account: AccountId,
+    params: BuybackParams,
+}
+
+impl StartBuybackProgramFixture {
+    pub fn default() -> Self {
+        Self {
+            token_id: TokenId::one(),
+            budget_source_account: member!(1).1,
+            params: BuybackParams {
+                budget: DEFAULT_BUYBACK_BUDGET,
+                max_unit_price: DEFAULT_BUYBACK_MAX_UNIT_PRICE,
+                start: None,
+                duration: DEFAULT_BUYBACK_DURATION,
+            },
+        }
+    }
+
+    pub fn with_budget(self, budget: JoyBalance) -> Self {
+        Self {
+            params: BuybackParams {
+                budget,
+                ..self.params
+            },
+            ..self
+        }
+    }
+
+    pub fn with_max_unit_price(self, max_unit_price: JoyBalance) -> Self {
+        Self {
+            params: BuybackParams {
+                max_unit_price,
+                ..self.params
+            },
+            ..self
+        }
+    }
+
+    pub fn with_start(self, start: BlockNumber) -> Self {
+        Self {
+            params: BuybackParams {
+                start: Some(start),
+                ..self.params
+            },
+            ..self
+        }
+    }
+
+    pub fn with_duration(self, duration: BlockNumber) -> Self {
+        Self {
+            params: BuybackParams {
+                duration,
+                ..self.params
+            },
+            ..self
+        }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::start_buyback_program(
+            self.token_id,
+            self.budget_source_account,
+            self.params.clone(),
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct ExecuteBuybackFixture {
+    sender: AccountId,
+    token_id: TokenId,
+    swap_offers_number: u32,
+}
+
+impl ExecuteBuybackFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: member!(3).1,
+            token_id: TokenId::one(),
+            swap_offers_number: DEFAULT_BUYBACK_SWAP_OFFERS_NUMBER,
+        }
+    }
+
+    pub fn with_swap_offers_number(self, swap_offers_number: u32) -> Self {
+        Self {
+            swap_offers_number,
+            ..self
+        }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::execute_buyback(
+            Origin::signed(self.sender),
+            self.token_id,
+            self.swap_offers_number,
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct FinalizeBuybackProgramFixture {
+    token_id: TokenId,
+    account_id: AccountId,
+}
+
+impl FinalizeBuybackProgramFixture {
+    pub fn default() -> Self {
+        Self {
+            token_id: TokenId::one(),
+            account_id: member!(1).1,
+        }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::finalize_buyback_program(self.token_id, self.account_id);
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct SetTimeWeightedRevenueSplitsFixture {
+    token_id: TokenId,
+    lookback: Option<BlockNumber>,
+}
+
+impl SetTimeWeightedRevenueSplitsFixture {
+    pub fn default() -> Self {
+        Self {
+            token_id: TokenId::one(),
+            lookback: Some(DEFAULT_TIME_WEIGHTED_SPLITS_LOOKBACK),
+        }
+    }
+
+    pub fn with_lookback(self, lookback: Option<BlockNumber>) -> Self {
+        Self { lookback, ..self }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::set_time_weighted_revenue_splits(self.token_id, self.lookback);
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
@@ -0,0 +1,81 @@
+#![cfg(test)]
+use codec::Encode;
+use frame_support::storage::{unhashed, StorageDoubleMap, StorageMap};
+
+use crate::migrations::{
+    LegacyAccountDataOf, LegacyRevenueSplitState, LegacyTokenDataOf, LegacyTokenSaleOf,
+};
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::types::RevenueSplitState;
+use crate::{member, AccountInfoByTokenAndMember, TokenInfoById};
+
+#[test]
+fn migrate_account_data_ok() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        let account = Token::account_info_by_token_and_member(1u64, member!(1).0);
+        unhashed::put_raw(
+            &AccountInfoByTokenAndMember::<Test>::hashed_key_for(1u64, member!(1).0),
+            &LegacyAccountDataOf::<Test> {
+                vesting_schedules: account.vesting_schedules.clone(),
+                amount: account.amount,
+                split_staking_status: account.split_staking_status.clone(),
+                bloat_bond: account.bloat_bond.clone(),
+                next_vesting_transfer_id: account.next_vesting_transfer_id,
+                last_sale_total_purchased_amount: account.last_sale_total_purchased_amount,
+            }
+            .encode(),
+        );
+
+        Token::migrate_account_data();
+
+        assert_eq!(
+            Token::account_info_by_token_and_member(1u64, member!(1).0),
+            account
+        );
+    })
+}
+
+#[test]
+fn migrate_token_data_ok() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().call_and_assert(Ok(()));
+        InitTokenSaleFixture::default().call_and_assert(Ok(()));
+        let token = Token::token_info_by_id(1u64);
+        let sale = token.sale.clone().unwrap();
+        assert!(matches!(token.revenue_split, RevenueSplitState::Inactive));
+        unhashed::put_raw(
+            &TokenInfoById::<Test>::hashed_key_for(1u64),
+            &LegacyTokenDataOf::<Test> {
+                total_supply: token.total_supply,
+                tokens_issued: token.tokens_issued,
+                next_sale_id: token.next_sale_id,
+                sale: Some(LegacyTokenSaleOf::<Test> {
+                    unit_price: sale.unit_price,
+                    quantity_left: sale.quantity_left,
+                    funds_collected: sale.funds_collected,
+                    tokens_source: sale.tokens_source,
+                    earnings_destination: sale.earnings_destination,
+                    start_block: sale.start_block,
+                    duration: sale.duration,
+                    vesting_schedule_params: sale.vesting_schedule_params,
+                    cap_per_member: sale.cap_per_member,
+                    auto_finalize: sale.auto_finalize,
+                }),
+                transfer_policy: token.transfer_policy.clone(),
+                symbol: token.symbol,
+                patronage_info: token.patronage_info.clone(),
+                accounts_number: token.accounts_number,
+                revenue_split_rate: token.revenue_split_rate,
+                revenue_split: LegacyRevenueSplitState::Inactive,
+                next_revenue_split_id: token.next_revenue_split_id,
+            }
+            .encode(),
+        );
+
+        Token::migrate_token_data();
+
+        assert_eq!(Token::token_info_by_id(1u64), token);
+    })
+}
@@ -37,10 +37,12 @@ pub type SingleDataObjectUploadParams = SingleDataObjectUploadParamsOf<Test>;
 pub type WhitelistParams = WhitelistParamsOf<Test>;
 pub type TokenSaleParams = TokenSaleParamsOf<Test>;
 pub type TokenSale = TokenSaleOf<Test>;
+pub type TokenSaleTierParams = TokenSaleTierParamsOf<Test>;
 pub type IssuanceParams = TokenIssuanceParametersOf<Test>;
 pub type VestingScheduleParams = VestingScheduleParamsOf<Test>;
 pub type IssuanceState = OfferingStateOf<Test>;
 pub type TransferPolicyParams = TransferPolicyParamsOf<Test>;
+pub type BuybackParams = BuybackParamsOf<Test>;
 pub type AccountData = AccountDataOf<Test>;
 pub type ConfigAccountData = ConfigAccountDataOf<Test>;
 pub type AccountId = <Test as frame_system::Config>::AccountId;
@@ -78,6 +80,10 @@ parameter_types! {
     // --------- Pallet Project Token parameters ---------------------
     pub const TokenModuleId: PalletId = PalletId(*b"m__Token");
     pub const MaxVestingSchedulesPerAccountPerToken: u32 = 3;
+    pub const MaxPollAlternatives: u32 = 5;
+    pub const MaxAllowancesPerAccount: u32 = 3;
+    pub const MaxSaleTiers: u32 = 3;
+    pub const MaxBalanceCheckpoints: u32 = 3;
     pub const BlocksPerYear: u32 = 5259487; // blocks every 6s
     // --------- balances::Config parameters ---------------------------
     pub const ExistentialDeposit: u128 = 10;
@@ -168,6 +174,10 @@ impl Config for Test {
     type ModuleId = TokenModuleId;
     type JoyExistentialDeposit = ExistentialDeposit;
     type MaxVestingSchedulesPerAccountPerToken = MaxVestingSchedulesPerAccountPerToken;
+    type MaxPollAlternatives = MaxPollAlternatives;
+    type MaxAllowancesPerAccount = MaxAllowancesPerAccount;
+    type MaxSaleTiers = MaxSaleTiers;
+    type MaxBalanceCheckpoints = MaxBalanceCheckpoints;
     type BlocksPerYear = BlocksPerYear;
     type WeightInfo = ();
     type MemberOriginValidator = TestMemberships;
@@ -383,6 +393,13 @@ impl membership::Config for Test {
     type CandidateStake = CandidateStake;
     type WeightInfo = ();
     type DefaultMemberInvitesCount = DefaultMemberInvitesCount;
+    type MemberReputationRules = ();
+    type MaxRecoveryGuardians = ();
+    type RecoveryDelay = ();
+    type RecoveryDeposit = ();
+    type HandleExpiryPeriod = ();
+    type HandleExpiryGracePeriod = ();
+    type MaxUplineReferralLevels = ();
 }
 
 impl LockComparator<u128> for Test {
@@ -630,6 +647,38 @@ pub const DEFAULT_SPLIT_DURATION: u64 = 100;
 pub const DEFAULT_SPLIT_PARTICIPATION: u128 = 100_000;
 pub const DEFAULT_SPLIT_JOY_DIVIDEND: u128 = 10; // (participation / issuance) * revenue * rate
 
+// ------ Poll constants ---------------------
+pub const DEFAULT_POLL_ALTERNATIVES: u32 = 3;
+pub const DEFAULT_POLL_DURATION: u64 = 100;
+pub const DEFAULT_POLL_VOTE: u128 = 100_000; // amount sent to member 2 by TransferFixture
+
+// ------ Airdrop constants ------------------
+pub const DEFAULT_AIRDROP_AMOUNT: u128 = 10_000;
+pub const DEFAULT_AIRDROP_CLAIM: u128 = 1_000;
+pub const DEFAULT_AIRDROP_DURATION: u64 = 100;
+
+// ------ Allowance constants ----------------
+pub const DEFAULT_ALLOWANCE_AMOUNT: u128 = 10_000;
+pub const DEFAULT_ALLOWANCE_TRANSFER: u128 = 1_000;
+pub const DEFAULT_ALLOWANCE_DURATION: u64 = 100;
+
+// ------ Swap offer constants ---------------
+pub const DEFAULT_SWAP_OFFER_AMOUNT: u128 = 10_000;
+pub const DEFAULT_SWAP_OFFER_WANTED: u128 = 20_000;
+pub const DEFAULT_SWAP_OFFER_FILL: u128 = 5_000;
+pub const DEFAULT_SWAP_OFFER_PAYMENT: u128 = 10_000; // fill * wanted / amount
+
+// ------ Buyback constants ------------------
+pub const DEFAULT_BUYBACK_BUDGET: u128 = 100_000;
+pub const DEFAULT_BUYBACK_MAX_UNIT_PRICE: u128 = 10;
+pub const DEFAULT_BUYBACK_DURATION: u64 = 100;
+pub const DEFAULT_BUYBACK_SWAP_OFFERS_NUMBER: u32 = 10;
+pub const DEFAULT_BUYBACK_OFFER_AMOUNT: u128 = 1_000;
+pub const DEFAULT_BUYBACK_OFFER_WANTED: u128 = 10_000; // offer amount * max unit price
+
+// ------ Time-weighted splits constants ------------------
+pub const DEFAULT_TIME_WEIGHTED_SPLITS_LOOKBACK: u64 = 100;
+
 // ------ Storage Constants ------------------
 pub const STORAGE_WG_LEADER_ACCOUNT_ID: u64 = 100001;
 pub const DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID: u64 = 100002;
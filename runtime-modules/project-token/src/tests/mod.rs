@@ -1,10 +1,18 @@
 #![cfg(test)]
 
+mod airdrop;
+mod allowance;
+mod buyback;
 mod canonical;
+mod compliance;
 mod fixtures;
+mod migrations;
 pub mod mock;
 mod patronage;
+mod poll;
 mod sale;
 mod split;
+mod swap;
 mod test_utils;
+mod time_weighted_split;
 mod transfer;
@@ -0,0 +1,433 @@
+#[cfg(test)]
+use frame_support::assert_err;
+use sp_arithmetic::traits::Zero;
+
+use crate::member;
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::types::{PollVote, Timeline, TokenPollParams};
+use crate::{last_event_eq, Error, RawEvent};
+
+#[test]
+fn issue_poll_fails_with_invalid_token_id() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        let result = IssueTokenPollFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenDoesNotExist);
+    })
+}
+
+#[test]
+fn issue_poll_fails_with_too_few_alternatives() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = IssueTokenPollFixture::default()
+            .with_alternatives(1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::InvalidNumberOfTokenPollAlternatives);
+    })
+}
+
+#[test]
+fn issue_poll_fails_with_too_many_alternatives() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = IssueTokenPollFixture::default()
+            .with_alternatives(MaxPollAlternatives::get() + 1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::InvalidNumberOfTokenPollAlternatives);
+    })
+}
+
+#[test]
+fn issue_poll_fails_with_zero_duration() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = IssueTokenPollFixture::default()
+            .with_duration(0)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::TokenPollDurationIsZero);
+    })
+}
+
+#[test]
+fn issue_poll_fails_with_start_in_the_past() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        increase_block_number_by(10);
+
+        let result = IssueTokenPollFixture::default()
+            .with_starting_block(Token::current_block() - 1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::TokenPollStartInThePast);
+    })
+}
+
+#[test]
+fn issue_poll_fails_with_previous_poll_not_finalized() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        IssueTokenPollFixture::default().execute_call().unwrap();
+        increase_block_number_by(DEFAULT_POLL_DURATION);
+
+        let result = IssueTokenPollFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::PreviousTokenPollNotFinalized);
+    })
+}
+
+#[test]
+fn issue_poll_ok_with_poll_stored_and_event_deposited() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let poll_id = IssueTokenPollFixture::default().execute_call().unwrap();
+
+        let poll = Token::token_poll_by_token_id(1u64).unwrap();
+        assert_eq!(poll_id, 0u32);
+        assert_eq!(
+            poll.timeline,
+            Timeline {
+                start: Token::current_block(),
+                duration: DEFAULT_POLL_DURATION,
+            }
+        );
+        assert_eq!(
+            poll.tallies.into_inner(),
+            vec![0u128; DEFAULT_POLL_ALTERNATIVES as usize]
+        );
+        assert!(!poll.finalized);
+        last_event_eq!(RawEvent::TokenPollIssued(
+            1u64,
+            0u32,
+            TokenPollParams {
+                metadata: b"poll".to_vec(),
+                alternatives: DEFAULT_POLL_ALTERNATIVES,
+                start: None,
+                duration: DEFAULT_POLL_DURATION,
+            }
+        ));
+    })
+}
+
+#[test]
+fn issue_poll_ok_with_id_incremented_after_previous_poll_finalized() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        IssueTokenPollFixture::default().execute_call().unwrap();
+        increase_block_number_by(DEFAULT_POLL_DURATION);
+        FinalizeTokenPollFixture::default().execute_call().unwrap();
+
+        let poll_id = IssueTokenPollFixture::default().execute_call().unwrap();
+
+        assert_eq!(poll_id, 1u32);
+    })
+}
+
+#[test]
+fn vote_in_poll_fails_with_zero_amount() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap(); // send voting tokens to other acc
+        IssueTokenPollFixture::default().execute_call().unwrap();
+
+        let result = VoteInTokenPollFixture::default()
+            .with_amount(0u128)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::TokenPollVoteAmountIsZero);
+    })
+}
+
+#[test]
+fn vote_in_poll_fails_with_no_poll() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap(); // send voting tokens to other acc
+
+        let result = VoteInTokenPollFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::NoTokenPoll);
+    })
+}
+
+#[test]
+fn vote_in_poll_fails_with_poll_not_started() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap(); // send voting tokens to other acc
+        IssueTokenPollFixture::default()
+            .with_starting_block(Token::current_block() + 10)
+            .execute_call()
+            .unwrap();
+
+        let result = VoteInTokenPollFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenPollNotOngoing);
+    })
+}
+
+#[test]
+fn vote_in_poll_fails_with_poll_ended() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap(); // send voting tokens to other acc
+        IssueTokenPollFixture::default().execute_call().unwrap();
+        increase_block_number_by(DEFAULT_POLL_DURATION);
+
+        let result = VoteInTokenPollFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenPollNotOngoing);
+    })
+}
+
+#[test]
+fn vote_in_poll_fails_with_invalid_alternative() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap(); // send voting tokens to other acc
+        IssueTokenPollFixture::default().execute_call().unwrap();
+
+        let result = VoteInTokenPollFixture::default()
+            .with_alternative(DEFAULT_POLL_ALTERNATIVES)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::InvalidTokenPollAlternative);
+    })
+}
+
+#[test]
+fn vote_in_poll_fails_with_insufficient_balance() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap(); // send voting tokens to other acc
+        IssueTokenPollFixture::default().execute_call().unwrap();
+
+        let result = VoteInTokenPollFixture::default()
+            .with_amount(DEFAULT_POLL_VOTE + 1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientBalanceForTokenPollVote);
+    })
+}
+
+#[test]
+fn vote_in_poll_fails_with_user_already_voted() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap(); // send voting tokens to other acc
+        IssueTokenPollFixture::default().execute_call().unwrap();
+        VoteInTokenPollFixture::default()
+            .with_amount(DEFAULT_POLL_VOTE / 2)
+            .execute_call()
+            .unwrap();
+
+        let result = VoteInTokenPollFixture::default()
+            .with_alternative(1)
+            .with_amount(DEFAULT_POLL_VOTE / 2)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::UserAlreadyVotedInTokenPoll);
+    })
+}
+
+#[test]
+fn vote_in_poll_ok_with_tally_increased_and_tokens_locked() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap(); // send voting tokens to other acc
+        IssueTokenPollFixture::default().execute_call().unwrap();
+
+        VoteInTokenPollFixture::default()
+            .with_alternative(1)
+            .execute_call()
+            .unwrap();
+
+        let account = Token::account_info_by_token_and_member(1u64, member!(2).0);
+        assert_eq!(
+            account.poll_vote,
+            Some(PollVote {
+                poll_id: 0u32,
+                alternative: 1u32,
+                amount: DEFAULT_POLL_VOTE,
+            })
+        );
+        // transferrable balance should be 0, since all available amount is locked
+        assert!(account
+            .transferrable::<Test>(System::block_number())
+            .is_zero());
+        assert_eq!(
+            Token::token_poll_tallies(1u64),
+            Some((0u32, vec![0u128, DEFAULT_POLL_VOTE, 0u128]))
+        );
+        last_event_eq!(RawEvent::VotedInTokenPoll(
+            1u64,
+            member!(2).0,
+            0u32,
+            1u32,
+            DEFAULT_POLL_VOTE,
+        ));
+    })
+}
+
+#[test]
+fn vote_in_poll_ok_with_vote_in_previous_poll_not_unlocked() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap(); // send voting tokens to other acc
+        IssueTokenPollFixture::default().execute_call().unwrap();
+        VoteInTokenPollFixture::default().execute_call().unwrap();
+        increase_block_number_by(DEFAULT_POLL_DURATION);
+        FinalizeTokenPollFixture::default().execute_call().unwrap();
+        IssueTokenPollFixture::default().execute_call().unwrap();
+
+        VoteInTokenPollFixture::default().execute_call().unwrap();
+
+        assert!(matches!(
+            Token::account_info_by_token_and_member(1u64, member!(2).0).poll_vote,
+            Some(PollVote { poll_id: 1u32, .. })
+        ));
+    })
+}
+
+#[test]
+fn unlock_poll_vote_fails_with_user_not_voting() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap(); // send voting tokens to other acc
+        IssueTokenPollFixture::default().execute_call().unwrap();
+
+        let result = UnlockTokenPollVoteFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::UserNotVotingInAnyTokenPoll);
+    })
+}
+
+#[test]
+fn unlock_poll_vote_fails_with_poll_not_ended() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap(); // send voting tokens to other acc
+        IssueTokenPollFixture::default().execute_call().unwrap();
+        VoteInTokenPollFixture::default().execute_call().unwrap();
+        increase_block_number_by(DEFAULT_POLL_DURATION - 1);
+
+        let result = UnlockTokenPollVoteFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenPollDidNotEnd);
+    })
+}
+
+#[test]
+fn unlock_poll_vote_ok_with_tokens_unlocked_and_event_deposited() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap(); // send voting tokens to other acc
+        IssueTokenPollFixture::default().execute_call().unwrap();
+        VoteInTokenPollFixture::default().execute_call().unwrap();
+        increase_block_number_by(DEFAULT_POLL_DURATION);
+
+        UnlockTokenPollVoteFixture::default()
+            .execute_call()
+            .unwrap();
+
+        let account = Token::account_info_by_token_and_member(1u64, member!(2).0);
+        assert!(account.poll_vote.is_none());
+        assert_eq!(
+            account.transferrable::<Test>(System::block_number()),
+            DEFAULT_POLL_VOTE
+        );
+        last_event_eq!(RawEvent::TokenPollTokensUnlocked(
+            1u64,
+            member!(2).0,
+            DEFAULT_POLL_VOTE,
+        ));
+    })
+}
+
+#[test]
+fn finalize_poll_fails_with_poll_not_ended() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        IssueTokenPollFixture::default().execute_call().unwrap();
+        increase_block_number_by(DEFAULT_POLL_DURATION - 1);
+
+        let result = FinalizeTokenPollFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenPollDidNotEnd);
+    })
+}
+
+#[test]
+fn finalize_poll_fails_with_poll_already_finalized() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        IssueTokenPollFixture::default().execute_call().unwrap();
+        increase_block_number_by(DEFAULT_POLL_DURATION);
+        FinalizeTokenPollFixture::default().execute_call().unwrap();
+
+        let result = FinalizeTokenPollFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenPollAlreadyFinalized);
+    })
+}
+
+#[test]
+fn finalize_poll_ok_with_winning_alternative_set() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap(); // send voting tokens to other acc
+        IssueTokenPollFixture::default().execute_call().unwrap();
+        VoteInTokenPollFixture::default()
+            .with_alternative(2)
+            .execute_call()
+            .unwrap();
+        VoteInTokenPollFixture::default()
+            .with_member(member!(1).0, member!(1).1)
+            .with_alternative(1)
+            .with_amount(DEFAULT_POLL_VOTE - 1)
+            .execute_call()
+            .unwrap();
+        increase_block_number_by(DEFAULT_POLL_DURATION);
+
+        FinalizeTokenPollFixture::default().execute_call().unwrap();
+
+        let poll = Token::token_poll_by_token_id(1u64).unwrap();
+        assert!(poll.finalized);
+        assert_eq!(poll.winning_alternative, Some(2u32));
+        last_event_eq!(RawEvent::TokenPollFinalized(1u64, 0u32, Some(2u32)));
+    })
+}
+
+#[test]
+fn finalize_poll_ok_with_no_winner_in_case_of_tie() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap(); // send voting tokens to other acc
+        IssueTokenPollFixture::default().execute_call().unwrap();
+        VoteInTokenPollFixture::default()
+            .with_alternative(2)
+            .execute_call()
+            .unwrap();
+        VoteInTokenPollFixture::default()
+            .with_member(member!(1).0, member!(1).1)
+            .with_alternative(1)
+            .execute_call()
+            .unwrap();
+        increase_block_number_by(DEFAULT_POLL_DURATION);
+
+        FinalizeTokenPollFixture::default().execute_call().unwrap();
+
+        let poll = Token::token_poll_by_token_id(1u64).unwrap();
+        assert!(poll.finalized);
+        assert_eq!(poll.winning_alternative, None);
+        last_event_eq!(RawEvent::TokenPollFinalized(1u64, 0u32, None));
+    })
+}
@@ -7,6 +7,7 @@ use crate::tests::fixtures::*;
 use crate::tests::mock::*;
 use crate::types::Joy;
 use crate::types::MerkleProofOf;
+use crate::types::VestingSource;
 use crate::{member, merkle_proof, merkle_root};
 use frame_support::assert_ok;
 use sp_arithmetic::Permill;
@@ -980,3 +981,303 @@ fn succesful_finalize_token_sale() {
         FinalizeTokenSaleFixture::default().call_and_assert(Ok(()));
     })
 }
+
+/////////////////////////////////////////////////////////
+///////////////////// TIERED SALES //////////////////////
+/////////////////////////////////////////////////////////
+
+fn default_sale_tiers() -> Vec<TokenSaleTierParams> {
+    vec![
+        TokenSaleTierParams {
+            unit_price: DEFAULT_SALE_UNIT_PRICE * 2,
+            quantity: DEFAULT_SALE_PURCHASE_AMOUNT,
+            vesting_schedule_params: None,
+        },
+        TokenSaleTierParams {
+            unit_price: DEFAULT_SALE_UNIT_PRICE * 3,
+            quantity: DEFAULT_SALE_PURCHASE_AMOUNT,
+            vesting_schedule_params: Some(VestingScheduleParams {
+                blocks_before_cliff: 100,
+                linear_vesting_duration: 0,
+                cliff_amount_percentage: Permill::from_percent(100),
+            }),
+        },
+    ]
+}
+
+fn default_tiered_sale_fixture() -> InitTokenSaleFixture {
+    InitTokenSaleFixture::default()
+        .with_upper_bound_quantity(DEFAULT_SALE_PURCHASE_AMOUNT)
+        .with_vesting_schedule_params(None)
+        .with_tiers(default_sale_tiers())
+}
+
+#[test]
+fn unsuccesful_token_sale_init_with_zero_tier_quantity() {
+    let config = GenesisConfigBuilder::new_empty().build();
+
+    build_test_externalities(config).execute_with(|| {
+        IssueTokenFixture::default().call_and_assert(Ok(()));
+        default_tiered_sale_fixture()
+            .with_tiers(vec![TokenSaleTierParams {
+                quantity: 0,
+                ..default_sale_tiers()[0].clone()
+            }])
+            .call_and_assert(Err(Error::<Test>::SaleTierQuantityIsZero.into()))
+    })
+}
+
+#[test]
+fn unsuccesful_token_sale_init_with_zero_tier_unit_price() {
+    let config = GenesisConfigBuilder::new_empty().build();
+
+    build_test_externalities(config).execute_with(|| {
+        IssueTokenFixture::default().call_and_assert(Ok(()));
+        default_tiered_sale_fixture()
+            .with_tiers(vec![TokenSaleTierParams {
+                unit_price: 0,
+                ..default_sale_tiers()[0].clone()
+            }])
+            .call_and_assert(Err(Error::<Test>::SaleUnitPriceIsZero.into()))
+    })
+}
+
+#[test]
+fn unsuccesful_token_sale_init_with_max_sale_tiers_exceeded() {
+    let config = GenesisConfigBuilder::new_empty().build();
+
+    build_test_externalities(config).execute_with(|| {
+        IssueTokenFixture::default().call_and_assert(Ok(()));
+        default_tiered_sale_fixture()
+            .with_tiers(vec![
+                default_sale_tiers()[0].clone();
+                MaxSaleTiers::get() as usize
+            ])
+            .call_and_assert(Err(Error::<Test>::MaxSaleTiersExceeded.into()))
+    })
+}
+
+#[test]
+fn unsuccesful_token_sale_init_with_total_tiers_quantity_exceeding_source_balance() {
+    let config = GenesisConfigBuilder::new_empty().build();
+
+    build_test_externalities(config).execute_with(|| {
+        IssueTokenFixture::default().call_and_assert(Ok(()));
+        default_tiered_sale_fixture()
+            .with_upper_bound_quantity(DEFAULT_INITIAL_ISSUANCE - DEFAULT_SALE_PURCHASE_AMOUNT)
+            .call_and_assert(Err(Error::<Test>::InsufficientTransferrableBalance.into()));
+    })
+}
+
+#[test]
+fn succesful_token_sale_init_with_tiers() {
+    let config = GenesisConfigBuilder::new_empty().build();
+
+    build_test_externalities(config).execute_with(|| {
+        IssueTokenFixture::default().call_and_assert(Ok(()));
+        default_tiered_sale_fixture().call_and_assert(Ok(()));
+
+        let sale = Token::token_info_by_id(1).sale.unwrap();
+        assert_eq!(sale.quantity_left, DEFAULT_SALE_PURCHASE_AMOUNT * 3);
+        assert_eq!(sale.tiers.len(), 3);
+    })
+}
+
+#[test]
+fn unsuccesful_sale_purchase_amount_exceeds_quantity_left_in_all_tiers() {
+    let config = GenesisConfigBuilder::new_empty().build();
+
+    build_test_externalities(config).execute_with(|| {
+        IssueTokenFixture::default().call_and_assert(Ok(()));
+        default_tiered_sale_fixture().call_and_assert(Ok(()));
+        increase_account_balance(
+            &member!(2).1,
+            <Test as crate::Config>::JoyExistentialDeposit::get()
+                + DEFAULT_SALE_UNIT_PRICE * 6 * DEFAULT_SALE_PURCHASE_AMOUNT,
+        );
+
+        PurchaseTokensOnSaleFixture::default()
+            .with_amount(DEFAULT_SALE_PURCHASE_AMOUNT * 3 + 1)
+            .call_and_assert(Err(Error::<Test>::NotEnoughTokensOnSale.into()));
+    })
+}
+
+#[test]
+fn unsuccesful_sale_purchase_spanning_tiers_with_insufficient_joy_balance() {
+    let config = GenesisConfigBuilder::new_empty().build();
+
+    build_test_externalities(config).execute_with(|| {
+        IssueTokenFixture::default().call_and_assert(Ok(()));
+        default_tiered_sale_fixture().call_and_assert(Ok(()));
+        // Enough to cover the purchase at initial tier's price only
+        increase_account_balance(
+            &member!(2).1,
+            <Test as crate::Config>::JoyExistentialDeposit::get()
+                + DEFAULT_SALE_UNIT_PRICE * DEFAULT_SALE_PURCHASE_AMOUNT * 2,
+        );
+
+        PurchaseTokensOnSaleFixture::default()
+            .with_amount(DEFAULT_SALE_PURCHASE_AMOUNT * 2)
+            .call_and_assert(Err(Error::<Test>::InsufficientJoyBalance.into()));
+    })
+}
+
+#[test]
+fn unsuccesful_sale_purchase_spanning_tiers_with_cap_exceeded() {
+    let config = GenesisConfigBuilder::new_empty().build();
+
+    build_test_externalities(config).execute_with(|| {
+        IssueTokenFixture::default().call_and_assert(Ok(()));
+        default_tiered_sale_fixture()
+            .with_cap_per_member(DEFAULT_SALE_PURCHASE_AMOUNT * 2)
+            .call_and_assert(Ok(()));
+        increase_account_balance(
+            &member!(2).1,
+            <Test as crate::Config>::JoyExistentialDeposit::get()
+                + DEFAULT_SALE_UNIT_PRICE * 6 * DEFAULT_SALE_PURCHASE_AMOUNT,
+        );
+        PurchaseTokensOnSaleFixture::default()
+            .with_amount(DEFAULT_SALE_PURCHASE_AMOUNT * 2)
+            .call_and_assert(Ok(()));
+
+        PurchaseTokensOnSaleFixture::default()
+            .with_amount(1)
+            .call_and_assert(Err(Error::<Test>::SalePurchaseCapExceeded.into()));
+    })
+}
+
+#[test]
+fn succesful_sale_purchase_spanning_two_tiers() {
+    let config = GenesisConfigBuilder::new_empty().build();
+
+    build_test_externalities(config).execute_with(|| {
+        IssueTokenFixture::default().call_and_assert(Ok(()));
+        default_tiered_sale_fixture().call_and_assert(Ok(()));
+        // 1000 tokens from the initial tier + 500 tokens from the 2nd tier (at 2x price)
+        let amount = DEFAULT_SALE_PURCHASE_AMOUNT + DEFAULT_SALE_PURCHASE_AMOUNT / 2;
+        let expected_cost = DEFAULT_SALE_UNIT_PRICE * DEFAULT_SALE_PURCHASE_AMOUNT
+            + DEFAULT_SALE_UNIT_PRICE * 2 * (DEFAULT_SALE_PURCHASE_AMOUNT / 2);
+        increase_account_balance(
+            &member!(2).1,
+            <Test as crate::Config>::JoyExistentialDeposit::get() + expected_cost,
+        );
+
+        PurchaseTokensOnSaleFixture::default()
+            .with_amount(amount)
+            .call_and_assert(Ok(()));
+
+        let sale = Token::token_info_by_id(1).sale.unwrap();
+        assert_eq!(sale.funds_collected, expected_cost);
+        assert_eq!(sale.unit_price, DEFAULT_SALE_UNIT_PRICE * 2);
+        assert_eq!(
+            sale.tiers
+                .iter()
+                .map(|t| t.quantity_left)
+                .collect::<Vec<_>>(),
+            vec![
+                0,
+                DEFAULT_SALE_PURCHASE_AMOUNT / 2,
+                DEFAULT_SALE_PURCHASE_AMOUNT
+            ]
+        );
+        assert_eq!(
+            Joy::<Test>::usable_balance(member!(2).1),
+            <Test as crate::Config>::JoyExistentialDeposit::get()
+        );
+        assert_eq!(
+            Token::account_info_by_token_and_member(1, member!(2).0)
+                .transferrable::<Test>(System::block_number()),
+            amount
+        );
+    })
+}
+
+#[test]
+fn succesful_sale_purchase_with_tier_vesting_schedule() {
+    let config = GenesisConfigBuilder::new_empty().build();
+
+    build_test_externalities(config).execute_with(|| {
+        IssueTokenFixture::default().call_and_assert(Ok(()));
+        default_tiered_sale_fixture().call_and_assert(Ok(()));
+        increase_account_balance(
+            &member!(2).1,
+            <Test as crate::Config>::JoyExistentialDeposit::get()
+                + DEFAULT_SALE_UNIT_PRICE * 6 * DEFAULT_SALE_PURCHASE_AMOUNT,
+        );
+
+        // 500 tokens purchased from the 3rd tier are subject to tier's vesting schedule
+        PurchaseTokensOnSaleFixture::default()
+            .with_amount(DEFAULT_SALE_PURCHASE_AMOUNT * 2 + DEFAULT_SALE_PURCHASE_AMOUNT / 2)
+            .call_and_assert(Ok(()));
+
+        let buyer_acc_info = Token::account_info_by_token_and_member(1, member!(2).0);
+        assert!(buyer_acc_info
+            .vesting_schedules
+            .contains_key(&VestingSource::SaleTier(0, 2)));
+        assert_eq!(
+            buyer_acc_info.transferrable::<Test>(System::block_number()),
+            DEFAULT_SALE_PURCHASE_AMOUNT * 2
+        );
+
+        // After sale end and the tier's cliff all tokens are available
+        increase_block_number_by(DEFAULT_SALE_DURATION + 100);
+        let buyer_acc_info = Token::account_info_by_token_and_member(1, member!(2).0);
+        assert_eq!(
+            buyer_acc_info.transferrable::<Test>(System::block_number()),
+            DEFAULT_SALE_PURCHASE_AMOUNT * 2 + DEFAULT_SALE_PURCHASE_AMOUNT / 2
+        );
+    })
+}
+
+#[test]
+fn succesful_sale_purchase_auto_finalizing_the_tiered_sale() {
+    let config = GenesisConfigBuilder::new_empty().build();
+
+    build_test_externalities(config).execute_with(|| {
+        IssueTokenFixture::default().call_and_assert(Ok(()));
+        default_tiered_sale_fixture().call_and_assert(Ok(()));
+        increase_account_balance(
+            &member!(2).1,
+            <Test as crate::Config>::JoyExistentialDeposit::get()
+                + DEFAULT_SALE_UNIT_PRICE * 6 * DEFAULT_SALE_PURCHASE_AMOUNT,
+        );
+
+        PurchaseTokensOnSaleFixture::default()
+            .with_amount(DEFAULT_SALE_PURCHASE_AMOUNT)
+            .call_and_assert(Ok(()));
+        assert!(Token::token_info_by_id(1).sale.is_some());
+
+        PurchaseTokensOnSaleFixture::default()
+            .with_amount(DEFAULT_SALE_PURCHASE_AMOUNT * 2)
+            .call_and_assert(Ok(()));
+        assert!(Token::token_info_by_id(1).sale.is_none());
+    })
+}
+
+#[test]
+fn succesful_finalize_tiered_token_sale() {
+    let config = GenesisConfigBuilder::new_empty().build();
+
+    build_test_externalities(config).execute_with(|| {
+        IssueTokenFixture::default().call_and_assert(Ok(()));
+        default_tiered_sale_fixture().call_and_assert(Ok(()));
+        increase_account_balance(
+            &member!(2).1,
+            <Test as crate::Config>::JoyExistentialDeposit::get()
+                + DEFAULT_SALE_UNIT_PRICE * 2 * DEFAULT_SALE_PURCHASE_AMOUNT,
+        );
+        PurchaseTokensOnSaleFixture::default()
+            .with_amount(DEFAULT_SALE_PURCHASE_AMOUNT + DEFAULT_SALE_PURCHASE_AMOUNT / 2)
+            .call_and_assert(Ok(()));
+        increase_block_number_by(DEFAULT_SALE_DURATION);
+
+        FinalizeTokenSaleFixture::default().call_and_assert(Ok(()));
+
+        assert_eq!(
+            Token::account_info_by_token_and_member(1, member!(1).0).amount,
+            DEFAULT_INITIAL_ISSUANCE
+                - DEFAULT_SALE_PURCHASE_AMOUNT
+                - DEFAULT_SALE_PURCHASE_AMOUNT / 2
+        );
+    })
+}
@@ -201,6 +201,34 @@ fn issue_split_ok_with_allocation_transferred_to_treasury_account() {
     })
 }
 
+#[test]
+fn issue_split_with_rate_ok_with_given_rate_allocated() {
+    build_default_test_externalities_with_balances(vec![(
+        member!(1).1,
+        DEFAULT_SPLIT_REVENUE + ExistentialDeposit::get(),
+    )])
+    .execute_with(|| {
+        let treasury_account = Token::module_treasury_account();
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let allocation = IssueRevenueSplitFixture::default()
+            .with_split_rate(Permill::from_percent(100))
+            .execute_call()
+            .unwrap();
+
+        // whole revenue allocated regardless of the token revenue split rate
+        assert_eq!(allocation, DEFAULT_SPLIT_REVENUE);
+        assert_eq!(
+            Joy::<Test>::usable_balance(treasury_account),
+            DEFAULT_SPLIT_REVENUE + ExistentialDeposit::get(),
+        );
+        assert_eq!(
+            Joy::<Test>::usable_balance(member!(1).1),
+            ExistentialDeposit::get()
+        );
+    })
+}
+
 #[test]
 fn issue_split_ok_with_revenue_split_correctly_activated() {
     pub const START: u64 = 1u64 + MIN_REVENUE_SPLIT_TIME_TO_START;
@@ -222,7 +250,8 @@ fn issue_split_ok_with_revenue_split_correctly_activated() {
                     start: START,
                     duration: DEFAULT_SPLIT_DURATION,
                 },
-                dividends_claimed: 0u128
+                dividends_claimed: 0u128,
+                eligibility_window: None,
             })
         );
 
@@ -669,6 +698,7 @@ fn participate_in_split_ok_with_dividends_transferred_to_claimer_joy_balance() {
                     duration: DEFAULT_SPLIT_DURATION,
                 },
                 dividends_claimed: DEFAULT_SPLIT_JOY_DIVIDEND,
+                eligibility_window: None,
             })
         );
     })
@@ -0,0 +1,546 @@
+#![cfg(test)]
+use frame_support::{assert_err, StorageValue};
+use sp_runtime::{traits::Hash, DispatchError};
+
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::traits::PalletToken;
+use crate::types::{SwapAsset, SwapOffer};
+use crate::{joy, last_event_eq, member, origin, BloatBond, Error, RawEvent};
+
+// Issues token 1 (offered) and token 2 (wanted) by member 1 and sends
+// `DEFAULT_SPLIT_PARTICIPATION` of token 2 to member 2 (the taker)
+fn issue_tokens_and_fund_taker() {
+    IssueTokenFixture::default().execute_call().unwrap();
+    IssueTokenFixture::default()
+        .with_symbol(b"DEF")
+        .execute_call()
+        .unwrap();
+    TransferFixture::default()
+        .with_token_id(2u64)
+        .execute_call()
+        .unwrap();
+}
+
+#[test]
+fn create_swap_offer_fails_with_invalid_token_id() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        let result = CreateSwapOfferFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenDoesNotExist);
+    })
+}
+
+#[test]
+fn create_swap_offer_fails_with_invalid_member_controller() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        issue_tokens_and_fund_taker();
+
+        let result = CreateSwapOfferFixture::default()
+            .with_member(member!(1).0, member!(2).1)
+            .execute_call();
+
+        assert_err!(
+            result,
+            DispatchError::Other("origin signer not a member controller account")
+        );
+    })
+}
+
+#[test]
+fn create_swap_offer_fails_with_zero_amount() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        issue_tokens_and_fund_taker();
+
+        let result = CreateSwapOfferFixture::default()
+            .with_amount(0)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::SwapOfferAmountIsZero);
+    })
+}
+
+#[test]
+fn create_swap_offer_fails_with_zero_wanted_amount() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        issue_tokens_and_fund_taker();
+
+        let result = CreateSwapOfferFixture::default()
+            .with_wanted(SwapAsset::Joy(0))
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::SwapOfferWantedAmountIsZero);
+    })
+}
+
+#[test]
+fn create_swap_offer_fails_with_offered_token_wanted() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        issue_tokens_and_fund_taker();
+
+        let result = CreateSwapOfferFixture::default()
+            .with_wanted(SwapAsset::Token(1u64, DEFAULT_SWAP_OFFER_WANTED))
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::SwapOfferWantedTokenIsOfferedToken);
+    })
+}
+
+#[test]
+fn create_swap_offer_fails_with_non_existing_wanted_token() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        issue_tokens_and_fund_taker();
+
+        let result = CreateSwapOfferFixture::default()
+            .with_wanted(SwapAsset::Token(3u64, DEFAULT_SWAP_OFFER_WANTED))
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::TokenDoesNotExist);
+    })
+}
+
+#[test]
+fn create_swap_offer_fails_with_insufficient_transferrable_balance() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        issue_tokens_and_fund_taker();
+
+        let result = CreateSwapOfferFixture::default()
+            .with_amount(DEFAULT_INITIAL_ISSUANCE + 1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientTransferrableBalance);
+    })
+}
+
+#[test]
+fn create_swap_offer_fails_with_frozen_account() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        issue_tokens_and_fund_taker();
+        SetAccountFrozenStatusFixture::default()
+            .with_member_id(member!(1).0)
+            .execute_call()
+            .unwrap();
+
+        let result = CreateSwapOfferFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::AccountFrozen);
+    })
+}
+
+#[test]
+fn create_swap_offer_ok_with_tokens_escrowed_and_event_deposited() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        issue_tokens_and_fund_taker();
+
+        CreateSwapOfferFixture::default().execute_call().unwrap();
+
+        assert_eq!(
+            Token::account_info_by_token_and_member(1u64, member!(1).0).amount,
+            DEFAULT_INITIAL_ISSUANCE - DEFAULT_SWAP_OFFER_AMOUNT
+        );
+        assert_eq!(
+            Token::swap_offer_by_id(1u64, 0),
+            Some(SwapOffer {
+                maker: member!(1).0,
+                amount_left: DEFAULT_SWAP_OFFER_AMOUNT,
+                wanted_left: SwapAsset::Token(2u64, DEFAULT_SWAP_OFFER_WANTED),
+            })
+        );
+        assert_eq!(Token::next_swap_offer_id(), 1);
+        last_event_eq!(RawEvent::SwapOfferCreated(
+            1u64,
+            0,
+            member!(1).0,
+            DEFAULT_SWAP_OFFER_AMOUNT,
+            SwapAsset::Token(2u64, DEFAULT_SWAP_OFFER_WANTED)
+        ));
+    })
+}
+
+#[test]
+fn fill_swap_offer_fails_with_non_existing_offer() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        issue_tokens_and_fund_taker();
+
+        let result = FillSwapOfferFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::SwapOfferDoesNotExist);
+    })
+}
+
+#[test]
+fn fill_swap_offer_fails_with_maker_as_taker() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        issue_tokens_and_fund_taker();
+        CreateSwapOfferFixture::default().execute_call().unwrap();
+
+        let result = FillSwapOfferFixture::default()
+            .with_member(member!(1).0, member!(1).1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::SwapOfferFilledByMaker);
+    })
+}
+
+#[test]
+fn fill_swap_offer_fails_with_paused_token() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        issue_tokens_and_fund_taker();
+        CreateSwapOfferFixture::default().execute_call().unwrap();
+        SetTokenPausedStatusFixture::default()
+            .execute_call()
+            .unwrap();
+
+        let result = FillSwapOfferFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenPaused);
+    })
+}
+
+#[test]
+fn fill_swap_offer_fails_with_insufficient_amount_left() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        issue_tokens_and_fund_taker();
+        CreateSwapOfferFixture::default().execute_call().unwrap();
+
+        let result = FillSwapOfferFixture::default()
+            .with_amount(DEFAULT_SWAP_OFFER_AMOUNT + 1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientSwapOfferAmountLeft);
+    })
+}
+
+#[test]
+fn fill_swap_offer_ok_with_offer_closed_once_nothing_left_to_pay_for() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        issue_tokens_and_fund_taker();
+        CreateSwapOfferFixture::default()
+            .with_amount(3)
+            .with_wanted(SwapAsset::Token(2u64, 2))
+            .execute_call()
+            .unwrap();
+        // payment is rounded up in favor of the maker: 3 -> 2 tokens left with 1 wanted left
+        FillSwapOfferFixture::default()
+            .with_amount(1)
+            .execute_call()
+            .unwrap();
+
+        // paying the whole wanted amount left buys the whole amount left
+        FillSwapOfferFixture::default()
+            .with_amount(1)
+            .execute_call()
+            .unwrap();
+
+        assert!(Token::swap_offer_by_id(1u64, 0).is_none());
+        assert_eq!(
+            Token::account_info_by_token_and_member(1u64, member!(2).0).amount,
+            3
+        );
+        last_event_eq!(RawEvent::SwapOfferFilled(
+            1u64,
+            0,
+            member!(2).0,
+            2,
+            SwapAsset::Token(2u64, 1)
+        ));
+    })
+}
+
+#[test]
+fn fill_swap_offer_ok_with_exact_payment_for_small_fills() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        issue_tokens_and_fund_taker();
+        CreateSwapOfferFixture::default()
+            .with_amount(700_000)
+            .with_wanted(SwapAsset::Joy(700_000_000))
+            .execute_call()
+            .unwrap();
+        increase_account_balance(&member!(2).1, ExistentialDeposit::get() + 1_000);
+
+        FillSwapOfferFixture::default()
+            .with_amount(1)
+            .execute_call()
+            .unwrap();
+
+        assert_eq!(
+            Token::swap_offer_by_id(1u64, 0).unwrap().wanted_left,
+            SwapAsset::Joy(699_999_000)
+        );
+        last_event_eq!(RawEvent::SwapOfferFilled(
+            1u64,
+            0,
+            member!(2).0,
+            1,
+            SwapAsset::Joy(1_000)
+        ));
+    })
+}
+
+#[test]
+fn fill_swap_offer_fails_with_permissioned_token_and_non_existing_taker_account() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default()
+            .with_transfer_policy(TransferPolicyParams::Permissioned(WhitelistParams {
+                commitment: Hashing::hash_of(b"commitment"),
+                payload: None,
+            }))
+            .execute_call()
+            .unwrap();
+        IssueTokenFixture::default()
+            .with_symbol(b"DEF")
+            .execute_call()
+            .unwrap();
+        TransferFixture::default()
+            .with_token_id(2u64)
+            .execute_call()
+            .unwrap();
+        CreateSwapOfferFixture::default().execute_call().unwrap();
+
+        let result = FillSwapOfferFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::AccountInformationDoesNotExist);
+    })
+}
+
+#[test]
+fn fill_swap_offer_fails_with_insufficient_payment_token_balance() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        issue_tokens_and_fund_taker();
+        CreateSwapOfferFixture::default()
+            .with_wanted(SwapAsset::Token(2u64, DEFAULT_SPLIT_PARTICIPATION * 2))
+            .execute_call()
+            .unwrap();
+
+        let result = FillSwapOfferFixture::default()
+            .with_amount(DEFAULT_SWAP_OFFER_AMOUNT)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientTransferrableBalance);
+    })
+}
+
+#[test]
+fn fill_swap_offer_fails_with_insufficient_joy_balance() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        issue_tokens_and_fund_taker();
+        CreateSwapOfferFixture::default()
+            .with_wanted(SwapAsset::Joy(DEFAULT_SWAP_OFFER_WANTED))
+            .execute_call()
+            .unwrap();
+        increase_account_balance(
+            &member!(2).1,
+            ExistentialDeposit::get() + DEFAULT_SWAP_OFFER_PAYMENT - 1,
+        );
+
+        let result = FillSwapOfferFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientJoyBalance);
+    })
+}
+
+#[test]
+fn fill_swap_offer_ok_with_token_payment() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        issue_tokens_and_fund_taker();
+        CreateSwapOfferFixture::default().execute_call().unwrap();
+
+        FillSwapOfferFixture::default().execute_call().unwrap();
+
+        assert_eq!(
+            Token::account_info_by_token_and_member(1u64, member!(2).0).amount,
+            DEFAULT_SWAP_OFFER_FILL
+        );
+        assert_eq!(
+            Token::account_info_by_token_and_member(2u64, member!(2).0).amount,
+            DEFAULT_SPLIT_PARTICIPATION - DEFAULT_SWAP_OFFER_PAYMENT
+        );
+        assert_eq!(
+            Token::account_info_by_token_and_member(2u64, member!(1).0).amount,
+            DEFAULT_INITIAL_ISSUANCE - DEFAULT_SPLIT_PARTICIPATION + DEFAULT_SWAP_OFFER_PAYMENT
+        );
+        assert_eq!(
+            Token::swap_offer_by_id(1u64, 0),
+            Some(SwapOffer {
+                maker: member!(1).0,
+                amount_left: DEFAULT_SWAP_OFFER_AMOUNT - DEFAULT_SWAP_OFFER_FILL,
+                wanted_left: SwapAsset::Token(
+                    2u64,
+                    DEFAULT_SWAP_OFFER_WANTED - DEFAULT_SWAP_OFFER_PAYMENT
+                ),
+            })
+        );
+        last_event_eq!(RawEvent::SwapOfferFilled(
+            1u64,
+            0,
+            member!(2).0,
+            DEFAULT_SWAP_OFFER_FILL,
+            SwapAsset::Token(2u64, DEFAULT_SWAP_OFFER_PAYMENT)
+        ));
+    })
+}
+
+#[test]
+fn fill_swap_offer_ok_with_joy_payment() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        issue_tokens_and_fund_taker();
+        CreateSwapOfferFixture::default()
+            .with_wanted(SwapAsset::Joy(DEFAULT_SWAP_OFFER_WANTED))
+            .execute_call()
+            .unwrap();
+        increase_account_balance(
+            &member!(2).1,
+            ExistentialDeposit::get() + DEFAULT_SWAP_OFFER_PAYMENT,
+        );
+        let maker_balance_pre = Balances::usable_balance(member!(1).1);
+
+        FillSwapOfferFixture::default().execute_call().unwrap();
+
+        assert_eq!(
+            Balances::usable_balance(member!(1).1),
+            maker_balance_pre + DEFAULT_SWAP_OFFER_PAYMENT
+        );
+        assert_eq!(
+            Balances::usable_balance(member!(2).1),
+            ExistentialDeposit::get()
+        );
+        assert_eq!(
+            Token::account_info_by_token_and_member(1u64, member!(2).0).amount,
+            DEFAULT_SWAP_OFFER_FILL
+        );
+        last_event_eq!(RawEvent::SwapOfferFilled(
+            1u64,
+            0,
+            member!(2).0,
+            DEFAULT_SWAP_OFFER_FILL,
+            SwapAsset::Joy(DEFAULT_SWAP_OFFER_PAYMENT)
+        ));
+    })
+}
+
+#[test]
+fn fill_swap_offer_ok_with_offer_fully_filled_and_removed() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        issue_tokens_and_fund_taker();
+        CreateSwapOfferFixture::default().execute_call().unwrap();
+
+        FillSwapOfferFixture::default()
+            .with_amount(DEFAULT_SWAP_OFFER_AMOUNT)
+            .execute_call()
+            .unwrap();
+
+        assert!(Token::swap_offer_by_id(1u64, 0).is_none());
+        assert_eq!(
+            Token::account_info_by_token_and_member(1u64, member!(2).0).amount,
+            DEFAULT_SWAP_OFFER_AMOUNT
+        );
+        assert_eq!(
+            Token::account_info_by_token_and_member(2u64, member!(2).0).amount,
+            DEFAULT_SPLIT_PARTICIPATION - DEFAULT_SWAP_OFFER_WANTED
+        );
+    })
+}
+
+#[test]
+fn cancel_swap_offer_fails_with_non_existing_offer() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        issue_tokens_and_fund_taker();
+
+        let result = CancelSwapOfferFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::SwapOfferDoesNotExist);
+    })
+}
+
+#[test]
+fn cancel_swap_offer_fails_with_invalid_maker_controller() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        issue_tokens_and_fund_taker();
+        CreateSwapOfferFixture::default().execute_call().unwrap();
+
+        let result = CancelSwapOfferFixture::default()
+            .with_sender(member!(2).1)
+            .execute_call();
+
+        assert_err!(
+            result,
+            DispatchError::Other("origin signer not a member controller account")
+        );
+    })
+}
+
+#[test]
+fn cancel_swap_offer_ok_with_escrow_refunded_and_event_deposited() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        issue_tokens_and_fund_taker();
+        CreateSwapOfferFixture::default().execute_call().unwrap();
+        FillSwapOfferFixture::default().execute_call().unwrap();
+
+        CancelSwapOfferFixture::default().execute_call().unwrap();
+
+        assert!(Token::swap_offer_by_id(1u64, 0).is_none());
+        assert_eq!(
+            Token::account_info_by_token_and_member(1u64, member!(1).0).amount,
+            DEFAULT_INITIAL_ISSUANCE - DEFAULT_SWAP_OFFER_FILL
+        );
+        last_event_eq!(RawEvent::SwapOfferCancelled(
+            1u64,
+            0,
+            DEFAULT_SWAP_OFFER_AMOUNT - DEFAULT_SWAP_OFFER_FILL
+        ));
+    })
+}
+
+#[test]
+fn cancel_swap_offer_fails_with_insufficient_joy_balance_for_maker_account() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        issue_tokens_and_fund_taker();
+        CreateSwapOfferFixture::default()
+            .with_amount(DEFAULT_INITIAL_ISSUANCE)
+            .execute_call()
+            .unwrap();
+        Token::dust_account(origin!(member!(1).1), 1u64, member!(1).0).unwrap();
+        BloatBond::<Test>::put(joy!(100));
+
+        let result = CancelSwapOfferFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientJoyBalance);
+    })
+}
+
+#[test]
+fn cancel_swap_offer_ok_with_maker_account_recreated_and_bloat_bond_paid() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        issue_tokens_and_fund_taker();
+        CreateSwapOfferFixture::default()
+            .with_amount(DEFAULT_INITIAL_ISSUANCE)
+            .execute_call()
+            .unwrap();
+        Token::dust_account(origin!(member!(1).1), 1u64, member!(1).0).unwrap();
+        BloatBond::<Test>::put(joy!(100));
+        increase_account_balance(&member!(1).1, ExistentialDeposit::get() + joy!(100));
+
+        CancelSwapOfferFixture::default().execute_call().unwrap();
+
+        let account = Token::account_info_by_token_and_member(1u64, member!(1).0);
+        assert_eq!(account.amount, DEFAULT_INITIAL_ISSUANCE);
+        assert_eq!(account.bloat_bond.amount, joy!(100));
+    })
+}
+
+#[test]
+fn deissue_token_fails_with_open_swap_offer() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        issue_tokens_and_fund_taker();
+        CreateSwapOfferFixture::default()
+            .with_amount(DEFAULT_INITIAL_ISSUANCE)
+            .execute_call()
+            .unwrap();
+        // All the tokens are in the offer escrow, the maker account can be removed
+        Token::dust_account(origin!(member!(1).1), 1u64, member!(1).0).unwrap();
+
+        let result = Token::deissue_token(1u64);
+
+        assert_err!(result, Error::<Test>::CannotDeissueTokenWithOpenSwapOffers);
+    })
+}
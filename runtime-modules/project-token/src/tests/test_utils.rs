@@ -48,6 +48,8 @@ impl TokenDataBuilder {
             revenue_split: self.revenue_split,
             next_revenue_split_id: 0u32,
             revenue_split_rate: self.revenue_split_rate,
+            paused: false,
+            time_weighted_splits: None,
         }
     }
 
@@ -208,8 +210,19 @@ pub fn default_vesting_schedule() -> VestingScheduleOf<Test> {
     }
 }
 
-impl<Balance: Default + Zero, RepayableBloatBond: Default, VestingSchedulesMap: Default>
-    AccountData<Balance, StakingStatus<Balance>, RepayableBloatBond, VestingSchedulesMap>
+impl<
+        Balance: Default + Zero,
+        RepayableBloatBond: Default,
+        VestingSchedulesMap: Default,
+        BalanceCheckpoints: Default,
+    >
+    AccountData<
+        Balance,
+        StakingStatus<Balance>,
+        RepayableBloatBond,
+        VestingSchedulesMap,
+        BalanceCheckpoints,
+    >
 {
     pub fn new_with_amount(amount: Balance) -> Self {
         Self {
@@ -233,12 +246,14 @@ impl<
         BlockNumber: From<u32> + Clone,
         Balance: Zero + From<u32> + Saturating + Clone,
         RepayableBloatBond: Default + Clone,
+        BalanceCheckpoints: Clone,
     >
     AccountData<
         Balance,
         StakingStatus<Balance>,
         RepayableBloatBond,
         BTreeMap<VestingSource, VestingSchedule<BlockNumber, Balance>>,
+        BalanceCheckpoints,
     >
 {
     pub fn with_max_vesting_schedules(
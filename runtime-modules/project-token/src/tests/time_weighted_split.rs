@@ -0,0 +1,370 @@
+#![cfg(test)]
+use frame_support::assert_err;
+
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::types::{
+    BalanceCheckpoint, Joy, RevenueSplitState, TimeWeightedSplitsSettings, Timeline,
+};
+use crate::{last_event_eq, member, Error, RawEvent};
+
+fn time_weighted_split_test_externalities() -> TestExternalities {
+    build_default_test_externalities_with_balances(vec![(
+        member!(1).1,
+        DEFAULT_SPLIT_REVENUE + ExistentialDeposit::get(),
+    )])
+}
+
+#[test]
+fn set_time_weighted_revenue_splits_fails_with_invalid_token_id() {
+    time_weighted_split_test_externalities().execute_with(|| {
+        let result = SetTimeWeightedRevenueSplitsFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenDoesNotExist);
+    })
+}
+
+#[test]
+fn set_time_weighted_revenue_splits_fails_with_zero_lookback() {
+    time_weighted_split_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = SetTimeWeightedRevenueSplitsFixture::default()
+            .with_lookback(Some(0))
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::TimeWeightedSplitsLookbackIsZero);
+    })
+}
+
+#[test]
+fn set_time_weighted_revenue_splits_fails_with_revenue_split_active() {
+    time_weighted_split_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        SetTimeWeightedRevenueSplitsFixture::default()
+            .execute_call()
+            .unwrap();
+        IssueRevenueSplitFixture::default().execute_call().unwrap();
+
+        let result = SetTimeWeightedRevenueSplitsFixture::default()
+            .with_lookback(None)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::RevenueSplitAlreadyActiveForToken);
+    })
+}
+
+#[test]
+fn set_time_weighted_revenue_splits_ok_with_settings_stored_and_event_deposited() {
+    time_weighted_split_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        SetTimeWeightedRevenueSplitsFixture::default()
+            .execute_call()
+            .unwrap();
+
+        let settings = TimeWeightedSplitsSettings {
+            lookback: DEFAULT_TIME_WEIGHTED_SPLITS_LOOKBACK,
+            tracked_since: System::block_number(),
+        };
+        assert_eq!(
+            Token::token_info_by_id(1u64).time_weighted_splits,
+            Some(settings.clone())
+        );
+        last_event_eq!(RawEvent::TimeWeightedSplitsUpdated(1u64, Some(settings)));
+    })
+}
+
+#[test]
+fn set_time_weighted_revenue_splits_ok_with_tracking_period_preserved_on_lookback_update() {
+    time_weighted_split_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        SetTimeWeightedRevenueSplitsFixture::default()
+            .execute_call()
+            .unwrap();
+        let tracked_since = System::block_number();
+        increase_block_number_by(10);
+
+        SetTimeWeightedRevenueSplitsFixture::default()
+            .with_lookback(Some(50))
+            .execute_call()
+            .unwrap();
+
+        assert_eq!(
+            Token::token_info_by_id(1u64).time_weighted_splits,
+            Some(TimeWeightedSplitsSettings {
+                lookback: 50,
+                tracked_since,
+            })
+        );
+    })
+}
+
+#[test]
+fn set_time_weighted_revenue_splits_ok_with_splits_disabled() {
+    time_weighted_split_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        SetTimeWeightedRevenueSplitsFixture::default()
+            .execute_call()
+            .unwrap();
+
+        SetTimeWeightedRevenueSplitsFixture::default()
+            .with_lookback(None)
+            .execute_call()
+            .unwrap();
+
+        assert_eq!(Token::token_info_by_id(1u64).time_weighted_splits, None);
+        last_event_eq!(RawEvent::TimeWeightedSplitsUpdated(1u64, None));
+    })
+}
+
+#[test]
+fn transfer_ok_without_balance_checkpoints_recorded_when_time_weighted_splits_disabled() {
+    time_weighted_split_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        TransferFixture::default().execute_call().unwrap();
+
+        assert!(Token::account_info_by_token_and_member(1u64, member!(1).0)
+            .balance_checkpoints
+            .is_empty());
+        assert!(Token::account_info_by_token_and_member(1u64, member!(2).0)
+            .balance_checkpoints
+            .is_empty());
+    })
+}
+
+#[test]
+fn transfer_ok_with_balance_checkpoints_recorded_when_time_weighted_splits_enabled() {
+    time_weighted_split_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        SetTimeWeightedRevenueSplitsFixture::default()
+            .execute_call()
+            .unwrap();
+        let block = System::block_number();
+
+        TransferFixture::default().execute_call().unwrap();
+
+        assert_eq!(
+            Token::account_info_by_token_and_member(1u64, member!(1).0)
+                .balance_checkpoints
+                .to_vec(),
+            vec![BalanceCheckpoint {
+                block,
+                amount_before: DEFAULT_INITIAL_ISSUANCE,
+            }]
+        );
+        assert_eq!(
+            Token::account_info_by_token_and_member(1u64, member!(2).0)
+                .balance_checkpoints
+                .to_vec(),
+            vec![BalanceCheckpoint {
+                block,
+                amount_before: 0,
+            }]
+        );
+    })
+}
+
+#[test]
+fn transfer_ok_with_stale_balance_checkpoints_dropped() {
+    time_weighted_split_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        SetTimeWeightedRevenueSplitsFixture::default()
+            .with_lookback(Some(2))
+            .execute_call()
+            .unwrap();
+        increase_block_number_by(1);
+        TransferFixture::default().execute_call().unwrap();
+        // the first checkpoint is no longer within the lookback window
+        increase_block_number_by(3);
+        let block = System::block_number();
+
+        TransferFixture::default().execute_call().unwrap();
+
+        assert_eq!(
+            Token::account_info_by_token_and_member(1u64, member!(2).0)
+                .balance_checkpoints
+                .to_vec(),
+            vec![BalanceCheckpoint {
+                block,
+                amount_before: DEFAULT_SPLIT_PARTICIPATION,
+            }]
+        );
+    })
+}
+
+#[test]
+fn transfer_ok_with_oldest_balance_checkpoints_merged_when_limit_reached() {
+    time_weighted_split_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        SetTimeWeightedRevenueSplitsFixture::default()
+            .execute_call()
+            .unwrap();
+        increase_block_number_by(1);
+        let first_block = System::block_number();
+
+        // MaxBalanceCheckpoints + 1 transfers in different blocks
+        for _ in 0..=MaxBalanceCheckpoints::get() {
+            TransferFixture::default().execute_call().unwrap();
+            increase_block_number_by(1);
+        }
+
+        // oldest checkpoint dropped, the lower of the two balances kept
+        assert_eq!(
+            Token::account_info_by_token_and_member(1u64, member!(2).0)
+                .balance_checkpoints
+                .to_vec(),
+            vec![
+                BalanceCheckpoint {
+                    block: first_block + 1,
+                    amount_before: 0,
+                },
+                BalanceCheckpoint {
+                    block: first_block + 2,
+                    amount_before: 2 * DEFAULT_SPLIT_PARTICIPATION,
+                },
+                BalanceCheckpoint {
+                    block: first_block + 3,
+                    amount_before: 3 * DEFAULT_SPLIT_PARTICIPATION,
+                },
+            ]
+        );
+    })
+}
+
+#[test]
+fn transfer_ok_with_significant_balance_changes_kept_when_limit_reached_by_dust_transfers() {
+    time_weighted_split_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        SetTimeWeightedRevenueSplitsFixture::default()
+            .execute_call()
+            .unwrap();
+        increase_block_number_by(1);
+        let first_block = System::block_number();
+        TransferFixture::default().execute_call().unwrap();
+
+        // MaxBalanceCheckpoints dust transfers in different blocks
+        for _ in 0..MaxBalanceCheckpoints::get() {
+            increase_block_number_by(1);
+            TransferFixture::default()
+                .with_amount(1)
+                .execute_call()
+                .unwrap();
+        }
+
+        // the closest balances merged, the balance held before the first transfer kept
+        assert_eq!(
+            Token::account_info_by_token_and_member(1u64, member!(2).0)
+                .balance_checkpoints
+                .to_vec(),
+            vec![
+                BalanceCheckpoint {
+                    block: first_block,
+                    amount_before: 0,
+                },
+                BalanceCheckpoint {
+                    block: first_block + 2,
+                    amount_before: DEFAULT_SPLIT_PARTICIPATION,
+                },
+                BalanceCheckpoint {
+                    block: first_block + 3,
+                    amount_before: DEFAULT_SPLIT_PARTICIPATION + 2,
+                },
+            ]
+        );
+    })
+}
+
+#[test]
+fn issue_split_ok_with_eligibility_window_limited_to_tracking_period() {
+    time_weighted_split_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        increase_block_number_by(DEFAULT_TIME_WEIGHTED_SPLITS_LOOKBACK);
+        SetTimeWeightedRevenueSplitsFixture::default()
+            .execute_call()
+            .unwrap();
+        let tracked_since = System::block_number();
+        increase_block_number_by(10);
+
+        IssueRevenueSplitFixture::default().execute_call().unwrap();
+
+        assert!(matches!(
+            Token::token_info_by_id(1u64).revenue_split,
+            RevenueSplitState::Active(info) if info.eligibility_window == Some(Timeline {
+                start: tracked_since,
+                duration: 10,
+            })
+        ));
+    })
+}
+
+#[test]
+fn participate_in_split_fails_with_tokens_acquired_after_eligibility_window() {
+    time_weighted_split_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        SetTimeWeightedRevenueSplitsFixture::default()
+            .execute_call()
+            .unwrap();
+        increase_block_number_by(DEFAULT_TIME_WEIGHTED_SPLITS_LOOKBACK);
+        // tokens acquired just before the split issuance
+        TransferFixture::default().execute_call().unwrap();
+        IssueRevenueSplitFixture::default().execute_call().unwrap();
+        increase_block_number_by(MIN_REVENUE_SPLIT_TIME_TO_START);
+
+        let result = ParticipateInSplitFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::RevenueSplitEligibleAmountIsZero);
+    })
+}
+
+#[test]
+fn participate_in_split_ok_with_dividend_based_on_time_weighted_balance() {
+    time_weighted_split_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        SetTimeWeightedRevenueSplitsFixture::default()
+            .execute_call()
+            .unwrap();
+        // tokens held throughout the second half of the eligibility window
+        increase_block_number_by(DEFAULT_TIME_WEIGHTED_SPLITS_LOOKBACK / 2);
+        TransferFixture::default().execute_call().unwrap();
+        increase_block_number_by(DEFAULT_TIME_WEIGHTED_SPLITS_LOOKBACK / 2);
+        IssueRevenueSplitFixture::default().execute_call().unwrap();
+        increase_block_number_by(MIN_REVENUE_SPLIT_TIME_TO_START);
+
+        ParticipateInSplitFixture::default().execute_call().unwrap();
+
+        assert_eq!(
+            Joy::<Test>::usable_balance(member!(2).1),
+            DEFAULT_SPLIT_JOY_DIVIDEND / 2,
+        );
+        last_event_eq!(RawEvent::UserParticipatedInSplit(
+            1u64,
+            member!(2).0,
+            DEFAULT_SPLIT_PARTICIPATION,
+            DEFAULT_SPLIT_JOY_DIVIDEND / 2,
+            0u32,
+        ));
+    })
+}
+
+#[test]
+fn participate_in_split_ok_with_full_dividend_for_tokens_held_throughout_eligibility_window() {
+    time_weighted_split_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        SetTimeWeightedRevenueSplitsFixture::default()
+            .execute_call()
+            .unwrap();
+        TransferFixture::default().execute_call().unwrap();
+        increase_block_number_by(DEFAULT_TIME_WEIGHTED_SPLITS_LOOKBACK);
+        IssueRevenueSplitFixture::default().execute_call().unwrap();
+        increase_block_number_by(MIN_REVENUE_SPLIT_TIME_TO_START);
+
+        ParticipateInSplitFixture::default().execute_call().unwrap();
+
+        assert_eq!(
+            Joy::<Test>::usable_balance(member!(2).1),
+            DEFAULT_SPLIT_JOY_DIVIDEND,
+        );
+    })
+}
@@ -1,8 +1,10 @@
 use frame_support::dispatch::DispatchResult;
-use sp_runtime::DispatchError;
+use sp_runtime::{DispatchError, Permill};
 use sp_std::vec::Vec;
 
-use crate::types::YearlyRate;
+use crate::types::{
+    AirdropId, AllowanceSpender, BuybackParams, TokenPollId, TokenPollParams, YearlyRate,
+};
 
 pub trait PalletToken<
     TokenId,
@@ -14,6 +16,9 @@ pub trait PalletToken<
     TokenSaleParams,
     UploadContext,
     TransfersWithVesting,
+    AirdropParams,
+    Allowance,
+    Transfers,
 >
 {
     /// Issue token with specified characteristics
@@ -70,12 +75,88 @@ pub trait PalletToken<
         revenue_amount: JoyBalance,
     ) -> Result<JoyBalance, DispatchError>;
 
+    /// Issue a revenue split for the token allocating `split_rate` of `revenue_amount`
+    /// instead of the token's `revenue_split_rate`
+    fn issue_revenue_split_with_rate(
+        token_id: TokenId,
+        start: Option<BlockNumber>,
+        duration: BlockNumber,
+        revenue_source_account: AccountId,
+        revenue_amount: JoyBalance,
+        split_rate: Permill,
+    ) -> Result<JoyBalance, DispatchError>;
+
     /// Finalize split by sending back eventual JOYs leftover
     fn finalize_revenue_split(token_id: TokenId, account_id: AccountId) -> DispatchResult;
 
     /// Finalize creator token sale and recover unsold tokens
     fn finalize_token_sale(token_id: TokenId) -> Result<JoyBalance, DispatchError>;
 
+    /// Issue a token holder poll for the token
+    fn issue_poll(
+        token_id: TokenId,
+        params: TokenPollParams<BlockNumber>,
+    ) -> Result<TokenPollId, DispatchError>;
+
+    /// Issue an airdrop funded from `member_id` account
+    fn issue_airdrop(
+        token_id: TokenId,
+        member_id: MemberId,
+        params: AirdropParams,
+    ) -> Result<AirdropId, DispatchError>;
+
+    /// Set the allowance of `spender` for `member_id` account (revoke it if the amount is 0)
+    fn approve(
+        token_id: TokenId,
+        member_id: MemberId,
+        spender: AllowanceSpender<AccountId, MemberId>,
+        allowance: Allowance,
+    ) -> DispatchResult;
+
+    /// Transfer tokens from `src_member_id` account using the allowance of `spender`
+    fn transfer_from(
+        spender: AllowanceSpender<AccountId, MemberId>,
+        bloat_bond_payer: AccountId,
+        token_id: TokenId,
+        src_member_id: MemberId,
+        outputs: Transfers,
+        metadata: Vec<u8>,
+    ) -> DispatchResult;
+
+    /// Return the unclaimed tokens of an expired airdrop to its tokens source
+    fn recover_unclaimed_airdrop(
+        token_id: TokenId,
+        airdrop_id: AirdropId,
+        bloat_bond_payer: AccountId,
+    ) -> DispatchResult;
+
+    /// Freeze (or unfreeze) `member_id` account
+    fn set_account_frozen_status(
+        token_id: TokenId,
+        member_id: MemberId,
+        frozen: bool,
+    ) -> DispatchResult;
+
+    /// Pause (or unpause) the token
+    fn set_token_paused_status(token_id: TokenId, paused: bool) -> DispatchResult;
+
+    /// Start a buyback program funded from `budget_source_account`
+    fn start_buyback_program(
+        token_id: TokenId,
+        budget_source_account: AccountId,
+        params: BuybackParams<JoyBalance, BlockNumber>,
+    ) -> DispatchResult;
+
+    /// Finalize an ended buyback program by sending back the unspent budget
+    fn finalize_buyback_program(token_id: TokenId, account_id: AccountId) -> DispatchResult;
+
+    /// Enable time-weighted revenue splits with the specified `lookback`
+    /// (or disable them if `lookback` is `None`)
+    fn set_time_weighted_revenue_splits(
+        token_id: TokenId,
+        lookback: Option<BlockNumber>,
+    ) -> DispatchResult;
+
     /// Establish whether the token has an unfinalized revenue split
     fn is_revenue_split_inactive(token_id: TokenId) -> bool;
 
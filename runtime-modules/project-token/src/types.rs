@@ -5,14 +5,16 @@ use frame_support::{
     dispatch::{fmt::Debug, DispatchError, DispatchResult},
     ensure,
     traits::Get,
+    BoundedVec,
 };
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
+use sp_arithmetic::helpers_128bit::multiply_by_rational;
 use sp_arithmetic::traits::{AtLeast32BitUnsigned, One, Saturating, Unsigned, Zero};
 use sp_runtime::{
-    traits::{Convert, Hash, UniqueSaturatedInto},
-    PerThing, Permill, Perquintill, SaturatedConversion,
+    traits::{Convert, Hash, UniqueSaturatedFrom, UniqueSaturatedInto},
+    FixedPointNumber, FixedU128, PerThing, Permill, Perquintill, SaturatedConversion,
 };
 use sp_std::{
     borrow::ToOwned,
@@ -34,7 +36,9 @@ use crate::{errors::Error, Config, RepayableBloatBondOf};
 pub enum VestingSource {
     InitialIssuance,
     Sale(TokenSaleId),
+    SaleTier(TokenSaleId, u32),
     IssuerTransfer(u64),
+    Airdrop(AirdropId),
 }
 
 /// Represent's account's split staking status
@@ -48,11 +52,225 @@ pub struct StakingStatus<Balance> {
     pub(crate) amount: Balance,
 }
 
+/// Represents account's vote in a token holder poll, the voted amount of tokens
+/// stays locked until the vote is withdrawn
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, PartialOrd, Ord, TypeInfo, MaxEncodedLen)]
+pub struct PollVote<Balance> {
+    // identifier for the poll
+    pub(crate) poll_id: TokenPollId,
+
+    // index of the alternative voted for
+    pub(crate) alternative: u32,
+
+    // The amount of tokens locked for the vote
+    pub(crate) amount: Balance,
+}
+
+/// Entity allowed to transfer tokens on behalf of a token holder
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, PartialOrd, Ord, TypeInfo, MaxEncodedLen)]
+pub enum AllowanceSpender<AccountId, MemberId> {
+    /// Any origin signed by the account
+    Account(AccountId),
+
+    /// Any origin signed by the member's controller account
+    Member(MemberId),
+}
+
+/// Amount of tokens a spender is allowed to transfer on behalf of a token holder
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Allowance<Balance, BlockNumber> {
+    /// Amount of tokens that can still be transferred by the spender
+    pub amount: Balance,
+
+    /// Block at which the allowance expires (if any)
+    pub expiry: Option<BlockNumber>,
+}
+
+impl<Balance: PartialOrd + Copy, BlockNumber: PartialOrd + Copy> Allowance<Balance, BlockNumber> {
+    /// Wether the allowance has expired at block `b`
+    pub(crate) fn is_expired(&self, b: BlockNumber) -> bool {
+        self.expiry.map_or(false, |expiry| expiry <= b)
+    }
+
+    /// Ensure `amount` can be spent out of the allowance at block `b`
+    pub(crate) fn ensure_can_spend<T: Config>(
+        &self,
+        b: BlockNumber,
+        amount: Balance,
+    ) -> DispatchResult {
+        ensure!(!self.is_expired(b), Error::<T>::AllowanceExpired);
+        ensure!(self.amount >= amount, Error::<T>::InsufficientAllowance);
+        Ok(())
+    }
+}
+
+/// Asset (and its amount) requested in exchange for the tokens offered in a swap offer
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum SwapAsset<TokenId, Balance, JoyBalance> {
+    /// Amount of another token
+    Token(TokenId, Balance),
+
+    /// Amount of JOY
+    Joy(JoyBalance),
+}
+
+impl<TokenId, Balance: Zero, JoyBalance: Zero> SwapAsset<TokenId, Balance, JoyBalance> {
+    /// Wether the requested amount is zero
+    pub(crate) fn is_zero(&self) -> bool {
+        match self {
+            Self::Token(_, amount) => amount.is_zero(),
+            Self::Joy(amount) => amount.is_zero(),
+        }
+    }
+}
+
+/// Offer to swap an amount of tokens (held in escrow) for another asset
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct SwapOffer<MemberId, Balance, SwapAsset> {
+    /// Member who created the offer (and receives the requested asset)
+    pub maker: MemberId,
+
+    /// Amount of offered tokens still held in escrow
+    pub amount_left: Balance,
+
+    /// Asset still requested in exchange for `amount_left`
+    pub wanted_left: SwapAsset,
+}
+
+impl<MemberId, Balance, TokenId, JoyBalance>
+    SwapOffer<MemberId, Balance, SwapAsset<TokenId, Balance, JoyBalance>>
+where
+    Balance: AtLeast32BitUnsigned + Copy,
+    TokenId: Copy,
+    JoyBalance: AtLeast32BitUnsigned + Copy,
+{
+    /// Ensure `amount` of the offered tokens can be bought and return the amount of tokens
+    /// bought together with the asset to pay for them. The payment is proportional to `amount`
+    /// (rounded up in favor of the maker). A payment covering the whole `wanted_left` buys
+    /// the whole `amount_left`, so that no tokens are left in escrow once nothing is wanted.
+    pub(crate) fn ensure_can_fill<T: Config>(
+        &self,
+        amount: Balance,
+    ) -> Result<(Balance, SwapAsset<TokenId, Balance, JoyBalance>), DispatchError> {
+        ensure!(
+            self.amount_left >= amount,
+            Error::<T>::InsufficientSwapOfferAmountLeft
+        );
+        let (payment, pays_off_offer) = match self.wanted_left {
+            SwapAsset::Token(token_id, wanted) => {
+                let price = self.fill_price(wanted, amount);
+                (SwapAsset::Token(token_id, price), price == wanted)
+            }
+            SwapAsset::Joy(wanted) => {
+                let price = self.fill_price(wanted, amount);
+                (SwapAsset::Joy(price), price == wanted)
+            }
+        };
+        let amount_bought = if pays_off_offer {
+            self.amount_left
+        } else {
+            amount
+        };
+        Ok((amount_bought, payment))
+    }
+
+    // Exact `ceil(wanted * amount / amount_left)`, computed without overflow
+    // as `wanted - floor(wanted * (amount_left - amount) / amount_left)`
+    fn fill_price<Wanted: AtLeast32BitUnsigned + Copy>(
+        &self,
+        wanted: Wanted,
+        amount: Balance,
+    ) -> Wanted {
+        let amount_not_bought = self.amount_left.saturating_sub(amount);
+        let not_paid = multiply_by_rational(
+            wanted.unique_saturated_into(),
+            amount_not_bought.unique_saturated_into(),
+            self.amount_left.unique_saturated_into(),
+        )
+        // only fails for zero `amount_left`: the maker is paid in full
+        .unwrap_or(0);
+        wanted.saturating_sub(Wanted::unique_saturated_from(not_paid))
+    }
+
+    /// Decrease the offer's amounts after `amount` of the offered tokens were bought for `paid`
+    pub(crate) fn process_fill(
+        &mut self,
+        amount: Balance,
+        paid: &SwapAsset<TokenId, Balance, JoyBalance>,
+    ) {
+        self.amount_left = self.amount_left.saturating_sub(amount);
+        self.wanted_left = match (self.wanted_left, paid) {
+            (SwapAsset::Token(token_id, wanted), SwapAsset::Token(_, paid)) => {
+                SwapAsset::Token(token_id, wanted.saturating_sub(*paid))
+            }
+            (SwapAsset::Joy(wanted), SwapAsset::Joy(paid)) => {
+                SwapAsset::Joy(wanted.saturating_sub(*paid))
+            }
+            (wanted_left, _) => wanted_left,
+        };
+    }
+
+    /// Price (in JOY) of a single offered token, `None` if the offer does not request JOY
+    pub(crate) fn joy_unit_price(&self) -> Option<FixedU128> {
+        match self.wanted_left {
+            SwapAsset::Joy(wanted) => Some(
+                FixedU128::checked_from_rational(
+                    wanted.unique_saturated_into(),
+                    self.amount_left.unique_saturated_into(),
+                )
+                .unwrap_or_else(FixedU128::max_value),
+            ),
+            SwapAsset::Token(..) => None,
+        }
+    }
+
+    /// Amount of the offered tokens that can be bought for at most `budget` of JOY
+    /// (zero if the offer does not request JOY)
+    pub(crate) fn amount_affordable_with(&self, budget: JoyBalance) -> Balance {
+        match self.wanted_left {
+            SwapAsset::Joy(wanted) if wanted > budget => {
+                let affordable = multiply_by_rational(
+                    budget.unique_saturated_into(),
+                    self.amount_left.unique_saturated_into(),
+                    wanted.unique_saturated_into(),
+                )
+                // `wanted` is non-zero
+                .unwrap_or(0);
+                Balance::unique_saturated_from(affordable)
+            }
+            SwapAsset::Joy(_) => self.amount_left,
+            SwapAsset::Token(..) => Balance::zero(),
+        }
+    }
+}
+
+/// Represents the amount of tokens held by an account before its balance changed at `block`
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct BalanceCheckpoint<Balance, BlockNumber> {
+    /// Block at which the balance changed
+    pub block: BlockNumber,
+
+    /// Amount of tokens held by the account before the change
+    pub amount_before: Balance,
+}
+
 /// Info for the account
 
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
-pub struct AccountData<Balance, StakingStatus, RepayableBloatBond, VestingSchedules> {
+pub struct AccountData<
+    Balance,
+    StakingStatus,
+    RepayableBloatBond,
+    VestingSchedules,
+    BalanceCheckpoints,
+> {
     /// Map that represents account's vesting schedules indexed by source.
     /// Account's total unvested (locked) balance at current block (b)
     /// can be calculated by summing `v.locks()` of all
@@ -77,6 +295,20 @@ pub struct AccountData<Balance, StakingStatus, RepayableBloatBond, VestingSchedu
     /// The sum of all tokens purchased on the last sale the account participated in
     /// along with the id of that sale.
     pub last_sale_total_purchased_amount: Option<(TokenSaleId, Balance)>,
+
+    /// Account's vote in the token holder poll (if any)
+    pub poll_vote: Option<PollVote<Balance>>,
+
+    /// Number of spending allowances granted by the account
+    pub allowances_number: u32,
+
+    /// Whether the account is frozen (can't transfer, burn, participate in splits
+    /// or purchase on sales)
+    pub frozen: bool,
+
+    /// Account's balance checkpoints ordered by block, only recorded while the token
+    /// has time-weighted revenue splits enabled
+    pub balance_checkpoints: BalanceCheckpoints,
 }
 
 /// Info for the token
@@ -115,6 +347,25 @@ pub struct TokenData<Balance, Hash, BlockNumber, TokenSale, RevenueSplitState> {
 
     /// Latest Token Revenue split (active / inactive)
     pub next_revenue_split_id: RevenueSplitId,
+
+    /// Whether the token is paused (no account can transfer, burn, participate in splits
+    /// or purchase on sales)
+    pub paused: bool,
+
+    /// Time-weighted revenue splits settings (if enabled for the token)
+    pub time_weighted_splits: Option<TimeWeightedSplitsSettings<BlockNumber>>,
+}
+
+/// Settings of time-weighted revenue splits: the split dividend of an account is based
+/// on its time-weighted average balance over `lookback` blocks before the split issuance
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct TimeWeightedSplitsSettings<BlockNumber> {
+    /// Length of the lookback window preceding the split issuance
+    pub lookback: BlockNumber,
+
+    /// Block since which account balance checkpoints are tracked
+    pub tracked_since: BlockNumber,
 }
 
 /// Revenue Split State
@@ -149,11 +400,17 @@ impl<JoyBalance: Saturating + Copy + Zero, BlockNumber: Copy>
         }
     }
 
-    pub(crate) fn activate(&mut self, allocation: JoyBalance, timeline: Timeline<BlockNumber>) {
+    pub(crate) fn activate(
+        &mut self,
+        allocation: JoyBalance,
+        timeline: Timeline<BlockNumber>,
+        eligibility_window: Option<Timeline<BlockNumber>>,
+    ) {
         *self = RevenueSplitState::<_, _>::Active(RevenueSplitInfo {
             allocation,
             timeline,
             dividends_claimed: JoyBalance::zero(),
+            eligibility_window,
         });
     }
 
@@ -187,6 +444,10 @@ pub struct RevenueSplitInfo<JoyBalance, BlockNumber> {
 
     /// Dividends payed out after staking period is over
     pub dividends_claimed: JoyBalance,
+
+    /// Window over which the time-weighted average balance of participants is computed
+    /// (only for splits issued while time-weighted revenue splits are enabled)
+    pub eligibility_window: Option<Timeline<BlockNumber>>,
 }
 
 impl<JoyBalance: Saturating + Zero + Copy, BlockNumber: Copy>
@@ -196,6 +457,24 @@ impl<JoyBalance: Saturating + Zero + Copy, BlockNumber: Copy>
     pub fn leftovers(&self) -> JoyBalance {
         self.allocation.saturating_sub(self.dividends_claimed)
     }
+
+    /// Amount of staked tokens eligible for the split dividend: for time-weighted splits
+    /// it is capped by the time-weighted average balance of the account over the
+    /// eligibility window
+    pub fn eligible_amount<Balance, BalanceAt>(
+        &self,
+        staked_amount: Balance,
+        time_weighted_balance: BalanceAt,
+    ) -> Balance
+    where
+        Balance: Ord,
+        BalanceAt: FnOnce(&Timeline<BlockNumber>) -> Balance,
+    {
+        match self.eligibility_window {
+            Some(ref window) => min(staked_amount, time_weighted_balance(window)),
+            None => staked_amount,
+        }
+    }
 }
 
 /// Defines a range [start, start + duration)
@@ -231,6 +510,219 @@ impl<BlockNumber: Copy + Saturating + PartialOrd> Timeline<BlockNumber> {
     }
 }
 
+/// Parameters for a token buyback program
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct BuybackParams<JoyBalance, BlockNumber> {
+    /// Total JOY budget committed to the program
+    pub budget: JoyBalance,
+
+    /// Maximum price (in JOY) paid for a single token
+    pub max_unit_price: JoyBalance,
+
+    /// Optional starting block, current block is used if not provided
+    pub start: Option<BlockNumber>,
+
+    /// Program duration in blocks, the budget is released linearly over it
+    pub duration: BlockNumber,
+}
+
+/// Token buyback program
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct BuybackProgram<JoyBalance, BlockNumber> {
+    /// Total JOY budget committed to the program (held by the module treasury)
+    pub budget: JoyBalance,
+
+    /// Maximum price (in JOY) paid for a single token
+    pub max_unit_price: JoyBalance,
+
+    /// Amount of JOY spent on buybacks so far
+    pub spent: JoyBalance,
+
+    /// Program timeline [start, start + duration)
+    pub timeline: Timeline<BlockNumber>,
+}
+
+impl<JoyBalance, BlockNumber> BuybackProgram<JoyBalance, BlockNumber>
+where
+    JoyBalance: AtLeast32BitUnsigned + Copy,
+    BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+    /// Amount of JOY released by block `b` that was not spent yet
+    pub(crate) fn available_budget_at_block(&self, b: BlockNumber) -> JoyBalance {
+        if !self.timeline.is_started(b) {
+            return JoyBalance::zero();
+        }
+        let elapsed = min(
+            b.saturating_sub(self.timeline.start),
+            self.timeline.duration,
+        );
+        Permill::from_rational(elapsed, self.timeline.duration)
+            .mul_floor(self.budget)
+            .saturating_sub(self.spent)
+    }
+
+    /// Budget not spent so far
+    pub(crate) fn leftovers(&self) -> JoyBalance {
+        self.budget.saturating_sub(self.spent)
+    }
+
+    /// Wether the program buys tokens offered for `wanted` JOY in exchange for `amount`
+    pub(crate) fn accepts_price(&self, wanted: JoyBalance, amount: JoyBalance) -> bool {
+        wanted <= self.max_unit_price.saturating_mul(amount)
+    }
+
+    pub(crate) fn ensure_ongoing<T: Config>(&self, b: BlockNumber) -> DispatchResult {
+        ensure!(
+            self.timeline.is_ongoing(b),
+            Error::<T>::BuybackProgramNotOngoing
+        );
+        Ok(())
+    }
+
+    pub(crate) fn account_for_buyback(&mut self, cost: JoyBalance) {
+        self.spent = self.spent.saturating_add(cost);
+    }
+}
+
+/// Parameters for a token airdrop
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct AirdropParams<Hash, Balance, BlockNumber> {
+    /// Merkle root of the `(member_id, amount, vesting_schedule_params)` airdrop entries
+    pub commitment: Hash,
+
+    /// Total amount of tokens reserved for the airdrop (taken from the issuer's account)
+    pub total_amount: Balance,
+
+    /// Block at which the airdrop expires (it can only be claimed before this block)
+    pub expiry: BlockNumber,
+}
+
+/// Token airdrop
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Airdrop<Hash, Balance, BlockNumber, MemberId> {
+    /// Merkle root of the `(member_id, amount, vesting_schedule_params)` airdrop entries
+    pub commitment: Hash,
+
+    /// Member whose account funded the airdrop (and receives the unclaimed tokens back)
+    pub tokens_source: MemberId,
+
+    /// Amount of tokens not claimed yet
+    pub amount_left: Balance,
+
+    /// Block at which the airdrop expires
+    pub expiry: BlockNumber,
+}
+
+impl<Hash, Balance: Saturating + PartialOrd + Copy, BlockNumber: PartialOrd, MemberId>
+    Airdrop<Hash, Balance, BlockNumber, MemberId>
+{
+    /// Wether the airdrop has expired at block `b`
+    pub(crate) fn is_expired(&self, b: BlockNumber) -> bool {
+        self.expiry <= b
+    }
+
+    /// Ensure `amount` can be claimed from the airdrop at block `b`
+    pub(crate) fn ensure_can_claim<T: Config>(
+        &self,
+        b: BlockNumber,
+        amount: Balance,
+    ) -> DispatchResult {
+        ensure!(!self.is_expired(b), Error::<T>::AirdropExpired);
+        ensure!(
+            self.amount_left >= amount,
+            Error::<T>::InsufficientAirdropFunds
+        );
+        Ok(())
+    }
+}
+
+/// Parameters for a token holder poll
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct TokenPollParams<BlockNumber> {
+    /// Poll metadata (description of the poll and its alternatives)
+    pub metadata: Vec<u8>,
+
+    /// Number of alternatives to choose from
+    pub alternatives: u32,
+
+    /// Optional starting block, current block is used if not provided
+    pub start: Option<BlockNumber>,
+
+    /// Poll duration in blocks
+    pub duration: BlockNumber,
+}
+
+/// Token holder poll
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct TokenPoll<BlockNumber, Tallies> {
+    /// Identifier of the poll (unique per token)
+    pub poll_id: TokenPollId,
+
+    /// Voting timeline [start, start + duration)
+    pub timeline: Timeline<BlockNumber>,
+
+    /// Amount of tokens voted for each of the alternatives
+    pub tallies: Tallies,
+
+    /// Whether the poll results have been finalized
+    pub finalized: bool,
+
+    /// Alternative with the strictly highest tally (set upon finalization)
+    pub winning_alternative: Option<u32>,
+}
+
+impl<BlockNumber, Balance, MaxAlternatives>
+    TokenPoll<BlockNumber, BoundedVec<Balance, MaxAlternatives>>
+where
+    BlockNumber: Copy + Saturating + PartialOrd,
+    Balance: Copy + Zero + Saturating + Ord,
+    MaxAlternatives: Get<u32>,
+{
+    /// Ensure the poll is open for voting at block `b` and `alternative` is valid
+    pub(crate) fn ensure_can_vote<T: Config>(
+        &self,
+        b: BlockNumber,
+        alternative: u32,
+    ) -> DispatchResult {
+        ensure!(self.timeline.is_ongoing(b), Error::<T>::TokenPollNotOngoing);
+        ensure!(
+            (alternative as usize) < self.tallies.len(),
+            Error::<T>::InvalidTokenPollAlternative
+        );
+        Ok(())
+    }
+
+    /// Increase the tally of `alternative` by `amount`
+    pub(crate) fn account_for_vote(&mut self, alternative: u32, amount: Balance) {
+        if let Some(tally) = self.tallies.get_mut(alternative as usize) {
+            *tally = tally.saturating_add(amount);
+        }
+    }
+
+    /// Alternative with the strictly highest non-zero tally, None in case of a tie
+    pub(crate) fn leading_alternative(&self) -> Option<u32> {
+        let max_tally = self.tallies.iter().copied().max()?;
+        if max_tally.is_zero() {
+            return None;
+        }
+        let mut leaders = self
+            .tallies
+            .iter()
+            .enumerate()
+            .filter(|(_, tally)| **tally == max_tally)
+            .map(|(index, _)| index as u32);
+        match (leaders.next(), leaders.next()) {
+            (Some(winner), None) => Some(winner),
+            _ => None,
+        }
+    }
+}
+
 /// Patronage information, patronage configuration = set of values for its fields
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Default, Debug, TypeInfo, MaxEncodedLen)]
@@ -424,14 +916,77 @@ pub struct TokenSaleParams<JoyBalance, Balance, BlockNumber, VestingSchedulePara
     pub cap_per_member: Option<Balance>,
     /// Optional sale metadata
     pub metadata: Option<Vec<u8>>,
+    /// Additional price tiers, filled sequentially once the initial
+    /// `upper_bound_quantity` tokens (sold at `unit_price`) are sold out
+    pub tiers: Vec<TokenSaleTierParams<JoyBalance, Balance, VestingScheduleParams>>,
+}
+
+impl<JoyBalance, Balance, BlockNumber, VestingScheduleParams>
+    TokenSaleParams<JoyBalance, Balance, BlockNumber, VestingScheduleParams>
+where
+    Balance: Saturating + Copy,
+{
+    /// Total number of tokens on sale (across all tiers)
+    pub fn total_quantity(&self) -> Balance {
+        self.tiers
+            .iter()
+            .fold(self.upper_bound_quantity, |acc, tier| {
+                acc.saturating_add(tier.quantity)
+            })
+    }
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct TokenSaleTierParams<JoyBalance, Balance, VestingScheduleParams> {
+    /// Token's unit price in JOY within the tier
+    pub unit_price: JoyBalance,
+    /// Number of tokens on sale within the tier
+    pub quantity: Balance,
+    /// Optional vesting schedule parameters for tokens purchased within the tier
+    /// (if None: sale's `vesting_schedule_params` are used)
+    pub vesting_schedule_params: Option<VestingScheduleParams>,
 }
 
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
-pub struct TokenSale<JoyBalance, Balance, BlockNumber, VestingScheduleParams, MemberId, AccountId> {
-    /// Token's unit price in JOY
+pub struct TokenSaleTier<JoyBalance, Balance, VestingScheduleParams> {
+    /// Token's unit price in JOY within the tier
     pub unit_price: JoyBalance,
-    /// Number of tokens still on sale (if any)
+    /// Number of tokens still on sale within the tier
+    pub quantity_left: Balance,
+    /// Optional vesting schedule parameters for tokens purchased within the tier
+    /// (if None: sale's `vesting_schedule_params` are used)
+    pub vesting_schedule_params: Option<VestingScheduleParams>,
+}
+
+/// Part of a sale purchase falling into a single sale tier
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TierPurchase<JoyBalance, Balance, VestingScheduleParams> {
+    /// Index of the sale tier
+    pub tier_index: u32,
+    /// Number of tokens purchased within the tier
+    pub amount: Balance,
+    /// Cost of the purchased tokens in JOY
+    pub cost: JoyBalance,
+    /// Vesting source & vesting schedule parameters of the purchased tokens
+    /// (if subject to vesting)
+    pub vesting: Option<(VestingSource, VestingScheduleParams)>,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct TokenSale<
+    JoyBalance,
+    Balance,
+    BlockNumber,
+    VestingScheduleParams,
+    MemberId,
+    AccountId,
+    Tiers,
+> {
+    /// Token's unit price in JOY within the currently active sale tier
+    pub unit_price: JoyBalance,
+    /// Number of tokens still on sale (if any) across all tiers
     pub quantity_left: Balance,
     /// Sum of all JOY tokens collected from sale participants
     pub funds_collected: JoyBalance,
@@ -450,6 +1005,8 @@ pub struct TokenSale<JoyBalance, Balance, BlockNumber, VestingScheduleParams, Me
     pub cap_per_member: Option<Balance>,
     /// Whether the sale should be automatically finalized (removed) when `quantity_left == 0`
     pub auto_finalize: bool,
+    /// Sale's price tiers (including the initial one), filled sequentially
+    pub tiers: Tiers,
 }
 
 impl<
@@ -459,8 +1016,17 @@ impl<
         VestingScheduleParams: Default,
         MemberId: Default,
         AccountId,
+        Tiers: Default,
     > Default
-    for TokenSale<JoyBalance, Balance, BlockNumber, VestingScheduleParams, MemberId, AccountId>
+    for TokenSale<
+        JoyBalance,
+        Balance,
+        BlockNumber,
+        VestingScheduleParams,
+        MemberId,
+        AccountId,
+        Tiers,
+    >
 {
     fn default() -> Self {
         Self {
@@ -474,11 +1040,12 @@ impl<
             vesting_schedule_params: None,
             cap_per_member: Default::default(),
             auto_finalize: Default::default(),
+            tiers: Default::default(),
         }
     }
 }
 
-impl<JoyBalance, Balance, BlockNumber, MemberId, AccountId>
+impl<JoyBalance, Balance, BlockNumber, MemberId, AccountId, MaxTiers>
     TokenSale<
         JoyBalance,
         Balance,
@@ -486,10 +1053,24 @@ impl<JoyBalance, Balance, BlockNumber, MemberId, AccountId>
         VestingScheduleParams<BlockNumber>,
         MemberId,
         AccountId,
+        BoundedVec<
+            TokenSaleTier<JoyBalance, Balance, VestingScheduleParams<BlockNumber>>,
+            MaxTiers,
+        >,
     >
 where
+    JoyBalance: Saturating + Copy,
     BlockNumber: Saturating + Zero + Copy + Clone + PartialOrd,
-    Balance: Saturating + Clone + Copy + From<u32> + Unsigned + TryInto<u32> + TryInto<u64> + Ord,
+    Balance: Saturating
+        + Clone
+        + Copy
+        + From<u32>
+        + Unsigned
+        + TryInto<u32>
+        + TryInto<u64>
+        + Ord
+        + Into<JoyBalance>,
+    MaxTiers: Get<u32>,
 {
     pub(crate) fn try_from_params<T: Config>(
         params: TokenSaleParamsOf<T>,
@@ -525,20 +1106,135 @@ where
             Error::<T>::SaleUnitPriceIsZero
         );
 
+        // Ensure all additional tiers have non-zero quantity and unit_price
+        for tier in params.tiers.iter() {
+            ensure!(!tier.quantity.is_zero(), Error::<T>::SaleTierQuantityIsZero);
+            ensure!(!tier.unit_price.is_zero(), Error::<T>::SaleUnitPriceIsZero);
+        }
+
+        // Initial tier is vested according to sale's `vesting_schedule_params`
+        let initial_tier = TokenSaleTier {
+            unit_price: params.unit_price,
+            quantity_left: params.upper_bound_quantity,
+            vesting_schedule_params: None,
+        };
+        let tiers = sp_std::iter::once(initial_tier)
+            .chain(params.tiers.iter().map(|tier| TokenSaleTier {
+                unit_price: tier.unit_price,
+                quantity_left: tier.quantity,
+                vesting_schedule_params: tier.vesting_schedule_params.clone(),
+            }))
+            .collect::<Vec<_>>()
+            .try_into()
+            .map_err(|_| Error::<T>::MaxSaleTiersExceeded)?;
+
         Ok(TokenSale {
             start_block,
             duration: params.duration,
             unit_price: params.unit_price,
-            quantity_left: params.upper_bound_quantity,
+            quantity_left: params.total_quantity(),
             vesting_schedule_params: params.vesting_schedule_params,
             tokens_source: member_id,
             cap_per_member: params.cap_per_member,
             earnings_destination,
             funds_collected: <T as balances::Config>::Balance::zero(),
             auto_finalize,
+            tiers,
         })
     }
 
+    /// Split a purchase of `amount` tokens between the sale tiers (filled sequentially)
+    ///
+    /// Tokens from tiers with their own `vesting_schedule_params` are vested under
+    /// `VestingSource::SaleTier(sale_id, tier_index)`, tokens from the remaining tiers
+    /// are vested according to sale's `vesting_schedule_params` (if any)
+    /// under `VestingSource::Sale(sale_id)`
+    pub(crate) fn split_purchase<T: Config>(
+        &self,
+        sale_id: TokenSaleId,
+        amount: Balance,
+    ) -> Result<
+        Vec<TierPurchase<JoyBalance, Balance, VestingScheduleParams<BlockNumber>>>,
+        DispatchError,
+    > {
+        let mut unprocessed = amount;
+        let mut purchases = Vec::new();
+        for (tier_index, tier) in (0u32..).zip(self.tiers.iter()) {
+            let tier_amount = min(unprocessed, tier.quantity_left);
+            if tier_amount.is_zero() {
+                continue;
+            }
+            unprocessed = unprocessed.saturating_sub(tier_amount);
+            let vesting = match tier.vesting_schedule_params.as_ref() {
+                Some(vsp) => Some((VestingSource::SaleTier(sale_id, tier_index), vsp.clone())),
+                None => self
+                    .vesting_schedule_params
+                    .as_ref()
+                    .map(|vsp| (VestingSource::Sale(sale_id), vsp.clone())),
+            };
+            purchases.push(TierPurchase {
+                tier_index,
+                amount: tier_amount,
+                cost: tier.unit_price.saturating_mul(tier_amount.into()),
+                vesting,
+            });
+        }
+
+        // Ensure enough tokens are available on sale
+        ensure!(unprocessed.is_zero(), Error::<T>::NotEnoughTokensOnSale);
+
+        Ok(purchases)
+    }
+
+    /// Get vesting schedules of the vested parts of a purchase
+    /// (parts sharing the same vesting source are covered by a single schedule)
+    pub(crate) fn get_purchase_vesting_schedules(
+        &self,
+        purchases: &[TierPurchase<JoyBalance, Balance, VestingScheduleParams<BlockNumber>>],
+    ) -> Vec<(VestingSource, VestingSchedule<BlockNumber, Balance>)> {
+        let mut vested =
+            BTreeMap::<VestingSource, (Balance, VestingScheduleParams<BlockNumber>)>::new();
+        for purchase in purchases.iter() {
+            if let Some((source, vsp)) = purchase.vesting.as_ref() {
+                vested
+                    .entry(source.clone())
+                    .and_modify(|(amount, _)| *amount = amount.saturating_add(purchase.amount))
+                    .or_insert_with(|| (purchase.amount, vsp.clone()));
+            }
+        }
+        vested
+            .into_iter()
+            .map(|(source, (amount, vsp))| {
+                (
+                    source,
+                    VestingSchedule::<BlockNumber, Balance>::from_params(
+                        self.end_block(),
+                        amount,
+                        vsp,
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    /// Apply a purchase to the sale: update quantities left, collected funds
+    /// and the unit price of the currently active tier
+    pub(crate) fn process_purchase(
+        &mut self,
+        purchases: &[TierPurchase<JoyBalance, Balance, VestingScheduleParams<BlockNumber>>],
+    ) {
+        for purchase in purchases.iter() {
+            if let Some(tier) = self.tiers.get_mut(purchase.tier_index as usize) {
+                tier.quantity_left = tier.quantity_left.saturating_sub(purchase.amount);
+            }
+            self.quantity_left = self.quantity_left.saturating_sub(purchase.amount);
+            self.funds_collected = self.funds_collected.saturating_add(purchase.cost);
+        }
+        if let Some(active_tier) = self.tiers.iter().find(|t| !t.quantity_left.is_zero()) {
+            self.unit_price = active_tier.unit_price;
+        }
+    }
+
     pub(crate) fn end_block(&self) -> BlockNumber {
         self.start_block.saturating_add(self.duration)
     }
@@ -697,7 +1393,10 @@ pub enum MerkleSide {
 }
 
 /// Yearly rate used for patronage info initialization
-#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, Copy, Default, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(
+    Encode, Decode, Clone, PartialEq, Eq, Debug, Copy, PartialOrd, Default, TypeInfo, MaxEncodedLen,
+)]
 pub struct YearlyRate(pub Permill);
 
 /// Block rate used for patronage accounting
@@ -829,8 +1528,20 @@ impl<Balance: Zero, VestingScheduleParams> Default
 }
 
 /// Default trait for AccountData
-impl<Balance: Zero, StakingStatus, RepayableBloatBond: Default, VestingSchedules: Default> Default
-    for AccountData<Balance, StakingStatus, RepayableBloatBond, VestingSchedules>
+impl<
+        Balance: Zero,
+        StakingStatus,
+        RepayableBloatBond: Default,
+        VestingSchedules: Default,
+        BalanceCheckpoints: Default,
+    > Default
+    for AccountData<
+        Balance,
+        StakingStatus,
+        RepayableBloatBond,
+        VestingSchedules,
+        BalanceCheckpoints,
+    >
 {
     fn default() -> Self {
         Self {
@@ -840,18 +1551,31 @@ impl<Balance: Zero, StakingStatus, RepayableBloatBond: Default, VestingSchedules
             bloat_bond: RepayableBloatBond::default(),
             next_vesting_transfer_id: 0,
             last_sale_total_purchased_amount: None,
+            poll_vote: None,
+            allowances_number: 0,
+            frozen: false,
+            balance_checkpoints: BalanceCheckpoints::default(),
         }
     }
 }
 
 /// Helper for ConfigAccountData -> AccountData conversion
-impl<Balance, StakingStatus, RepayableBloatBond, VestingSchedule, MaxVestingSchedules>
+impl<
+        Balance,
+        StakingStatus,
+        RepayableBloatBond,
+        VestingSchedule,
+        MaxVestingSchedules,
+        Checkpoint,
+        MaxBalanceCheckpoints,
+    >
     TryFrom<
         AccountData<
             Balance,
             StakingStatus,
             RepayableBloatBond,
             BTreeMap<VestingSource, VestingSchedule>,
+            Vec<Checkpoint>,
         >,
     >
     for AccountData<
@@ -859,9 +1583,11 @@ impl<Balance, StakingStatus, RepayableBloatBond, VestingSchedule, MaxVestingSche
         StakingStatus,
         RepayableBloatBond,
         BoundedBTreeMap<VestingSource, VestingSchedule, MaxVestingSchedules>,
+        BoundedVec<Checkpoint, MaxBalanceCheckpoints>,
     >
 where
     MaxVestingSchedules: Get<u32>,
+    MaxBalanceCheckpoints: Get<u32>,
 {
     type Error = ();
 
@@ -871,6 +1597,7 @@ where
             StakingStatus,
             RepayableBloatBond,
             BTreeMap<VestingSource, VestingSchedule>,
+            Vec<Checkpoint>,
         >,
     ) -> Result<Self, Self::Error> {
         let converted = Self {
@@ -880,13 +1607,28 @@ where
             bloat_bond: data.bloat_bond,
             next_vesting_transfer_id: data.next_vesting_transfer_id,
             last_sale_total_purchased_amount: data.last_sale_total_purchased_amount,
+            poll_vote: data.poll_vote,
+            allowances_number: data.allowances_number,
+            frozen: data.frozen,
+            balance_checkpoints: data.balance_checkpoints.try_into().map_err(|_| ())?,
         };
         Ok(converted)
     }
 }
 
-impl<Balance: Default + Zero, RepayableBloatBond: Default, VestingSchedulesMap: Default>
-    AccountData<Balance, StakingStatus<Balance>, RepayableBloatBond, VestingSchedulesMap>
+impl<
+        Balance: Default + Zero,
+        RepayableBloatBond: Default,
+        VestingSchedulesMap: Default,
+        BalanceCheckpoints: Default,
+    >
+    AccountData<
+        Balance,
+        StakingStatus<Balance>,
+        RepayableBloatBond,
+        VestingSchedulesMap,
+        BalanceCheckpoints,
+    >
 {
     pub fn new_with_amount_and_bond(amount: Balance, bloat_bond: RepayableBloatBond) -> Self {
         Self {
@@ -897,12 +1639,13 @@ impl<Balance: Default + Zero, RepayableBloatBond: Default, VestingSchedulesMap:
     }
 }
 
-impl<Balance, BlockNumber, RepayableBloatBond, MaxVestingSchedules>
+impl<Balance, BlockNumber, RepayableBloatBond, MaxVestingSchedules, MaxBalanceCheckpoints>
     AccountData<
         Balance,
         StakingStatus<Balance>,
         RepayableBloatBond,
         BoundedBTreeMap<VestingSource, VestingSchedule<BlockNumber, Balance>, MaxVestingSchedules>,
+        BoundedVec<BalanceCheckpoint<Balance, BlockNumber>, MaxBalanceCheckpoints>,
     >
 where
     Balance: Clone
@@ -915,10 +1658,19 @@ where
         + PartialOrd
         + Ord
         + TryInto<u64>
+        + sp_std::ops::Div<Output = Balance>
         + Copy,
-    BlockNumber: Copy + Clone + PartialOrd + Ord + Saturating + From<u32> + Unsigned,
+    BlockNumber: Copy
+        + Clone
+        + PartialOrd
+        + Ord
+        + Saturating
+        + From<u32>
+        + Unsigned
+        + UniqueSaturatedInto<u32>,
     RepayableBloatBond: Default,
     MaxVestingSchedules: Get<u32>,
+    MaxBalanceCheckpoints: Get<u32>,
 {
     pub fn new_with_vesting_and_bond<T: Config>(
         source: VestingSource,
@@ -1002,13 +1754,83 @@ where
         self.split_staking_status = None;
     }
 
+    /// Determine wether user can lock `amount` of tokens voting in poll `poll_id`
+    pub fn ensure_can_vote_in_poll<T: Config>(
+        &self,
+        amount: Balance,
+        poll_id: TokenPollId,
+    ) -> DispatchResult {
+        if let Some(vote) = self.poll_vote.as_ref() {
+            ensure!(
+                vote.poll_id != poll_id,
+                Error::<T>::UserAlreadyVotedInTokenPoll
+            );
+        }
+
+        ensure!(
+            self.amount >= amount,
+            Error::<T>::InsufficientBalanceForTokenPollVote
+        );
+        Ok(())
+    }
+
+    /// Ensure user has a vote in any poll
+    pub fn ensure_account_is_poll_voter<T: Config>(
+        &self,
+    ) -> Result<PollVote<Balance>, DispatchError> {
+        self.poll_vote
+            .clone()
+            .ok_or_else(|| Error::<T>::UserNotVotingInAnyTokenPoll.into())
+    }
+
+    /// Set self.poll_vote to Some(..)
+    pub fn lock_for_poll(&mut self, poll_id: TokenPollId, alternative: u32, amount: Balance) {
+        self.poll_vote = Some(PollVote {
+            poll_id,
+            alternative,
+            amount,
+        });
+    }
+
+    /// Set self.poll_vote to None
+    pub fn unlock_poll_tokens(&mut self) {
+        self.poll_vote = None;
+    }
+
+    /// Ensure the account can grant one more spending allowance
+    pub fn ensure_can_add_allowance<T: Config>(&self) -> DispatchResult {
+        ensure!(
+            self.allowances_number < T::MaxAllowancesPerAccount::get(),
+            Error::<T>::MaxAllowancesPerAccountReached
+        );
+        Ok(())
+    }
+
+    /// Ensure the account is not frozen
+    pub fn ensure_not_frozen<T: Config>(&self) -> DispatchResult {
+        ensure!(!self.frozen, Error::<T>::AccountFrozen);
+        Ok(())
+    }
+
+    /// Increase the number of allowances granted by the account
+    pub fn increment_allowances_number(&mut self) {
+        self.allowances_number = self.allowances_number.saturating_add(1);
+    }
+
+    /// Decrease the number of allowances granted by the account
+    pub fn decrement_allowances_number(&mut self) {
+        self.allowances_number = self.allowances_number.saturating_sub(1);
+    }
+
     /// Calculate account's transferrable balance at block `b`
     pub fn transferrable<T: Config<Balance = Balance, BlockNumber = BlockNumber>>(
         &self,
         b: BlockNumber,
     ) -> Balance {
-        self.amount
-            .saturating_sub(max(self.unvested::<T>(b), self.staked()))
+        self.amount.saturating_sub(max(
+            self.unvested::<T>(b),
+            max(self.staked(), self.locked_for_poll()),
+        ))
     }
 
     pub fn staked(&self) -> Balance {
@@ -1017,6 +1839,12 @@ where
             .map_or(Balance::zero(), |info| info.amount)
     }
 
+    pub fn locked_for_poll(&self) -> Balance {
+        self.poll_vote
+            .as_ref()
+            .map_or(Balance::zero(), |vote| vote.amount)
+    }
+
     pub fn ensure_can_add_or_update_vesting_schedule<
         T: Config<Balance = Balance, BlockNumber = BlockNumber>,
     >(
@@ -1090,6 +1918,112 @@ where
         self.amount = self.amount.saturating_sub(amount);
     }
 
+    /// Record the amount of tokens held by the account before its balance changes at block `b`
+    /// (only if balance checkpoints are tracked, in which case the balances held before
+    /// block `relevant_since` are no longer needed)
+    pub fn checkpoint_balance(
+        &mut self,
+        amount_before: Balance,
+        b: BlockNumber,
+        relevant_since: Option<BlockNumber>,
+    ) {
+        let relevant_since = match relevant_since {
+            Some(relevant_since) => relevant_since,
+            None => return,
+        };
+
+        // Only the balance held before the first change within a block is relevant
+        if self
+            .balance_checkpoints
+            .last()
+            .map_or(false, |checkpoint| checkpoint.block == b)
+        {
+            return;
+        }
+
+        // Checkpoints up to `relevant_since` can't affect the balance held within any eligibility
+        // window still to be evaluated (this also drops the checkpoints recorded before
+        // the tracking was (re)enabled)
+        let mut checkpoints = self
+            .balance_checkpoints
+            .iter()
+            .filter(|checkpoint| checkpoint.block > relevant_since)
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut amount_before = amount_before;
+
+        // If the limit is reached, merge the two consecutive balances that differ the least:
+        // the balance held before the later checkpoint is conservatively set to the lower
+        // of the two balances. This way a series of small balance changes (ie. dust transfers
+        // to the account) can't erase the significant changes from the account history.
+        if !checkpoints.is_empty() && checkpoints.len() >= MaxBalanceCheckpoints::get() as usize {
+            let balances = checkpoints
+                .iter()
+                .map(|checkpoint| checkpoint.amount_before)
+                .chain(sp_std::iter::once(amount_before))
+                .collect::<Vec<_>>();
+            let merged = balances
+                .windows(2)
+                .enumerate()
+                .min_by_key(|(_, pair)| max(pair[0], pair[1]).saturating_sub(min(pair[0], pair[1])))
+                .map_or(0, |(index, _)| index);
+            let removed = checkpoints.remove(merged);
+            match checkpoints.get_mut(merged) {
+                Some(next) => next.amount_before = min(next.amount_before, removed.amount_before),
+                None => amount_before = min(amount_before, removed.amount_before),
+            }
+        }
+
+        checkpoints.push(BalanceCheckpoint {
+            block: b,
+            amount_before,
+        });
+        self.balance_checkpoints = checkpoints.try_into().unwrap_or_default();
+    }
+
+    /// Amount of tokens held by the account at block `b` according to its balance checkpoints
+    pub fn balance_at(&self, b: BlockNumber) -> Balance {
+        self.balance_checkpoints
+            .iter()
+            .find(|checkpoint| checkpoint.block > b)
+            .map_or(self.amount, |checkpoint| checkpoint.amount_before)
+    }
+
+    /// Time-weighted average amount of tokens held by the account over the `window`
+    pub fn time_weighted_balance(&self, window: &Timeline<BlockNumber>) -> Balance {
+        let duration: u32 = window.duration.saturated_into();
+        if duration == 0 {
+            return self.balance_at(window.start);
+        }
+
+        let end = window.end();
+        let mut cursor = window.start;
+        let mut weighted_sum = Balance::zero();
+        for checkpoint in self
+            .balance_checkpoints
+            .iter()
+            .filter(|checkpoint| checkpoint.block > window.start)
+        {
+            // Account held `checkpoint.amount_before` tokens in [cursor, checkpoint.block)
+            let segment_end = min(checkpoint.block, end);
+            let blocks: u32 = segment_end.saturating_sub(cursor).saturated_into();
+            weighted_sum = weighted_sum.saturating_add(
+                checkpoint
+                    .amount_before
+                    .saturating_mul(Balance::from(blocks)),
+            );
+            cursor = segment_end;
+            if cursor >= end {
+                break;
+            }
+        }
+        let blocks: u32 = end.saturating_sub(cursor).saturated_into();
+        weighted_sum =
+            weighted_sum.saturating_add(self.amount.saturating_mul(Balance::from(blocks)));
+
+        weighted_sum / Balance::from(duration)
+    }
+
     /// Ensure that given amount of tokens can be transferred from the account at block `b`
     pub fn ensure_can_transfer<T: Config<Balance = Balance, BlockNumber = BlockNumber>>(
         &self,
@@ -1104,22 +2038,24 @@ where
     }
 
     /// Process changes related to new sale purchase
-    pub fn process_sale_purchase<T: Config>(
+    /// (`vesting_schedules` include the schedules of all vested parts of the purchase)
+    pub fn process_sale_purchase<T: Config<Balance = Balance, BlockNumber = BlockNumber>>(
         &mut self,
         sale_id: TokenSaleId,
         amount: Balance,
-        vesting_schedule: Option<VestingSchedule<BlockNumber, Balance>>,
-        vesting_cleanup_key: Option<VestingSource>,
+        vesting_schedules: Vec<(VestingSource, VestingSchedule<BlockNumber, Balance>)>,
+        b: BlockNumber,
     ) -> Result<&mut Self, DispatchError> {
-        if let Some(vs) = vesting_schedule {
-            self.add_or_update_vesting_schedule::<T>(
-                VestingSource::Sale(sale_id),
-                vs,
-                vesting_cleanup_key,
-            )?;
-        } else {
-            self.increase_amount_by(amount);
+        let mut vested_amount = Balance::zero();
+        for (source, vs) in vesting_schedules {
+            // Ensure vesting schedule can added if doesn't already exist
+            // (MaxVestingSchedulesPerAccountPerToken not exceeded)
+            let cleanup_key =
+                self.ensure_can_add_or_update_vesting_schedule::<T>(b, source.clone())?;
+            vested_amount = vested_amount.saturating_add(vs.total_amount());
+            self.add_or_update_vesting_schedule::<T>(source, vs, cleanup_key)?;
         }
+        self.increase_amount_by(amount.saturating_sub(vested_amount));
         self.last_sale_total_purchased_amount = match self.last_sale_total_purchased_amount {
             Some((last_sale_id, tokens_purchased)) if last_sale_id == sale_id => {
                 Some((last_sale_id, tokens_purchased.saturating_add(amount)))
@@ -1168,6 +2104,10 @@ where
         if let Some(staking_status) = self.split_staking_status.as_mut() {
             staking_status.amount = staking_status.amount.saturating_sub(amount);
         }
+        // Reduce amount of tokens locked for a poll vote by the burned amount
+        if let Some(poll_vote) = self.poll_vote.as_mut() {
+            poll_vote.amount = poll_vote.amount.saturating_sub(amount);
+        }
         // Reduce account's total tokens amount by the burned amount
         self.decrease_amount_by(amount);
 
@@ -1208,6 +2148,12 @@ where
         Ok(())
     }
 
+    // ensure token is not paused
+    pub(crate) fn ensure_not_paused<T: Config>(&self) -> DispatchResult {
+        ensure!(!self.paused, Error::<T>::TokenPaused);
+        Ok(())
+    }
+
     // increment account number
     pub(crate) fn increment_accounts_number(&mut self) {
         self.accounts_number = self.accounts_number.saturating_add(1u64);
@@ -1218,13 +2164,20 @@ where
         self.accounts_number = self.accounts_number.saturating_sub(1u64);
     }
 
+    // tally patronage accrued so far and decrease total supply by the burned amount
+    pub(crate) fn burn_supply_at_block(&mut self, amount: Balance, block: BlockNumber) {
+        let unclaimed_patronage = self.unclaimed_patronage_at_block(block);
+        self.set_unclaimed_tally_patronage_at_block(unclaimed_patronage, block);
+        self.decrease_supply_by(amount);
+    }
+
     pub fn set_unclaimed_tally_patronage_at_block(&mut self, amount: Balance, block: BlockNumber) {
         self.patronage_info.last_unclaimed_patronage_tally_block = block;
         self.patronage_info.unclaimed_patronage_tally_amount = amount;
     }
 
     /// Computes: period * rate * supply + tally
-    pub(crate) fn unclaimed_patronage_at_block(&self, block: BlockNumber) -> Balance {
+    pub fn unclaimed_patronage_at_block(&self, block: BlockNumber) -> Balance {
         let blocks = block.saturating_sub(self.patronage_info.last_unclaimed_patronage_tally_block);
         let unclaimed_patronage_percent = self.patronage_info.rate.for_period(blocks);
         unclaimed_patronage_percent
@@ -1244,8 +2197,10 @@ where
         &mut self,
         allocation: JoyBalance,
         timeline: Timeline<BlockNumber>,
+        eligibility_window: Option<Timeline<BlockNumber>>,
     ) {
-        self.revenue_split.activate(allocation, timeline);
+        self.revenue_split
+            .activate(allocation, timeline, eligibility_window);
         self.next_revenue_split_id = self
             .next_revenue_split_id
             .saturating_add(RevenueSplitId::one());
@@ -1255,6 +2210,55 @@ where
         self.revenue_split.deactivate()
     }
 
+    /// Block since which account balance checkpoints are tracked
+    /// (if time-weighted revenue splits are enabled)
+    pub fn balance_checkpoints_tracked_since(&self) -> Option<BlockNumber> {
+        self.time_weighted_splits
+            .as_ref()
+            .map(|settings| settings.tracked_since)
+    }
+
+    /// Block up to which the account balance checkpoints recorded at block `b` are no longer
+    /// needed (if time-weighted revenue splits are enabled): the eligibility windows of
+    /// the active revenue split and of the splits issued from block `b` on start after it
+    pub fn balance_checkpoints_relevant_since(&self, b: BlockNumber) -> Option<BlockNumber> {
+        self.revenue_split_eligibility_window(b)
+            .map(|window| match &self.revenue_split {
+                RevenueSplitState::Active(RevenueSplitInfo {
+                    eligibility_window: Some(active_window),
+                    ..
+                }) => min(window.start, active_window.start),
+                _ => window.start,
+            })
+    }
+
+    /// Eligibility window of a revenue split issued at block `b` (if time-weighted revenue
+    /// splits are enabled): `lookback` blocks preceding `b`, limited to the period
+    /// during which balance checkpoints were tracked
+    pub(crate) fn revenue_split_eligibility_window(
+        &self,
+        b: BlockNumber,
+    ) -> Option<Timeline<BlockNumber>> {
+        self.time_weighted_splits.as_ref().map(|settings| {
+            let start = max(b.saturating_sub(settings.lookback), settings.tracked_since);
+            Timeline::from_params(start, b.saturating_sub(start))
+        })
+    }
+
+    /// Enable (or disable if `lookback` is `None`) time-weighted revenue splits at block `b`
+    pub(crate) fn set_time_weighted_splits(
+        &mut self,
+        lookback: Option<BlockNumber>,
+        b: BlockNumber,
+    ) {
+        // Keep the tracking period if time-weighted revenue splits were already enabled
+        let tracked_since = self.balance_checkpoints_tracked_since().unwrap_or(b);
+        self.time_weighted_splits = lookback.map(|lookback| TimeWeightedSplitsSettings {
+            lookback,
+            tracked_since,
+        });
+    }
+
     pub(crate) fn from_params<T: crate::Config>(
         params: TokenIssuanceParametersOf<T>,
     ) -> Result<TokenDataOf<T>, DispatchError> {
@@ -1291,6 +2295,8 @@ where
             next_revenue_split_id: 0,
             // TODO: revenue split rate might be subjected to constraints: https://github.com/Joystream/atlas/issues/2728
             revenue_split_rate: params.revenue_split_rate,
+            paused: false,
+            time_weighted_splits: None,
         })
     }
 }
@@ -1409,6 +2415,7 @@ pub(crate) type AccountDataOf<T> = AccountData<
     StakingStatusOf<T>,
     RepayableBloatBond<<T as frame_system::Config>::AccountId, JoyBalanceOf<T>>,
     VestingSchedulesOf<T>,
+    BalanceCheckpointsOf<T>,
 >;
 
 /// Alias for genesis config Account Data
@@ -1417,6 +2424,7 @@ pub(crate) type ConfigAccountDataOf<T> = AccountData<
     StakingStatusOf<T>,
     RepayableBloatBond<<T as frame_system::Config>::AccountId, JoyBalanceOf<T>>,
     BTreeMap<VestingSource, VestingScheduleOf<T>>,
+    Vec<BalanceCheckpointOf<T>>,
 >;
 
 /// Alias for Token Data
@@ -1471,6 +2479,14 @@ pub type TokenSaleParamsOf<T> = TokenSaleParams<
     VestingScheduleParamsOf<T>,
 >;
 
+/// Alias for TokenSaleTierParams
+pub type TokenSaleTierParamsOf<T> =
+    TokenSaleTierParams<JoyBalanceOf<T>, TokenBalanceOf<T>, VestingScheduleParamsOf<T>>;
+
+/// Alias for TokenSaleTier
+pub type TokenSaleTierOf<T> =
+    TokenSaleTier<JoyBalanceOf<T>, TokenBalanceOf<T>, VestingScheduleParamsOf<T>>;
+
 /// Alias for TokenSale
 pub(crate) type TokenSaleOf<T> = TokenSale<
     JoyBalanceOf<T>,
@@ -1479,6 +2495,7 @@ pub(crate) type TokenSaleOf<T> = TokenSale<
     VestingScheduleParamsOf<T>,
     <T as MembershipTypes>::MemberId,
     <T as frame_system::Config>::AccountId,
+    BoundedVec<TokenSaleTierOf<T>, <T as Config>::MaxSaleTiers>,
 >;
 
 /// Alias for OfferingState
@@ -1493,6 +2510,15 @@ pub(crate) type TokenSaleId = u32;
 /// RevenueSplitId
 pub(crate) type RevenueSplitId = u32;
 
+/// Token holder poll id
+pub type TokenPollId = u32;
+
+/// Token airdrop id
+pub type AirdropId = u64;
+
+/// Token swap offer id
+pub type SwapOfferId = u64;
+
 /// Alias for PaymentWithVesting
 pub type PaymentWithVestingOf<T> =
     PaymentWithVesting<TokenBalanceOf<T>, VestingScheduleParamsOf<T>>;
@@ -1501,8 +2527,7 @@ pub type PaymentWithVestingOf<T> =
 pub(crate) type ValidatedPaymentOf<T> = ValidatedPayment<PaymentWithVestingOf<T>>;
 
 /// Alias for Transfers w/ Payment
-pub(crate) type TransfersOf<T> =
-    Transfers<<T as MembershipTypes>::MemberId, Payment<TokenBalanceOf<T>>>;
+pub type TransfersOf<T> = Transfers<<T as MembershipTypes>::MemberId, Payment<TokenBalanceOf<T>>>;
 
 /// Alias for Transfers w/ PaymentWithVesting
 pub type TransfersWithVestingOf<T> =
@@ -1534,3 +2559,71 @@ pub type VestingSchedulesOf<T> = BoundedBTreeMap<
     VestingScheduleOf<T>,
     <T as Config>::MaxVestingSchedulesPerAccountPerToken,
 >;
+
+/// Alias for BalanceCheckpoint
+pub type BalanceCheckpointOf<T> =
+    BalanceCheckpoint<TokenBalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+/// Alias for bounded balance checkpoints
+pub type BalanceCheckpointsOf<T> =
+    BoundedVec<BalanceCheckpointOf<T>, <T as Config>::MaxBalanceCheckpoints>;
+
+/// Alias for TimeWeightedSplitsSettings
+pub type TimeWeightedSplitsSettingsOf<T> =
+    TimeWeightedSplitsSettings<<T as frame_system::Config>::BlockNumber>;
+
+/// Alias for TokenPollParams
+pub type TokenPollParamsOf<T> = TokenPollParams<<T as frame_system::Config>::BlockNumber>;
+
+/// Alias for TokenPoll
+pub type TokenPollOf<T> = TokenPoll<
+    <T as frame_system::Config>::BlockNumber,
+    BoundedVec<TokenBalanceOf<T>, <T as Config>::MaxPollAlternatives>,
+>;
+
+/// Alias for AirdropParams
+pub type AirdropParamsOf<T> = AirdropParams<
+    <T as frame_system::Config>::Hash,
+    TokenBalanceOf<T>,
+    <T as frame_system::Config>::BlockNumber,
+>;
+
+/// Alias for Airdrop
+pub type AirdropOf<T> = Airdrop<
+    <T as frame_system::Config>::Hash,
+    TokenBalanceOf<T>,
+    <T as frame_system::Config>::BlockNumber,
+    <T as MembershipTypes>::MemberId,
+>;
+
+/// Alias for AllowanceSpender
+pub type AllowanceSpenderOf<T> =
+    AllowanceSpender<<T as frame_system::Config>::AccountId, <T as MembershipTypes>::MemberId>;
+
+/// Alias for Allowance
+pub type AllowanceOf<T> = Allowance<TokenBalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+/// Alias for SwapAsset
+pub type SwapAssetOf<T> = SwapAsset<<T as Config>::TokenId, TokenBalanceOf<T>, JoyBalanceOf<T>>;
+
+/// Alias for SwapOffer
+pub type SwapOfferOf<T> =
+    SwapOffer<<T as MembershipTypes>::MemberId, TokenBalanceOf<T>, SwapAssetOf<T>>;
+
+/// Swap offer filled by a buyback program: offer id, offer with its amounts decreased,
+/// maker's controller account, amount of tokens bought and JOY paid
+pub type BuybackFillOf<T> = (
+    SwapOfferId,
+    SwapOfferOf<T>,
+    <T as frame_system::Config>::AccountId,
+    TokenBalanceOf<T>,
+    JoyBalanceOf<T>,
+);
+
+/// Alias for BuybackParams
+pub type BuybackParamsOf<T> =
+    BuybackParams<JoyBalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+/// Alias for BuybackProgram
+pub type BuybackProgramOf<T> =
+    BuybackProgram<JoyBalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
@@ -52,6 +52,16 @@ pub trait WeightInfo {
 	fn participate_in_split() -> Weight;
 	fn exit_revenue_split() -> Weight;
 	fn burn() -> Weight;
+	fn vote_in_token_poll() -> Weight;
+	fn unlock_token_poll_vote() -> Weight;
+	fn finalize_token_poll(_a: u32, ) -> Weight;
+	fn claim_airdrop(_h: u32, ) -> Weight;
+	fn approve() -> Weight;
+	fn transfer_from(_o: u32, _m: u32, ) -> Weight;
+	fn create_swap_offer() -> Weight;
+	fn fill_swap_offer() -> Weight;
+	fn cancel_swap_offer() -> Weight;
+	fn execute_buyback(_n: u32, ) -> Weight;
 }
 
 /// Weights for project_token using the Substrate node and recommended hardware.
@@ -130,6 +140,118 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Token TokenInfoById (r:1 w:0)
+	// Storage: Token TokenPollByTokenId (r:1 w:1)
+	// Storage: Token AccountInfoByTokenAndMember (r:1 w:1)
+	fn vote_in_token_poll() -> Weight {
+		(46_520_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Token TokenInfoById (r:1 w:0)
+	// Storage: Token AccountInfoByTokenAndMember (r:1 w:1)
+	// Storage: Token TokenPollByTokenId (r:1 w:0)
+	fn unlock_token_poll_vote() -> Weight {
+		(41_870_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Token TokenInfoById (r:1 w:0)
+	// Storage: Token TokenPollByTokenId (r:1 w:1)
+	fn finalize_token_poll(a: u32, ) -> Weight {
+		(24_310_000 as Weight)
+			// Standard Error: 6_000
+			.saturating_add((312_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Token TokenInfoById (r:1 w:1)
+	// Storage: Token AirdropById (r:1 w:1)
+	// Storage: Token AirdropClaimed (r:1 w:1)
+	// Storage: Token AccountInfoByTokenAndMember (r:1 w:1)
+	// Storage: Token BloatBond (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn claim_airdrop(h: u32, ) -> Weight {
+		(92_415_000 as Weight)
+			// Standard Error: 21_000
+			.saturating_add((1_027_000 as Weight).saturating_mul(h as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Token TokenInfoById (r:1 w:0)
+	// Storage: Token AccountInfoByTokenAndMember (r:1 w:1)
+	// Storage: Token AllowanceByTokenAndMember (r:1 w:1)
+	fn approve() -> Weight {
+		(38_914_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Membership MembershipById (r:2 w:0)
+	// Storage: Token AllowanceByTokenAndMember (r:1 w:1)
+	// Storage: Token TokenInfoById (r:1 w:1)
+	// Storage: Token AccountInfoByTokenAndMember (r:2 w:2)
+	// Storage: Token BloatBond (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn transfer_from(o: u32, m: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 8_000
+			.saturating_add((12_871_000 as Weight).saturating_mul(o as Weight))
+			// Standard Error: 86_000
+			.saturating_add((1_442_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(o as Weight)))
+	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Token TokenInfoById (r:2 w:0)
+	// Storage: Token AccountInfoByTokenAndMember (r:1 w:1)
+	// Storage: Token NextSwapOfferId (r:1 w:1)
+	// Storage: Token SwapOfferById (r:0 w:1)
+	fn create_swap_offer() -> Weight {
+		(34_517_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Membership MembershipById (r:2 w:0)
+	// Storage: Token TokenInfoById (r:2 w:2)
+	// Storage: Token SwapOfferById (r:1 w:1)
+	// Storage: Token AccountInfoByTokenAndMember (r:4 w:4)
+	// Storage: Token BloatBond (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn fill_swap_offer() -> Weight {
+		(71_204_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: Token TokenInfoById (r:1 w:1)
+	// Storage: Token SwapOfferById (r:1 w:1)
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Token AccountInfoByTokenAndMember (r:1 w:1)
+	fn cancel_swap_offer() -> Weight {
+		(29_368_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Token TokenInfoById (r:1 w:1)
+	// Storage: Token BuybackProgramByTokenId (r:1 w:1)
+	// Storage: Token SwapOfferById (r:1 w:1)
+	// Storage: Token AccountInfoByTokenAndMember (r:1 w:0)
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn execute_buyback(n: u32, ) -> Weight {
+		(27_409_000 as Weight)
+			// Standard Error: 14_000
+			.saturating_add((31_872_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
 }
 
 // Default implementation for tests
@@ -155,4 +277,34 @@ impl WeightInfo for () {
 	fn burn() -> Weight {
 		0
 	}
+	fn vote_in_token_poll() -> Weight {
+		0
+	}
+	fn unlock_token_poll_vote() -> Weight {
+		0
+	}
+	fn finalize_token_poll(a: u32, ) -> Weight {
+		0
+	}
+	fn claim_airdrop(h: u32, ) -> Weight {
+		0
+	}
+	fn approve() -> Weight {
+		0
+	}
+	fn transfer_from(o: u32, m: u32, ) -> Weight {
+		0
+	}
+	fn create_swap_offer() -> Weight {
+		0
+	}
+	fn fill_swap_offer() -> Weight {
+		0
+	}
+	fn cancel_swap_offer() -> Weight {
+		0
+	}
+	fn execute_buyback(n: u32, ) -> Weight {
+		0
+	}
 }
@@ -9,6 +9,7 @@ use balances::Pallet as Balances;
 use common::to_kb;
 use common::working_group::WorkingGroup;
 use common::BalanceKind;
+use constitution::SectionAmendment;
 use content::NftLimitPeriod;
 use frame_benchmarking::{account, benchmarks, Zero};
 use frame_support::sp_runtime::traits::Bounded;
@@ -105,6 +106,7 @@ fn create_proposal_parameters<T: Config + membership::Config>(
         description: vec![0u8; (description_length_kb * 1000).try_into().unwrap()],
         staking_account_id: Some(account_id.clone()),
         exact_execution_block: None,
+        dependencies: Vec::new(),
     };
 
     (account_id, member_id, general_proposal_paramters)
@@ -133,6 +135,7 @@ fn create_proposal_verify<T: Config>(
         Engine::<T>::proposals(proposal_id),
         proposals_engine::Proposal {
             activated_at: System::<T>::block_number(),
+            voting_period_extension: Zero::zero(),
             parameters: Codex::<T>::get_proposal_parameters(&proposal_details),
             proposer_id: member_id,
             status: proposals_engine::ProposalStatus::Active,
@@ -151,7 +154,7 @@ fn create_proposal_verify<T: Config>(
 
     assert_eq!(
         Engine::<T>::proposal_codes(proposal_id),
-        T::ProposalEncoder::encode_proposal(proposal_details.clone()),
+        T::ProposalEncoder::encode_proposal(proposal_id, proposal_details.clone()),
         "Stored proposal code doesn't match"
     );
 
@@ -636,8 +639,10 @@ benchmarks! {
         let (account_id, member_id, general_proposal_paramters) =
             create_proposal_parameters::<T>(t, d);
 
-        let proposal_details =
-            ProposalDetails::AmendConstitution(vec![0u8; (i * 1000).try_into().unwrap()]);
+        let proposal_details = ProposalDetails::AmendConstitution(vec![SectionAmendment::Add(
+            0,
+            vec![0u8; (i * 1000).try_into().unwrap()],
+        )]);
     }: create_proposal(
         RawOrigin::Signed(account_id.clone()),
         general_proposal_paramters.clone(),
@@ -898,6 +903,67 @@ benchmarks! {
             proposal_details
         );
     }
+
+    create_proposal_set_creator_token_frozen_status {
+        let t in 1 .. to_kb(T::TitleMaxLength::get());
+        let d in 1 .. to_kb(T::DescriptionMaxLength::get());
+
+        let (account_id, member_id, general_proposal_parameters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::SetCreatorTokenFrozenStatus(
+            content::CreatorTokenFrozenStatusParameters::<T> {
+                member_id: Some(member_id),
+                frozen: true,
+                ..Default::default()
+            }
+        );
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_parameters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_parameters,
+            proposal_details
+        );
+    }
+
+    amend_proposal {
+        let i in 1 .. MAX_KILOBYTES_METADATA;
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(1, 1);
+
+        Codex::<T>::create_proposal(
+            RawOrigin::Signed(account_id.clone()).into(),
+            general_proposal_paramters,
+            ProposalDetails::Signal(vec![0u8]),
+        ).unwrap();
+
+        let proposal_id = T::ProposalId::from(Engine::<T>::proposal_count());
+        let proposal_details = ProposalDetails::Signal(vec![0u8; (i * 1000).try_into().unwrap()]);
+    }: _(
+        RawOrigin::Signed(account_id.clone()),
+        member_id,
+        proposal_id,
+        proposal_details.clone()
+    )
+    verify {
+        let thread_id = ThreadIdByProposalId::<T>::get(proposal_id);
+        assert_eq!(
+            proposals_discussion::ThreadAmendments::<T>::get(thread_id).len(),
+            1,
+            "Amendment not recorded"
+        );
+
+        assert_last_event::<T>(
+            RawEvent::ProposalAmended(proposal_id, member_id, proposal_details, false).into()
+        );
+    }
 }
 
 #[cfg(test)]
@@ -1080,4 +1146,20 @@ mod tests {
             assert_ok!(ProposalsCodex::test_benchmark_create_proposal_update_channel_payouts());
         });
     }
+
+    #[test]
+    fn test_set_creator_token_frozen_status_proposal() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(
+                ProposalsCodex::test_benchmark_create_proposal_set_creator_token_frozen_status()
+            );
+        });
+    }
+
+    #[test]
+    fn test_amend_proposal() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(ProposalsCodex::test_benchmark_amend_proposal());
+        });
+    }
 }
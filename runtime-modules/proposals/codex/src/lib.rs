@@ -17,6 +17,8 @@
 //! ## Extrinsics
 //!
 //! - [create_proposal](./struct.Module.html#method.create_proposal) - creates proposal
+//! - [amend_proposal](./struct.Module.html#method.amend_proposal) - amends details of an active
+//! proposal
 //!
 //! ### Dependencies:
 //! - [proposals engine](../substrate_proposals_engine_module/index.html)
@@ -30,6 +32,10 @@
 //! ### Notes
 //! The module uses [ProposalEncoder](./trait.ProposalEncoder.html) to encode the proposal using its
 //! details. Encoded byte vector is passed to the _proposals engine_ as serialized executable code.
+//! The `Batch` proposal executes several proposals atomically under a single vote and uses the
+//! strictest parameters of its members.
+//! An active proposal can be amended by its proposer as long as the new details require the same
+//! parameters. Amendments are recorded in the discussion thread history and reset existing votes.
 
 // `decl_module!` does a lot of recursion and requires us to increase the limit to 256.
 #![recursion_limit = "256"]
@@ -65,12 +71,13 @@ mod benchmarking;
 pub mod weights;
 pub use weights::WeightInfo;
 
+use codec::Encode;
 use frame_support::dispatch::DispatchResult;
 use frame_support::traits::Get;
 use frame_support::weights::Weight;
 use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure};
 use sp_arithmetic::traits::Zero;
-use sp_runtime::traits::CheckedAdd;
+use sp_runtime::traits::{CheckedAdd, Hash};
 use sp_runtime::SaturatedConversion;
 use sp_std::clone::Clone;
 use sp_std::collections::btree_set::BTreeSet;
@@ -83,7 +90,7 @@ use common::MemberId;
 use frame_support::traits::Instance;
 use proposals_discussion::ThreadMode;
 use proposals_engine::{
-    BalanceOf, ProposalCreationParameters, ProposalObserver, ProposalParameters,
+    BalanceOf, ProposalCreationParameters, ProposalObserver, ProposalParameters, VotersParameters,
 };
 pub use types::{
     CreateOpeningParameters, FillOpeningParameters, GeneralProposalParams, ProposalDetails,
@@ -265,6 +272,11 @@ pub trait Config:
         ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
     >;
 
+    /// `Set Creator Token Frozen Status` proposal parameters
+    type SetCreatorTokenFrozenStatusProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
     /// Maximum total amount in funding request proposal
     type FundingRequestProposalMaxTotalAmount: Get<BalanceOf<Self>>;
 
@@ -273,6 +285,9 @@ pub trait Config:
 
     /// Max allowed number of validators in set max validator count proposal
     type SetMaxValidatorCountProposalMaxValidators: Get<u32>;
+
+    /// Max number of proposals per batch proposal
+    type BatchProposalMaxProposals: Get<u32>;
 }
 
 /// Specialized alias of GeneralProposalParams
@@ -280,6 +295,7 @@ pub type GeneralProposalParameters<T> = GeneralProposalParams<
     MemberId<T>,
     <T as frame_system::Config>::AccountId,
     <T as frame_system::Config>::BlockNumber,
+    <T as proposals_engine::Config>::ProposalId,
 >;
 
 decl_event! {
@@ -287,7 +303,8 @@ decl_event! {
         GeneralProposalParameters = GeneralProposalParameters<T>,
         ProposalDetailsOf = ProposalDetailsOf<T>,
         <T as proposals_engine::Config>::ProposalId,
-        <T as proposals_discussion::Config>::ThreadId
+        <T as proposals_discussion::Config>::ThreadId,
+        MemberId = MemberId<T>,
     {
         /// A proposal was created
         /// Params:
@@ -296,6 +313,14 @@ decl_event! {
         /// - Proposal Details. Parameter of proposal with a variant for each kind of proposal
         /// - Id of a newly created proposal thread
         ProposalCreated(ProposalId, GeneralProposalParameters, ProposalDetailsOf, ThreadId),
+
+        /// A proposal was amended
+        /// Params:
+        /// - Id of the amended proposal
+        /// - Member Id of the proposer
+        /// - Amended proposal details
+        /// - Whether the existing votes were reset
+        ProposalAmended(ProposalId, MemberId, ProposalDetailsOf, bool),
     }
 }
 
@@ -377,6 +402,18 @@ decl_error! {
         /// Provided proposal id is not valid
         InvalidProposalId,
 
+        /// Invalid number of proposals for 'Batch' proposal.
+        InvalidBatchProposalNumberOfProposals,
+
+        /// 'Batch' proposal cannot contain another 'Batch' proposal.
+        NestedBatchProposal,
+
+        /// Amended proposal details require different proposal parameters.
+        ProposalAmendmentChangesParameters,
+
+        /// Constitution amendment is empty or targets the same section more than once.
+        InvalidConstitutionAmendment,
+
         /// Arithmeic Error
         ArithmeticError,
     }
@@ -485,6 +522,9 @@ decl_module! {
         const UpdateChannelPayoutsProposalParameters:
             ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::UpdateChannelPayoutsProposalParameters::get();
 
+        const SetCreatorTokenFrozenStatusProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::SetCreatorTokenFrozenStatusProposalParameters::get();
+
         /// Maximum total amount in funding request proposal
         const FundingRequestProposalMaxTotalAmount: BalanceOf<T> =
             T::FundingRequestProposalMaxTotalAmount::get();
@@ -497,6 +537,9 @@ decl_module! {
         const SetMaxValidatorCountProposalMaxValidators: u32 =
             T::SetMaxValidatorCountProposalMaxValidators::get();
 
+        /// Max number of proposals per batch proposal
+        const BatchProposalMaxProposals: u32 = T::BatchProposalMaxProposals::get();
+
 
         /// Create a proposal, the type of proposal depends on the `proposal_details` variant
         ///
@@ -509,7 +552,7 @@ decl_module! {
         /// - `I` is the size of any parameter in `proposal_details`
         ///   (in kilobytes if it's metadata)
         /// - DB:
-        ///    - O(1) doesn't depend on the state or parameters
+        ///    - O(D) where D is the number of proposal dependencies
         /// # </weight>
         #[weight = Module::<T>::get_create_proposal_weight(
                 general_proposal_parameters,
@@ -528,7 +571,10 @@ decl_module! {
             // since the encode trait takes a reference to `self`.
             // (Note: this is an useful change since this could be a ~3MB copy in the case of
             // a Runtime Upgrade). See: https://github.com/Joystream/joystream/issues/2161
-            let proposal_code = T::ProposalEncoder::encode_proposal(proposal_details.clone());
+            let proposal_code = T::ProposalEncoder::encode_proposal(
+                <proposals_engine::Module<T>>::next_proposal_id(),
+                proposal_details.clone(),
+            );
 
             let account_id =
                 T::MembershipOriginValidator::ensure_member_controller_account_origin(
@@ -543,6 +589,7 @@ decl_module! {
                 general_proposal_parameters.staking_account_id.clone(),
                 general_proposal_parameters.exact_execution_block,
                 general_proposal_parameters.member_id,
+                &general_proposal_parameters.dependencies,
             )?;
 
             let initial_thread_mode = ThreadMode::Open;
@@ -561,6 +608,7 @@ decl_module! {
                 staking_account_id: general_proposal_parameters.staking_account_id.clone(),
                 encoded_dispatchable_call_code: proposal_code,
                 exact_execution_block: general_proposal_parameters.exact_execution_block,
+                dependencies: general_proposal_parameters.dependencies.clone(),
             };
 
             let proposal_id =
@@ -570,6 +618,59 @@ decl_module! {
 
             Self::deposit_event(RawEvent::ProposalCreated(proposal_id, general_proposal_parameters, proposal_details, discussion_thread_id));
         }
+
+        /// Amend an active proposal with new details. The new details must require the same
+        /// proposal parameters. Existing votes are reset and the amendment is recorded in the
+        /// proposal discussion thread.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (I + V)` where:
+        /// - `I` is the size of the encoded `proposal_details` in kilobytes
+        /// - `V` is the number of votes to reset (council size)
+        /// - DB:
+        ///    - O(V)
+        /// # </weight>
+        #[weight = Module::<T>::get_amend_proposal_weight(proposal_details)]
+        pub fn amend_proposal(
+            origin,
+            member_id: MemberId<T>,
+            proposal_id: T::ProposalId,
+            proposal_details: ProposalDetailsOf<T>,
+        ) {
+            T::MembershipOriginValidator::ensure_member_controller_account_origin(
+                origin,
+                member_id
+            )?;
+
+            Self::ensure_details_checks(&proposal_details)?;
+
+            let proposal =
+                <proposals_engine::Module<T>>::ensure_can_amend_proposal(member_id, proposal_id)?;
+
+            ensure!(
+                Self::get_proposal_parameters(&proposal_details) == proposal.parameters,
+                Error::<T>::ProposalAmendmentChangesParameters
+            );
+
+            let thread_id = Self::thread_id_by_proposal_id(proposal_id);
+            <proposals_discussion::Module<T>>::ensure_can_record_amendment(thread_id)?;
+
+            let proposal_code =
+                T::ProposalEncoder::encode_proposal(proposal_id, proposal_details.clone());
+
+            let votes_reset =
+                <proposals_engine::Module<T>>::amend_proposal(member_id, proposal_id, proposal_code)?;
+
+            <proposals_discussion::Module<T>>::record_amendment(
+                thread_id,
+                T::Hashing::hash_of(&proposal_details),
+                votes_reset,
+            )?;
+
+            Self::deposit_event(RawEvent::ProposalAmended(proposal_id, member_id, proposal_details, votes_reset));
+        }
     }
 }
 
@@ -823,8 +924,19 @@ impl<T: Config> Module<T> {
             ProposalDetails::TerminateWorkingGroupLead(params) => {
                 Self::ensure_valid_lead_worker_id(&params.group, &params.worker_id)?;
             }
-            ProposalDetails::AmendConstitution(..) => {
-                // Note: No checks for this proposal for now
+            ProposalDetails::AmendConstitution(amendments) => {
+                ensure!(
+                    !amendments.is_empty(),
+                    Error::<T>::InvalidConstitutionAmendment
+                );
+
+                let mut section_ids = BTreeSet::new();
+                for amendment in amendments {
+                    ensure!(
+                        section_ids.insert(amendment.section_id()),
+                        Error::<T>::InvalidConstitutionAmendment
+                    );
+                }
             }
             ProposalDetails::CancelWorkingGroupLeadOpening(opening_id, wg) => {
                 Self::ensure_valid_lead_opening_id(wg, opening_id)?;
@@ -866,6 +978,25 @@ impl<T: Config> Module<T> {
                     );
                 }
             }
+            ProposalDetails::SetCreatorTokenFrozenStatus(..) => {
+                // Note: No checks for this proposal for now
+            }
+            ProposalDetails::Batch(batch) => {
+                ensure!(
+                    !batch.is_empty()
+                        && batch.len() <= T::BatchProposalMaxProposals::get() as usize,
+                    Error::<T>::InvalidBatchProposalNumberOfProposals
+                );
+
+                for details in batch {
+                    ensure!(
+                        !matches!(details, ProposalDetails::Batch(..)),
+                        Error::<T>::NestedBatchProposal
+                    );
+
+                    Self::ensure_details_checks(details)?;
+                }
+            }
         }
 
         Ok(())
@@ -933,6 +1064,40 @@ impl<T: Config> Module<T> {
             ProposalDetails::UpdateChannelPayouts(..) => {
                 T::UpdateChannelPayoutsProposalParameters::get()
             }
+            ProposalDetails::SetCreatorTokenFrozenStatus(..) => {
+                T::SetCreatorTokenFrozenStatusProposalParameters::get()
+            }
+            ProposalDetails::Batch(batch) => batch
+                .iter()
+                .map(Self::get_proposal_parameters)
+                .reduce(Self::strictest_proposal_parameters)
+                .unwrap_or_default(),
+        }
+    }
+
+    // Combines the parameters of two proposals into the parameters that are the hardest to pass:
+    // longest periods, highest approval requirements and stake, lowest slashing requirements.
+    fn strictest_proposal_parameters(
+        first: ProposalParameters<T::BlockNumber, BalanceOf<T>>,
+        second: ProposalParameters<T::BlockNumber, BalanceOf<T>>,
+    ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+        ProposalParameters {
+            voting_period: first.voting_period.max(second.voting_period),
+            grace_period: first.grace_period.max(second.grace_period),
+            approval_quorum_percentage: first
+                .approval_quorum_percentage
+                .max(second.approval_quorum_percentage),
+            approval_threshold_percentage: first
+                .approval_threshold_percentage
+                .max(second.approval_threshold_percentage),
+            slashing_quorum_percentage: first
+                .slashing_quorum_percentage
+                .min(second.slashing_quorum_percentage),
+            slashing_threshold_percentage: first
+                .slashing_threshold_percentage
+                .min(second.slashing_threshold_percentage),
+            required_stake: first.required_stake.max(second.required_stake),
+            constitutionality: first.constitutionality.max(second.constitutionality),
         }
     }
 
@@ -940,6 +1105,29 @@ impl<T: Config> Module<T> {
     fn get_create_proposal_weight(
         general: &GeneralProposalParameters<T>,
         details: &ProposalDetailsOf<T>,
+    ) -> Weight {
+        let dependencies_weight = T::DbWeight::get().reads_writes(
+            general.dependencies.len() as u64,
+            general.dependencies.len() as u64,
+        );
+
+        Self::get_proposal_details_weight(general, details).saturating_add(dependencies_weight)
+    }
+
+    // Returns weight for the proposal amendment
+    fn get_amend_proposal_weight(details: &ProposalDetailsOf<T>) -> Weight {
+        let votes_reset_weight = T::DbWeight::get().writes(
+            <T as proposals_engine::Config>::TotalVotersCounter::total_voters_count().into(),
+        );
+
+        WeightInfoCodex::<T>::amend_proposal(to_kb(details.encoded_size().saturated_into()))
+            .saturating_add(votes_reset_weight)
+    }
+
+    // Returns weight for the proposal details according to parameters
+    fn get_proposal_details_weight(
+        general: &GeneralProposalParameters<T>,
+        details: &ProposalDetailsOf<T>,
     ) -> Weight {
         let title_length = general.title.len();
         let description_length = general.description.len();
@@ -1012,9 +1200,9 @@ impl<T: Config> Module<T> {
                     to_kb(description_length.saturated_into()),
                 )
             }
-            ProposalDetails::AmendConstitution(new_constitution) => {
+            ProposalDetails::AmendConstitution(amendments) => {
                 WeightInfoCodex::<T>::create_proposal_amend_constitution(
-                    to_kb(new_constitution.len().saturated_into()),
+                    to_kb(amendments.encoded_size().saturated_into()),
                     to_kb(title_length.saturated_into()),
                     to_kb(description_length.saturated_into()),
                 )
@@ -1094,16 +1282,27 @@ impl<T: Config> Module<T> {
                 )
                 .saturated_into()
             }
+            ProposalDetails::SetCreatorTokenFrozenStatus(..) => {
+                WeightInfoCodex::<T>::create_proposal_set_creator_token_frozen_status(
+                    to_kb(title_length.saturated_into()),
+                    to_kb(description_length.saturated_into()),
+                )
+                .saturated_into()
+            }
+            ProposalDetails::Batch(batch) => batch.iter().fold(0, |weight: Weight, details| {
+                weight.saturating_add(Self::get_proposal_details_weight(general, details))
+            }),
         }
     }
 }
 
 impl<T: Config> ProposalObserver<T> for Module<T> {
     fn proposal_removed(proposal_id: &<T as proposals_engine::Config>::ProposalId) {
-        <ThreadIdByProposalId<T>>::remove(proposal_id);
-
         let thread_id = Self::thread_id_by_proposal_id(proposal_id);
 
+        <ThreadIdByProposalId<T>>::remove(proposal_id);
+
         proposals_discussion::ThreadById::<T>::remove(thread_id);
+        proposals_discussion::ThreadAmendments::<T>::remove(thread_id);
     }
 }
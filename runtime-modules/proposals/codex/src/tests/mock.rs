@@ -312,6 +312,13 @@ impl membership::Config for Test {
         staking_handler::StakingManager<Self, StakingCandidateLockId>;
     type CandidateStake = CandidateStake;
     type DefaultMemberInvitesCount = DefaultMemberInvitesCount;
+    type MemberReputationRules = ();
+    type MaxRecoveryGuardians = ();
+    type RecoveryDelay = ();
+    type RecoveryDeposit = ();
+    type HandleExpiryPeriod = ();
+    type HandleExpiryGracePeriod = ();
+    type MaxUplineReferralLevels = ();
 }
 
 pub struct Wg;
@@ -389,11 +396,15 @@ parameter_types! {
     pub const MaxActiveProposalLimit: u32 = 100;
     pub const LockId: LockIdentifier = [2; 8];
     pub const DispatchableCallCodeMaxLen: u32 = 1024 * 1024;
+    pub const MaxProposalDependencies: u32 = 5;
+    pub const ProposalAmendmentVotingPeriodRule: proposals_engine::AmendmentVotingPeriodRule<u64> =
+        proposals_engine::AmendmentVotingPeriodRule::Restart;
 }
 
 impl proposals_engine::Config for Test {
     type Event = Event;
     type ProposerOriginValidator = ();
+    type MemberReputationHandler = ();
     type CouncilOriginValidator = ();
     type TotalVotersCounter = MockVotersParameters;
     type ProposalId = u32;
@@ -408,6 +419,8 @@ impl proposals_engine::Config for Test {
     type WeightInfo = ();
     type StakingAccountValidator = ();
     type DispatchableCallCodeMaxLen = DispatchableCallCodeMaxLen;
+    type MaxProposalDependencies = MaxProposalDependencies;
+    type AmendmentVotingPeriodRule = ProposalAmendmentVotingPeriodRule;
 }
 
 pub const STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER: u64 = 222;
@@ -458,6 +471,8 @@ parameter_types! {
     pub const PostLengthLimit: u32 = 2000;
     pub const MaxWhiteListSize: u32 = 20;
     pub const PostLifeTime: u64 = 10;
+    pub const MaxThreadAmendments: u32 = 5;
+    pub const MaxReactionsPerPost: u32 = 10;
     pub const PostDeposit: u64 = 100;
     pub const ProposalsDiscussionModuleId: PalletId = PalletId(*b"mo:propo");
 }
@@ -474,6 +489,8 @@ impl proposals_discussion::Config for Test {
     type PostLifeTime = PostLifeTime;
     type PostDeposit = PostDeposit;
     type ModuleId = ProposalsDiscussionModuleId;
+    type MaxThreadAmendments = MaxThreadAmendments;
+    type MaxReactionsPerPost = MaxReactionsPerPost;
 }
 
 pub struct MockVotersParameters;
@@ -504,6 +521,7 @@ impl working_group::Config<ForumWorkingGroupInstance> for Test {
     type StakingHandler = StakingManager<Self, LockId1>;
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
+    type MemberReputationHandler = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
     type WeightInfo = ();
@@ -517,6 +535,7 @@ impl working_group::Config<StorageWorkingGroupInstance> for Test {
     type StakingHandler = StakingManager<Self, LockId2>;
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
+    type MemberReputationHandler = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
     type WeightInfo = ();
@@ -530,6 +549,7 @@ impl working_group::Config<ContentWorkingGroupInstance> for Test {
     type StakingHandler = StakingManager<Self, LockId3>;
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
+    type MemberReputationHandler = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
     type WeightInfo = ();
@@ -543,6 +563,7 @@ impl working_group::Config<OperationsWorkingGroupInstanceAlpha> for Test {
     type StakingHandler = StakingManager<Self, LockId4>;
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
+    type MemberReputationHandler = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
     type WeightInfo = ();
@@ -556,6 +577,7 @@ impl working_group::Config<GatewayWorkingGroupInstance> for Test {
     type StakingHandler = StakingManager<Self, LockId5>;
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
+    type MemberReputationHandler = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
     type WeightInfo = ();
@@ -569,6 +591,7 @@ impl working_group::Config<MembershipWorkingGroupInstance> for Test {
     type StakingHandler = StakingManager<Self, LockId6>;
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
+    type MemberReputationHandler = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
     type WeightInfo = ();
@@ -582,6 +605,7 @@ impl working_group::Config<OperationsWorkingGroupInstanceBeta> for Test {
     type StakingHandler = StakingManager<Self, LockId7>;
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
+    type MemberReputationHandler = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
     type WeightInfo = ();
@@ -595,6 +619,7 @@ impl working_group::Config<OperationsWorkingGroupInstanceGamma> for Test {
     type StakingHandler = StakingManager<Self, LockId8>;
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
+    type MemberReputationHandler = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
     type WeightInfo = ();
@@ -608,6 +633,7 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
     type StakingHandler = StakingManager<Self, LockId9>;
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
+    type MemberReputationHandler = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
     type WeightInfo = ();
@@ -660,9 +686,11 @@ impl crate::Config for Test {
     type VetoProposalProposalParameters = DefaultProposalParameters;
     type UpdateGlobalNftLimitProposalParameters = DefaultProposalParameters;
     type UpdateChannelPayoutsProposalParameters = DefaultProposalParameters;
+    type SetCreatorTokenFrozenStatusProposalParameters = DefaultProposalParameters;
     type FundingRequestProposalMaxTotalAmount = FundingRequestProposalMaxTotalAmount;
     type FundingRequestProposalMaxAccounts = FundingRequestProposalMaxAccounts;
     type SetMaxValidatorCountProposalMaxValidators = SetMaxValidatorCountProposalMaxValidators;
+    type BatchProposalMaxProposals = BatchProposalMaxProposals;
 }
 
 parameter_types! {
@@ -680,6 +708,7 @@ parameter_types! {
     pub const FundingRequestProposalMaxTotalAmount: Balance = 10_000_000_000_000;
     pub const FundingRequestProposalMaxAccounts: u32 = 100;
     pub const SetMaxValidatorCountProposalMaxValidators: u32 = 300;
+    pub const BatchProposalMaxProposals: u32 = 3;
 }
 
 pub type ReferendumInstance = referendum::Instance1;
@@ -689,14 +718,22 @@ impl council::Config for Test {
 
     type Referendum = referendum::Module<Test, ReferendumInstance>;
 
+    type RecallReferendum = referendum::Module<Test, ReferendumInstance>;
+
     type MinNumberOfExtraCandidates = MinNumberOfExtraCandidates;
     type CouncilSize = CouncilSize;
+    type StaggeredSeatsPerCycle = ConstU32<0>;
+    type MaxConsecutiveTerms = ConstU32<0>;
+    type MaxRunnersUp = ConstU32<0>;
     type AnnouncingPeriodDuration = AnnouncingPeriodDuration;
     type IdlePeriodDuration = IdlePeriodDuration;
     type MinCandidateStake = MinCandidateStake;
 
     type CandidacyLock = StakingManager<Self, CandidacyLockId>;
     type CouncilorLock = StakingManager<Self, CouncilorLockId>;
+    type RecallPetitionLock = StakingManager<Self, common::locks::RecallPetitionLockId>;
+    type MinRecallPetitionStake = ConstU64<100>;
+    type RecallPetitionThreshold = ConstU64<10000>;
 
     type ElectedMemberRewardPeriod = ElectedMemberRewardPeriod;
 
@@ -737,6 +774,7 @@ impl referendum::Config<ReferendumInstance> for Test {
     type WeightInfo = ();
 
     type MaxWinnerTargetCount = MaxWinnerTargetCount;
+    type MaxDelegatorsPerDelegate = ConstU32<10>;
 
     fn calculate_vote_power(
         _: &<Self as frame_system::Config>::AccountId,
@@ -782,7 +820,10 @@ impl referendum::Config<ReferendumInstance> for Test {
 }
 
 impl ProposalEncoder<Test> for () {
-    fn encode_proposal(_proposal_details: ProposalDetailsOf<Test>) -> Vec<u8> {
+    fn encode_proposal(
+        _proposal_id: <Test as proposals_engine::Config>::ProposalId,
+        _proposal_details: ProposalDetailsOf<Test>,
+    ) -> Vec<u8> {
         Vec::new()
     }
 }
@@ -12,6 +12,7 @@ use sp_std::iter::FromIterator;
 
 use common::working_group::WorkingGroup;
 use common::BalanceKind;
+use constitution::SectionAmendment;
 use proposals_engine::ProposalParameters;
 use referendum::ReferendumManager;
 use working_group::StakePolicy;
@@ -293,6 +294,7 @@ fn create_signal_proposal_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: None,
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
@@ -301,6 +303,7 @@ fn create_signal_proposal_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters = GeneralProposalParameters::<Test> {
@@ -309,6 +312,7 @@ fn create_signal_proposal_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let proposal_details = ProposalDetails::Signal(b"text".to_vec());
@@ -359,6 +363,7 @@ fn create_signal_proposal_codex_call_fails_without_text() {
             description: b"body".to_vec(),
             staking_account_id: None,
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         assert_eq!(
@@ -381,6 +386,7 @@ fn create_runtime_upgrade_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: None,
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
@@ -389,6 +395,7 @@ fn create_runtime_upgrade_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
         let general_proposal_parameters = GeneralProposalParameters::<Test> {
             member_id: 1,
@@ -396,6 +403,7 @@ fn create_runtime_upgrade_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let proposal_details = ProposalDetails::RuntimeUpgrade(b"wasm".to_vec());
@@ -446,6 +454,7 @@ fn create_upgrade_runtime_proposal_codex_call_fails_with_empty_wasm() {
             description: b"body".to_vec(),
             staking_account_id: None,
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         assert_eq!(
@@ -471,6 +480,7 @@ fn create_funding_request_proposal_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: None,
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
@@ -479,6 +489,7 @@ fn create_funding_request_proposal_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
         let general_proposal_parameters = GeneralProposalParameters::<Test> {
             member_id: 1,
@@ -486,6 +497,7 @@ fn create_funding_request_proposal_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let proposal_details = ProposalDetails::FundingRequest(vec![
@@ -545,6 +557,7 @@ fn create_funding_request_proposal_call_fails_with_zero_balance() {
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let funding_request_proposal_zero_balance =
@@ -573,6 +586,7 @@ fn create_funding_request_proposal_call_fails_with_exceeding_balance() {
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
         let accounts_number = 5u64;
 
@@ -613,6 +627,7 @@ fn create_funding_request_proposal_call_fails_with_incorrect_number_of_accounts(
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         assert_eq!(
@@ -660,6 +675,7 @@ fn create_funding_request_proposal_call_fails_repeated_account() {
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let funding_request_proposal_details = vec![
@@ -690,6 +706,7 @@ fn create_set_max_validator_count_proposal_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: None,
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
@@ -698,6 +715,7 @@ fn create_set_max_validator_count_proposal_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters = GeneralProposalParameters::<Test> {
@@ -706,6 +724,7 @@ fn create_set_max_validator_count_proposal_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let proposal_details = ProposalDetails::SetMaxValidatorCount(4);
@@ -757,6 +776,7 @@ fn create_veto_proposal_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: None,
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
@@ -765,6 +785,7 @@ fn create_veto_proposal_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters = GeneralProposalParameters::<Test> {
@@ -773,6 +794,7 @@ fn create_veto_proposal_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         // Create proposal to be vetoed
@@ -785,6 +807,7 @@ fn create_veto_proposal_common_checks_succeed() {
                 description: b"body".to_vec(),
                 staking_account_id: Some(2),
                 exact_execution_block: None,
+                dependencies: Vec::new(),
             },
             ProposalDetails::Signal(vec![0u8]),
         )
@@ -842,6 +865,7 @@ fn create_veto_proposal_fails_with_invalid_proposal_id() {
             description: b"body".to_vec(),
             staking_account_id: Some(account_id),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         assert_eq!(
@@ -869,6 +893,7 @@ fn create_set_max_validator_count_proposal_failed_with_invalid_validator_count()
             description: b"body".to_vec(),
             staking_account_id: Some(account_id),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         assert_eq!(
@@ -909,6 +934,7 @@ fn run_create_add_working_group_leader_opening_proposal_common_checks_succeed(gr
             description: b"body".to_vec(),
             staking_account_id: None,
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
@@ -917,6 +943,7 @@ fn run_create_add_working_group_leader_opening_proposal_common_checks_succeed(gr
             description: b"body".to_vec(),
             staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
         let general_proposal_parameters = GeneralProposalParameters::<Test> {
             member_id: 1,
@@ -924,6 +951,7 @@ fn run_create_add_working_group_leader_opening_proposal_common_checks_succeed(gr
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let add_opening_parameters = CreateOpeningParameters {
@@ -998,6 +1026,7 @@ fn run_create_fill_working_group_leader_opening_proposal_common_checks_succeed(
             description: b"body".to_vec(),
             staking_account_id: None,
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
@@ -1006,6 +1035,7 @@ fn run_create_fill_working_group_leader_opening_proposal_common_checks_succeed(
             description: b"body".to_vec(),
             staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters = GeneralProposalParameters::<Test> {
@@ -1014,6 +1044,7 @@ fn run_create_fill_working_group_leader_opening_proposal_common_checks_succeed(
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let fill_opening_parameters = FillOpeningParameters {
@@ -1083,6 +1114,7 @@ fn run_create_fill_working_group_leader_opening_proposal_with_invalid_opening_id
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         setup_council(2);
@@ -1126,6 +1158,7 @@ fn run_create_fill_working_group_leader_opening_proposal_with_invalid_applicatio
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         setup_council(2);
@@ -1165,6 +1198,7 @@ fn run_create_update_working_group_budget_proposal_common_checks_succeed(
             description: b"body".to_vec(),
             staking_account_id: None,
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
@@ -1173,6 +1207,7 @@ fn run_create_update_working_group_budget_proposal_common_checks_succeed(
             description: b"body".to_vec(),
             staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters = GeneralProposalParameters::<Test> {
@@ -1181,6 +1216,7 @@ fn run_create_update_working_group_budget_proposal_common_checks_succeed(
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let proposal_details =
@@ -1245,6 +1281,7 @@ fn run_create_decrease_working_group_leader_stake_proposal_common_checks_succeed
             description: b"body".to_vec(),
             staking_account_id: None,
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
@@ -1253,6 +1290,7 @@ fn run_create_decrease_working_group_leader_stake_proposal_common_checks_succeed
             description: b"body".to_vec(),
             staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters = GeneralProposalParameters::<Test> {
@@ -1261,6 +1299,7 @@ fn run_create_decrease_working_group_leader_stake_proposal_common_checks_succeed
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let proposal_details =
@@ -1326,6 +1365,7 @@ fn run_create_slash_working_group_leader_stake_proposal_common_checks_succeed(
             description: b"body".to_vec(),
             staking_account_id: None,
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters = GeneralProposalParameters::<Test> {
@@ -1334,6 +1374,7 @@ fn run_create_slash_working_group_leader_stake_proposal_common_checks_succeed(
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
@@ -1342,6 +1383,7 @@ fn run_create_slash_working_group_leader_stake_proposal_common_checks_succeed(
             description: b"body".to_vec(),
             staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let proposal_details = ProposalDetails::SlashWorkingGroupLead(lead_id, 10, working_group);
@@ -1404,6 +1446,7 @@ fn run_create_slash_working_group_leader_stake_proposal_with_invalid_worker_id_f
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         setup_council(2);
@@ -1528,6 +1571,7 @@ fn run_decrease_stake_with_zero_staking_balance_fails(working_group: WorkingGrou
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         setup_council(2);
@@ -1561,6 +1605,7 @@ fn run_create_decrease_stake_proposal_with_invalid_worker_id_fails(working_group
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         setup_council(2);
@@ -1596,6 +1641,7 @@ fn run_create_set_working_group_leader_reward_proposal_common_checks_succeed(
             description: b"body".to_vec(),
             staking_account_id: None,
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
@@ -1604,6 +1650,7 @@ fn run_create_set_working_group_leader_reward_proposal_common_checks_succeed(
             description: b"body".to_vec(),
             staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters = GeneralProposalParameters::<Test> {
@@ -1612,6 +1659,7 @@ fn run_create_set_working_group_leader_reward_proposal_common_checks_succeed(
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let proposal_details =
@@ -1675,6 +1723,7 @@ fn run_create_set_working_group_leader_reward_proposal_with_invalid_worker_id_fa
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         setup_council(2);
@@ -1712,6 +1761,7 @@ fn run_create_terminate_working_group_leader_role_proposal_common_checks_succeed
             description: b"body".to_vec(),
             staking_account_id: None,
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
@@ -1720,6 +1770,7 @@ fn run_create_terminate_working_group_leader_role_proposal_common_checks_succeed
             description: b"body".to_vec(),
             staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters = GeneralProposalParameters::<Test> {
@@ -1728,6 +1779,7 @@ fn run_create_terminate_working_group_leader_role_proposal_common_checks_succeed
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let terminate_role_parameters = TerminateRoleParameters {
@@ -1797,6 +1849,7 @@ fn run_create_terminate_working_group_leader_role_proposal_with_invalid_worker_i
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         setup_council(2);
@@ -1825,6 +1878,7 @@ fn create_amend_constitution_proposal_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: None,
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters = GeneralProposalParameters::<Test> {
@@ -1833,6 +1887,7 @@ fn create_amend_constitution_proposal_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
@@ -1841,9 +1896,13 @@ fn create_amend_constitution_proposal_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
-        let proposal_details = ProposalDetails::AmendConstitution(b"constitution text".to_vec());
+        let proposal_details = ProposalDetails::AmendConstitution(vec![SectionAmendment::Add(
+            1,
+            b"constitution text".to_vec(),
+        )]);
 
         let proposal_fixture = ProposalTestFixture {
             general_proposal_parameters: general_proposal_parameters.clone(),
@@ -1883,6 +1942,39 @@ fn create_amend_constitution_proposal_common_checks_succeed() {
     });
 }
 
+#[test]
+fn create_amend_constitution_proposal_fails_with_invalid_amendments() {
+    initial_test_ext().execute_with(|| {
+        let general_proposal_parameters = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(1),
+            exact_execution_block: None,
+            dependencies: Vec::new(),
+        };
+
+        let invalid_amendments = vec![
+            Vec::new(),
+            vec![
+                SectionAmendment::Add(1, b"text".to_vec()),
+                SectionAmendment::Remove(1),
+            ],
+        ];
+
+        for amendments in invalid_amendments {
+            assert_eq!(
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters.clone(),
+                    ProposalDetails::AmendConstitution(amendments),
+                ),
+                Err(Error::<Test>::InvalidConstitutionAmendment.into())
+            );
+        }
+    });
+}
+
 #[test]
 fn create_set_council_budget_increment_common_checks_succeed() {
     initial_test_ext().execute_with(|| {
@@ -1892,6 +1984,7 @@ fn create_set_council_budget_increment_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: None,
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters = GeneralProposalParameters::<Test> {
@@ -1900,6 +1993,7 @@ fn create_set_council_budget_increment_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
@@ -1908,6 +2002,7 @@ fn create_set_council_budget_increment_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let proposal_details = ProposalDetails::SetCouncilBudgetIncrement(100);
@@ -1970,6 +2065,7 @@ fn run_create_cancel_working_group_leader_opening_proposal_common_checks_succeed
             description: b"body".to_vec(),
             staking_account_id: None,
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters = GeneralProposalParameters::<Test> {
@@ -1978,6 +2074,7 @@ fn run_create_cancel_working_group_leader_opening_proposal_common_checks_succeed
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
@@ -1986,6 +2083,7 @@ fn run_create_cancel_working_group_leader_opening_proposal_common_checks_succeed
             description: b"body".to_vec(),
             staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let proposal_details =
@@ -2051,6 +2149,7 @@ fn run_create_cancel_working_group_leader_opening_proposal_with_invalid_opening_
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         setup_council(2);
@@ -2078,6 +2177,7 @@ fn create_set_councilor_reward_proposal_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: None,
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters = GeneralProposalParameters::<Test> {
@@ -2086,6 +2186,7 @@ fn create_set_councilor_reward_proposal_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
@@ -2094,6 +2195,7 @@ fn create_set_councilor_reward_proposal_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let proposal_details = ProposalDetails::SetCouncilorReward(100);
@@ -2145,6 +2247,7 @@ fn create_set_initial_invitation_balance_proposal_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: None,
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters = GeneralProposalParameters::<Test> {
@@ -2153,6 +2256,7 @@ fn create_set_initial_invitation_balance_proposal_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
@@ -2161,6 +2265,7 @@ fn create_set_initial_invitation_balance_proposal_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let proposal_details = ProposalDetails::SetInitialInvitationBalance(100);
@@ -2212,6 +2317,7 @@ fn create_set_initial_invitation_count_proposal_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: None,
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters = GeneralProposalParameters::<Test> {
@@ -2220,6 +2326,7 @@ fn create_set_initial_invitation_count_proposal_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
@@ -2228,6 +2335,7 @@ fn create_set_initial_invitation_count_proposal_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let proposal_details = ProposalDetails::SetInitialInvitationCount(100);
@@ -2279,6 +2387,7 @@ fn create_set_membership_lead_invitation_quota_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: None,
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters = GeneralProposalParameters::<Test> {
@@ -2287,6 +2396,7 @@ fn create_set_membership_lead_invitation_quota_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
@@ -2295,6 +2405,7 @@ fn create_set_membership_lead_invitation_quota_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let proposal_details = ProposalDetails::SetMembershipLeadInvitationQuota(100);
@@ -2346,6 +2457,7 @@ fn create_set_referral_cut_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: None,
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters = GeneralProposalParameters::<Test> {
@@ -2354,6 +2466,7 @@ fn create_set_referral_cut_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
@@ -2362,6 +2475,7 @@ fn create_set_referral_cut_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let proposal_details = ProposalDetails::SetReferralCut(100);
@@ -2412,6 +2526,7 @@ fn create_update_global_nft_limit_proposal_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: None,
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters = GeneralProposalParameters::<Test> {
@@ -2420,6 +2535,7 @@ fn create_update_global_nft_limit_proposal_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
@@ -2428,6 +2544,7 @@ fn create_update_global_nft_limit_proposal_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let proposal_details = ProposalDetails::UpdateGlobalNftLimit(NftLimitPeriod::Daily, 100);
@@ -2479,6 +2596,7 @@ fn create_update_channel_payouts_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: None,
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters = GeneralProposalParameters::<Test> {
@@ -2487,6 +2605,7 @@ fn create_update_channel_payouts_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
@@ -2495,6 +2614,7 @@ fn create_update_channel_payouts_common_checks_succeed() {
             description: b"body".to_vec(),
             staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let proposal_details = ProposalDetails::UpdateChannelPayouts(
@@ -2565,6 +2685,7 @@ fn create_update_channel_payouts_proposal_fails_when_min_cashout_exceeds_max_cas
             description: b"body".to_vec(),
             staking_account_id: Some(1),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         let details = ProposalDetailsOf::<Test>::UpdateChannelPayouts(
@@ -2587,3 +2708,454 @@ fn create_update_channel_payouts_proposal_fails_when_min_cashout_exceeds_max_cas
         );
     });
 }
+
+#[test]
+fn create_set_creator_token_frozen_status_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        let general_proposal_parameters_no_staking = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: None,
+            exact_execution_block: None,
+            dependencies: Vec::new(),
+        };
+
+        let general_proposal_parameters = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(1),
+            exact_execution_block: None,
+            dependencies: Vec::new(),
+        };
+
+        let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
+            exact_execution_block: None,
+            dependencies: Vec::new(),
+        };
+
+        let proposal_details = ProposalDetails::SetCreatorTokenFrozenStatus(
+            content::CreatorTokenFrozenStatusParameters::<Test> {
+                channel_id: 1,
+                member_id: None,
+                frozen: true,
+            },
+        );
+
+        let proposal_fixture = ProposalTestFixture {
+            proposal_details: proposal_details.clone(),
+            general_proposal_parameters: general_proposal_parameters.clone(),
+            insufficient_rights_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::None.into(),
+                    general_proposal_parameters_no_staking.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            invalid_stake_account_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters_incorrect_staking.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters_no_staking.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            successful_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            proposal_parameters:
+                <Test as crate::Config>::SetCreatorTokenFrozenStatusProposalParameters::get(),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_batch_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        let general_proposal_parameters_no_staking = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: None,
+            exact_execution_block: None,
+            dependencies: Vec::new(),
+        };
+
+        let general_proposal_parameters = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(1),
+            exact_execution_block: None,
+            dependencies: Vec::new(),
+        };
+
+        let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
+            exact_execution_block: None,
+            dependencies: Vec::new(),
+        };
+
+        let proposal_details = ProposalDetails::Batch(vec![
+            ProposalDetails::Signal(b"text".to_vec()),
+            ProposalDetails::SetMembershipPrice(100),
+        ]);
+
+        let proposal_fixture = ProposalTestFixture {
+            proposal_details: proposal_details.clone(),
+            general_proposal_parameters: general_proposal_parameters.clone(),
+            insufficient_rights_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::None.into(),
+                    general_proposal_parameters_no_staking.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            invalid_stake_account_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters_incorrect_staking.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters_no_staking.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            successful_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            proposal_parameters: <Test as crate::Config>::SignalProposalParameters::get(),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_batch_proposal_fails_with_invalid_batch() {
+    initial_test_ext().execute_with(|| {
+        let general_proposal_parameters = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(1),
+            exact_execution_block: None,
+            dependencies: Vec::new(),
+        };
+
+        let signal = ProposalDetails::Signal(b"text".to_vec());
+        let max_proposals = <Test as crate::Config>::BatchProposalMaxProposals::get() as usize;
+
+        let invalid_batches = vec![
+            (
+                Vec::new(),
+                Error::<Test>::InvalidBatchProposalNumberOfProposals,
+            ),
+            (
+                vec![signal.clone(); max_proposals + 1],
+                Error::<Test>::InvalidBatchProposalNumberOfProposals,
+            ),
+            (
+                vec![signal.clone(), ProposalDetails::Batch(vec![signal.clone()])],
+                Error::<Test>::NestedBatchProposal,
+            ),
+            (
+                vec![signal.clone(), ProposalDetails::Signal(Vec::new())],
+                Error::<Test>::SignalProposalIsEmpty,
+            ),
+        ];
+
+        for (batch, error) in invalid_batches {
+            assert_eq!(
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters.clone(),
+                    ProposalDetails::Batch(batch),
+                ),
+                Err(error.into())
+            );
+        }
+    });
+}
+
+#[test]
+fn batch_proposal_parameters_are_the_strictest_of_its_members() {
+    let lenient = ProposalParameters {
+        voting_period: 10,
+        grace_period: 5,
+        approval_quorum_percentage: 50,
+        approval_threshold_percentage: 80,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 40,
+        required_stake: None,
+        constitutionality: 2,
+    };
+    let strict = ProposalParameters {
+        voting_period: 5,
+        grace_period: 10,
+        approval_quorum_percentage: 80,
+        approval_threshold_percentage: 50,
+        slashing_quorum_percentage: 40,
+        slashing_threshold_percentage: 60,
+        required_stake: Some(100),
+        constitutionality: 1,
+    };
+
+    assert_eq!(
+        ProposalsCodex::strictest_proposal_parameters(lenient, strict),
+        ProposalParameters {
+            voting_period: 10,
+            grace_period: 10,
+            approval_quorum_percentage: 80,
+            approval_threshold_percentage: 80,
+            slashing_quorum_percentage: 40,
+            slashing_threshold_percentage: 40,
+            required_stake: Some(100),
+            constitutionality: 2,
+        }
+    );
+}
+
+#[test]
+fn create_proposal_with_dependencies_succeeds() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let general_proposal_parameters = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(1),
+            exact_execution_block: None,
+            dependencies: Vec::new(),
+        };
+
+        assert_eq!(
+            ProposalsCodex::create_proposal(
+                RawOrigin::Signed(1).into(),
+                general_proposal_parameters.clone(),
+                ProposalDetails::Signal(b"text".to_vec()),
+            ),
+            Ok(())
+        );
+        let dependency_id = ProposalsEngine::proposal_count();
+
+        assert_eq!(
+            ProposalsCodex::create_proposal(
+                RawOrigin::Signed(1).into(),
+                GeneralProposalParameters::<Test> {
+                    dependencies: vec![dependency_id + 1],
+                    ..general_proposal_parameters.clone()
+                },
+                ProposalDetails::Signal(b"text".to_vec()),
+            ),
+            Err(proposals_engine::Error::<Test>::InvalidProposalDependency.into())
+        );
+
+        assert_eq!(
+            ProposalsCodex::create_proposal(
+                RawOrigin::Signed(1).into(),
+                GeneralProposalParameters::<Test> {
+                    dependencies: vec![dependency_id],
+                    ..general_proposal_parameters
+                },
+                ProposalDetails::Signal(b"text".to_vec()),
+            ),
+            Ok(())
+        );
+
+        let proposal_id = ProposalsEngine::proposal_count();
+        assert_eq!(
+            proposals_engine::ProposalDependencies::<Test>::get(proposal_id).into_inner(),
+            vec![dependency_id]
+        );
+    });
+}
+
+fn create_signal_proposal_for_amendment() -> u32 {
+    increase_total_balance_issuance_using_account_id(1, 500000);
+
+    let general_proposal_parameters = GeneralProposalParameters::<Test> {
+        member_id: 1,
+        title: b"title".to_vec(),
+        description: b"body".to_vec(),
+        staking_account_id: Some(1),
+        exact_execution_block: None,
+        dependencies: Vec::new(),
+    };
+
+    assert_eq!(
+        ProposalsCodex::create_proposal(
+            RawOrigin::Signed(1).into(),
+            general_proposal_parameters,
+            ProposalDetails::Signal(b"text".to_vec()),
+        ),
+        Ok(())
+    );
+
+    ProposalsEngine::proposal_count()
+}
+
+#[test]
+fn amend_proposal_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let proposal_id = create_signal_proposal_for_amendment();
+        let thread_id = <crate::ThreadIdByProposalId<Test>>::get(proposal_id);
+
+        run_to_block(3);
+
+        let proposal_details = ProposalDetails::Signal(b"amended text".to_vec());
+        assert_eq!(
+            ProposalsCodex::amend_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                proposal_id,
+                proposal_details.clone(),
+            ),
+            Ok(())
+        );
+
+        assert_eq!(ProposalsEngine::proposals(proposal_id).activated_at, 3);
+        assert_eq!(
+            ProposalsDiscussion::thread_amendments(thread_id).into_inner(),
+            vec![proposals_discussion::ThreadAmendment {
+                amended_at: 3,
+                details_hash: <Test as frame_system::Config>::Hashing::hash_of(&proposal_details),
+                votes_reset: false,
+            }]
+        );
+
+        assert_last_event(
+            RawEvent::ProposalAmended(proposal_id, 1, proposal_details, false).into(),
+        );
+    });
+}
+
+#[test]
+fn amend_proposal_resets_votes() {
+    initial_test_ext().execute_with(|| {
+        let proposal_id = create_signal_proposal_for_amendment();
+        let thread_id = <crate::ThreadIdByProposalId<Test>>::get(proposal_id);
+
+        assert_eq!(
+            ProposalsEngine::vote(
+                RawOrigin::Signed(2).into(),
+                2,
+                proposal_id,
+                proposals_engine::VoteKind::Approve,
+                Vec::new(),
+            ),
+            Ok(())
+        );
+
+        let proposal_details = ProposalDetails::Signal(b"amended text".to_vec());
+        assert_eq!(
+            ProposalsCodex::amend_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                proposal_id,
+                proposal_details.clone(),
+            ),
+            Ok(())
+        );
+
+        assert!(ProposalsEngine::proposals(proposal_id)
+            .voting_results
+            .no_votes_yet());
+        assert!(ProposalsDiscussion::thread_amendments(thread_id)[0].votes_reset);
+
+        assert_last_event(RawEvent::ProposalAmended(proposal_id, 1, proposal_details, true).into());
+    });
+}
+
+#[test]
+fn amend_proposal_fails_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        let proposal_id = create_signal_proposal_for_amendment();
+
+        assert_eq!(
+            ProposalsCodex::amend_proposal(
+                RawOrigin::Signed(2).into(),
+                2,
+                proposal_id,
+                ProposalDetails::Signal(b"amended text".to_vec()),
+            ),
+            Err(proposals_engine::Error::<Test>::NotAuthor.into())
+        );
+
+        assert_eq!(
+            ProposalsCodex::amend_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                proposal_id,
+                ProposalDetails::Signal(Vec::new()),
+            ),
+            Err(Error::<Test>::SignalProposalIsEmpty.into())
+        );
+
+        assert_eq!(
+            ProposalsCodex::amend_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                proposal_id + 1,
+                ProposalDetails::Signal(b"amended text".to_vec()),
+            ),
+            Err(proposals_engine::Error::<Test>::ProposalNotFound.into())
+        );
+    });
+}
+
+#[test]
+fn amend_proposal_fails_with_exceeded_max_amendments() {
+    initial_test_ext().execute_with(|| {
+        let proposal_id = create_signal_proposal_for_amendment();
+
+        for _ in 0..MaxThreadAmendments::get() {
+            assert_eq!(
+                ProposalsCodex::amend_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    proposal_id,
+                    ProposalDetails::Signal(b"amended text".to_vec()),
+                ),
+                Ok(())
+            );
+        }
+
+        assert_eq!(
+            ProposalsCodex::amend_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                proposal_id,
+                ProposalDetails::Signal(b"amended text".to_vec()),
+            ),
+            Err(proposals_discussion::Error::<Test>::MaxThreadAmendmentsExceeded.into())
+        );
+    });
+}
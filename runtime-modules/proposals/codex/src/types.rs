@@ -10,13 +10,17 @@ use common::working_group::WorkingGroup;
 use common::BalanceKind;
 use common::FundingRequestParameters;
 
+use constitution::SectionAmendment;
 use content::NftLimitPeriod;
 use working_group::StakePolicy;
 
 /// Encodes proposal using its details information.
 pub trait ProposalEncoder<T: crate::Config> {
-    /// Encodes proposal using its details information.
-    fn encode_proposal(proposal_details: ProposalDetailsOf<T>) -> Vec<u8>;
+    /// Encodes proposal with the given id using its details information.
+    fn encode_proposal(
+        proposal_id: <T as proposals_engine::Config>::ProposalId,
+        proposal_details: ProposalDetailsOf<T>,
+    ) -> Vec<u8>;
 }
 
 /// _ProposalDetails_ alias for type simplification
@@ -28,6 +32,7 @@ pub type ProposalDetailsOf<T> = ProposalDetails<
     working_group::OpeningId,
     <T as proposals_engine::Config>::ProposalId,
     content::UpdateChannelPayoutsParameters<T>,
+    content::CreatorTokenFrozenStatusParameters<T>,
 >;
 
 /// Proposal details provide voters the information required for the perceived voting.
@@ -41,6 +46,7 @@ pub enum ProposalDetails<
     OpeningId,
     ProposalId,
     UpdateChannelPayoutsParameters,
+    CreatorTokenFrozenStatusParameters,
 > {
     /// The signal of the `Signal` proposal
     Signal(Vec<u8>),
@@ -78,8 +84,9 @@ pub enum ProposalDetails<
     /// Fire the working group leader with possible slashing.
     TerminateWorkingGroupLead(TerminateRoleParameters<WorkerId, Balance>),
 
-    /// `Amend constitution` proposal.
-    AmendConstitution(Vec<u8>),
+    /// `Amend constitution` proposal:
+    /// Amends the constitution sections with the provided section diff.
+    AmendConstitution(Vec<SectionAmendment>),
 
     /// `Cancel Working Group Lead Opening` proposal:
     /// Cancels an opening for a working group leader
@@ -115,6 +122,26 @@ pub enum ProposalDetails<
 
     /// `Update Channel Payouts` proposal
     UpdateChannelPayouts(UpdateChannelPayoutsParameters),
+
+    /// `Batch` proposal: executes all the inner proposals atomically in the provided order.
+    Batch(
+        Vec<
+            ProposalDetails<
+                Balance,
+                BlockNumber,
+                AccountId,
+                WorkerId,
+                OpeningId,
+                ProposalId,
+                UpdateChannelPayoutsParameters,
+                CreatorTokenFrozenStatusParameters,
+            >,
+        >,
+    ),
+
+    /// `Set Creator Token Frozen Status` proposal: freeze (unfreeze) a creator token account
+    /// or pause (unpause) the whole creator token, overriding the channel issuer.
+    SetCreatorTokenFrozenStatus(CreatorTokenFrozenStatusParameters),
 }
 
 impl<
@@ -125,6 +152,7 @@ impl<
         OpeningId,
         ProposalId,
         UpdateChannelPayoutsParameters,
+        CreatorTokenFrozenStatusParameters,
     > Default
     for ProposalDetails<
         Balance,
@@ -134,6 +162,7 @@ impl<
         OpeningId,
         ProposalId,
         UpdateChannelPayoutsParameters,
+        CreatorTokenFrozenStatusParameters,
     >
 {
     fn default() -> Self {
@@ -144,7 +173,7 @@ impl<
 /// Proposal parameters common to all proposals
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Debug, Default, Clone, PartialEq, Eq, TypeInfo)]
-pub struct GeneralProposalParams<MemberId, AccountId, BlockNumber> {
+pub struct GeneralProposalParams<MemberId, AccountId, BlockNumber, ProposalId> {
     /// Member ID of proposer
     pub member_id: MemberId,
 
@@ -159,6 +188,9 @@ pub struct GeneralProposalParams<MemberId, AccountId, BlockNumber> {
 
     /// Intended execution block for the proposal
     pub exact_execution_block: Option<BlockNumber>,
+
+    /// Proposals that must be executed before this proposal can be executed
+    pub dependencies: Vec<ProposalId>,
 }
 
 /// Parameters for the 'terminate the leader position' proposal.
@@ -68,6 +68,8 @@ pub trait WeightInfo {
 	fn create_proposal_set_referral_cut(_t: u32, _d: u32, ) -> Weight;
 	fn create_proposal_update_global_nft_limit(_t: u32, _d: u32, ) -> Weight;
 	fn create_proposal_update_channel_payouts(_t: u32, _d: u32, _i: u32, ) -> Weight;
+	fn create_proposal_set_creator_token_frozen_status(_t: u32, _d: u32, ) -> Weight;
+	fn amend_proposal(_i: u32, ) -> Weight;
 }
 
 /// Weights for proposals_codex using the Substrate node and recommended hardware.
@@ -551,6 +553,40 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(7 as Weight))
 			.saturating_add(T::DbWeight::get().writes(9 as Weight))
 	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: ProposalEngine ActiveProposalCount (r:1 w:1)
+	// Storage: Membership StakingAccountIdMemberStatus (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ProposalDiscussion ThreadCount (r:1 w:1)
+	// Storage: ProposalEngine ProposalCount (r:1 w:1)
+	// Storage: ProposalsCodex ThreadIdByProposalId (r:0 w:1)
+	// Storage: ProposalDiscussion ThreadById (r:0 w:1)
+	// Storage: ProposalEngine Proposals (r:0 w:1)
+	// Storage: ProposalEngine DispatchableCallCode (r:0 w:1)
+	fn create_proposal_set_creator_token_frozen_status(t: u32, d: u32, ) -> Weight {
+		(81_946_000 as Weight)
+			// Standard Error: 17_000
+			.saturating_add((812_000 as Weight).saturating_mul(t as Weight))
+			// Standard Error: 17_000
+			.saturating_add((967_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: ProposalEngine Proposals (r:1 w:1)
+	// Storage: ProposalsCodex ThreadIdByProposalId (r:1 w:0)
+	// Storage: ProposalDiscussion ThreadById (r:1 w:0)
+	// Storage: ProposalDiscussion ThreadAmendments (r:1 w:1)
+	// Storage: ProposalEngine VoteExistsByProposalByVoter (r:0 w:1)
+	// Storage: ProposalEngine DispatchableCallCode (r:0 w:1)
+	fn amend_proposal(i: u32, ) -> Weight {
+		(61_412_000 as Weight)
+			// Standard Error: 4_000
+			.saturating_add((1_297_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
 }
 
 // Default implementation for tests
@@ -624,4 +660,10 @@ impl WeightInfo for () {
 	fn create_proposal_update_channel_payouts(t: u32, d: u32, i: u32, ) -> Weight {
 		0
 	}
+	fn create_proposal_set_creator_token_frozen_status(t: u32, d: u32, ) -> Weight {
+		0
+	}
+	fn amend_proposal(i: u32, ) -> Weight {
+		0
+	}
 }
@@ -6,6 +6,7 @@ use balances::Pallet as Balances;
 use council::Module as Council;
 use frame_benchmarking::{account, benchmarks};
 use frame_support::sp_runtime::traits::Bounded;
+use frame_support::storage::IterableStorageDoubleMap;
 use frame_support::traits::{Currency, OnFinalize, OnInitialize};
 use frame_system::EventRecord;
 use frame_system::Pallet as System;
@@ -73,6 +74,21 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
     assert_eq!(event, &system_event);
 }
 
+// Attaches `reactions` reactions of distinct members to the post.
+fn add_post_reactions<T: Config>(post_id: T::PostId, reactions: u32) {
+    let mut counters = ProposalsDiscussion::<T>::post_reaction_counters_by_post_id(post_id);
+    for id in 0..reactions {
+        let member_id = T::MemberId::from(id.try_into().unwrap());
+        assert!(counters.try_replace(
+            None,
+            Some(PostReaction::Like),
+            T::MaxReactionsPerPost::get()
+        ));
+        PostReactionByMember::<T>::insert(post_id, member_id, PostReaction::Like);
+    }
+    PostReactionCountersByPostId::<T>::insert(post_id, counters);
+}
+
 fn member_account<
     T: common::membership::MembershipTypes + balances::Config + membership::Config,
 >(
@@ -312,6 +328,8 @@ benchmarks! {
     }
 
     delete_post {
+        let r in 0 .. T::MaxReactionsPerPost::get();
+
         // We do this to ignore the id 0 because the `Test` runtime
         // returns 0 as an invalid id but 1 as a valid one
         let (_, _) = member_account::<T>("caller_member", 0);
@@ -336,12 +354,102 @@ benchmarks! {
 
         assert!(PostThreadIdByPostId::<T>::contains_key(thread_id, post_id), "Post not created");
 
+        add_post_reactions::<T>(post_id, r);
+
     }: _ (RawOrigin::Signed(account_id), caller_member_id, post_id, thread_id, true)
     verify {
         assert!(!PostThreadIdByPostId::<T>::contains_key(thread_id, post_id));
+        assert!(PostReactionByMember::<T>::iter_prefix(post_id).next().is_none());
         assert_last_event::<T>(RawEvent::PostDeleted(caller_member_id, thread_id, post_id, true).into());
     }
 
+    react_post {
+        // We do this to ignore the id 0 because the `Test` runtime
+        // returns 0 as an invalid id but 1 as a valid one
+        let (_, _) = member_account::<T>("caller_member", 0);
+        let (account_id, caller_member_id) = member_account::<T>("caller_member", 1);
+
+        let thread_id = ProposalsDiscussion::<T>::create_thread(
+            caller_member_id,
+            ThreadMode::Open
+        ).unwrap();
+
+        ProposalsDiscussion::<T>::add_post(
+            RawOrigin::Signed(account_id.clone()).into(),
+            caller_member_id,
+            thread_id,
+            vec![0u8],
+            true
+        ).unwrap();
+
+        let post_id = T::PostId::from(1);
+
+        // Worst case scenario: previous reaction is replaced
+        ProposalsDiscussion::<T>::react_post(
+            RawOrigin::Signed(account_id.clone()).into(),
+            caller_member_id,
+            thread_id,
+            post_id,
+            Some(PostReaction::Like),
+        ).unwrap();
+    }: _ (RawOrigin::Signed(account_id), caller_member_id, thread_id, post_id, Some(PostReaction::Celebrate))
+    verify {
+        let counters = ProposalsDiscussion::<T>::post_reaction_counters_by_post_id(post_id);
+        assert_eq!(counters.count(PostReaction::Like), 0);
+        assert_eq!(counters.count(PostReaction::Celebrate), 1);
+
+        assert_last_event::<T>(RawEvent::PostReacted(
+                caller_member_id,
+                thread_id,
+                post_id,
+                Some(PostReaction::Celebrate),
+            ).into()
+        );
+    }
+
+    tip_post_author {
+        // We do this to ignore the id 0 because the `Test` runtime
+        // returns 0 as an invalid id but 1 as a valid one
+        let (_, _) = member_account::<T>("member", 0);
+        let (author_account_id, author_member_id) = member_account::<T>("member", 1);
+        let (account_id, caller_member_id) = member_account::<T>("caller_member", 2);
+
+        let thread_id = ProposalsDiscussion::<T>::create_thread(
+            author_member_id,
+            ThreadMode::Open
+        ).unwrap();
+
+        ProposalsDiscussion::<T>::add_post(
+            RawOrigin::Signed(author_account_id).into(),
+            author_member_id,
+            thread_id,
+            vec![0u8],
+            true
+        ).unwrap();
+
+        let post_id = T::PostId::from(1);
+        let amount = T::PostDeposit::get();
+
+        let author_controller_account =
+            T::MembershipInfoProvider::controller_account_id(author_member_id).unwrap();
+        let initial_author_balance = Balances::<T>::free_balance(&author_controller_account);
+    }: _ (RawOrigin::Signed(account_id), caller_member_id, thread_id, post_id, amount)
+    verify {
+        assert_eq!(
+            Balances::<T>::free_balance(&author_controller_account),
+            initial_author_balance + amount
+        );
+
+        assert_last_event::<T>(RawEvent::PostAuthorTipped(
+                caller_member_id,
+                thread_id,
+                post_id,
+                author_member_id,
+                amount,
+            ).into()
+        );
+    }
+
     change_thread_mode {
         let i in 1 .. T::MaxWhiteListSize::get();
 
@@ -407,6 +515,20 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_react_post() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(Discussions::test_benchmark_react_post());
+        });
+    }
+
+    #[test]
+    fn test_tip_post_author() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(Discussions::test_benchmark_tip_post_author());
+        });
+    }
+
     #[test]
     fn test_change_thread_mode() {
         initial_test_ext().execute_with(|| {
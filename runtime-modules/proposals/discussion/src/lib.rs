@@ -14,11 +14,16 @@
 //! - [change_thread_mode](./struct.Module.html#method.change_thread_mode) - changes thread
 //! - [delete_post](./struct.Module.html#method.delete_post) - Removes thread from storage
 //! permission mode
+//! - [react_post](./struct.Module.html#method.react_post) - reacts to an existing post
+//! - [tip_post_author](./struct.Module.html#method.tip_post_author) - tips the author of an
+//! existing post
 //!
 //! ## Public API methods
 //! - [create_thread](./struct.Module.html#method.create_thread) - creates a discussion thread
 //! - [ensure_can_create_thread](./struct.Module.html#method.ensure_can_create_thread) - ensures
 //! safe thread creation
+//! - [record_amendment](./struct.Module.html#method.record_amendment) - records an amendment of
+//! the discussed subject in the thread history
 //!
 //! ## Usage
 //!
@@ -88,9 +93,10 @@ use sp_std::convert::TryInto;
 use sp_std::vec::Vec;
 
 use common::bloat_bond::{RepayableBloatBond, RepayableBloatBondOf};
-use common::costs::{has_sufficient_balance_for_fees, pay_fee};
+use common::costs::{has_sufficient_balance_for_fees, has_sufficient_balance_for_payment, pay_fee};
 use common::council::CouncilOriginValidator;
 use common::membership::{MemberOriginValidator, MembershipInfoProvider};
+use common::post_reactions::{tip_post_author, PostReaction, PostReactionCounters};
 use common::to_kb;
 use common::{MemberId, MembershipTypes};
 pub use types::*;
@@ -109,6 +115,8 @@ decl_event!(
         <T as Config>::ThreadId,
         MemberId = MemberId<T>,
         <T as Config>::PostId,
+        ThreadAmendment = ThreadAmendmentOf<T>,
+        Balance = BalanceOf<T>,
     {
         /// Emits on thread creation.
         ThreadCreated(ThreadId, MemberId),
@@ -124,6 +132,29 @@ decl_event!(
 
         /// Emits on post deleted
         PostDeleted(MemberId, ThreadId, PostId, bool),
+
+        /// Emits on amendment of the discussed subject.
+        /// Params:
+        /// - thread id
+        /// - recorded amendment
+        ThreadAmendmentRecorded(ThreadId, ThreadAmendment),
+
+        /// Emits on post reaction.
+        /// Params:
+        /// - member id
+        /// - thread id
+        /// - post id
+        /// - new reaction (`None` if the reaction was removed)
+        PostReacted(MemberId, ThreadId, PostId, Option<PostReaction>),
+
+        /// Emits on post author tip.
+        /// Params:
+        /// - member id of the tipper
+        /// - thread id
+        /// - post id
+        /// - member id of the post author
+        /// - tip amount
+        PostAuthorTipped(MemberId, ThreadId, PostId, MemberId, Balance),
     }
 );
 
@@ -173,6 +204,12 @@ pub trait Config:
 
     /// Maximum number of blocks before a post can be erased by anyone
     type PostLifeTime: Get<Self::BlockNumber>;
+
+    /// Defines the maximum length of the thread amendment history.
+    type MaxThreadAmendments: Get<u32>;
+
+    /// Defines the maximum number of reactions attached to a single post.
+    type MaxReactionsPerPost: Get<u32>;
 }
 
 decl_error! {
@@ -208,6 +245,24 @@ decl_error! {
 
         /// Account can't delete post at the moment
         CannotDeletePost,
+
+        /// Max allowed number of thread amendments exceeded.
+        MaxThreadAmendmentsExceeded,
+
+        /// Max allowed number of post reactions exceeded.
+        MaxReactionsPerPostExceeded,
+
+        /// Member has already reacted to the post with the same reaction.
+        PostReactionUnchanged,
+
+        /// Tip amount cannot be zero.
+        TipAmountCannotBeZero,
+
+        /// Member cannot tip their own post.
+        CannotTipOwnPost,
+
+        /// Account has insufficient balance to pay the tip.
+        InsufficientBalanceForTip,
     }
 }
 
@@ -228,6 +283,19 @@ decl_storage! { generate_storage_info
 
         /// Count of all posts that have been created.
         pub PostCount get(fn post_count): u64;
+
+        /// Map thread id to the history of amendments of the discussed subject.
+        pub ThreadAmendments get(fn thread_amendments): map hasher(blake2_128_concat)
+            T::ThreadId => ThreadAmendmentsOf<T>;
+
+        /// Map post id to the number of reactions of each kind attached to the post.
+        pub PostReactionCountersByPostId get(fn post_reaction_counters_by_post_id):
+            map hasher(blake2_128_concat) T::PostId => PostReactionCounters;
+
+        /// Map post id and member id to the reaction of the member to the post.
+        pub PostReactionByMember get(fn post_reaction_by_member):
+            double_map hasher(blake2_128_concat) T::PostId, hasher(blake2_128_concat) MemberId<T> =>
+                Option<PostReaction>;
     }
     add_extra_genesis {
         build(|_| {
@@ -257,6 +325,12 @@ decl_module! {
         /// Exports const - maximum number of blocks before a post can be erased by anyone
         const PostLifeTime: T::BlockNumber = T::PostLifeTime::get();
 
+        /// Exports const - maximum length of the thread amendment history.
+        const MaxThreadAmendments: u32 = T::MaxThreadAmendments::get();
+
+        /// Exports const - maximum number of reactions attached to a single post.
+        const MaxReactionsPerPost: u32 = T::MaxReactionsPerPost::get();
+
         /// Adds a post with author origin check.
         ///
         /// <weight>
@@ -324,11 +398,12 @@ decl_module! {
         /// <weight>
         ///
         /// ## Weight
-        /// `O (1)`
+        /// `O (R)` where:
+        /// - `R` is the maximum number of reactions per post
         /// - DB:
-        ///    - O(1) doesn't depend on the state or parameters
+        ///    - O(R)
         /// # </weight>
-        #[weight = WeightInfoDiscussion::<T>::delete_post()]
+        #[weight = WeightInfoDiscussion::<T>::delete_post(T::MaxReactionsPerPost::get())]
         pub fn delete_post(
             origin,
             deleter_id: MemberId<T>,
@@ -364,6 +439,8 @@ decl_module! {
             post.cleanup_pay_off.repay::<T>(&state_cleanup_treasury_account, &sender, false)?;
 
             <PostThreadIdByPostId<T>>::remove(thread_id, post_id);
+            <PostReactionCountersByPostId<T>>::remove(post_id);
+            <PostReactionByMember<T>>::remove_prefix(post_id, None);
             Self::deposit_event(RawEvent::PostDeleted(deleter_id, thread_id, post_id, hide));
         }
 
@@ -453,6 +530,93 @@ decl_module! {
 
             Self::deposit_event(RawEvent::ThreadModeChanged(thread_id, mode, member_id));
        }
+
+        /// Reacts to a post with one of the predefined reactions. Providing `None` removes
+        /// the previous reaction of the member.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoDiscussion::<T>::react_post()]
+        pub fn react_post(
+            origin,
+            member_id: MemberId<T>,
+            thread_id: T::ThreadId,
+            post_id: T::PostId,
+            reaction: Option<PostReaction>,
+        ) {
+            T::AuthorOriginValidator::ensure_member_controller_account_origin(origin, member_id)?;
+
+            Self::ensure_post_exists(thread_id, post_id)?;
+
+            let previous_reaction = Self::post_reaction_by_member(post_id, member_id);
+            ensure!(previous_reaction != reaction, Error::<T>::PostReactionUnchanged);
+
+            let mut counters = Self::post_reaction_counters_by_post_id(post_id);
+            ensure!(
+                counters.try_replace(previous_reaction, reaction, T::MaxReactionsPerPost::get()),
+                Error::<T>::MaxReactionsPerPostExceeded
+            );
+
+            // mutation
+
+            <PostReactionCountersByPostId<T>>::insert(post_id, counters);
+
+            match reaction {
+                Some(reaction) => <PostReactionByMember<T>>::insert(post_id, member_id, reaction),
+                None => <PostReactionByMember<T>>::remove(post_id, member_id),
+            }
+
+            Self::deposit_event(RawEvent::PostReacted(member_id, thread_id, post_id, reaction));
+        }
+
+        /// Tips the post author. The tip is transferred to the controller account of the author.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoDiscussion::<T>::tip_post_author()]
+        pub fn tip_post_author(
+            origin,
+            member_id: MemberId<T>,
+            thread_id: T::ThreadId,
+            post_id: T::PostId,
+            amount: BalanceOf<T>,
+        ) {
+            let account_id = T::AuthorOriginValidator::ensure_member_controller_account_origin(
+                origin,
+                member_id,
+            )?;
+
+            let post = Self::ensure_post_exists(thread_id, post_id)?;
+
+            ensure!(!amount.is_zero(), Error::<T>::TipAmountCannotBeZero);
+
+            ensure!(post.author_id != member_id, Error::<T>::CannotTipOwnPost);
+
+            ensure!(
+                has_sufficient_balance_for_payment::<T>(&account_id, amount),
+                Error::<T>::InsufficientBalanceForTip
+            );
+
+            let author_account = T::MembershipInfoProvider::controller_account_id(post.author_id)?;
+
+            // mutation
+
+            tip_post_author::<T>(&account_id, &author_account, amount)?;
+
+            Self::deposit_event(
+                RawEvent::PostAuthorTipped(member_id, thread_id, post_id, post.author_id, amount)
+            );
+        }
     }
 }
 
@@ -486,6 +650,48 @@ impl<T: Config> Module<T> {
         Ok(thread_id)
     }
 
+    /// Ensures an amendment can be recorded for the thread.
+    pub fn ensure_can_record_amendment(thread_id: T::ThreadId) -> DispatchResult {
+        ensure!(
+            <ThreadById<T>>::contains_key(thread_id),
+            Error::<T>::ThreadDoesntExist
+        );
+
+        ensure!(
+            (Self::thread_amendments(thread_id).len() as u32) < T::MaxThreadAmendments::get(),
+            Error::<T>::MaxThreadAmendmentsExceeded
+        );
+
+        Ok(())
+    }
+
+    /// Records an amendment of the discussed subject in the thread history.
+    pub fn record_amendment(
+        thread_id: T::ThreadId,
+        details_hash: T::Hash,
+        votes_reset: bool,
+    ) -> DispatchResult {
+        Self::ensure_can_record_amendment(thread_id)?;
+
+        let amendment = ThreadAmendment {
+            amended_at: Self::current_block(),
+            details_hash,
+            votes_reset,
+        };
+
+        // mutation
+
+        <ThreadAmendments<T>>::try_mutate(thread_id, |amendments| {
+            amendments
+                .try_push(amendment.clone())
+                .map_err(|_| Error::<T>::MaxThreadAmendmentsExceeded)
+        })?;
+
+        Self::deposit_event(RawEvent::ThreadAmendmentRecorded(thread_id, amendment));
+
+        Ok(())
+    }
+
     /// Ensures thread mode is valid and converts it to a thread mode with bounded whitelist.
     pub fn ensure_thread_mode_ok(
         mode: &ThreadMode<BTreeSet<<T as MembershipTypes>::MemberId>>,
@@ -567,6 +773,18 @@ impl<T: Config> Module<T> {
         }
     }
 
+    fn ensure_post_exists(
+        thread_id: T::ThreadId,
+        post_id: T::PostId,
+    ) -> Result<DiscussionPostOf<T>, Error<T>> {
+        ensure!(
+            <PostThreadIdByPostId<T>>::contains_key(thread_id, post_id),
+            Error::<T>::PostDoesntExist
+        );
+
+        Ok(<PostThreadIdByPostId<T>>::get(thread_id, post_id))
+    }
+
     fn module_account_id() -> T::AccountId {
         T::ModuleId::get().into_sub_account_truncating("TREASURY")
     }
@@ -68,6 +68,8 @@ parameter_types! {
     pub const ReferralCutMaximumPercent: u8 = 50;
     pub const CandidateStake: u64 = 100;
     pub const PostLifeTime: u64 = 10;
+    pub const MaxThreadAmendments: u32 = 2;
+    pub const MaxReactionsPerPost: u32 = 2;
     pub const PostDeposit: u64 = 100;
     pub const ProposalsDiscussionModuleId: PalletId = PalletId(*b"mo:propo");
 }
@@ -139,6 +141,13 @@ impl membership::Config for Test {
         staking_handler::StakingManager<Self, BoundStakingAccountLockId>;
     type CandidateStake = CandidateStake;
     type DefaultMemberInvitesCount = DefaultMemberInvitesCount;
+    type MemberReputationRules = ();
+    type MaxRecoveryGuardians = ();
+    type RecoveryDelay = ();
+    type RecoveryDeposit = ();
+    type HandleExpiryPeriod = ();
+    type HandleExpiryGracePeriod = ();
+    type MaxUplineReferralLevels = ();
 }
 
 impl LockComparator<<Test as balances::Config>::Balance> for Test {
@@ -221,6 +230,8 @@ impl crate::Config for Test {
     type PostLifeTime = PostLifeTime;
     type PostDeposit = PostDeposit;
     type ModuleId = ProposalsDiscussionModuleId;
+    type MaxThreadAmendments = MaxThreadAmendments;
+    type MaxReactionsPerPost = MaxReactionsPerPost;
 }
 
 impl MemberOriginValidator<Origin, u64, u128> for () {
@@ -281,14 +292,22 @@ impl council::Config for Test {
 
     type Referendum = referendum::Module<Test, ReferendumInstance>;
 
+    type RecallReferendum = referendum::Module<Test, ReferendumInstance>;
+
     type MinNumberOfExtraCandidates = MinNumberOfExtraCandidates;
     type CouncilSize = CouncilSize;
+    type StaggeredSeatsPerCycle = ConstU32<0>;
+    type MaxConsecutiveTerms = ConstU32<0>;
+    type MaxRunnersUp = ConstU32<0>;
     type AnnouncingPeriodDuration = AnnouncingPeriodDuration;
     type IdlePeriodDuration = IdlePeriodDuration;
     type MinCandidateStake = MinCandidateStake;
 
     type CandidacyLock = StakingManager<Self, CandidacyLockId>;
     type CouncilorLock = StakingManager<Self, CouncilorLockId>;
+    type RecallPetitionLock = StakingManager<Self, common::locks::RecallPetitionLockId>;
+    type MinRecallPetitionStake = ConstU64<100>;
+    type RecallPetitionThreshold = ConstU64<10000>;
 
     type ElectedMemberRewardPeriod = ElectedMemberRewardPeriod;
 
@@ -339,6 +358,7 @@ impl referendum::Config<ReferendumInstance> for Test {
     type WeightInfo = ();
 
     type MaxWinnerTargetCount = MaxWinnerTargetCount;
+    type MaxDelegatorsPerDelegate = ConstU32<10>;
 
     fn calculate_vote_power(
         _: &<Self as frame_system::Config>::AccountId,
@@ -4,6 +4,7 @@ use frame_support::assert_noop;
 use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_system::RawOrigin;
 use frame_system::{EventRecord, Phase};
+use sp_runtime::traits::Hash;
 use sp_std::iter::FromIterator;
 
 use crate::*;
@@ -13,7 +14,9 @@ pub(crate) use mock::*;
 
 struct EventFixture;
 impl EventFixture {
-    fn assert_events(expected_raw_events: Vec<RawEvent<u64, u64, u64>>) {
+    fn assert_events(
+        expected_raw_events: Vec<RawEvent<u64, u64, u64, ThreadAmendmentOf<Test>, u64>>,
+    ) {
         let expected_events = expected_raw_events
             .iter()
             .map(|ev| EventRecord {
@@ -1017,3 +1020,343 @@ fn create_discussion_call_fails_with_exceeded_max_author_list_size() {
             .create_discussion_and_assert(Err(Error::<Test>::MaxWhiteListSizeExceeded.into()));
     });
 }
+
+#[test]
+fn record_amendment_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        run_to_block(2);
+
+        let details_hash = <Test as frame_system::Config>::Hashing::hash_of(&b"details".to_vec());
+        assert_eq!(
+            Discussions::record_amendment(thread_id, details_hash, true),
+            Ok(())
+        );
+
+        let expected_amendment = ThreadAmendment {
+            amended_at: 2,
+            details_hash,
+            votes_reset: true,
+        };
+        assert_eq!(
+            Discussions::thread_amendments(thread_id).into_inner(),
+            vec![expected_amendment.clone()]
+        );
+
+        EventFixture::assert_events(vec![
+            RawEvent::ThreadCreated(1, 1),
+            RawEvent::ThreadAmendmentRecorded(1, expected_amendment),
+        ]);
+    });
+}
+
+#[test]
+fn record_amendment_fails_with_invalid_thread_id() {
+    initial_test_ext().execute_with(|| {
+        assert_eq!(
+            Discussions::record_amendment(1, Default::default(), false),
+            Err(Error::<Test>::ThreadDoesntExist.into())
+        );
+    });
+}
+
+#[test]
+fn record_amendment_fails_with_exceeded_max_amendments() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        for _ in 0..MaxThreadAmendments::get() {
+            assert_eq!(
+                Discussions::record_amendment(thread_id, Default::default(), false),
+                Ok(())
+            );
+        }
+
+        assert_eq!(
+            Discussions::record_amendment(thread_id, Default::default(), false),
+            Err(Error::<Test>::MaxThreadAmendmentsExceeded.into())
+        );
+    });
+}
+
+struct ReactPostFixture {
+    pub origin: RawOrigin<u128>,
+    pub member_id: u64,
+    pub thread_id: u64,
+    pub post_id: u64,
+    pub reaction: Option<PostReaction>,
+}
+
+impl ReactPostFixture {
+    fn default_for_post(thread_id: u64, post_id: u64) -> Self {
+        Self {
+            origin: RawOrigin::Signed(1),
+            member_id: 1,
+            thread_id,
+            post_id,
+            reaction: Some(PostReaction::Like),
+        }
+    }
+
+    fn with_member_id(self, member_id: u64) -> Self {
+        Self {
+            origin: RawOrigin::Signed(member_id.into()),
+            member_id,
+            ..self
+        }
+    }
+
+    fn with_reaction(self, reaction: Option<PostReaction>) -> Self {
+        Self { reaction, ..self }
+    }
+
+    fn call_and_assert(&self, expected_result: DispatchResult) {
+        let initial_counters = Discussions::post_reaction_counters_by_post_id(self.post_id);
+
+        let actual_result = Discussions::react_post(
+            self.origin.clone().into(),
+            self.member_id,
+            self.thread_id,
+            self.post_id,
+            self.reaction,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(
+                Discussions::post_reaction_by_member(self.post_id, self.member_id),
+                self.reaction
+            );
+        } else {
+            assert_eq!(
+                Discussions::post_reaction_counters_by_post_id(self.post_id),
+                initial_counters
+            );
+        }
+    }
+}
+
+struct TipPostAuthorFixture {
+    pub origin: RawOrigin<u128>,
+    pub member_id: u64,
+    pub thread_id: u64,
+    pub post_id: u64,
+    pub amount: u64,
+}
+
+impl TipPostAuthorFixture {
+    fn default_for_post(thread_id: u64, post_id: u64) -> Self {
+        Self {
+            origin: RawOrigin::Signed(2),
+            member_id: 2,
+            thread_id,
+            post_id,
+            amount: 100,
+        }
+    }
+
+    fn with_member_id(self, member_id: u64) -> Self {
+        Self {
+            origin: RawOrigin::Signed(member_id.into()),
+            member_id,
+            ..self
+        }
+    }
+
+    fn with_amount(self, amount: u64) -> Self {
+        Self { amount, ..self }
+    }
+
+    fn call_and_assert(&self, expected_result: DispatchResult) {
+        let author_id = PostThreadIdByPostId::<Test>::get(self.thread_id, self.post_id).author_id;
+        let author_account = u128::from(author_id);
+        let initial_author_balance = Balances::free_balance(author_account);
+
+        let actual_result = Discussions::tip_post_author(
+            self.origin.clone().into(),
+            self.member_id,
+            self.thread_id,
+            self.post_id,
+            self.amount,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let expected_author_balance = if actual_result.is_ok() {
+            initial_author_balance + self.amount
+        } else {
+            initial_author_balance
+        };
+        assert_eq!(
+            Balances::free_balance(author_account),
+            expected_author_balance
+        );
+    }
+}
+
+#[test]
+fn react_post_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        let post_id = post_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        let react_post_fixture = ReactPostFixture::default_for_post(thread_id, post_id);
+        react_post_fixture.call_and_assert(Ok(()));
+
+        let react_post_fixture = react_post_fixture.with_reaction(Some(PostReaction::Insightful));
+        react_post_fixture.call_and_assert(Ok(()));
+
+        let counters = Discussions::post_reaction_counters_by_post_id(post_id);
+        assert_eq!(counters.count(PostReaction::Like), 0);
+        assert_eq!(counters.count(PostReaction::Insightful), 1);
+
+        EventFixture::assert_events(vec![
+            RawEvent::ThreadCreated(1, 1),
+            RawEvent::PostCreated(1, 1, 1, b"text".to_vec(), true),
+            RawEvent::PostReacted(1, 1, 1, Some(PostReaction::Like)),
+            RawEvent::PostReacted(1, 1, 1, Some(PostReaction::Insightful)),
+        ]);
+    });
+}
+
+#[test]
+fn react_post_fails_with_invalid_post_or_unchanged_reaction() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        let post_id = post_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        ReactPostFixture::default_for_post(thread_id, post_id + 1)
+            .call_and_assert(Err(Error::<Test>::PostDoesntExist.into()));
+
+        ReactPostFixture::default_for_post(thread_id, post_id)
+            .with_reaction(None)
+            .call_and_assert(Err(Error::<Test>::PostReactionUnchanged.into()));
+    });
+}
+
+#[test]
+fn react_post_fails_with_exceeded_max_reactions() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        let post_id = post_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        let max_reactions = MaxReactionsPerPost::get() as u64;
+        for member_id in 1..=max_reactions {
+            ReactPostFixture::default_for_post(thread_id, post_id)
+                .with_member_id(member_id)
+                .call_and_assert(Ok(()));
+        }
+
+        ReactPostFixture::default_for_post(thread_id, post_id)
+            .with_member_id(max_reactions + 1)
+            .call_and_assert(Err(Error::<Test>::MaxReactionsPerPostExceeded.into()));
+
+        // Removing a reaction frees the slot
+        ReactPostFixture::default_for_post(thread_id, post_id)
+            .with_reaction(None)
+            .call_and_assert(Ok(()));
+        ReactPostFixture::default_for_post(thread_id, post_id)
+            .with_member_id(max_reactions + 1)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn delete_post_removes_reactions() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        let post_id = post_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        ReactPostFixture::default_for_post(thread_id, post_id).call_and_assert(Ok(()));
+
+        post_fixture.delete_post_and_assert(Ok(()));
+
+        assert_eq!(
+            Discussions::post_reaction_counters_by_post_id(post_id),
+            PostReactionCounters::default()
+        );
+        assert_eq!(Discussions::post_reaction_by_member(post_id, 1), None);
+    });
+}
+
+#[test]
+fn tip_post_author_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        let post_id = post_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        let tip_fixture = TipPostAuthorFixture::default_for_post(thread_id, post_id);
+        balances::Pallet::<Test>::make_free_balance_be(&2, ed() + tip_fixture.amount);
+
+        tip_fixture.call_and_assert(Ok(()));
+
+        EventFixture::assert_events(vec![
+            RawEvent::ThreadCreated(1, 1),
+            RawEvent::PostCreated(1, 1, 1, b"text".to_vec(), true),
+            RawEvent::PostAuthorTipped(2, 1, 1, 1, tip_fixture.amount),
+        ]);
+    });
+}
+
+#[test]
+fn tip_post_author_fails_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        let post_id = post_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        let tip_fixture = TipPostAuthorFixture::default_for_post(thread_id, post_id);
+
+        tip_fixture.call_and_assert(Err(Error::<Test>::InsufficientBalanceForTip.into()));
+
+        balances::Pallet::<Test>::make_free_balance_be(&2, ed() + tip_fixture.amount);
+
+        TipPostAuthorFixture::default_for_post(thread_id, post_id + 1)
+            .call_and_assert(Err(Error::<Test>::PostDoesntExist.into()));
+
+        TipPostAuthorFixture::default_for_post(thread_id, post_id)
+            .with_amount(0)
+            .call_and_assert(Err(Error::<Test>::TipAmountCannotBeZero.into()));
+
+        TipPostAuthorFixture::default_for_post(thread_id, post_id)
+            .with_member_id(1)
+            .call_and_assert(Err(Error::<Test>::CannotTipOwnPost.into()));
+    });
+}
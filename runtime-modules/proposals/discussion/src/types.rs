@@ -3,7 +3,7 @@
 use crate::{BalanceOf, Config};
 use codec::{Decode, Encode, MaxEncodedLen};
 use common::{bloat_bond::RepayableBloatBond, MembershipTypes};
-use frame_support::storage::bounded_btree_set::BoundedBTreeSet;
+use frame_support::storage::{bounded_btree_set::BoundedBTreeSet, bounded_vec::BoundedVec};
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -47,6 +47,20 @@ pub enum ThreadMode<ThreadWhitelist> {
     Closed(ThreadWhitelist),
 }
 
+/// Amendment of the discussed subject, recorded in the thread history.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct ThreadAmendment<BlockNumber, Hash> {
+    /// When the amendment was made.
+    pub amended_at: BlockNumber,
+
+    /// Hash of the amended content.
+    pub details_hash: Hash,
+
+    /// Whether the amendment caused the existing votes to be reset.
+    pub votes_reset: bool,
+}
+
 impl<MemberId> Default for ThreadMode<MemberId> {
     fn default() -> Self {
         Self::Open
@@ -72,3 +86,11 @@ pub type DiscussionPostOf<T> = DiscussionPost<
 /// Alias for BoundedBTreeSet<MemberId, MaxWhiteListSize>
 pub type ThreadWhitelistOf<T> =
     BoundedBTreeSet<<T as MembershipTypes>::MemberId, <T as Config>::MaxWhiteListSize>;
+
+/// Alias for ThreadAmendment
+pub type ThreadAmendmentOf<T> =
+    ThreadAmendment<<T as frame_system::Config>::BlockNumber, <T as frame_system::Config>::Hash>;
+
+/// Alias for BoundedVec<ThreadAmendment, MaxThreadAmendments>
+pub type ThreadAmendmentsOf<T> =
+    BoundedVec<ThreadAmendmentOf<T>, <T as Config>::MaxThreadAmendments>;
@@ -47,8 +47,10 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn add_post(_j: u32, ) -> Weight;
 	fn update_post(_j: u32, ) -> Weight;
-	fn delete_post() -> Weight;
+	fn delete_post(_r: u32, ) -> Weight;
 	fn change_thread_mode(_i: u32, ) -> Weight;
+	fn react_post() -> Weight;
+	fn tip_post_author() -> Weight;
 }
 
 /// Weights for proposals_discussion using the Substrate node and recommended hardware.
@@ -81,10 +83,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: ProposalDiscussion PostThreadIdByPostId (r:1 w:1)
 	// Storage: ProposalDiscussion ThreadById (r:1 w:0)
 	// Storage: System Account (r:2 w:2)
-	fn delete_post() -> Weight {
-		(122_960_000 as Weight)
+	// Storage: ProposalDiscussion PostReactionCountersByPostId (r:0 w:1)
+	// Storage: ProposalDiscussion PostReactionByMember (r:0 w:10)
+	fn delete_post(r: u32, ) -> Weight {
+		(123_417_000 as Weight)
+			// Standard Error: 4_000
+			.saturating_add((1_176_000 as Weight).saturating_mul(r as Weight))
 			.saturating_add(T::DbWeight::get().reads(5 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
 	}
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Storage: ProposalDiscussion ThreadById (r:1 w:1)
@@ -97,6 +104,23 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: ProposalDiscussion PostThreadIdByPostId (r:1 w:0)
+	// Storage: ProposalDiscussion PostReactionByMember (r:1 w:1)
+	// Storage: ProposalDiscussion PostReactionCountersByPostId (r:1 w:1)
+	fn react_post() -> Weight {
+		(47_316_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Membership MembershipById (r:2 w:0)
+	// Storage: ProposalDiscussion PostThreadIdByPostId (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn tip_post_author() -> Weight {
+		(83_527_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
 }
 
 // Default implementation for tests
@@ -107,10 +131,16 @@ impl WeightInfo for () {
 	fn update_post(j: u32, ) -> Weight {
 		0
 	}
-	fn delete_post() -> Weight {
+	fn delete_post(r: u32, ) -> Weight {
 		0
 	}
 	fn change_thread_mode(i: u32, ) -> Weight {
 		0
 	}
+	fn react_post() -> Weight {
+		0
+	}
+	fn tip_post_author() -> Weight {
+		0
+	}
 }
@@ -161,6 +161,7 @@ fn create_proposal<T: Config + membership::Config>(
         staking_account_id: Some(account_id.clone()),
         encoded_dispatchable_call_code: call_code.clone(),
         exact_execution_block: None,
+        dependencies: Vec::new(),
     };
 
     let proposal_id = ProposalsEngine::<T>::create_proposal(proposal_creation_parameters).unwrap();
@@ -32,6 +32,12 @@
 //! [reactivate_pending_constitutionality_proposals](./struct.Module.html#method.reactivate_pending_constitutionality_proposals)
 //! and proposals with pending constitutionality become active again.
 //! - There are different fees to apply for slashed, rejected, expired or cancelled proposals.
+//! - A proposal can depend on previously created proposals that weren't finalized yet. An approved
+//! proposal waits until all its dependencies are finalized and fails the execution if any of them
+//! wasn't executed.
+//! - An active proposal can be amended by its proposer. Existing votes are reset and the voting
+//! period is changed according to the configured
+//! [rule](./enum.AmendmentVotingPeriodRule.html).
 //! - On runtime upgrade the proposals code could be obsolete, so we cancel all active proposals
 //! with statuses: Active, PendingExecution, PendingConstitutionality using this function
 //! [cancel_active_and_pending_proposals](./struct.Module.html#method.cancel_active_and_pending_proposals).
@@ -65,6 +71,8 @@
 //! reactivate proposals with pending constitutionality.
 //! - [cancel_active_and_pending_proposals](./struct.Module.html#method.cancel_active_and_pending_proposals) -
 //! cancels all active proposals.
+//! - [amend_proposal](./struct.Module.html#method.amend_proposal) - amends the active proposal
+//! code, resetting the votes and the voting period when required.
 //!
 //! ## Usage
 //!
@@ -103,6 +111,7 @@
 //!                 None,
 //!                 None,
 //!                 proposer_id,
+//!                 &[],
 //!             )?;
 //!
 //!             let creation_parameters = ProposalCreationParameters {
@@ -114,6 +123,7 @@
 //!                 staking_account_id: None,
 //!                 encoded_dispatchable_call_code: encoded_proposal_code,
 //!                 exact_execution_block: None,
+//!                 dependencies: Vec::new(),
 //!             };
 //!
 //!             <engine::Module<T>>::create_proposal(creation_parameters)?;
@@ -146,14 +156,15 @@ extern crate common;
 use types::ProposalOf;
 
 pub use types::{
-    ApprovedProposalDecision, BalanceOf, ExecutionStatus, Proposal, ProposalCodeDecoder,
-    ProposalCreationParameters, ProposalDecision, ProposalExecutable, ProposalParameters,
-    ProposalStatus, VoteKind, VotersParameters, VotingResults,
+    AmendmentVotingPeriodRule, ApprovedProposalDecision, BalanceOf, ExecutionStatus, Proposal,
+    ProposalCodeDecoder, ProposalCreationParameters, ProposalDecision, ProposalExecutable,
+    ProposalParameters, ProposalStatus, VoteKind, VotersParameters, VotingResults,
 };
 
 pub(crate) mod types;
 
 mod benchmarking;
+mod migrations;
 
 #[cfg(test)]
 mod tests;
@@ -174,7 +185,7 @@ use sp_std::convert::TryInto;
 use sp_std::vec::Vec;
 
 use common::council::CouncilOriginValidator;
-use common::membership::MemberOriginValidator;
+use common::membership::{MemberOriginValidator, MemberReputationHandler, ReputationActivity};
 use common::to_kb;
 use common::{MemberId, StakingAccountValidator};
 use staking_handler::StakingHandler;
@@ -198,6 +209,9 @@ pub trait Config:
         Self::AccountId,
     >;
 
+    /// Updates proposer reputation on the proposal execution.
+    type MemberReputationHandler: MemberReputationHandler<Self>;
+
     /// Validates voter id and origin combination
     type CouncilOriginValidator: CouncilOriginValidator<
         Self::Origin,
@@ -237,6 +251,12 @@ pub trait Config:
     /// Defines max size of encoded dispatchable call (to be executed through a proposal)
     type DispatchableCallCodeMaxLen: Get<u32>;
 
+    /// Defines max number of proposals a single proposal can depend on.
+    type MaxProposalDependencies: Get<u32>;
+
+    /// Defines how the voting period is changed on the proposal amendment.
+    type AmendmentVotingPeriodRule: Get<AmendmentVotingPeriodRule<Self::BlockNumber>>;
+
     /// Proposals executable code. Can be instantiated by external module Call enum members.
     type DispatchableCallCode: Parameter
         + UnfilteredDispatchable<Origin = Self::Origin>
@@ -312,6 +332,13 @@ decl_event!(
         /// - proposal id
         /// - message
         ProposerRemarked(MemberId, ProposalId, Vec<u8>),
+
+        /// Emits on proposal amendment
+        /// Params:
+        /// - Id of the proposal
+        /// - whether the existing votes were reset
+        /// - new end of the voting period
+        ProposalAmended(ProposalId, bool, BlockNumber),
     }
 );
 
@@ -386,6 +413,15 @@ decl_error! {
 
         /// The size of encoded dispatchable call to be executed by the proposal is too big
         MaxDispatchableCallCodeSizeExceeded,
+
+        /// Max number of proposal dependencies exceeded
+        MaxProposalDependenciesExceeded,
+
+        /// Proposal dependency is neither an existing nor an executed proposal
+        InvalidProposalDependency,
+
+        /// Only active proposals can be amended
+        ProposalNotActive,
     }
 }
 
@@ -409,6 +445,21 @@ decl_storage! { generate_storage_info
         /// Double map for preventing duplicate votes. Should be cleaned after usage.
         pub VoteExistsByProposalByVoter get(fn vote_by_proposal_by_voter):
             double_map hasher(blake2_128_concat) T::ProposalId, hasher(blake2_128_concat) MemberId<T> => VoteKind;
+
+        /// Map proposal id to the proposals that must be executed before it.
+        pub ProposalDependencies get(fn proposal_dependencies): map hasher(blake2_128_concat)
+            T::ProposalId => BoundedVec<T::ProposalId, T::MaxProposalDependencies>;
+
+        /// Number of pending proposals that depend on the proposal. Only proposals with
+        /// dependent proposals are tracked.
+        pub DependentProposalsCount get(fn dependent_proposals_count):
+            map hasher(blake2_128_concat) T::ProposalId => u32;
+
+        /// Successfully executed proposals that pending proposals still depend on. Used to
+        /// resolve proposal dependencies. An entry is removed once no pending proposal depends
+        /// on the executed proposal anymore.
+        pub ExecutedProposals get(fn executed_proposals): map hasher(blake2_128_concat)
+            T::ProposalId => ();
     }
 }
 
@@ -440,6 +491,13 @@ decl_module! {
         /// Exports const - staking handler lock id.
         const StakingHandlerLockId: LockIdentifier = T::StakingHandler::lock_id();
 
+        /// Exports const - max number of proposals a single proposal can depend on.
+        const MaxProposalDependencies: u32 = T::MaxProposalDependencies::get();
+
+        /// Exports const - the voting period rule applied on the proposal amendment.
+        const AmendmentVotingPeriodRule: AmendmentVotingPeriodRule<T::BlockNumber> =
+            T::AmendmentVotingPeriodRule::get();
+
         /// Block Initialization. Perform voting period check, vote result tally, approved proposals
         /// grace period checks, and proposal execution.
         /// # <weight>
@@ -526,7 +584,8 @@ decl_module! {
         /// - DB:
         ///    - O(1) doesn't depend on the state or parameters
         /// # </weight>
-        #[weight = WeightInfoEngine::<T>::cancel_proposal()]
+        #[weight = WeightInfoEngine::<T>::cancel_proposal()
+            .saturating_add(Module::<T>::release_proposal_dependencies_weight())]
         pub fn cancel_proposal(origin, proposer_id: MemberId<T>, proposal_id: T::ProposalId) {
             T::ProposerOriginValidator::ensure_member_controller_account_origin(origin, proposer_id)?;
 
@@ -557,7 +616,8 @@ decl_module! {
         /// - DB:
         ///    - O(1) doesn't depend on the state or parameters
         /// # </weight>
-        #[weight = WeightInfoEngine::<T>::veto_proposal()]
+        #[weight = WeightInfoEngine::<T>::veto_proposal()
+            .saturating_add(Module::<T>::release_proposal_dependencies_weight())]
         pub fn veto_proposal(origin, proposal_id: T::ProposalId) {
             ensure_root(origin)?;
 
@@ -614,6 +674,7 @@ impl<T: Config> Module<T> {
             BalanceOf<T>,
             MemberId<T>,
             T::AccountId,
+            T::ProposalId,
         >,
     ) -> Result<T::ProposalId, DispatchError> {
         Self::ensure_create_proposal_parameters_are_valid(
@@ -623,6 +684,7 @@ impl<T: Config> Module<T> {
             creation_params.staking_account_id.clone(),
             creation_params.exact_execution_block,
             creation_params.proposer_id,
+            &creation_params.dependencies,
         )?;
 
         let encoded_dispatchable_call_code: BoundedVec<u8, T::DispatchableCallCodeMaxLen> =
@@ -631,6 +693,11 @@ impl<T: Config> Module<T> {
                 .try_into()
                 .map_err(|_| Error::<T>::MaxDispatchableCallCodeSizeExceeded)?;
 
+        let dependencies: BoundedVec<T::ProposalId, T::MaxProposalDependencies> = creation_params
+            .dependencies
+            .try_into()
+            .map_err(|_| Error::<T>::MaxProposalDependenciesExceeded)?;
+
         //
         // == MUTATION SAFE ==
         //
@@ -650,6 +717,7 @@ impl<T: Config> Module<T> {
 
         let new_proposal = Proposal {
             activated_at: Self::current_block(),
+            voting_period_extension: Zero::zero(),
             parameters: creation_params.proposal_parameters,
             proposer_id: creation_params.proposer_id,
             status: ProposalStatus::Active,
@@ -661,17 +729,31 @@ impl<T: Config> Module<T> {
 
         <Proposals<T>>::insert(proposal_id, new_proposal);
         <DispatchableCallCode<T>>::insert(proposal_id, encoded_dispatchable_call_code);
+        if !dependencies.is_empty() {
+            for dependency in dependencies.iter() {
+                <DependentProposalsCount<T>>::mutate(dependency, |count| {
+                    *count = count.saturating_add(1)
+                });
+            }
+            <ProposalDependencies<T>>::insert(proposal_id, dependencies);
+        }
         ProposalCount::put(next_proposal_count_value);
         Self::increase_active_proposal_counter()?;
 
         Ok(proposal_id)
     }
 
+    /// Id of the next created proposal.
+    pub fn next_proposal_id() -> T::ProposalId {
+        T::ProposalId::from(Self::proposal_count().saturating_add(1))
+    }
+
     /// Performs all checks for the proposal creation:
     /// - title, body lengths
     /// - max active proposal
     /// - provided parameters: approval_threshold_percentage and slashing_threshold_percentage > 0
     /// - provided stake balance and parameters.required_stake are valid
+    /// - dependencies are existing (not yet finalized) proposals
     pub fn ensure_create_proposal_parameters_are_valid(
         parameters: &ProposalParameters<T::BlockNumber, BalanceOf<T>>,
         title: &[u8],
@@ -679,6 +761,7 @@ impl<T: Config> Module<T> {
         staking_account_id: Option<T::AccountId>,
         exact_execution_block: Option<T::BlockNumber>,
         member_id: T::MemberId,
+        dependencies: &[T::ProposalId],
     ) -> DispatchResult {
         ensure!(!title.is_empty(), Error::<T>::EmptyTitleProvided);
         ensure!(
@@ -754,9 +837,107 @@ impl<T: Config> Module<T> {
             }
         }
 
+        // Check dependencies.
+        ensure!(
+            dependencies.len() <= T::MaxProposalDependencies::get() as usize,
+            Error::<T>::MaxProposalDependenciesExceeded
+        );
+
+        for dependency in dependencies {
+            ensure!(
+                <Proposals<T>>::contains_key(dependency),
+                Error::<T>::InvalidProposalDependency
+            );
+        }
+
         Ok(())
     }
 
+    /// Amends the executable code of an active proposal. Existing votes and council confirmations
+    /// are reset and the voting period is changed according to the `AmendmentVotingPeriodRule`.
+    /// Returns whether the votes were reset.
+    pub fn amend_proposal(
+        proposer_id: MemberId<T>,
+        proposal_id: T::ProposalId,
+        encoded_dispatchable_call_code: Vec<u8>,
+    ) -> Result<bool, DispatchError> {
+        let mut proposal = Self::ensure_can_amend_proposal(proposer_id, proposal_id)?;
+
+        let encoded_dispatchable_call_code: BoundedVec<u8, T::DispatchableCallCodeMaxLen> =
+            encoded_dispatchable_call_code
+                .try_into()
+                .map_err(|_| Error::<T>::MaxDispatchableCallCodeSizeExceeded)?;
+
+        let now = Self::current_block();
+        match T::AmendmentVotingPeriodRule::get() {
+            AmendmentVotingPeriodRule::Keep => {}
+            AmendmentVotingPeriodRule::Restart => {
+                proposal.activated_at = now;
+                proposal.voting_period_extension = Zero::zero();
+            }
+            AmendmentVotingPeriodRule::ExtendTo(min_remaining_period) => {
+                // the activation block is kept, only the voting period end is moved
+                let min_voting_period_end = now.saturating_add(min_remaining_period);
+                proposal.voting_period_extension = proposal.voting_period_extension.saturating_add(
+                    min_voting_period_end.saturating_sub(proposal.voting_period_end()),
+                );
+            }
+        }
+        let voting_period_end = proposal.voting_period_end();
+
+        if let Some(execution_block) = proposal.exact_execution_block {
+            ensure!(
+                execution_block
+                    >= voting_period_end.saturating_add(proposal.parameters.grace_period),
+                Error::<T>::InvalidExactExecutionBlock
+            );
+        }
+
+        let votes_reset =
+            !proposal.voting_results.no_votes_yet() || proposal.nr_of_council_confirmations > 0;
+
+        //
+        // == MUTATION SAFE ==
+        //
+
+        if votes_reset {
+            proposal.reset_proposal_votes();
+            proposal.nr_of_council_confirmations = 0;
+            <VoteExistsByProposalByVoter<T>>::remove_prefix(&proposal_id, None);
+        }
+
+        <Proposals<T>>::insert(proposal_id, proposal);
+        <DispatchableCallCode<T>>::insert(proposal_id, encoded_dispatchable_call_code);
+
+        Self::deposit_event(RawEvent::ProposalAmended(
+            proposal_id,
+            votes_reset,
+            voting_period_end,
+        ));
+
+        Ok(votes_reset)
+    }
+
+    /// Ensures the proposal exists, is active and belongs to the proposer.
+    pub fn ensure_can_amend_proposal(
+        proposer_id: MemberId<T>,
+        proposal_id: T::ProposalId,
+    ) -> Result<ProposalOf<T>, DispatchError> {
+        ensure!(
+            <Proposals<T>>::contains_key(proposal_id),
+            Error::<T>::ProposalNotFound
+        );
+        let proposal = Self::proposals(proposal_id);
+
+        ensure!(proposer_id == proposal.proposer_id, Error::<T>::NotAuthor);
+        ensure!(
+            proposal.status == ProposalStatus::Active,
+            Error::<T>::ProposalNotActive
+        );
+
+        Ok(proposal)
+    }
+
     /// Rejects all active proposals.
     /// Possible application includes new council elections.
     pub fn reject_active_proposals() {
@@ -807,6 +988,7 @@ impl<T: Config> Module<T> {
             .filter_map(|(proposal_id, mut proposal)| {
                 if proposal.status.is_pending_constitutionality_proposal() {
                     proposal.activated_at = Self::current_block();
+                    proposal.voting_period_extension = Zero::zero();
                     proposal.status = ProposalStatus::Active;
                     // Resets votes for a proposal.
                     proposal.reset_proposal_votes();
@@ -859,12 +1041,30 @@ impl<T: Config> Module<T> {
         let slashed_branch_weight =
             WeightInfoEngine::<T>::on_initialize_slashed(max_active_proposals);
 
+        // Weight of resolving and releasing the dependencies of all the finalized proposals
+        let dependencies_weight = T::DbWeight::get()
+            .reads(
+                max_active_proposals
+                    .saturating_mul(T::MaxProposalDependencies::get().saturating_add(1))
+                    .into(),
+            )
+            .saturating_add(
+                Self::release_proposal_dependencies_weight()
+                    .saturating_mul(max_active_proposals.into()),
+            );
+
+        // Weight of updating the proposer reputation for all the executed proposals
+        let reputation_weight = T::MemberReputationHandler::record_activity_weight()
+            .saturating_mul(max_active_proposals.into());
+
         // Maximum Weight of all possible worst case scenarios
         immediate_execution_branch_weight
             .max(pending_execution_branch_weight)
             .max(approved_pending_constitutionality_branch_weight)
             .max(rejected_branch_weight)
             .max(slashed_branch_weight)
+            .saturating_add(dependencies_weight)
+            .saturating_add(reputation_weight)
     }
 
     // Wrapper-function over System::block_number()
@@ -872,9 +1072,48 @@ impl<T: Config> Module<T> {
         <frame_system::Pallet<T>>::block_number()
     }
 
+    // Returns whether none of the proposal dependencies awaits a decision or execution anymore.
+    fn are_proposal_dependencies_finalized(proposal_id: &T::ProposalId) -> bool {
+        Self::proposal_dependencies(proposal_id)
+            .iter()
+            .all(|dependency| !<Proposals<T>>::contains_key(dependency))
+    }
+
+    // Removes the proposal dependencies and stops tracking the executed dependencies that no
+    // other pending proposal depends on.
+    fn release_proposal_dependencies(proposal_id: &T::ProposalId) {
+        for dependency in <ProposalDependencies<T>>::take(proposal_id).iter() {
+            let dependents_left = <DependentProposalsCount<T>>::get(dependency).saturating_sub(1);
+
+            if dependents_left == 0 {
+                <DependentProposalsCount<T>>::remove(dependency);
+                <ExecutedProposals<T>>::remove(dependency);
+            } else {
+                <DependentProposalsCount<T>>::insert(dependency, dependents_left);
+            }
+        }
+    }
+
+    // Weight of releasing the dependencies of a finalized proposal in the worst case.
+    fn release_proposal_dependencies_weight() -> Weight {
+        let max_dependencies: u64 = T::MaxProposalDependencies::get().into();
+
+        T::DbWeight::get().reads_writes(
+            max_dependencies.saturating_add(1),
+            max_dependencies.saturating_mul(2).saturating_add(1),
+        )
+    }
+
+    // Returns whether all the proposal dependencies were successfully executed.
+    fn are_proposal_dependencies_executed(proposal_id: &T::ProposalId) -> bool {
+        Self::proposal_dependencies(proposal_id)
+            .iter()
+            .all(<ExecutedProposals<T>>::contains_key)
+    }
+
     // Executes proposal code.
     // Returns the weight of the proposal(wether execution failed or not) or 0 if the proposal
-    // couldn't be decoded.
+    // couldn't be decoded or its dependencies weren't executed.
     fn execute_proposal(proposal_id: T::ProposalId) -> Weight {
         let proposal_code = Self::proposal_codes(proposal_id);
 
@@ -882,7 +1121,12 @@ impl<T: Config> Module<T> {
 
         let mut execution_code_weight = 0;
 
+        let dependencies_executed = Self::are_proposal_dependencies_executed(&proposal_id);
+
         let execution_status = match proposal_code_result {
+            Ok(_) if !dependencies_executed => {
+                ExecutionStatus::failed_execution("Proposal dependency was not executed")
+            }
             Ok(proposal_code) => {
                 execution_code_weight = proposal_code.get_dispatch_info().weight;
 
@@ -893,6 +1137,17 @@ impl<T: Config> Module<T> {
                         dispatch_error.error,
                     ))
                 } else {
+                    // executed proposals are only tracked while other proposals depend on them
+                    if <DependentProposalsCount<T>>::contains_key(proposal_id) {
+                        <ExecutedProposals<T>>::insert(proposal_id, ());
+                    }
+
+                    let proposal = Self::proposals(proposal_id);
+                    T::MemberReputationHandler::record_activity(
+                        &proposal.proposer_id,
+                        ReputationActivity::ProposalExecuted,
+                    );
+
                     ExecutionStatus::Executed
                 }
             }
@@ -953,7 +1208,9 @@ impl<T: Config> Module<T> {
             ));
 
             // immediately execute proposal if it ready for execution or save it for the future otherwise.
-            if finalized_proposal.is_ready_for_execution(now) {
+            if finalized_proposal.is_ready_for_execution(now)
+                && Self::are_proposal_dependencies_finalized(&proposal_id)
+            {
                 executed_weight = Self::execute_proposal(proposal_id);
             } else {
                 <Proposals<T>>::insert(proposal_id, finalized_proposal);
@@ -1028,6 +1285,7 @@ impl<T: Config> Module<T> {
         <Proposals<T>>::remove(proposal_id);
         <DispatchableCallCode<T>>::remove(proposal_id);
         <VoteExistsByProposalByVoter<T>>::remove_prefix(&proposal_id, None);
+        Self::release_proposal_dependencies(proposal_id);
         let _ = Self::decrease_active_proposal_counter();
 
         T::ProposalObserver::proposal_removed(proposal_id);
@@ -1060,9 +1318,12 @@ impl<T: Config> Module<T> {
                         ));
                     }
                 }
-                // Execute the proposal code if the proposal is ready for execution.
+                // Execute the proposal code if the proposal is ready for execution and all its
+                // dependencies are finalized.
                 ProposalStatus::PendingExecution(_) => {
-                    if proposal.is_ready_for_execution(now) {
+                    if proposal.is_ready_for_execution(now)
+                        && Self::are_proposal_dependencies_finalized(&proposal_id)
+                    {
                         executed_weight =
                             executed_weight.saturating_add(Self::execute_proposal(proposal_id));
                     }
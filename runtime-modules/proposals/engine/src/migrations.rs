@@ -0,0 +1,55 @@
+// Storage migrations of the proposals engine pallet.
+
+use codec::{Decode, Encode};
+use frame_support::storage::IterableStorageMap;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+
+use crate::types::{BalanceOf, ProposalOf, ProposalParameters, ProposalStatus, VotingResults};
+use crate::{Config, Module, Proposals};
+use common::MemberId;
+
+/// Proposal stored before the proposal amendments were introduced
+#[derive(Encode, Decode)]
+pub(crate) struct LegacyProposal<BlockNumber, ProposerId, Balance, AccountId> {
+    pub parameters: ProposalParameters<BlockNumber, Balance>,
+    pub proposer_id: ProposerId,
+    pub activated_at: BlockNumber,
+    pub status: ProposalStatus<BlockNumber>,
+    pub voting_results: VotingResults,
+    pub exact_execution_block: Option<BlockNumber>,
+    pub nr_of_council_confirmations: u32,
+    pub staking_account_id: Option<AccountId>,
+}
+
+pub(crate) type LegacyProposalOf<T> = LegacyProposal<
+    <T as frame_system::Config>::BlockNumber,
+    MemberId<T>,
+    BalanceOf<T>,
+    <T as frame_system::Config>::AccountId,
+>;
+
+impl<T: Config> Module<T> {
+    /// Translates the stored proposals to the current `Proposal` format: the voting period
+    /// of the existing proposals wasn't extended.
+    pub fn migrate_proposals() -> Weight {
+        let mut translated: u64 = 0;
+
+        Proposals::<T>::translate::<LegacyProposalOf<T>, _>(|_, proposal| {
+            translated = translated.saturating_add(1);
+            Some(ProposalOf::<T> {
+                parameters: proposal.parameters,
+                proposer_id: proposal.proposer_id,
+                activated_at: proposal.activated_at,
+                voting_period_extension: Default::default(),
+                status: proposal.status,
+                voting_results: proposal.voting_results,
+                exact_execution_block: proposal.exact_execution_block,
+                nr_of_council_confirmations: proposal.nr_of_council_confirmations,
+                staking_account_id: proposal.staking_account_id,
+            })
+        });
+
+        T::DbWeight::get().reads_writes(translated, translated)
+    }
+}
@@ -75,6 +75,7 @@ impl referendum::Config<ReferendumInstance> for Test {
     type WeightInfo = ();
 
     type MaxWinnerTargetCount = MaxWinnerTargetCount;
+    type MaxDelegatorsPerDelegate = ConstU32<10>;
 
     fn calculate_vote_power(
         _: &<Self as frame_system::Config>::AccountId,
@@ -148,6 +149,13 @@ impl membership::Config for Test {
         staking_handler::StakingManager<Self, StakingCandidateLockId>;
     type CandidateStake = CandidateStake;
     type DefaultMemberInvitesCount = DefaultMemberInvitesCount;
+    type MemberReputationRules = ();
+    type MaxRecoveryGuardians = ();
+    type RecoveryDelay = ();
+    type RecoveryDeposit = ();
+    type HandleExpiryPeriod = ();
+    type HandleExpiryGracePeriod = ();
+    type MaxUplineReferralLevels = ();
 }
 
 pub struct Wg;
@@ -211,11 +219,15 @@ impl common::working_group::WorkingGroupAuthenticator<Test> for Wg {
 
 parameter_types! {
     pub const DispatchableCallCodeMaxLen: u32 = 1024 * 1024;
+    pub const MaxProposalDependencies: u32 = 2;
+    pub const ProposalAmendmentVotingPeriodRule: crate::AmendmentVotingPeriodRule<u64> =
+        crate::AmendmentVotingPeriodRule::ExtendTo(5);
 }
 
 impl crate::Config for Test {
     type Event = Event;
     type ProposerOriginValidator = ();
+    type MemberReputationHandler = ();
     type CouncilOriginValidator = ();
     type TotalVotersCounter = ();
     type ProposalId = u32;
@@ -230,6 +242,8 @@ impl crate::Config for Test {
     type WeightInfo = ();
     type StakingAccountValidator = ();
     type DispatchableCallCodeMaxLen = DispatchableCallCodeMaxLen;
+    type MaxProposalDependencies = MaxProposalDependencies;
+    type AmendmentVotingPeriodRule = ProposalAmendmentVotingPeriodRule;
 }
 
 pub const STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER: u64 = 222;
@@ -353,14 +367,22 @@ impl council::Config for Test {
 
     type Referendum = referendum::Module<Test, ReferendumInstance>;
 
+    type RecallReferendum = referendum::Module<Test, ReferendumInstance>;
+
     type MinNumberOfExtraCandidates = MinNumberOfExtraCandidates;
     type CouncilSize = CouncilSize;
+    type StaggeredSeatsPerCycle = ConstU32<0>;
+    type MaxConsecutiveTerms = ConstU32<0>;
+    type MaxRunnersUp = ConstU32<0>;
     type AnnouncingPeriodDuration = AnnouncingPeriodDuration;
     type IdlePeriodDuration = IdlePeriodDuration;
     type MinCandidateStake = MinCandidateStake;
 
     type CandidacyLock = StakingManager<Self, CandidacyLockId>;
     type CouncilorLock = StakingManager<Self, CouncilorLockId>;
+    type RecallPetitionLock = StakingManager<Self, common::locks::RecallPetitionLockId>;
+    type MinRecallPetitionStake = ConstU64<100>;
+    type RecallPetitionThreshold = ConstU64<10000>;
 
     type ElectedMemberRewardPeriod = ElectedMemberRewardPeriod;
 
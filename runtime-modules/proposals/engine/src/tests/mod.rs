@@ -83,6 +83,7 @@ struct DummyProposalFixture {
     description: Vec<u8>,
     staking_account_id: Option<u64>,
     exact_execution_block: Option<u64>,
+    dependencies: Vec<u32>,
 }
 
 impl Default for DummyProposalFixture {
@@ -112,6 +113,7 @@ impl Default for DummyProposalFixture {
             description,
             staking_account_id: None,
             exact_execution_block: None,
+            dependencies: Vec::new(),
         }
     }
 }
@@ -140,6 +142,13 @@ impl DummyProposalFixture {
         }
     }
 
+    fn with_dependencies(self, dependencies: Vec<u32>) -> Self {
+        DummyProposalFixture {
+            dependencies,
+            ..self
+        }
+    }
+
     fn with_stake(self, account_id: u64) -> Self {
         DummyProposalFixture {
             staking_account_id: Some(account_id),
@@ -164,6 +173,7 @@ impl DummyProposalFixture {
             staking_account_id: self.staking_account_id,
             encoded_dispatchable_call_code: self.proposal_code,
             exact_execution_block: self.exact_execution_block,
+            dependencies: self.dependencies,
         });
         assert_eq!(proposal_id_result, result);
 
@@ -1003,6 +1013,7 @@ fn proposal_execution_postponed_because_of_grace_period() {
                 parameters: parameters_fixture.params(),
                 proposer_id: 1,
                 activated_at: 0,
+                voting_period_extension: 0,
                 status: ProposalStatus::approved(ApprovedProposalDecision::PendingExecution, 1),
                 voting_results: VotingResults {
                     abstentions: 0,
@@ -1057,6 +1068,7 @@ fn cancel_active_and_pending_execution_proposal_by_runtime() {
                 parameters: parameters_fixture.params(),
                 proposer_id: 1,
                 activated_at: starting_block,
+                voting_period_extension: 0,
                 status: ProposalStatus::approved(
                     ApprovedProposalDecision::PendingExecution,
                     starting_block + 1
@@ -1080,6 +1092,7 @@ fn cancel_active_and_pending_execution_proposal_by_runtime() {
                 parameters: parameters_fixture.params(),
                 proposer_id: 1,
                 activated_at: starting_block,
+                voting_period_extension: 0,
                 status: ProposalStatus::Active,
                 voting_results: VotingResults {
                     abstentions: 0,
@@ -1173,6 +1186,7 @@ fn cancel_pending_constitutionality_proposal_by_runtime() {
                 parameters: parameters_fixture.params(),
                 proposer_id: 1,
                 activated_at: starting_block,
+                voting_period_extension: 0,
                 status: ProposalStatus::PendingConstitutionality,
                 voting_results: VotingResults {
                     abstentions: 0,
@@ -1230,6 +1244,7 @@ fn proposal_execution_succeeds_after_the_grace_period() {
             parameters: parameters_fixture.params(),
             proposer_id: 1,
             activated_at: starting_block,
+            voting_period_extension: 0,
             status: ProposalStatus::approved(
                 ApprovedProposalDecision::PendingExecution,
                 starting_block + 1,
@@ -1257,6 +1272,169 @@ fn proposal_execution_succeeds_after_the_grace_period() {
     });
 }
 
+#[test]
+fn create_proposal_fails_with_invalid_dependencies() {
+    initial_test_ext().execute_with(|| {
+        let dependency_id = DummyProposalFixture::default()
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+
+        DummyProposalFixture::default()
+            .with_dependencies(vec![dependency_id, 2, 3])
+            .create_proposal_and_assert(Err(Error::<Test>::MaxProposalDependenciesExceeded.into()));
+
+        DummyProposalFixture::default()
+            .with_dependencies(vec![dependency_id, 10])
+            .create_proposal_and_assert(Err(Error::<Test>::InvalidProposalDependency.into()));
+
+        DummyProposalFixture::default()
+            .with_dependencies(vec![dependency_id])
+            .create_proposal_and_assert(Ok(2));
+    });
+}
+
+#[test]
+fn proposal_execution_waits_for_dependencies() {
+    initial_test_ext().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let dependency_parameters = ProposalParametersFixture::default().with_grace_period(3);
+        let dependency_id = DummyProposalFixture::default()
+            .with_parameters(dependency_parameters.params())
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+
+        let proposal_id = DummyProposalFixture::default()
+            .with_parameters(ProposalParametersFixture::default().params())
+            .with_dependencies(vec![dependency_id])
+            .create_proposal_and_assert(Ok(2))
+            .unwrap();
+
+        for id in [dependency_id, proposal_id] {
+            let mut vote_generator = VoteGenerator::new(id);
+            vote_generator.vote_and_assert_ok(VoteKind::Approve);
+            vote_generator.vote_and_assert_ok(VoteKind::Approve);
+            vote_generator.vote_and_assert_ok(VoteKind::Approve);
+            vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        }
+
+        run_to_block(3);
+
+        // The dependency is still in its grace period.
+        assert_eq!(
+            <crate::Proposals<Test>>::get(proposal_id).status,
+            ProposalStatus::PendingExecution(starting_block + 1)
+        );
+
+        run_to_block(7);
+
+        assert!(!<crate::Proposals<Test>>::contains_key(dependency_id));
+        assert!(!<crate::Proposals<Test>>::contains_key(proposal_id));
+        // executed proposals are not tracked once no pending proposal depends on them
+        assert!(!<crate::ExecutedProposals<Test>>::contains_key(
+            dependency_id
+        ));
+        assert!(!<crate::ExecutedProposals<Test>>::contains_key(proposal_id));
+        assert!(!<crate::DependentProposalsCount<Test>>::contains_key(
+            dependency_id
+        ));
+        assert!(!<crate::ProposalDependencies<Test>>::contains_key(
+            proposal_id
+        ));
+    });
+}
+
+#[test]
+fn executed_proposal_tracked_while_dependent_proposal_is_pending() {
+    initial_test_ext().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let dependency_id = DummyProposalFixture::default()
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+
+        let proposal_id = DummyProposalFixture::default()
+            .with_dependencies(vec![dependency_id])
+            .create_proposal_and_assert(Ok(2))
+            .unwrap();
+
+        assert_eq!(
+            <crate::DependentProposalsCount<Test>>::get(dependency_id),
+            1
+        );
+
+        let mut vote_generator = VoteGenerator::new(dependency_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block(5);
+
+        assert!(!<crate::Proposals<Test>>::contains_key(dependency_id));
+        assert!(<crate::ExecutedProposals<Test>>::contains_key(
+            dependency_id
+        ));
+
+        VetoProposalFixture::new(proposal_id).veto_and_assert(Ok(()));
+
+        assert!(!<crate::ExecutedProposals<Test>>::contains_key(
+            dependency_id
+        ));
+        assert!(!<crate::DependentProposalsCount<Test>>::contains_key(
+            dependency_id
+        ));
+
+        // finalized proposals can't be used as dependencies
+        DummyProposalFixture::default()
+            .with_dependencies(vec![dependency_id])
+            .create_proposal_and_assert(Err(Error::<Test>::InvalidProposalDependency.into()));
+    });
+}
+
+#[test]
+fn proposal_execution_fails_with_not_executed_dependency() {
+    initial_test_ext().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let dependency_parameters = ProposalParametersFixture::default().with_grace_period(3);
+        let dependency_id = DummyProposalFixture::default()
+            .with_parameters(dependency_parameters.params())
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+
+        let proposal_id = DummyProposalFixture::default()
+            .with_parameters(ProposalParametersFixture::default().params())
+            .with_dependencies(vec![dependency_id])
+            .create_proposal_and_assert(Ok(2))
+            .unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block(2);
+
+        assert!(<crate::Proposals<Test>>::contains_key(proposal_id));
+
+        VetoProposalFixture::new(dependency_id).veto_and_assert(Ok(()));
+
+        run_to_block(3);
+
+        EventFixture::assert_last_crate_event(RawEvent::ProposalExecuted(
+            proposal_id,
+            ExecutionStatus::failed_execution("Proposal dependency was not executed"),
+        ));
+        assert!(!<crate::Proposals<Test>>::contains_key(proposal_id));
+        assert!(!<crate::ExecutedProposals<Test>>::contains_key(proposal_id));
+    });
+}
+
 #[test]
 fn create_proposal_fails_on_exceeding_max_active_proposals_count() {
     initial_test_ext().execute_with(|| {
@@ -1301,6 +1479,7 @@ fn create_dummy_proposal_succeeds_with_stake() {
                 parameters: parameters_fixture.params(),
                 proposer_id: 1,
                 activated_at: 0,
+                voting_period_extension: 0,
                 voting_results: VotingResults::default(),
                 exact_execution_block: None,
                 nr_of_council_confirmations: 0,
@@ -1426,6 +1605,7 @@ fn finalize_expired_proposal_and_check_stake_removing_with_balance_checks_succee
             parameters,
             proposer_id: 1,
             activated_at: starting_block,
+            voting_period_extension: 0,
             status: ProposalStatus::Active,
             voting_results: VotingResults::default(),
             exact_execution_block: None,
@@ -1497,6 +1677,7 @@ fn proposal_cancellation_with_slashes_with_balance_checks_succeeds() {
             parameters,
             proposer_id: 1,
             activated_at: starting_block,
+            voting_period_extension: 0,
             status: ProposalStatus::Active,
             voting_results: VotingResults::default(),
             exact_execution_block: None,
@@ -1812,6 +1993,7 @@ fn proposal_execution_with_exact_execution_works() {
                 parameters: parameters_fixture.params(),
                 proposer_id: 1,
                 activated_at: 0,
+                voting_period_extension: 0,
                 status: ProposalStatus::approved(ApprovedProposalDecision::PendingExecution, 1),
                 voting_results: VotingResults {
                     abstentions: 0,
@@ -1880,6 +2062,7 @@ fn proposal_with_pending_constitutionality_succeeds() {
                 parameters: parameters_fixture.params(),
                 proposer_id: 1,
                 activated_at: starting_block,
+                voting_period_extension: 0,
                 status: ProposalStatus::approved(
                     ApprovedProposalDecision::PendingConstitutionality,
                     starting_block
@@ -1942,6 +2125,7 @@ fn proposal_with_pending_constitutionality_reactivation_succeeds() {
                 parameters: parameters_fixture.params(),
                 proposer_id: 1,
                 activated_at: starting_block,
+                voting_period_extension: 0,
                 status: ProposalStatus::approved(
                     ApprovedProposalDecision::PendingConstitutionality,
                     starting_block
@@ -1969,6 +2153,7 @@ fn proposal_with_pending_constitutionality_reactivation_succeeds() {
             proposal,
             Proposal {
                 activated_at: reactivation_block,
+                voting_period_extension: 0,
                 status: ProposalStatus::Active,
                 voting_results: VotingResults::default(),
                 ..initial_proposal
@@ -2069,6 +2254,7 @@ fn proposal_with_pending_constitutionality_execution_succeeds() {
                 parameters: parameters_fixture.params(),
                 proposer_id: 1,
                 activated_at: starting_block,
+                voting_period_extension: 0,
                 status: ProposalStatus::approved(
                     ApprovedProposalDecision::PendingConstitutionality,
                     starting_block + 1
@@ -2103,6 +2289,7 @@ fn proposal_with_pending_constitutionality_execution_succeeds() {
             proposal,
             Proposal {
                 activated_at: reactivation_block,
+                voting_period_extension: 0,
                 status: ProposalStatus::Active,
                 voting_results: VotingResults::default(),
                 ..initial_proposal
@@ -2708,3 +2895,179 @@ fn proposal_status_resolution_is_slashing_achievable_works_correctly() {
     assert!(slashing_threshold_achievable_resolution.is_slashing_threshold_achievable());
     assert!(!slashing_threshold_achievable_resolution.is_rejection_imminent());
 }
+
+#[test]
+fn amend_proposal_succeeds_without_votes() {
+    initial_test_ext().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let proposal_id = DummyProposalFixture::default()
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+        let proposer_id = <crate::Proposals<Test>>::get(proposal_id).proposer_id;
+
+        let new_code = mock::proposals::Call::<Test>::dummy_proposal {
+            _title: b"amended title".to_vec(),
+            _description: b"amended description".to_vec(),
+        }
+        .encode();
+
+        assert_eq!(
+            ProposalsEngine::amend_proposal(proposer_id, proposal_id, new_code.clone()),
+            Ok(false)
+        );
+
+        // The voting period is extended to last at least 5 blocks, the activation block is kept.
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+        assert_eq!(proposal.activated_at, starting_block);
+        assert_eq!(proposal.voting_period_extension, 2);
+        assert_eq!(
+            <crate::DispatchableCallCode<Test>>::get(proposal_id).to_vec(),
+            new_code
+        );
+
+        EventFixture::assert_last_crate_event(RawEvent::ProposalAmended(proposal_id, false, 6));
+    });
+}
+
+#[test]
+fn amend_proposal_resets_votes() {
+    initial_test_ext().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let proposal_id = DummyProposalFixture::default()
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+        let proposer_id = <crate::Proposals<Test>>::get(proposal_id).proposer_id;
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block(3);
+
+        assert_eq!(
+            ProposalsEngine::amend_proposal(proposer_id, proposal_id, Vec::new()),
+            Ok(true)
+        );
+
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+        assert_eq!(proposal.activated_at, starting_block);
+        assert_eq!(proposal.voting_period_extension, 4);
+        assert_eq!(proposal.voting_results, VotingResults::default());
+        assert!(!<crate::VoteExistsByProposalByVoter<Test>>::contains_key(
+            proposal_id,
+            1
+        ));
+
+        EventFixture::assert_last_crate_event(RawEvent::ProposalAmended(proposal_id, true, 8));
+
+        // The same voter can vote again on the amended proposal.
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+    });
+}
+
+#[test]
+fn amended_proposal_voting_period_ends_after_extension() {
+    initial_test_ext().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let proposal_id = DummyProposalFixture::default()
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+        let proposer_id = <crate::Proposals<Test>>::get(proposal_id).proposer_id;
+
+        assert_eq!(
+            ProposalsEngine::amend_proposal(proposer_id, proposal_id, Vec::new()),
+            Ok(false)
+        );
+
+        // The original voting period end has passed.
+        run_to_block(5);
+
+        assert_eq!(
+            <crate::Proposals<Test>>::get(proposal_id).status,
+            ProposalStatus::Active
+        );
+
+        run_to_block(7);
+
+        assert!(!<crate::Proposals<Test>>::contains_key(proposal_id));
+    });
+}
+
+#[test]
+fn amend_proposal_fails_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        run_to_block(1);
+
+        assert_eq!(
+            ProposalsEngine::amend_proposal(1, 1, Vec::new()),
+            Err(Error::<Test>::ProposalNotFound.into())
+        );
+
+        let parameters = ProposalParametersFixture::default().with_grace_period(5);
+        let proposal_id = DummyProposalFixture::default()
+            .with_parameters(parameters.params())
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+        let proposer_id = <crate::Proposals<Test>>::get(proposal_id).proposer_id;
+
+        assert_eq!(
+            ProposalsEngine::amend_proposal(proposer_id + 1, proposal_id, Vec::new()),
+            Err(Error::<Test>::NotAuthor.into())
+        );
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block(2);
+
+        assert_eq!(
+            ProposalsEngine::amend_proposal(proposer_id, proposal_id, Vec::new()),
+            Err(Error::<Test>::ProposalNotActive.into())
+        );
+    });
+}
+
+#[test]
+fn migrate_proposals_ok() {
+    initial_test_ext().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let proposal_id = DummyProposalFixture::default()
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+
+        let legacy_proposal = crate::migrations::LegacyProposalOf::<Test> {
+            parameters: proposal.parameters,
+            proposer_id: proposal.proposer_id,
+            activated_at: proposal.activated_at,
+            status: proposal.status.clone(),
+            voting_results: proposal.voting_results.clone(),
+            exact_execution_block: proposal.exact_execution_block,
+            nr_of_council_confirmations: proposal.nr_of_council_confirmations,
+            staking_account_id: proposal.staking_account_id,
+        };
+        frame_support::storage::unhashed::put(
+            &<crate::Proposals<Test>>::hashed_key_for(proposal_id),
+            &legacy_proposal,
+        );
+
+        ProposalsEngine::migrate_proposals();
+
+        assert_eq!(<crate::Proposals<Test>>::get(proposal_id), proposal);
+        assert_eq!(
+            <crate::Proposals<Test>>::get(proposal_id).voting_period_extension,
+            0
+        );
+    });
+}
@@ -133,6 +133,9 @@ pub struct Proposal<BlockNumber, ProposerId, Balance, AccountId> {
     /// where the proposal was automatically activated due to constitutionality.
     pub activated_at: BlockNumber,
 
+    /// Number of blocks the voting period was extended by amendments of the proposal.
+    pub voting_period_extension: BlockNumber,
+
     /// Current proposal status
     pub status: ProposalStatus<BlockNumber>,
 
@@ -158,6 +161,7 @@ impl<BlockNumber: Default, ProposerId: Default, Balance: Default, AccountId> Def
             parameters: Default::default(),
             proposer_id: Default::default(),
             activated_at: Default::default(),
+            voting_period_extension: Default::default(),
             status: Default::default(),
             voting_results: Default::default(),
             exact_execution_block: Default::default(),
@@ -178,11 +182,16 @@ where
         self.nr_of_council_confirmations = self.nr_of_council_confirmations.saturating_add(1);
     }
 
+    /// Returns the block the voting period ends at (including the amendment extension).
+    pub fn voting_period_end(&self) -> BlockNumber {
+        self.activated_at
+            .saturating_add(self.parameters.voting_period)
+            .saturating_add(self.voting_period_extension)
+    }
+
     /// Returns whether voting period expired by now
     pub fn is_voting_period_expired(&self, now: BlockNumber) -> bool {
-        now >= self
-            .activated_at
-            .saturating_add(self.parameters.voting_period)
+        now >= self.voting_period_end()
     }
 
     /// Returns whether grace period expired by now.
@@ -402,8 +411,29 @@ pub trait ProposalCodeDecoder<T: frame_system::Config> {
     ) -> Result<Box<dyn ProposalExecutable>, &'static str>;
 }
 
+/// Defines how the voting period is changed when an active proposal is amended.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum AmendmentVotingPeriodRule<BlockNumber> {
+    /// The voting period is not changed.
+    Keep,
+
+    /// The voting period starts again from the amendment block.
+    Restart,
+
+    /// The voting period is extended (if needed) to last at least the provided number of blocks
+    /// after the amendment block.
+    ExtendTo(BlockNumber),
+}
+
+impl<BlockNumber> Default for AmendmentVotingPeriodRule<BlockNumber> {
+    fn default() -> Self {
+        Self::Restart
+    }
+}
+
 /// Containter-type for a proposal creation method.
-pub struct ProposalCreationParameters<BlockNumber, Balance, MemberId, AccountId> {
+pub struct ProposalCreationParameters<BlockNumber, Balance, MemberId, AccountId, ProposalId> {
     /// Account id of the proposer.
     pub account_id: AccountId,
 
@@ -428,6 +458,9 @@ pub struct ProposalCreationParameters<BlockNumber, Balance, MemberId, AccountId>
     /// Exact block for the proposal execution.
     /// Should be greater than starting block + grace_period if set.
     pub exact_execution_block: Option<BlockNumber>,
+
+    /// Proposals that must be executed before this proposal can be executed.
+    pub dependencies: Vec<ProposalId>,
 }
 
 /// Balance alias for `balances` module.
@@ -277,6 +277,29 @@ fn member_funded_account<T: Config<I> + membership::Config, I: Instance>(
     (account_id, member_id)
 }
 
+// Delegate voting stake of `number_of_delegators` new accounts to the delegate.
+fn delegate_votes_to<T: Config<I>, I: Instance>(
+    delegate: &T::AccountId,
+    number_of_delegators: u32,
+) -> Vec<T::AccountId> {
+    let stake = T::MinimumStake::get() + One::one();
+
+    (0..number_of_delegators)
+        .map(|i| {
+            let account_id = funded_account::<T, I>("delegator", i);
+
+            Referendum::<T, I>::delegate_vote(
+                RawOrigin::Signed(account_id.clone()).into(),
+                delegate.clone(),
+                stake,
+            )
+            .unwrap();
+
+            account_id
+        })
+        .collect()
+}
+
 #[derive(Clone, PartialEq, Eq, Debug, Default)]
 struct MultipleVotesWithExtraVote<T: Config<I>, I: Instance> {
     pub intermediate_winners: Vec<OptionResult<T::MemberId, T::VotePower>>,
@@ -777,6 +800,181 @@ benchmarks_instance! {
         assert_last_event::<T, I>(RawEvent::StakeReleased(account_id).into());
     }
 
+    vote_override_delegation {
+        let i in 0 .. (T::MaxDelegatorsPerDelegate::get() - 1) as u32;
+
+        start_voting_cycle::<T, I>(0);
+
+        let delegate = funded_account::<T, I>("delegate", 0);
+        delegate_votes_to::<T, I>(&delegate, i);
+
+        let account_id = funded_account::<T, I>("caller", 0);
+        let stake = T::MinimumStake::get() + One::one();
+        Referendum::<T, I>::delegate_vote(
+            RawOrigin::Signed(account_id.clone()).into(),
+            delegate.clone(),
+            stake,
+        )
+        .unwrap();
+
+        let salt = vec![0u8];
+        let cycle_id = 1;
+        let commitment =
+            Referendum::<T, I>::calculate_commitment(
+                &account_id,
+                &salt,
+                &cycle_id,
+                &Zero::zero()
+            );
+    }: vote(RawOrigin::Signed(account_id.clone()), commitment, stake)
+    verify {
+        assert!(
+            !VoteDelegations::<T, I>::contains_key(account_id.clone()),
+            "Delegation not removed"
+        );
+
+        assert_eq!(
+            Referendum::<T, I>::votes(account_id.clone()),
+            CastVote {
+                commitment,
+                stake,
+                cycle_id,
+                vote_for: None,
+            },
+            "Vote is not correctly Stored",
+        );
+
+        assert_last_event::<T, I>(RawEvent::VoteCast(account_id, commitment, stake).into());
+    }
+
+    reveal_vote_with_delegators {
+        let i in 0 .. (T::MaxWinnerTargetCount::get() - 1) as u32;
+
+        let j in 0 .. T::MaxDelegatorsPerDelegate::get();
+
+        start_voting_cycle::<T, I>(i);
+
+        let cycle_id = 1;
+        let salt = vec![0u8];
+        let started_voting_block_number = System::<T>::block_number();
+
+        let multiple_votes = make_multiple_votes_for_multiple_options::<T, I>(i, cycle_id);
+
+        let (account_id, option_id, _) = create_account_and_vote::<T, I>(
+            "caller",
+            2 * i + 1,
+            2 * (i + 1), // Greater than number of voters + number of candidates
+            cycle_id,
+            One::one(),
+        );
+
+        let delegators = delegate_votes_to::<T, I>(&account_id, j);
+
+        let target_block_number = T::VoteStageDuration::get() + started_voting_block_number;
+        let target_stage = ReferendumStage::Revealing(ReferendumStageRevealingOf::<T, I> {
+            started: target_block_number,
+            winning_target_count: i + 1,
+            intermediate_winners: WeakBoundedVec::default(),
+            current_cycle_id: cycle_id.into(),
+            ends_at: target_block_number + T::RevealStageDuration::get(),
+        });
+
+        move_to_block::<T, I>(target_block_number, target_stage);
+
+        multiple_votes.votes.into_iter().for_each(|v| {
+            Referendum::<T, I>::reveal_vote(
+                RawOrigin::Signed(v.account_id).into(),
+                v.salt,
+                v.member_id,
+            )
+            .unwrap();
+        });
+    }: reveal_vote(RawOrigin::Signed(account_id.clone()), salt.clone(), option_id)
+    verify {
+        for delegator in delegators {
+            assert_eq!(
+                Referendum::<T, I>::votes(delegator).vote_for,
+                Some(option_id),
+                "Delegated vote not revealed",
+            );
+        }
+
+        if j > 0 {
+            assert_last_event::<T, I>(
+                RawEvent::DelegatedVotesRevealed(account_id, option_id, j).into()
+            );
+        } else {
+            assert_last_event::<T, I>(RawEvent::VoteRevealed(account_id, option_id, salt).into());
+        }
+    }
+
+    release_delegated_vote_stake {
+        let i in 0 .. (T::MaxDelegatorsPerDelegate::get() - 1) as u32;
+
+        start_voting_cycle::<T, I>(0);
+
+        let delegate = funded_account::<T, I>("delegate", 0);
+        delegate_votes_to::<T, I>(&delegate, i);
+
+        let account_id = funded_account::<T, I>("caller", 0);
+        Referendum::<T, I>::delegate_vote(
+            RawOrigin::Signed(account_id.clone()).into(),
+            delegate.clone(),
+            T::MinimumStake::get() + One::one(),
+        )
+        .unwrap();
+
+        let started_voting_block_number = System::<T>::block_number() + One::one();
+        let target_block_number = T::RevealStageDuration::get() +
+            T::VoteStageDuration::get() +
+            started_voting_block_number;
+
+        move_to_block::<T, I>(target_block_number, ReferendumStage::Inactive);
+    }: release_vote_stake(RawOrigin::Signed(account_id.clone()))
+    verify {
+        assert!(!Votes::<T, I>::contains_key(account_id.clone()), "Vote not removed");
+        assert!(
+            !VoteDelegations::<T, I>::contains_key(account_id.clone()),
+            "Delegation not removed"
+        );
+
+        assert_last_event::<T, I>(RawEvent::StakeReleased(account_id).into());
+    }
+
+    delegate_vote {
+        let i in 0 .. (T::MaxDelegatorsPerDelegate::get() - 1) as u32;
+
+        start_voting_cycle::<T, I>(0);
+
+        // Worst case the caller switches from a delegate with the most delegators
+        let old_delegate = funded_account::<T, I>("old_delegate", 0);
+        delegate_votes_to::<T, I>(&old_delegate, i);
+
+        let account_id = funded_account::<T, I>("caller", 0);
+        let stake = T::MinimumStake::get() + One::one();
+        Referendum::<T, I>::delegate_vote(
+            RawOrigin::Signed(account_id.clone()).into(),
+            old_delegate.clone(),
+            stake,
+        )
+        .unwrap();
+
+        let delegate = funded_account::<T, I>("delegate", 0);
+    }: _ (RawOrigin::Signed(account_id.clone()), delegate.clone(), stake)
+    verify {
+        assert_eq!(
+            Referendum::<T, I>::vote_delegations(account_id.clone()),
+            Some(delegate.clone()),
+            "Delegation not stored"
+        );
+        assert!(
+            !Referendum::<T, I>::delegators(old_delegate).contains(&account_id),
+            "Previous delegation not removed"
+        );
+
+        assert_last_event::<T, I>(RawEvent::VoteDelegated(account_id, delegate, stake).into());
+    }
+
     impl_benchmark_test_suite!(
         Module,
         crate::mock::build_test_externalities(),
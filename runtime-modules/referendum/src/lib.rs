@@ -22,6 +22,21 @@
 //! - [vote](./struct.Module.html#method.vote)
 //! - [reveal_vote](./struct.Module.html#method.reveal_vote)
 //! - [release_vote_stake](./struct.Module.html#method.release_vote_stake)
+//! - [delegate_vote](./struct.Module.html#method.delegate_vote)
+//!
+//! ## Vote delegation
+//! Instead of voting directly, users can delegate their voting stake to another account. The
+//! delegated stake is locked the same way as the stake of a regular vote and it is released via
+//! `release_vote_stake`. Whenever the delegate reveals a vote, the vote power of all their
+//! delegators is added to the revealed option and the delegators' votes are recorded as cast
+//! for that option. Delegation stays in place for the following referendum cycles until the
+//! delegator releases the stake or overrides it by casting a vote directly. Delegation is not
+//! transitive - a delegate's own delegation is not followed.
+//!
+//! Like the votes themselves, delegations are keyed by the voting account (`AccountId`), not by
+//! the member id: the delegator's stake is locked on the account that signed `delegate_vote` and
+//! the delegate is the account whose revealed vote is counted. A member voting from several
+//! accounts delegates the stake of each of them separately.
 //!
 //! ## Notes
 //! This module is instantiable pallet as described here https://substrate.dev/recipes/3-entrees/instantiable.html
@@ -176,6 +191,10 @@ pub type ReferendumStageVotingOf<T> =
     ReferendumStageVoting<<T as frame_system::Config>::BlockNumber>;
 pub type ReferendumStageRevealingOf<T, I> =
     ReferendumStageRevealing<<T as frame_system::Config>::BlockNumber, IntermediateWinnersOf<T, I>>;
+pub type DelegatorsOf<T, I> = WeakBoundedVec<
+    <T as frame_system::Config>::AccountId,
+    <T as Config<I>>::MaxDelegatorsPerDelegate,
+>;
 pub type OptionResultOf<T, I> =
     OptionResult<<T as common::membership::MembershipTypes>::MemberId, <T as Config<I>>::VotePower>;
 
@@ -275,6 +294,9 @@ pub trait Config<I: Instance = DefaultInstance>:
     /// Maximum number of winning target count
     type MaxWinnerTargetCount: Get<u32>;
 
+    /// Maximum number of accounts that can delegate their voting stake to a single account.
+    type MaxDelegatorsPerDelegate: Get<u32>;
+
     /// Calculate the vote's power for user and his stake.
     fn calculate_vote_power(
         account_id: &<Self as frame_system::Config>::AccountId,
@@ -314,6 +336,16 @@ decl_storage! { generate_storage_info
         /// A stake for a vote can be reused in future referendum cycles.
         pub Votes get(fn votes): map hasher(blake2_128_concat)
                                           T::AccountId => CastVoteOf<T>;
+
+        /// Delegates of the accounts that delegated their voting stake (both keyed by account,
+        /// not by member id). A delegator's vote record is stored in `Votes` and updated
+        /// whenever the delegate reveals a vote.
+        pub VoteDelegations get(fn vote_delegations): map hasher(blake2_128_concat)
+                                          T::AccountId => Option<T::AccountId>;
+
+        /// Accounts that delegated their voting stake to the given delegate.
+        pub Delegators get(fn delegators): map hasher(blake2_128_concat)
+                                          T::AccountId => DelegatorsOf<T, I>;
     }
 }
 
@@ -347,6 +379,20 @@ decl_event! {
 
         /// User released his stake
         StakeReleased(AccountId),
+
+        /// User delegated his voting stake
+        /// Params:
+        /// - Delegator account
+        /// - Delegate account
+        /// - Delegated stake
+        VoteDelegated(AccountId, AccountId, Balance),
+
+        /// Votes of delegators were counted with the delegate's revealed vote
+        /// Params:
+        /// - Delegate account
+        /// - Option the votes were counted for
+        /// - Number of delegators
+        DelegatedVotesRevealed(AccountId, MemberId, u32),
     }
 }
 
@@ -392,6 +438,12 @@ decl_error! {
 
         /// Unstaking has been forbidden for the user (at least for now)
         UnstakingForbidden,
+
+        /// Voting stake can't be delegated to the delegator himself
+        CantDelegateToSelf,
+
+        /// Delegate already has the maximum number of delegators
+        TooManyDelegators,
     }
 }
 
@@ -428,6 +480,9 @@ decl_module! {
         /// Exports const - staking handler lock id.
         const StakingHandlerLockId: LockIdentifier = T::StakingHandler::lock_id();
 
+        /// Maximum number of accounts that can delegate their voting stake to a single account.
+        const MaxDelegatorsPerDelegate: u32 = T::MaxDelegatorsPerDelegate::get();
+
         /////////////////// Lifetime ///////////////////////////////////////////
 
         // No origin so this is a priviledged call
@@ -442,16 +497,23 @@ decl_module! {
 
         /////////////////// User actions ///////////////////////////////////////
 
-        /// Cast a sealed vote in the referendum.
+        /// Cast a sealed vote in the referendum. Voting directly overrides the delegation of
+        /// the signing account (if any).
         ///
         /// # <weight>
         ///
         /// ## weight
-        /// `O (1)`
+        /// `O (D)` where:
+        /// - `D` is `MaxDelegatorsPerDelegate` - the voter is removed from the delegators of their
+        ///   delegate
         /// - db:
-        ///    - `O(1)` doesn't depend on the state or parameters
+        ///    - `O(D)` - the delegators list is decoded and re-encoded
         /// # </weight>
-        #[weight = ReferendumWeightInfo::<T, I>::vote()]
+        #[weight = ReferendumWeightInfo::<T, I>::vote().max(
+            ReferendumWeightInfo::<T, I>::vote_override_delegation(
+                T::MaxDelegatorsPerDelegate::get()
+            )
+        )]
         pub fn vote(origin, commitment: T::Hash, stake: BalanceOf<T>) -> Result<(), Error<T, I>> {
             // ensure action can be started
             let (current_cycle_id, account_id) = EnsureChecks::<T, I>::can_vote(origin, &stake)?;
@@ -496,10 +558,23 @@ decl_module! {
             //
 
             // reveal the vote
-            Mutations::<T, I>::reveal_vote(stage_data, &account_id, &vote_option_id, cast_vote);
+            let delegators_count = Mutations::<T, I>::reveal_vote(
+                stage_data,
+                &account_id,
+                &vote_option_id,
+                cast_vote,
+            );
 
-            // emit event
-            Self::deposit_event(RawEvent::VoteRevealed(account_id, vote_option_id, salt));
+            // emit events
+            Self::deposit_event(RawEvent::VoteRevealed(account_id.clone(), vote_option_id, salt));
+
+            if delegators_count > 0 {
+                Self::deposit_event(RawEvent::DelegatedVotesRevealed(
+                    account_id,
+                    vote_option_id,
+                    delegators_count,
+                ));
+            }
 
             Ok(())
         }
@@ -512,7 +587,11 @@ decl_module! {
         /// - db:
         ///    - `O(1)` doesn't depend on the state or parameters
         /// # </weight>
-        #[weight = ReferendumWeightInfo::<T, I>::release_vote_stake()]
+        #[weight = ReferendumWeightInfo::<T, I>::release_vote_stake().max(
+            ReferendumWeightInfo::<T, I>::release_delegated_vote_stake(
+                T::MaxDelegatorsPerDelegate::get()
+            )
+        )]
         pub fn release_vote_stake(origin) -> Result<(), Error<T, I>> {
             let account_id = EnsureChecks::<T, I>::can_release_vote_stake(origin)?;
 
@@ -528,6 +607,42 @@ decl_module! {
 
             Ok(())
         }
+
+        /// Delegate voting stake of the signing account to the `delegate` account. The stake is
+        /// locked and counted with the delegate's revealed votes until it is released or the
+        /// delegator votes directly. Delegation is keyed by account, not by member id.
+        ///
+        /// # <weight>
+        ///
+        /// ## weight
+        /// `O (D)` where:
+        /// - `D` is `MaxDelegatorsPerDelegate` - the delegator is removed from the delegators of
+        ///   the previous delegate and added to the delegators of the new one
+        /// - db:
+        ///    - `O(D)` - both delegators lists are decoded and re-encoded
+        /// # </weight>
+        #[weight = ReferendumWeightInfo::<T, I>::delegate_vote(
+            T::MaxDelegatorsPerDelegate::get()
+        )]
+        pub fn delegate_vote(
+            origin,
+            delegate: T::AccountId,
+            stake: BalanceOf<T>,
+        ) -> Result<(), Error<T, I>> {
+            let (current_cycle_id, account_id) =
+                EnsureChecks::<T, I>::can_delegate_vote(origin, &delegate, &stake)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Mutations::<T, I>::delegate_vote(&account_id, &delegate, &stake, &current_cycle_id);
+
+            // emit event
+            Self::deposit_event(RawEvent::VoteDelegated(account_id, delegate, stake));
+
+            Ok(())
+        }
     }
 }
 
@@ -551,6 +666,10 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
             .max(ReferendumWeightInfo::<T, I>::reveal_vote_already_existing(
                 number_of_winners,
             ))
+            .max(ReferendumWeightInfo::<T, I>::reveal_vote_with_delegators(
+                number_of_winners,
+                T::MaxDelegatorsPerDelegate::get(),
+            ))
     }
 
     // Checkout expire of referendum stage.
@@ -749,6 +868,9 @@ impl<T: Config<I>, I: Instance> Mutations<T, I> {
         // Should call after `can_vote`
         T::StakingHandler::lock(account_id, *stake);
 
+        // voting directly overrides the delegation
+        Self::remove_vote_delegation(account_id);
+
         // store vote
         Votes::<T, I>::insert(
             account_id,
@@ -761,15 +883,21 @@ impl<T: Config<I>, I: Instance> Mutations<T, I> {
         );
     }
 
-    // Reveal user's vote target and check the commitment proof.
+    // Reveal user's vote target and check the commitment proof. Votes of the user's delegators
+    // are counted for the same option. Returns the number of counted delegators.
     fn reveal_vote(
         stage_data: ReferendumStageRevealingOf<T, I>,
         account_id: &<T as frame_system::Config>::AccountId,
         option_id: &<T as common::membership::MembershipTypes>::MemberId,
         cast_vote: CastVoteOf<T>,
-    ) {
+    ) -> u32 {
+        // count delegated votes
+        let (delegated_vote_power, delegators_count) =
+            Self::reveal_delegated_votes(account_id, option_id, &stage_data.current_cycle_id);
+
         // prepare new values
-        let vote_power = T::calculate_vote_power(account_id, &cast_vote.stake);
+        let vote_power = T::calculate_vote_power(account_id, &cast_vote.stake)
+            .saturating_add(delegated_vote_power);
         let total_vote_power = T::get_option_power(option_id) + vote_power;
         let option_result = OptionResult {
             option_id: *option_id,
@@ -794,6 +922,36 @@ impl<T: Config<I>, I: Instance> Mutations<T, I> {
 
         // store revealed vote
         Votes::<T, I>::mutate(account_id, |vote| (*vote).vote_for = Some(*option_id));
+
+        delegators_count
+    }
+
+    // Record delegators' votes for the option revealed by their delegate and return their total
+    // vote power and count.
+    fn reveal_delegated_votes(
+        delegate: &<T as frame_system::Config>::AccountId,
+        option_id: &<T as common::membership::MembershipTypes>::MemberId,
+        current_cycle_id: &u64,
+    ) -> (<T as Config<I>>::VotePower, u32) {
+        let mut vote_power: <T as Config<I>>::VotePower = Zero::zero();
+        let mut delegators_count = 0u32;
+
+        for delegator in Delegators::<T, I>::get(delegate).iter() {
+            if !Votes::<T, I>::contains_key(delegator) {
+                continue;
+            }
+
+            Votes::<T, I>::mutate(delegator, |vote| {
+                vote_power =
+                    vote_power.saturating_add(T::calculate_vote_power(delegator, &vote.stake));
+
+                vote.cycle_id = *current_cycle_id;
+                vote.vote_for = Some(*option_id);
+            });
+            delegators_count = delegators_count.saturating_add(1);
+        }
+
+        (vote_power, delegators_count)
     }
 
     // Release stake associated to the user's last vote.
@@ -803,6 +961,65 @@ impl<T: Config<I>, I: Instance> Mutations<T, I> {
 
         // remove vote record
         Votes::<T, I>::remove(account_id);
+
+        // delegation ends with the stake release
+        Self::remove_vote_delegation(account_id);
+    }
+
+    // Delegate user's voting stake to another account.
+    fn delegate_vote(
+        account_id: &<T as frame_system::Config>::AccountId,
+        delegate: &<T as frame_system::Config>::AccountId,
+        stake: &BalanceOf<T>,
+        current_cycle_id: &u64,
+    ) {
+        // Should call after `can_delegate_vote`
+        T::StakingHandler::lock(account_id, *stake);
+
+        // replace the previous delegation
+        Self::remove_vote_delegation(account_id);
+
+        // store vote record; it has no commitment and it is revealed by the delegate
+        Votes::<T, I>::insert(
+            account_id,
+            CastVote {
+                commitment: T::Hash::default(),
+                stake: *stake,
+                cycle_id: *current_cycle_id,
+                vote_for: None,
+            },
+        );
+
+        VoteDelegations::<T, I>::insert(account_id, delegate);
+
+        let mut delegators = Delegators::<T, I>::get(delegate).to_vec();
+        delegators.push(account_id.clone());
+        Delegators::<T, I>::insert(
+            delegate,
+            WeakBoundedVec::force_from(delegators, Some("Referendum delegate_vote")),
+        );
+    }
+
+    // Remove user's delegation if there is any.
+    fn remove_vote_delegation(account_id: &<T as frame_system::Config>::AccountId) {
+        if let Some(delegate) = VoteDelegations::<T, I>::take(account_id) {
+            Delegators::<T, I>::mutate_exists(&delegate, |maybe_delegators| {
+                let delegators: Vec<_> = maybe_delegators
+                    .take()
+                    .map(|delegators| delegators.to_vec())
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|delegator| delegator != account_id)
+                    .collect();
+
+                if !delegators.is_empty() {
+                    *maybe_delegators = Some(WeakBoundedVec::force_from(
+                        delegators,
+                        Some("Referendum remove_vote_delegation"),
+                    ));
+                }
+            });
+        }
     }
 
     // Tries to insert option to the proper place in the winners list. Utility for reaveal_vote()
@@ -948,6 +1165,11 @@ impl<T: Config<I>, I: Instance> EnsureChecks<T, I> {
                 return Ok(());
             }
 
+            // voting directly overrides the delegation
+            if VoteDelegations::<T, I>::contains_key(&account_id) {
+                return Ok(());
+            }
+
             let existing_vote = Votes::<T, I>::get(&account_id);
 
             // don't allow repeated vote
@@ -988,6 +1210,29 @@ impl<T: Config<I>, I: Instance> EnsureChecks<T, I> {
         Ok((current_cycle_id, account_id))
     }
 
+    fn can_delegate_vote(
+        origin: T::Origin,
+        delegate: &T::AccountId,
+        stake: &BalanceOf<T>,
+    ) -> Result<(u64, T::AccountId), Error<T, I>> {
+        // delegation is subject to the same checks as a regular vote
+        let (current_cycle_id, account_id) = Self::can_vote(origin, stake)?;
+
+        ensure!(account_id != *delegate, Error::CantDelegateToSelf);
+
+        // ensure delegate can accept another delegator
+        let already_delegating =
+            VoteDelegations::<T, I>::get(&account_id).as_ref() == Some(delegate);
+        ensure!(
+            already_delegating
+                || (Delegators::<T, I>::decode_len(delegate).unwrap_or(0) as u32)
+                    < T::MaxDelegatorsPerDelegate::get(),
+            Error::TooManyDelegators
+        );
+
+        Ok((current_cycle_id, account_id))
+    }
+
     fn can_reveal_vote<R: ReferendumManager<T::Origin, T::AccountId, T::MemberId, T::Hash>>(
         origin: T::Origin,
         salt: &[u8],
@@ -2,9 +2,9 @@
 
 /////////////////// Configuration //////////////////////////////////////////////
 use crate::{
-    BalanceOf, CastVote, Config, Error, Instance, Module, OptionResult, RawEvent,
+    BalanceOf, CastVote, Config, Delegators, Error, Instance, Module, OptionResult, RawEvent,
     ReferendumManager, ReferendumStage, ReferendumStageRevealing, ReferendumStageVoting, Stage,
-    Votes,
+    VoteDelegations, Votes,
 };
 
 pub use crate::DefaultInstance;
@@ -53,6 +53,7 @@ parameter_types! {
     pub const MinimumStake: u64 = 10000;
     pub const LockId: LockIdentifier = *b"referend";
     pub const MaxWinnerTargetCount: u32 = 10;
+    pub const MaxDelegatorsPerDelegate: u32 = 3;
 }
 
 thread_local! {
@@ -89,6 +90,7 @@ impl Config for Runtime {
     type WeightInfo = ();
 
     type MaxWinnerTargetCount = MaxWinnerTargetCount;
+    type MaxDelegatorsPerDelegate = MaxDelegatorsPerDelegate;
 
     fn calculate_vote_power(
         account_id: &<Self as frame_system::Config>::AccountId,
@@ -163,6 +165,13 @@ impl membership::Config for Runtime {
         staking_handler::StakingManager<Self, StakingCandidateLockId>;
     type CandidateStake = CandidateStake;
     type DefaultMemberInvitesCount = DefaultMemberInvitesCount;
+    type MemberReputationRules = ();
+    type MaxRecoveryGuardians = ();
+    type RecoveryDelay = ();
+    type RecoveryDeposit = ();
+    type HandleExpiryPeriod = ();
+    type HandleExpiryGracePeriod = ();
+    type MaxUplineReferralLevels = ();
 }
 
 impl pallet_timestamp::Config for Runtime {
@@ -758,6 +767,96 @@ impl InstanceMocks<Runtime, DefaultInstance> {
                 .event,
             Event::Referendum(RawEvent::StakeReleased(account_id))
         );
+
+        assert_eq!(
+            VoteDelegations::<Runtime, DefaultInstance>::get(account_id),
+            None
+        );
+    }
+
+    pub fn delegate_vote(
+        origin: OriginType<<Runtime as frame_system::Config>::AccountId>,
+        account_id: <Runtime as frame_system::Config>::AccountId,
+        delegate: <Runtime as frame_system::Config>::AccountId,
+        stake: BalanceOf<Runtime>,
+        cycle_id: u64,
+        expected_result: Result<(), Error<Runtime, DefaultInstance>>,
+    ) -> () {
+        // check method returns expected result
+        assert_eq!(
+            Module::<Runtime>::delegate_vote(
+                InstanceMockUtils::<Runtime, DefaultInstance>::mock_origin(origin),
+                delegate,
+                stake,
+            ),
+            expected_result,
+        );
+
+        if expected_result.is_err() {
+            return;
+        }
+
+        assert_eq!(
+            Votes::<Runtime, DefaultInstance>::get(account_id),
+            CastVote {
+                commitment: Default::default(),
+                cycle_id,
+                stake,
+                vote_for: None,
+            },
+        );
+        assert_eq!(
+            VoteDelegations::<Runtime, DefaultInstance>::get(account_id),
+            Some(delegate)
+        );
+        assert!(Delegators::<Runtime, DefaultInstance>::get(delegate).contains(&account_id));
+
+        // check event was emitted
+        assert_eq!(
+            frame_system::Pallet::<Runtime>::events()
+                .last()
+                .unwrap()
+                .event,
+            Event::Referendum(RawEvent::VoteDelegated(account_id, delegate, stake))
+        );
+    }
+
+    pub fn reveal_vote_with_delegators(
+        origin: OriginType<<Runtime as frame_system::Config>::AccountId>,
+        account_id: <Runtime as frame_system::Config>::AccountId,
+        salt: Vec<u8>,
+        vote_option_index: u64,
+        expected_delegators: Vec<<Runtime as frame_system::Config>::AccountId>,
+    ) -> () {
+        // check method returns expected result
+        assert_eq!(
+            Module::<Runtime>::reveal_vote(
+                InstanceMockUtils::<Runtime, DefaultInstance>::mock_origin(origin),
+                salt,
+                vote_option_index,
+            ),
+            Ok(()),
+        );
+
+        for delegator in expected_delegators.iter() {
+            assert_eq!(
+                Votes::<Runtime, DefaultInstance>::get(delegator).vote_for,
+                Some(vote_option_index)
+            );
+        }
+
+        // check event was emitted
+        assert_eq!(
+            frame_system::Pallet::<Runtime>::events()
+                .last()
+                .unwrap()
+                .event,
+            Event::Referendum(RawEvent::DelegatedVotesRevealed(
+                account_id,
+                vote_option_index,
+                expected_delegators.len() as u32,
+            ))
+        );
     }
 }
 
@@ -1142,6 +1142,318 @@ fn referendum_release_stake() {
     });
 }
 
+/////////////////// Vote delegation ////////////////////////////////////////////
+
+/// Test that delegated stake is counted with the delegate's revealed vote.
+#[test]
+fn delegated_votes_counted() {
+    build_test_externalities().execute_with(|| {
+        let voting_stage_duration = <Runtime as Config>::VoteStageDuration::get();
+        let reveal_stage_duration = <Runtime as Config>::RevealStageDuration::get();
+        let delegate = USER_ADMIN;
+        let delegators = vec![USER_REGULAR, USER_REGULAR_2];
+        let origin = OriginType::Signed(delegate);
+        let cycle_id = 1;
+        let winning_target_count = 1;
+
+        let option_to_vote_for = 0;
+        let stake = <Runtime as Config>::MinimumStake::get();
+        let (commitment, salt) =
+            MockUtils::calculate_commitment(&delegate, &option_to_vote_for, &cycle_id);
+
+        Mocks::start_referendum_extrinsic(origin.clone(), winning_target_count, cycle_id, Ok(()));
+        Mocks::vote(
+            origin.clone(),
+            delegate,
+            commitment,
+            stake,
+            cycle_id,
+            Ok(()),
+        );
+        for delegator in delegators.iter() {
+            Mocks::delegate_vote(
+                OriginType::Signed(*delegator),
+                *delegator,
+                delegate,
+                stake,
+                cycle_id,
+                Ok(()),
+            );
+        }
+
+        // voting period starts at block 1
+        MockUtils::move_to_block(voting_stage_duration + 1);
+
+        Mocks::check_voting_finished(winning_target_count, cycle_id);
+        Mocks::reveal_vote_with_delegators(origin, delegate, salt, option_to_vote_for, delegators);
+        MockUtils::increase_block_number(reveal_stage_duration);
+
+        Mocks::check_revealing_finished(
+            vec![OptionResult {
+                option_id: option_to_vote_for,
+                vote_power: 3 * stake,
+            }],
+            MockUtils::transform_results(vec![3 * stake, 0, 0]),
+        );
+    });
+}
+
+/// Test that delegator can override the delegation by voting directly.
+#[test]
+fn delegated_vote_overridden_by_direct_vote() {
+    build_test_externalities().execute_with(|| {
+        let voting_stage_duration = <Runtime as Config>::VoteStageDuration::get();
+        let reveal_stage_duration = <Runtime as Config>::RevealStageDuration::get();
+        let delegate = USER_ADMIN;
+        let delegator = USER_REGULAR;
+        let origin = OriginType::Signed(delegate);
+        let origin_delegator = OriginType::Signed(delegator);
+        let cycle_id = 1;
+        let winning_target_count = 2;
+
+        let option_delegate = 0;
+        let option_delegator = 1;
+        let stake = <Runtime as Config>::MinimumStake::get();
+        let (commitment, salt) =
+            MockUtils::calculate_commitment(&delegate, &option_delegate, &cycle_id);
+        let (commitment_delegator, salt_delegator) =
+            MockUtils::calculate_commitment(&delegator, &option_delegator, &cycle_id);
+
+        Mocks::start_referendum_extrinsic(origin.clone(), winning_target_count, cycle_id, Ok(()));
+        Mocks::vote(
+            origin.clone(),
+            delegate,
+            commitment,
+            stake,
+            cycle_id,
+            Ok(()),
+        );
+        Mocks::delegate_vote(
+            origin_delegator.clone(),
+            delegator,
+            delegate,
+            stake,
+            cycle_id,
+            Ok(()),
+        );
+        Mocks::vote(
+            origin_delegator.clone(),
+            delegator,
+            commitment_delegator,
+            stake,
+            cycle_id,
+            Ok(()),
+        );
+
+        assert_eq!(Referendum::vote_delegations(delegator), None);
+        assert!(Referendum::delegators(delegate).is_empty());
+
+        // voting period starts at block 1
+        MockUtils::move_to_block(voting_stage_duration + 1);
+
+        Mocks::check_voting_finished(winning_target_count, cycle_id);
+        Mocks::reveal_vote(origin, delegate, salt, option_delegate, Ok(()));
+        Mocks::reveal_vote(
+            origin_delegator,
+            delegator,
+            salt_delegator,
+            option_delegator,
+            Ok(()),
+        );
+        MockUtils::increase_block_number(reveal_stage_duration);
+
+        Mocks::check_revealing_finished_referendum_results(MockUtils::transform_results(vec![
+            stake, stake, 0,
+        ]));
+    });
+}
+
+/// Test that delegation stays in place for the following referendum cycles.
+#[test]
+fn delegated_vote_persists_across_cycles() {
+    build_test_externalities().execute_with(|| {
+        let voting_stage_duration = <Runtime as Config>::VoteStageDuration::get();
+        let reveal_stage_duration = <Runtime as Config>::RevealStageDuration::get();
+        let delegate = USER_ADMIN;
+        let delegator = USER_REGULAR;
+        let origin = OriginType::Signed(delegate);
+        let cycle_id1 = 1;
+        let cycle_id2 = 2;
+        let winning_target_count = 1;
+
+        let option_to_vote_for = 0;
+        let stake = <Runtime as Config>::MinimumStake::get();
+        let (commitment, salt) =
+            MockUtils::calculate_commitment(&delegate, &option_to_vote_for, &cycle_id1);
+
+        Mocks::start_referendum_extrinsic(origin.clone(), winning_target_count, cycle_id1, Ok(()));
+        Mocks::vote(
+            origin.clone(),
+            delegate,
+            commitment,
+            stake,
+            cycle_id1,
+            Ok(()),
+        );
+        Mocks::delegate_vote(
+            OriginType::Signed(delegator),
+            delegator,
+            delegate,
+            stake,
+            cycle_id1,
+            Ok(()),
+        );
+        MockUtils::increase_block_number(voting_stage_duration);
+        Mocks::reveal_vote_with_delegators(
+            origin.clone(),
+            delegate,
+            salt,
+            option_to_vote_for,
+            vec![delegator],
+        );
+        MockUtils::increase_block_number(reveal_stage_duration);
+
+        // second cycle - only delegate votes
+        let option_to_vote_for = 1;
+        let (commitment, salt) =
+            MockUtils::calculate_commitment(&delegate, &option_to_vote_for, &cycle_id2);
+
+        Mocks::start_referendum_extrinsic(origin.clone(), winning_target_count, cycle_id2, Ok(()));
+        Mocks::vote(
+            origin.clone(),
+            delegate,
+            commitment,
+            stake,
+            cycle_id2,
+            Ok(()),
+        );
+        MockUtils::increase_block_number(voting_stage_duration);
+        Mocks::reveal_vote_with_delegators(
+            origin,
+            delegate,
+            salt,
+            option_to_vote_for,
+            vec![delegator],
+        );
+
+        assert_eq!(Referendum::votes(delegator).cycle_id, cycle_id2);
+    });
+}
+
+/// Test that delegated stake is released as usual and the delegation ends with it.
+#[test]
+fn delegated_vote_stake_released() {
+    build_test_externalities().execute_with(|| {
+        let delegate = USER_ADMIN;
+        let delegator = USER_REGULAR;
+        let origin_delegator = OriginType::Signed(delegator);
+        let cycle_id = 1;
+        let stake = <Runtime as Config>::MinimumStake::get();
+
+        Mocks::start_referendum_extrinsic(OriginType::Signed(delegate), 1, cycle_id, Ok(()));
+        Mocks::delegate_vote(
+            origin_delegator.clone(),
+            delegator,
+            delegate,
+            stake,
+            cycle_id,
+            Ok(()),
+        );
+
+        Runtime::feature_stack_lock(false);
+        Mocks::release_stake(
+            origin_delegator.clone(),
+            delegator,
+            Err(Error::UnstakingForbidden),
+        );
+        Runtime::feature_stack_lock(true);
+
+        Mocks::release_stake(origin_delegator, delegator, Ok(()));
+
+        assert!(Referendum::delegators(delegate).is_empty());
+    });
+}
+
+/// Test that voting stake can't be delegated under invalid conditions.
+#[test]
+fn delegate_vote_invalid() {
+    build_test_externalities().execute_with(|| {
+        let delegate = USER_ADMIN;
+        let cycle_id = 1;
+        let stake = <Runtime as Config>::MinimumStake::get();
+        let delegators = vec![USER_REGULAR, USER_REGULAR_2, USER_REGULAR_3];
+        let late_delegator = USER_REGULAR_4;
+
+        Mocks::delegate_vote(
+            OriginType::Signed(USER_REGULAR),
+            USER_REGULAR,
+            delegate,
+            stake,
+            cycle_id,
+            Err(Error::ReferendumNotRunning),
+        );
+
+        Mocks::start_referendum_extrinsic(OriginType::Signed(delegate), 1, cycle_id, Ok(()));
+
+        Mocks::delegate_vote(
+            OriginType::Signed(delegate),
+            delegate,
+            delegate,
+            stake,
+            cycle_id,
+            Err(Error::CantDelegateToSelf),
+        );
+
+        Mocks::delegate_vote(
+            OriginType::Signed(USER_REGULAR),
+            USER_REGULAR,
+            delegate,
+            stake - 1,
+            cycle_id,
+            Err(Error::InsufficientStake),
+        );
+
+        for delegator in delegators.iter() {
+            Mocks::delegate_vote(
+                OriginType::Signed(*delegator),
+                *delegator,
+                delegate,
+                stake,
+                cycle_id,
+                Ok(()),
+            );
+        }
+
+        Mocks::delegate_vote(
+            OriginType::Signed(late_delegator),
+            late_delegator,
+            delegate,
+            stake,
+            cycle_id,
+            Err(Error::TooManyDelegators),
+        );
+
+        // direct vote can't be replaced by delegation in the same cycle
+        let (commitment, _) = MockUtils::calculate_commitment(&late_delegator, &0, &cycle_id);
+        Mocks::vote(
+            OriginType::Signed(late_delegator),
+            late_delegator,
+            commitment,
+            stake,
+            cycle_id,
+            Ok(()),
+        );
+        Mocks::delegate_vote(
+            OriginType::Signed(late_delegator),
+            late_delegator,
+            USER_REGULAR_5,
+            stake,
+            cycle_id,
+            Err(Error::AlreadyVotedThisCycle),
+        );
+    });
+}
+
 /////////////////// ReferendumManager //////////////////////////////////////////
 
 /// Test that other runtime modules can start the referendum.
@@ -53,6 +53,10 @@ pub trait WeightInfo {
 	fn reveal_vote_space_replace_last_winner(_i: u32, ) -> Weight;
 	fn reveal_vote_already_existing(_i: u32, ) -> Weight;
 	fn release_vote_stake() -> Weight;
+	fn vote_override_delegation(_i: u32, ) -> Weight;
+	fn reveal_vote_with_delegators(_i: u32, _j: u32, ) -> Weight;
+	fn release_delegated_vote_stake(_i: u32, ) -> Weight;
+	fn delegate_vote(_i: u32, ) -> Weight;
 }
 
 /// Weights for referendum using the Substrate node and recommended hardware.
@@ -136,6 +140,61 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
+	// Storage: Instance1Referendum Stage (r:1 w:0)
+	// Storage: Instance1Referendum Votes (r:1 w:1)
+	// Storage: Instance1Referendum VoteDelegations (r:2 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Instance1Referendum Delegators (r:1 w:1)
+	fn vote_override_delegation(i: u32, ) -> Weight {
+		(58_204_000 as Weight)
+			// Standard Error: 2_000
+			.saturating_add((187_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Instance1Referendum Stage (r:1 w:1)
+	// Storage: Instance1Referendum Votes (r:1 w:1)
+	// Storage: Instance1Referendum Delegators (r:1 w:0)
+	// Storage: Council Candidates (r:1 w:1)
+	// Storage: Council AnnouncementPeriodNr (r:1 w:0)
+	fn reveal_vote_with_delegators(i: u32, j: u32, ) -> Weight {
+		(52_117_000 as Weight)
+			// Standard Error: 81_000
+			.saturating_add((1_392_000 as Weight).saturating_mul(i as Weight))
+			// Standard Error: 3_000
+			.saturating_add((9_746_000 as Weight).saturating_mul(j as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(j as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(j as Weight)))
+	}
+	// Storage: Instance1Referendum Votes (r:1 w:1)
+	// Storage: Council AnnouncementPeriodNr (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Instance1Referendum VoteDelegations (r:1 w:1)
+	// Storage: Instance1Referendum Delegators (r:1 w:1)
+	fn release_delegated_vote_stake(i: u32, ) -> Weight {
+		(55_372_000 as Weight)
+			// Standard Error: 2_000
+			.saturating_add((181_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Instance1Referendum Stage (r:1 w:0)
+	// Storage: Instance1Referendum Votes (r:1 w:1)
+	// Storage: Instance1Referendum VoteDelegations (r:2 w:1)
+	// Storage: Instance1Referendum Delegators (r:2 w:2)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn delegate_vote(i: u32, ) -> Weight {
+		(64_528_000 as Weight)
+			// Standard Error: 3_000
+			.saturating_add((236_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
 }
 
 // Default implementation for tests
@@ -164,4 +223,16 @@ impl WeightInfo for () {
 	fn release_vote_stake() -> Weight {
 		0
 	}
+	fn vote_override_delegation(i: u32, ) -> Weight {
+		0
+	}
+	fn reveal_vote_with_delegators(i: u32, j: u32, ) -> Weight {
+		0
+	}
+	fn release_delegated_vote_stake(i: u32, ) -> Weight {
+		0
+	}
+	fn delegate_vote(i: u32, ) -> Weight {
+		0
+	}
 }
@@ -208,6 +208,13 @@ impl membership::Config for Test {
         staking_handler::StakingManager<Self, StakingCandidateLockId>;
     type CandidateStake = CandidateStake;
     type DefaultMemberInvitesCount = DefaultMemberInvitesCount;
+    type MemberReputationRules = ();
+    type MaxRecoveryGuardians = ();
+    type RecoveryDelay = ();
+    type RecoveryDeposit = ();
+    type HandleExpiryPeriod = ();
+    type HandleExpiryGracePeriod = ();
+    type MaxUplineReferralLevels = ();
 }
 
 pub struct Wg;
@@ -283,6 +290,7 @@ impl working_group::Config<ContentWorkingGroupInstance> for Test {
     type StakingHandler = StakingManager<Self, LockId1>;
     type StakingAccountValidator = membership::Module<Test>;
     type MemberOriginValidator = ();
+    type MemberReputationHandler = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
     type WeightInfo = ();
@@ -296,6 +304,7 @@ impl working_group::Config<StorageWorkingGroupInstance> for Test {
     type StakingHandler = StakingManager<Self, LockId2>;
     type StakingAccountValidator = membership::Module<Test>;
     type MemberOriginValidator = ();
+    type MemberReputationHandler = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
     type WeightInfo = ();
@@ -309,6 +318,7 @@ impl working_group::Config<ForumWorkingGroupInstance> for Test {
     type StakingHandler = staking_handler::StakingManager<Self, LockId2>;
     type StakingAccountValidator = membership::Module<Test>;
     type MemberOriginValidator = ();
+    type MemberReputationHandler = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
     type WeightInfo = ();
@@ -322,6 +332,7 @@ impl working_group::Config<MembershipWorkingGroupInstance> for Test {
     type StakingHandler = StakingManager<Self, LockId2>;
     type StakingAccountValidator = membership::Module<Test>;
     type MemberOriginValidator = ();
+    type MemberReputationHandler = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
     type WeightInfo = ();
@@ -335,6 +346,7 @@ impl working_group::Config<GatewayWorkingGroupInstance> for Test {
     type StakingHandler = StakingManager<Self, LockId2>;
     type StakingAccountValidator = membership::Module<Test>;
     type MemberOriginValidator = ();
+    type MemberReputationHandler = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
     type WeightInfo = ();
@@ -348,6 +360,7 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
     type StakingHandler = StakingManager<Self, LockId2>;
     type StakingAccountValidator = membership::Module<Test>;
     type MemberOriginValidator = ();
+    type MemberReputationHandler = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
     type WeightInfo = ();
@@ -361,6 +374,7 @@ impl working_group::Config<OperationsWorkingGroupInstanceAlpha> for Test {
     type StakingHandler = StakingManager<Self, LockId2>;
     type StakingAccountValidator = membership::Module<Test>;
     type MemberOriginValidator = ();
+    type MemberReputationHandler = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
     type WeightInfo = ();
@@ -374,6 +388,7 @@ impl working_group::Config<OperationsWorkingGroupInstanceBeta> for Test {
     type StakingHandler = StakingManager<Self, LockId2>;
     type StakingAccountValidator = membership::Module<Test>;
     type MemberOriginValidator = ();
+    type MemberReputationHandler = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
     type WeightInfo = ();
@@ -387,6 +402,7 @@ impl working_group::Config<OperationsWorkingGroupInstanceGamma> for Test {
     type StakingHandler = StakingManager<Self, LockId2>;
     type StakingAccountValidator = membership::Module<Test>;
     type MemberOriginValidator = ();
+    type MemberReputationHandler = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
     type WeightInfo = ();
@@ -430,6 +446,7 @@ impl referendum::Config<ReferendumInstance> for Test {
     type MinimumStake = MinimumVotingStake;
     type WeightInfo = ();
     type MaxWinnerTargetCount = MaxWinnerTargetCount;
+    type MaxDelegatorsPerDelegate = ConstU32<10>;
 
     fn calculate_vote_power(
         _: &<Self as frame_system::Config>::AccountId,
@@ -546,13 +563,20 @@ impl BurnTokensFixture {
 impl council::Config for Test {
     type Event = Event;
     type Referendum = referendum::Module<Test, ReferendumInstance>;
+    type RecallReferendum = referendum::Module<Test, ReferendumInstance>;
     type MinNumberOfExtraCandidates = MinNumberOfExtraCandidates;
     type CouncilSize = CouncilSize;
+    type StaggeredSeatsPerCycle = ConstU32<0>;
+    type MaxConsecutiveTerms = ConstU32<0>;
+    type MaxRunnersUp = ConstU32<0>;
     type AnnouncingPeriodDuration = AnnouncingPeriodDuration;
     type IdlePeriodDuration = IdlePeriodDuration;
     type MinCandidateStake = MinCandidateStake;
     type CandidacyLock = StakingManager<Self, CandidacyLockId>;
     type CouncilorLock = StakingManager<Self, CouncilorLockId>;
+    type RecallPetitionLock = StakingManager<Self, common::locks::RecallPetitionLockId>;
+    type MinRecallPetitionStake = ConstU64<100>;
+    type RecallPetitionThreshold = ConstU64<10000>;
     type ElectedMemberRewardPeriod = ElectedMemberRewardPeriod;
     type BudgetRefillPeriod = BudgetRefillPeriod;
     type StakingAccountValidator = ();
@@ -78,7 +78,7 @@ pub use types::*;
 use types::{ApplicationInfo, WorkerInfo};
 
 use common::costs::burn_from_usable;
-use common::membership::MemberOriginValidator;
+use common::membership::{MemberOriginValidator, MemberReputationHandler, ReputationActivity};
 use common::to_kb;
 use common::{MemberId, StakingAccountValidator};
 use frame_support::dispatch::DispatchResult;
@@ -111,6 +111,9 @@ pub trait Config<I: Instance = DefaultInstance>:
     /// Validates member id and origin combination.
     type MemberOriginValidator: MemberOriginValidator<Self::Origin, MemberId<Self>, Self::AccountId>;
 
+    /// Updates worker reputation on the served tenure.
+    type MemberReputationHandler: MemberReputationHandler<Self>;
+
     /// Defines min unstaking period in the group.
     type MinUnstakingPeriodLimit: Get<Self::BlockNumber>;
 
@@ -325,6 +328,10 @@ decl_storage! { generate_storage_info
         pub WorkerById get(fn worker_by_id) : map hasher(blake2_128_concat)
             WorkerId<T> => Option<Worker<T>>;
 
+        /// Number of workers (including the leader) of the member.
+        pub WorkerCountByMemberId get(fn worker_count_by_member_id) : map hasher(blake2_128_concat)
+            MemberId<T> => u32;
+
         /// Current group lead.
         pub CurrentLead get(fn current_lead) : Option<WorkerId<T>>;
 
@@ -1269,6 +1276,10 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
         .max(WeightInfoWorkingGroup::<T, I>::on_initialize_leaving(
             workers,
         ))
+        // Rewarded workers tenure is recorded in the member reputation
+        .saturating_add(
+            T::MemberReputationHandler::record_activity_weight().saturating_mul(workers.into()),
+        )
     }
 
     // Calculate weight for `leave_role`
@@ -1326,6 +1337,40 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
         <ActiveWorkerCount<I>>::put(next_active_worker_count_value);
     }
 
+    // Decreases the worker counter of the member, removes the counter when it reaches zero.
+    fn decrease_worker_count_by_member_id(member_id: &MemberId<T>) {
+        <WorkerCountByMemberId<T, I>>::mutate_exists(member_id, |count| {
+            *count = count
+                .map(|count| count.saturating_sub(1))
+                .filter(|count| !count.is_zero());
+        });
+    }
+
+    /// Checks whether the member is a worker (or the leader) of the working group.
+    pub fn is_member_worker(member_id: &MemberId<T>) -> bool {
+        !Self::worker_count_by_member_id(member_id).is_zero()
+    }
+
+    /// Rebuilds the worker counters of the members from the active workers.
+    /// Used by the runtime upgrade indexing the workers created before the counters existed.
+    /// Iterates over at most `MaxWorkerNumberLimit` workers.
+    pub fn rebuild_worker_count_by_member_id() -> Weight {
+        <WorkerCountByMemberId<T, I>>::remove_all(None);
+
+        let mut workers_number: u64 = 0;
+        WorkerById::<T, I>::iter_values().for_each(|worker| {
+            <WorkerCountByMemberId<T, I>>::mutate(worker.member_id, |count| {
+                *count = count.saturating_add(1)
+            });
+            workers_number = workers_number.saturating_add(1);
+        });
+
+        T::DbWeight::get().reads_writes(
+            workers_number.saturating_add(1),
+            workers_number.saturating_mul(2).saturating_add(1),
+        )
+    }
+
     // Processes successful application during the fill_opening().
     fn fulfill_successful_applications(
         opening: &OpeningOf<T>,
@@ -1371,6 +1416,9 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
         // Store a worker.
         <WorkerById<T, I>>::insert(new_worker_id, worker);
         Self::increase_active_worker_counter();
+        <WorkerCountByMemberId<T, I>>::mutate(&application_info.application.member_id, |count| {
+            *count = count.saturating_add(1)
+        });
 
         // Update the next worker id.
         <NextWorkerId<T, I>>::mutate(|id| *id += <WorkerId<T> as One>::one());
@@ -1416,6 +1464,7 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
         // Remove the worker from the storage.
         WorkerById::<T, I>::remove(worker_id);
         Self::decrease_active_worker_counter();
+        Self::decrease_worker_count_by_member_id(&worker.member_id);
 
         T::StakingHandler::unlock(&worker.staking_account_id);
 
@@ -1451,6 +1500,12 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
             (Self::current_block() - worker.created_at).saturated_into();
         if block_from_worker_creation < rewarding_period.into() {
             rewarding_period = block_from_worker_creation.saturated_into();
+        } else {
+            // Only the full rewarding period counts towards the worker tenure.
+            T::MemberReputationHandler::record_activity(
+                &worker.member_id,
+                ReputationActivity::WorkerTenure,
+            );
         }
 
         if let Some(reward_per_block) = worker.reward_per_block {
@@ -846,6 +846,22 @@ fn terminate_worker_role_succeeds() {
     });
 }
 
+#[test]
+fn worker_count_by_member_id_follows_hired_and_removed_workers() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+        let member_id = TestWorkingGroup::worker_by_id(worker_id).unwrap().member_id;
+
+        assert_eq!(TestWorkingGroup::worker_count_by_member_id(member_id), 1);
+        assert!(TestWorkingGroup::is_member_worker(&member_id));
+
+        TerminateWorkerRoleFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        assert!(!TestWorkingGroup::is_member_worker(&member_id));
+        assert!(!crate::WorkerCountByMemberId::<Test, DefaultInstance>::contains_key(member_id));
+    });
+}
+
 #[test]
 fn terminate_worker_role_succeeds_with_paying_missed_reward() {
     build_test_externalities().execute_with(|| {
@@ -81,14 +81,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: System Account (r:2 w:2)
 	// Storage: Instance3WorkingGroup ActiveWorkerCount (r:1 w:1)
 	// Storage: Balances Locks (r:2 w:2)
+	// Storage: Instance3WorkingGroup WorkerCountByMemberId (r:2 w:2)
 	fn on_initialize_leaving(i: u32, ) -> Weight {
 		(26_873_000 as Weight)
-			// Standard Error: 35_000
-			.saturating_add((55_730_000 as Weight).saturating_mul(i as Weight))
+			// Standard Error: 36_000
+			.saturating_add((58_914_000 as Weight).saturating_mul(i as Weight))
 			.saturating_add(T::DbWeight::get().reads(4 as Weight))
-			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(i as Weight)))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(i as Weight)))
 	}
 	// Storage: Instance3WorkingGroup WorkerById (r:3 w:2)
 	// Storage: Instance3WorkingGroup Budget (r:1 w:1)
@@ -143,11 +144,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:1)
 	// Storage: Instance3WorkingGroup ApplicationById (r:1 w:1)
 	// Storage: Instance3WorkingGroup NextWorkerId (r:1 w:1)
+	// Storage: Instance3WorkingGroup WorkerCountByMemberId (r:1 w:1)
 	// Storage: Instance3WorkingGroup WorkerById (r:0 w:1)
 	fn fill_opening_lead() -> Weight {
-		(49_500_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(5 as Weight))
-			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+		(52_691_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
 	}
 	// Storage: Instance3WorkingGroup OpeningById (r:1 w:1)
 	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
@@ -157,14 +159,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	// Storage: Instance3WorkingGroup NextWorkerId (r:1 w:1)
+	// Storage: Instance3WorkingGroup WorkerCountByMemberId (r:1 w:1)
 	fn fill_opening_worker(i: u32, ) -> Weight {
 		(51_093_000 as Weight)
-			// Standard Error: 19_000
-			.saturating_add((15_263_000 as Weight).saturating_mul(i as Weight))
+			// Standard Error: 20_000
+			.saturating_add((18_427_000 as Weight).saturating_mul(i as Weight))
 			.saturating_add(T::DbWeight::get().reads(6 as Weight))
-			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(i as Weight)))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(i as Weight)))
 	}
 	// Storage: Instance3WorkingGroup WorkerById (r:1 w:1)
 	// Storage: Membership MembershipById (r:1 w:0)
@@ -208,12 +211,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: System Account (r:1 w:1)
 	// Storage: Instance3WorkingGroup Budget (r:1 w:1)
 	// Storage: Instance3WorkingGroup ActiveWorkerCount (r:1 w:1)
+	// Storage: Instance3WorkingGroup WorkerCountByMemberId (r:1 w:1)
 	fn terminate_role_worker(i: u32, ) -> Weight {
-		(122_568_000 as Weight)
+		(125_704_000 as Weight)
 			// Standard Error: 5_000
 			.saturating_add((1_271_000 as Weight).saturating_mul(i as Weight))
-			.saturating_add(T::DbWeight::get().reads(7 as Weight))
-			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
 	}
 	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:1)
 	// Storage: Instance3WorkingGroup WorkerById (r:1 w:1)
@@ -221,12 +225,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: System Account (r:1 w:1)
 	// Storage: Instance3WorkingGroup Budget (r:1 w:1)
 	// Storage: Instance3WorkingGroup ActiveWorkerCount (r:1 w:1)
+	// Storage: Instance3WorkingGroup WorkerCountByMemberId (r:1 w:1)
 	fn terminate_role_lead(i: u32, ) -> Weight {
-		(121_275_000 as Weight)
+		(124_390_000 as Weight)
 			// Standard Error: 3_000
 			.saturating_add((1_264_000 as Weight).saturating_mul(i as Weight))
-			.saturating_add(T::DbWeight::get().reads(6 as Weight))
-			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
 	}
 	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
 	// Storage: System Account (r:1 w:1)
@@ -121,9 +121,11 @@ pub mod fees {
 lazy_static! {
     pub static ref NON_RIVALROUS_LOCKS: Vec<LockIdentifier> = [
         VotingLockId::get(),
+        RecallVotingLockId::get(),
         VESTING_LOCK_ID,
         InvitedMemberLockId::get(),
         BoundStakingAccountLockId::get(),
+        ForumPollLockId::get(),
     ]
     .to_vec();
 }
@@ -42,8 +42,21 @@ macro_rules! wrap_working_group_call {
 /// using Runtime Call and parity codec.
 pub struct ExtrinsicProposalEncoder;
 impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
-    fn encode_proposal(proposal_details: ProposalDetailsOf<Runtime>) -> Vec<u8> {
-        let call = match proposal_details {
+    fn encode_proposal(
+        proposal_id: <Runtime as proposals_engine::Config>::ProposalId,
+        proposal_details: ProposalDetailsOf<Runtime>,
+    ) -> Vec<u8> {
+        Self::proposal_call(proposal_id, proposal_details).encode()
+    }
+}
+
+impl ExtrinsicProposalEncoder {
+    // Creates the Runtime Call executed by the proposal with the provided details.
+    fn proposal_call(
+        proposal_id: <Runtime as proposals_engine::Config>::ProposalId,
+        proposal_details: ProposalDetailsOf<Runtime>,
+    ) -> Call {
+        match proposal_details {
             ProposalDetails::Signal(signal) => {
                 Call::JoystreamUtility(joystream_utility::Call::execute_signal_proposal { signal })
             }
@@ -103,9 +116,10 @@ impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
                     Wg::terminate_role_call(terminate_role_params)
                 )
             }
-            ProposalDetails::AmendConstitution(constitution_text) => {
+            ProposalDetails::AmendConstitution(amendments) => {
                 Call::Constitution(pallet_constitution::Call::amend_constitution {
-                    constitution_text,
+                    amendments,
+                    proposal_id: Some(proposal_id),
                 })
             }
             ProposalDetails::CancelWorkingGroupLeadOpening(opening_id, working_group) => {
@@ -153,9 +167,16 @@ impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
             ProposalDetails::UpdateChannelPayouts(params) => {
                 Call::Content(content::Call::update_channel_payouts { params })
             }
-        };
-
-        call.encode()
+            ProposalDetails::SetCreatorTokenFrozenStatus(params) => {
+                Call::Content(content::Call::set_creator_token_frozen_status_as_council { params })
+            }
+            ProposalDetails::Batch(batch) => Call::Utility(substrate_utility::Call::batch_all {
+                calls: batch
+                    .into_iter()
+                    .map(|details| Self::proposal_call(proposal_id, details))
+                    .collect(),
+            }),
+        }
     }
 }
 
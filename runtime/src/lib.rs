@@ -122,7 +122,7 @@ pub use runtime_api::*;
 use integration::proposals::{CouncilManager, ExtrinsicProposalEncoder};
 
 use common::working_group::{WorkingGroup, WorkingGroupBudgetHandler};
-use council::ReferendumConnection;
+use council::{RecallReferendumConnection, ReferendumConnection};
 use pallet_staking::EraPayout;
 use referendum::{CastVote, OptionResult};
 use staking_handler::{LockComparator, StakingManager};
@@ -871,6 +871,7 @@ parameter_types! {
     };
     pub const MinimumCashoutAllowedLimit: Balance = dollars!(10);
     pub const MaximumCashoutAllowedLimit: Balance = dollars!(100_000);
+    pub const MaxAutoRevenueSplitsPerBlock: u32 = 100;
     pub const MaxNftAuctionWhitelistLength: MaxNumber = 20;
 
     // Channel bloat bond related:
@@ -923,6 +924,7 @@ impl content::Config for Runtime {
     type WeightInfo = content::weights::SubstrateWeight<Runtime>;
     type ModuleId = ContentModuleId;
     type MemberAuthenticator = Members;
+    type MemberReputationHandler = Members;
     type MaxKeysPerCuratorGroupPermissionsByLevelMap = MaxKeysPerCuratorGroupPermissionsByLevelMap;
     type MaxNumberOfAssetsPerChannel = MaxNumberOfAssetsPerChannel;
     type MaxNumberOfAssetsPerVideo = MaxNumberOfAssetsPerVideo;
@@ -938,6 +940,7 @@ impl content::Config for Runtime {
     type TransferId = TransferId;
     type MinimumCashoutAllowedLimit = MinimumCashoutAllowedLimit;
     type MaximumCashoutAllowedLimit = MaximumCashoutAllowedLimit;
+    type MaxAutoRevenueSplitsPerBlock = MaxAutoRevenueSplitsPerBlock;
     type MaxNftAuctionWhitelistLength = MaxNftAuctionWhitelistLength;
 }
 
@@ -945,6 +948,10 @@ parameter_types! {
     pub const ProjectTokenModuleId: PalletId = PalletId(*b"mo:token"); // module: token
     pub const MaxVestingSchedulesPerAccountPerToken: u32 = 5;
     pub const BlocksPerYear: u32 = 5259600; // 365,25 * 24 * 60 * 60 / 6
+    pub const MaxTokenPollAlternatives: u32 = 10;
+    pub const MaxAllowancesPerAccount: u32 = 10;
+    pub const MaxSaleTiers: u32 = 10;
+    pub const MaxBalanceCheckpoints: u32 = 20;
     // Account bloat bond related:
     pub ProjectTokenAccountCleanupTxFee: Balance = compute_fee(
         Call::ProjectToken(project_token::Call::<Runtime>::dust_account {
@@ -973,12 +980,18 @@ impl project_token::Config for Runtime {
     type BlocksPerYear = BlocksPerYear;
     type MemberOriginValidator = Members;
     type MembershipInfoProvider = Members;
+    type MaxPollAlternatives = MaxTokenPollAlternatives;
+    type MaxAllowancesPerAccount = MaxAllowancesPerAccount;
+    type MaxSaleTiers = MaxSaleTiers;
+    type MaxBalanceCheckpoints = MaxBalanceCheckpoints;
     type WeightInfo = project_token::weights::SubstrateWeight<Runtime>;
 }
 
 // The referendum instance alias.
 pub type ReferendumInstance = referendum::Instance1;
 pub type ReferendumModule = referendum::Module<Runtime, ReferendumInstance>;
+pub type RecallReferendumInstance = referendum::Instance2;
+pub type RecallReferendumModule = referendum::Module<Runtime, RecallReferendumInstance>;
 pub type CouncilModule = council::Module<Runtime>;
 
 // Production coucil and elections configuration
@@ -994,7 +1007,7 @@ parameter_types! {
     pub const VoteStageDuration: BlockNumber = days!(3);
     pub const RevealStageDuration: BlockNumber = days!(3);
     pub const MinimumVotingStake: Balance = dollars!(10);
-    pub const MaxWinnerTargetCount: u32 = CouncilSize::get();
+    pub const MaxWinnerTargetCount: u32 = CouncilSize::get() + MaxCouncilRunnersUp::get();
 
     // council parameteres
     pub const MinNumberOfExtraCandidates: u32 = 0;
@@ -1017,7 +1030,7 @@ parameter_types! {
     pub const VoteStageDuration: BlockNumber = 100;
     pub const RevealStageDuration: BlockNumber = 100;
     pub const MinimumVotingStake: Balance = dollars!(10);
-    pub const MaxWinnerTargetCount: u32 = CouncilSize::get();
+    pub const MaxWinnerTargetCount: u32 = CouncilSize::get() + MaxCouncilRunnersUp::get();
 
     // council parameteres
     pub const MinNumberOfExtraCandidates: u32 = 0;
@@ -1037,7 +1050,7 @@ parameter_types! {
     pub const VoteStageDuration: BlockNumber = hours!(1);
     pub const RevealStageDuration: BlockNumber = hours!(1);
     pub const MinimumVotingStake: Balance = dollars!(10);
-    pub const MaxWinnerTargetCount: u32 = CouncilSize::get();
+    pub const MaxWinnerTargetCount: u32 = CouncilSize::get() + MaxCouncilRunnersUp::get();
 
     // council parameteres
     pub const MinNumberOfExtraCandidates: u32 = 0;
@@ -1057,7 +1070,7 @@ parameter_types! {
     pub const VoteStageDuration: BlockNumber = 20;
     pub const RevealStageDuration: BlockNumber = 20;
     pub const MinimumVotingStake: Balance = dollars!(10);
-    pub const MaxWinnerTargetCount: u32 = CouncilSize::get();
+    pub const MaxWinnerTargetCount: u32 = CouncilSize::get() + MaxCouncilRunnersUp::get();
 
     // council parameteres
     pub const MinNumberOfExtraCandidates: u32 = 0;
@@ -1069,6 +1082,25 @@ parameter_types! {
     pub const BudgetRefillPeriod: BlockNumber = 6;
 }
 
+// Council seat rotation parameters independent of runtime profile
+parameter_types! {
+    pub const StaggeredSeatsPerCycle: u32 = 0; // whole council is replaced every cycle
+    pub const MaxConsecutiveTerms: u32 = 0; // no limit
+}
+
+// Referendum vote delegation parameters independent of runtime profile
+parameter_types! {
+    pub const MaxDelegatorsPerDelegate: u32 = 100;
+}
+
+// Council recall parameters independent of runtime profile
+parameter_types! {
+    pub const MinRecallPetitionStake: Balance = dollars!(100);
+    pub const RecallPetitionThreshold: Balance = dollars!(50_000);
+    pub const RecallMaxWinnerTargetCount: u32 = 2; // remove and keep options
+    pub const MaxCouncilRunnersUp: u32 = 3;
+}
+
 impl referendum::Config<ReferendumInstance> for Runtime {
     type Event = Event;
     type MaxSaltLength = MaxSaltLength;
@@ -1080,6 +1112,7 @@ impl referendum::Config<ReferendumInstance> for Runtime {
     type MinimumStake = MinimumVotingStake;
     type WeightInfo = referendum::weights::SubstrateWeight<Runtime>;
     type MaxWinnerTargetCount = MaxWinnerTargetCount;
+    type MaxDelegatorsPerDelegate = MaxDelegatorsPerDelegate;
 
     fn calculate_vote_power(
         _account_id: &<Self as frame_system::Config>::AccountId,
@@ -1118,16 +1151,71 @@ impl referendum::Config<ReferendumInstance> for Runtime {
     }
 }
 
+impl referendum::Config<RecallReferendumInstance> for Runtime {
+    type Event = Event;
+    type MaxSaltLength = MaxSaltLength;
+    type StakingHandler = RecallVotingStakingManager;
+    type ManagerOrigin = EnsureOneOf<EnsureSigned<Self::AccountId>, EnsureRoot<Self::AccountId>>;
+    type VotePower = Balance;
+    type VoteStageDuration = VoteStageDuration;
+    type RevealStageDuration = RevealStageDuration;
+    type MinimumStake = MinimumVotingStake;
+    type WeightInfo = referendum::weights::SubstrateWeight<Runtime>;
+    type MaxWinnerTargetCount = RecallMaxWinnerTargetCount;
+    type MaxDelegatorsPerDelegate = MaxDelegatorsPerDelegate;
+
+    fn calculate_vote_power(
+        _account_id: &<Self as frame_system::Config>::AccountId,
+        stake: &Balance,
+    ) -> Self::VotePower {
+        *stake
+    }
+
+    fn can_unlock_vote_stake(vote: &CastVote<Self::Hash, Balance, Self::MemberId>) -> bool {
+        <CouncilModule as RecallReferendumConnection<Runtime>>::can_unlock_recall_vote_stake(vote)
+            .is_ok()
+    }
+
+    fn process_results(winners: &[OptionResult<Self::MemberId, Self::VotePower>]) {
+        <CouncilModule as RecallReferendumConnection<Runtime>>::recieve_recall_referendum_results(
+            winners,
+        );
+    }
+
+    fn is_valid_option_id(option_index: &u64) -> bool {
+        <CouncilModule as RecallReferendumConnection<Runtime>>::is_valid_recall_option_id(
+            option_index,
+        )
+    }
+
+    fn get_option_power(option_id: &u64) -> Self::VotePower {
+        <CouncilModule as RecallReferendumConnection<Runtime>>::get_recall_option_power(option_id)
+    }
+
+    fn increase_option_power(option_id: &u64, amount: &Self::VotePower) {
+        <CouncilModule as RecallReferendumConnection<Runtime>>::increase_recall_option_power(
+            option_id, amount,
+        );
+    }
+}
+
 impl council::Config for Runtime {
     type Event = Event;
     type Referendum = ReferendumModule;
+    type RecallReferendum = RecallReferendumModule;
     type MinNumberOfExtraCandidates = MinNumberOfExtraCandidates;
     type CouncilSize = CouncilSize;
+    type StaggeredSeatsPerCycle = StaggeredSeatsPerCycle;
+    type MaxConsecutiveTerms = MaxConsecutiveTerms;
+    type MaxRunnersUp = MaxCouncilRunnersUp;
     type AnnouncingPeriodDuration = AnnouncingPeriodDuration;
     type IdlePeriodDuration = IdlePeriodDuration;
     type MinCandidateStake = MinCandidateStake;
     type CandidacyLock = StakingManager<Self, CandidacyLockId>;
     type CouncilorLock = StakingManager<Self, CouncilorLockId>;
+    type RecallPetitionLock = StakingManager<Self, RecallPetitionLockId>;
+    type MinRecallPetitionStake = MinRecallPetitionStake;
+    type RecallPetitionThreshold = RecallPetitionThreshold;
     type StakingAccountValidator = Members;
     type ElectedMemberRewardPeriod = ElectedMemberRewardPeriod;
     type BudgetRefillPeriod = BudgetRefillPeriod;
@@ -1249,6 +1337,18 @@ parameter_types! {
     pub const ReferralCutMaximumPercent: u8 = 50;
     pub const DefaultInitialInvitationBalance: Balance = cents!(50);
     pub const DefaultMemberInvitesCount: u32 = 2;
+    pub const MemberReputationRules: membership::ReputationRules = membership::ReputationRules {
+        successful_bounty_entry_reward: 10,
+        executed_proposal_reward: 5,
+        worker_tenure_reward: 1,
+        moderation_action_penalty: 10,
+    };
+    pub const MaxRecoveryGuardians: u32 = 10;
+    pub const RecoveryDelay: BlockNumber = days!(3);
+    pub const RecoveryDeposit: Balance = dollars!(10);
+    pub const HandleExpiryPeriod: BlockNumber = days!(365);
+    pub const HandleExpiryGracePeriod: BlockNumber = days!(730);
+    pub const MaxUplineReferralLevels: u32 = 3;
     // Candidate stake related:
     pub StakingAccountCleanupTxFee: Balance = compute_fee(
         Call::Members(membership::Call::<Runtime>::remove_staking_account { member_id: 0 })
@@ -1270,6 +1370,13 @@ impl membership::Config for Runtime {
     type ReferralCutMaximumPercent = ReferralCutMaximumPercent;
     type CandidateStake = CandidateStake;
     type DefaultMemberInvitesCount = DefaultMemberInvitesCount;
+    type MemberReputationRules = MemberReputationRules;
+    type MaxRecoveryGuardians = MaxRecoveryGuardians;
+    type RecoveryDelay = RecoveryDelay;
+    type RecoveryDeposit = RecoveryDeposit;
+    type HandleExpiryPeriod = HandleExpiryPeriod;
+    type HandleExpiryGracePeriod = HandleExpiryGracePeriod;
+    type MaxUplineReferralLevels = MaxUplineReferralLevels;
 }
 
 parameter_types! {
@@ -1314,6 +1421,12 @@ parameter_types! {
     pub const ForumModuleId: PalletId = PalletId(*b"mo:forum"); // module : forum
     pub const PostLifeTime: BlockNumber = days!(30);
     pub const MaxStickiedThreads: u32 = 20; // TODO: adjust
+    pub const MaxPollAlternatives: u32 = 20;
+    pub const ForumMaxReactionsPerPost: u32 = 200;
+    pub const ForumReportDeposit: Balance = dollars!(1);
+    pub const ForumReportReward: Balance = cents!(50);
+    pub const ForumMaxReportsPerPeriod: u32 = 10;
+    pub const ForumReportRateLimitPeriod: BlockNumber = days!(1);
 }
 
 pub struct MapLimits;
@@ -1331,6 +1444,10 @@ impl forum::Config for Runtime {
     type MaxCategoryDepth = MaxCategoryDepth;
     type ThreadDeposit = ThreadDeposit;
     type PostDeposit = PostDeposit;
+    type ReportDeposit = ForumReportDeposit;
+    type ReportReward = ForumReportReward;
+    type MaxReportsPerPeriod = ForumMaxReportsPerPeriod;
+    type ReportRateLimitPeriod = ForumReportRateLimitPeriod;
     type ModuleId = ForumModuleId;
     type MapLimits = MapLimits;
     type WeightInfo = forum::weights::SubstrateWeight<Runtime>;
@@ -1338,10 +1455,63 @@ impl forum::Config for Runtime {
     type MemberOriginValidator = Members;
     type PostLifeTime = PostLifeTime;
     type MaxStickiedThreads = MaxStickiedThreads;
+    type MaxPollAlternatives = MaxPollAlternatives;
+    type MaxReactionsPerPost = ForumMaxReactionsPerPost;
+    type StakingAccountValidator = Members;
+    type StakingHandler = ForumPollStakingManager;
+    type MembershipInfoProvider = Members;
+    type MemberReputationHandler = Members;
+    type TokenId = TokenId;
 
     fn calculate_hash(text: &[u8]) -> Self::Hash {
         Self::Hashing::hash(text)
     }
+
+    fn is_verified_member(member_id: &MemberId) -> bool {
+        Members::membership(member_id).map_or(false, |membership| membership.verified)
+    }
+
+    fn membership_created_at(member_id: &MemberId) -> BlockNumber {
+        Members::membership_created_at(member_id)
+    }
+
+    fn is_creator_token_holder(token_id: TokenId, member_id: &MemberId) -> bool {
+        !project_token::AccountInfoByTokenAndMember::<Runtime>::get(token_id, member_id)
+            .amount
+            .is_zero()
+    }
+
+    fn is_working_group_worker(working_group: WorkingGroup, member_id: &MemberId) -> bool {
+        match working_group {
+            WorkingGroup::Forum => is_worker_member::<ForumWorkingGroupInstance>(member_id),
+            WorkingGroup::Storage => is_worker_member::<StorageWorkingGroupInstance>(member_id),
+            WorkingGroup::Content => is_worker_member::<ContentWorkingGroupInstance>(member_id),
+            WorkingGroup::OperationsAlpha => {
+                is_worker_member::<OperationsWorkingGroupInstanceAlpha>(member_id)
+            }
+            WorkingGroup::Gateway => is_worker_member::<GatewayWorkingGroupInstance>(member_id),
+            WorkingGroup::Distribution => {
+                is_worker_member::<DistributionWorkingGroupInstance>(member_id)
+            }
+            WorkingGroup::OperationsBeta => {
+                is_worker_member::<OperationsWorkingGroupInstanceBeta>(member_id)
+            }
+            WorkingGroup::OperationsGamma => {
+                is_worker_member::<OperationsWorkingGroupInstanceGamma>(member_id)
+            }
+            WorkingGroup::Membership => {
+                is_worker_member::<MembershipWorkingGroupInstance>(member_id)
+            }
+        }
+    }
+}
+
+// Checks whether the member is a worker (or the leader) of the working group instance.
+fn is_worker_member<I: working_group::Instance>(member_id: &MemberId) -> bool
+where
+    Runtime: working_group::Config<I>,
+{
+    working_group::Module::<Runtime, I>::is_member_worker(member_id)
 }
 
 impl LockComparator<<Runtime as pallet_balances::Config>::Balance> for Runtime {
@@ -1400,6 +1570,7 @@ const_assert!(
 pub type ForumWorkingGroupStakingManager =
     staking_handler::StakingManager<Runtime, ForumGroupLockId>;
 pub type VotingStakingManager = staking_handler::StakingManager<Runtime, VotingLockId>;
+pub type RecallVotingStakingManager = staking_handler::StakingManager<Runtime, RecallVotingLockId>;
 pub type ContentWorkingGroupStakingManager =
     staking_handler::StakingManager<Runtime, ContentWorkingGroupLockId>;
 pub type StorageWorkingGroupStakingManager =
@@ -1420,6 +1591,7 @@ pub type OperationsWorkingGroupGammaStakingManager =
     staking_handler::StakingManager<Runtime, OperationsWorkingGroupGammaLockId>;
 pub type DistributionWorkingGroupStakingManager =
     staking_handler::StakingManager<Runtime, DistributionWorkingGroupLockId>;
+pub type ForumPollStakingManager = staking_handler::StakingManager<Runtime, ForumPollLockId>;
 
 // The forum working group instance alias.
 pub type ForumWorkingGroupInstance = working_group::Instance1;
@@ -1454,6 +1626,7 @@ impl working_group::Config<ForumWorkingGroupInstance> for Runtime {
     type StakingHandler = ForumWorkingGroupStakingManager;
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
+    type MemberReputationHandler = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
     type RewardPeriod = ForumWorkingGroupRewardPeriod;
     type WeightInfo = working_group::weights::SubstrateWeight<Runtime>;
@@ -1467,6 +1640,7 @@ impl working_group::Config<StorageWorkingGroupInstance> for Runtime {
     type StakingHandler = StorageWorkingGroupStakingManager;
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
+    type MemberReputationHandler = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
     type RewardPeriod = StorageWorkingGroupRewardPeriod;
     type WeightInfo = working_group::weights::SubstrateWeight<Runtime>;
@@ -1480,6 +1654,7 @@ impl working_group::Config<ContentWorkingGroupInstance> for Runtime {
     type StakingHandler = ContentWorkingGroupStakingManager;
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
+    type MemberReputationHandler = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
     type RewardPeriod = ContentWorkingGroupRewardPeriod;
     type WeightInfo = working_group::weights::SubstrateWeight<Runtime>;
@@ -1493,6 +1668,7 @@ impl working_group::Config<MembershipWorkingGroupInstance> for Runtime {
     type StakingHandler = MembershipWorkingGroupStakingManager;
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
+    type MemberReputationHandler = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
     type RewardPeriod = MembershipRewardPeriod;
     type WeightInfo = working_group::weights::SubstrateWeight<Runtime>;
@@ -1506,6 +1682,7 @@ impl working_group::Config<OperationsWorkingGroupInstanceAlpha> for Runtime {
     type StakingHandler = OperationsWorkingGroupAlphaStakingManager;
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
+    type MemberReputationHandler = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
     type RewardPeriod = OperationsAlphaRewardPeriod;
     type WeightInfo = working_group::weights::SubstrateWeight<Runtime>;
@@ -1519,6 +1696,7 @@ impl working_group::Config<GatewayWorkingGroupInstance> for Runtime {
     type StakingHandler = GatewayWorkingGroupStakingManager;
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
+    type MemberReputationHandler = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
     type RewardPeriod = GatewayRewardPeriod;
     type WeightInfo = working_group::weights::SubstrateWeight<Runtime>;
@@ -1532,6 +1710,7 @@ impl working_group::Config<OperationsWorkingGroupInstanceBeta> for Runtime {
     type StakingHandler = OperationsWorkingGroupBetaStakingManager;
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
+    type MemberReputationHandler = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
     type RewardPeriod = OperationsBetaRewardPeriod;
     type WeightInfo = working_group::weights::SubstrateWeight<Runtime>;
@@ -1545,6 +1724,7 @@ impl working_group::Config<OperationsWorkingGroupInstanceGamma> for Runtime {
     type StakingHandler = OperationsWorkingGroupGammaStakingManager;
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
+    type MemberReputationHandler = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
     type RewardPeriod = OperationsGammaRewardPeriod;
     type WeightInfo = working_group::weights::SubstrateWeight<Runtime>;
@@ -1558,6 +1738,7 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Runtime {
     type StakingHandler = DistributionWorkingGroupStakingManager;
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
+    type MemberReputationHandler = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
     type RewardPeriod = DistributionRewardPeriod;
     type WeightInfo = working_group::weights::SubstrateWeight<Runtime>;
@@ -1570,6 +1751,9 @@ parameter_types! {
     pub const ProposalRejectionFee: Balance = dollars!(5);
     pub const ProposalMaxActiveProposalLimit: u32 = 20;
     pub const DispatchableCallCodeMaxLen: u32 = mega_bytes!(3);
+    pub const MaxProposalDependencies: u32 = 5;
+    pub const ProposalAmendmentVotingPeriodRule: proposals_engine::AmendmentVotingPeriodRule<BlockNumber> =
+        proposals_engine::AmendmentVotingPeriodRule::ExtendTo(days!(1));
 }
 
 #[cfg(not(feature = "runtime-benchmarks"))]
@@ -1588,6 +1772,7 @@ parameter_types! {
 impl proposals_engine::Config for Runtime {
     type Event = Event;
     type ProposerOriginValidator = Members;
+    type MemberReputationHandler = Members;
     type CouncilOriginValidator = Council;
     type TotalVotersCounter = CouncilManager<Self>;
     type ProposalId = u32;
@@ -1602,6 +1787,8 @@ impl proposals_engine::Config for Runtime {
     type WeightInfo = proposals_engine::weights::SubstrateWeight<Runtime>;
     type StakingAccountValidator = Members;
     type DispatchableCallCodeMaxLen = DispatchableCallCodeMaxLen;
+    type MaxProposalDependencies = MaxProposalDependencies;
+    type AmendmentVotingPeriodRule = ProposalAmendmentVotingPeriodRule;
 }
 
 impl Default for Call {
@@ -1615,6 +1802,8 @@ parameter_types! {
     // module : proposals_discussion
     pub const ProposalsDiscussionModuleId: PalletId = PalletId(*b"mo:prdis");
     pub const ProposalsDiscussionPostLifetime: BlockNumber = hours!(1);
+    pub const ProposalsDiscussionMaxThreadAmendments: u32 = 10;
+    pub const ProposalsDiscussionMaxReactionsPerPost: u32 = 200;
 
     // Proposal discussion post deposit related:
     pub ProposalDiscussionPostCleanupTxFee: Balance = compute_fee(
@@ -1662,6 +1851,8 @@ impl proposals_discussion::Config for Runtime {
     type PostDeposit = ProposalsPostDeposit;
     type ModuleId = ProposalsDiscussionModuleId;
     type PostLifeTime = ProposalsDiscussionPostLifetime;
+    type MaxThreadAmendments = ProposalsDiscussionMaxThreadAmendments;
+    type MaxReactionsPerPost = ProposalsDiscussionMaxReactionsPerPost;
 }
 
 impl joystream_utility::Config for Runtime {
@@ -1684,6 +1875,7 @@ parameter_types! {
     pub const FundingRequestProposalMaxTotalAmount: Balance = dollars!(10_000);
     pub const FundingRequestProposalMaxAccounts: u32 = 20;
     pub const SetMaxValidatorCountProposalMaxValidators: u32 = 100;
+    pub const BatchProposalMaxProposals: u32 = 10;
 }
 
 const_assert!(
@@ -1725,14 +1917,18 @@ impl proposals_codex::Config for Runtime {
     type VetoProposalProposalParameters = VetoProposalProposalParameters;
     type UpdateGlobalNftLimitProposalParameters = UpdateGlobalNftLimitProposalParameters;
     type UpdateChannelPayoutsProposalParameters = UpdateChannelPayoutsProposalParameters;
+    type SetCreatorTokenFrozenStatusProposalParameters =
+        SetCreatorTokenFrozenStatusProposalParameters;
     type FundingRequestProposalMaxTotalAmount = FundingRequestProposalMaxTotalAmount;
     type FundingRequestProposalMaxAccounts = FundingRequestProposalMaxAccounts;
     type SetMaxValidatorCountProposalMaxValidators = SetMaxValidatorCountProposalMaxValidators;
+    type BatchProposalMaxProposals = BatchProposalMaxProposals;
     type WeightInfo = proposals_codex::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_constitution::Config for Runtime {
     type Event = Event;
+    type ProposalId = u32;
     type WeightInfo = pallet_constitution::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1915,5 +2111,7 @@ construct_runtime!(
         OperationsWorkingGroupBeta: working_group::<Instance7>::{Pallet, Call, Storage, Event<T>},
         OperationsWorkingGroupGamma: working_group::<Instance8>::{Pallet, Call, Storage, Event<T>},
         DistributionWorkingGroup: working_group::<Instance9>::{Pallet, Call, Storage, Event<T>},
+        // --- Council recall (appended to keep the indices of the pallets above unchanged)
+        RecallReferendum: referendum::<Instance2>::{Pallet, Call, Storage, Event<T>},
     }
 );
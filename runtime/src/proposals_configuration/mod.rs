@@ -106,4 +106,7 @@ parameter_types! {
 
     pub UpdateChannelPayoutsProposalParameters: ProposalParameters<BlockNumber, Balance> =
         update_channel_payouts_proposal();
+
+    pub SetCreatorTokenFrozenStatusProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        set_creator_token_frozen_status_proposal();
 }
@@ -13,9 +13,13 @@ use sp_std::vec::Vec;
 
 use crate::{
     AccountId, AllPalletsWithSystem, AuthorityDiscovery, AuthorityDiscoveryId, Babe, Balance,
-    BlockNumber, Call, EpochDuration, Grandpa, GrandpaAuthorityList, GrandpaId, Historical, Index,
-    InherentDataExt, ProposalsEngine, Runtime, RuntimeVersion, SessionKeys, Signature, System,
-    TransactionPayment, BABE_GENESIS_EPOCH_CONFIG, VERSION,
+    BlockNumber, Call, ChannelId, Constitution, Content, ContentWorkingGroup, Council,
+    DistributionWorkingGroup, EpochDuration, Forum, ForumWorkingGroup, GatewayWorkingGroup,
+    Grandpa, GrandpaAuthorityList, GrandpaId, Historical, Index, InherentDataExt, MemberId,
+    Members, MembershipWorkingGroup, OperationsWorkingGroupAlpha, OperationsWorkingGroupBeta,
+    OperationsWorkingGroupGamma, ProjectToken, ProposalsEngine, Runtime, RuntimeVersion,
+    SessionKeys, Signature, StorageWorkingGroup, System, TokenId, TransactionPayment,
+    BABE_GENESIS_EPOCH_CONFIG, VERSION,
 };
 
 use frame_support::weights::Weight;
@@ -63,9 +67,47 @@ pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signatu
 pub struct CustomOnRuntimeUpgrade;
 impl OnRuntimeUpgrade for CustomOnRuntimeUpgrade {
     fn on_runtime_upgrade() -> Weight {
+        // Translate the stored proposals to the current format before they are canceled.
+        let proposals_weight = ProposalsEngine::migrate_proposals();
+
         ProposalsEngine::cancel_active_and_pending_proposals();
 
-        10_000_000 // TODO: adjust weight
+        // Index the existing workers by their member ids.
+        let workers_indexing_weight = ForumWorkingGroup::rebuild_worker_count_by_member_id()
+            .saturating_add(StorageWorkingGroup::rebuild_worker_count_by_member_id())
+            .saturating_add(ContentWorkingGroup::rebuild_worker_count_by_member_id())
+            .saturating_add(OperationsWorkingGroupAlpha::rebuild_worker_count_by_member_id())
+            .saturating_add(GatewayWorkingGroup::rebuild_worker_count_by_member_id())
+            .saturating_add(MembershipWorkingGroup::rebuild_worker_count_by_member_id())
+            .saturating_add(OperationsWorkingGroupBeta::rebuild_worker_count_by_member_id())
+            .saturating_add(OperationsWorkingGroupGamma::rebuild_worker_count_by_member_id())
+            .saturating_add(DistributionWorkingGroup::rebuild_worker_count_by_member_id());
+
+        // Start the handle expiry grace period for the existing members.
+        let handle_expiry_weight = Members::introduce_handle_expiry();
+
+        // Consider the sitting councilors elected in the current announcement cycle.
+        let council_members_weight = Council::migrate_council_members();
+
+        // Carry the current constitution over to the sectioned constitution.
+        let constitution_weight = Constitution::migrate_constitution();
+
+        // Translate the stored forum threads to the current format.
+        let forum_threads_weight = Forum::migrate_threads();
+
+        // Translate the stored creator tokens and their accounts to the current format.
+        let tokens_weight = ProjectToken::migrate_token_data();
+        let token_accounts_weight = ProjectToken::migrate_account_data();
+
+        proposals_weight
+            .saturating_add(workers_indexing_weight)
+            .saturating_add(handle_expiry_weight)
+            .saturating_add(council_members_weight)
+            .saturating_add(constitution_weight)
+            .saturating_add(forum_threads_weight)
+            .saturating_add(tokens_weight)
+            .saturating_add(token_accounts_weight)
+            .saturating_add(10_000_000) // TODO: adjust weight
     }
 }
 
@@ -298,6 +340,25 @@ impl_runtime_apis! {
         }
     }
 
+    impl content::runtime_api::ContentApi<Block, ChannelId, MemberId, BlockNumber> for Runtime {
+        fn is_subscribed(
+            channel_id: ChannelId,
+            member_id: MemberId,
+            tier_id: content::SubscriptionTierId,
+            block: BlockNumber,
+        ) -> bool {
+            Content::is_subscribed(channel_id, member_id, tier_id, block)
+        }
+    }
+
+    impl project_token::runtime_api::ProjectTokenApi<Block, TokenId, Balance> for Runtime {
+        fn token_poll_tallies(
+            token_id: TokenId,
+        ) -> Option<(project_token::types::TokenPollId, Vec<Balance>)> {
+            ProjectToken::token_poll_tallies(token_id)
+        }
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     impl frame_benchmarking::Benchmark<Block> for Runtime {
         fn benchmark_metadata(extra: bool) -> (
@@ -3,7 +3,7 @@ use super::{
 };
 use crate::{
     currency, BoundStakingAccountStakingManager, ContentWorkingGroupStakingManager,
-    GatewayWorkingGroupStakingManager, MinVestedTransfer, Runtime,
+    ForumPollStakingManager, GatewayWorkingGroupStakingManager, MinVestedTransfer, Runtime,
 };
 use frame_support::{assert_err, assert_ok, traits::StoredMap};
 use frame_system::RawOrigin;
@@ -48,6 +48,23 @@ fn compatible_stakes_check_reversed_order_passed_successfully() {
     });
 }
 
+#[test]
+fn forum_poll_stake_is_compatible_with_working_group_stake() {
+    initial_test_ext().execute_with(|| {
+        let account_id = account_from_member_id(0);
+        let stake_amount = currency::DOLLARS * 100;
+        let total_amount = stake_amount * 100;
+
+        increase_total_balance_issuance_using_account_id(account_id.clone(), total_amount);
+
+        assert_eq!(
+            ContentWorkingGroupStakingManager::set_stake(&account_id, stake_amount),
+            Ok(())
+        );
+        assert!(ForumPollStakingManager::is_account_free_of_conflicting_stakes(&account_id));
+    });
+}
+
 #[test]
 fn incompatible_stakes_check_passed_successfully() {
     initial_test_ext().execute_with(|| {
@@ -313,6 +313,9 @@ pub(crate) fn max_proposal_stake() -> Balance {
         .push(<Runtime as proposals_codex::Config>::UpdateChannelPayoutsProposalParameters::get());
     stakes
         .push(<Runtime as proposals_codex::Config>::UpdateGlobalNftLimitProposalParameters::get());
+    stakes.push(
+        <Runtime as proposals_codex::Config>::SetCreatorTokenFrozenStatusProposalParameters::get(),
+    );
 
     stakes
         .iter()
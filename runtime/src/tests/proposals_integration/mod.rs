@@ -10,6 +10,7 @@ use crate::tests::{
 use crate::{currency, MembershipWorkingGroupInstance, ProposalCancellationFee, Runtime};
 use codec::Encode;
 use content::NftLimitPeriod;
+use pallet_constitution::SectionAmendment;
 use proposals_codex::{GeneralProposalParameters, ProposalDetails};
 use proposals_engine::{
     ApprovedProposalDecision, Proposal, ProposalCreationParameters, ProposalParameters,
@@ -37,6 +38,7 @@ pub type ProposalsCodex = proposals_codex::Module<Runtime>;
 pub type Council = council::Module<Runtime>;
 pub type Membership = membership::Module<Runtime>;
 pub type MembershipWorkingGroup = working_group::Module<Runtime, MembershipWorkingGroupInstance>;
+pub type Constitution = pallet_constitution::Module<Runtime>;
 
 struct VoteGenerator {
     proposal_id: u32,
@@ -191,6 +193,7 @@ impl DummyProposalFixture {
             staking_account_id: self.staking_account_id,
             encoded_dispatchable_call_code: self.proposal_code,
             exact_execution_block: self.exact_execution_block,
+            dependencies: Vec::new(),
         });
 
         assert_eq!(proposal_id_result, result);
@@ -302,6 +305,7 @@ fn proposal_cancellation_with_slashes_with_balance_checks_succeeds() {
             parameters,
             proposer_id: member_id,
             activated_at: 1,
+            voting_period_extension: 0,
             status: ProposalStatus::Active,
             voting_results: VotingResults::default(),
             exact_execution_block: None,
@@ -565,6 +569,7 @@ fn text_proposal_execution_succeeds() {
                 description: b"body".to_vec(),
                 staking_account_id: Some(account_id.clone()),
                 exact_execution_block: None,
+                dependencies: Vec::new(),
             };
 
             ProposalsCodex::create_proposal(
@@ -601,6 +606,7 @@ fn funding_request_proposal_execution_succeeds() {
                 description: b"body".to_vec(),
                 staking_account_id: Some(account_id.clone()),
                 exact_execution_block: None,
+                dependencies: Vec::new(),
             };
 
             ProposalsCodex::create_proposal(
@@ -646,12 +652,13 @@ fn veto_proposal_proposal_execution_succeeds() {
                 description: b"body".to_vec(),
                 staking_account_id: Some(account_id.clone()),
                 exact_execution_block: None,
+                dependencies: Vec::new(),
             };
 
             ProposalsCodex::create_proposal(
                 RawOrigin::Signed(account_id.clone()).into(),
                 general_proposal_parameters,
-                ProposalDetails::AmendConstitution(vec![0u8]),
+                ProposalDetails::AmendConstitution(vec![SectionAmendment::Add(1, vec![0u8])]),
             )
         })
         .with_member_id(member_id as u64);
@@ -671,6 +678,7 @@ fn veto_proposal_proposal_execution_succeeds() {
                 description: b"body".to_vec(),
                 staking_account_id: Some(account_id.clone()),
                 exact_execution_block: None,
+                dependencies: Vec::new(),
             };
 
             ProposalsCodex::create_proposal(
@@ -733,6 +741,7 @@ fn set_validator_count_proposal_execution_succeeds() {
                 description: b"body".to_vec(),
                 staking_account_id: Some(staking_account_id.into()),
                 exact_execution_block: None,
+                dependencies: Vec::new(),
             };
 
             ProposalsCodex::create_proposal(
@@ -763,6 +772,7 @@ fn amend_constitution_proposal_execution_succeeds() {
 
         let member_id = create_new_members(1)[0];
         let account_id = account_from_member_id(member_id);
+        let proposal_id = ProposalsEngine::proposal_count() + 1;
 
         let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
             let general_proposal_parameters = GeneralProposalParameters::<Runtime> {
@@ -771,12 +781,16 @@ fn amend_constitution_proposal_execution_succeeds() {
                 description: b"body".to_vec(),
                 staking_account_id: Some(account_id.clone()),
                 exact_execution_block: None,
+                dependencies: Vec::new(),
             };
 
             ProposalsCodex::create_proposal(
                 RawOrigin::Signed(account_id.clone()).into(),
                 general_proposal_parameters,
-                ProposalDetails::AmendConstitution(b"Constitution text".to_vec()),
+                ProposalDetails::AmendConstitution(vec![SectionAmendment::Add(
+                    1,
+                    b"Constitution text".to_vec(),
+                )]),
             )
         })
         .with_member_id(member_id as u64);
@@ -787,7 +801,13 @@ fn amend_constitution_proposal_execution_succeeds() {
             <Runtime as proposals_codex::Config>::AmendConstitutionProposalParameters::get();
         run_to_block(System::block_number() + params.grace_period + 1);
 
-        // assert constitution text was changed
+        // assert constitution sections were amended by the proposal
+        assert_eq!(Constitution::constitution().version, 1);
+        assert_eq!(
+            Constitution::constitution_versions(1).map(|version| version.proposal_id),
+            Some(Some(proposal_id))
+        );
+        assert!(Constitution::constitution_sections(1).text_hash.is_some());
     });
 }
 
@@ -808,6 +828,7 @@ fn set_membership_price_proposal_execution_succeeds() {
                 description: b"body".to_vec(),
                 staking_account_id: Some(account_id.clone()),
                 exact_execution_block: None,
+                dependencies: Vec::new(),
             };
 
             ProposalsCodex::create_proposal(
@@ -845,6 +866,7 @@ fn set_initial_invitation_balance_proposal_succeeds() {
                 description: b"body".to_vec(),
                 staking_account_id: Some(account_id.clone()),
                 exact_execution_block: None,
+                dependencies: Vec::new(),
             };
 
             ProposalsCodex::create_proposal(
@@ -886,6 +908,7 @@ fn set_initial_invitation_count_proposal_succeeds() {
                 description: b"body".to_vec(),
                 staking_account_id: Some(account_id.clone()),
                 exact_execution_block: None,
+                dependencies: Vec::new(),
             };
 
             ProposalsCodex::create_proposal(
@@ -927,6 +950,7 @@ fn set_membership_leader_invitation_quota_proposal_succeeds() {
                 description: b"body".to_vec(),
                 staking_account_id: Some(account_id.clone()),
                 exact_execution_block: None,
+                dependencies: Vec::new(),
             };
 
             ProposalsCodex::create_proposal(
@@ -966,6 +990,7 @@ fn set_referral_cut_proposal_succeeds() {
                 description: b"body".to_vec(),
                 staking_account_id: Some(account_id.clone()),
                 exact_execution_block: None,
+                dependencies: Vec::new(),
             };
 
             ProposalsCodex::create_proposal(
@@ -1006,6 +1031,7 @@ fn set_budget_increment_proposal_succeds() {
                 description: b"body".to_vec(),
                 staking_account_id: Some(account_id.clone()),
                 exact_execution_block: None,
+                dependencies: Vec::new(),
             };
 
             ProposalsCodex::create_proposal(
@@ -1048,6 +1074,7 @@ fn set_councilor_reward_proposal_succeds() {
                 description: b"body".to_vec(),
                 staking_account_id: Some(account_id.clone()),
                 exact_execution_block: None,
+                dependencies: Vec::new(),
             };
 
             ProposalsCodex::create_proposal(
@@ -1152,6 +1179,7 @@ fn update_global_nft_limit_proposal_succeeds() {
                 description: b"body".to_vec(),
                 staking_account_id: Some(account_id.clone()),
                 exact_execution_block: None,
+                dependencies: Vec::new(),
             };
 
             ProposalsCodex::create_proposal(
@@ -129,6 +129,7 @@ fn add_opening(
             description: b"body".to_vec(),
             staking_account_id: Some(staking_account_id.into()),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         ProposalsCodex::create_proposal(
@@ -215,6 +216,7 @@ fn fill_opening(
             description: b"body".to_vec(),
             staking_account_id: Some(staking_account_id.into()),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         ProposalsCodex::create_proposal(
@@ -260,6 +262,7 @@ fn decrease_stake(
             description: b"body".to_vec(),
             staking_account_id: Some(staking_account_id.into()),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         ProposalsCodex::create_proposal(
@@ -300,6 +303,7 @@ fn slash_stake(
             description: b"body".to_vec(),
             staking_account_id: Some(staking_account_id.into()),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         ProposalsCodex::create_proposal(
@@ -341,6 +345,7 @@ fn set_reward(
             description: b"body".to_vec(),
             staking_account_id: Some(staking_account_id.into()),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         ProposalsCodex::create_proposal(
@@ -390,6 +395,7 @@ fn set_mint_capacity<
             description: b"body".to_vec(),
             staking_account_id: Some(staking_account_id.into()),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         ProposalsCodex::create_proposal(
@@ -431,6 +437,7 @@ fn terminate_role(
             description: b"body".to_vec(),
             staking_account_id: Some(staking_account_id.into()),
             exact_execution_block: None,
+            dependencies: Vec::new(),
         };
 
         ProposalsCodex::create_proposal(